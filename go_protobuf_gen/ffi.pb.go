@@ -174,6 +174,15 @@ type TransactionContext struct {
 	BlockBaseFeePerGas []byte `protobuf:"bytes,5,opt,name=block_base_fee_per_gas,json=blockBaseFeePerGas,proto3" json:"block_base_fee_per_gas,omitempty"`
 	BlockCoinbase      []byte `protobuf:"bytes,6,opt,name=block_coinbase,json=blockCoinbase,proto3" json:"block_coinbase,omitempty"`
 	BlockNumber        uint64 `protobuf:"varint,7,opt,name=block_number,json=blockNumber,proto3" json:"block_number,omitempty"`
+	// EVM hard fork to use: 0 - London, 1 - Shanghai. Cancun is not supported yet
+	EvmFork uint32 `protobuf:"varint,8,opt,name=evm_fork,json=evmFork,proto3" json:"evm_fork,omitempty"`
+	// 32-byte block randomness returned by PREVRANDAO opcode. Mixed with secret
+	// derived from master key inside the enclave, so transactions are rejected
+	// if master key is not initialized. If empty, PREVRANDAO returns 0
+	PrevRandao []byte `protobuf:"bytes,9,opt,name=prev_randao,json=prevRandao,proto3" json:"prev_randao,omitempty"`
+	// gas used by previous transactions of the block. Cumulative gas used of
+	// receipts is counted starting from this value
+	BlockGasUsed uint64 `protobuf:"varint,10,opt,name=block_gas_used,json=blockGasUsed,proto3" json:"block_gas_used,omitempty"`
 }
 
 func (x *TransactionContext) Reset() {
@@ -257,6 +266,27 @@ func (x *TransactionContext) GetBlockNumber() uint64 {
 	return 0
 }
 
+func (x *TransactionContext) GetEvmFork() uint32 {
+	if x != nil {
+		return x.EvmFork
+	}
+	return 0
+}
+
+func (x *TransactionContext) GetPrevRandao() []byte {
+	if x != nil {
+		return x.PrevRandao
+	}
+	return nil
+}
+
+func (x *TransactionContext) GetBlockGasUsed() uint64 {
+	if x != nil {
+		return x.BlockGasUsed
+	}
+	return 0
+}
+
 type HandleTransactionRequest struct {
 	state         protoimpl.MessageState
 	sizeCache     protoimpl.SizeCache
@@ -327,6 +357,25 @@ type HandleTransactionResponse struct {
 	VmError string `protobuf:"bytes,4,opt,name=vm_error,json=vmError,proto3" json:"vm_error,omitempty"`
 	// gas consumed by the transaction
 	GasUsed uint64 `protobuf:"varint,5,opt,name=gas_used,json=gasUsed,proto3" json:"gas_used,omitempty"`
+	// decoded reason of reverted transaction. Not set for encrypted
+	// transactions, since their returned data is encrypted
+	Revert *RevertReason `protobuf:"bytes,6,opt,name=revert,proto3" json:"revert,omitempty"`
+	// exit status returned by vm: "succeed", "revert", "error" or "fatal".
+	// Empty if transaction was not executed
+	ExitStatus string `protobuf:"bytes,7,opt,name=exitStatus,proto3" json:"exitStatus,omitempty"`
+	// variant of exit reason, for example "Returned" or "OutOfGas"
+	ExitReason string `protobuf:"bytes,8,opt,name=exitReason,proto3" json:"exitReason,omitempty"`
+	// address of contract created by successful contract creation
+	ContractAddress []byte `protobuf:"bytes,9,opt,name=contractAddress,proto3" json:"contractAddress,omitempty"`
+	// gas refund, which is already subtracted from gas_used
+	GasRefunded uint64 `protobuf:"varint,10,opt,name=gasRefunded,proto3" json:"gasRefunded,omitempty"`
+	// gas charged before execution for transaction data and access list
+	IntrinsicGas uint64 `protobuf:"varint,11,opt,name=intrinsicGas,proto3" json:"intrinsicGas,omitempty"`
+	// gas consumed by execution before refund
+	ExecutionGas uint64 `protobuf:"varint,12,opt,name=executionGas,proto3" json:"executionGas,omitempty"`
+	// receipt of executed transaction. Set for call, create, batch and signed
+	// transaction requests
+	Receipt *Receipt `protobuf:"bytes,13,opt,name=receipt,proto3" json:"receipt,omitempty"`
 }
 
 func (x *HandleTransactionResponse) Reset() {
@@ -389,6 +438,222 @@ func (x *HandleTransactionResponse) GetGasUsed() uint64 {
 	return 0
 }
 
+func (x *HandleTransactionResponse) GetRevert() *RevertReason {
+	if x != nil {
+		return x.Revert
+	}
+	return nil
+}
+
+func (x *HandleTransactionResponse) GetExitStatus() string {
+	if x != nil {
+		return x.ExitStatus
+	}
+	return ""
+}
+
+func (x *HandleTransactionResponse) GetExitReason() string {
+	if x != nil {
+		return x.ExitReason
+	}
+	return ""
+}
+
+func (x *HandleTransactionResponse) GetContractAddress() []byte {
+	if x != nil {
+		return x.ContractAddress
+	}
+	return nil
+}
+
+func (x *HandleTransactionResponse) GetGasRefunded() uint64 {
+	if x != nil {
+		return x.GasRefunded
+	}
+	return 0
+}
+
+func (x *HandleTransactionResponse) GetIntrinsicGas() uint64 {
+	if x != nil {
+		return x.IntrinsicGas
+	}
+	return 0
+}
+
+func (x *HandleTransactionResponse) GetExecutionGas() uint64 {
+	if x != nil {
+		return x.ExecutionGas
+	}
+	return 0
+}
+
+func (x *HandleTransactionResponse) GetReceipt() *Receipt {
+	if x != nil {
+		return x.Receipt
+	}
+	return nil
+}
+
+// Receipt of executed transaction
+type Receipt struct {
+	state         protoimpl.MessageState
+	sizeCache     protoimpl.SizeCache
+	unknownFields protoimpl.UnknownFields
+
+	// 1 if transaction succeeded, 0 otherwise
+	Status uint32 `protobuf:"varint,1,opt,name=status,proto3" json:"status,omitempty"`
+	// gas used by this and all previous transactions of the block
+	CumulativeGasUsed uint64 `protobuf:"varint,2,opt,name=cumulativeGasUsed,proto3" json:"cumulativeGasUsed,omitempty"`
+	// 2048-bit bloom filter of log addresses and topics
+	LogsBloom []byte `protobuf:"bytes,3,opt,name=logsBloom,proto3" json:"logsBloom,omitempty"`
+	// RLP-encoded receipt, prefixed with transaction type for typed transactions
+	Encoded []byte `protobuf:"bytes,4,opt,name=encoded,proto3" json:"encoded,omitempty"`
+}
+
+func (x *Receipt) Reset() {
+	*x = Receipt{}
+	if protoimpl.UnsafeEnabled {
+		mi := &file_ffi_proto_msgTypes[5]
+		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
+		ms.StoreMessageInfo(mi)
+	}
+}
+
+func (x *Receipt) String() string {
+	return protoimpl.X.MessageStringOf(x)
+}
+
+func (*Receipt) ProtoMessage() {}
+
+func (x *Receipt) ProtoReflect() protoreflect.Message {
+	mi := &file_ffi_proto_msgTypes[5]
+	if protoimpl.UnsafeEnabled && x != nil {
+		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
+		if ms.LoadMessageInfo() == nil {
+			ms.StoreMessageInfo(mi)
+		}
+		return ms
+	}
+	return mi.MessageOf(x)
+}
+
+// Deprecated: Use Receipt.ProtoReflect.Descriptor instead.
+func (*Receipt) Descriptor() ([]byte, []int) {
+	return file_ffi_proto_rawDescGZIP(), []int{5}
+}
+
+func (x *Receipt) GetStatus() uint32 {
+	if x != nil {
+		return x.Status
+	}
+	return 0
+}
+
+func (x *Receipt) GetCumulativeGasUsed() uint64 {
+	if x != nil {
+		return x.CumulativeGasUsed
+	}
+	return 0
+}
+
+func (x *Receipt) GetLogsBloom() []byte {
+	if x != nil {
+		return x.LogsBloom
+	}
+	return nil
+}
+
+func (x *Receipt) GetEncoded() []byte {
+	if x != nil {
+		return x.Encoded
+	}
+	return nil
+}
+
+// RevertReason contains decoded data returned by reverted transaction
+type RevertReason struct {
+	state         protoimpl.MessageState
+	sizeCache     protoimpl.SizeCache
+	unknownFields protoimpl.UnknownFields
+
+	// "error" for `Error(string)`, "panic" for `Panic(uint256)`, "custom" for
+	// custom errors
+	Kind string `protobuf:"bytes,1,opt,name=kind,proto3" json:"kind,omitempty"`
+	// message of `Error(string)` or meaning of panic code
+	Message   string `protobuf:"bytes,2,opt,name=message,proto3" json:"message,omitempty"`
+	PanicCode uint64 `protobuf:"varint,3,opt,name=panicCode,proto3" json:"panicCode,omitempty"`
+	// 4-byte selector and ABI-encoded arguments of custom error
+	Selector []byte `protobuf:"bytes,4,opt,name=selector,proto3" json:"selector,omitempty"`
+	Args     []byte `protobuf:"bytes,5,opt,name=args,proto3" json:"args,omitempty"`
+}
+
+func (x *RevertReason) Reset() {
+	*x = RevertReason{}
+	if protoimpl.UnsafeEnabled {
+		mi := &file_ffi_proto_msgTypes[6]
+		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
+		ms.StoreMessageInfo(mi)
+	}
+}
+
+func (x *RevertReason) String() string {
+	return protoimpl.X.MessageStringOf(x)
+}
+
+func (*RevertReason) ProtoMessage() {}
+
+func (x *RevertReason) ProtoReflect() protoreflect.Message {
+	mi := &file_ffi_proto_msgTypes[6]
+	if protoimpl.UnsafeEnabled && x != nil {
+		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
+		if ms.LoadMessageInfo() == nil {
+			ms.StoreMessageInfo(mi)
+		}
+		return ms
+	}
+	return mi.MessageOf(x)
+}
+
+// Deprecated: Use RevertReason.ProtoReflect.Descriptor instead.
+func (*RevertReason) Descriptor() ([]byte, []int) {
+	return file_ffi_proto_rawDescGZIP(), []int{6}
+}
+
+func (x *RevertReason) GetKind() string {
+	if x != nil {
+		return x.Kind
+	}
+	return ""
+}
+
+func (x *RevertReason) GetMessage() string {
+	if x != nil {
+		return x.Message
+	}
+	return ""
+}
+
+func (x *RevertReason) GetPanicCode() uint64 {
+	if x != nil {
+		return x.PanicCode
+	}
+	return 0
+}
+
+func (x *RevertReason) GetSelector() []byte {
+	if x != nil {
+		return x.Selector
+	}
+	return nil
+}
+
+func (x *RevertReason) GetArgs() []byte {
+	if x != nil {
+		return x.Args
+	}
+	return nil
+}
+
 // Topic represents 32-byte words that is used to describe what’s going on in an
 // event
 type Topic struct {
@@ -402,7 +667,7 @@ type Topic struct {
 func (x *Topic) Reset() {
 	*x = Topic{}
 	if protoimpl.UnsafeEnabled {
-		mi := &file_ffi_proto_msgTypes[5]
+		mi := &file_ffi_proto_msgTypes[7]
 		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
 		ms.StoreMessageInfo(mi)
 	}
@@ -415,7 +680,7 @@ func (x *Topic) String() string {
 func (*Topic) ProtoMessage() {}
 
 func (x *Topic) ProtoReflect() protoreflect.Message {
-	mi := &file_ffi_proto_msgTypes[5]
+	mi := &file_ffi_proto_msgTypes[7]
 	if protoimpl.UnsafeEnabled && x != nil {
 		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
 		if ms.LoadMessageInfo() == nil {
@@ -428,7 +693,7 @@ func (x *Topic) ProtoReflect() protoreflect.Message {
 
 // Deprecated: Use Topic.ProtoReflect.Descriptor instead.
 func (*Topic) Descriptor() ([]byte, []int) {
-	return file_ffi_proto_rawDescGZIP(), []int{5}
+	return file_ffi_proto_rawDescGZIP(), []int{7}
 }
 
 func (x *Topic) GetInner() []byte {
@@ -457,7 +722,7 @@ type Log struct {
 func (x *Log) Reset() {
 	*x = Log{}
 	if protoimpl.UnsafeEnabled {
-		mi := &file_ffi_proto_msgTypes[6]
+		mi := &file_ffi_proto_msgTypes[8]
 		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
 		ms.StoreMessageInfo(mi)
 	}
@@ -470,7 +735,7 @@ func (x *Log) String() string {
 func (*Log) ProtoMessage() {}
 
 func (x *Log) ProtoReflect() protoreflect.Message {
-	mi := &file_ffi_proto_msgTypes[6]
+	mi := &file_ffi_proto_msgTypes[8]
 	if protoimpl.UnsafeEnabled && x != nil {
 		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
 		if ms.LoadMessageInfo() == nil {
@@ -483,7 +748,7 @@ func (x *Log) ProtoReflect() protoreflect.Message {
 
 // Deprecated: Use Log.ProtoReflect.Descriptor instead.
 func (*Log) Descriptor() ([]byte, []int) {
-	return file_ffi_proto_rawDescGZIP(), []int{6}
+	return file_ffi_proto_rawDescGZIP(), []int{8}
 }
 
 func (x *Log) GetAddress() []byte {
@@ -520,7 +785,7 @@ type QueryGetAccount struct {
 func (x *QueryGetAccount) Reset() {
 	*x = QueryGetAccount{}
 	if protoimpl.UnsafeEnabled {
-		mi := &file_ffi_proto_msgTypes[7]
+		mi := &file_ffi_proto_msgTypes[9]
 		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
 		ms.StoreMessageInfo(mi)
 	}
@@ -533,7 +798,7 @@ func (x *QueryGetAccount) String() string {
 func (*QueryGetAccount) ProtoMessage() {}
 
 func (x *QueryGetAccount) ProtoReflect() protoreflect.Message {
-	mi := &file_ffi_proto_msgTypes[7]
+	mi := &file_ffi_proto_msgTypes[9]
 	if protoimpl.UnsafeEnabled && x != nil {
 		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
 		if ms.LoadMessageInfo() == nil {
@@ -546,7 +811,7 @@ func (x *QueryGetAccount) ProtoReflect() protoreflect.Message {
 
 // Deprecated: Use QueryGetAccount.ProtoReflect.Descriptor instead.
 func (*QueryGetAccount) Descriptor() ([]byte, []int) {
-	return file_ffi_proto_rawDescGZIP(), []int{7}
+	return file_ffi_proto_rawDescGZIP(), []int{9}
 }
 
 func (x *QueryGetAccount) GetAddress() []byte {
@@ -571,7 +836,7 @@ type QueryGetAccountResponse struct {
 func (x *QueryGetAccountResponse) Reset() {
 	*x = QueryGetAccountResponse{}
 	if protoimpl.UnsafeEnabled {
-		mi := &file_ffi_proto_msgTypes[8]
+		mi := &file_ffi_proto_msgTypes[10]
 		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
 		ms.StoreMessageInfo(mi)
 	}
@@ -584,7 +849,7 @@ func (x *QueryGetAccountResponse) String() string {
 func (*QueryGetAccountResponse) ProtoMessage() {}
 
 func (x *QueryGetAccountResponse) ProtoReflect() protoreflect.Message {
-	mi := &file_ffi_proto_msgTypes[8]
+	mi := &file_ffi_proto_msgTypes[10]
 	if protoimpl.UnsafeEnabled && x != nil {
 		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
 		if ms.LoadMessageInfo() == nil {
@@ -597,7 +862,7 @@ func (x *QueryGetAccountResponse) ProtoReflect() protoreflect.Message {
 
 // Deprecated: Use QueryGetAccountResponse.ProtoReflect.Descriptor instead.
 func (*QueryGetAccountResponse) Descriptor() ([]byte, []int) {
-	return file_ffi_proto_rawDescGZIP(), []int{8}
+	return file_ffi_proto_rawDescGZIP(), []int{10}
 }
 
 func (x *QueryGetAccountResponse) GetBalance() []byte {
@@ -628,7 +893,7 @@ type QueryInsertAccount struct {
 func (x *QueryInsertAccount) Reset() {
 	*x = QueryInsertAccount{}
 	if protoimpl.UnsafeEnabled {
-		mi := &file_ffi_proto_msgTypes[9]
+		mi := &file_ffi_proto_msgTypes[11]
 		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
 		ms.StoreMessageInfo(mi)
 	}
@@ -641,7 +906,7 @@ func (x *QueryInsertAccount) String() string {
 func (*QueryInsertAccount) ProtoMessage() {}
 
 func (x *QueryInsertAccount) ProtoReflect() protoreflect.Message {
-	mi := &file_ffi_proto_msgTypes[9]
+	mi := &file_ffi_proto_msgTypes[11]
 	if protoimpl.UnsafeEnabled && x != nil {
 		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
 		if ms.LoadMessageInfo() == nil {
@@ -654,7 +919,7 @@ func (x *QueryInsertAccount) ProtoReflect() protoreflect.Message {
 
 // Deprecated: Use QueryInsertAccount.ProtoReflect.Descriptor instead.
 func (*QueryInsertAccount) Descriptor() ([]byte, []int) {
-	return file_ffi_proto_rawDescGZIP(), []int{9}
+	return file_ffi_proto_rawDescGZIP(), []int{11}
 }
 
 func (x *QueryInsertAccount) GetAddress() []byte {
@@ -688,7 +953,7 @@ type QueryInsertAccountResponse struct {
 func (x *QueryInsertAccountResponse) Reset() {
 	*x = QueryInsertAccountResponse{}
 	if protoimpl.UnsafeEnabled {
-		mi := &file_ffi_proto_msgTypes[10]
+		mi := &file_ffi_proto_msgTypes[12]
 		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
 		ms.StoreMessageInfo(mi)
 	}
@@ -701,7 +966,7 @@ func (x *QueryInsertAccountResponse) String() string {
 func (*QueryInsertAccountResponse) ProtoMessage() {}
 
 func (x *QueryInsertAccountResponse) ProtoReflect() protoreflect.Message {
-	mi := &file_ffi_proto_msgTypes[10]
+	mi := &file_ffi_proto_msgTypes[12]
 	if protoimpl.UnsafeEnabled && x != nil {
 		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
 		if ms.LoadMessageInfo() == nil {
@@ -714,7 +979,7 @@ func (x *QueryInsertAccountResponse) ProtoReflect() protoreflect.Message {
 
 // Deprecated: Use QueryInsertAccountResponse.ProtoReflect.Descriptor instead.
 func (*QueryInsertAccountResponse) Descriptor() ([]byte, []int) {
-	return file_ffi_proto_rawDescGZIP(), []int{10}
+	return file_ffi_proto_rawDescGZIP(), []int{12}
 }
 
 type QueryContainsKey struct {
@@ -728,7 +993,7 @@ type QueryContainsKey struct {
 func (x *QueryContainsKey) Reset() {
 	*x = QueryContainsKey{}
 	if protoimpl.UnsafeEnabled {
-		mi := &file_ffi_proto_msgTypes[11]
+		mi := &file_ffi_proto_msgTypes[13]
 		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
 		ms.StoreMessageInfo(mi)
 	}
@@ -741,7 +1006,7 @@ func (x *QueryContainsKey) String() string {
 func (*QueryContainsKey) ProtoMessage() {}
 
 func (x *QueryContainsKey) ProtoReflect() protoreflect.Message {
-	mi := &file_ffi_proto_msgTypes[11]
+	mi := &file_ffi_proto_msgTypes[13]
 	if protoimpl.UnsafeEnabled && x != nil {
 		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
 		if ms.LoadMessageInfo() == nil {
@@ -754,7 +1019,7 @@ func (x *QueryContainsKey) ProtoReflect() protoreflect.Message {
 
 // Deprecated: Use QueryContainsKey.ProtoReflect.Descriptor instead.
 func (*QueryContainsKey) Descriptor() ([]byte, []int) {
-	return file_ffi_proto_rawDescGZIP(), []int{11}
+	return file_ffi_proto_rawDescGZIP(), []int{13}
 }
 
 func (x *QueryContainsKey) GetKey() []byte {
@@ -775,7 +1040,7 @@ type QueryContainsKeyResponse struct {
 func (x *QueryContainsKeyResponse) Reset() {
 	*x = QueryContainsKeyResponse{}
 	if protoimpl.UnsafeEnabled {
-		mi := &file_ffi_proto_msgTypes[12]
+		mi := &file_ffi_proto_msgTypes[14]
 		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
 		ms.StoreMessageInfo(mi)
 	}
@@ -788,7 +1053,7 @@ func (x *QueryContainsKeyResponse) String() string {
 func (*QueryContainsKeyResponse) ProtoMessage() {}
 
 func (x *QueryContainsKeyResponse) ProtoReflect() protoreflect.Message {
-	mi := &file_ffi_proto_msgTypes[12]
+	mi := &file_ffi_proto_msgTypes[14]
 	if protoimpl.UnsafeEnabled && x != nil {
 		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
 		if ms.LoadMessageInfo() == nil {
@@ -801,7 +1066,7 @@ func (x *QueryContainsKeyResponse) ProtoReflect() protoreflect.Message {
 
 // Deprecated: Use QueryContainsKeyResponse.ProtoReflect.Descriptor instead.
 func (*QueryContainsKeyResponse) Descriptor() ([]byte, []int) {
-	return file_ffi_proto_rawDescGZIP(), []int{12}
+	return file_ffi_proto_rawDescGZIP(), []int{14}
 }
 
 func (x *QueryContainsKeyResponse) GetContains() bool {
@@ -823,7 +1088,7 @@ type QueryGetAccountStorageCell struct {
 func (x *QueryGetAccountStorageCell) Reset() {
 	*x = QueryGetAccountStorageCell{}
 	if protoimpl.UnsafeEnabled {
-		mi := &file_ffi_proto_msgTypes[13]
+		mi := &file_ffi_proto_msgTypes[15]
 		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
 		ms.StoreMessageInfo(mi)
 	}
@@ -836,7 +1101,7 @@ func (x *QueryGetAccountStorageCell) String() string {
 func (*QueryGetAccountStorageCell) ProtoMessage() {}
 
 func (x *QueryGetAccountStorageCell) ProtoReflect() protoreflect.Message {
-	mi := &file_ffi_proto_msgTypes[13]
+	mi := &file_ffi_proto_msgTypes[15]
 	if protoimpl.UnsafeEnabled && x != nil {
 		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
 		if ms.LoadMessageInfo() == nil {
@@ -849,7 +1114,7 @@ func (x *QueryGetAccountStorageCell) ProtoReflect() protoreflect.Message {
 
 // Deprecated: Use QueryGetAccountStorageCell.ProtoReflect.Descriptor instead.
 func (*QueryGetAccountStorageCell) Descriptor() ([]byte, []int) {
-	return file_ffi_proto_rawDescGZIP(), []int{13}
+	return file_ffi_proto_rawDescGZIP(), []int{15}
 }
 
 func (x *QueryGetAccountStorageCell) GetAddress() []byte {
@@ -877,7 +1142,7 @@ type QueryGetAccountStorageCellResponse struct {
 func (x *QueryGetAccountStorageCellResponse) Reset() {
 	*x = QueryGetAccountStorageCellResponse{}
 	if protoimpl.UnsafeEnabled {
-		mi := &file_ffi_proto_msgTypes[14]
+		mi := &file_ffi_proto_msgTypes[16]
 		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
 		ms.StoreMessageInfo(mi)
 	}
@@ -890,7 +1155,7 @@ func (x *QueryGetAccountStorageCellResponse) String() string {
 func (*QueryGetAccountStorageCellResponse) ProtoMessage() {}
 
 func (x *QueryGetAccountStorageCellResponse) ProtoReflect() protoreflect.Message {
-	mi := &file_ffi_proto_msgTypes[14]
+	mi := &file_ffi_proto_msgTypes[16]
 	if protoimpl.UnsafeEnabled && x != nil {
 		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
 		if ms.LoadMessageInfo() == nil {
@@ -903,7 +1168,7 @@ func (x *QueryGetAccountStorageCellResponse) ProtoReflect() protoreflect.Message
 
 // Deprecated: Use QueryGetAccountStorageCellResponse.ProtoReflect.Descriptor instead.
 func (*QueryGetAccountStorageCellResponse) Descriptor() ([]byte, []int) {
-	return file_ffi_proto_rawDescGZIP(), []int{14}
+	return file_ffi_proto_rawDescGZIP(), []int{16}
 }
 
 func (x *QueryGetAccountStorageCellResponse) GetValue() []byte {
@@ -924,7 +1189,7 @@ type QueryGetAccountCode struct {
 func (x *QueryGetAccountCode) Reset() {
 	*x = QueryGetAccountCode{}
 	if protoimpl.UnsafeEnabled {
-		mi := &file_ffi_proto_msgTypes[15]
+		mi := &file_ffi_proto_msgTypes[17]
 		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
 		ms.StoreMessageInfo(mi)
 	}
@@ -937,7 +1202,7 @@ func (x *QueryGetAccountCode) String() string {
 func (*QueryGetAccountCode) ProtoMessage() {}
 
 func (x *QueryGetAccountCode) ProtoReflect() protoreflect.Message {
-	mi := &file_ffi_proto_msgTypes[15]
+	mi := &file_ffi_proto_msgTypes[17]
 	if protoimpl.UnsafeEnabled && x != nil {
 		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
 		if ms.LoadMessageInfo() == nil {
@@ -950,7 +1215,7 @@ func (x *QueryGetAccountCode) ProtoReflect() protoreflect.Message {
 
 // Deprecated: Use QueryGetAccountCode.ProtoReflect.Descriptor instead.
 func (*QueryGetAccountCode) Descriptor() ([]byte, []int) {
-	return file_ffi_proto_rawDescGZIP(), []int{15}
+	return file_ffi_proto_rawDescGZIP(), []int{17}
 }
 
 func (x *QueryGetAccountCode) GetAddress() []byte {
@@ -971,7 +1236,7 @@ type QueryGetAccountCodeResponse struct {
 func (x *QueryGetAccountCodeResponse) Reset() {
 	*x = QueryGetAccountCodeResponse{}
 	if protoimpl.UnsafeEnabled {
-		mi := &file_ffi_proto_msgTypes[16]
+		mi := &file_ffi_proto_msgTypes[18]
 		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
 		ms.StoreMessageInfo(mi)
 	}
@@ -984,7 +1249,7 @@ func (x *QueryGetAccountCodeResponse) String() string {
 func (*QueryGetAccountCodeResponse) ProtoMessage() {}
 
 func (x *QueryGetAccountCodeResponse) ProtoReflect() protoreflect.Message {
-	mi := &file_ffi_proto_msgTypes[16]
+	mi := &file_ffi_proto_msgTypes[18]
 	if protoimpl.UnsafeEnabled && x != nil {
 		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
 		if ms.LoadMessageInfo() == nil {
@@ -997,7 +1262,7 @@ func (x *QueryGetAccountCodeResponse) ProtoReflect() protoreflect.Message {
 
 // Deprecated: Use QueryGetAccountCodeResponse.ProtoReflect.Descriptor instead.
 func (*QueryGetAccountCodeResponse) Descriptor() ([]byte, []int) {
-	return file_ffi_proto_rawDescGZIP(), []int{16}
+	return file_ffi_proto_rawDescGZIP(), []int{18}
 }
 
 func (x *QueryGetAccountCodeResponse) GetCode() []byte {
@@ -1019,7 +1284,7 @@ type QueryInsertAccountCode struct {
 func (x *QueryInsertAccountCode) Reset() {
 	*x = QueryInsertAccountCode{}
 	if protoimpl.UnsafeEnabled {
-		mi := &file_ffi_proto_msgTypes[17]
+		mi := &file_ffi_proto_msgTypes[19]
 		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
 		ms.StoreMessageInfo(mi)
 	}
@@ -1032,7 +1297,7 @@ func (x *QueryInsertAccountCode) String() string {
 func (*QueryInsertAccountCode) ProtoMessage() {}
 
 func (x *QueryInsertAccountCode) ProtoReflect() protoreflect.Message {
-	mi := &file_ffi_proto_msgTypes[17]
+	mi := &file_ffi_proto_msgTypes[19]
 	if protoimpl.UnsafeEnabled && x != nil {
 		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
 		if ms.LoadMessageInfo() == nil {
@@ -1045,7 +1310,7 @@ func (x *QueryInsertAccountCode) ProtoReflect() protoreflect.Message {
 
 // Deprecated: Use QueryInsertAccountCode.ProtoReflect.Descriptor instead.
 func (*QueryInsertAccountCode) Descriptor() ([]byte, []int) {
-	return file_ffi_proto_rawDescGZIP(), []int{17}
+	return file_ffi_proto_rawDescGZIP(), []int{19}
 }
 
 func (x *QueryInsertAccountCode) GetAddress() []byte {
@@ -1071,7 +1336,7 @@ type QueryInsertAccountCodeResponse struct {
 func (x *QueryInsertAccountCodeResponse) Reset() {
 	*x = QueryInsertAccountCodeResponse{}
 	if protoimpl.UnsafeEnabled {
-		mi := &file_ffi_proto_msgTypes[18]
+		mi := &file_ffi_proto_msgTypes[20]
 		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
 		ms.StoreMessageInfo(mi)
 	}
@@ -1084,7 +1349,7 @@ func (x *QueryInsertAccountCodeResponse) String() string {
 func (*QueryInsertAccountCodeResponse) ProtoMessage() {}
 
 func (x *QueryInsertAccountCodeResponse) ProtoReflect() protoreflect.Message {
-	mi := &file_ffi_proto_msgTypes[18]
+	mi := &file_ffi_proto_msgTypes[20]
 	if protoimpl.UnsafeEnabled && x != nil {
 		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
 		if ms.LoadMessageInfo() == nil {
@@ -1097,7 +1362,7 @@ func (x *QueryInsertAccountCodeResponse) ProtoReflect() protoreflect.Message {
 
 // Deprecated: Use QueryInsertAccountCodeResponse.ProtoReflect.Descriptor instead.
 func (*QueryInsertAccountCodeResponse) Descriptor() ([]byte, []int) {
-	return file_ffi_proto_rawDescGZIP(), []int{18}
+	return file_ffi_proto_rawDescGZIP(), []int{20}
 }
 
 type QueryInsertStorageCell struct {
@@ -1113,7 +1378,7 @@ type QueryInsertStorageCell struct {
 func (x *QueryInsertStorageCell) Reset() {
 	*x = QueryInsertStorageCell{}
 	if protoimpl.UnsafeEnabled {
-		mi := &file_ffi_proto_msgTypes[19]
+		mi := &file_ffi_proto_msgTypes[21]
 		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
 		ms.StoreMessageInfo(mi)
 	}
@@ -1126,7 +1391,7 @@ func (x *QueryInsertStorageCell) String() string {
 func (*QueryInsertStorageCell) ProtoMessage() {}
 
 func (x *QueryInsertStorageCell) ProtoReflect() protoreflect.Message {
-	mi := &file_ffi_proto_msgTypes[19]
+	mi := &file_ffi_proto_msgTypes[21]
 	if protoimpl.UnsafeEnabled && x != nil {
 		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
 		if ms.LoadMessageInfo() == nil {
@@ -1139,7 +1404,7 @@ func (x *QueryInsertStorageCell) ProtoReflect() protoreflect.Message {
 
 // Deprecated: Use QueryInsertStorageCell.ProtoReflect.Descriptor instead.
 func (*QueryInsertStorageCell) Descriptor() ([]byte, []int) {
-	return file_ffi_proto_rawDescGZIP(), []int{19}
+	return file_ffi_proto_rawDescGZIP(), []int{21}
 }
 
 func (x *QueryInsertStorageCell) GetAddress() []byte {
@@ -1172,7 +1437,7 @@ type QueryInsertStorageCellResponse struct {
 func (x *QueryInsertStorageCellResponse) Reset() {
 	*x = QueryInsertStorageCellResponse{}
 	if protoimpl.UnsafeEnabled {
-		mi := &file_ffi_proto_msgTypes[20]
+		mi := &file_ffi_proto_msgTypes[22]
 		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
 		ms.StoreMessageInfo(mi)
 	}
@@ -1185,7 +1450,7 @@ func (x *QueryInsertStorageCellResponse) String() string {
 func (*QueryInsertStorageCellResponse) ProtoMessage() {}
 
 func (x *QueryInsertStorageCellResponse) ProtoReflect() protoreflect.Message {
-	mi := &file_ffi_proto_msgTypes[20]
+	mi := &file_ffi_proto_msgTypes[22]
 	if protoimpl.UnsafeEnabled && x != nil {
 		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
 		if ms.LoadMessageInfo() == nil {
@@ -1198,7 +1463,7 @@ func (x *QueryInsertStorageCellResponse) ProtoReflect() protoreflect.Message {
 
 // Deprecated: Use QueryInsertStorageCellResponse.ProtoReflect.Descriptor instead.
 func (*QueryInsertStorageCellResponse) Descriptor() ([]byte, []int) {
-	return file_ffi_proto_rawDescGZIP(), []int{20}
+	return file_ffi_proto_rawDescGZIP(), []int{22}
 }
 
 type QueryRemove struct {
@@ -1212,7 +1477,7 @@ type QueryRemove struct {
 func (x *QueryRemove) Reset() {
 	*x = QueryRemove{}
 	if protoimpl.UnsafeEnabled {
-		mi := &file_ffi_proto_msgTypes[21]
+		mi := &file_ffi_proto_msgTypes[23]
 		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
 		ms.StoreMessageInfo(mi)
 	}
@@ -1225,7 +1490,7 @@ func (x *QueryRemove) String() string {
 func (*QueryRemove) ProtoMessage() {}
 
 func (x *QueryRemove) ProtoReflect() protoreflect.Message {
-	mi := &file_ffi_proto_msgTypes[21]
+	mi := &file_ffi_proto_msgTypes[23]
 	if protoimpl.UnsafeEnabled && x != nil {
 		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
 		if ms.LoadMessageInfo() == nil {
@@ -1238,7 +1503,7 @@ func (x *QueryRemove) ProtoReflect() protoreflect.Message {
 
 // Deprecated: Use QueryRemove.ProtoReflect.Descriptor instead.
 func (*QueryRemove) Descriptor() ([]byte, []int) {
-	return file_ffi_proto_rawDescGZIP(), []int{21}
+	return file_ffi_proto_rawDescGZIP(), []int{23}
 }
 
 func (x *QueryRemove) GetAddress() []byte {
@@ -1257,7 +1522,7 @@ type QueryRemoveResponse struct {
 func (x *QueryRemoveResponse) Reset() {
 	*x = QueryRemoveResponse{}
 	if protoimpl.UnsafeEnabled {
-		mi := &file_ffi_proto_msgTypes[22]
+		mi := &file_ffi_proto_msgTypes[24]
 		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
 		ms.StoreMessageInfo(mi)
 	}
@@ -1270,7 +1535,7 @@ func (x *QueryRemoveResponse) String() string {
 func (*QueryRemoveResponse) ProtoMessage() {}
 
 func (x *QueryRemoveResponse) ProtoReflect() protoreflect.Message {
-	mi := &file_ffi_proto_msgTypes[22]
+	mi := &file_ffi_proto_msgTypes[24]
 	if protoimpl.UnsafeEnabled && x != nil {
 		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
 		if ms.LoadMessageInfo() == nil {
@@ -1283,7 +1548,7 @@ func (x *QueryRemoveResponse) ProtoReflect() protoreflect.Message {
 
 // Deprecated: Use QueryRemoveResponse.ProtoReflect.Descriptor instead.
 func (*QueryRemoveResponse) Descriptor() ([]byte, []int) {
-	return file_ffi_proto_rawDescGZIP(), []int{22}
+	return file_ffi_proto_rawDescGZIP(), []int{24}
 }
 
 type QueryRemoveStorageCell struct {
@@ -1298,7 +1563,7 @@ type QueryRemoveStorageCell struct {
 func (x *QueryRemoveStorageCell) Reset() {
 	*x = QueryRemoveStorageCell{}
 	if protoimpl.UnsafeEnabled {
-		mi := &file_ffi_proto_msgTypes[23]
+		mi := &file_ffi_proto_msgTypes[25]
 		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
 		ms.StoreMessageInfo(mi)
 	}
@@ -1311,7 +1576,7 @@ func (x *QueryRemoveStorageCell) String() string {
 func (*QueryRemoveStorageCell) ProtoMessage() {}
 
 func (x *QueryRemoveStorageCell) ProtoReflect() protoreflect.Message {
-	mi := &file_ffi_proto_msgTypes[23]
+	mi := &file_ffi_proto_msgTypes[25]
 	if protoimpl.UnsafeEnabled && x != nil {
 		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
 		if ms.LoadMessageInfo() == nil {
@@ -1324,7 +1589,7 @@ func (x *QueryRemoveStorageCell) ProtoReflect() protoreflect.Message {
 
 // Deprecated: Use QueryRemoveStorageCell.ProtoReflect.Descriptor instead.
 func (*QueryRemoveStorageCell) Descriptor() ([]byte, []int) {
-	return file_ffi_proto_rawDescGZIP(), []int{23}
+	return file_ffi_proto_rawDescGZIP(), []int{25}
 }
 
 func (x *QueryRemoveStorageCell) GetAddress() []byte {
@@ -1350,7 +1615,7 @@ type QueryRemoveStorageCellResponse struct {
 func (x *QueryRemoveStorageCellResponse) Reset() {
 	*x = QueryRemoveStorageCellResponse{}
 	if protoimpl.UnsafeEnabled {
-		mi := &file_ffi_proto_msgTypes[24]
+		mi := &file_ffi_proto_msgTypes[26]
 		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
 		ms.StoreMessageInfo(mi)
 	}
@@ -1363,7 +1628,7 @@ func (x *QueryRemoveStorageCellResponse) String() string {
 func (*QueryRemoveStorageCellResponse) ProtoMessage() {}
 
 func (x *QueryRemoveStorageCellResponse) ProtoReflect() protoreflect.Message {
-	mi := &file_ffi_proto_msgTypes[24]
+	mi := &file_ffi_proto_msgTypes[26]
 	if protoimpl.UnsafeEnabled && x != nil {
 		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
 		if ms.LoadMessageInfo() == nil {
@@ -1376,7 +1641,7 @@ func (x *QueryRemoveStorageCellResponse) ProtoReflect() protoreflect.Message {
 
 // Deprecated: Use QueryRemoveStorageCellResponse.ProtoReflect.Descriptor instead.
 func (*QueryRemoveStorageCellResponse) Descriptor() ([]byte, []int) {
-	return file_ffi_proto_rawDescGZIP(), []int{24}
+	return file_ffi_proto_rawDescGZIP(), []int{26}
 }
 
 type QueryRemoveStorage struct {
@@ -1390,7 +1655,7 @@ type QueryRemoveStorage struct {
 func (x *QueryRemoveStorage) Reset() {
 	*x = QueryRemoveStorage{}
 	if protoimpl.UnsafeEnabled {
-		mi := &file_ffi_proto_msgTypes[25]
+		mi := &file_ffi_proto_msgTypes[27]
 		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
 		ms.StoreMessageInfo(mi)
 	}
@@ -1403,7 +1668,7 @@ func (x *QueryRemoveStorage) String() string {
 func (*QueryRemoveStorage) ProtoMessage() {}
 
 func (x *QueryRemoveStorage) ProtoReflect() protoreflect.Message {
-	mi := &file_ffi_proto_msgTypes[25]
+	mi := &file_ffi_proto_msgTypes[27]
 	if protoimpl.UnsafeEnabled && x != nil {
 		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
 		if ms.LoadMessageInfo() == nil {
@@ -1416,7 +1681,7 @@ func (x *QueryRemoveStorage) ProtoReflect() protoreflect.Message {
 
 // Deprecated: Use QueryRemoveStorage.ProtoReflect.Descriptor instead.
 func (*QueryRemoveStorage) Descriptor() ([]byte, []int) {
-	return file_ffi_proto_rawDescGZIP(), []int{25}
+	return file_ffi_proto_rawDescGZIP(), []int{27}
 }
 
 func (x *QueryRemoveStorage) GetAddress() []byte {
@@ -1435,7 +1700,7 @@ type QueryRemoveStorageResponse struct {
 func (x *QueryRemoveStorageResponse) Reset() {
 	*x = QueryRemoveStorageResponse{}
 	if protoimpl.UnsafeEnabled {
-		mi := &file_ffi_proto_msgTypes[26]
+		mi := &file_ffi_proto_msgTypes[28]
 		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
 		ms.StoreMessageInfo(mi)
 	}
@@ -1448,7 +1713,7 @@ func (x *QueryRemoveStorageResponse) String() string {
 func (*QueryRemoveStorageResponse) ProtoMessage() {}
 
 func (x *QueryRemoveStorageResponse) ProtoReflect() protoreflect.Message {
-	mi := &file_ffi_proto_msgTypes[26]
+	mi := &file_ffi_proto_msgTypes[28]
 	if protoimpl.UnsafeEnabled && x != nil {
 		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
 		if ms.LoadMessageInfo() == nil {
@@ -1461,7 +1726,7 @@ func (x *QueryRemoveStorageResponse) ProtoReflect() protoreflect.Message {
 
 // Deprecated: Use QueryRemoveStorageResponse.ProtoReflect.Descriptor instead.
 func (*QueryRemoveStorageResponse) Descriptor() ([]byte, []int) {
-	return file_ffi_proto_rawDescGZIP(), []int{26}
+	return file_ffi_proto_rawDescGZIP(), []int{28}
 }
 
 type QueryBlockHash struct {
@@ -1475,7 +1740,7 @@ type QueryBlockHash struct {
 func (x *QueryBlockHash) Reset() {
 	*x = QueryBlockHash{}
 	if protoimpl.UnsafeEnabled {
-		mi := &file_ffi_proto_msgTypes[27]
+		mi := &file_ffi_proto_msgTypes[29]
 		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
 		ms.StoreMessageInfo(mi)
 	}
@@ -1488,7 +1753,7 @@ func (x *QueryBlockHash) String() string {
 func (*QueryBlockHash) ProtoMessage() {}
 
 func (x *QueryBlockHash) ProtoReflect() protoreflect.Message {
-	mi := &file_ffi_proto_msgTypes[27]
+	mi := &file_ffi_proto_msgTypes[29]
 	if protoimpl.UnsafeEnabled && x != nil {
 		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
 		if ms.LoadMessageInfo() == nil {
@@ -1501,7 +1766,7 @@ func (x *QueryBlockHash) ProtoReflect() protoreflect.Message {
 
 // Deprecated: Use QueryBlockHash.ProtoReflect.Descriptor instead.
 func (*QueryBlockHash) Descriptor() ([]byte, []int) {
-	return file_ffi_proto_rawDescGZIP(), []int{27}
+	return file_ffi_proto_rawDescGZIP(), []int{29}
 }
 
 func (x *QueryBlockHash) GetNumber() []byte {
@@ -1522,7 +1787,7 @@ type QueryBlockHashResponse struct {
 func (x *QueryBlockHashResponse) Reset() {
 	*x = QueryBlockHashResponse{}
 	if protoimpl.UnsafeEnabled {
-		mi := &file_ffi_proto_msgTypes[28]
+		mi := &file_ffi_proto_msgTypes[30]
 		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
 		ms.StoreMessageInfo(mi)
 	}
@@ -1535,7 +1800,7 @@ func (x *QueryBlockHashResponse) String() string {
 func (*QueryBlockHashResponse) ProtoMessage() {}
 
 func (x *QueryBlockHashResponse) ProtoReflect() protoreflect.Message {
-	mi := &file_ffi_proto_msgTypes[28]
+	mi := &file_ffi_proto_msgTypes[30]
 	if protoimpl.UnsafeEnabled && x != nil {
 		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
 		if ms.LoadMessageInfo() == nil {
@@ -1548,7 +1813,7 @@ func (x *QueryBlockHashResponse) ProtoReflect() protoreflect.Message {
 
 // Deprecated: Use QueryBlockHashResponse.ProtoReflect.Descriptor instead.
 func (*QueryBlockHashResponse) Descriptor() ([]byte, []int) {
-	return file_ffi_proto_rawDescGZIP(), []int{28}
+	return file_ffi_proto_rawDescGZIP(), []int{30}
 }
 
 func (x *QueryBlockHashResponse) GetHash() []byte {
@@ -1558,43 +1823,35 @@ func (x *QueryBlockHashResponse) GetHash() []byte {
 	return nil
 }
 
-type CosmosRequest struct {
+// Request to obtain several accounts, contract codes and storage cells within
+// a single query
+type QueryBatchRead struct {
 	state         protoimpl.MessageState
 	sizeCache     protoimpl.SizeCache
 	unknownFields protoimpl.UnknownFields
 
-	// Types that are assignable to Req:
-	//	*CosmosRequest_GetAccount
-	//	*CosmosRequest_InsertAccount
-	//	*CosmosRequest_ContainsKey
-	//	*CosmosRequest_AccountCode
-	//	*CosmosRequest_StorageCell
-	//	*CosmosRequest_InsertAccountCode
-	//	*CosmosRequest_InsertStorageCell
-	//	*CosmosRequest_Remove
-	//	*CosmosRequest_RemoveStorageCell
-	//	*CosmosRequest_RemoveStorage
-	//	*CosmosRequest_BlockHash
-	Req isCosmosRequest_Req `protobuf_oneof:"req"`
+	Accounts     []*QueryGetAccount            `protobuf:"bytes,1,rep,name=accounts,proto3" json:"accounts,omitempty"`
+	Codes        []*QueryGetAccountCode        `protobuf:"bytes,2,rep,name=codes,proto3" json:"codes,omitempty"`
+	StorageCells []*QueryGetAccountStorageCell `protobuf:"bytes,3,rep,name=storageCells,proto3" json:"storageCells,omitempty"`
 }
 
-func (x *CosmosRequest) Reset() {
-	*x = CosmosRequest{}
+func (x *QueryBatchRead) Reset() {
+	*x = QueryBatchRead{}
 	if protoimpl.UnsafeEnabled {
-		mi := &file_ffi_proto_msgTypes[29]
+		mi := &file_ffi_proto_msgTypes[31]
 		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
 		ms.StoreMessageInfo(mi)
 	}
 }
 
-func (x *CosmosRequest) String() string {
+func (x *QueryBatchRead) String() string {
 	return protoimpl.X.MessageStringOf(x)
 }
 
-func (*CosmosRequest) ProtoMessage() {}
+func (*QueryBatchRead) ProtoMessage() {}
 
-func (x *CosmosRequest) ProtoReflect() protoreflect.Message {
-	mi := &file_ffi_proto_msgTypes[29]
+func (x *QueryBatchRead) ProtoReflect() protoreflect.Message {
+	mi := &file_ffi_proto_msgTypes[31]
 	if protoimpl.UnsafeEnabled && x != nil {
 		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
 		if ms.LoadMessageInfo() == nil {
@@ -1605,198 +1862,130 @@ func (x *CosmosRequest) ProtoReflect() protoreflect.Message {
 	return mi.MessageOf(x)
 }
 
-// Deprecated: Use CosmosRequest.ProtoReflect.Descriptor instead.
-func (*CosmosRequest) Descriptor() ([]byte, []int) {
-	return file_ffi_proto_rawDescGZIP(), []int{29}
+// Deprecated: Use QueryBatchRead.ProtoReflect.Descriptor instead.
+func (*QueryBatchRead) Descriptor() ([]byte, []int) {
+	return file_ffi_proto_rawDescGZIP(), []int{31}
 }
 
-func (m *CosmosRequest) GetReq() isCosmosRequest_Req {
-	if m != nil {
-		return m.Req
+func (x *QueryBatchRead) GetAccounts() []*QueryGetAccount {
+	if x != nil {
+		return x.Accounts
 	}
 	return nil
 }
 
-func (x *CosmosRequest) GetGetAccount() *QueryGetAccount {
-	if x, ok := x.GetReq().(*CosmosRequest_GetAccount); ok {
-		return x.GetAccount
+func (x *QueryBatchRead) GetCodes() []*QueryGetAccountCode {
+	if x != nil {
+		return x.Codes
 	}
 	return nil
 }
 
-func (x *CosmosRequest) GetInsertAccount() *QueryInsertAccount {
-	if x, ok := x.GetReq().(*CosmosRequest_InsertAccount); ok {
-		return x.InsertAccount
+func (x *QueryBatchRead) GetStorageCells() []*QueryGetAccountStorageCell {
+	if x != nil {
+		return x.StorageCells
 	}
 	return nil
 }
 
-func (x *CosmosRequest) GetContainsKey() *QueryContainsKey {
-	if x, ok := x.GetReq().(*CosmosRequest_ContainsKey); ok {
-		return x.ContainsKey
-	}
-	return nil
-}
+// Response for batch read. Items are in the same order as corresponding
+// sub-requests
+type QueryBatchReadResponse struct {
+	state         protoimpl.MessageState
+	sizeCache     protoimpl.SizeCache
+	unknownFields protoimpl.UnknownFields
 
-func (x *CosmosRequest) GetAccountCode() *QueryGetAccountCode {
-	if x, ok := x.GetReq().(*CosmosRequest_AccountCode); ok {
-		return x.AccountCode
-	}
-	return nil
+	Accounts     []*QueryGetAccountResponse            `protobuf:"bytes,1,rep,name=accounts,proto3" json:"accounts,omitempty"`
+	Codes        []*QueryGetAccountCodeResponse        `protobuf:"bytes,2,rep,name=codes,proto3" json:"codes,omitempty"`
+	StorageCells []*QueryGetAccountStorageCellResponse `protobuf:"bytes,3,rep,name=storageCells,proto3" json:"storageCells,omitempty"`
 }
 
-func (x *CosmosRequest) GetStorageCell() *QueryGetAccountStorageCell {
-	if x, ok := x.GetReq().(*CosmosRequest_StorageCell); ok {
-		return x.StorageCell
+func (x *QueryBatchReadResponse) Reset() {
+	*x = QueryBatchReadResponse{}
+	if protoimpl.UnsafeEnabled {
+		mi := &file_ffi_proto_msgTypes[32]
+		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
+		ms.StoreMessageInfo(mi)
 	}
-	return nil
 }
 
-func (x *CosmosRequest) GetInsertAccountCode() *QueryInsertAccountCode {
-	if x, ok := x.GetReq().(*CosmosRequest_InsertAccountCode); ok {
-		return x.InsertAccountCode
-	}
-	return nil
+func (x *QueryBatchReadResponse) String() string {
+	return protoimpl.X.MessageStringOf(x)
 }
 
-func (x *CosmosRequest) GetInsertStorageCell() *QueryInsertStorageCell {
-	if x, ok := x.GetReq().(*CosmosRequest_InsertStorageCell); ok {
-		return x.InsertStorageCell
+func (*QueryBatchReadResponse) ProtoMessage() {}
+
+func (x *QueryBatchReadResponse) ProtoReflect() protoreflect.Message {
+	mi := &file_ffi_proto_msgTypes[32]
+	if protoimpl.UnsafeEnabled && x != nil {
+		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
+		if ms.LoadMessageInfo() == nil {
+			ms.StoreMessageInfo(mi)
+		}
+		return ms
 	}
-	return nil
+	return mi.MessageOf(x)
 }
 
-func (x *CosmosRequest) GetRemove() *QueryRemove {
-	if x, ok := x.GetReq().(*CosmosRequest_Remove); ok {
-		return x.Remove
-	}
-	return nil
+// Deprecated: Use QueryBatchReadResponse.ProtoReflect.Descriptor instead.
+func (*QueryBatchReadResponse) Descriptor() ([]byte, []int) {
+	return file_ffi_proto_rawDescGZIP(), []int{32}
 }
 
-func (x *CosmosRequest) GetRemoveStorageCell() *QueryRemoveStorageCell {
-	if x, ok := x.GetReq().(*CosmosRequest_RemoveStorageCell); ok {
-		return x.RemoveStorageCell
+func (x *QueryBatchReadResponse) GetAccounts() []*QueryGetAccountResponse {
+	if x != nil {
+		return x.Accounts
 	}
 	return nil
 }
 
-func (x *CosmosRequest) GetRemoveStorage() *QueryRemoveStorage {
-	if x, ok := x.GetReq().(*CosmosRequest_RemoveStorage); ok {
-		return x.RemoveStorage
+func (x *QueryBatchReadResponse) GetCodes() []*QueryGetAccountCodeResponse {
+	if x != nil {
+		return x.Codes
 	}
 	return nil
 }
 
-func (x *CosmosRequest) GetBlockHash() *QueryBlockHash {
-	if x, ok := x.GetReq().(*CosmosRequest_BlockHash); ok {
-		return x.BlockHash
+func (x *QueryBatchReadResponse) GetStorageCells() []*QueryGetAccountStorageCellResponse {
+	if x != nil {
+		return x.StorageCells
 	}
 	return nil
 }
 
-type isCosmosRequest_Req interface {
-	isCosmosRequest_Req()
-}
-
-type CosmosRequest_GetAccount struct {
-	GetAccount *QueryGetAccount `protobuf:"bytes,1,opt,name=getAccount,proto3,oneof"`
-}
-
-type CosmosRequest_InsertAccount struct {
-	InsertAccount *QueryInsertAccount `protobuf:"bytes,2,opt,name=insertAccount,proto3,oneof"`
-}
-
-type CosmosRequest_ContainsKey struct {
-	ContainsKey *QueryContainsKey `protobuf:"bytes,3,opt,name=containsKey,proto3,oneof"`
-}
-
-type CosmosRequest_AccountCode struct {
-	AccountCode *QueryGetAccountCode `protobuf:"bytes,4,opt,name=accountCode,proto3,oneof"`
-}
-
-type CosmosRequest_StorageCell struct {
-	StorageCell *QueryGetAccountStorageCell `protobuf:"bytes,5,opt,name=storageCell,proto3,oneof"`
-}
-
-type CosmosRequest_InsertAccountCode struct {
-	InsertAccountCode *QueryInsertAccountCode `protobuf:"bytes,6,opt,name=insertAccountCode,proto3,oneof"`
-}
-
-type CosmosRequest_InsertStorageCell struct {
-	InsertStorageCell *QueryInsertStorageCell `protobuf:"bytes,7,opt,name=insertStorageCell,proto3,oneof"`
-}
-
-type CosmosRequest_Remove struct {
-	Remove *QueryRemove `protobuf:"bytes,8,opt,name=remove,proto3,oneof"`
-}
-
-type CosmosRequest_RemoveStorageCell struct {
-	RemoveStorageCell *QueryRemoveStorageCell `protobuf:"bytes,9,opt,name=removeStorageCell,proto3,oneof"`
-}
-
-type CosmosRequest_RemoveStorage struct {
-	RemoveStorage *QueryRemoveStorage `protobuf:"bytes,10,opt,name=removeStorage,proto3,oneof"`
-}
-
-type CosmosRequest_BlockHash struct {
-	BlockHash *QueryBlockHash `protobuf:"bytes,11,opt,name=blockHash,proto3,oneof"`
-}
-
-func (*CosmosRequest_GetAccount) isCosmosRequest_Req() {}
-
-func (*CosmosRequest_InsertAccount) isCosmosRequest_Req() {}
-
-func (*CosmosRequest_ContainsKey) isCosmosRequest_Req() {}
-
-func (*CosmosRequest_AccountCode) isCosmosRequest_Req() {}
-
-func (*CosmosRequest_StorageCell) isCosmosRequest_Req() {}
-
-func (*CosmosRequest_InsertAccountCode) isCosmosRequest_Req() {}
-
-func (*CosmosRequest_InsertStorageCell) isCosmosRequest_Req() {}
-
-func (*CosmosRequest_Remove) isCosmosRequest_Req() {}
-
-func (*CosmosRequest_RemoveStorageCell) isCosmosRequest_Req() {}
-
-func (*CosmosRequest_RemoveStorage) isCosmosRequest_Req() {}
-
-func (*CosmosRequest_BlockHash) isCosmosRequest_Req() {}
-
-// Message with data required to execute `call` operation
-type SGXVMCallParams struct {
+// Request to apply all state changes of transaction at once. Changes should be
+// applied atomically in the following order: removed accounts, reset storages,
+// accounts, codes, storage cells
+type QueryCommit struct {
 	state         protoimpl.MessageState
 	sizeCache     protoimpl.SizeCache
 	unknownFields protoimpl.UnknownFields
 
-	From       []byte            `protobuf:"bytes,1,opt,name=from,proto3" json:"from,omitempty"`
-	To         []byte            `protobuf:"bytes,2,opt,name=to,proto3" json:"to,omitempty"`
-	Data       []byte            `protobuf:"bytes,3,opt,name=data,proto3" json:"data,omitempty"`
-	GasLimit   uint64            `protobuf:"varint,4,opt,name=gasLimit,proto3" json:"gasLimit,omitempty"`
-	Value      []byte            `protobuf:"bytes,5,opt,name=value,proto3" json:"value,omitempty"`
-	AccessList []*AccessListItem `protobuf:"bytes,6,rep,name=accessList,proto3" json:"accessList,omitempty"`
-	Commit     bool              `protobuf:"varint,7,opt,name=commit,proto3" json:"commit,omitempty"`
-	Nonce      uint64            `protobuf:"varint,8,opt,name=nonce,proto3" json:"nonce,omitempty"`
+	Removed      []*QueryRemove            `protobuf:"bytes,1,rep,name=removed,proto3" json:"removed,omitempty"`
+	ResetStorage []*QueryRemoveStorage     `protobuf:"bytes,2,rep,name=resetStorage,proto3" json:"resetStorage,omitempty"`
+	Accounts     []*QueryInsertAccount     `protobuf:"bytes,3,rep,name=accounts,proto3" json:"accounts,omitempty"`
+	Codes        []*QueryInsertAccountCode `protobuf:"bytes,4,rep,name=codes,proto3" json:"codes,omitempty"`
+	// storage cells with empty value should be removed
+	StorageCells []*QueryInsertStorageCell `protobuf:"bytes,5,rep,name=storageCells,proto3" json:"storageCells,omitempty"`
 }
 
-func (x *SGXVMCallParams) Reset() {
-	*x = SGXVMCallParams{}
+func (x *QueryCommit) Reset() {
+	*x = QueryCommit{}
 	if protoimpl.UnsafeEnabled {
-		mi := &file_ffi_proto_msgTypes[30]
+		mi := &file_ffi_proto_msgTypes[33]
 		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
 		ms.StoreMessageInfo(mi)
 	}
 }
 
-func (x *SGXVMCallParams) String() string {
+func (x *QueryCommit) String() string {
 	return protoimpl.X.MessageStringOf(x)
 }
 
-func (*SGXVMCallParams) ProtoMessage() {}
+func (*QueryCommit) ProtoMessage() {}
 
-func (x *SGXVMCallParams) ProtoReflect() protoreflect.Message {
-	mi := &file_ffi_proto_msgTypes[30]
+func (x *QueryCommit) ProtoReflect() protoreflect.Message {
+	mi := &file_ffi_proto_msgTypes[33]
 	if protoimpl.UnsafeEnabled && x != nil {
 		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
 		if ms.LoadMessageInfo() == nil {
@@ -1807,99 +1996,69 @@ func (x *SGXVMCallParams) ProtoReflect() protoreflect.Message {
 	return mi.MessageOf(x)
 }
 
-// Deprecated: Use SGXVMCallParams.ProtoReflect.Descriptor instead.
-func (*SGXVMCallParams) Descriptor() ([]byte, []int) {
-	return file_ffi_proto_rawDescGZIP(), []int{30}
+// Deprecated: Use QueryCommit.ProtoReflect.Descriptor instead.
+func (*QueryCommit) Descriptor() ([]byte, []int) {
+	return file_ffi_proto_rawDescGZIP(), []int{33}
 }
 
-func (x *SGXVMCallParams) GetFrom() []byte {
+func (x *QueryCommit) GetRemoved() []*QueryRemove {
 	if x != nil {
-		return x.From
+		return x.Removed
 	}
 	return nil
 }
 
-func (x *SGXVMCallParams) GetTo() []byte {
+func (x *QueryCommit) GetResetStorage() []*QueryRemoveStorage {
 	if x != nil {
-		return x.To
+		return x.ResetStorage
 	}
 	return nil
 }
 
-func (x *SGXVMCallParams) GetData() []byte {
+func (x *QueryCommit) GetAccounts() []*QueryInsertAccount {
 	if x != nil {
-		return x.Data
+		return x.Accounts
 	}
 	return nil
 }
 
-func (x *SGXVMCallParams) GetGasLimit() uint64 {
+func (x *QueryCommit) GetCodes() []*QueryInsertAccountCode {
 	if x != nil {
-		return x.GasLimit
+		return x.Codes
 	}
-	return 0
+	return nil
 }
 
-func (x *SGXVMCallParams) GetValue() []byte {
+func (x *QueryCommit) GetStorageCells() []*QueryInsertStorageCell {
 	if x != nil {
-		return x.Value
+		return x.StorageCells
 	}
 	return nil
 }
 
-func (x *SGXVMCallParams) GetAccessList() []*AccessListItem {
-	if x != nil {
-		return x.AccessList
-	}
-	return nil
-}
-
-func (x *SGXVMCallParams) GetCommit() bool {
-	if x != nil {
-		return x.Commit
-	}
-	return false
-}
-
-func (x *SGXVMCallParams) GetNonce() uint64 {
-	if x != nil {
-		return x.Nonce
-	}
-	return 0
-}
-
-// Message with data required to execute `create` operation
-type SGXVMCreateParams struct {
+type QueryCommitResponse struct {
 	state         protoimpl.MessageState
 	sizeCache     protoimpl.SizeCache
 	unknownFields protoimpl.UnknownFields
-
-	From       []byte            `protobuf:"bytes,1,opt,name=from,proto3" json:"from,omitempty"`
-	Data       []byte            `protobuf:"bytes,2,opt,name=data,proto3" json:"data,omitempty"`
-	GasLimit   uint64            `protobuf:"varint,3,opt,name=gasLimit,proto3" json:"gasLimit,omitempty"`
-	Value      []byte            `protobuf:"bytes,4,opt,name=value,proto3" json:"value,omitempty"`
-	AccessList []*AccessListItem `protobuf:"bytes,5,rep,name=accessList,proto3" json:"accessList,omitempty"`
-	Commit     bool              `protobuf:"varint,6,opt,name=commit,proto3" json:"commit,omitempty"`
-	Nonce      uint64            `protobuf:"varint,7,opt,name=nonce,proto3" json:"nonce,omitempty"`
 }
 
-func (x *SGXVMCreateParams) Reset() {
-	*x = SGXVMCreateParams{}
+func (x *QueryCommitResponse) Reset() {
+	*x = QueryCommitResponse{}
 	if protoimpl.UnsafeEnabled {
-		mi := &file_ffi_proto_msgTypes[31]
+		mi := &file_ffi_proto_msgTypes[34]
 		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
 		ms.StoreMessageInfo(mi)
 	}
 }
 
-func (x *SGXVMCreateParams) String() string {
+func (x *QueryCommitResponse) String() string {
 	return protoimpl.X.MessageStringOf(x)
 }
 
-func (*SGXVMCreateParams) ProtoMessage() {}
+func (*QueryCommitResponse) ProtoMessage() {}
 
-func (x *SGXVMCreateParams) ProtoReflect() protoreflect.Message {
-	mi := &file_ffi_proto_msgTypes[31]
+func (x *QueryCommitResponse) ProtoReflect() protoreflect.Message {
+	mi := &file_ffi_proto_msgTypes[34]
 	if protoimpl.UnsafeEnabled && x != nil {
 		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
 		if ms.LoadMessageInfo() == nil {
@@ -1910,87 +2069,39 @@ func (x *SGXVMCreateParams) ProtoReflect() protoreflect.Message {
 	return mi.MessageOf(x)
 }
 
-// Deprecated: Use SGXVMCreateParams.ProtoReflect.Descriptor instead.
-func (*SGXVMCreateParams) Descriptor() ([]byte, []int) {
-	return file_ffi_proto_rawDescGZIP(), []int{31}
-}
-
-func (x *SGXVMCreateParams) GetFrom() []byte {
-	if x != nil {
-		return x.From
-	}
-	return nil
-}
-
-func (x *SGXVMCreateParams) GetData() []byte {
-	if x != nil {
-		return x.Data
-	}
-	return nil
-}
-
-func (x *SGXVMCreateParams) GetGasLimit() uint64 {
-	if x != nil {
-		return x.GasLimit
-	}
-	return 0
-}
-
-func (x *SGXVMCreateParams) GetValue() []byte {
-	if x != nil {
-		return x.Value
-	}
-	return nil
-}
-
-func (x *SGXVMCreateParams) GetAccessList() []*AccessListItem {
-	if x != nil {
-		return x.AccessList
-	}
-	return nil
-}
-
-func (x *SGXVMCreateParams) GetCommit() bool {
-	if x != nil {
-		return x.Commit
-	}
-	return false
-}
-
-func (x *SGXVMCreateParams) GetNonce() uint64 {
-	if x != nil {
-		return x.Nonce
-	}
-	return 0
+// Deprecated: Use QueryCommitResponse.ProtoReflect.Descriptor instead.
+func (*QueryCommitResponse) Descriptor() ([]byte, []int) {
+	return file_ffi_proto_rawDescGZIP(), []int{34}
 }
 
-// Request to execute `call` operation
-type SGXVMCallRequest struct {
+// Request to obtain balance of Cosmos account in provided denomination from
+// bank module
+type QueryBankBalance struct {
 	state         protoimpl.MessageState
 	sizeCache     protoimpl.SizeCache
 	unknownFields protoimpl.UnknownFields
 
-	Params  *SGXVMCallParams    `protobuf:"bytes,1,opt,name=params,proto3" json:"params,omitempty"`
-	Context *TransactionContext `protobuf:"bytes,2,opt,name=context,proto3" json:"context,omitempty"`
+	Address []byte `protobuf:"bytes,1,opt,name=address,proto3" json:"address,omitempty"`
+	Denom   string `protobuf:"bytes,2,opt,name=denom,proto3" json:"denom,omitempty"`
 }
 
-func (x *SGXVMCallRequest) Reset() {
-	*x = SGXVMCallRequest{}
+func (x *QueryBankBalance) Reset() {
+	*x = QueryBankBalance{}
 	if protoimpl.UnsafeEnabled {
-		mi := &file_ffi_proto_msgTypes[32]
+		mi := &file_ffi_proto_msgTypes[35]
 		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
 		ms.StoreMessageInfo(mi)
 	}
 }
 
-func (x *SGXVMCallRequest) String() string {
+func (x *QueryBankBalance) String() string {
 	return protoimpl.X.MessageStringOf(x)
 }
 
-func (*SGXVMCallRequest) ProtoMessage() {}
+func (*QueryBankBalance) ProtoMessage() {}
 
-func (x *SGXVMCallRequest) ProtoReflect() protoreflect.Message {
-	mi := &file_ffi_proto_msgTypes[32]
+func (x *QueryBankBalance) ProtoReflect() protoreflect.Message {
+	mi := &file_ffi_proto_msgTypes[35]
 	if protoimpl.UnsafeEnabled && x != nil {
 		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
 		if ms.LoadMessageInfo() == nil {
@@ -2001,52 +2112,50 @@ func (x *SGXVMCallRequest) ProtoReflect() protoreflect.Message {
 	return mi.MessageOf(x)
 }
 
-// Deprecated: Use SGXVMCallRequest.ProtoReflect.Descriptor instead.
-func (*SGXVMCallRequest) Descriptor() ([]byte, []int) {
-	return file_ffi_proto_rawDescGZIP(), []int{32}
+// Deprecated: Use QueryBankBalance.ProtoReflect.Descriptor instead.
+func (*QueryBankBalance) Descriptor() ([]byte, []int) {
+	return file_ffi_proto_rawDescGZIP(), []int{35}
 }
 
-func (x *SGXVMCallRequest) GetParams() *SGXVMCallParams {
+func (x *QueryBankBalance) GetAddress() []byte {
 	if x != nil {
-		return x.Params
+		return x.Address
 	}
 	return nil
 }
 
-func (x *SGXVMCallRequest) GetContext() *TransactionContext {
+func (x *QueryBankBalance) GetDenom() string {
 	if x != nil {
-		return x.Context
+		return x.Denom
 	}
-	return nil
+	return ""
 }
 
-// Request to execute `create` operation
-type SGXVMCreateRequest struct {
+type QueryBankBalanceResponse struct {
 	state         protoimpl.MessageState
 	sizeCache     protoimpl.SizeCache
 	unknownFields protoimpl.UnknownFields
 
-	Params  *SGXVMCreateParams  `protobuf:"bytes,1,opt,name=params,proto3" json:"params,omitempty"`
-	Context *TransactionContext `protobuf:"bytes,2,opt,name=context,proto3" json:"context,omitempty"`
+	Balance []byte `protobuf:"bytes,1,opt,name=balance,proto3" json:"balance,omitempty"`
 }
 
-func (x *SGXVMCreateRequest) Reset() {
-	*x = SGXVMCreateRequest{}
+func (x *QueryBankBalanceResponse) Reset() {
+	*x = QueryBankBalanceResponse{}
 	if protoimpl.UnsafeEnabled {
-		mi := &file_ffi_proto_msgTypes[33]
+		mi := &file_ffi_proto_msgTypes[36]
 		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
 		ms.StoreMessageInfo(mi)
 	}
 }
 
-func (x *SGXVMCreateRequest) String() string {
+func (x *QueryBankBalanceResponse) String() string {
 	return protoimpl.X.MessageStringOf(x)
 }
 
-func (*SGXVMCreateRequest) ProtoMessage() {}
+func (*QueryBankBalanceResponse) ProtoMessage() {}
 
-func (x *SGXVMCreateRequest) ProtoReflect() protoreflect.Message {
-	mi := &file_ffi_proto_msgTypes[33]
+func (x *QueryBankBalanceResponse) ProtoReflect() protoreflect.Message {
+	mi := &file_ffi_proto_msgTypes[36]
 	if protoimpl.UnsafeEnabled && x != nil {
 		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
 		if ms.LoadMessageInfo() == nil {
@@ -2057,49 +2166,58 @@ func (x *SGXVMCreateRequest) ProtoReflect() protoreflect.Message {
 	return mi.MessageOf(x)
 }
 
-// Deprecated: Use SGXVMCreateRequest.ProtoReflect.Descriptor instead.
-func (*SGXVMCreateRequest) Descriptor() ([]byte, []int) {
-	return file_ffi_proto_rawDescGZIP(), []int{33}
-}
-
-func (x *SGXVMCreateRequest) GetParams() *SGXVMCreateParams {
-	if x != nil {
-		return x.Params
-	}
-	return nil
+// Deprecated: Use QueryBankBalanceResponse.ProtoReflect.Descriptor instead.
+func (*QueryBankBalanceResponse) Descriptor() ([]byte, []int) {
+	return file_ffi_proto_rawDescGZIP(), []int{36}
 }
 
-func (x *SGXVMCreateRequest) GetContext() *TransactionContext {
+func (x *QueryBankBalanceResponse) GetBalance() []byte {
 	if x != nil {
-		return x.Context
+		return x.Balance
 	}
 	return nil
 }
 
-// Request to obtain node public key
-type NodePublicKeyRequest struct {
+type CosmosRequest struct {
 	state         protoimpl.MessageState
 	sizeCache     protoimpl.SizeCache
 	unknownFields protoimpl.UnknownFields
+
+	// Types that are assignable to Req:
+	//	*CosmosRequest_GetAccount
+	//	*CosmosRequest_InsertAccount
+	//	*CosmosRequest_ContainsKey
+	//	*CosmosRequest_AccountCode
+	//	*CosmosRequest_StorageCell
+	//	*CosmosRequest_InsertAccountCode
+	//	*CosmosRequest_InsertStorageCell
+	//	*CosmosRequest_Remove
+	//	*CosmosRequest_RemoveStorageCell
+	//	*CosmosRequest_RemoveStorage
+	//	*CosmosRequest_BlockHash
+	//	*CosmosRequest_BatchRead
+	//	*CosmosRequest_Commit
+	//	*CosmosRequest_BankBalance
+	Req isCosmosRequest_Req `protobuf_oneof:"req"`
 }
 
-func (x *NodePublicKeyRequest) Reset() {
-	*x = NodePublicKeyRequest{}
+func (x *CosmosRequest) Reset() {
+	*x = CosmosRequest{}
 	if protoimpl.UnsafeEnabled {
-		mi := &file_ffi_proto_msgTypes[34]
+		mi := &file_ffi_proto_msgTypes[37]
 		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
 		ms.StoreMessageInfo(mi)
 	}
 }
 
-func (x *NodePublicKeyRequest) String() string {
+func (x *CosmosRequest) String() string {
 	return protoimpl.X.MessageStringOf(x)
 }
 
-func (*NodePublicKeyRequest) ProtoMessage() {}
+func (*CosmosRequest) ProtoMessage() {}
 
-func (x *NodePublicKeyRequest) ProtoReflect() protoreflect.Message {
-	mi := &file_ffi_proto_msgTypes[34]
+func (x *CosmosRequest) ProtoReflect() protoreflect.Message {
+	mi := &file_ffi_proto_msgTypes[37]
 	if protoimpl.UnsafeEnabled && x != nil {
 		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
 		if ms.LoadMessageInfo() == nil {
@@ -2110,495 +2228,3637 @@ func (x *NodePublicKeyRequest) ProtoReflect() protoreflect.Message {
 	return mi.MessageOf(x)
 }
 
-// Deprecated: Use NodePublicKeyRequest.ProtoReflect.Descriptor instead.
-func (*NodePublicKeyRequest) Descriptor() ([]byte, []int) {
-	return file_ffi_proto_rawDescGZIP(), []int{34}
-}
-
-// Response with node public key
-type NodePublicKeyResponse struct {
-	state         protoimpl.MessageState
-	sizeCache     protoimpl.SizeCache
-	unknownFields protoimpl.UnknownFields
-
-	PublicKey []byte `protobuf:"bytes,1,opt,name=publicKey,proto3" json:"publicKey,omitempty"`
+// Deprecated: Use CosmosRequest.ProtoReflect.Descriptor instead.
+func (*CosmosRequest) Descriptor() ([]byte, []int) {
+	return file_ffi_proto_rawDescGZIP(), []int{37}
 }
 
-func (x *NodePublicKeyResponse) Reset() {
-	*x = NodePublicKeyResponse{}
-	if protoimpl.UnsafeEnabled {
-		mi := &file_ffi_proto_msgTypes[35]
-		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
-		ms.StoreMessageInfo(mi)
+func (m *CosmosRequest) GetReq() isCosmosRequest_Req {
+	if m != nil {
+		return m.Req
 	}
+	return nil
 }
 
-func (x *NodePublicKeyResponse) String() string {
-	return protoimpl.X.MessageStringOf(x)
+func (x *CosmosRequest) GetGetAccount() *QueryGetAccount {
+	if x, ok := x.GetReq().(*CosmosRequest_GetAccount); ok {
+		return x.GetAccount
+	}
+	return nil
 }
 
-func (*NodePublicKeyResponse) ProtoMessage() {}
-
-func (x *NodePublicKeyResponse) ProtoReflect() protoreflect.Message {
-	mi := &file_ffi_proto_msgTypes[35]
-	if protoimpl.UnsafeEnabled && x != nil {
-		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
-		if ms.LoadMessageInfo() == nil {
-			ms.StoreMessageInfo(mi)
-		}
-		return ms
+func (x *CosmosRequest) GetInsertAccount() *QueryInsertAccount {
+	if x, ok := x.GetReq().(*CosmosRequest_InsertAccount); ok {
+		return x.InsertAccount
 	}
-	return mi.MessageOf(x)
+	return nil
 }
 
-// Deprecated: Use NodePublicKeyResponse.ProtoReflect.Descriptor instead.
-func (*NodePublicKeyResponse) Descriptor() ([]byte, []int) {
-	return file_ffi_proto_rawDescGZIP(), []int{35}
+func (x *CosmosRequest) GetContainsKey() *QueryContainsKey {
+	if x, ok := x.GetReq().(*CosmosRequest_ContainsKey); ok {
+		return x.ContainsKey
+	}
+	return nil
 }
 
-func (x *NodePublicKeyResponse) GetPublicKey() []byte {
-	if x != nil {
-		return x.PublicKey
+func (x *CosmosRequest) GetAccountCode() *QueryGetAccountCode {
+	if x, ok := x.GetReq().(*CosmosRequest_AccountCode); ok {
+		return x.AccountCode
 	}
 	return nil
 }
 
-type FFIRequest struct {
-	state         protoimpl.MessageState
-	sizeCache     protoimpl.SizeCache
-	unknownFields protoimpl.UnknownFields
-
-	// Types that are assignable to Req:
-	//	*FFIRequest_CallRequest
-	//	*FFIRequest_CreateRequest
-	//	*FFIRequest_PublicKeyRequest
-	Req isFFIRequest_Req `protobuf_oneof:"req"`
+func (x *CosmosRequest) GetStorageCell() *QueryGetAccountStorageCell {
+	if x, ok := x.GetReq().(*CosmosRequest_StorageCell); ok {
+		return x.StorageCell
+	}
+	return nil
 }
 
-func (x *FFIRequest) Reset() {
-	*x = FFIRequest{}
-	if protoimpl.UnsafeEnabled {
-		mi := &file_ffi_proto_msgTypes[36]
-		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
-		ms.StoreMessageInfo(mi)
+func (x *CosmosRequest) GetInsertAccountCode() *QueryInsertAccountCode {
+	if x, ok := x.GetReq().(*CosmosRequest_InsertAccountCode); ok {
+		return x.InsertAccountCode
 	}
+	return nil
 }
 
-func (x *FFIRequest) String() string {
-	return protoimpl.X.MessageStringOf(x)
+func (x *CosmosRequest) GetInsertStorageCell() *QueryInsertStorageCell {
+	if x, ok := x.GetReq().(*CosmosRequest_InsertStorageCell); ok {
+		return x.InsertStorageCell
+	}
+	return nil
 }
 
-func (*FFIRequest) ProtoMessage() {}
+func (x *CosmosRequest) GetRemove() *QueryRemove {
+	if x, ok := x.GetReq().(*CosmosRequest_Remove); ok {
+		return x.Remove
+	}
+	return nil
+}
 
-func (x *FFIRequest) ProtoReflect() protoreflect.Message {
-	mi := &file_ffi_proto_msgTypes[36]
+func (x *CosmosRequest) GetRemoveStorageCell() *QueryRemoveStorageCell {
+	if x, ok := x.GetReq().(*CosmosRequest_RemoveStorageCell); ok {
+		return x.RemoveStorageCell
+	}
+	return nil
+}
+
+func (x *CosmosRequest) GetRemoveStorage() *QueryRemoveStorage {
+	if x, ok := x.GetReq().(*CosmosRequest_RemoveStorage); ok {
+		return x.RemoveStorage
+	}
+	return nil
+}
+
+func (x *CosmosRequest) GetBlockHash() *QueryBlockHash {
+	if x, ok := x.GetReq().(*CosmosRequest_BlockHash); ok {
+		return x.BlockHash
+	}
+	return nil
+}
+
+func (x *CosmosRequest) GetBatchRead() *QueryBatchRead {
+	if x, ok := x.GetReq().(*CosmosRequest_BatchRead); ok {
+		return x.BatchRead
+	}
+	return nil
+}
+
+func (x *CosmosRequest) GetCommit() *QueryCommit {
+	if x, ok := x.GetReq().(*CosmosRequest_Commit); ok {
+		return x.Commit
+	}
+	return nil
+}
+
+func (x *CosmosRequest) GetBankBalance() *QueryBankBalance {
+	if x, ok := x.GetReq().(*CosmosRequest_BankBalance); ok {
+		return x.BankBalance
+	}
+	return nil
+}
+
+type isCosmosRequest_Req interface {
+	isCosmosRequest_Req()
+}
+
+type CosmosRequest_GetAccount struct {
+	GetAccount *QueryGetAccount `protobuf:"bytes,1,opt,name=getAccount,proto3,oneof"`
+}
+
+type CosmosRequest_InsertAccount struct {
+	InsertAccount *QueryInsertAccount `protobuf:"bytes,2,opt,name=insertAccount,proto3,oneof"`
+}
+
+type CosmosRequest_ContainsKey struct {
+	ContainsKey *QueryContainsKey `protobuf:"bytes,3,opt,name=containsKey,proto3,oneof"`
+}
+
+type CosmosRequest_AccountCode struct {
+	AccountCode *QueryGetAccountCode `protobuf:"bytes,4,opt,name=accountCode,proto3,oneof"`
+}
+
+type CosmosRequest_StorageCell struct {
+	StorageCell *QueryGetAccountStorageCell `protobuf:"bytes,5,opt,name=storageCell,proto3,oneof"`
+}
+
+type CosmosRequest_InsertAccountCode struct {
+	InsertAccountCode *QueryInsertAccountCode `protobuf:"bytes,6,opt,name=insertAccountCode,proto3,oneof"`
+}
+
+type CosmosRequest_InsertStorageCell struct {
+	InsertStorageCell *QueryInsertStorageCell `protobuf:"bytes,7,opt,name=insertStorageCell,proto3,oneof"`
+}
+
+type CosmosRequest_Remove struct {
+	Remove *QueryRemove `protobuf:"bytes,8,opt,name=remove,proto3,oneof"`
+}
+
+type CosmosRequest_RemoveStorageCell struct {
+	RemoveStorageCell *QueryRemoveStorageCell `protobuf:"bytes,9,opt,name=removeStorageCell,proto3,oneof"`
+}
+
+type CosmosRequest_RemoveStorage struct {
+	RemoveStorage *QueryRemoveStorage `protobuf:"bytes,10,opt,name=removeStorage,proto3,oneof"`
+}
+
+type CosmosRequest_BlockHash struct {
+	BlockHash *QueryBlockHash `protobuf:"bytes,11,opt,name=blockHash,proto3,oneof"`
+}
+
+type CosmosRequest_BatchRead struct {
+	BatchRead *QueryBatchRead `protobuf:"bytes,12,opt,name=batchRead,proto3,oneof"`
+}
+
+type CosmosRequest_Commit struct {
+	Commit *QueryCommit `protobuf:"bytes,13,opt,name=commit,proto3,oneof"`
+}
+
+type CosmosRequest_BankBalance struct {
+	BankBalance *QueryBankBalance `protobuf:"bytes,14,opt,name=bankBalance,proto3,oneof"`
+}
+
+func (*CosmosRequest_GetAccount) isCosmosRequest_Req() {}
+
+func (*CosmosRequest_InsertAccount) isCosmosRequest_Req() {}
+
+func (*CosmosRequest_ContainsKey) isCosmosRequest_Req() {}
+
+func (*CosmosRequest_AccountCode) isCosmosRequest_Req() {}
+
+func (*CosmosRequest_StorageCell) isCosmosRequest_Req() {}
+
+func (*CosmosRequest_InsertAccountCode) isCosmosRequest_Req() {}
+
+func (*CosmosRequest_InsertStorageCell) isCosmosRequest_Req() {}
+
+func (*CosmosRequest_Remove) isCosmosRequest_Req() {}
+
+func (*CosmosRequest_RemoveStorageCell) isCosmosRequest_Req() {}
+
+func (*CosmosRequest_RemoveStorage) isCosmosRequest_Req() {}
+
+func (*CosmosRequest_BlockHash) isCosmosRequest_Req() {}
+
+func (*CosmosRequest_BatchRead) isCosmosRequest_Req() {}
+
+func (*CosmosRequest_Commit) isCosmosRequest_Req() {}
+
+func (*CosmosRequest_BankBalance) isCosmosRequest_Req() {}
+
+// Message with data required to execute `call` operation
+type SGXVMCallParams struct {
+	state         protoimpl.MessageState
+	sizeCache     protoimpl.SizeCache
+	unknownFields protoimpl.UnknownFields
+
+	From       []byte            `protobuf:"bytes,1,opt,name=from,proto3" json:"from,omitempty"`
+	To         []byte            `protobuf:"bytes,2,opt,name=to,proto3" json:"to,omitempty"`
+	Data       []byte            `protobuf:"bytes,3,opt,name=data,proto3" json:"data,omitempty"`
+	GasLimit   uint64            `protobuf:"varint,4,opt,name=gasLimit,proto3" json:"gasLimit,omitempty"`
+	Value      []byte            `protobuf:"bytes,5,opt,name=value,proto3" json:"value,omitempty"`
+	AccessList []*AccessListItem `protobuf:"bytes,6,rep,name=accessList,proto3" json:"accessList,omitempty"`
+	Commit     bool              `protobuf:"varint,7,opt,name=commit,proto3" json:"commit,omitempty"`
+	// nonce of transaction. If `commit` is set, it should be equal to nonce of
+	// sender account. Nonce is incremented inside the enclave for both successful
+	// and failed transactions, so Go side should not increment it
+	Nonce uint64 `protobuf:"varint,8,opt,name=nonce,proto3" json:"nonce,omitempty"`
+	// type of transaction used for receipt encoding: 0 - legacy, 1 - EIP-2930,
+	// 2 - EIP-1559. Transactions of other types are rejected
+	TxType uint32 `protobuf:"varint,9,opt,name=txType,proto3" json:"txType,omitempty"`
+}
+
+func (x *SGXVMCallParams) Reset() {
+	*x = SGXVMCallParams{}
+	if protoimpl.UnsafeEnabled {
+		mi := &file_ffi_proto_msgTypes[38]
+		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
+		ms.StoreMessageInfo(mi)
+	}
+}
+
+func (x *SGXVMCallParams) String() string {
+	return protoimpl.X.MessageStringOf(x)
+}
+
+func (*SGXVMCallParams) ProtoMessage() {}
+
+func (x *SGXVMCallParams) ProtoReflect() protoreflect.Message {
+	mi := &file_ffi_proto_msgTypes[38]
 	if protoimpl.UnsafeEnabled && x != nil {
 		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
 		if ms.LoadMessageInfo() == nil {
 			ms.StoreMessageInfo(mi)
 		}
-		return ms
-	}
-	return mi.MessageOf(x)
-}
-
-// Deprecated: Use FFIRequest.ProtoReflect.Descriptor instead.
-func (*FFIRequest) Descriptor() ([]byte, []int) {
-	return file_ffi_proto_rawDescGZIP(), []int{36}
-}
-
-func (m *FFIRequest) GetReq() isFFIRequest_Req {
-	if m != nil {
-		return m.Req
-	}
-	return nil
-}
-
-func (x *FFIRequest) GetCallRequest() *SGXVMCallRequest {
-	if x, ok := x.GetReq().(*FFIRequest_CallRequest); ok {
-		return x.CallRequest
-	}
-	return nil
-}
-
-func (x *FFIRequest) GetCreateRequest() *SGXVMCreateRequest {
-	if x, ok := x.GetReq().(*FFIRequest_CreateRequest); ok {
-		return x.CreateRequest
-	}
-	return nil
-}
-
-func (x *FFIRequest) GetPublicKeyRequest() *NodePublicKeyRequest {
-	if x, ok := x.GetReq().(*FFIRequest_PublicKeyRequest); ok {
-		return x.PublicKeyRequest
-	}
-	return nil
-}
-
-type isFFIRequest_Req interface {
-	isFFIRequest_Req()
-}
-
-type FFIRequest_CallRequest struct {
-	CallRequest *SGXVMCallRequest `protobuf:"bytes,1,opt,name=callRequest,proto3,oneof"`
-}
-
-type FFIRequest_CreateRequest struct {
-	CreateRequest *SGXVMCreateRequest `protobuf:"bytes,2,opt,name=createRequest,proto3,oneof"`
-}
-
-type FFIRequest_PublicKeyRequest struct {
-	PublicKeyRequest *NodePublicKeyRequest `protobuf:"bytes,3,opt,name=publicKeyRequest,proto3,oneof"`
-}
-
-func (*FFIRequest_CallRequest) isFFIRequest_Req() {}
-
-func (*FFIRequest_CreateRequest) isFFIRequest_Req() {}
-
-func (*FFIRequest_PublicKeyRequest) isFFIRequest_Req() {}
-
-var File_ffi_proto protoreflect.FileDescriptor
-
-var file_ffi_proto_rawDesc = []byte{
-	0x0a, 0x09, 0x66, 0x66, 0x69, 0x2e, 0x70, 0x72, 0x6f, 0x74, 0x6f, 0x12, 0x07, 0x66, 0x66, 0x69,
-	0x2e, 0x66, 0x66, 0x69, 0x22, 0x4c, 0x0a, 0x0e, 0x41, 0x63, 0x63, 0x65, 0x73, 0x73, 0x4c, 0x69,
-	0x73, 0x74, 0x49, 0x74, 0x65, 0x6d, 0x12, 0x20, 0x0a, 0x0b, 0x73, 0x74, 0x6f, 0x72, 0x61, 0x67,
-	0x65, 0x53, 0x6c, 0x6f, 0x74, 0x18, 0x01, 0x20, 0x03, 0x28, 0x0c, 0x52, 0x0b, 0x73, 0x74, 0x6f,
-	0x72, 0x61, 0x67, 0x65, 0x53, 0x6c, 0x6f, 0x74, 0x12, 0x18, 0x0a, 0x07, 0x61, 0x64, 0x64, 0x72,
-	0x65, 0x73, 0x73, 0x18, 0x02, 0x20, 0x01, 0x28, 0x0c, 0x52, 0x07, 0x61, 0x64, 0x64, 0x72, 0x65,
-	0x73, 0x73, 0x22, 0xb4, 0x01, 0x0a, 0x0f, 0x54, 0x72, 0x61, 0x6e, 0x73, 0x61, 0x63, 0x74, 0x69,
-	0x6f, 0x6e, 0x44, 0x61, 0x74, 0x61, 0x12, 0x12, 0x0a, 0x04, 0x66, 0x72, 0x6f, 0x6d, 0x18, 0x01,
-	0x20, 0x01, 0x28, 0x0c, 0x52, 0x04, 0x66, 0x72, 0x6f, 0x6d, 0x12, 0x0e, 0x0a, 0x02, 0x74, 0x6f,
-	0x18, 0x02, 0x20, 0x01, 0x28, 0x0c, 0x52, 0x02, 0x74, 0x6f, 0x12, 0x12, 0x0a, 0x04, 0x64, 0x61,
-	0x74, 0x61, 0x18, 0x03, 0x20, 0x01, 0x28, 0x0c, 0x52, 0x04, 0x64, 0x61, 0x74, 0x61, 0x12, 0x1a,
-	0x0a, 0x08, 0x67, 0x61, 0x73, 0x4c, 0x69, 0x6d, 0x69, 0x74, 0x18, 0x04, 0x20, 0x01, 0x28, 0x04,
-	0x52, 0x08, 0x67, 0x61, 0x73, 0x4c, 0x69, 0x6d, 0x69, 0x74, 0x12, 0x14, 0x0a, 0x05, 0x76, 0x61,
-	0x6c, 0x75, 0x65, 0x18, 0x05, 0x20, 0x01, 0x28, 0x0c, 0x52, 0x05, 0x76, 0x61, 0x6c, 0x75, 0x65,
-	0x12, 0x37, 0x0a, 0x0a, 0x61, 0x63, 0x63, 0x65, 0x73, 0x73, 0x4c, 0x69, 0x73, 0x74, 0x18, 0x06,
-	0x20, 0x03, 0x28, 0x0b, 0x32, 0x17, 0x2e, 0x66, 0x66, 0x69, 0x2e, 0x66, 0x66, 0x69, 0x2e, 0x41,
-	0x63, 0x63, 0x65, 0x73, 0x73, 0x4c, 0x69, 0x73, 0x74, 0x49, 0x74, 0x65, 0x6d, 0x52, 0x0a, 0x61,
-	0x63, 0x63, 0x65, 0x73, 0x73, 0x4c, 0x69, 0x73, 0x74, 0x22, 0x90, 0x02, 0x0a, 0x12, 0x54, 0x72,
-	0x61, 0x6e, 0x73, 0x61, 0x63, 0x74, 0x69, 0x6f, 0x6e, 0x43, 0x6f, 0x6e, 0x74, 0x65, 0x78, 0x74,
-	0x12, 0x19, 0x0a, 0x08, 0x63, 0x68, 0x61, 0x69, 0x6e, 0x5f, 0x69, 0x64, 0x18, 0x01, 0x20, 0x01,
-	0x28, 0x04, 0x52, 0x07, 0x63, 0x68, 0x61, 0x69, 0x6e, 0x49, 0x64, 0x12, 0x1b, 0x0a, 0x09, 0x67,
-	0x61, 0x73, 0x5f, 0x70, 0x72, 0x69, 0x63, 0x65, 0x18, 0x02, 0x20, 0x01, 0x28, 0x0c, 0x52, 0x08,
-	0x67, 0x61, 0x73, 0x50, 0x72, 0x69, 0x63, 0x65, 0x12, 0x1c, 0x0a, 0x09, 0x74, 0x69, 0x6d, 0x65,
-	0x73, 0x74, 0x61, 0x6d, 0x70, 0x18, 0x03, 0x20, 0x01, 0x28, 0x04, 0x52, 0x09, 0x74, 0x69, 0x6d,
-	0x65, 0x73, 0x74, 0x61, 0x6d, 0x70, 0x12, 0x26, 0x0a, 0x0f, 0x62, 0x6c, 0x6f, 0x63, 0x6b, 0x5f,
-	0x67, 0x61, 0x73, 0x5f, 0x6c, 0x69, 0x6d, 0x69, 0x74, 0x18, 0x04, 0x20, 0x01, 0x28, 0x04, 0x52,
-	0x0d, 0x62, 0x6c, 0x6f, 0x63, 0x6b, 0x47, 0x61, 0x73, 0x4c, 0x69, 0x6d, 0x69, 0x74, 0x12, 0x32,
-	0x0a, 0x16, 0x62, 0x6c, 0x6f, 0x63, 0x6b, 0x5f, 0x62, 0x61, 0x73, 0x65, 0x5f, 0x66, 0x65, 0x65,
-	0x5f, 0x70, 0x65, 0x72, 0x5f, 0x67, 0x61, 0x73, 0x18, 0x05, 0x20, 0x01, 0x28, 0x0c, 0x52, 0x12,
-	0x62, 0x6c, 0x6f, 0x63, 0x6b, 0x42, 0x61, 0x73, 0x65, 0x46, 0x65, 0x65, 0x50, 0x65, 0x72, 0x47,
-	0x61, 0x73, 0x12, 0x25, 0x0a, 0x0e, 0x62, 0x6c, 0x6f, 0x63, 0x6b, 0x5f, 0x63, 0x6f, 0x69, 0x6e,
-	0x62, 0x61, 0x73, 0x65, 0x18, 0x06, 0x20, 0x01, 0x28, 0x0c, 0x52, 0x0d, 0x62, 0x6c, 0x6f, 0x63,
-	0x6b, 0x43, 0x6f, 0x69, 0x6e, 0x62, 0x61, 0x73, 0x65, 0x12, 0x21, 0x0a, 0x0c, 0x62, 0x6c, 0x6f,
-	0x63, 0x6b, 0x5f, 0x6e, 0x75, 0x6d, 0x62, 0x65, 0x72, 0x18, 0x07, 0x20, 0x01, 0x28, 0x04, 0x52,
-	0x0b, 0x62, 0x6c, 0x6f, 0x63, 0x6b, 0x4e, 0x75, 0x6d, 0x62, 0x65, 0x72, 0x22, 0x89, 0x01, 0x0a,
-	0x18, 0x48, 0x61, 0x6e, 0x64, 0x6c, 0x65, 0x54, 0x72, 0x61, 0x6e, 0x73, 0x61, 0x63, 0x74, 0x69,
-	0x6f, 0x6e, 0x52, 0x65, 0x71, 0x75, 0x65, 0x73, 0x74, 0x12, 0x31, 0x0a, 0x07, 0x74, 0x78, 0x5f,
-	0x64, 0x61, 0x74, 0x61, 0x18, 0x01, 0x20, 0x01, 0x28, 0x0b, 0x32, 0x18, 0x2e, 0x66, 0x66, 0x69,
-	0x2e, 0x66, 0x66, 0x69, 0x2e, 0x54, 0x72, 0x61, 0x6e, 0x73, 0x61, 0x63, 0x74, 0x69, 0x6f, 0x6e,
-	0x44, 0x61, 0x74, 0x61, 0x52, 0x06, 0x74, 0x78, 0x44, 0x61, 0x74, 0x61, 0x12, 0x3a, 0x0a, 0x0a,
-	0x74, 0x78, 0x5f, 0x63, 0x6f, 0x6e, 0x74, 0x65, 0x78, 0x74, 0x18, 0x02, 0x20, 0x01, 0x28, 0x0b,
-	0x32, 0x1b, 0x2e, 0x66, 0x66, 0x69, 0x2e, 0x66, 0x66, 0x69, 0x2e, 0x54, 0x72, 0x61, 0x6e, 0x73,
-	0x61, 0x63, 0x74, 0x69, 0x6f, 0x6e, 0x43, 0x6f, 0x6e, 0x74, 0x65, 0x78, 0x74, 0x52, 0x09, 0x74,
-	0x78, 0x43, 0x6f, 0x6e, 0x74, 0x65, 0x78, 0x74, 0x22, 0x85, 0x01, 0x0a, 0x19, 0x48, 0x61, 0x6e,
-	0x64, 0x6c, 0x65, 0x54, 0x72, 0x61, 0x6e, 0x73, 0x61, 0x63, 0x74, 0x69, 0x6f, 0x6e, 0x52, 0x65,
-	0x73, 0x70, 0x6f, 0x6e, 0x73, 0x65, 0x12, 0x20, 0x0a, 0x04, 0x6c, 0x6f, 0x67, 0x73, 0x18, 0x02,
-	0x20, 0x03, 0x28, 0x0b, 0x32, 0x0c, 0x2e, 0x66, 0x66, 0x69, 0x2e, 0x66, 0x66, 0x69, 0x2e, 0x4c,
-	0x6f, 0x67, 0x52, 0x04, 0x6c, 0x6f, 0x67, 0x73, 0x12, 0x10, 0x0a, 0x03, 0x72, 0x65, 0x74, 0x18,
-	0x03, 0x20, 0x01, 0x28, 0x0c, 0x52, 0x03, 0x72, 0x65, 0x74, 0x12, 0x19, 0x0a, 0x08, 0x76, 0x6d,
-	0x5f, 0x65, 0x72, 0x72, 0x6f, 0x72, 0x18, 0x04, 0x20, 0x01, 0x28, 0x09, 0x52, 0x07, 0x76, 0x6d,
-	0x45, 0x72, 0x72, 0x6f, 0x72, 0x12, 0x19, 0x0a, 0x08, 0x67, 0x61, 0x73, 0x5f, 0x75, 0x73, 0x65,
-	0x64, 0x18, 0x05, 0x20, 0x01, 0x28, 0x04, 0x52, 0x07, 0x67, 0x61, 0x73, 0x55, 0x73, 0x65, 0x64,
-	0x22, 0x1d, 0x0a, 0x05, 0x54, 0x6f, 0x70, 0x69, 0x63, 0x12, 0x14, 0x0a, 0x05, 0x69, 0x6e, 0x6e,
-	0x65, 0x72, 0x18, 0x01, 0x20, 0x01, 0x28, 0x0c, 0x52, 0x05, 0x69, 0x6e, 0x6e, 0x65, 0x72, 0x22,
-	0x5b, 0x0a, 0x03, 0x4c, 0x6f, 0x67, 0x12, 0x18, 0x0a, 0x07, 0x61, 0x64, 0x64, 0x72, 0x65, 0x73,
-	0x73, 0x18, 0x01, 0x20, 0x01, 0x28, 0x0c, 0x52, 0x07, 0x61, 0x64, 0x64, 0x72, 0x65, 0x73, 0x73,
-	0x12, 0x26, 0x0a, 0x06, 0x74, 0x6f, 0x70, 0x69, 0x63, 0x73, 0x18, 0x02, 0x20, 0x03, 0x28, 0x0b,
-	0x32, 0x0e, 0x2e, 0x66, 0x66, 0x69, 0x2e, 0x66, 0x66, 0x69, 0x2e, 0x54, 0x6f, 0x70, 0x69, 0x63,
-	0x52, 0x06, 0x74, 0x6f, 0x70, 0x69, 0x63, 0x73, 0x12, 0x12, 0x0a, 0x04, 0x64, 0x61, 0x74, 0x61,
-	0x18, 0x03, 0x20, 0x01, 0x28, 0x0c, 0x52, 0x04, 0x64, 0x61, 0x74, 0x61, 0x22, 0x2b, 0x0a, 0x0f,
-	0x51, 0x75, 0x65, 0x72, 0x79, 0x47, 0x65, 0x74, 0x41, 0x63, 0x63, 0x6f, 0x75, 0x6e, 0x74, 0x12,
-	0x18, 0x0a, 0x07, 0x61, 0x64, 0x64, 0x72, 0x65, 0x73, 0x73, 0x18, 0x01, 0x20, 0x01, 0x28, 0x0c,
-	0x52, 0x07, 0x61, 0x64, 0x64, 0x72, 0x65, 0x73, 0x73, 0x22, 0x49, 0x0a, 0x17, 0x51, 0x75, 0x65,
-	0x72, 0x79, 0x47, 0x65, 0x74, 0x41, 0x63, 0x63, 0x6f, 0x75, 0x6e, 0x74, 0x52, 0x65, 0x73, 0x70,
-	0x6f, 0x6e, 0x73, 0x65, 0x12, 0x18, 0x0a, 0x07, 0x62, 0x61, 0x6c, 0x61, 0x6e, 0x63, 0x65, 0x18,
-	0x01, 0x20, 0x01, 0x28, 0x0c, 0x52, 0x07, 0x62, 0x61, 0x6c, 0x61, 0x6e, 0x63, 0x65, 0x12, 0x14,
-	0x0a, 0x05, 0x6e, 0x6f, 0x6e, 0x63, 0x65, 0x18, 0x02, 0x20, 0x01, 0x28, 0x04, 0x52, 0x05, 0x6e,
-	0x6f, 0x6e, 0x63, 0x65, 0x22, 0x5e, 0x0a, 0x12, 0x51, 0x75, 0x65, 0x72, 0x79, 0x49, 0x6e, 0x73,
-	0x65, 0x72, 0x74, 0x41, 0x63, 0x63, 0x6f, 0x75, 0x6e, 0x74, 0x12, 0x18, 0x0a, 0x07, 0x61, 0x64,
-	0x64, 0x72, 0x65, 0x73, 0x73, 0x18, 0x01, 0x20, 0x01, 0x28, 0x0c, 0x52, 0x07, 0x61, 0x64, 0x64,
-	0x72, 0x65, 0x73, 0x73, 0x12, 0x18, 0x0a, 0x07, 0x62, 0x61, 0x6c, 0x61, 0x6e, 0x63, 0x65, 0x18,
-	0x02, 0x20, 0x01, 0x28, 0x0c, 0x52, 0x07, 0x62, 0x61, 0x6c, 0x61, 0x6e, 0x63, 0x65, 0x12, 0x14,
-	0x0a, 0x05, 0x6e, 0x6f, 0x6e, 0x63, 0x65, 0x18, 0x03, 0x20, 0x01, 0x28, 0x04, 0x52, 0x05, 0x6e,
-	0x6f, 0x6e, 0x63, 0x65, 0x22, 0x1c, 0x0a, 0x1a, 0x51, 0x75, 0x65, 0x72, 0x79, 0x49, 0x6e, 0x73,
-	0x65, 0x72, 0x74, 0x41, 0x63, 0x63, 0x6f, 0x75, 0x6e, 0x74, 0x52, 0x65, 0x73, 0x70, 0x6f, 0x6e,
-	0x73, 0x65, 0x22, 0x24, 0x0a, 0x10, 0x51, 0x75, 0x65, 0x72, 0x79, 0x43, 0x6f, 0x6e, 0x74, 0x61,
-	0x69, 0x6e, 0x73, 0x4b, 0x65, 0x79, 0x12, 0x10, 0x0a, 0x03, 0x6b, 0x65, 0x79, 0x18, 0x01, 0x20,
-	0x01, 0x28, 0x0c, 0x52, 0x03, 0x6b, 0x65, 0x79, 0x22, 0x36, 0x0a, 0x18, 0x51, 0x75, 0x65, 0x72,
-	0x79, 0x43, 0x6f, 0x6e, 0x74, 0x61, 0x69, 0x6e, 0x73, 0x4b, 0x65, 0x79, 0x52, 0x65, 0x73, 0x70,
-	0x6f, 0x6e, 0x73, 0x65, 0x12, 0x1a, 0x0a, 0x08, 0x63, 0x6f, 0x6e, 0x74, 0x61, 0x69, 0x6e, 0x73,
-	0x18, 0x01, 0x20, 0x01, 0x28, 0x08, 0x52, 0x08, 0x63, 0x6f, 0x6e, 0x74, 0x61, 0x69, 0x6e, 0x73,
-	0x22, 0x4c, 0x0a, 0x1a, 0x51, 0x75, 0x65, 0x72, 0x79, 0x47, 0x65, 0x74, 0x41, 0x63, 0x63, 0x6f,
-	0x75, 0x6e, 0x74, 0x53, 0x74, 0x6f, 0x72, 0x61, 0x67, 0x65, 0x43, 0x65, 0x6c, 0x6c, 0x12, 0x18,
-	0x0a, 0x07, 0x61, 0x64, 0x64, 0x72, 0x65, 0x73, 0x73, 0x18, 0x01, 0x20, 0x01, 0x28, 0x0c, 0x52,
-	0x07, 0x61, 0x64, 0x64, 0x72, 0x65, 0x73, 0x73, 0x12, 0x14, 0x0a, 0x05, 0x69, 0x6e, 0x64, 0x65,
-	0x78, 0x18, 0x02, 0x20, 0x01, 0x28, 0x0c, 0x52, 0x05, 0x69, 0x6e, 0x64, 0x65, 0x78, 0x22, 0x3a,
-	0x0a, 0x22, 0x51, 0x75, 0x65, 0x72, 0x79, 0x47, 0x65, 0x74, 0x41, 0x63, 0x63, 0x6f, 0x75, 0x6e,
-	0x74, 0x53, 0x74, 0x6f, 0x72, 0x61, 0x67, 0x65, 0x43, 0x65, 0x6c, 0x6c, 0x52, 0x65, 0x73, 0x70,
-	0x6f, 0x6e, 0x73, 0x65, 0x12, 0x14, 0x0a, 0x05, 0x76, 0x61, 0x6c, 0x75, 0x65, 0x18, 0x01, 0x20,
-	0x01, 0x28, 0x0c, 0x52, 0x05, 0x76, 0x61, 0x6c, 0x75, 0x65, 0x22, 0x2f, 0x0a, 0x13, 0x51, 0x75,
-	0x65, 0x72, 0x79, 0x47, 0x65, 0x74, 0x41, 0x63, 0x63, 0x6f, 0x75, 0x6e, 0x74, 0x43, 0x6f, 0x64,
-	0x65, 0x12, 0x18, 0x0a, 0x07, 0x61, 0x64, 0x64, 0x72, 0x65, 0x73, 0x73, 0x18, 0x01, 0x20, 0x01,
-	0x28, 0x0c, 0x52, 0x07, 0x61, 0x64, 0x64, 0x72, 0x65, 0x73, 0x73, 0x22, 0x31, 0x0a, 0x1b, 0x51,
-	0x75, 0x65, 0x72, 0x79, 0x47, 0x65, 0x74, 0x41, 0x63, 0x63, 0x6f, 0x75, 0x6e, 0x74, 0x43, 0x6f,
-	0x64, 0x65, 0x52, 0x65, 0x73, 0x70, 0x6f, 0x6e, 0x73, 0x65, 0x12, 0x12, 0x0a, 0x04, 0x63, 0x6f,
-	0x64, 0x65, 0x18, 0x01, 0x20, 0x01, 0x28, 0x0c, 0x52, 0x04, 0x63, 0x6f, 0x64, 0x65, 0x22, 0x46,
-	0x0a, 0x16, 0x51, 0x75, 0x65, 0x72, 0x79, 0x49, 0x6e, 0x73, 0x65, 0x72, 0x74, 0x41, 0x63, 0x63,
-	0x6f, 0x75, 0x6e, 0x74, 0x43, 0x6f, 0x64, 0x65, 0x12, 0x18, 0x0a, 0x07, 0x61, 0x64, 0x64, 0x72,
-	0x65, 0x73, 0x73, 0x18, 0x01, 0x20, 0x01, 0x28, 0x0c, 0x52, 0x07, 0x61, 0x64, 0x64, 0x72, 0x65,
-	0x73, 0x73, 0x12, 0x12, 0x0a, 0x04, 0x63, 0x6f, 0x64, 0x65, 0x18, 0x02, 0x20, 0x01, 0x28, 0x0c,
-	0x52, 0x04, 0x63, 0x6f, 0x64, 0x65, 0x22, 0x20, 0x0a, 0x1e, 0x51, 0x75, 0x65, 0x72, 0x79, 0x49,
-	0x6e, 0x73, 0x65, 0x72, 0x74, 0x41, 0x63, 0x63, 0x6f, 0x75, 0x6e, 0x74, 0x43, 0x6f, 0x64, 0x65,
-	0x52, 0x65, 0x73, 0x70, 0x6f, 0x6e, 0x73, 0x65, 0x22, 0x5e, 0x0a, 0x16, 0x51, 0x75, 0x65, 0x72,
-	0x79, 0x49, 0x6e, 0x73, 0x65, 0x72, 0x74, 0x53, 0x74, 0x6f, 0x72, 0x61, 0x67, 0x65, 0x43, 0x65,
-	0x6c, 0x6c, 0x12, 0x18, 0x0a, 0x07, 0x61, 0x64, 0x64, 0x72, 0x65, 0x73, 0x73, 0x18, 0x01, 0x20,
-	0x01, 0x28, 0x0c, 0x52, 0x07, 0x61, 0x64, 0x64, 0x72, 0x65, 0x73, 0x73, 0x12, 0x14, 0x0a, 0x05,
-	0x69, 0x6e, 0x64, 0x65, 0x78, 0x18, 0x02, 0x20, 0x01, 0x28, 0x0c, 0x52, 0x05, 0x69, 0x6e, 0x64,
-	0x65, 0x78, 0x12, 0x14, 0x0a, 0x05, 0x76, 0x61, 0x6c, 0x75, 0x65, 0x18, 0x03, 0x20, 0x01, 0x28,
-	0x0c, 0x52, 0x05, 0x76, 0x61, 0x6c, 0x75, 0x65, 0x22, 0x20, 0x0a, 0x1e, 0x51, 0x75, 0x65, 0x72,
-	0x79, 0x49, 0x6e, 0x73, 0x65, 0x72, 0x74, 0x53, 0x74, 0x6f, 0x72, 0x61, 0x67, 0x65, 0x43, 0x65,
-	0x6c, 0x6c, 0x52, 0x65, 0x73, 0x70, 0x6f, 0x6e, 0x73, 0x65, 0x22, 0x27, 0x0a, 0x0b, 0x51, 0x75,
-	0x65, 0x72, 0x79, 0x52, 0x65, 0x6d, 0x6f, 0x76, 0x65, 0x12, 0x18, 0x0a, 0x07, 0x61, 0x64, 0x64,
-	0x72, 0x65, 0x73, 0x73, 0x18, 0x01, 0x20, 0x01, 0x28, 0x0c, 0x52, 0x07, 0x61, 0x64, 0x64, 0x72,
-	0x65, 0x73, 0x73, 0x22, 0x15, 0x0a, 0x13, 0x51, 0x75, 0x65, 0x72, 0x79, 0x52, 0x65, 0x6d, 0x6f,
-	0x76, 0x65, 0x52, 0x65, 0x73, 0x70, 0x6f, 0x6e, 0x73, 0x65, 0x22, 0x48, 0x0a, 0x16, 0x51, 0x75,
-	0x65, 0x72, 0x79, 0x52, 0x65, 0x6d, 0x6f, 0x76, 0x65, 0x53, 0x74, 0x6f, 0x72, 0x61, 0x67, 0x65,
-	0x43, 0x65, 0x6c, 0x6c, 0x12, 0x18, 0x0a, 0x07, 0x61, 0x64, 0x64, 0x72, 0x65, 0x73, 0x73, 0x18,
-	0x01, 0x20, 0x01, 0x28, 0x0c, 0x52, 0x07, 0x61, 0x64, 0x64, 0x72, 0x65, 0x73, 0x73, 0x12, 0x14,
-	0x0a, 0x05, 0x69, 0x6e, 0x64, 0x65, 0x78, 0x18, 0x02, 0x20, 0x01, 0x28, 0x0c, 0x52, 0x05, 0x69,
-	0x6e, 0x64, 0x65, 0x78, 0x22, 0x20, 0x0a, 0x1e, 0x51, 0x75, 0x65, 0x72, 0x79, 0x52, 0x65, 0x6d,
-	0x6f, 0x76, 0x65, 0x53, 0x74, 0x6f, 0x72, 0x61, 0x67, 0x65, 0x43, 0x65, 0x6c, 0x6c, 0x52, 0x65,
-	0x73, 0x70, 0x6f, 0x6e, 0x73, 0x65, 0x22, 0x2e, 0x0a, 0x12, 0x51, 0x75, 0x65, 0x72, 0x79, 0x52,
-	0x65, 0x6d, 0x6f, 0x76, 0x65, 0x53, 0x74, 0x6f, 0x72, 0x61, 0x67, 0x65, 0x12, 0x18, 0x0a, 0x07,
-	0x61, 0x64, 0x64, 0x72, 0x65, 0x73, 0x73, 0x18, 0x01, 0x20, 0x01, 0x28, 0x0c, 0x52, 0x07, 0x61,
-	0x64, 0x64, 0x72, 0x65, 0x73, 0x73, 0x22, 0x1c, 0x0a, 0x1a, 0x51, 0x75, 0x65, 0x72, 0x79, 0x52,
-	0x65, 0x6d, 0x6f, 0x76, 0x65, 0x53, 0x74, 0x6f, 0x72, 0x61, 0x67, 0x65, 0x52, 0x65, 0x73, 0x70,
-	0x6f, 0x6e, 0x73, 0x65, 0x22, 0x28, 0x0a, 0x0e, 0x51, 0x75, 0x65, 0x72, 0x79, 0x42, 0x6c, 0x6f,
-	0x63, 0x6b, 0x48, 0x61, 0x73, 0x68, 0x12, 0x16, 0x0a, 0x06, 0x6e, 0x75, 0x6d, 0x62, 0x65, 0x72,
-	0x18, 0x01, 0x20, 0x01, 0x28, 0x0c, 0x52, 0x06, 0x6e, 0x75, 0x6d, 0x62, 0x65, 0x72, 0x22, 0x2c,
-	0x0a, 0x16, 0x51, 0x75, 0x65, 0x72, 0x79, 0x42, 0x6c, 0x6f, 0x63, 0x6b, 0x48, 0x61, 0x73, 0x68,
-	0x52, 0x65, 0x73, 0x70, 0x6f, 0x6e, 0x73, 0x65, 0x12, 0x12, 0x0a, 0x04, 0x68, 0x61, 0x73, 0x68,
-	0x18, 0x01, 0x20, 0x01, 0x28, 0x0c, 0x52, 0x04, 0x68, 0x61, 0x73, 0x68, 0x22, 0x82, 0x06, 0x0a,
-	0x0d, 0x43, 0x6f, 0x73, 0x6d, 0x6f, 0x73, 0x52, 0x65, 0x71, 0x75, 0x65, 0x73, 0x74, 0x12, 0x3a,
-	0x0a, 0x0a, 0x67, 0x65, 0x74, 0x41, 0x63, 0x63, 0x6f, 0x75, 0x6e, 0x74, 0x18, 0x01, 0x20, 0x01,
-	0x28, 0x0b, 0x32, 0x18, 0x2e, 0x66, 0x66, 0x69, 0x2e, 0x66, 0x66, 0x69, 0x2e, 0x51, 0x75, 0x65,
-	0x72, 0x79, 0x47, 0x65, 0x74, 0x41, 0x63, 0x63, 0x6f, 0x75, 0x6e, 0x74, 0x48, 0x00, 0x52, 0x0a,
-	0x67, 0x65, 0x74, 0x41, 0x63, 0x63, 0x6f, 0x75, 0x6e, 0x74, 0x12, 0x43, 0x0a, 0x0d, 0x69, 0x6e,
-	0x73, 0x65, 0x72, 0x74, 0x41, 0x63, 0x63, 0x6f, 0x75, 0x6e, 0x74, 0x18, 0x02, 0x20, 0x01, 0x28,
-	0x0b, 0x32, 0x1b, 0x2e, 0x66, 0x66, 0x69, 0x2e, 0x66, 0x66, 0x69, 0x2e, 0x51, 0x75, 0x65, 0x72,
-	0x79, 0x49, 0x6e, 0x73, 0x65, 0x72, 0x74, 0x41, 0x63, 0x63, 0x6f, 0x75, 0x6e, 0x74, 0x48, 0x00,
-	0x52, 0x0d, 0x69, 0x6e, 0x73, 0x65, 0x72, 0x74, 0x41, 0x63, 0x63, 0x6f, 0x75, 0x6e, 0x74, 0x12,
-	0x3d, 0x0a, 0x0b, 0x63, 0x6f, 0x6e, 0x74, 0x61, 0x69, 0x6e, 0x73, 0x4b, 0x65, 0x79, 0x18, 0x03,
-	0x20, 0x01, 0x28, 0x0b, 0x32, 0x19, 0x2e, 0x66, 0x66, 0x69, 0x2e, 0x66, 0x66, 0x69, 0x2e, 0x51,
-	0x75, 0x65, 0x72, 0x79, 0x43, 0x6f, 0x6e, 0x74, 0x61, 0x69, 0x6e, 0x73, 0x4b, 0x65, 0x79, 0x48,
-	0x00, 0x52, 0x0b, 0x63, 0x6f, 0x6e, 0x74, 0x61, 0x69, 0x6e, 0x73, 0x4b, 0x65, 0x79, 0x12, 0x40,
-	0x0a, 0x0b, 0x61, 0x63, 0x63, 0x6f, 0x75, 0x6e, 0x74, 0x43, 0x6f, 0x64, 0x65, 0x18, 0x04, 0x20,
-	0x01, 0x28, 0x0b, 0x32, 0x1c, 0x2e, 0x66, 0x66, 0x69, 0x2e, 0x66, 0x66, 0x69, 0x2e, 0x51, 0x75,
-	0x65, 0x72, 0x79, 0x47, 0x65, 0x74, 0x41, 0x63, 0x63, 0x6f, 0x75, 0x6e, 0x74, 0x43, 0x6f, 0x64,
-	0x65, 0x48, 0x00, 0x52, 0x0b, 0x61, 0x63, 0x63, 0x6f, 0x75, 0x6e, 0x74, 0x43, 0x6f, 0x64, 0x65,
-	0x12, 0x47, 0x0a, 0x0b, 0x73, 0x74, 0x6f, 0x72, 0x61, 0x67, 0x65, 0x43, 0x65, 0x6c, 0x6c, 0x18,
-	0x05, 0x20, 0x01, 0x28, 0x0b, 0x32, 0x23, 0x2e, 0x66, 0x66, 0x69, 0x2e, 0x66, 0x66, 0x69, 0x2e,
-	0x51, 0x75, 0x65, 0x72, 0x79, 0x47, 0x65, 0x74, 0x41, 0x63, 0x63, 0x6f, 0x75, 0x6e, 0x74, 0x53,
-	0x74, 0x6f, 0x72, 0x61, 0x67, 0x65, 0x43, 0x65, 0x6c, 0x6c, 0x48, 0x00, 0x52, 0x0b, 0x73, 0x74,
-	0x6f, 0x72, 0x61, 0x67, 0x65, 0x43, 0x65, 0x6c, 0x6c, 0x12, 0x4f, 0x0a, 0x11, 0x69, 0x6e, 0x73,
-	0x65, 0x72, 0x74, 0x41, 0x63, 0x63, 0x6f, 0x75, 0x6e, 0x74, 0x43, 0x6f, 0x64, 0x65, 0x18, 0x06,
-	0x20, 0x01, 0x28, 0x0b, 0x32, 0x1f, 0x2e, 0x66, 0x66, 0x69, 0x2e, 0x66, 0x66, 0x69, 0x2e, 0x51,
-	0x75, 0x65, 0x72, 0x79, 0x49, 0x6e, 0x73, 0x65, 0x72, 0x74, 0x41, 0x63, 0x63, 0x6f, 0x75, 0x6e,
-	0x74, 0x43, 0x6f, 0x64, 0x65, 0x48, 0x00, 0x52, 0x11, 0x69, 0x6e, 0x73, 0x65, 0x72, 0x74, 0x41,
-	0x63, 0x63, 0x6f, 0x75, 0x6e, 0x74, 0x43, 0x6f, 0x64, 0x65, 0x12, 0x4f, 0x0a, 0x11, 0x69, 0x6e,
-	0x73, 0x65, 0x72, 0x74, 0x53, 0x74, 0x6f, 0x72, 0x61, 0x67, 0x65, 0x43, 0x65, 0x6c, 0x6c, 0x18,
-	0x07, 0x20, 0x01, 0x28, 0x0b, 0x32, 0x1f, 0x2e, 0x66, 0x66, 0x69, 0x2e, 0x66, 0x66, 0x69, 0x2e,
-	0x51, 0x75, 0x65, 0x72, 0x79, 0x49, 0x6e, 0x73, 0x65, 0x72, 0x74, 0x53, 0x74, 0x6f, 0x72, 0x61,
-	0x67, 0x65, 0x43, 0x65, 0x6c, 0x6c, 0x48, 0x00, 0x52, 0x11, 0x69, 0x6e, 0x73, 0x65, 0x72, 0x74,
-	0x53, 0x74, 0x6f, 0x72, 0x61, 0x67, 0x65, 0x43, 0x65, 0x6c, 0x6c, 0x12, 0x2e, 0x0a, 0x06, 0x72,
-	0x65, 0x6d, 0x6f, 0x76, 0x65, 0x18, 0x08, 0x20, 0x01, 0x28, 0x0b, 0x32, 0x14, 0x2e, 0x66, 0x66,
-	0x69, 0x2e, 0x66, 0x66, 0x69, 0x2e, 0x51, 0x75, 0x65, 0x72, 0x79, 0x52, 0x65, 0x6d, 0x6f, 0x76,
-	0x65, 0x48, 0x00, 0x52, 0x06, 0x72, 0x65, 0x6d, 0x6f, 0x76, 0x65, 0x12, 0x4f, 0x0a, 0x11, 0x72,
-	0x65, 0x6d, 0x6f, 0x76, 0x65, 0x53, 0x74, 0x6f, 0x72, 0x61, 0x67, 0x65, 0x43, 0x65, 0x6c, 0x6c,
-	0x18, 0x09, 0x20, 0x01, 0x28, 0x0b, 0x32, 0x1f, 0x2e, 0x66, 0x66, 0x69, 0x2e, 0x66, 0x66, 0x69,
-	0x2e, 0x51, 0x75, 0x65, 0x72, 0x79, 0x52, 0x65, 0x6d, 0x6f, 0x76, 0x65, 0x53, 0x74, 0x6f, 0x72,
-	0x61, 0x67, 0x65, 0x43, 0x65, 0x6c, 0x6c, 0x48, 0x00, 0x52, 0x11, 0x72, 0x65, 0x6d, 0x6f, 0x76,
-	0x65, 0x53, 0x74, 0x6f, 0x72, 0x61, 0x67, 0x65, 0x43, 0x65, 0x6c, 0x6c, 0x12, 0x43, 0x0a, 0x0d,
-	0x72, 0x65, 0x6d, 0x6f, 0x76, 0x65, 0x53, 0x74, 0x6f, 0x72, 0x61, 0x67, 0x65, 0x18, 0x0a, 0x20,
-	0x01, 0x28, 0x0b, 0x32, 0x1b, 0x2e, 0x66, 0x66, 0x69, 0x2e, 0x66, 0x66, 0x69, 0x2e, 0x51, 0x75,
-	0x65, 0x72, 0x79, 0x52, 0x65, 0x6d, 0x6f, 0x76, 0x65, 0x53, 0x74, 0x6f, 0x72, 0x61, 0x67, 0x65,
-	0x48, 0x00, 0x52, 0x0d, 0x72, 0x65, 0x6d, 0x6f, 0x76, 0x65, 0x53, 0x74, 0x6f, 0x72, 0x61, 0x67,
-	0x65, 0x12, 0x37, 0x0a, 0x09, 0x62, 0x6c, 0x6f, 0x63, 0x6b, 0x48, 0x61, 0x73, 0x68, 0x18, 0x0b,
-	0x20, 0x01, 0x28, 0x0b, 0x32, 0x17, 0x2e, 0x66, 0x66, 0x69, 0x2e, 0x66, 0x66, 0x69, 0x2e, 0x51,
-	0x75, 0x65, 0x72, 0x79, 0x42, 0x6c, 0x6f, 0x63, 0x6b, 0x48, 0x61, 0x73, 0x68, 0x48, 0x00, 0x52,
-	0x09, 0x62, 0x6c, 0x6f, 0x63, 0x6b, 0x48, 0x61, 0x73, 0x68, 0x42, 0x05, 0x0a, 0x03, 0x72, 0x65,
-	0x71, 0x22, 0xe2, 0x01, 0x0a, 0x0f, 0x53, 0x47, 0x58, 0x56, 0x4d, 0x43, 0x61, 0x6c, 0x6c, 0x50,
-	0x61, 0x72, 0x61, 0x6d, 0x73, 0x12, 0x12, 0x0a, 0x04, 0x66, 0x72, 0x6f, 0x6d, 0x18, 0x01, 0x20,
-	0x01, 0x28, 0x0c, 0x52, 0x04, 0x66, 0x72, 0x6f, 0x6d, 0x12, 0x0e, 0x0a, 0x02, 0x74, 0x6f, 0x18,
-	0x02, 0x20, 0x01, 0x28, 0x0c, 0x52, 0x02, 0x74, 0x6f, 0x12, 0x12, 0x0a, 0x04, 0x64, 0x61, 0x74,
-	0x61, 0x18, 0x03, 0x20, 0x01, 0x28, 0x0c, 0x52, 0x04, 0x64, 0x61, 0x74, 0x61, 0x12, 0x1a, 0x0a,
-	0x08, 0x67, 0x61, 0x73, 0x4c, 0x69, 0x6d, 0x69, 0x74, 0x18, 0x04, 0x20, 0x01, 0x28, 0x04, 0x52,
-	0x08, 0x67, 0x61, 0x73, 0x4c, 0x69, 0x6d, 0x69, 0x74, 0x12, 0x14, 0x0a, 0x05, 0x76, 0x61, 0x6c,
-	0x75, 0x65, 0x18, 0x05, 0x20, 0x01, 0x28, 0x0c, 0x52, 0x05, 0x76, 0x61, 0x6c, 0x75, 0x65, 0x12,
-	0x37, 0x0a, 0x0a, 0x61, 0x63, 0x63, 0x65, 0x73, 0x73, 0x4c, 0x69, 0x73, 0x74, 0x18, 0x06, 0x20,
-	0x03, 0x28, 0x0b, 0x32, 0x17, 0x2e, 0x66, 0x66, 0x69, 0x2e, 0x66, 0x66, 0x69, 0x2e, 0x41, 0x63,
-	0x63, 0x65, 0x73, 0x73, 0x4c, 0x69, 0x73, 0x74, 0x49, 0x74, 0x65, 0x6d, 0x52, 0x0a, 0x61, 0x63,
-	0x63, 0x65, 0x73, 0x73, 0x4c, 0x69, 0x73, 0x74, 0x12, 0x16, 0x0a, 0x06, 0x63, 0x6f, 0x6d, 0x6d,
-	0x69, 0x74, 0x18, 0x07, 0x20, 0x01, 0x28, 0x08, 0x52, 0x06, 0x63, 0x6f, 0x6d, 0x6d, 0x69, 0x74,
-	0x12, 0x14, 0x0a, 0x05, 0x6e, 0x6f, 0x6e, 0x63, 0x65, 0x18, 0x08, 0x20, 0x01, 0x28, 0x04, 0x52,
-	0x05, 0x6e, 0x6f, 0x6e, 0x63, 0x65, 0x22, 0xd4, 0x01, 0x0a, 0x11, 0x53, 0x47, 0x58, 0x56, 0x4d,
-	0x43, 0x72, 0x65, 0x61, 0x74, 0x65, 0x50, 0x61, 0x72, 0x61, 0x6d, 0x73, 0x12, 0x12, 0x0a, 0x04,
-	0x66, 0x72, 0x6f, 0x6d, 0x18, 0x01, 0x20, 0x01, 0x28, 0x0c, 0x52, 0x04, 0x66, 0x72, 0x6f, 0x6d,
-	0x12, 0x12, 0x0a, 0x04, 0x64, 0x61, 0x74, 0x61, 0x18, 0x02, 0x20, 0x01, 0x28, 0x0c, 0x52, 0x04,
-	0x64, 0x61, 0x74, 0x61, 0x12, 0x1a, 0x0a, 0x08, 0x67, 0x61, 0x73, 0x4c, 0x69, 0x6d, 0x69, 0x74,
-	0x18, 0x03, 0x20, 0x01, 0x28, 0x04, 0x52, 0x08, 0x67, 0x61, 0x73, 0x4c, 0x69, 0x6d, 0x69, 0x74,
-	0x12, 0x14, 0x0a, 0x05, 0x76, 0x61, 0x6c, 0x75, 0x65, 0x18, 0x04, 0x20, 0x01, 0x28, 0x0c, 0x52,
-	0x05, 0x76, 0x61, 0x6c, 0x75, 0x65, 0x12, 0x37, 0x0a, 0x0a, 0x61, 0x63, 0x63, 0x65, 0x73, 0x73,
-	0x4c, 0x69, 0x73, 0x74, 0x18, 0x05, 0x20, 0x03, 0x28, 0x0b, 0x32, 0x17, 0x2e, 0x66, 0x66, 0x69,
-	0x2e, 0x66, 0x66, 0x69, 0x2e, 0x41, 0x63, 0x63, 0x65, 0x73, 0x73, 0x4c, 0x69, 0x73, 0x74, 0x49,
-	0x74, 0x65, 0x6d, 0x52, 0x0a, 0x61, 0x63, 0x63, 0x65, 0x73, 0x73, 0x4c, 0x69, 0x73, 0x74, 0x12,
-	0x16, 0x0a, 0x06, 0x63, 0x6f, 0x6d, 0x6d, 0x69, 0x74, 0x18, 0x06, 0x20, 0x01, 0x28, 0x08, 0x52,
-	0x06, 0x63, 0x6f, 0x6d, 0x6d, 0x69, 0x74, 0x12, 0x14, 0x0a, 0x05, 0x6e, 0x6f, 0x6e, 0x63, 0x65,
-	0x18, 0x07, 0x20, 0x01, 0x28, 0x04, 0x52, 0x05, 0x6e, 0x6f, 0x6e, 0x63, 0x65, 0x22, 0x7b, 0x0a,
-	0x10, 0x53, 0x47, 0x58, 0x56, 0x4d, 0x43, 0x61, 0x6c, 0x6c, 0x52, 0x65, 0x71, 0x75, 0x65, 0x73,
-	0x74, 0x12, 0x30, 0x0a, 0x06, 0x70, 0x61, 0x72, 0x61, 0x6d, 0x73, 0x18, 0x01, 0x20, 0x01, 0x28,
-	0x0b, 0x32, 0x18, 0x2e, 0x66, 0x66, 0x69, 0x2e, 0x66, 0x66, 0x69, 0x2e, 0x53, 0x47, 0x58, 0x56,
-	0x4d, 0x43, 0x61, 0x6c, 0x6c, 0x50, 0x61, 0x72, 0x61, 0x6d, 0x73, 0x52, 0x06, 0x70, 0x61, 0x72,
-	0x61, 0x6d, 0x73, 0x12, 0x35, 0x0a, 0x07, 0x63, 0x6f, 0x6e, 0x74, 0x65, 0x78, 0x74, 0x18, 0x02,
-	0x20, 0x01, 0x28, 0x0b, 0x32, 0x1b, 0x2e, 0x66, 0x66, 0x69, 0x2e, 0x66, 0x66, 0x69, 0x2e, 0x54,
-	0x72, 0x61, 0x6e, 0x73, 0x61, 0x63, 0x74, 0x69, 0x6f, 0x6e, 0x43, 0x6f, 0x6e, 0x74, 0x65, 0x78,
-	0x74, 0x52, 0x07, 0x63, 0x6f, 0x6e, 0x74, 0x65, 0x78, 0x74, 0x22, 0x7f, 0x0a, 0x12, 0x53, 0x47,
-	0x58, 0x56, 0x4d, 0x43, 0x72, 0x65, 0x61, 0x74, 0x65, 0x52, 0x65, 0x71, 0x75, 0x65, 0x73, 0x74,
-	0x12, 0x32, 0x0a, 0x06, 0x70, 0x61, 0x72, 0x61, 0x6d, 0x73, 0x18, 0x01, 0x20, 0x01, 0x28, 0x0b,
-	0x32, 0x1a, 0x2e, 0x66, 0x66, 0x69, 0x2e, 0x66, 0x66, 0x69, 0x2e, 0x53, 0x47, 0x58, 0x56, 0x4d,
-	0x43, 0x72, 0x65, 0x61, 0x74, 0x65, 0x50, 0x61, 0x72, 0x61, 0x6d, 0x73, 0x52, 0x06, 0x70, 0x61,
-	0x72, 0x61, 0x6d, 0x73, 0x12, 0x35, 0x0a, 0x07, 0x63, 0x6f, 0x6e, 0x74, 0x65, 0x78, 0x74, 0x18,
-	0x02, 0x20, 0x01, 0x28, 0x0b, 0x32, 0x1b, 0x2e, 0x66, 0x66, 0x69, 0x2e, 0x66, 0x66, 0x69, 0x2e,
-	0x54, 0x72, 0x61, 0x6e, 0x73, 0x61, 0x63, 0x74, 0x69, 0x6f, 0x6e, 0x43, 0x6f, 0x6e, 0x74, 0x65,
-	0x78, 0x74, 0x52, 0x07, 0x63, 0x6f, 0x6e, 0x74, 0x65, 0x78, 0x74, 0x22, 0x16, 0x0a, 0x14, 0x4e,
-	0x6f, 0x64, 0x65, 0x50, 0x75, 0x62, 0x6c, 0x69, 0x63, 0x4b, 0x65, 0x79, 0x52, 0x65, 0x71, 0x75,
-	0x65, 0x73, 0x74, 0x22, 0x35, 0x0a, 0x15, 0x4e, 0x6f, 0x64, 0x65, 0x50, 0x75, 0x62, 0x6c, 0x69,
-	0x63, 0x4b, 0x65, 0x79, 0x52, 0x65, 0x73, 0x70, 0x6f, 0x6e, 0x73, 0x65, 0x12, 0x1c, 0x0a, 0x09,
-	0x70, 0x75, 0x62, 0x6c, 0x69, 0x63, 0x4b, 0x65, 0x79, 0x18, 0x01, 0x20, 0x01, 0x28, 0x0c, 0x52,
-	0x09, 0x70, 0x75, 0x62, 0x6c, 0x69, 0x63, 0x4b, 0x65, 0x79, 0x22, 0xe4, 0x01, 0x0a, 0x0a, 0x46,
-	0x46, 0x49, 0x52, 0x65, 0x71, 0x75, 0x65, 0x73, 0x74, 0x12, 0x3d, 0x0a, 0x0b, 0x63, 0x61, 0x6c,
-	0x6c, 0x52, 0x65, 0x71, 0x75, 0x65, 0x73, 0x74, 0x18, 0x01, 0x20, 0x01, 0x28, 0x0b, 0x32, 0x19,
-	0x2e, 0x66, 0x66, 0x69, 0x2e, 0x66, 0x66, 0x69, 0x2e, 0x53, 0x47, 0x58, 0x56, 0x4d, 0x43, 0x61,
-	0x6c, 0x6c, 0x52, 0x65, 0x71, 0x75, 0x65, 0x73, 0x74, 0x48, 0x00, 0x52, 0x0b, 0x63, 0x61, 0x6c,
-	0x6c, 0x52, 0x65, 0x71, 0x75, 0x65, 0x73, 0x74, 0x12, 0x43, 0x0a, 0x0d, 0x63, 0x72, 0x65, 0x61,
-	0x74, 0x65, 0x52, 0x65, 0x71, 0x75, 0x65, 0x73, 0x74, 0x18, 0x02, 0x20, 0x01, 0x28, 0x0b, 0x32,
-	0x1b, 0x2e, 0x66, 0x66, 0x69, 0x2e, 0x66, 0x66, 0x69, 0x2e, 0x53, 0x47, 0x58, 0x56, 0x4d, 0x43,
-	0x72, 0x65, 0x61, 0x74, 0x65, 0x52, 0x65, 0x71, 0x75, 0x65, 0x73, 0x74, 0x48, 0x00, 0x52, 0x0d,
-	0x63, 0x72, 0x65, 0x61, 0x74, 0x65, 0x52, 0x65, 0x71, 0x75, 0x65, 0x73, 0x74, 0x12, 0x4b, 0x0a,
-	0x10, 0x70, 0x75, 0x62, 0x6c, 0x69, 0x63, 0x4b, 0x65, 0x79, 0x52, 0x65, 0x71, 0x75, 0x65, 0x73,
-	0x74, 0x18, 0x03, 0x20, 0x01, 0x28, 0x0b, 0x32, 0x1d, 0x2e, 0x66, 0x66, 0x69, 0x2e, 0x66, 0x66,
-	0x69, 0x2e, 0x4e, 0x6f, 0x64, 0x65, 0x50, 0x75, 0x62, 0x6c, 0x69, 0x63, 0x4b, 0x65, 0x79, 0x52,
-	0x65, 0x71, 0x75, 0x65, 0x73, 0x74, 0x48, 0x00, 0x52, 0x10, 0x70, 0x75, 0x62, 0x6c, 0x69, 0x63,
-	0x4b, 0x65, 0x79, 0x52, 0x65, 0x71, 0x75, 0x65, 0x73, 0x74, 0x42, 0x05, 0x0a, 0x03, 0x72, 0x65,
-	0x71, 0x42, 0x04, 0x5a, 0x02, 0x2e, 0x2f, 0x62, 0x06, 0x70, 0x72, 0x6f, 0x74, 0x6f, 0x33,
-}
-
-var (
-	file_ffi_proto_rawDescOnce sync.Once
-	file_ffi_proto_rawDescData = file_ffi_proto_rawDesc
-)
-
-func file_ffi_proto_rawDescGZIP() []byte {
-	file_ffi_proto_rawDescOnce.Do(func() {
-		file_ffi_proto_rawDescData = protoimpl.X.CompressGZIP(file_ffi_proto_rawDescData)
-	})
-	return file_ffi_proto_rawDescData
-}
-
-var file_ffi_proto_msgTypes = make([]protoimpl.MessageInfo, 37)
-var file_ffi_proto_goTypes = []interface{}{
-	(*AccessListItem)(nil),                     // 0: ffi.ffi.AccessListItem
-	(*TransactionData)(nil),                    // 1: ffi.ffi.TransactionData
-	(*TransactionContext)(nil),                 // 2: ffi.ffi.TransactionContext
-	(*HandleTransactionRequest)(nil),           // 3: ffi.ffi.HandleTransactionRequest
-	(*HandleTransactionResponse)(nil),          // 4: ffi.ffi.HandleTransactionResponse
-	(*Topic)(nil),                              // 5: ffi.ffi.Topic
-	(*Log)(nil),                                // 6: ffi.ffi.Log
-	(*QueryGetAccount)(nil),                    // 7: ffi.ffi.QueryGetAccount
-	(*QueryGetAccountResponse)(nil),            // 8: ffi.ffi.QueryGetAccountResponse
-	(*QueryInsertAccount)(nil),                 // 9: ffi.ffi.QueryInsertAccount
-	(*QueryInsertAccountResponse)(nil),         // 10: ffi.ffi.QueryInsertAccountResponse
-	(*QueryContainsKey)(nil),                   // 11: ffi.ffi.QueryContainsKey
-	(*QueryContainsKeyResponse)(nil),           // 12: ffi.ffi.QueryContainsKeyResponse
-	(*QueryGetAccountStorageCell)(nil),         // 13: ffi.ffi.QueryGetAccountStorageCell
-	(*QueryGetAccountStorageCellResponse)(nil), // 14: ffi.ffi.QueryGetAccountStorageCellResponse
-	(*QueryGetAccountCode)(nil),                // 15: ffi.ffi.QueryGetAccountCode
-	(*QueryGetAccountCodeResponse)(nil),        // 16: ffi.ffi.QueryGetAccountCodeResponse
-	(*QueryInsertAccountCode)(nil),             // 17: ffi.ffi.QueryInsertAccountCode
-	(*QueryInsertAccountCodeResponse)(nil),     // 18: ffi.ffi.QueryInsertAccountCodeResponse
-	(*QueryInsertStorageCell)(nil),             // 19: ffi.ffi.QueryInsertStorageCell
-	(*QueryInsertStorageCellResponse)(nil),     // 20: ffi.ffi.QueryInsertStorageCellResponse
-	(*QueryRemove)(nil),                        // 21: ffi.ffi.QueryRemove
-	(*QueryRemoveResponse)(nil),                // 22: ffi.ffi.QueryRemoveResponse
-	(*QueryRemoveStorageCell)(nil),             // 23: ffi.ffi.QueryRemoveStorageCell
-	(*QueryRemoveStorageCellResponse)(nil),     // 24: ffi.ffi.QueryRemoveStorageCellResponse
-	(*QueryRemoveStorage)(nil),                 // 25: ffi.ffi.QueryRemoveStorage
-	(*QueryRemoveStorageResponse)(nil),         // 26: ffi.ffi.QueryRemoveStorageResponse
-	(*QueryBlockHash)(nil),                     // 27: ffi.ffi.QueryBlockHash
-	(*QueryBlockHashResponse)(nil),             // 28: ffi.ffi.QueryBlockHashResponse
-	(*CosmosRequest)(nil),                      // 29: ffi.ffi.CosmosRequest
-	(*SGXVMCallParams)(nil),                    // 30: ffi.ffi.SGXVMCallParams
-	(*SGXVMCreateParams)(nil),                  // 31: ffi.ffi.SGXVMCreateParams
-	(*SGXVMCallRequest)(nil),                   // 32: ffi.ffi.SGXVMCallRequest
-	(*SGXVMCreateRequest)(nil),                 // 33: ffi.ffi.SGXVMCreateRequest
-	(*NodePublicKeyRequest)(nil),               // 34: ffi.ffi.NodePublicKeyRequest
-	(*NodePublicKeyResponse)(nil),              // 35: ffi.ffi.NodePublicKeyResponse
-	(*FFIRequest)(nil),                         // 36: ffi.ffi.FFIRequest
-}
-var file_ffi_proto_depIdxs = []int32{
-	0,  // 0: ffi.ffi.TransactionData.accessList:type_name -> ffi.ffi.AccessListItem
-	1,  // 1: ffi.ffi.HandleTransactionRequest.tx_data:type_name -> ffi.ffi.TransactionData
-	2,  // 2: ffi.ffi.HandleTransactionRequest.tx_context:type_name -> ffi.ffi.TransactionContext
-	6,  // 3: ffi.ffi.HandleTransactionResponse.logs:type_name -> ffi.ffi.Log
-	5,  // 4: ffi.ffi.Log.topics:type_name -> ffi.ffi.Topic
-	7,  // 5: ffi.ffi.CosmosRequest.getAccount:type_name -> ffi.ffi.QueryGetAccount
-	9,  // 6: ffi.ffi.CosmosRequest.insertAccount:type_name -> ffi.ffi.QueryInsertAccount
-	11, // 7: ffi.ffi.CosmosRequest.containsKey:type_name -> ffi.ffi.QueryContainsKey
-	15, // 8: ffi.ffi.CosmosRequest.accountCode:type_name -> ffi.ffi.QueryGetAccountCode
-	13, // 9: ffi.ffi.CosmosRequest.storageCell:type_name -> ffi.ffi.QueryGetAccountStorageCell
-	17, // 10: ffi.ffi.CosmosRequest.insertAccountCode:type_name -> ffi.ffi.QueryInsertAccountCode
-	19, // 11: ffi.ffi.CosmosRequest.insertStorageCell:type_name -> ffi.ffi.QueryInsertStorageCell
-	21, // 12: ffi.ffi.CosmosRequest.remove:type_name -> ffi.ffi.QueryRemove
-	23, // 13: ffi.ffi.CosmosRequest.removeStorageCell:type_name -> ffi.ffi.QueryRemoveStorageCell
-	25, // 14: ffi.ffi.CosmosRequest.removeStorage:type_name -> ffi.ffi.QueryRemoveStorage
-	27, // 15: ffi.ffi.CosmosRequest.blockHash:type_name -> ffi.ffi.QueryBlockHash
-	0,  // 16: ffi.ffi.SGXVMCallParams.accessList:type_name -> ffi.ffi.AccessListItem
-	0,  // 17: ffi.ffi.SGXVMCreateParams.accessList:type_name -> ffi.ffi.AccessListItem
-	30, // 18: ffi.ffi.SGXVMCallRequest.params:type_name -> ffi.ffi.SGXVMCallParams
-	2,  // 19: ffi.ffi.SGXVMCallRequest.context:type_name -> ffi.ffi.TransactionContext
-	31, // 20: ffi.ffi.SGXVMCreateRequest.params:type_name -> ffi.ffi.SGXVMCreateParams
-	2,  // 21: ffi.ffi.SGXVMCreateRequest.context:type_name -> ffi.ffi.TransactionContext
-	32, // 22: ffi.ffi.FFIRequest.callRequest:type_name -> ffi.ffi.SGXVMCallRequest
-	33, // 23: ffi.ffi.FFIRequest.createRequest:type_name -> ffi.ffi.SGXVMCreateRequest
-	34, // 24: ffi.ffi.FFIRequest.publicKeyRequest:type_name -> ffi.ffi.NodePublicKeyRequest
-	25, // [25:25] is the sub-list for method output_type
-	25, // [25:25] is the sub-list for method input_type
-	25, // [25:25] is the sub-list for extension type_name
-	25, // [25:25] is the sub-list for extension extendee
-	0,  // [0:25] is the sub-list for field type_name
-}
-
-func init() { file_ffi_proto_init() }
-func file_ffi_proto_init() {
-	if File_ffi_proto != nil {
-		return
-	}
-	if !protoimpl.UnsafeEnabled {
-		file_ffi_proto_msgTypes[0].Exporter = func(v interface{}, i int) interface{} {
-			switch v := v.(*AccessListItem); i {
+		return ms
+	}
+	return mi.MessageOf(x)
+}
+
+// Deprecated: Use SGXVMCallParams.ProtoReflect.Descriptor instead.
+func (*SGXVMCallParams) Descriptor() ([]byte, []int) {
+	return file_ffi_proto_rawDescGZIP(), []int{38}
+}
+
+func (x *SGXVMCallParams) GetFrom() []byte {
+	if x != nil {
+		return x.From
+	}
+	return nil
+}
+
+func (x *SGXVMCallParams) GetTo() []byte {
+	if x != nil {
+		return x.To
+	}
+	return nil
+}
+
+func (x *SGXVMCallParams) GetData() []byte {
+	if x != nil {
+		return x.Data
+	}
+	return nil
+}
+
+func (x *SGXVMCallParams) GetGasLimit() uint64 {
+	if x != nil {
+		return x.GasLimit
+	}
+	return 0
+}
+
+func (x *SGXVMCallParams) GetValue() []byte {
+	if x != nil {
+		return x.Value
+	}
+	return nil
+}
+
+func (x *SGXVMCallParams) GetAccessList() []*AccessListItem {
+	if x != nil {
+		return x.AccessList
+	}
+	return nil
+}
+
+func (x *SGXVMCallParams) GetCommit() bool {
+	if x != nil {
+		return x.Commit
+	}
+	return false
+}
+
+func (x *SGXVMCallParams) GetNonce() uint64 {
+	if x != nil {
+		return x.Nonce
+	}
+	return 0
+}
+
+func (x *SGXVMCallParams) GetTxType() uint32 {
+	if x != nil {
+		return x.TxType
+	}
+	return 0
+}
+
+// Message with data required to execute `create` operation
+type SGXVMCreateParams struct {
+	state         protoimpl.MessageState
+	sizeCache     protoimpl.SizeCache
+	unknownFields protoimpl.UnknownFields
+
+	From       []byte            `protobuf:"bytes,1,opt,name=from,proto3" json:"from,omitempty"`
+	Data       []byte            `protobuf:"bytes,2,opt,name=data,proto3" json:"data,omitempty"`
+	GasLimit   uint64            `protobuf:"varint,3,opt,name=gasLimit,proto3" json:"gasLimit,omitempty"`
+	Value      []byte            `protobuf:"bytes,4,opt,name=value,proto3" json:"value,omitempty"`
+	AccessList []*AccessListItem `protobuf:"bytes,5,rep,name=accessList,proto3" json:"accessList,omitempty"`
+	Commit     bool              `protobuf:"varint,6,opt,name=commit,proto3" json:"commit,omitempty"`
+	// nonce of transaction. If `commit` is set, it should be equal to nonce of
+	// sender account. Nonce is incremented inside the enclave for both successful
+	// and failed transactions, so Go side should not increment it
+	Nonce uint64 `protobuf:"varint,7,opt,name=nonce,proto3" json:"nonce,omitempty"`
+	// type of transaction used for receipt encoding: 0 - legacy, 1 - EIP-2930,
+	// 2 - EIP-1559. Transactions of other types are rejected
+	TxType uint32 `protobuf:"varint,8,opt,name=txType,proto3" json:"txType,omitempty"`
+}
+
+func (x *SGXVMCreateParams) Reset() {
+	*x = SGXVMCreateParams{}
+	if protoimpl.UnsafeEnabled {
+		mi := &file_ffi_proto_msgTypes[39]
+		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
+		ms.StoreMessageInfo(mi)
+	}
+}
+
+func (x *SGXVMCreateParams) String() string {
+	return protoimpl.X.MessageStringOf(x)
+}
+
+func (*SGXVMCreateParams) ProtoMessage() {}
+
+func (x *SGXVMCreateParams) ProtoReflect() protoreflect.Message {
+	mi := &file_ffi_proto_msgTypes[39]
+	if protoimpl.UnsafeEnabled && x != nil {
+		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
+		if ms.LoadMessageInfo() == nil {
+			ms.StoreMessageInfo(mi)
+		}
+		return ms
+	}
+	return mi.MessageOf(x)
+}
+
+// Deprecated: Use SGXVMCreateParams.ProtoReflect.Descriptor instead.
+func (*SGXVMCreateParams) Descriptor() ([]byte, []int) {
+	return file_ffi_proto_rawDescGZIP(), []int{39}
+}
+
+func (x *SGXVMCreateParams) GetFrom() []byte {
+	if x != nil {
+		return x.From
+	}
+	return nil
+}
+
+func (x *SGXVMCreateParams) GetData() []byte {
+	if x != nil {
+		return x.Data
+	}
+	return nil
+}
+
+func (x *SGXVMCreateParams) GetGasLimit() uint64 {
+	if x != nil {
+		return x.GasLimit
+	}
+	return 0
+}
+
+func (x *SGXVMCreateParams) GetValue() []byte {
+	if x != nil {
+		return x.Value
+	}
+	return nil
+}
+
+func (x *SGXVMCreateParams) GetAccessList() []*AccessListItem {
+	if x != nil {
+		return x.AccessList
+	}
+	return nil
+}
+
+func (x *SGXVMCreateParams) GetCommit() bool {
+	if x != nil {
+		return x.Commit
+	}
+	return false
+}
+
+func (x *SGXVMCreateParams) GetNonce() uint64 {
+	if x != nil {
+		return x.Nonce
+	}
+	return 0
+}
+
+func (x *SGXVMCreateParams) GetTxType() uint32 {
+	if x != nil {
+		return x.TxType
+	}
+	return 0
+}
+
+// Request to execute `call` operation. Sender is provided by the host and is not
+// verified, while encrypted data is decrypted anyway. Only signed transactions
+// bind encrypted data to its sender
+type SGXVMCallRequest struct {
+	state         protoimpl.MessageState
+	sizeCache     protoimpl.SizeCache
+	unknownFields protoimpl.UnknownFields
+
+	Params  *SGXVMCallParams    `protobuf:"bytes,1,opt,name=params,proto3" json:"params,omitempty"`
+	Context *TransactionContext `protobuf:"bytes,2,opt,name=context,proto3" json:"context,omitempty"`
+}
+
+func (x *SGXVMCallRequest) Reset() {
+	*x = SGXVMCallRequest{}
+	if protoimpl.UnsafeEnabled {
+		mi := &file_ffi_proto_msgTypes[40]
+		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
+		ms.StoreMessageInfo(mi)
+	}
+}
+
+func (x *SGXVMCallRequest) String() string {
+	return protoimpl.X.MessageStringOf(x)
+}
+
+func (*SGXVMCallRequest) ProtoMessage() {}
+
+func (x *SGXVMCallRequest) ProtoReflect() protoreflect.Message {
+	mi := &file_ffi_proto_msgTypes[40]
+	if protoimpl.UnsafeEnabled && x != nil {
+		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
+		if ms.LoadMessageInfo() == nil {
+			ms.StoreMessageInfo(mi)
+		}
+		return ms
+	}
+	return mi.MessageOf(x)
+}
+
+// Deprecated: Use SGXVMCallRequest.ProtoReflect.Descriptor instead.
+func (*SGXVMCallRequest) Descriptor() ([]byte, []int) {
+	return file_ffi_proto_rawDescGZIP(), []int{40}
+}
+
+func (x *SGXVMCallRequest) GetParams() *SGXVMCallParams {
+	if x != nil {
+		return x.Params
+	}
+	return nil
+}
+
+func (x *SGXVMCallRequest) GetContext() *TransactionContext {
+	if x != nil {
+		return x.Context
+	}
+	return nil
+}
+
+// Request to execute `create` operation
+type SGXVMCreateRequest struct {
+	state         protoimpl.MessageState
+	sizeCache     protoimpl.SizeCache
+	unknownFields protoimpl.UnknownFields
+
+	Params  *SGXVMCreateParams  `protobuf:"bytes,1,opt,name=params,proto3" json:"params,omitempty"`
+	Context *TransactionContext `protobuf:"bytes,2,opt,name=context,proto3" json:"context,omitempty"`
+}
+
+func (x *SGXVMCreateRequest) Reset() {
+	*x = SGXVMCreateRequest{}
+	if protoimpl.UnsafeEnabled {
+		mi := &file_ffi_proto_msgTypes[41]
+		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
+		ms.StoreMessageInfo(mi)
+	}
+}
+
+func (x *SGXVMCreateRequest) String() string {
+	return protoimpl.X.MessageStringOf(x)
+}
+
+func (*SGXVMCreateRequest) ProtoMessage() {}
+
+func (x *SGXVMCreateRequest) ProtoReflect() protoreflect.Message {
+	mi := &file_ffi_proto_msgTypes[41]
+	if protoimpl.UnsafeEnabled && x != nil {
+		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
+		if ms.LoadMessageInfo() == nil {
+			ms.StoreMessageInfo(mi)
+		}
+		return ms
+	}
+	return mi.MessageOf(x)
+}
+
+// Deprecated: Use SGXVMCreateRequest.ProtoReflect.Descriptor instead.
+func (*SGXVMCreateRequest) Descriptor() ([]byte, []int) {
+	return file_ffi_proto_rawDescGZIP(), []int{41}
+}
+
+func (x *SGXVMCreateRequest) GetParams() *SGXVMCreateParams {
+	if x != nil {
+		return x.Params
+	}
+	return nil
+}
+
+func (x *SGXVMCreateRequest) GetContext() *TransactionContext {
+	if x != nil {
+		return x.Context
+	}
+	return nil
+}
+
+// Frame of internal call tree collected by call tracer
+type CallFrame struct {
+	state         protoimpl.MessageState
+	sizeCache     protoimpl.SizeCache
+	unknownFields protoimpl.UnknownFields
+
+	// CALL, STATICCALL, DELEGATECALL, CALLCODE, CREATE, CREATE2 or SELFDESTRUCT
+	Type  string `protobuf:"bytes,1,opt,name=type,proto3" json:"type,omitempty"`
+	From  []byte `protobuf:"bytes,2,opt,name=from,proto3" json:"from,omitempty"`
+	To    []byte `protobuf:"bytes,3,opt,name=to,proto3" json:"to,omitempty"`
+	Value []byte `protobuf:"bytes,4,opt,name=value,proto3" json:"value,omitempty"`
+	// gas provided to the frame
+	Gas uint64 `protobuf:"varint,5,opt,name=gas,proto3" json:"gas,omitempty"`
+	// call input. Encrypted to caller key for encrypted transactions. Empty for
+	// nested calls, since it can contain confidential contract state
+	Input []byte `protobuf:"bytes,6,opt,name=input,proto3" json:"input,omitempty"`
+	// call output. Encrypted to caller key for encrypted transactions. Empty for
+	// nested calls, since it can contain confidential contract state
+	Output []byte       `protobuf:"bytes,7,opt,name=output,proto3" json:"output,omitempty"`
+	Error  string       `protobuf:"bytes,8,opt,name=error,proto3" json:"error,omitempty"`
+	Calls  []*CallFrame `protobuf:"bytes,9,rep,name=calls,proto3" json:"calls,omitempty"`
+}
+
+func (x *CallFrame) Reset() {
+	*x = CallFrame{}
+	if protoimpl.UnsafeEnabled {
+		mi := &file_ffi_proto_msgTypes[42]
+		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
+		ms.StoreMessageInfo(mi)
+	}
+}
+
+func (x *CallFrame) String() string {
+	return protoimpl.X.MessageStringOf(x)
+}
+
+func (*CallFrame) ProtoMessage() {}
+
+func (x *CallFrame) ProtoReflect() protoreflect.Message {
+	mi := &file_ffi_proto_msgTypes[42]
+	if protoimpl.UnsafeEnabled && x != nil {
+		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
+		if ms.LoadMessageInfo() == nil {
+			ms.StoreMessageInfo(mi)
+		}
+		return ms
+	}
+	return mi.MessageOf(x)
+}
+
+// Deprecated: Use CallFrame.ProtoReflect.Descriptor instead.
+func (*CallFrame) Descriptor() ([]byte, []int) {
+	return file_ffi_proto_rawDescGZIP(), []int{42}
+}
+
+func (x *CallFrame) GetType() string {
+	if x != nil {
+		return x.Type
+	}
+	return ""
+}
+
+func (x *CallFrame) GetFrom() []byte {
+	if x != nil {
+		return x.From
+	}
+	return nil
+}
+
+func (x *CallFrame) GetTo() []byte {
+	if x != nil {
+		return x.To
+	}
+	return nil
+}
+
+func (x *CallFrame) GetValue() []byte {
+	if x != nil {
+		return x.Value
+	}
+	return nil
+}
+
+func (x *CallFrame) GetGas() uint64 {
+	if x != nil {
+		return x.Gas
+	}
+	return 0
+}
+
+func (x *CallFrame) GetInput() []byte {
+	if x != nil {
+		return x.Input
+	}
+	return nil
+}
+
+func (x *CallFrame) GetOutput() []byte {
+	if x != nil {
+		return x.Output
+	}
+	return nil
+}
+
+func (x *CallFrame) GetError() string {
+	if x != nil {
+		return x.Error
+	}
+	return ""
+}
+
+func (x *CallFrame) GetCalls() []*CallFrame {
+	if x != nil {
+		return x.Calls
+	}
+	return nil
+}
+
+// Response for traced call or create
+type TraceTransactionResponse struct {
+	state         protoimpl.MessageState
+	sizeCache     protoimpl.SizeCache
+	unknownFields protoimpl.UnknownFields
+
+	Result    *HandleTransactionResponse `protobuf:"bytes,1,opt,name=result,proto3" json:"result,omitempty"`
+	CallTrace *CallFrame                 `protobuf:"bytes,2,opt,name=callTrace,proto3" json:"callTrace,omitempty"`
+}
+
+func (x *TraceTransactionResponse) Reset() {
+	*x = TraceTransactionResponse{}
+	if protoimpl.UnsafeEnabled {
+		mi := &file_ffi_proto_msgTypes[43]
+		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
+		ms.StoreMessageInfo(mi)
+	}
+}
+
+func (x *TraceTransactionResponse) String() string {
+	return protoimpl.X.MessageStringOf(x)
+}
+
+func (*TraceTransactionResponse) ProtoMessage() {}
+
+func (x *TraceTransactionResponse) ProtoReflect() protoreflect.Message {
+	mi := &file_ffi_proto_msgTypes[43]
+	if protoimpl.UnsafeEnabled && x != nil {
+		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
+		if ms.LoadMessageInfo() == nil {
+			ms.StoreMessageInfo(mi)
+		}
+		return ms
+	}
+	return mi.MessageOf(x)
+}
+
+// Deprecated: Use TraceTransactionResponse.ProtoReflect.Descriptor instead.
+func (*TraceTransactionResponse) Descriptor() ([]byte, []int) {
+	return file_ffi_proto_rawDescGZIP(), []int{43}
+}
+
+func (x *TraceTransactionResponse) GetResult() *HandleTransactionResponse {
+	if x != nil {
+		return x.Result
+	}
+	return nil
+}
+
+func (x *TraceTransactionResponse) GetCallTrace() *CallFrame {
+	if x != nil {
+		return x.CallTrace
+	}
+	return nil
+}
+
+// Options of opcode-level struct logger. Memory and storage contain confidential
+// contract state, so requests with any of these options enabled are rejected
+type StructLoggerConfig struct {
+	state         protoimpl.MessageState
+	sizeCache     protoimpl.SizeCache
+	unknownFields protoimpl.UnknownFields
+
+	EnableMemory  bool `protobuf:"varint,1,opt,name=enableMemory,proto3" json:"enableMemory,omitempty"`
+	EnableStorage bool `protobuf:"varint,2,opt,name=enableStorage,proto3" json:"enableStorage,omitempty"`
+}
+
+func (x *StructLoggerConfig) Reset() {
+	*x = StructLoggerConfig{}
+	if protoimpl.UnsafeEnabled {
+		mi := &file_ffi_proto_msgTypes[44]
+		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
+		ms.StoreMessageInfo(mi)
+	}
+}
+
+func (x *StructLoggerConfig) String() string {
+	return protoimpl.X.MessageStringOf(x)
+}
+
+func (*StructLoggerConfig) ProtoMessage() {}
+
+func (x *StructLoggerConfig) ProtoReflect() protoreflect.Message {
+	mi := &file_ffi_proto_msgTypes[44]
+	if protoimpl.UnsafeEnabled && x != nil {
+		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
+		if ms.LoadMessageInfo() == nil {
+			ms.StoreMessageInfo(mi)
+		}
+		return ms
+	}
+	return mi.MessageOf(x)
+}
+
+// Deprecated: Use StructLoggerConfig.ProtoReflect.Descriptor instead.
+func (*StructLoggerConfig) Descriptor() ([]byte, []int) {
+	return file_ffi_proto_rawDescGZIP(), []int{44}
+}
+
+func (x *StructLoggerConfig) GetEnableMemory() bool {
+	if x != nil {
+		return x.EnableMemory
+	}
+	return false
+}
+
+func (x *StructLoggerConfig) GetEnableStorage() bool {
+	if x != nil {
+		return x.EnableStorage
+	}
+	return false
+}
+
+// Request to execute call with attached struct logger. Struct logs are
+// returned only for non-commit calls with encrypted data. Each struct log
+// contains only opcode, gas and depth of the step, stack is never included.
+// Amount of collected steps is limited
+type SGXVMStructLogRequest struct {
+	state         protoimpl.MessageState
+	sizeCache     protoimpl.SizeCache
+	unknownFields protoimpl.UnknownFields
+
+	Params  *SGXVMCallParams    `protobuf:"bytes,1,opt,name=params,proto3" json:"params,omitempty"`
+	Context *TransactionContext `protobuf:"bytes,2,opt,name=context,proto3" json:"context,omitempty"`
+	Config  *StructLoggerConfig `protobuf:"bytes,3,opt,name=config,proto3" json:"config,omitempty"`
+}
+
+func (x *SGXVMStructLogRequest) Reset() {
+	*x = SGXVMStructLogRequest{}
+	if protoimpl.UnsafeEnabled {
+		mi := &file_ffi_proto_msgTypes[45]
+		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
+		ms.StoreMessageInfo(mi)
+	}
+}
+
+func (x *SGXVMStructLogRequest) String() string {
+	return protoimpl.X.MessageStringOf(x)
+}
+
+func (*SGXVMStructLogRequest) ProtoMessage() {}
+
+func (x *SGXVMStructLogRequest) ProtoReflect() protoreflect.Message {
+	mi := &file_ffi_proto_msgTypes[45]
+	if protoimpl.UnsafeEnabled && x != nil {
+		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
+		if ms.LoadMessageInfo() == nil {
+			ms.StoreMessageInfo(mi)
+		}
+		return ms
+	}
+	return mi.MessageOf(x)
+}
+
+// Deprecated: Use SGXVMStructLogRequest.ProtoReflect.Descriptor instead.
+func (*SGXVMStructLogRequest) Descriptor() ([]byte, []int) {
+	return file_ffi_proto_rawDescGZIP(), []int{45}
+}
+
+func (x *SGXVMStructLogRequest) GetParams() *SGXVMCallParams {
+	if x != nil {
+		return x.Params
+	}
+	return nil
+}
+
+func (x *SGXVMStructLogRequest) GetContext() *TransactionContext {
+	if x != nil {
+		return x.Context
+	}
+	return nil
+}
+
+func (x *SGXVMStructLogRequest) GetConfig() *StructLoggerConfig {
+	if x != nil {
+		return x.Config
+	}
+	return nil
+}
+
+type StorageEntry struct {
+	state         protoimpl.MessageState
+	sizeCache     protoimpl.SizeCache
+	unknownFields protoimpl.UnknownFields
+
+	Key   []byte `protobuf:"bytes,1,opt,name=key,proto3" json:"key,omitempty"`
+	Value []byte `protobuf:"bytes,2,opt,name=value,proto3" json:"value,omitempty"`
+}
+
+func (x *StorageEntry) Reset() {
+	*x = StorageEntry{}
+	if protoimpl.UnsafeEnabled {
+		mi := &file_ffi_proto_msgTypes[46]
+		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
+		ms.StoreMessageInfo(mi)
+	}
+}
+
+func (x *StorageEntry) String() string {
+	return protoimpl.X.MessageStringOf(x)
+}
+
+func (*StorageEntry) ProtoMessage() {}
+
+func (x *StorageEntry) ProtoReflect() protoreflect.Message {
+	mi := &file_ffi_proto_msgTypes[46]
+	if protoimpl.UnsafeEnabled && x != nil {
+		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
+		if ms.LoadMessageInfo() == nil {
+			ms.StoreMessageInfo(mi)
+		}
+		return ms
+	}
+	return mi.MessageOf(x)
+}
+
+// Deprecated: Use StorageEntry.ProtoReflect.Descriptor instead.
+func (*StorageEntry) Descriptor() ([]byte, []int) {
+	return file_ffi_proto_rawDescGZIP(), []int{46}
+}
+
+func (x *StorageEntry) GetKey() []byte {
+	if x != nil {
+		return x.Key
+	}
+	return nil
+}
+
+func (x *StorageEntry) GetValue() []byte {
+	if x != nil {
+		return x.Value
+	}
+	return nil
+}
+
+// Single step of evm execution
+type StructLog struct {
+	state         protoimpl.MessageState
+	sizeCache     protoimpl.SizeCache
+	unknownFields protoimpl.UnknownFields
+
+	Pc      uint64          `protobuf:"varint,1,opt,name=pc,proto3" json:"pc,omitempty"`
+	Op      string          `protobuf:"bytes,2,opt,name=op,proto3" json:"op,omitempty"`
+	Gas     uint64          `protobuf:"varint,3,opt,name=gas,proto3" json:"gas,omitempty"`
+	GasCost uint64          `protobuf:"varint,4,opt,name=gasCost,proto3" json:"gasCost,omitempty"`
+	Depth   uint64          `protobuf:"varint,5,opt,name=depth,proto3" json:"depth,omitempty"`
+	Stack   [][]byte        `protobuf:"bytes,6,rep,name=stack,proto3" json:"stack,omitempty"`
+	Memory  []byte          `protobuf:"bytes,7,opt,name=memory,proto3" json:"memory,omitempty"`
+	Storage []*StorageEntry `protobuf:"bytes,8,rep,name=storage,proto3" json:"storage,omitempty"`
+	Error   string          `protobuf:"bytes,9,opt,name=error,proto3" json:"error,omitempty"`
+}
+
+func (x *StructLog) Reset() {
+	*x = StructLog{}
+	if protoimpl.UnsafeEnabled {
+		mi := &file_ffi_proto_msgTypes[47]
+		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
+		ms.StoreMessageInfo(mi)
+	}
+}
+
+func (x *StructLog) String() string {
+	return protoimpl.X.MessageStringOf(x)
+}
+
+func (*StructLog) ProtoMessage() {}
+
+func (x *StructLog) ProtoReflect() protoreflect.Message {
+	mi := &file_ffi_proto_msgTypes[47]
+	if protoimpl.UnsafeEnabled && x != nil {
+		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
+		if ms.LoadMessageInfo() == nil {
+			ms.StoreMessageInfo(mi)
+		}
+		return ms
+	}
+	return mi.MessageOf(x)
+}
+
+// Deprecated: Use StructLog.ProtoReflect.Descriptor instead.
+func (*StructLog) Descriptor() ([]byte, []int) {
+	return file_ffi_proto_rawDescGZIP(), []int{47}
+}
+
+func (x *StructLog) GetPc() uint64 {
+	if x != nil {
+		return x.Pc
+	}
+	return 0
+}
+
+func (x *StructLog) GetOp() string {
+	if x != nil {
+		return x.Op
+	}
+	return ""
+}
+
+func (x *StructLog) GetGas() uint64 {
+	if x != nil {
+		return x.Gas
+	}
+	return 0
+}
+
+func (x *StructLog) GetGasCost() uint64 {
+	if x != nil {
+		return x.GasCost
+	}
+	return 0
+}
+
+func (x *StructLog) GetDepth() uint64 {
+	if x != nil {
+		return x.Depth
+	}
+	return 0
+}
+
+func (x *StructLog) GetStack() [][]byte {
+	if x != nil {
+		return x.Stack
+	}
+	return nil
+}
+
+func (x *StructLog) GetMemory() []byte {
+	if x != nil {
+		return x.Memory
+	}
+	return nil
+}
+
+func (x *StructLog) GetStorage() []*StorageEntry {
+	if x != nil {
+		return x.Storage
+	}
+	return nil
+}
+
+func (x *StructLog) GetError() string {
+	if x != nil {
+		return x.Error
+	}
+	return ""
+}
+
+type StructLogs struct {
+	state         protoimpl.MessageState
+	sizeCache     protoimpl.SizeCache
+	unknownFields protoimpl.UnknownFields
+
+	Logs []*StructLog `protobuf:"bytes,1,rep,name=logs,proto3" json:"logs,omitempty"`
+}
+
+func (x *StructLogs) Reset() {
+	*x = StructLogs{}
+	if protoimpl.UnsafeEnabled {
+		mi := &file_ffi_proto_msgTypes[48]
+		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
+		ms.StoreMessageInfo(mi)
+	}
+}
+
+func (x *StructLogs) String() string {
+	return protoimpl.X.MessageStringOf(x)
+}
+
+func (*StructLogs) ProtoMessage() {}
+
+func (x *StructLogs) ProtoReflect() protoreflect.Message {
+	mi := &file_ffi_proto_msgTypes[48]
+	if protoimpl.UnsafeEnabled && x != nil {
+		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
+		if ms.LoadMessageInfo() == nil {
+			ms.StoreMessageInfo(mi)
+		}
+		return ms
+	}
+	return mi.MessageOf(x)
+}
+
+// Deprecated: Use StructLogs.ProtoReflect.Descriptor instead.
+func (*StructLogs) Descriptor() ([]byte, []int) {
+	return file_ffi_proto_rawDescGZIP(), []int{48}
+}
+
+func (x *StructLogs) GetLogs() []*StructLog {
+	if x != nil {
+		return x.Logs
+	}
+	return nil
+}
+
+// Response for call with attached struct logger
+type StructLogResponse struct {
+	state         protoimpl.MessageState
+	sizeCache     protoimpl.SizeCache
+	unknownFields protoimpl.UnknownFields
+
+	Result *HandleTransactionResponse `protobuf:"bytes,1,opt,name=result,proto3" json:"result,omitempty"`
+	// protobuf-encoded `StructLogs` encrypted to caller key
+	StructLogs []byte `protobuf:"bytes,2,opt,name=structLogs,proto3" json:"structLogs,omitempty"`
+}
+
+func (x *StructLogResponse) Reset() {
+	*x = StructLogResponse{}
+	if protoimpl.UnsafeEnabled {
+		mi := &file_ffi_proto_msgTypes[49]
+		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
+		ms.StoreMessageInfo(mi)
+	}
+}
+
+func (x *StructLogResponse) String() string {
+	return protoimpl.X.MessageStringOf(x)
+}
+
+func (*StructLogResponse) ProtoMessage() {}
+
+func (x *StructLogResponse) ProtoReflect() protoreflect.Message {
+	mi := &file_ffi_proto_msgTypes[49]
+	if protoimpl.UnsafeEnabled && x != nil {
+		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
+		if ms.LoadMessageInfo() == nil {
+			ms.StoreMessageInfo(mi)
+		}
+		return ms
+	}
+	return mi.MessageOf(x)
+}
+
+// Deprecated: Use StructLogResponse.ProtoReflect.Descriptor instead.
+func (*StructLogResponse) Descriptor() ([]byte, []int) {
+	return file_ffi_proto_rawDescGZIP(), []int{49}
+}
+
+func (x *StructLogResponse) GetResult() *HandleTransactionResponse {
+	if x != nil {
+		return x.Result
+	}
+	return nil
+}
+
+func (x *StructLogResponse) GetStructLogs() []byte {
+	if x != nil {
+		return x.StructLogs
+	}
+	return nil
+}
+
+// Request to estimate minimal gas limit of the transaction. If `to` field
+// of params is empty, gas is estimated for contract creation
+type SGXVMEstimateGasRequest struct {
+	state         protoimpl.MessageState
+	sizeCache     protoimpl.SizeCache
+	unknownFields protoimpl.UnknownFields
+
+	Params  *SGXVMCallParams    `protobuf:"bytes,1,opt,name=params,proto3" json:"params,omitempty"`
+	Context *TransactionContext `protobuf:"bytes,2,opt,name=context,proto3" json:"context,omitempty"`
+}
+
+func (x *SGXVMEstimateGasRequest) Reset() {
+	*x = SGXVMEstimateGasRequest{}
+	if protoimpl.UnsafeEnabled {
+		mi := &file_ffi_proto_msgTypes[50]
+		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
+		ms.StoreMessageInfo(mi)
+	}
+}
+
+func (x *SGXVMEstimateGasRequest) String() string {
+	return protoimpl.X.MessageStringOf(x)
+}
+
+func (*SGXVMEstimateGasRequest) ProtoMessage() {}
+
+func (x *SGXVMEstimateGasRequest) ProtoReflect() protoreflect.Message {
+	mi := &file_ffi_proto_msgTypes[50]
+	if protoimpl.UnsafeEnabled && x != nil {
+		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
+		if ms.LoadMessageInfo() == nil {
+			ms.StoreMessageInfo(mi)
+		}
+		return ms
+	}
+	return mi.MessageOf(x)
+}
+
+// Deprecated: Use SGXVMEstimateGasRequest.ProtoReflect.Descriptor instead.
+func (*SGXVMEstimateGasRequest) Descriptor() ([]byte, []int) {
+	return file_ffi_proto_rawDescGZIP(), []int{50}
+}
+
+func (x *SGXVMEstimateGasRequest) GetParams() *SGXVMCallParams {
+	if x != nil {
+		return x.Params
+	}
+	return nil
+}
+
+func (x *SGXVMEstimateGasRequest) GetContext() *TransactionContext {
+	if x != nil {
+		return x.Context
+	}
+	return nil
+}
+
+type EstimateGasResponse struct {
+	state         protoimpl.MessageState
+	sizeCache     protoimpl.SizeCache
+	unknownFields protoimpl.UnknownFields
+
+	// minimal gas limit with which transaction succeeds. Zero if transaction
+	// fails even with the highest gas limit
+	Gas uint64 `protobuf:"varint,1,opt,name=gas,proto3" json:"gas,omitempty"`
+	// error returned by execution with the highest failed gas limit
+	VmError string `protobuf:"bytes,2,opt,name=vm_error,json=vmError,proto3" json:"vm_error,omitempty"`
+	// data returned by execution with the highest failed gas limit
+	Ret []byte `protobuf:"bytes,3,opt,name=ret,proto3" json:"ret,omitempty"`
+}
+
+func (x *EstimateGasResponse) Reset() {
+	*x = EstimateGasResponse{}
+	if protoimpl.UnsafeEnabled {
+		mi := &file_ffi_proto_msgTypes[51]
+		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
+		ms.StoreMessageInfo(mi)
+	}
+}
+
+func (x *EstimateGasResponse) String() string {
+	return protoimpl.X.MessageStringOf(x)
+}
+
+func (*EstimateGasResponse) ProtoMessage() {}
+
+func (x *EstimateGasResponse) ProtoReflect() protoreflect.Message {
+	mi := &file_ffi_proto_msgTypes[51]
+	if protoimpl.UnsafeEnabled && x != nil {
+		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
+		if ms.LoadMessageInfo() == nil {
+			ms.StoreMessageInfo(mi)
+		}
+		return ms
+	}
+	return mi.MessageOf(x)
+}
+
+// Deprecated: Use EstimateGasResponse.ProtoReflect.Descriptor instead.
+func (*EstimateGasResponse) Descriptor() ([]byte, []int) {
+	return file_ffi_proto_rawDescGZIP(), []int{51}
+}
+
+func (x *EstimateGasResponse) GetGas() uint64 {
+	if x != nil {
+		return x.Gas
+	}
+	return 0
+}
+
+func (x *EstimateGasResponse) GetVmError() string {
+	if x != nil {
+		return x.VmError
+	}
+	return ""
+}
+
+func (x *EstimateGasResponse) GetRet() []byte {
+	if x != nil {
+		return x.Ret
+	}
+	return nil
+}
+
+type SGXVMStateDiffRequest struct {
+	state         protoimpl.MessageState
+	sizeCache     protoimpl.SizeCache
+	unknownFields protoimpl.UnknownFields
+
+	// if `to` is empty, contract creation is simulated
+	Params  *SGXVMCallParams    `protobuf:"bytes,1,opt,name=params,proto3" json:"params,omitempty"`
+	Context *TransactionContext `protobuf:"bytes,2,opt,name=context,proto3" json:"context,omitempty"`
+}
+
+func (x *SGXVMStateDiffRequest) Reset() {
+	*x = SGXVMStateDiffRequest{}
+	if protoimpl.UnsafeEnabled {
+		mi := &file_ffi_proto_msgTypes[52]
+		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
+		ms.StoreMessageInfo(mi)
+	}
+}
+
+func (x *SGXVMStateDiffRequest) String() string {
+	return protoimpl.X.MessageStringOf(x)
+}
+
+func (*SGXVMStateDiffRequest) ProtoMessage() {}
+
+func (x *SGXVMStateDiffRequest) ProtoReflect() protoreflect.Message {
+	mi := &file_ffi_proto_msgTypes[52]
+	if protoimpl.UnsafeEnabled && x != nil {
+		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
+		if ms.LoadMessageInfo() == nil {
+			ms.StoreMessageInfo(mi)
+		}
+		return ms
+	}
+	return mi.MessageOf(x)
+}
+
+// Deprecated: Use SGXVMStateDiffRequest.ProtoReflect.Descriptor instead.
+func (*SGXVMStateDiffRequest) Descriptor() ([]byte, []int) {
+	return file_ffi_proto_rawDescGZIP(), []int{52}
+}
+
+func (x *SGXVMStateDiffRequest) GetParams() *SGXVMCallParams {
+	if x != nil {
+		return x.Params
+	}
+	return nil
+}
+
+func (x *SGXVMStateDiffRequest) GetContext() *TransactionContext {
+	if x != nil {
+		return x.Context
+	}
+	return nil
+}
+
+type StorageDiff struct {
+	state         protoimpl.MessageState
+	sizeCache     protoimpl.SizeCache
+	unknownFields protoimpl.UnknownFields
+
+	Key []byte `protobuf:"bytes,1,opt,name=key,proto3" json:"key,omitempty"`
+	// storage values are always omitted, since contract storage is confidential
+	OriginalValue []byte `protobuf:"bytes,2,opt,name=originalValue,proto3" json:"originalValue,omitempty"`
+	Value         []byte `protobuf:"bytes,3,opt,name=value,proto3" json:"value,omitempty"`
+}
+
+func (x *StorageDiff) Reset() {
+	*x = StorageDiff{}
+	if protoimpl.UnsafeEnabled {
+		mi := &file_ffi_proto_msgTypes[53]
+		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
+		ms.StoreMessageInfo(mi)
+	}
+}
+
+func (x *StorageDiff) String() string {
+	return protoimpl.X.MessageStringOf(x)
+}
+
+func (*StorageDiff) ProtoMessage() {}
+
+func (x *StorageDiff) ProtoReflect() protoreflect.Message {
+	mi := &file_ffi_proto_msgTypes[53]
+	if protoimpl.UnsafeEnabled && x != nil {
+		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
+		if ms.LoadMessageInfo() == nil {
+			ms.StoreMessageInfo(mi)
+		}
+		return ms
+	}
+	return mi.MessageOf(x)
+}
+
+// Deprecated: Use StorageDiff.ProtoReflect.Descriptor instead.
+func (*StorageDiff) Descriptor() ([]byte, []int) {
+	return file_ffi_proto_rawDescGZIP(), []int{53}
+}
+
+func (x *StorageDiff) GetKey() []byte {
+	if x != nil {
+		return x.Key
+	}
+	return nil
+}
+
+func (x *StorageDiff) GetOriginalValue() []byte {
+	if x != nil {
+		return x.OriginalValue
+	}
+	return nil
+}
+
+func (x *StorageDiff) GetValue() []byte {
+	if x != nil {
+		return x.Value
+	}
+	return nil
+}
+
+type AccountDiff struct {
+	state         protoimpl.MessageState
+	sizeCache     protoimpl.SizeCache
+	unknownFields protoimpl.UnknownFields
+
+	Address       []byte `protobuf:"bytes,1,opt,name=address,proto3" json:"address,omitempty"`
+	BalanceBefore []byte `protobuf:"bytes,2,opt,name=balanceBefore,proto3" json:"balanceBefore,omitempty"`
+	BalanceAfter  []byte `protobuf:"bytes,3,opt,name=balanceAfter,proto3" json:"balanceAfter,omitempty"`
+	NonceBefore   uint64 `protobuf:"varint,4,opt,name=nonceBefore,proto3" json:"nonceBefore,omitempty"`
+	NonceAfter    uint64 `protobuf:"varint,5,opt,name=nonceAfter,proto3" json:"nonceAfter,omitempty"`
+	// new contract bytecode, empty if code was not changed
+	Code    []byte         `protobuf:"bytes,6,opt,name=code,proto3" json:"code,omitempty"`
+	Deleted bool           `protobuf:"varint,7,opt,name=deleted,proto3" json:"deleted,omitempty"`
+	Storage []*StorageDiff `protobuf:"bytes,8,rep,name=storage,proto3" json:"storage,omitempty"`
+}
+
+func (x *AccountDiff) Reset() {
+	*x = AccountDiff{}
+	if protoimpl.UnsafeEnabled {
+		mi := &file_ffi_proto_msgTypes[54]
+		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
+		ms.StoreMessageInfo(mi)
+	}
+}
+
+func (x *AccountDiff) String() string {
+	return protoimpl.X.MessageStringOf(x)
+}
+
+func (*AccountDiff) ProtoMessage() {}
+
+func (x *AccountDiff) ProtoReflect() protoreflect.Message {
+	mi := &file_ffi_proto_msgTypes[54]
+	if protoimpl.UnsafeEnabled && x != nil {
+		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
+		if ms.LoadMessageInfo() == nil {
+			ms.StoreMessageInfo(mi)
+		}
+		return ms
+	}
+	return mi.MessageOf(x)
+}
+
+// Deprecated: Use AccountDiff.ProtoReflect.Descriptor instead.
+func (*AccountDiff) Descriptor() ([]byte, []int) {
+	return file_ffi_proto_rawDescGZIP(), []int{54}
+}
+
+func (x *AccountDiff) GetAddress() []byte {
+	if x != nil {
+		return x.Address
+	}
+	return nil
+}
+
+func (x *AccountDiff) GetBalanceBefore() []byte {
+	if x != nil {
+		return x.BalanceBefore
+	}
+	return nil
+}
+
+func (x *AccountDiff) GetBalanceAfter() []byte {
+	if x != nil {
+		return x.BalanceAfter
+	}
+	return nil
+}
+
+func (x *AccountDiff) GetNonceBefore() uint64 {
+	if x != nil {
+		return x.NonceBefore
+	}
+	return 0
+}
+
+func (x *AccountDiff) GetNonceAfter() uint64 {
+	if x != nil {
+		return x.NonceAfter
+	}
+	return 0
+}
+
+func (x *AccountDiff) GetCode() []byte {
+	if x != nil {
+		return x.Code
+	}
+	return nil
+}
+
+func (x *AccountDiff) GetDeleted() bool {
+	if x != nil {
+		return x.Deleted
+	}
+	return false
+}
+
+func (x *AccountDiff) GetStorage() []*StorageDiff {
+	if x != nil {
+		return x.Storage
+	}
+	return nil
+}
+
+type StateDiffResponse struct {
+	state         protoimpl.MessageState
+	sizeCache     protoimpl.SizeCache
+	unknownFields protoimpl.UnknownFields
+
+	Result   *HandleTransactionResponse `protobuf:"bytes,1,opt,name=result,proto3" json:"result,omitempty"`
+	Accounts []*AccountDiff             `protobuf:"bytes,2,rep,name=accounts,proto3" json:"accounts,omitempty"`
+}
+
+func (x *StateDiffResponse) Reset() {
+	*x = StateDiffResponse{}
+	if protoimpl.UnsafeEnabled {
+		mi := &file_ffi_proto_msgTypes[55]
+		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
+		ms.StoreMessageInfo(mi)
+	}
+}
+
+func (x *StateDiffResponse) String() string {
+	return protoimpl.X.MessageStringOf(x)
+}
+
+func (*StateDiffResponse) ProtoMessage() {}
+
+func (x *StateDiffResponse) ProtoReflect() protoreflect.Message {
+	mi := &file_ffi_proto_msgTypes[55]
+	if protoimpl.UnsafeEnabled && x != nil {
+		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
+		if ms.LoadMessageInfo() == nil {
+			ms.StoreMessageInfo(mi)
+		}
+		return ms
+	}
+	return mi.MessageOf(x)
+}
+
+// Deprecated: Use StateDiffResponse.ProtoReflect.Descriptor instead.
+func (*StateDiffResponse) Descriptor() ([]byte, []int) {
+	return file_ffi_proto_rawDescGZIP(), []int{55}
+}
+
+func (x *StateDiffResponse) GetResult() *HandleTransactionResponse {
+	if x != nil {
+		return x.Result
+	}
+	return nil
+}
+
+func (x *StateDiffResponse) GetAccounts() []*AccountDiff {
+	if x != nil {
+		return x.Accounts
+	}
+	return nil
+}
+
+type SGXVMCreateAccessListRequest struct {
+	state         protoimpl.MessageState
+	sizeCache     protoimpl.SizeCache
+	unknownFields protoimpl.UnknownFields
+
+	// if `to` is empty, access list is generated for contract creation
+	Params  *SGXVMCallParams    `protobuf:"bytes,1,opt,name=params,proto3" json:"params,omitempty"`
+	Context *TransactionContext `protobuf:"bytes,2,opt,name=context,proto3" json:"context,omitempty"`
+}
+
+func (x *SGXVMCreateAccessListRequest) Reset() {
+	*x = SGXVMCreateAccessListRequest{}
+	if protoimpl.UnsafeEnabled {
+		mi := &file_ffi_proto_msgTypes[56]
+		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
+		ms.StoreMessageInfo(mi)
+	}
+}
+
+func (x *SGXVMCreateAccessListRequest) String() string {
+	return protoimpl.X.MessageStringOf(x)
+}
+
+func (*SGXVMCreateAccessListRequest) ProtoMessage() {}
+
+func (x *SGXVMCreateAccessListRequest) ProtoReflect() protoreflect.Message {
+	mi := &file_ffi_proto_msgTypes[56]
+	if protoimpl.UnsafeEnabled && x != nil {
+		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
+		if ms.LoadMessageInfo() == nil {
+			ms.StoreMessageInfo(mi)
+		}
+		return ms
+	}
+	return mi.MessageOf(x)
+}
+
+// Deprecated: Use SGXVMCreateAccessListRequest.ProtoReflect.Descriptor instead.
+func (*SGXVMCreateAccessListRequest) Descriptor() ([]byte, []int) {
+	return file_ffi_proto_rawDescGZIP(), []int{56}
+}
+
+func (x *SGXVMCreateAccessListRequest) GetParams() *SGXVMCallParams {
+	if x != nil {
+		return x.Params
+	}
+	return nil
+}
+
+func (x *SGXVMCreateAccessListRequest) GetContext() *TransactionContext {
+	if x != nil {
+		return x.Context
+	}
+	return nil
+}
+
+type CreateAccessListResponse struct {
+	state         protoimpl.MessageState
+	sizeCache     protoimpl.SizeCache
+	unknownFields protoimpl.UnknownFields
+
+	// result of execution with generated access list applied
+	Result     *HandleTransactionResponse `protobuf:"bytes,1,opt,name=result,proto3" json:"result,omitempty"`
+	AccessList []*AccessListItem          `protobuf:"bytes,2,rep,name=accessList,proto3" json:"accessList,omitempty"`
+}
+
+func (x *CreateAccessListResponse) Reset() {
+	*x = CreateAccessListResponse{}
+	if protoimpl.UnsafeEnabled {
+		mi := &file_ffi_proto_msgTypes[57]
+		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
+		ms.StoreMessageInfo(mi)
+	}
+}
+
+func (x *CreateAccessListResponse) String() string {
+	return protoimpl.X.MessageStringOf(x)
+}
+
+func (*CreateAccessListResponse) ProtoMessage() {}
+
+func (x *CreateAccessListResponse) ProtoReflect() protoreflect.Message {
+	mi := &file_ffi_proto_msgTypes[57]
+	if protoimpl.UnsafeEnabled && x != nil {
+		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
+		if ms.LoadMessageInfo() == nil {
+			ms.StoreMessageInfo(mi)
+		}
+		return ms
+	}
+	return mi.MessageOf(x)
+}
+
+// Deprecated: Use CreateAccessListResponse.ProtoReflect.Descriptor instead.
+func (*CreateAccessListResponse) Descriptor() ([]byte, []int) {
+	return file_ffi_proto_rawDescGZIP(), []int{57}
+}
+
+func (x *CreateAccessListResponse) GetResult() *HandleTransactionResponse {
+	if x != nil {
+		return x.Result
+	}
+	return nil
+}
+
+func (x *CreateAccessListResponse) GetAccessList() []*AccessListItem {
+	if x != nil {
+		return x.AccessList
+	}
+	return nil
+}
+
+// Transaction executed as part of a batch
+type SGXVMBatchTransaction struct {
+	state         protoimpl.MessageState
+	sizeCache     protoimpl.SizeCache
+	unknownFields protoimpl.UnknownFields
+
+	// Types that are assignable to Tx:
+	//	*SGXVMBatchTransaction_Call
+	//	*SGXVMBatchTransaction_Create
+	Tx isSGXVMBatchTransaction_Tx `protobuf_oneof:"tx"`
+}
+
+func (x *SGXVMBatchTransaction) Reset() {
+	*x = SGXVMBatchTransaction{}
+	if protoimpl.UnsafeEnabled {
+		mi := &file_ffi_proto_msgTypes[58]
+		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
+		ms.StoreMessageInfo(mi)
+	}
+}
+
+func (x *SGXVMBatchTransaction) String() string {
+	return protoimpl.X.MessageStringOf(x)
+}
+
+func (*SGXVMBatchTransaction) ProtoMessage() {}
+
+func (x *SGXVMBatchTransaction) ProtoReflect() protoreflect.Message {
+	mi := &file_ffi_proto_msgTypes[58]
+	if protoimpl.UnsafeEnabled && x != nil {
+		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
+		if ms.LoadMessageInfo() == nil {
+			ms.StoreMessageInfo(mi)
+		}
+		return ms
+	}
+	return mi.MessageOf(x)
+}
+
+// Deprecated: Use SGXVMBatchTransaction.ProtoReflect.Descriptor instead.
+func (*SGXVMBatchTransaction) Descriptor() ([]byte, []int) {
+	return file_ffi_proto_rawDescGZIP(), []int{58}
+}
+
+func (m *SGXVMBatchTransaction) GetTx() isSGXVMBatchTransaction_Tx {
+	if m != nil {
+		return m.Tx
+	}
+	return nil
+}
+
+func (x *SGXVMBatchTransaction) GetCall() *SGXVMCallParams {
+	if x, ok := x.GetTx().(*SGXVMBatchTransaction_Call); ok {
+		return x.Call
+	}
+	return nil
+}
+
+func (x *SGXVMBatchTransaction) GetCreate() *SGXVMCreateParams {
+	if x, ok := x.GetTx().(*SGXVMBatchTransaction_Create); ok {
+		return x.Create
+	}
+	return nil
+}
+
+type isSGXVMBatchTransaction_Tx interface {
+	isSGXVMBatchTransaction_Tx()
+}
+
+type SGXVMBatchTransaction_Call struct {
+	Call *SGXVMCallParams `protobuf:"bytes,1,opt,name=call,proto3,oneof"`
+}
+
+type SGXVMBatchTransaction_Create struct {
+	Create *SGXVMCreateParams `protobuf:"bytes,2,opt,name=create,proto3,oneof"`
+}
+
+func (*SGXVMBatchTransaction_Call) isSGXVMBatchTransaction_Tx() {}
+
+func (*SGXVMBatchTransaction_Create) isSGXVMBatchTransaction_Tx() {}
+
+// Request to execute ordered list of transactions against the same block
+// context. State changes are written to the keeper once all transactions are
+// executed
+type SGXVMBatchRequest struct {
+	state         protoimpl.MessageState
+	sizeCache     protoimpl.SizeCache
+	unknownFields protoimpl.UnknownFields
+
+	Transactions []*SGXVMBatchTransaction `protobuf:"bytes,1,rep,name=transactions,proto3" json:"transactions,omitempty"`
+	Context      *TransactionContext      `protobuf:"bytes,2,opt,name=context,proto3" json:"context,omitempty"`
+}
+
+func (x *SGXVMBatchRequest) Reset() {
+	*x = SGXVMBatchRequest{}
+	if protoimpl.UnsafeEnabled {
+		mi := &file_ffi_proto_msgTypes[59]
+		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
+		ms.StoreMessageInfo(mi)
+	}
+}
+
+func (x *SGXVMBatchRequest) String() string {
+	return protoimpl.X.MessageStringOf(x)
+}
+
+func (*SGXVMBatchRequest) ProtoMessage() {}
+
+func (x *SGXVMBatchRequest) ProtoReflect() protoreflect.Message {
+	mi := &file_ffi_proto_msgTypes[59]
+	if protoimpl.UnsafeEnabled && x != nil {
+		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
+		if ms.LoadMessageInfo() == nil {
+			ms.StoreMessageInfo(mi)
+		}
+		return ms
+	}
+	return mi.MessageOf(x)
+}
+
+// Deprecated: Use SGXVMBatchRequest.ProtoReflect.Descriptor instead.
+func (*SGXVMBatchRequest) Descriptor() ([]byte, []int) {
+	return file_ffi_proto_rawDescGZIP(), []int{59}
+}
+
+func (x *SGXVMBatchRequest) GetTransactions() []*SGXVMBatchTransaction {
+	if x != nil {
+		return x.Transactions
+	}
+	return nil
+}
+
+func (x *SGXVMBatchRequest) GetContext() *TransactionContext {
+	if x != nil {
+		return x.Context
+	}
+	return nil
+}
+
+type BatchTransactionResult struct {
+	state         protoimpl.MessageState
+	sizeCache     protoimpl.SizeCache
+	unknownFields protoimpl.UnknownFields
+
+	Result *HandleTransactionResponse `protobuf:"bytes,1,opt,name=result,proto3" json:"result,omitempty"`
+	// gas used by this and all previous transactions of the block
+	CumulativeGasUsed uint64 `protobuf:"varint,2,opt,name=cumulativeGasUsed,proto3" json:"cumulativeGasUsed,omitempty"`
+}
+
+func (x *BatchTransactionResult) Reset() {
+	*x = BatchTransactionResult{}
+	if protoimpl.UnsafeEnabled {
+		mi := &file_ffi_proto_msgTypes[60]
+		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
+		ms.StoreMessageInfo(mi)
+	}
+}
+
+func (x *BatchTransactionResult) String() string {
+	return protoimpl.X.MessageStringOf(x)
+}
+
+func (*BatchTransactionResult) ProtoMessage() {}
+
+func (x *BatchTransactionResult) ProtoReflect() protoreflect.Message {
+	mi := &file_ffi_proto_msgTypes[60]
+	if protoimpl.UnsafeEnabled && x != nil {
+		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
+		if ms.LoadMessageInfo() == nil {
+			ms.StoreMessageInfo(mi)
+		}
+		return ms
+	}
+	return mi.MessageOf(x)
+}
+
+// Deprecated: Use BatchTransactionResult.ProtoReflect.Descriptor instead.
+func (*BatchTransactionResult) Descriptor() ([]byte, []int) {
+	return file_ffi_proto_rawDescGZIP(), []int{60}
+}
+
+func (x *BatchTransactionResult) GetResult() *HandleTransactionResponse {
+	if x != nil {
+		return x.Result
+	}
+	return nil
+}
+
+func (x *BatchTransactionResult) GetCumulativeGasUsed() uint64 {
+	if x != nil {
+		return x.CumulativeGasUsed
+	}
+	return 0
+}
+
+type BatchResponse struct {
+	state         protoimpl.MessageState
+	sizeCache     protoimpl.SizeCache
+	unknownFields protoimpl.UnknownFields
+
+	Results []*BatchTransactionResult `protobuf:"bytes,1,rep,name=results,proto3" json:"results,omitempty"`
+}
+
+func (x *BatchResponse) Reset() {
+	*x = BatchResponse{}
+	if protoimpl.UnsafeEnabled {
+		mi := &file_ffi_proto_msgTypes[61]
+		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
+		ms.StoreMessageInfo(mi)
+	}
+}
+
+func (x *BatchResponse) String() string {
+	return protoimpl.X.MessageStringOf(x)
+}
+
+func (*BatchResponse) ProtoMessage() {}
+
+func (x *BatchResponse) ProtoReflect() protoreflect.Message {
+	mi := &file_ffi_proto_msgTypes[61]
+	if protoimpl.UnsafeEnabled && x != nil {
+		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
+		if ms.LoadMessageInfo() == nil {
+			ms.StoreMessageInfo(mi)
+		}
+		return ms
+	}
+	return mi.MessageOf(x)
+}
+
+// Deprecated: Use BatchResponse.ProtoReflect.Descriptor instead.
+func (*BatchResponse) Descriptor() ([]byte, []int) {
+	return file_ffi_proto_rawDescGZIP(), []int{61}
+}
+
+func (x *BatchResponse) GetResults() []*BatchTransactionResult {
+	if x != nil {
+		return x.Results
+	}
+	return nil
+}
+
+// Request to execute signed Ethereum transaction. Sender is recovered from
+// transaction signature inside the enclave, transaction data is decrypted after
+// signature verification. Chain id of context should match chain id pinned in
+// the enclave at build time
+type SGXVMSignedTransactionRequest struct {
+	state         protoimpl.MessageState
+	sizeCache     protoimpl.SizeCache
+	unknownFields protoimpl.UnknownFields
+
+	// RLP-encoded signed legacy, EIP-2930 or EIP-1559 transaction
+	RawTransaction []byte              `protobuf:"bytes,1,opt,name=rawTransaction,proto3" json:"rawTransaction,omitempty"`
+	Context        *TransactionContext `protobuf:"bytes,2,opt,name=context,proto3" json:"context,omitempty"`
+	Commit         bool                `protobuf:"varint,3,opt,name=commit,proto3" json:"commit,omitempty"`
+}
+
+func (x *SGXVMSignedTransactionRequest) Reset() {
+	*x = SGXVMSignedTransactionRequest{}
+	if protoimpl.UnsafeEnabled {
+		mi := &file_ffi_proto_msgTypes[62]
+		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
+		ms.StoreMessageInfo(mi)
+	}
+}
+
+func (x *SGXVMSignedTransactionRequest) String() string {
+	return protoimpl.X.MessageStringOf(x)
+}
+
+func (*SGXVMSignedTransactionRequest) ProtoMessage() {}
+
+func (x *SGXVMSignedTransactionRequest) ProtoReflect() protoreflect.Message {
+	mi := &file_ffi_proto_msgTypes[62]
+	if protoimpl.UnsafeEnabled && x != nil {
+		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
+		if ms.LoadMessageInfo() == nil {
+			ms.StoreMessageInfo(mi)
+		}
+		return ms
+	}
+	return mi.MessageOf(x)
+}
+
+// Deprecated: Use SGXVMSignedTransactionRequest.ProtoReflect.Descriptor instead.
+func (*SGXVMSignedTransactionRequest) Descriptor() ([]byte, []int) {
+	return file_ffi_proto_rawDescGZIP(), []int{62}
+}
+
+func (x *SGXVMSignedTransactionRequest) GetRawTransaction() []byte {
+	if x != nil {
+		return x.RawTransaction
+	}
+	return nil
+}
+
+func (x *SGXVMSignedTransactionRequest) GetContext() *TransactionContext {
+	if x != nil {
+		return x.Context
+	}
+	return nil
+}
+
+func (x *SGXVMSignedTransactionRequest) GetCommit() bool {
+	if x != nil {
+		return x.Commit
+	}
+	return false
+}
+
+type SignedTransactionResponse struct {
+	state         protoimpl.MessageState
+	sizeCache     protoimpl.SizeCache
+	unknownFields protoimpl.UnknownFields
+
+	Result *HandleTransactionResponse `protobuf:"bytes,1,opt,name=result,proto3" json:"result,omitempty"`
+	// sender recovered from transaction signature
+	From []byte `protobuf:"bytes,2,opt,name=from,proto3" json:"from,omitempty"`
+	// hash of signed transaction
+	Hash []byte `protobuf:"bytes,3,opt,name=hash,proto3" json:"hash,omitempty"`
+}
+
+func (x *SignedTransactionResponse) Reset() {
+	*x = SignedTransactionResponse{}
+	if protoimpl.UnsafeEnabled {
+		mi := &file_ffi_proto_msgTypes[63]
+		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
+		ms.StoreMessageInfo(mi)
+	}
+}
+
+func (x *SignedTransactionResponse) String() string {
+	return protoimpl.X.MessageStringOf(x)
+}
+
+func (*SignedTransactionResponse) ProtoMessage() {}
+
+func (x *SignedTransactionResponse) ProtoReflect() protoreflect.Message {
+	mi := &file_ffi_proto_msgTypes[63]
+	if protoimpl.UnsafeEnabled && x != nil {
+		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
+		if ms.LoadMessageInfo() == nil {
+			ms.StoreMessageInfo(mi)
+		}
+		return ms
+	}
+	return mi.MessageOf(x)
+}
+
+// Deprecated: Use SignedTransactionResponse.ProtoReflect.Descriptor instead.
+func (*SignedTransactionResponse) Descriptor() ([]byte, []int) {
+	return file_ffi_proto_rawDescGZIP(), []int{63}
+}
+
+func (x *SignedTransactionResponse) GetResult() *HandleTransactionResponse {
+	if x != nil {
+		return x.Result
+	}
+	return nil
+}
+
+func (x *SignedTransactionResponse) GetFrom() []byte {
+	if x != nil {
+		return x.From
+	}
+	return nil
+}
+
+func (x *SignedTransactionResponse) GetHash() []byte {
+	if x != nil {
+		return x.Hash
+	}
+	return nil
+}
+
+// Request to obtain node public key
+type NodePublicKeyRequest struct {
+	state         protoimpl.MessageState
+	sizeCache     protoimpl.SizeCache
+	unknownFields protoimpl.UnknownFields
+}
+
+func (x *NodePublicKeyRequest) Reset() {
+	*x = NodePublicKeyRequest{}
+	if protoimpl.UnsafeEnabled {
+		mi := &file_ffi_proto_msgTypes[64]
+		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
+		ms.StoreMessageInfo(mi)
+	}
+}
+
+func (x *NodePublicKeyRequest) String() string {
+	return protoimpl.X.MessageStringOf(x)
+}
+
+func (*NodePublicKeyRequest) ProtoMessage() {}
+
+func (x *NodePublicKeyRequest) ProtoReflect() protoreflect.Message {
+	mi := &file_ffi_proto_msgTypes[64]
+	if protoimpl.UnsafeEnabled && x != nil {
+		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
+		if ms.LoadMessageInfo() == nil {
+			ms.StoreMessageInfo(mi)
+		}
+		return ms
+	}
+	return mi.MessageOf(x)
+}
+
+// Deprecated: Use NodePublicKeyRequest.ProtoReflect.Descriptor instead.
+func (*NodePublicKeyRequest) Descriptor() ([]byte, []int) {
+	return file_ffi_proto_rawDescGZIP(), []int{64}
+}
+
+// Response with node public key
+type NodePublicKeyResponse struct {
+	state         protoimpl.MessageState
+	sizeCache     protoimpl.SizeCache
+	unknownFields protoimpl.UnknownFields
+
+	PublicKey []byte `protobuf:"bytes,1,opt,name=publicKey,proto3" json:"publicKey,omitempty"`
+}
+
+func (x *NodePublicKeyResponse) Reset() {
+	*x = NodePublicKeyResponse{}
+	if protoimpl.UnsafeEnabled {
+		mi := &file_ffi_proto_msgTypes[65]
+		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
+		ms.StoreMessageInfo(mi)
+	}
+}
+
+func (x *NodePublicKeyResponse) String() string {
+	return protoimpl.X.MessageStringOf(x)
+}
+
+func (*NodePublicKeyResponse) ProtoMessage() {}
+
+func (x *NodePublicKeyResponse) ProtoReflect() protoreflect.Message {
+	mi := &file_ffi_proto_msgTypes[65]
+	if protoimpl.UnsafeEnabled && x != nil {
+		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
+		if ms.LoadMessageInfo() == nil {
+			ms.StoreMessageInfo(mi)
+		}
+		return ms
+	}
+	return mi.MessageOf(x)
+}
+
+// Deprecated: Use NodePublicKeyResponse.ProtoReflect.Descriptor instead.
+func (*NodePublicKeyResponse) Descriptor() ([]byte, []int) {
+	return file_ffi_proto_rawDescGZIP(), []int{65}
+}
+
+func (x *NodePublicKeyResponse) GetPublicKey() []byte {
+	if x != nil {
+		return x.PublicKey
+	}
+	return nil
+}
+
+// Overrides parameters of precompile registered at provided address
+type PrecompileOverride struct {
+	state         protoimpl.MessageState
+	sizeCache     protoimpl.SizeCache
+	unknownFields protoimpl.UnknownFields
+
+	Address []byte `protobuf:"bytes,1,opt,name=address,proto3" json:"address,omitempty"`
+	// block number starting from which precompile is available
+	ActivationHeight uint64 `protobuf:"varint,2,opt,name=activationHeight,proto3" json:"activationHeight,omitempty"`
+	// fixed gas cost of precompile. If 0, own cost of precompile is charged
+	GasOverride uint64 `protobuf:"varint,3,opt,name=gasOverride,proto3" json:"gasOverride,omitempty"`
+	// removes precompile from the set of available precompiles
+	Disabled bool `protobuf:"varint,4,opt,name=disabled,proto3" json:"disabled,omitempty"`
+}
+
+func (x *PrecompileOverride) Reset() {
+	*x = PrecompileOverride{}
+	if protoimpl.UnsafeEnabled {
+		mi := &file_ffi_proto_msgTypes[66]
+		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
+		ms.StoreMessageInfo(mi)
+	}
+}
+
+func (x *PrecompileOverride) String() string {
+	return protoimpl.X.MessageStringOf(x)
+}
+
+func (*PrecompileOverride) ProtoMessage() {}
+
+func (x *PrecompileOverride) ProtoReflect() protoreflect.Message {
+	mi := &file_ffi_proto_msgTypes[66]
+	if protoimpl.UnsafeEnabled && x != nil {
+		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
+		if ms.LoadMessageInfo() == nil {
+			ms.StoreMessageInfo(mi)
+		}
+		return ms
+	}
+	return mi.MessageOf(x)
+}
+
+// Deprecated: Use PrecompileOverride.ProtoReflect.Descriptor instead.
+func (*PrecompileOverride) Descriptor() ([]byte, []int) {
+	return file_ffi_proto_rawDescGZIP(), []int{66}
+}
+
+func (x *PrecompileOverride) GetAddress() []byte {
+	if x != nil {
+		return x.Address
+	}
+	return nil
+}
+
+func (x *PrecompileOverride) GetActivationHeight() uint64 {
+	if x != nil {
+		return x.ActivationHeight
+	}
+	return 0
+}
+
+func (x *PrecompileOverride) GetGasOverride() uint64 {
+	if x != nil {
+		return x.GasOverride
+	}
+	return 0
+}
+
+func (x *PrecompileOverride) GetDisabled() bool {
+	if x != nil {
+		return x.Disabled
+	}
+	return false
+}
+
+// Request to configure precompiles. Should be sent once on node start, before
+// any transaction is executed. Configuration affects results of transactions,
+// therefore it should be the same on every node
+type ConfigurePrecompilesRequest struct {
+	state         protoimpl.MessageState
+	sizeCache     protoimpl.SizeCache
+	unknownFields protoimpl.UnknownFields
+
+	Overrides []*PrecompileOverride `protobuf:"bytes,1,rep,name=overrides,proto3" json:"overrides,omitempty"`
+}
+
+func (x *ConfigurePrecompilesRequest) Reset() {
+	*x = ConfigurePrecompilesRequest{}
+	if protoimpl.UnsafeEnabled {
+		mi := &file_ffi_proto_msgTypes[67]
+		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
+		ms.StoreMessageInfo(mi)
+	}
+}
+
+func (x *ConfigurePrecompilesRequest) String() string {
+	return protoimpl.X.MessageStringOf(x)
+}
+
+func (*ConfigurePrecompilesRequest) ProtoMessage() {}
+
+func (x *ConfigurePrecompilesRequest) ProtoReflect() protoreflect.Message {
+	mi := &file_ffi_proto_msgTypes[67]
+	if protoimpl.UnsafeEnabled && x != nil {
+		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
+		if ms.LoadMessageInfo() == nil {
+			ms.StoreMessageInfo(mi)
+		}
+		return ms
+	}
+	return mi.MessageOf(x)
+}
+
+// Deprecated: Use ConfigurePrecompilesRequest.ProtoReflect.Descriptor instead.
+func (*ConfigurePrecompilesRequest) Descriptor() ([]byte, []int) {
+	return file_ffi_proto_rawDescGZIP(), []int{67}
+}
+
+func (x *ConfigurePrecompilesRequest) GetOverrides() []*PrecompileOverride {
+	if x != nil {
+		return x.Overrides
+	}
+	return nil
+}
+
+type ConfigurePrecompilesResponse struct {
+	state         protoimpl.MessageState
+	sizeCache     protoimpl.SizeCache
+	unknownFields protoimpl.UnknownFields
+
+	// empty if configuration was applied
+	Error string `protobuf:"bytes,1,opt,name=error,proto3" json:"error,omitempty"`
+}
+
+func (x *ConfigurePrecompilesResponse) Reset() {
+	*x = ConfigurePrecompilesResponse{}
+	if protoimpl.UnsafeEnabled {
+		mi := &file_ffi_proto_msgTypes[68]
+		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
+		ms.StoreMessageInfo(mi)
+	}
+}
+
+func (x *ConfigurePrecompilesResponse) String() string {
+	return protoimpl.X.MessageStringOf(x)
+}
+
+func (*ConfigurePrecompilesResponse) ProtoMessage() {}
+
+func (x *ConfigurePrecompilesResponse) ProtoReflect() protoreflect.Message {
+	mi := &file_ffi_proto_msgTypes[68]
+	if protoimpl.UnsafeEnabled && x != nil {
+		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
+		if ms.LoadMessageInfo() == nil {
+			ms.StoreMessageInfo(mi)
+		}
+		return ms
+	}
+	return mi.MessageOf(x)
+}
+
+// Deprecated: Use ConfigurePrecompilesResponse.ProtoReflect.Descriptor instead.
+func (*ConfigurePrecompilesResponse) Descriptor() ([]byte, []int) {
+	return file_ffi_proto_rawDescGZIP(), []int{68}
+}
+
+func (x *ConfigurePrecompilesResponse) GetError() string {
+	if x != nil {
+		return x.Error
+	}
+	return ""
+}
+
+type FFIRequest struct {
+	state         protoimpl.MessageState
+	sizeCache     protoimpl.SizeCache
+	unknownFields protoimpl.UnknownFields
+
+	// Types that are assignable to Req:
+	//	*FFIRequest_CallRequest
+	//	*FFIRequest_CreateRequest
+	//	*FFIRequest_PublicKeyRequest
+	//	*FFIRequest_TraceCallRequest
+	//	*FFIRequest_TraceCreateRequest
+	//	*FFIRequest_StructLogRequest
+	//	*FFIRequest_EstimateGasRequest
+	//	*FFIRequest_StateDiffRequest
+	//	*FFIRequest_CreateAccessListRequest
+	//	*FFIRequest_BatchRequest
+	//	*FFIRequest_SignedTransactionRequest
+	//	*FFIRequest_ConfigurePrecompilesRequest
+	Req isFFIRequest_Req `protobuf_oneof:"req"`
+}
+
+func (x *FFIRequest) Reset() {
+	*x = FFIRequest{}
+	if protoimpl.UnsafeEnabled {
+		mi := &file_ffi_proto_msgTypes[69]
+		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
+		ms.StoreMessageInfo(mi)
+	}
+}
+
+func (x *FFIRequest) String() string {
+	return protoimpl.X.MessageStringOf(x)
+}
+
+func (*FFIRequest) ProtoMessage() {}
+
+func (x *FFIRequest) ProtoReflect() protoreflect.Message {
+	mi := &file_ffi_proto_msgTypes[69]
+	if protoimpl.UnsafeEnabled && x != nil {
+		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
+		if ms.LoadMessageInfo() == nil {
+			ms.StoreMessageInfo(mi)
+		}
+		return ms
+	}
+	return mi.MessageOf(x)
+}
+
+// Deprecated: Use FFIRequest.ProtoReflect.Descriptor instead.
+func (*FFIRequest) Descriptor() ([]byte, []int) {
+	return file_ffi_proto_rawDescGZIP(), []int{69}
+}
+
+func (m *FFIRequest) GetReq() isFFIRequest_Req {
+	if m != nil {
+		return m.Req
+	}
+	return nil
+}
+
+func (x *FFIRequest) GetCallRequest() *SGXVMCallRequest {
+	if x, ok := x.GetReq().(*FFIRequest_CallRequest); ok {
+		return x.CallRequest
+	}
+	return nil
+}
+
+func (x *FFIRequest) GetCreateRequest() *SGXVMCreateRequest {
+	if x, ok := x.GetReq().(*FFIRequest_CreateRequest); ok {
+		return x.CreateRequest
+	}
+	return nil
+}
+
+func (x *FFIRequest) GetPublicKeyRequest() *NodePublicKeyRequest {
+	if x, ok := x.GetReq().(*FFIRequest_PublicKeyRequest); ok {
+		return x.PublicKeyRequest
+	}
+	return nil
+}
+
+func (x *FFIRequest) GetTraceCallRequest() *SGXVMCallRequest {
+	if x, ok := x.GetReq().(*FFIRequest_TraceCallRequest); ok {
+		return x.TraceCallRequest
+	}
+	return nil
+}
+
+func (x *FFIRequest) GetTraceCreateRequest() *SGXVMCreateRequest {
+	if x, ok := x.GetReq().(*FFIRequest_TraceCreateRequest); ok {
+		return x.TraceCreateRequest
+	}
+	return nil
+}
+
+func (x *FFIRequest) GetStructLogRequest() *SGXVMStructLogRequest {
+	if x, ok := x.GetReq().(*FFIRequest_StructLogRequest); ok {
+		return x.StructLogRequest
+	}
+	return nil
+}
+
+func (x *FFIRequest) GetEstimateGasRequest() *SGXVMEstimateGasRequest {
+	if x, ok := x.GetReq().(*FFIRequest_EstimateGasRequest); ok {
+		return x.EstimateGasRequest
+	}
+	return nil
+}
+
+func (x *FFIRequest) GetStateDiffRequest() *SGXVMStateDiffRequest {
+	if x, ok := x.GetReq().(*FFIRequest_StateDiffRequest); ok {
+		return x.StateDiffRequest
+	}
+	return nil
+}
+
+func (x *FFIRequest) GetCreateAccessListRequest() *SGXVMCreateAccessListRequest {
+	if x, ok := x.GetReq().(*FFIRequest_CreateAccessListRequest); ok {
+		return x.CreateAccessListRequest
+	}
+	return nil
+}
+
+func (x *FFIRequest) GetBatchRequest() *SGXVMBatchRequest {
+	if x, ok := x.GetReq().(*FFIRequest_BatchRequest); ok {
+		return x.BatchRequest
+	}
+	return nil
+}
+
+func (x *FFIRequest) GetSignedTransactionRequest() *SGXVMSignedTransactionRequest {
+	if x, ok := x.GetReq().(*FFIRequest_SignedTransactionRequest); ok {
+		return x.SignedTransactionRequest
+	}
+	return nil
+}
+
+func (x *FFIRequest) GetConfigurePrecompilesRequest() *ConfigurePrecompilesRequest {
+	if x, ok := x.GetReq().(*FFIRequest_ConfigurePrecompilesRequest); ok {
+		return x.ConfigurePrecompilesRequest
+	}
+	return nil
+}
+
+type isFFIRequest_Req interface {
+	isFFIRequest_Req()
+}
+
+type FFIRequest_CallRequest struct {
+	CallRequest *SGXVMCallRequest `protobuf:"bytes,1,opt,name=callRequest,proto3,oneof"`
+}
+
+type FFIRequest_CreateRequest struct {
+	CreateRequest *SGXVMCreateRequest `protobuf:"bytes,2,opt,name=createRequest,proto3,oneof"`
+}
+
+type FFIRequest_PublicKeyRequest struct {
+	PublicKeyRequest *NodePublicKeyRequest `protobuf:"bytes,3,opt,name=publicKeyRequest,proto3,oneof"`
+}
+
+type FFIRequest_TraceCallRequest struct {
+	TraceCallRequest *SGXVMCallRequest `protobuf:"bytes,4,opt,name=traceCallRequest,proto3,oneof"`
+}
+
+type FFIRequest_TraceCreateRequest struct {
+	TraceCreateRequest *SGXVMCreateRequest `protobuf:"bytes,5,opt,name=traceCreateRequest,proto3,oneof"`
+}
+
+type FFIRequest_StructLogRequest struct {
+	StructLogRequest *SGXVMStructLogRequest `protobuf:"bytes,6,opt,name=structLogRequest,proto3,oneof"`
+}
+
+type FFIRequest_EstimateGasRequest struct {
+	EstimateGasRequest *SGXVMEstimateGasRequest `protobuf:"bytes,7,opt,name=estimateGasRequest,proto3,oneof"`
+}
+
+type FFIRequest_StateDiffRequest struct {
+	StateDiffRequest *SGXVMStateDiffRequest `protobuf:"bytes,8,opt,name=stateDiffRequest,proto3,oneof"`
+}
+
+type FFIRequest_CreateAccessListRequest struct {
+	CreateAccessListRequest *SGXVMCreateAccessListRequest `protobuf:"bytes,9,opt,name=createAccessListRequest,proto3,oneof"`
+}
+
+type FFIRequest_BatchRequest struct {
+	BatchRequest *SGXVMBatchRequest `protobuf:"bytes,10,opt,name=batchRequest,proto3,oneof"`
+}
+
+type FFIRequest_SignedTransactionRequest struct {
+	SignedTransactionRequest *SGXVMSignedTransactionRequest `protobuf:"bytes,11,opt,name=signedTransactionRequest,proto3,oneof"`
+}
+
+type FFIRequest_ConfigurePrecompilesRequest struct {
+	ConfigurePrecompilesRequest *ConfigurePrecompilesRequest `protobuf:"bytes,12,opt,name=configurePrecompilesRequest,proto3,oneof"`
+}
+
+func (*FFIRequest_CallRequest) isFFIRequest_Req() {}
+
+func (*FFIRequest_CreateRequest) isFFIRequest_Req() {}
+
+func (*FFIRequest_PublicKeyRequest) isFFIRequest_Req() {}
+
+func (*FFIRequest_TraceCallRequest) isFFIRequest_Req() {}
+
+func (*FFIRequest_TraceCreateRequest) isFFIRequest_Req() {}
+
+func (*FFIRequest_StructLogRequest) isFFIRequest_Req() {}
+
+func (*FFIRequest_EstimateGasRequest) isFFIRequest_Req() {}
+
+func (*FFIRequest_StateDiffRequest) isFFIRequest_Req() {}
+
+func (*FFIRequest_CreateAccessListRequest) isFFIRequest_Req() {}
+
+func (*FFIRequest_BatchRequest) isFFIRequest_Req() {}
+
+func (*FFIRequest_SignedTransactionRequest) isFFIRequest_Req() {}
+
+func (*FFIRequest_ConfigurePrecompilesRequest) isFFIRequest_Req() {}
+
+var File_ffi_proto protoreflect.FileDescriptor
+
+var file_ffi_proto_rawDesc = []byte{
+	0x0a, 0x09, 0x66, 0x66, 0x69, 0x2e, 0x70, 0x72, 0x6f, 0x74, 0x6f, 0x12, 0x07, 0x66, 0x66, 0x69,
+	0x2e, 0x66, 0x66, 0x69, 0x22, 0x4c, 0x0a, 0x0e, 0x41, 0x63, 0x63, 0x65, 0x73, 0x73, 0x4c, 0x69,
+	0x73, 0x74, 0x49, 0x74, 0x65, 0x6d, 0x12, 0x20, 0x0a, 0x0b, 0x73, 0x74, 0x6f, 0x72, 0x61, 0x67,
+	0x65, 0x53, 0x6c, 0x6f, 0x74, 0x18, 0x01, 0x20, 0x03, 0x28, 0x0c, 0x52, 0x0b, 0x73, 0x74, 0x6f,
+	0x72, 0x61, 0x67, 0x65, 0x53, 0x6c, 0x6f, 0x74, 0x12, 0x18, 0x0a, 0x07, 0x61, 0x64, 0x64, 0x72,
+	0x65, 0x73, 0x73, 0x18, 0x02, 0x20, 0x01, 0x28, 0x0c, 0x52, 0x07, 0x61, 0x64, 0x64, 0x72, 0x65,
+	0x73, 0x73, 0x22, 0xb4, 0x01, 0x0a, 0x0f, 0x54, 0x72, 0x61, 0x6e, 0x73, 0x61, 0x63, 0x74, 0x69,
+	0x6f, 0x6e, 0x44, 0x61, 0x74, 0x61, 0x12, 0x12, 0x0a, 0x04, 0x66, 0x72, 0x6f, 0x6d, 0x18, 0x01,
+	0x20, 0x01, 0x28, 0x0c, 0x52, 0x04, 0x66, 0x72, 0x6f, 0x6d, 0x12, 0x0e, 0x0a, 0x02, 0x74, 0x6f,
+	0x18, 0x02, 0x20, 0x01, 0x28, 0x0c, 0x52, 0x02, 0x74, 0x6f, 0x12, 0x12, 0x0a, 0x04, 0x64, 0x61,
+	0x74, 0x61, 0x18, 0x03, 0x20, 0x01, 0x28, 0x0c, 0x52, 0x04, 0x64, 0x61, 0x74, 0x61, 0x12, 0x1a,
+	0x0a, 0x08, 0x67, 0x61, 0x73, 0x4c, 0x69, 0x6d, 0x69, 0x74, 0x18, 0x04, 0x20, 0x01, 0x28, 0x04,
+	0x52, 0x08, 0x67, 0x61, 0x73, 0x4c, 0x69, 0x6d, 0x69, 0x74, 0x12, 0x14, 0x0a, 0x05, 0x76, 0x61,
+	0x6c, 0x75, 0x65, 0x18, 0x05, 0x20, 0x01, 0x28, 0x0c, 0x52, 0x05, 0x76, 0x61, 0x6c, 0x75, 0x65,
+	0x12, 0x37, 0x0a, 0x0a, 0x61, 0x63, 0x63, 0x65, 0x73, 0x73, 0x4c, 0x69, 0x73, 0x74, 0x18, 0x06,
+	0x20, 0x03, 0x28, 0x0b, 0x32, 0x17, 0x2e, 0x66, 0x66, 0x69, 0x2e, 0x66, 0x66, 0x69, 0x2e, 0x41,
+	0x63, 0x63, 0x65, 0x73, 0x73, 0x4c, 0x69, 0x73, 0x74, 0x49, 0x74, 0x65, 0x6d, 0x52, 0x0a, 0x61,
+	0x63, 0x63, 0x65, 0x73, 0x73, 0x4c, 0x69, 0x73, 0x74, 0x22, 0xf2, 0x02, 0x0a, 0x12, 0x54, 0x72,
+	0x61, 0x6e, 0x73, 0x61, 0x63, 0x74, 0x69, 0x6f, 0x6e, 0x43, 0x6f, 0x6e, 0x74, 0x65, 0x78, 0x74,
+	0x12, 0x19, 0x0a, 0x08, 0x63, 0x68, 0x61, 0x69, 0x6e, 0x5f, 0x69, 0x64, 0x18, 0x01, 0x20, 0x01,
+	0x28, 0x04, 0x52, 0x07, 0x63, 0x68, 0x61, 0x69, 0x6e, 0x49, 0x64, 0x12, 0x1b, 0x0a, 0x09, 0x67,
+	0x61, 0x73, 0x5f, 0x70, 0x72, 0x69, 0x63, 0x65, 0x18, 0x02, 0x20, 0x01, 0x28, 0x0c, 0x52, 0x08,
+	0x67, 0x61, 0x73, 0x50, 0x72, 0x69, 0x63, 0x65, 0x12, 0x1c, 0x0a, 0x09, 0x74, 0x69, 0x6d, 0x65,
+	0x73, 0x74, 0x61, 0x6d, 0x70, 0x18, 0x03, 0x20, 0x01, 0x28, 0x04, 0x52, 0x09, 0x74, 0x69, 0x6d,
+	0x65, 0x73, 0x74, 0x61, 0x6d, 0x70, 0x12, 0x26, 0x0a, 0x0f, 0x62, 0x6c, 0x6f, 0x63, 0x6b, 0x5f,
+	0x67, 0x61, 0x73, 0x5f, 0x6c, 0x69, 0x6d, 0x69, 0x74, 0x18, 0x04, 0x20, 0x01, 0x28, 0x04, 0x52,
+	0x0d, 0x62, 0x6c, 0x6f, 0x63, 0x6b, 0x47, 0x61, 0x73, 0x4c, 0x69, 0x6d, 0x69, 0x74, 0x12, 0x32,
+	0x0a, 0x16, 0x62, 0x6c, 0x6f, 0x63, 0x6b, 0x5f, 0x62, 0x61, 0x73, 0x65, 0x5f, 0x66, 0x65, 0x65,
+	0x5f, 0x70, 0x65, 0x72, 0x5f, 0x67, 0x61, 0x73, 0x18, 0x05, 0x20, 0x01, 0x28, 0x0c, 0x52, 0x12,
+	0x62, 0x6c, 0x6f, 0x63, 0x6b, 0x42, 0x61, 0x73, 0x65, 0x46, 0x65, 0x65, 0x50, 0x65, 0x72, 0x47,
+	0x61, 0x73, 0x12, 0x25, 0x0a, 0x0e, 0x62, 0x6c, 0x6f, 0x63, 0x6b, 0x5f, 0x63, 0x6f, 0x69, 0x6e,
+	0x62, 0x61, 0x73, 0x65, 0x18, 0x06, 0x20, 0x01, 0x28, 0x0c, 0x52, 0x0d, 0x62, 0x6c, 0x6f, 0x63,
+	0x6b, 0x43, 0x6f, 0x69, 0x6e, 0x62, 0x61, 0x73, 0x65, 0x12, 0x21, 0x0a, 0x0c, 0x62, 0x6c, 0x6f,
+	0x63, 0x6b, 0x5f, 0x6e, 0x75, 0x6d, 0x62, 0x65, 0x72, 0x18, 0x07, 0x20, 0x01, 0x28, 0x04, 0x52,
+	0x0b, 0x62, 0x6c, 0x6f, 0x63, 0x6b, 0x4e, 0x75, 0x6d, 0x62, 0x65, 0x72, 0x12, 0x19, 0x0a, 0x08,
+	0x65, 0x76, 0x6d, 0x5f, 0x66, 0x6f, 0x72, 0x6b, 0x18, 0x08, 0x20, 0x01, 0x28, 0x0d, 0x52, 0x07,
+	0x65, 0x76, 0x6d, 0x46, 0x6f, 0x72, 0x6b, 0x12, 0x1f, 0x0a, 0x0b, 0x70, 0x72, 0x65, 0x76, 0x5f,
+	0x72, 0x61, 0x6e, 0x64, 0x61, 0x6f, 0x18, 0x09, 0x20, 0x01, 0x28, 0x0c, 0x52, 0x0a, 0x70, 0x72,
+	0x65, 0x76, 0x52, 0x61, 0x6e, 0x64, 0x61, 0x6f, 0x12, 0x24, 0x0a, 0x0e, 0x62, 0x6c, 0x6f, 0x63,
+	0x6b, 0x5f, 0x67, 0x61, 0x73, 0x5f, 0x75, 0x73, 0x65, 0x64, 0x18, 0x0a, 0x20, 0x01, 0x28, 0x04,
+	0x52, 0x0c, 0x62, 0x6c, 0x6f, 0x63, 0x6b, 0x47, 0x61, 0x73, 0x55, 0x73, 0x65, 0x64, 0x22, 0x89,
+	0x01, 0x0a, 0x18, 0x48, 0x61, 0x6e, 0x64, 0x6c, 0x65, 0x54, 0x72, 0x61, 0x6e, 0x73, 0x61, 0x63,
+	0x74, 0x69, 0x6f, 0x6e, 0x52, 0x65, 0x71, 0x75, 0x65, 0x73, 0x74, 0x12, 0x31, 0x0a, 0x07, 0x74,
+	0x78, 0x5f, 0x64, 0x61, 0x74, 0x61, 0x18, 0x01, 0x20, 0x01, 0x28, 0x0b, 0x32, 0x18, 0x2e, 0x66,
+	0x66, 0x69, 0x2e, 0x66, 0x66, 0x69, 0x2e, 0x54, 0x72, 0x61, 0x6e, 0x73, 0x61, 0x63, 0x74, 0x69,
+	0x6f, 0x6e, 0x44, 0x61, 0x74, 0x61, 0x52, 0x06, 0x74, 0x78, 0x44, 0x61, 0x74, 0x61, 0x12, 0x3a,
+	0x0a, 0x0a, 0x74, 0x78, 0x5f, 0x63, 0x6f, 0x6e, 0x74, 0x65, 0x78, 0x74, 0x18, 0x02, 0x20, 0x01,
+	0x28, 0x0b, 0x32, 0x1b, 0x2e, 0x66, 0x66, 0x69, 0x2e, 0x66, 0x66, 0x69, 0x2e, 0x54, 0x72, 0x61,
+	0x6e, 0x73, 0x61, 0x63, 0x74, 0x69, 0x6f, 0x6e, 0x43, 0x6f, 0x6e, 0x74, 0x65, 0x78, 0x74, 0x52,
+	0x09, 0x74, 0x78, 0x43, 0x6f, 0x6e, 0x74, 0x65, 0x78, 0x74, 0x22, 0xb4, 0x03, 0x0a, 0x19, 0x48,
+	0x61, 0x6e, 0x64, 0x6c, 0x65, 0x54, 0x72, 0x61, 0x6e, 0x73, 0x61, 0x63, 0x74, 0x69, 0x6f, 0x6e,
+	0x52, 0x65, 0x73, 0x70, 0x6f, 0x6e, 0x73, 0x65, 0x12, 0x20, 0x0a, 0x04, 0x6c, 0x6f, 0x67, 0x73,
+	0x18, 0x02, 0x20, 0x03, 0x28, 0x0b, 0x32, 0x0c, 0x2e, 0x66, 0x66, 0x69, 0x2e, 0x66, 0x66, 0x69,
+	0x2e, 0x4c, 0x6f, 0x67, 0x52, 0x04, 0x6c, 0x6f, 0x67, 0x73, 0x12, 0x10, 0x0a, 0x03, 0x72, 0x65,
+	0x74, 0x18, 0x03, 0x20, 0x01, 0x28, 0x0c, 0x52, 0x03, 0x72, 0x65, 0x74, 0x12, 0x19, 0x0a, 0x08,
+	0x76, 0x6d, 0x5f, 0x65, 0x72, 0x72, 0x6f, 0x72, 0x18, 0x04, 0x20, 0x01, 0x28, 0x09, 0x52, 0x07,
+	0x76, 0x6d, 0x45, 0x72, 0x72, 0x6f, 0x72, 0x12, 0x19, 0x0a, 0x08, 0x67, 0x61, 0x73, 0x5f, 0x75,
+	0x73, 0x65, 0x64, 0x18, 0x05, 0x20, 0x01, 0x28, 0x04, 0x52, 0x07, 0x67, 0x61, 0x73, 0x55, 0x73,
+	0x65, 0x64, 0x12, 0x2d, 0x0a, 0x06, 0x72, 0x65, 0x76, 0x65, 0x72, 0x74, 0x18, 0x06, 0x20, 0x01,
+	0x28, 0x0b, 0x32, 0x15, 0x2e, 0x66, 0x66, 0x69, 0x2e, 0x66, 0x66, 0x69, 0x2e, 0x52, 0x65, 0x76,
+	0x65, 0x72, 0x74, 0x52, 0x65, 0x61, 0x73, 0x6f, 0x6e, 0x52, 0x06, 0x72, 0x65, 0x76, 0x65, 0x72,
+	0x74, 0x12, 0x1e, 0x0a, 0x0a, 0x65, 0x78, 0x69, 0x74, 0x53, 0x74, 0x61, 0x74, 0x75, 0x73, 0x18,
+	0x07, 0x20, 0x01, 0x28, 0x09, 0x52, 0x0a, 0x65, 0x78, 0x69, 0x74, 0x53, 0x74, 0x61, 0x74, 0x75,
+	0x73, 0x12, 0x1e, 0x0a, 0x0a, 0x65, 0x78, 0x69, 0x74, 0x52, 0x65, 0x61, 0x73, 0x6f, 0x6e, 0x18,
+	0x08, 0x20, 0x01, 0x28, 0x09, 0x52, 0x0a, 0x65, 0x78, 0x69, 0x74, 0x52, 0x65, 0x61, 0x73, 0x6f,
+	0x6e, 0x12, 0x28, 0x0a, 0x0f, 0x63, 0x6f, 0x6e, 0x74, 0x72, 0x61, 0x63, 0x74, 0x41, 0x64, 0x64,
+	0x72, 0x65, 0x73, 0x73, 0x18, 0x09, 0x20, 0x01, 0x28, 0x0c, 0x52, 0x0f, 0x63, 0x6f, 0x6e, 0x74,
+	0x72, 0x61, 0x63, 0x74, 0x41, 0x64, 0x64, 0x72, 0x65, 0x73, 0x73, 0x12, 0x20, 0x0a, 0x0b, 0x67,
+	0x61, 0x73, 0x52, 0x65, 0x66, 0x75, 0x6e, 0x64, 0x65, 0x64, 0x18, 0x0a, 0x20, 0x01, 0x28, 0x04,
+	0x52, 0x0b, 0x67, 0x61, 0x73, 0x52, 0x65, 0x66, 0x75, 0x6e, 0x64, 0x65, 0x64, 0x12, 0x22, 0x0a,
+	0x0c, 0x69, 0x6e, 0x74, 0x72, 0x69, 0x6e, 0x73, 0x69, 0x63, 0x47, 0x61, 0x73, 0x18, 0x0b, 0x20,
+	0x01, 0x28, 0x04, 0x52, 0x0c, 0x69, 0x6e, 0x74, 0x72, 0x69, 0x6e, 0x73, 0x69, 0x63, 0x47, 0x61,
+	0x73, 0x12, 0x22, 0x0a, 0x0c, 0x65, 0x78, 0x65, 0x63, 0x75, 0x74, 0x69, 0x6f, 0x6e, 0x47, 0x61,
+	0x73, 0x18, 0x0c, 0x20, 0x01, 0x28, 0x04, 0x52, 0x0c, 0x65, 0x78, 0x65, 0x63, 0x75, 0x74, 0x69,
+	0x6f, 0x6e, 0x47, 0x61, 0x73, 0x12, 0x2a, 0x0a, 0x07, 0x72, 0x65, 0x63, 0x65, 0x69, 0x70, 0x74,
+	0x18, 0x0d, 0x20, 0x01, 0x28, 0x0b, 0x32, 0x10, 0x2e, 0x66, 0x66, 0x69, 0x2e, 0x66, 0x66, 0x69,
+	0x2e, 0x52, 0x65, 0x63, 0x65, 0x69, 0x70, 0x74, 0x52, 0x07, 0x72, 0x65, 0x63, 0x65, 0x69, 0x70,
+	0x74, 0x22, 0x87, 0x01, 0x0a, 0x07, 0x52, 0x65, 0x63, 0x65, 0x69, 0x70, 0x74, 0x12, 0x16, 0x0a,
+	0x06, 0x73, 0x74, 0x61, 0x74, 0x75, 0x73, 0x18, 0x01, 0x20, 0x01, 0x28, 0x0d, 0x52, 0x06, 0x73,
+	0x74, 0x61, 0x74, 0x75, 0x73, 0x12, 0x2c, 0x0a, 0x11, 0x63, 0x75, 0x6d, 0x75, 0x6c, 0x61, 0x74,
+	0x69, 0x76, 0x65, 0x47, 0x61, 0x73, 0x55, 0x73, 0x65, 0x64, 0x18, 0x02, 0x20, 0x01, 0x28, 0x04,
+	0x52, 0x11, 0x63, 0x75, 0x6d, 0x75, 0x6c, 0x61, 0x74, 0x69, 0x76, 0x65, 0x47, 0x61, 0x73, 0x55,
+	0x73, 0x65, 0x64, 0x12, 0x1c, 0x0a, 0x09, 0x6c, 0x6f, 0x67, 0x73, 0x42, 0x6c, 0x6f, 0x6f, 0x6d,
+	0x18, 0x03, 0x20, 0x01, 0x28, 0x0c, 0x52, 0x09, 0x6c, 0x6f, 0x67, 0x73, 0x42, 0x6c, 0x6f, 0x6f,
+	0x6d, 0x12, 0x18, 0x0a, 0x07, 0x65, 0x6e, 0x63, 0x6f, 0x64, 0x65, 0x64, 0x18, 0x04, 0x20, 0x01,
+	0x28, 0x0c, 0x52, 0x07, 0x65, 0x6e, 0x63, 0x6f, 0x64, 0x65, 0x64, 0x22, 0x8a, 0x01, 0x0a, 0x0c,
+	0x52, 0x65, 0x76, 0x65, 0x72, 0x74, 0x52, 0x65, 0x61, 0x73, 0x6f, 0x6e, 0x12, 0x12, 0x0a, 0x04,
+	0x6b, 0x69, 0x6e, 0x64, 0x18, 0x01, 0x20, 0x01, 0x28, 0x09, 0x52, 0x04, 0x6b, 0x69, 0x6e, 0x64,
+	0x12, 0x18, 0x0a, 0x07, 0x6d, 0x65, 0x73, 0x73, 0x61, 0x67, 0x65, 0x18, 0x02, 0x20, 0x01, 0x28,
+	0x09, 0x52, 0x07, 0x6d, 0x65, 0x73, 0x73, 0x61, 0x67, 0x65, 0x12, 0x1c, 0x0a, 0x09, 0x70, 0x61,
+	0x6e, 0x69, 0x63, 0x43, 0x6f, 0x64, 0x65, 0x18, 0x03, 0x20, 0x01, 0x28, 0x04, 0x52, 0x09, 0x70,
+	0x61, 0x6e, 0x69, 0x63, 0x43, 0x6f, 0x64, 0x65, 0x12, 0x1a, 0x0a, 0x08, 0x73, 0x65, 0x6c, 0x65,
+	0x63, 0x74, 0x6f, 0x72, 0x18, 0x04, 0x20, 0x01, 0x28, 0x0c, 0x52, 0x08, 0x73, 0x65, 0x6c, 0x65,
+	0x63, 0x74, 0x6f, 0x72, 0x12, 0x12, 0x0a, 0x04, 0x61, 0x72, 0x67, 0x73, 0x18, 0x05, 0x20, 0x01,
+	0x28, 0x0c, 0x52, 0x04, 0x61, 0x72, 0x67, 0x73, 0x22, 0x1d, 0x0a, 0x05, 0x54, 0x6f, 0x70, 0x69,
+	0x63, 0x12, 0x14, 0x0a, 0x05, 0x69, 0x6e, 0x6e, 0x65, 0x72, 0x18, 0x01, 0x20, 0x01, 0x28, 0x0c,
+	0x52, 0x05, 0x69, 0x6e, 0x6e, 0x65, 0x72, 0x22, 0x5b, 0x0a, 0x03, 0x4c, 0x6f, 0x67, 0x12, 0x18,
+	0x0a, 0x07, 0x61, 0x64, 0x64, 0x72, 0x65, 0x73, 0x73, 0x18, 0x01, 0x20, 0x01, 0x28, 0x0c, 0x52,
+	0x07, 0x61, 0x64, 0x64, 0x72, 0x65, 0x73, 0x73, 0x12, 0x26, 0x0a, 0x06, 0x74, 0x6f, 0x70, 0x69,
+	0x63, 0x73, 0x18, 0x02, 0x20, 0x03, 0x28, 0x0b, 0x32, 0x0e, 0x2e, 0x66, 0x66, 0x69, 0x2e, 0x66,
+	0x66, 0x69, 0x2e, 0x54, 0x6f, 0x70, 0x69, 0x63, 0x52, 0x06, 0x74, 0x6f, 0x70, 0x69, 0x63, 0x73,
+	0x12, 0x12, 0x0a, 0x04, 0x64, 0x61, 0x74, 0x61, 0x18, 0x03, 0x20, 0x01, 0x28, 0x0c, 0x52, 0x04,
+	0x64, 0x61, 0x74, 0x61, 0x22, 0x2b, 0x0a, 0x0f, 0x51, 0x75, 0x65, 0x72, 0x79, 0x47, 0x65, 0x74,
+	0x41, 0x63, 0x63, 0x6f, 0x75, 0x6e, 0x74, 0x12, 0x18, 0x0a, 0x07, 0x61, 0x64, 0x64, 0x72, 0x65,
+	0x73, 0x73, 0x18, 0x01, 0x20, 0x01, 0x28, 0x0c, 0x52, 0x07, 0x61, 0x64, 0x64, 0x72, 0x65, 0x73,
+	0x73, 0x22, 0x49, 0x0a, 0x17, 0x51, 0x75, 0x65, 0x72, 0x79, 0x47, 0x65, 0x74, 0x41, 0x63, 0x63,
+	0x6f, 0x75, 0x6e, 0x74, 0x52, 0x65, 0x73, 0x70, 0x6f, 0x6e, 0x73, 0x65, 0x12, 0x18, 0x0a, 0x07,
+	0x62, 0x61, 0x6c, 0x61, 0x6e, 0x63, 0x65, 0x18, 0x01, 0x20, 0x01, 0x28, 0x0c, 0x52, 0x07, 0x62,
+	0x61, 0x6c, 0x61, 0x6e, 0x63, 0x65, 0x12, 0x14, 0x0a, 0x05, 0x6e, 0x6f, 0x6e, 0x63, 0x65, 0x18,
+	0x02, 0x20, 0x01, 0x28, 0x04, 0x52, 0x05, 0x6e, 0x6f, 0x6e, 0x63, 0x65, 0x22, 0x5e, 0x0a, 0x12,
+	0x51, 0x75, 0x65, 0x72, 0x79, 0x49, 0x6e, 0x73, 0x65, 0x72, 0x74, 0x41, 0x63, 0x63, 0x6f, 0x75,
+	0x6e, 0x74, 0x12, 0x18, 0x0a, 0x07, 0x61, 0x64, 0x64, 0x72, 0x65, 0x73, 0x73, 0x18, 0x01, 0x20,
+	0x01, 0x28, 0x0c, 0x52, 0x07, 0x61, 0x64, 0x64, 0x72, 0x65, 0x73, 0x73, 0x12, 0x18, 0x0a, 0x07,
+	0x62, 0x61, 0x6c, 0x61, 0x6e, 0x63, 0x65, 0x18, 0x02, 0x20, 0x01, 0x28, 0x0c, 0x52, 0x07, 0x62,
+	0x61, 0x6c, 0x61, 0x6e, 0x63, 0x65, 0x12, 0x14, 0x0a, 0x05, 0x6e, 0x6f, 0x6e, 0x63, 0x65, 0x18,
+	0x03, 0x20, 0x01, 0x28, 0x04, 0x52, 0x05, 0x6e, 0x6f, 0x6e, 0x63, 0x65, 0x22, 0x1c, 0x0a, 0x1a,
+	0x51, 0x75, 0x65, 0x72, 0x79, 0x49, 0x6e, 0x73, 0x65, 0x72, 0x74, 0x41, 0x63, 0x63, 0x6f, 0x75,
+	0x6e, 0x74, 0x52, 0x65, 0x73, 0x70, 0x6f, 0x6e, 0x73, 0x65, 0x22, 0x24, 0x0a, 0x10, 0x51, 0x75,
+	0x65, 0x72, 0x79, 0x43, 0x6f, 0x6e, 0x74, 0x61, 0x69, 0x6e, 0x73, 0x4b, 0x65, 0x79, 0x12, 0x10,
+	0x0a, 0x03, 0x6b, 0x65, 0x79, 0x18, 0x01, 0x20, 0x01, 0x28, 0x0c, 0x52, 0x03, 0x6b, 0x65, 0x79,
+	0x22, 0x36, 0x0a, 0x18, 0x51, 0x75, 0x65, 0x72, 0x79, 0x43, 0x6f, 0x6e, 0x74, 0x61, 0x69, 0x6e,
+	0x73, 0x4b, 0x65, 0x79, 0x52, 0x65, 0x73, 0x70, 0x6f, 0x6e, 0x73, 0x65, 0x12, 0x1a, 0x0a, 0x08,
+	0x63, 0x6f, 0x6e, 0x74, 0x61, 0x69, 0x6e, 0x73, 0x18, 0x01, 0x20, 0x01, 0x28, 0x08, 0x52, 0x08,
+	0x63, 0x6f, 0x6e, 0x74, 0x61, 0x69, 0x6e, 0x73, 0x22, 0x4c, 0x0a, 0x1a, 0x51, 0x75, 0x65, 0x72,
+	0x79, 0x47, 0x65, 0x74, 0x41, 0x63, 0x63, 0x6f, 0x75, 0x6e, 0x74, 0x53, 0x74, 0x6f, 0x72, 0x61,
+	0x67, 0x65, 0x43, 0x65, 0x6c, 0x6c, 0x12, 0x18, 0x0a, 0x07, 0x61, 0x64, 0x64, 0x72, 0x65, 0x73,
+	0x73, 0x18, 0x01, 0x20, 0x01, 0x28, 0x0c, 0x52, 0x07, 0x61, 0x64, 0x64, 0x72, 0x65, 0x73, 0x73,
+	0x12, 0x14, 0x0a, 0x05, 0x69, 0x6e, 0x64, 0x65, 0x78, 0x18, 0x02, 0x20, 0x01, 0x28, 0x0c, 0x52,
+	0x05, 0x69, 0x6e, 0x64, 0x65, 0x78, 0x22, 0x3a, 0x0a, 0x22, 0x51, 0x75, 0x65, 0x72, 0x79, 0x47,
+	0x65, 0x74, 0x41, 0x63, 0x63, 0x6f, 0x75, 0x6e, 0x74, 0x53, 0x74, 0x6f, 0x72, 0x61, 0x67, 0x65,
+	0x43, 0x65, 0x6c, 0x6c, 0x52, 0x65, 0x73, 0x70, 0x6f, 0x6e, 0x73, 0x65, 0x12, 0x14, 0x0a, 0x05,
+	0x76, 0x61, 0x6c, 0x75, 0x65, 0x18, 0x01, 0x20, 0x01, 0x28, 0x0c, 0x52, 0x05, 0x76, 0x61, 0x6c,
+	0x75, 0x65, 0x22, 0x2f, 0x0a, 0x13, 0x51, 0x75, 0x65, 0x72, 0x79, 0x47, 0x65, 0x74, 0x41, 0x63,
+	0x63, 0x6f, 0x75, 0x6e, 0x74, 0x43, 0x6f, 0x64, 0x65, 0x12, 0x18, 0x0a, 0x07, 0x61, 0x64, 0x64,
+	0x72, 0x65, 0x73, 0x73, 0x18, 0x01, 0x20, 0x01, 0x28, 0x0c, 0x52, 0x07, 0x61, 0x64, 0x64, 0x72,
+	0x65, 0x73, 0x73, 0x22, 0x31, 0x0a, 0x1b, 0x51, 0x75, 0x65, 0x72, 0x79, 0x47, 0x65, 0x74, 0x41,
+	0x63, 0x63, 0x6f, 0x75, 0x6e, 0x74, 0x43, 0x6f, 0x64, 0x65, 0x52, 0x65, 0x73, 0x70, 0x6f, 0x6e,
+	0x73, 0x65, 0x12, 0x12, 0x0a, 0x04, 0x63, 0x6f, 0x64, 0x65, 0x18, 0x01, 0x20, 0x01, 0x28, 0x0c,
+	0x52, 0x04, 0x63, 0x6f, 0x64, 0x65, 0x22, 0x46, 0x0a, 0x16, 0x51, 0x75, 0x65, 0x72, 0x79, 0x49,
+	0x6e, 0x73, 0x65, 0x72, 0x74, 0x41, 0x63, 0x63, 0x6f, 0x75, 0x6e, 0x74, 0x43, 0x6f, 0x64, 0x65,
+	0x12, 0x18, 0x0a, 0x07, 0x61, 0x64, 0x64, 0x72, 0x65, 0x73, 0x73, 0x18, 0x01, 0x20, 0x01, 0x28,
+	0x0c, 0x52, 0x07, 0x61, 0x64, 0x64, 0x72, 0x65, 0x73, 0x73, 0x12, 0x12, 0x0a, 0x04, 0x63, 0x6f,
+	0x64, 0x65, 0x18, 0x02, 0x20, 0x01, 0x28, 0x0c, 0x52, 0x04, 0x63, 0x6f, 0x64, 0x65, 0x22, 0x20,
+	0x0a, 0x1e, 0x51, 0x75, 0x65, 0x72, 0x79, 0x49, 0x6e, 0x73, 0x65, 0x72, 0x74, 0x41, 0x63, 0x63,
+	0x6f, 0x75, 0x6e, 0x74, 0x43, 0x6f, 0x64, 0x65, 0x52, 0x65, 0x73, 0x70, 0x6f, 0x6e, 0x73, 0x65,
+	0x22, 0x5e, 0x0a, 0x16, 0x51, 0x75, 0x65, 0x72, 0x79, 0x49, 0x6e, 0x73, 0x65, 0x72, 0x74, 0x53,
+	0x74, 0x6f, 0x72, 0x61, 0x67, 0x65, 0x43, 0x65, 0x6c, 0x6c, 0x12, 0x18, 0x0a, 0x07, 0x61, 0x64,
+	0x64, 0x72, 0x65, 0x73, 0x73, 0x18, 0x01, 0x20, 0x01, 0x28, 0x0c, 0x52, 0x07, 0x61, 0x64, 0x64,
+	0x72, 0x65, 0x73, 0x73, 0x12, 0x14, 0x0a, 0x05, 0x69, 0x6e, 0x64, 0x65, 0x78, 0x18, 0x02, 0x20,
+	0x01, 0x28, 0x0c, 0x52, 0x05, 0x69, 0x6e, 0x64, 0x65, 0x78, 0x12, 0x14, 0x0a, 0x05, 0x76, 0x61,
+	0x6c, 0x75, 0x65, 0x18, 0x03, 0x20, 0x01, 0x28, 0x0c, 0x52, 0x05, 0x76, 0x61, 0x6c, 0x75, 0x65,
+	0x22, 0x20, 0x0a, 0x1e, 0x51, 0x75, 0x65, 0x72, 0x79, 0x49, 0x6e, 0x73, 0x65, 0x72, 0x74, 0x53,
+	0x74, 0x6f, 0x72, 0x61, 0x67, 0x65, 0x43, 0x65, 0x6c, 0x6c, 0x52, 0x65, 0x73, 0x70, 0x6f, 0x6e,
+	0x73, 0x65, 0x22, 0x27, 0x0a, 0x0b, 0x51, 0x75, 0x65, 0x72, 0x79, 0x52, 0x65, 0x6d, 0x6f, 0x76,
+	0x65, 0x12, 0x18, 0x0a, 0x07, 0x61, 0x64, 0x64, 0x72, 0x65, 0x73, 0x73, 0x18, 0x01, 0x20, 0x01,
+	0x28, 0x0c, 0x52, 0x07, 0x61, 0x64, 0x64, 0x72, 0x65, 0x73, 0x73, 0x22, 0x15, 0x0a, 0x13, 0x51,
+	0x75, 0x65, 0x72, 0x79, 0x52, 0x65, 0x6d, 0x6f, 0x76, 0x65, 0x52, 0x65, 0x73, 0x70, 0x6f, 0x6e,
+	0x73, 0x65, 0x22, 0x48, 0x0a, 0x16, 0x51, 0x75, 0x65, 0x72, 0x79, 0x52, 0x65, 0x6d, 0x6f, 0x76,
+	0x65, 0x53, 0x74, 0x6f, 0x72, 0x61, 0x67, 0x65, 0x43, 0x65, 0x6c, 0x6c, 0x12, 0x18, 0x0a, 0x07,
+	0x61, 0x64, 0x64, 0x72, 0x65, 0x73, 0x73, 0x18, 0x01, 0x20, 0x01, 0x28, 0x0c, 0x52, 0x07, 0x61,
+	0x64, 0x64, 0x72, 0x65, 0x73, 0x73, 0x12, 0x14, 0x0a, 0x05, 0x69, 0x6e, 0x64, 0x65, 0x78, 0x18,
+	0x02, 0x20, 0x01, 0x28, 0x0c, 0x52, 0x05, 0x69, 0x6e, 0x64, 0x65, 0x78, 0x22, 0x20, 0x0a, 0x1e,
+	0x51, 0x75, 0x65, 0x72, 0x79, 0x52, 0x65, 0x6d, 0x6f, 0x76, 0x65, 0x53, 0x74, 0x6f, 0x72, 0x61,
+	0x67, 0x65, 0x43, 0x65, 0x6c, 0x6c, 0x52, 0x65, 0x73, 0x70, 0x6f, 0x6e, 0x73, 0x65, 0x22, 0x2e,
+	0x0a, 0x12, 0x51, 0x75, 0x65, 0x72, 0x79, 0x52, 0x65, 0x6d, 0x6f, 0x76, 0x65, 0x53, 0x74, 0x6f,
+	0x72, 0x61, 0x67, 0x65, 0x12, 0x18, 0x0a, 0x07, 0x61, 0x64, 0x64, 0x72, 0x65, 0x73, 0x73, 0x18,
+	0x01, 0x20, 0x01, 0x28, 0x0c, 0x52, 0x07, 0x61, 0x64, 0x64, 0x72, 0x65, 0x73, 0x73, 0x22, 0x1c,
+	0x0a, 0x1a, 0x51, 0x75, 0x65, 0x72, 0x79, 0x52, 0x65, 0x6d, 0x6f, 0x76, 0x65, 0x53, 0x74, 0x6f,
+	0x72, 0x61, 0x67, 0x65, 0x52, 0x65, 0x73, 0x70, 0x6f, 0x6e, 0x73, 0x65, 0x22, 0x28, 0x0a, 0x0e,
+	0x51, 0x75, 0x65, 0x72, 0x79, 0x42, 0x6c, 0x6f, 0x63, 0x6b, 0x48, 0x61, 0x73, 0x68, 0x12, 0x16,
+	0x0a, 0x06, 0x6e, 0x75, 0x6d, 0x62, 0x65, 0x72, 0x18, 0x01, 0x20, 0x01, 0x28, 0x0c, 0x52, 0x06,
+	0x6e, 0x75, 0x6d, 0x62, 0x65, 0x72, 0x22, 0x2c, 0x0a, 0x16, 0x51, 0x75, 0x65, 0x72, 0x79, 0x42,
+	0x6c, 0x6f, 0x63, 0x6b, 0x48, 0x61, 0x73, 0x68, 0x52, 0x65, 0x73, 0x70, 0x6f, 0x6e, 0x73, 0x65,
+	0x12, 0x12, 0x0a, 0x04, 0x68, 0x61, 0x73, 0x68, 0x18, 0x01, 0x20, 0x01, 0x28, 0x0c, 0x52, 0x04,
+	0x68, 0x61, 0x73, 0x68, 0x22, 0xc3, 0x01, 0x0a, 0x0e, 0x51, 0x75, 0x65, 0x72, 0x79, 0x42, 0x61,
+	0x74, 0x63, 0x68, 0x52, 0x65, 0x61, 0x64, 0x12, 0x34, 0x0a, 0x08, 0x61, 0x63, 0x63, 0x6f, 0x75,
+	0x6e, 0x74, 0x73, 0x18, 0x01, 0x20, 0x03, 0x28, 0x0b, 0x32, 0x18, 0x2e, 0x66, 0x66, 0x69, 0x2e,
+	0x66, 0x66, 0x69, 0x2e, 0x51, 0x75, 0x65, 0x72, 0x79, 0x47, 0x65, 0x74, 0x41, 0x63, 0x63, 0x6f,
+	0x75, 0x6e, 0x74, 0x52, 0x08, 0x61, 0x63, 0x63, 0x6f, 0x75, 0x6e, 0x74, 0x73, 0x12, 0x32, 0x0a,
+	0x05, 0x63, 0x6f, 0x64, 0x65, 0x73, 0x18, 0x02, 0x20, 0x03, 0x28, 0x0b, 0x32, 0x1c, 0x2e, 0x66,
+	0x66, 0x69, 0x2e, 0x66, 0x66, 0x69, 0x2e, 0x51, 0x75, 0x65, 0x72, 0x79, 0x47, 0x65, 0x74, 0x41,
+	0x63, 0x63, 0x6f, 0x75, 0x6e, 0x74, 0x43, 0x6f, 0x64, 0x65, 0x52, 0x05, 0x63, 0x6f, 0x64, 0x65,
+	0x73, 0x12, 0x47, 0x0a, 0x0c, 0x73, 0x74, 0x6f, 0x72, 0x61, 0x67, 0x65, 0x43, 0x65, 0x6c, 0x6c,
+	0x73, 0x18, 0x03, 0x20, 0x03, 0x28, 0x0b, 0x32, 0x23, 0x2e, 0x66, 0x66, 0x69, 0x2e, 0x66, 0x66,
+	0x69, 0x2e, 0x51, 0x75, 0x65, 0x72, 0x79, 0x47, 0x65, 0x74, 0x41, 0x63, 0x63, 0x6f, 0x75, 0x6e,
+	0x74, 0x53, 0x74, 0x6f, 0x72, 0x61, 0x67, 0x65, 0x43, 0x65, 0x6c, 0x6c, 0x52, 0x0c, 0x73, 0x74,
+	0x6f, 0x72, 0x61, 0x67, 0x65, 0x43, 0x65, 0x6c, 0x6c, 0x73, 0x22, 0xe3, 0x01, 0x0a, 0x16, 0x51,
+	0x75, 0x65, 0x72, 0x79, 0x42, 0x61, 0x74, 0x63, 0x68, 0x52, 0x65, 0x61, 0x64, 0x52, 0x65, 0x73,
+	0x70, 0x6f, 0x6e, 0x73, 0x65, 0x12, 0x3c, 0x0a, 0x08, 0x61, 0x63, 0x63, 0x6f, 0x75, 0x6e, 0x74,
+	0x73, 0x18, 0x01, 0x20, 0x03, 0x28, 0x0b, 0x32, 0x20, 0x2e, 0x66, 0x66, 0x69, 0x2e, 0x66, 0x66,
+	0x69, 0x2e, 0x51, 0x75, 0x65, 0x72, 0x79, 0x47, 0x65, 0x74, 0x41, 0x63, 0x63, 0x6f, 0x75, 0x6e,
+	0x74, 0x52, 0x65, 0x73, 0x70, 0x6f, 0x6e, 0x73, 0x65, 0x52, 0x08, 0x61, 0x63, 0x63, 0x6f, 0x75,
+	0x6e, 0x74, 0x73, 0x12, 0x3a, 0x0a, 0x05, 0x63, 0x6f, 0x64, 0x65, 0x73, 0x18, 0x02, 0x20, 0x03,
+	0x28, 0x0b, 0x32, 0x24, 0x2e, 0x66, 0x66, 0x69, 0x2e, 0x66, 0x66, 0x69, 0x2e, 0x51, 0x75, 0x65,
+	0x72, 0x79, 0x47, 0x65, 0x74, 0x41, 0x63, 0x63, 0x6f, 0x75, 0x6e, 0x74, 0x43, 0x6f, 0x64, 0x65,
+	0x52, 0x65, 0x73, 0x70, 0x6f, 0x6e, 0x73, 0x65, 0x52, 0x05, 0x63, 0x6f, 0x64, 0x65, 0x73, 0x12,
+	0x4f, 0x0a, 0x0c, 0x73, 0x74, 0x6f, 0x72, 0x61, 0x67, 0x65, 0x43, 0x65, 0x6c, 0x6c, 0x73, 0x18,
+	0x03, 0x20, 0x03, 0x28, 0x0b, 0x32, 0x2b, 0x2e, 0x66, 0x66, 0x69, 0x2e, 0x66, 0x66, 0x69, 0x2e,
+	0x51, 0x75, 0x65, 0x72, 0x79, 0x47, 0x65, 0x74, 0x41, 0x63, 0x63, 0x6f, 0x75, 0x6e, 0x74, 0x53,
+	0x74, 0x6f, 0x72, 0x61, 0x67, 0x65, 0x43, 0x65, 0x6c, 0x6c, 0x52, 0x65, 0x73, 0x70, 0x6f, 0x6e,
+	0x73, 0x65, 0x52, 0x0c, 0x73, 0x74, 0x6f, 0x72, 0x61, 0x67, 0x65, 0x43, 0x65, 0x6c, 0x6c, 0x73,
+	0x22, 0xb3, 0x02, 0x0a, 0x0b, 0x51, 0x75, 0x65, 0x72, 0x79, 0x43, 0x6f, 0x6d, 0x6d, 0x69, 0x74,
+	0x12, 0x2e, 0x0a, 0x07, 0x72, 0x65, 0x6d, 0x6f, 0x76, 0x65, 0x64, 0x18, 0x01, 0x20, 0x03, 0x28,
+	0x0b, 0x32, 0x14, 0x2e, 0x66, 0x66, 0x69, 0x2e, 0x66, 0x66, 0x69, 0x2e, 0x51, 0x75, 0x65, 0x72,
+	0x79, 0x52, 0x65, 0x6d, 0x6f, 0x76, 0x65, 0x52, 0x07, 0x72, 0x65, 0x6d, 0x6f, 0x76, 0x65, 0x64,
+	0x12, 0x3f, 0x0a, 0x0c, 0x72, 0x65, 0x73, 0x65, 0x74, 0x53, 0x74, 0x6f, 0x72, 0x61, 0x67, 0x65,
+	0x18, 0x02, 0x20, 0x03, 0x28, 0x0b, 0x32, 0x1b, 0x2e, 0x66, 0x66, 0x69, 0x2e, 0x66, 0x66, 0x69,
+	0x2e, 0x51, 0x75, 0x65, 0x72, 0x79, 0x52, 0x65, 0x6d, 0x6f, 0x76, 0x65, 0x53, 0x74, 0x6f, 0x72,
+	0x61, 0x67, 0x65, 0x52, 0x0c, 0x72, 0x65, 0x73, 0x65, 0x74, 0x53, 0x74, 0x6f, 0x72, 0x61, 0x67,
+	0x65, 0x12, 0x37, 0x0a, 0x08, 0x61, 0x63, 0x63, 0x6f, 0x75, 0x6e, 0x74, 0x73, 0x18, 0x03, 0x20,
+	0x03, 0x28, 0x0b, 0x32, 0x1b, 0x2e, 0x66, 0x66, 0x69, 0x2e, 0x66, 0x66, 0x69, 0x2e, 0x51, 0x75,
+	0x65, 0x72, 0x79, 0x49, 0x6e, 0x73, 0x65, 0x72, 0x74, 0x41, 0x63, 0x63, 0x6f, 0x75, 0x6e, 0x74,
+	0x52, 0x08, 0x61, 0x63, 0x63, 0x6f, 0x75, 0x6e, 0x74, 0x73, 0x12, 0x35, 0x0a, 0x05, 0x63, 0x6f,
+	0x64, 0x65, 0x73, 0x18, 0x04, 0x20, 0x03, 0x28, 0x0b, 0x32, 0x1f, 0x2e, 0x66, 0x66, 0x69, 0x2e,
+	0x66, 0x66, 0x69, 0x2e, 0x51, 0x75, 0x65, 0x72, 0x79, 0x49, 0x6e, 0x73, 0x65, 0x72, 0x74, 0x41,
+	0x63, 0x63, 0x6f, 0x75, 0x6e, 0x74, 0x43, 0x6f, 0x64, 0x65, 0x52, 0x05, 0x63, 0x6f, 0x64, 0x65,
+	0x73, 0x12, 0x43, 0x0a, 0x0c, 0x73, 0x74, 0x6f, 0x72, 0x61, 0x67, 0x65, 0x43, 0x65, 0x6c, 0x6c,
+	0x73, 0x18, 0x05, 0x20, 0x03, 0x28, 0x0b, 0x32, 0x1f, 0x2e, 0x66, 0x66, 0x69, 0x2e, 0x66, 0x66,
+	0x69, 0x2e, 0x51, 0x75, 0x65, 0x72, 0x79, 0x49, 0x6e, 0x73, 0x65, 0x72, 0x74, 0x53, 0x74, 0x6f,
+	0x72, 0x61, 0x67, 0x65, 0x43, 0x65, 0x6c, 0x6c, 0x52, 0x0c, 0x73, 0x74, 0x6f, 0x72, 0x61, 0x67,
+	0x65, 0x43, 0x65, 0x6c, 0x6c, 0x73, 0x22, 0x15, 0x0a, 0x13, 0x51, 0x75, 0x65, 0x72, 0x79, 0x43,
+	0x6f, 0x6d, 0x6d, 0x69, 0x74, 0x52, 0x65, 0x73, 0x70, 0x6f, 0x6e, 0x73, 0x65, 0x22, 0x42, 0x0a,
+	0x10, 0x51, 0x75, 0x65, 0x72, 0x79, 0x42, 0x61, 0x6e, 0x6b, 0x42, 0x61, 0x6c, 0x61, 0x6e, 0x63,
+	0x65, 0x12, 0x18, 0x0a, 0x07, 0x61, 0x64, 0x64, 0x72, 0x65, 0x73, 0x73, 0x18, 0x01, 0x20, 0x01,
+	0x28, 0x0c, 0x52, 0x07, 0x61, 0x64, 0x64, 0x72, 0x65, 0x73, 0x73, 0x12, 0x14, 0x0a, 0x05, 0x64,
+	0x65, 0x6e, 0x6f, 0x6d, 0x18, 0x02, 0x20, 0x01, 0x28, 0x09, 0x52, 0x05, 0x64, 0x65, 0x6e, 0x6f,
+	0x6d, 0x22, 0x34, 0x0a, 0x18, 0x51, 0x75, 0x65, 0x72, 0x79, 0x42, 0x61, 0x6e, 0x6b, 0x42, 0x61,
+	0x6c, 0x61, 0x6e, 0x63, 0x65, 0x52, 0x65, 0x73, 0x70, 0x6f, 0x6e, 0x73, 0x65, 0x12, 0x18, 0x0a,
+	0x07, 0x62, 0x61, 0x6c, 0x61, 0x6e, 0x63, 0x65, 0x18, 0x01, 0x20, 0x01, 0x28, 0x0c, 0x52, 0x07,
+	0x62, 0x61, 0x6c, 0x61, 0x6e, 0x63, 0x65, 0x22, 0xaa, 0x07, 0x0a, 0x0d, 0x43, 0x6f, 0x73, 0x6d,
+	0x6f, 0x73, 0x52, 0x65, 0x71, 0x75, 0x65, 0x73, 0x74, 0x12, 0x3a, 0x0a, 0x0a, 0x67, 0x65, 0x74,
+	0x41, 0x63, 0x63, 0x6f, 0x75, 0x6e, 0x74, 0x18, 0x01, 0x20, 0x01, 0x28, 0x0b, 0x32, 0x18, 0x2e,
+	0x66, 0x66, 0x69, 0x2e, 0x66, 0x66, 0x69, 0x2e, 0x51, 0x75, 0x65, 0x72, 0x79, 0x47, 0x65, 0x74,
+	0x41, 0x63, 0x63, 0x6f, 0x75, 0x6e, 0x74, 0x48, 0x00, 0x52, 0x0a, 0x67, 0x65, 0x74, 0x41, 0x63,
+	0x63, 0x6f, 0x75, 0x6e, 0x74, 0x12, 0x43, 0x0a, 0x0d, 0x69, 0x6e, 0x73, 0x65, 0x72, 0x74, 0x41,
+	0x63, 0x63, 0x6f, 0x75, 0x6e, 0x74, 0x18, 0x02, 0x20, 0x01, 0x28, 0x0b, 0x32, 0x1b, 0x2e, 0x66,
+	0x66, 0x69, 0x2e, 0x66, 0x66, 0x69, 0x2e, 0x51, 0x75, 0x65, 0x72, 0x79, 0x49, 0x6e, 0x73, 0x65,
+	0x72, 0x74, 0x41, 0x63, 0x63, 0x6f, 0x75, 0x6e, 0x74, 0x48, 0x00, 0x52, 0x0d, 0x69, 0x6e, 0x73,
+	0x65, 0x72, 0x74, 0x41, 0x63, 0x63, 0x6f, 0x75, 0x6e, 0x74, 0x12, 0x3d, 0x0a, 0x0b, 0x63, 0x6f,
+	0x6e, 0x74, 0x61, 0x69, 0x6e, 0x73, 0x4b, 0x65, 0x79, 0x18, 0x03, 0x20, 0x01, 0x28, 0x0b, 0x32,
+	0x19, 0x2e, 0x66, 0x66, 0x69, 0x2e, 0x66, 0x66, 0x69, 0x2e, 0x51, 0x75, 0x65, 0x72, 0x79, 0x43,
+	0x6f, 0x6e, 0x74, 0x61, 0x69, 0x6e, 0x73, 0x4b, 0x65, 0x79, 0x48, 0x00, 0x52, 0x0b, 0x63, 0x6f,
+	0x6e, 0x74, 0x61, 0x69, 0x6e, 0x73, 0x4b, 0x65, 0x79, 0x12, 0x40, 0x0a, 0x0b, 0x61, 0x63, 0x63,
+	0x6f, 0x75, 0x6e, 0x74, 0x43, 0x6f, 0x64, 0x65, 0x18, 0x04, 0x20, 0x01, 0x28, 0x0b, 0x32, 0x1c,
+	0x2e, 0x66, 0x66, 0x69, 0x2e, 0x66, 0x66, 0x69, 0x2e, 0x51, 0x75, 0x65, 0x72, 0x79, 0x47, 0x65,
+	0x74, 0x41, 0x63, 0x63, 0x6f, 0x75, 0x6e, 0x74, 0x43, 0x6f, 0x64, 0x65, 0x48, 0x00, 0x52, 0x0b,
+	0x61, 0x63, 0x63, 0x6f, 0x75, 0x6e, 0x74, 0x43, 0x6f, 0x64, 0x65, 0x12, 0x47, 0x0a, 0x0b, 0x73,
+	0x74, 0x6f, 0x72, 0x61, 0x67, 0x65, 0x43, 0x65, 0x6c, 0x6c, 0x18, 0x05, 0x20, 0x01, 0x28, 0x0b,
+	0x32, 0x23, 0x2e, 0x66, 0x66, 0x69, 0x2e, 0x66, 0x66, 0x69, 0x2e, 0x51, 0x75, 0x65, 0x72, 0x79,
+	0x47, 0x65, 0x74, 0x41, 0x63, 0x63, 0x6f, 0x75, 0x6e, 0x74, 0x53, 0x74, 0x6f, 0x72, 0x61, 0x67,
+	0x65, 0x43, 0x65, 0x6c, 0x6c, 0x48, 0x00, 0x52, 0x0b, 0x73, 0x74, 0x6f, 0x72, 0x61, 0x67, 0x65,
+	0x43, 0x65, 0x6c, 0x6c, 0x12, 0x4f, 0x0a, 0x11, 0x69, 0x6e, 0x73, 0x65, 0x72, 0x74, 0x41, 0x63,
+	0x63, 0x6f, 0x75, 0x6e, 0x74, 0x43, 0x6f, 0x64, 0x65, 0x18, 0x06, 0x20, 0x01, 0x28, 0x0b, 0x32,
+	0x1f, 0x2e, 0x66, 0x66, 0x69, 0x2e, 0x66, 0x66, 0x69, 0x2e, 0x51, 0x75, 0x65, 0x72, 0x79, 0x49,
+	0x6e, 0x73, 0x65, 0x72, 0x74, 0x41, 0x63, 0x63, 0x6f, 0x75, 0x6e, 0x74, 0x43, 0x6f, 0x64, 0x65,
+	0x48, 0x00, 0x52, 0x11, 0x69, 0x6e, 0x73, 0x65, 0x72, 0x74, 0x41, 0x63, 0x63, 0x6f, 0x75, 0x6e,
+	0x74, 0x43, 0x6f, 0x64, 0x65, 0x12, 0x4f, 0x0a, 0x11, 0x69, 0x6e, 0x73, 0x65, 0x72, 0x74, 0x53,
+	0x74, 0x6f, 0x72, 0x61, 0x67, 0x65, 0x43, 0x65, 0x6c, 0x6c, 0x18, 0x07, 0x20, 0x01, 0x28, 0x0b,
+	0x32, 0x1f, 0x2e, 0x66, 0x66, 0x69, 0x2e, 0x66, 0x66, 0x69, 0x2e, 0x51, 0x75, 0x65, 0x72, 0x79,
+	0x49, 0x6e, 0x73, 0x65, 0x72, 0x74, 0x53, 0x74, 0x6f, 0x72, 0x61, 0x67, 0x65, 0x43, 0x65, 0x6c,
+	0x6c, 0x48, 0x00, 0x52, 0x11, 0x69, 0x6e, 0x73, 0x65, 0x72, 0x74, 0x53, 0x74, 0x6f, 0x72, 0x61,
+	0x67, 0x65, 0x43, 0x65, 0x6c, 0x6c, 0x12, 0x2e, 0x0a, 0x06, 0x72, 0x65, 0x6d, 0x6f, 0x76, 0x65,
+	0x18, 0x08, 0x20, 0x01, 0x28, 0x0b, 0x32, 0x14, 0x2e, 0x66, 0x66, 0x69, 0x2e, 0x66, 0x66, 0x69,
+	0x2e, 0x51, 0x75, 0x65, 0x72, 0x79, 0x52, 0x65, 0x6d, 0x6f, 0x76, 0x65, 0x48, 0x00, 0x52, 0x06,
+	0x72, 0x65, 0x6d, 0x6f, 0x76, 0x65, 0x12, 0x4f, 0x0a, 0x11, 0x72, 0x65, 0x6d, 0x6f, 0x76, 0x65,
+	0x53, 0x74, 0x6f, 0x72, 0x61, 0x67, 0x65, 0x43, 0x65, 0x6c, 0x6c, 0x18, 0x09, 0x20, 0x01, 0x28,
+	0x0b, 0x32, 0x1f, 0x2e, 0x66, 0x66, 0x69, 0x2e, 0x66, 0x66, 0x69, 0x2e, 0x51, 0x75, 0x65, 0x72,
+	0x79, 0x52, 0x65, 0x6d, 0x6f, 0x76, 0x65, 0x53, 0x74, 0x6f, 0x72, 0x61, 0x67, 0x65, 0x43, 0x65,
+	0x6c, 0x6c, 0x48, 0x00, 0x52, 0x11, 0x72, 0x65, 0x6d, 0x6f, 0x76, 0x65, 0x53, 0x74, 0x6f, 0x72,
+	0x61, 0x67, 0x65, 0x43, 0x65, 0x6c, 0x6c, 0x12, 0x43, 0x0a, 0x0d, 0x72, 0x65, 0x6d, 0x6f, 0x76,
+	0x65, 0x53, 0x74, 0x6f, 0x72, 0x61, 0x67, 0x65, 0x18, 0x0a, 0x20, 0x01, 0x28, 0x0b, 0x32, 0x1b,
+	0x2e, 0x66, 0x66, 0x69, 0x2e, 0x66, 0x66, 0x69, 0x2e, 0x51, 0x75, 0x65, 0x72, 0x79, 0x52, 0x65,
+	0x6d, 0x6f, 0x76, 0x65, 0x53, 0x74, 0x6f, 0x72, 0x61, 0x67, 0x65, 0x48, 0x00, 0x52, 0x0d, 0x72,
+	0x65, 0x6d, 0x6f, 0x76, 0x65, 0x53, 0x74, 0x6f, 0x72, 0x61, 0x67, 0x65, 0x12, 0x37, 0x0a, 0x09,
+	0x62, 0x6c, 0x6f, 0x63, 0x6b, 0x48, 0x61, 0x73, 0x68, 0x18, 0x0b, 0x20, 0x01, 0x28, 0x0b, 0x32,
+	0x17, 0x2e, 0x66, 0x66, 0x69, 0x2e, 0x66, 0x66, 0x69, 0x2e, 0x51, 0x75, 0x65, 0x72, 0x79, 0x42,
+	0x6c, 0x6f, 0x63, 0x6b, 0x48, 0x61, 0x73, 0x68, 0x48, 0x00, 0x52, 0x09, 0x62, 0x6c, 0x6f, 0x63,
+	0x6b, 0x48, 0x61, 0x73, 0x68, 0x12, 0x37, 0x0a, 0x09, 0x62, 0x61, 0x74, 0x63, 0x68, 0x52, 0x65,
+	0x61, 0x64, 0x18, 0x0c, 0x20, 0x01, 0x28, 0x0b, 0x32, 0x17, 0x2e, 0x66, 0x66, 0x69, 0x2e, 0x66,
+	0x66, 0x69, 0x2e, 0x51, 0x75, 0x65, 0x72, 0x79, 0x42, 0x61, 0x74, 0x63, 0x68, 0x52, 0x65, 0x61,
+	0x64, 0x48, 0x00, 0x52, 0x09, 0x62, 0x61, 0x74, 0x63, 0x68, 0x52, 0x65, 0x61, 0x64, 0x12, 0x2e,
+	0x0a, 0x06, 0x63, 0x6f, 0x6d, 0x6d, 0x69, 0x74, 0x18, 0x0d, 0x20, 0x01, 0x28, 0x0b, 0x32, 0x14,
+	0x2e, 0x66, 0x66, 0x69, 0x2e, 0x66, 0x66, 0x69, 0x2e, 0x51, 0x75, 0x65, 0x72, 0x79, 0x43, 0x6f,
+	0x6d, 0x6d, 0x69, 0x74, 0x48, 0x00, 0x52, 0x06, 0x63, 0x6f, 0x6d, 0x6d, 0x69, 0x74, 0x12, 0x3d,
+	0x0a, 0x0b, 0x62, 0x61, 0x6e, 0x6b, 0x42, 0x61, 0x6c, 0x61, 0x6e, 0x63, 0x65, 0x18, 0x0e, 0x20,
+	0x01, 0x28, 0x0b, 0x32, 0x19, 0x2e, 0x66, 0x66, 0x69, 0x2e, 0x66, 0x66, 0x69, 0x2e, 0x51, 0x75,
+	0x65, 0x72, 0x79, 0x42, 0x61, 0x6e, 0x6b, 0x42, 0x61, 0x6c, 0x61, 0x6e, 0x63, 0x65, 0x48, 0x00,
+	0x52, 0x0b, 0x62, 0x61, 0x6e, 0x6b, 0x42, 0x61, 0x6c, 0x61, 0x6e, 0x63, 0x65, 0x42, 0x05, 0x0a,
+	0x03, 0x72, 0x65, 0x71, 0x22, 0xfa, 0x01, 0x0a, 0x0f, 0x53, 0x47, 0x58, 0x56, 0x4d, 0x43, 0x61,
+	0x6c, 0x6c, 0x50, 0x61, 0x72, 0x61, 0x6d, 0x73, 0x12, 0x12, 0x0a, 0x04, 0x66, 0x72, 0x6f, 0x6d,
+	0x18, 0x01, 0x20, 0x01, 0x28, 0x0c, 0x52, 0x04, 0x66, 0x72, 0x6f, 0x6d, 0x12, 0x0e, 0x0a, 0x02,
+	0x74, 0x6f, 0x18, 0x02, 0x20, 0x01, 0x28, 0x0c, 0x52, 0x02, 0x74, 0x6f, 0x12, 0x12, 0x0a, 0x04,
+	0x64, 0x61, 0x74, 0x61, 0x18, 0x03, 0x20, 0x01, 0x28, 0x0c, 0x52, 0x04, 0x64, 0x61, 0x74, 0x61,
+	0x12, 0x1a, 0x0a, 0x08, 0x67, 0x61, 0x73, 0x4c, 0x69, 0x6d, 0x69, 0x74, 0x18, 0x04, 0x20, 0x01,
+	0x28, 0x04, 0x52, 0x08, 0x67, 0x61, 0x73, 0x4c, 0x69, 0x6d, 0x69, 0x74, 0x12, 0x14, 0x0a, 0x05,
+	0x76, 0x61, 0x6c, 0x75, 0x65, 0x18, 0x05, 0x20, 0x01, 0x28, 0x0c, 0x52, 0x05, 0x76, 0x61, 0x6c,
+	0x75, 0x65, 0x12, 0x37, 0x0a, 0x0a, 0x61, 0x63, 0x63, 0x65, 0x73, 0x73, 0x4c, 0x69, 0x73, 0x74,
+	0x18, 0x06, 0x20, 0x03, 0x28, 0x0b, 0x32, 0x17, 0x2e, 0x66, 0x66, 0x69, 0x2e, 0x66, 0x66, 0x69,
+	0x2e, 0x41, 0x63, 0x63, 0x65, 0x73, 0x73, 0x4c, 0x69, 0x73, 0x74, 0x49, 0x74, 0x65, 0x6d, 0x52,
+	0x0a, 0x61, 0x63, 0x63, 0x65, 0x73, 0x73, 0x4c, 0x69, 0x73, 0x74, 0x12, 0x16, 0x0a, 0x06, 0x63,
+	0x6f, 0x6d, 0x6d, 0x69, 0x74, 0x18, 0x07, 0x20, 0x01, 0x28, 0x08, 0x52, 0x06, 0x63, 0x6f, 0x6d,
+	0x6d, 0x69, 0x74, 0x12, 0x14, 0x0a, 0x05, 0x6e, 0x6f, 0x6e, 0x63, 0x65, 0x18, 0x08, 0x20, 0x01,
+	0x28, 0x04, 0x52, 0x05, 0x6e, 0x6f, 0x6e, 0x63, 0x65, 0x12, 0x16, 0x0a, 0x06, 0x74, 0x78, 0x54,
+	0x79, 0x70, 0x65, 0x18, 0x09, 0x20, 0x01, 0x28, 0x0d, 0x52, 0x06, 0x74, 0x78, 0x54, 0x79, 0x70,
+	0x65, 0x22, 0xec, 0x01, 0x0a, 0x11, 0x53, 0x47, 0x58, 0x56, 0x4d, 0x43, 0x72, 0x65, 0x61, 0x74,
+	0x65, 0x50, 0x61, 0x72, 0x61, 0x6d, 0x73, 0x12, 0x12, 0x0a, 0x04, 0x66, 0x72, 0x6f, 0x6d, 0x18,
+	0x01, 0x20, 0x01, 0x28, 0x0c, 0x52, 0x04, 0x66, 0x72, 0x6f, 0x6d, 0x12, 0x12, 0x0a, 0x04, 0x64,
+	0x61, 0x74, 0x61, 0x18, 0x02, 0x20, 0x01, 0x28, 0x0c, 0x52, 0x04, 0x64, 0x61, 0x74, 0x61, 0x12,
+	0x1a, 0x0a, 0x08, 0x67, 0x61, 0x73, 0x4c, 0x69, 0x6d, 0x69, 0x74, 0x18, 0x03, 0x20, 0x01, 0x28,
+	0x04, 0x52, 0x08, 0x67, 0x61, 0x73, 0x4c, 0x69, 0x6d, 0x69, 0x74, 0x12, 0x14, 0x0a, 0x05, 0x76,
+	0x61, 0x6c, 0x75, 0x65, 0x18, 0x04, 0x20, 0x01, 0x28, 0x0c, 0x52, 0x05, 0x76, 0x61, 0x6c, 0x75,
+	0x65, 0x12, 0x37, 0x0a, 0x0a, 0x61, 0x63, 0x63, 0x65, 0x73, 0x73, 0x4c, 0x69, 0x73, 0x74, 0x18,
+	0x05, 0x20, 0x03, 0x28, 0x0b, 0x32, 0x17, 0x2e, 0x66, 0x66, 0x69, 0x2e, 0x66, 0x66, 0x69, 0x2e,
+	0x41, 0x63, 0x63, 0x65, 0x73, 0x73, 0x4c, 0x69, 0x73, 0x74, 0x49, 0x74, 0x65, 0x6d, 0x52, 0x0a,
+	0x61, 0x63, 0x63, 0x65, 0x73, 0x73, 0x4c, 0x69, 0x73, 0x74, 0x12, 0x16, 0x0a, 0x06, 0x63, 0x6f,
+	0x6d, 0x6d, 0x69, 0x74, 0x18, 0x06, 0x20, 0x01, 0x28, 0x08, 0x52, 0x06, 0x63, 0x6f, 0x6d, 0x6d,
+	0x69, 0x74, 0x12, 0x14, 0x0a, 0x05, 0x6e, 0x6f, 0x6e, 0x63, 0x65, 0x18, 0x07, 0x20, 0x01, 0x28,
+	0x04, 0x52, 0x05, 0x6e, 0x6f, 0x6e, 0x63, 0x65, 0x12, 0x16, 0x0a, 0x06, 0x74, 0x78, 0x54, 0x79,
+	0x70, 0x65, 0x18, 0x08, 0x20, 0x01, 0x28, 0x0d, 0x52, 0x06, 0x74, 0x78, 0x54, 0x79, 0x70, 0x65,
+	0x22, 0x7b, 0x0a, 0x10, 0x53, 0x47, 0x58, 0x56, 0x4d, 0x43, 0x61, 0x6c, 0x6c, 0x52, 0x65, 0x71,
+	0x75, 0x65, 0x73, 0x74, 0x12, 0x30, 0x0a, 0x06, 0x70, 0x61, 0x72, 0x61, 0x6d, 0x73, 0x18, 0x01,
+	0x20, 0x01, 0x28, 0x0b, 0x32, 0x18, 0x2e, 0x66, 0x66, 0x69, 0x2e, 0x66, 0x66, 0x69, 0x2e, 0x53,
+	0x47, 0x58, 0x56, 0x4d, 0x43, 0x61, 0x6c, 0x6c, 0x50, 0x61, 0x72, 0x61, 0x6d, 0x73, 0x52, 0x06,
+	0x70, 0x61, 0x72, 0x61, 0x6d, 0x73, 0x12, 0x35, 0x0a, 0x07, 0x63, 0x6f, 0x6e, 0x74, 0x65, 0x78,
+	0x74, 0x18, 0x02, 0x20, 0x01, 0x28, 0x0b, 0x32, 0x1b, 0x2e, 0x66, 0x66, 0x69, 0x2e, 0x66, 0x66,
+	0x69, 0x2e, 0x54, 0x72, 0x61, 0x6e, 0x73, 0x61, 0x63, 0x74, 0x69, 0x6f, 0x6e, 0x43, 0x6f, 0x6e,
+	0x74, 0x65, 0x78, 0x74, 0x52, 0x07, 0x63, 0x6f, 0x6e, 0x74, 0x65, 0x78, 0x74, 0x22, 0x7f, 0x0a,
+	0x12, 0x53, 0x47, 0x58, 0x56, 0x4d, 0x43, 0x72, 0x65, 0x61, 0x74, 0x65, 0x52, 0x65, 0x71, 0x75,
+	0x65, 0x73, 0x74, 0x12, 0x32, 0x0a, 0x06, 0x70, 0x61, 0x72, 0x61, 0x6d, 0x73, 0x18, 0x01, 0x20,
+	0x01, 0x28, 0x0b, 0x32, 0x1a, 0x2e, 0x66, 0x66, 0x69, 0x2e, 0x66, 0x66, 0x69, 0x2e, 0x53, 0x47,
+	0x58, 0x56, 0x4d, 0x43, 0x72, 0x65, 0x61, 0x74, 0x65, 0x50, 0x61, 0x72, 0x61, 0x6d, 0x73, 0x52,
+	0x06, 0x70, 0x61, 0x72, 0x61, 0x6d, 0x73, 0x12, 0x35, 0x0a, 0x07, 0x63, 0x6f, 0x6e, 0x74, 0x65,
+	0x78, 0x74, 0x18, 0x02, 0x20, 0x01, 0x28, 0x0b, 0x32, 0x1b, 0x2e, 0x66, 0x66, 0x69, 0x2e, 0x66,
+	0x66, 0x69, 0x2e, 0x54, 0x72, 0x61, 0x6e, 0x73, 0x61, 0x63, 0x74, 0x69, 0x6f, 0x6e, 0x43, 0x6f,
+	0x6e, 0x74, 0x65, 0x78, 0x74, 0x52, 0x07, 0x63, 0x6f, 0x6e, 0x74, 0x65, 0x78, 0x74, 0x22, 0xd9,
+	0x01, 0x0a, 0x09, 0x43, 0x61, 0x6c, 0x6c, 0x46, 0x72, 0x61, 0x6d, 0x65, 0x12, 0x12, 0x0a, 0x04,
+	0x74, 0x79, 0x70, 0x65, 0x18, 0x01, 0x20, 0x01, 0x28, 0x09, 0x52, 0x04, 0x74, 0x79, 0x70, 0x65,
+	0x12, 0x12, 0x0a, 0x04, 0x66, 0x72, 0x6f, 0x6d, 0x18, 0x02, 0x20, 0x01, 0x28, 0x0c, 0x52, 0x04,
+	0x66, 0x72, 0x6f, 0x6d, 0x12, 0x0e, 0x0a, 0x02, 0x74, 0x6f, 0x18, 0x03, 0x20, 0x01, 0x28, 0x0c,
+	0x52, 0x02, 0x74, 0x6f, 0x12, 0x14, 0x0a, 0x05, 0x76, 0x61, 0x6c, 0x75, 0x65, 0x18, 0x04, 0x20,
+	0x01, 0x28, 0x0c, 0x52, 0x05, 0x76, 0x61, 0x6c, 0x75, 0x65, 0x12, 0x10, 0x0a, 0x03, 0x67, 0x61,
+	0x73, 0x18, 0x05, 0x20, 0x01, 0x28, 0x04, 0x52, 0x03, 0x67, 0x61, 0x73, 0x12, 0x14, 0x0a, 0x05,
+	0x69, 0x6e, 0x70, 0x75, 0x74, 0x18, 0x06, 0x20, 0x01, 0x28, 0x0c, 0x52, 0x05, 0x69, 0x6e, 0x70,
+	0x75, 0x74, 0x12, 0x16, 0x0a, 0x06, 0x6f, 0x75, 0x74, 0x70, 0x75, 0x74, 0x18, 0x07, 0x20, 0x01,
+	0x28, 0x0c, 0x52, 0x06, 0x6f, 0x75, 0x74, 0x70, 0x75, 0x74, 0x12, 0x14, 0x0a, 0x05, 0x65, 0x72,
+	0x72, 0x6f, 0x72, 0x18, 0x08, 0x20, 0x01, 0x28, 0x09, 0x52, 0x05, 0x65, 0x72, 0x72, 0x6f, 0x72,
+	0x12, 0x28, 0x0a, 0x05, 0x63, 0x61, 0x6c, 0x6c, 0x73, 0x18, 0x09, 0x20, 0x03, 0x28, 0x0b, 0x32,
+	0x12, 0x2e, 0x66, 0x66, 0x69, 0x2e, 0x66, 0x66, 0x69, 0x2e, 0x43, 0x61, 0x6c, 0x6c, 0x46, 0x72,
+	0x61, 0x6d, 0x65, 0x52, 0x05, 0x63, 0x61, 0x6c, 0x6c, 0x73, 0x22, 0x88, 0x01, 0x0a, 0x18, 0x54,
+	0x72, 0x61, 0x63, 0x65, 0x54, 0x72, 0x61, 0x6e, 0x73, 0x61, 0x63, 0x74, 0x69, 0x6f, 0x6e, 0x52,
+	0x65, 0x73, 0x70, 0x6f, 0x6e, 0x73, 0x65, 0x12, 0x3a, 0x0a, 0x06, 0x72, 0x65, 0x73, 0x75, 0x6c,
+	0x74, 0x18, 0x01, 0x20, 0x01, 0x28, 0x0b, 0x32, 0x22, 0x2e, 0x66, 0x66, 0x69, 0x2e, 0x66, 0x66,
+	0x69, 0x2e, 0x48, 0x61, 0x6e, 0x64, 0x6c, 0x65, 0x54, 0x72, 0x61, 0x6e, 0x73, 0x61, 0x63, 0x74,
+	0x69, 0x6f, 0x6e, 0x52, 0x65, 0x73, 0x70, 0x6f, 0x6e, 0x73, 0x65, 0x52, 0x06, 0x72, 0x65, 0x73,
+	0x75, 0x6c, 0x74, 0x12, 0x30, 0x0a, 0x09, 0x63, 0x61, 0x6c, 0x6c, 0x54, 0x72, 0x61, 0x63, 0x65,
+	0x18, 0x02, 0x20, 0x01, 0x28, 0x0b, 0x32, 0x12, 0x2e, 0x66, 0x66, 0x69, 0x2e, 0x66, 0x66, 0x69,
+	0x2e, 0x43, 0x61, 0x6c, 0x6c, 0x46, 0x72, 0x61, 0x6d, 0x65, 0x52, 0x09, 0x63, 0x61, 0x6c, 0x6c,
+	0x54, 0x72, 0x61, 0x63, 0x65, 0x22, 0x5e, 0x0a, 0x12, 0x53, 0x74, 0x72, 0x75, 0x63, 0x74, 0x4c,
+	0x6f, 0x67, 0x67, 0x65, 0x72, 0x43, 0x6f, 0x6e, 0x66, 0x69, 0x67, 0x12, 0x22, 0x0a, 0x0c, 0x65,
+	0x6e, 0x61, 0x62, 0x6c, 0x65, 0x4d, 0x65, 0x6d, 0x6f, 0x72, 0x79, 0x18, 0x01, 0x20, 0x01, 0x28,
+	0x08, 0x52, 0x0c, 0x65, 0x6e, 0x61, 0x62, 0x6c, 0x65, 0x4d, 0x65, 0x6d, 0x6f, 0x72, 0x79, 0x12,
+	0x24, 0x0a, 0x0d, 0x65, 0x6e, 0x61, 0x62, 0x6c, 0x65, 0x53, 0x74, 0x6f, 0x72, 0x61, 0x67, 0x65,
+	0x18, 0x02, 0x20, 0x01, 0x28, 0x08, 0x52, 0x0d, 0x65, 0x6e, 0x61, 0x62, 0x6c, 0x65, 0x53, 0x74,
+	0x6f, 0x72, 0x61, 0x67, 0x65, 0x22, 0xb5, 0x01, 0x0a, 0x15, 0x53, 0x47, 0x58, 0x56, 0x4d, 0x53,
+	0x74, 0x72, 0x75, 0x63, 0x74, 0x4c, 0x6f, 0x67, 0x52, 0x65, 0x71, 0x75, 0x65, 0x73, 0x74, 0x12,
+	0x30, 0x0a, 0x06, 0x70, 0x61, 0x72, 0x61, 0x6d, 0x73, 0x18, 0x01, 0x20, 0x01, 0x28, 0x0b, 0x32,
+	0x18, 0x2e, 0x66, 0x66, 0x69, 0x2e, 0x66, 0x66, 0x69, 0x2e, 0x53, 0x47, 0x58, 0x56, 0x4d, 0x43,
+	0x61, 0x6c, 0x6c, 0x50, 0x61, 0x72, 0x61, 0x6d, 0x73, 0x52, 0x06, 0x70, 0x61, 0x72, 0x61, 0x6d,
+	0x73, 0x12, 0x35, 0x0a, 0x07, 0x63, 0x6f, 0x6e, 0x74, 0x65, 0x78, 0x74, 0x18, 0x02, 0x20, 0x01,
+	0x28, 0x0b, 0x32, 0x1b, 0x2e, 0x66, 0x66, 0x69, 0x2e, 0x66, 0x66, 0x69, 0x2e, 0x54, 0x72, 0x61,
+	0x6e, 0x73, 0x61, 0x63, 0x74, 0x69, 0x6f, 0x6e, 0x43, 0x6f, 0x6e, 0x74, 0x65, 0x78, 0x74, 0x52,
+	0x07, 0x63, 0x6f, 0x6e, 0x74, 0x65, 0x78, 0x74, 0x12, 0x33, 0x0a, 0x06, 0x63, 0x6f, 0x6e, 0x66,
+	0x69, 0x67, 0x18, 0x03, 0x20, 0x01, 0x28, 0x0b, 0x32, 0x1b, 0x2e, 0x66, 0x66, 0x69, 0x2e, 0x66,
+	0x66, 0x69, 0x2e, 0x53, 0x74, 0x72, 0x75, 0x63, 0x74, 0x4c, 0x6f, 0x67, 0x67, 0x65, 0x72, 0x43,
+	0x6f, 0x6e, 0x66, 0x69, 0x67, 0x52, 0x06, 0x63, 0x6f, 0x6e, 0x66, 0x69, 0x67, 0x22, 0x36, 0x0a,
+	0x0c, 0x53, 0x74, 0x6f, 0x72, 0x61, 0x67, 0x65, 0x45, 0x6e, 0x74, 0x72, 0x79, 0x12, 0x10, 0x0a,
+	0x03, 0x6b, 0x65, 0x79, 0x18, 0x01, 0x20, 0x01, 0x28, 0x0c, 0x52, 0x03, 0x6b, 0x65, 0x79, 0x12,
+	0x14, 0x0a, 0x05, 0x76, 0x61, 0x6c, 0x75, 0x65, 0x18, 0x02, 0x20, 0x01, 0x28, 0x0c, 0x52, 0x05,
+	0x76, 0x61, 0x6c, 0x75, 0x65, 0x22, 0xe2, 0x01, 0x0a, 0x09, 0x53, 0x74, 0x72, 0x75, 0x63, 0x74,
+	0x4c, 0x6f, 0x67, 0x12, 0x0e, 0x0a, 0x02, 0x70, 0x63, 0x18, 0x01, 0x20, 0x01, 0x28, 0x04, 0x52,
+	0x02, 0x70, 0x63, 0x12, 0x0e, 0x0a, 0x02, 0x6f, 0x70, 0x18, 0x02, 0x20, 0x01, 0x28, 0x09, 0x52,
+	0x02, 0x6f, 0x70, 0x12, 0x10, 0x0a, 0x03, 0x67, 0x61, 0x73, 0x18, 0x03, 0x20, 0x01, 0x28, 0x04,
+	0x52, 0x03, 0x67, 0x61, 0x73, 0x12, 0x18, 0x0a, 0x07, 0x67, 0x61, 0x73, 0x43, 0x6f, 0x73, 0x74,
+	0x18, 0x04, 0x20, 0x01, 0x28, 0x04, 0x52, 0x07, 0x67, 0x61, 0x73, 0x43, 0x6f, 0x73, 0x74, 0x12,
+	0x14, 0x0a, 0x05, 0x64, 0x65, 0x70, 0x74, 0x68, 0x18, 0x05, 0x20, 0x01, 0x28, 0x04, 0x52, 0x05,
+	0x64, 0x65, 0x70, 0x74, 0x68, 0x12, 0x14, 0x0a, 0x05, 0x73, 0x74, 0x61, 0x63, 0x6b, 0x18, 0x06,
+	0x20, 0x03, 0x28, 0x0c, 0x52, 0x05, 0x73, 0x74, 0x61, 0x63, 0x6b, 0x12, 0x16, 0x0a, 0x06, 0x6d,
+	0x65, 0x6d, 0x6f, 0x72, 0x79, 0x18, 0x07, 0x20, 0x01, 0x28, 0x0c, 0x52, 0x06, 0x6d, 0x65, 0x6d,
+	0x6f, 0x72, 0x79, 0x12, 0x2f, 0x0a, 0x07, 0x73, 0x74, 0x6f, 0x72, 0x61, 0x67, 0x65, 0x18, 0x08,
+	0x20, 0x03, 0x28, 0x0b, 0x32, 0x15, 0x2e, 0x66, 0x66, 0x69, 0x2e, 0x66, 0x66, 0x69, 0x2e, 0x53,
+	0x74, 0x6f, 0x72, 0x61, 0x67, 0x65, 0x45, 0x6e, 0x74, 0x72, 0x79, 0x52, 0x07, 0x73, 0x74, 0x6f,
+	0x72, 0x61, 0x67, 0x65, 0x12, 0x14, 0x0a, 0x05, 0x65, 0x72, 0x72, 0x6f, 0x72, 0x18, 0x09, 0x20,
+	0x01, 0x28, 0x09, 0x52, 0x05, 0x65, 0x72, 0x72, 0x6f, 0x72, 0x22, 0x34, 0x0a, 0x0a, 0x53, 0x74,
+	0x72, 0x75, 0x63, 0x74, 0x4c, 0x6f, 0x67, 0x73, 0x12, 0x26, 0x0a, 0x04, 0x6c, 0x6f, 0x67, 0x73,
+	0x18, 0x01, 0x20, 0x03, 0x28, 0x0b, 0x32, 0x12, 0x2e, 0x66, 0x66, 0x69, 0x2e, 0x66, 0x66, 0x69,
+	0x2e, 0x53, 0x74, 0x72, 0x75, 0x63, 0x74, 0x4c, 0x6f, 0x67, 0x52, 0x04, 0x6c, 0x6f, 0x67, 0x73,
+	0x22, 0x6f, 0x0a, 0x11, 0x53, 0x74, 0x72, 0x75, 0x63, 0x74, 0x4c, 0x6f, 0x67, 0x52, 0x65, 0x73,
+	0x70, 0x6f, 0x6e, 0x73, 0x65, 0x12, 0x3a, 0x0a, 0x06, 0x72, 0x65, 0x73, 0x75, 0x6c, 0x74, 0x18,
+	0x01, 0x20, 0x01, 0x28, 0x0b, 0x32, 0x22, 0x2e, 0x66, 0x66, 0x69, 0x2e, 0x66, 0x66, 0x69, 0x2e,
+	0x48, 0x61, 0x6e, 0x64, 0x6c, 0x65, 0x54, 0x72, 0x61, 0x6e, 0x73, 0x61, 0x63, 0x74, 0x69, 0x6f,
+	0x6e, 0x52, 0x65, 0x73, 0x70, 0x6f, 0x6e, 0x73, 0x65, 0x52, 0x06, 0x72, 0x65, 0x73, 0x75, 0x6c,
+	0x74, 0x12, 0x1e, 0x0a, 0x0a, 0x73, 0x74, 0x72, 0x75, 0x63, 0x74, 0x4c, 0x6f, 0x67, 0x73, 0x18,
+	0x02, 0x20, 0x01, 0x28, 0x0c, 0x52, 0x0a, 0x73, 0x74, 0x72, 0x75, 0x63, 0x74, 0x4c, 0x6f, 0x67,
+	0x73, 0x22, 0x82, 0x01, 0x0a, 0x17, 0x53, 0x47, 0x58, 0x56, 0x4d, 0x45, 0x73, 0x74, 0x69, 0x6d,
+	0x61, 0x74, 0x65, 0x47, 0x61, 0x73, 0x52, 0x65, 0x71, 0x75, 0x65, 0x73, 0x74, 0x12, 0x30, 0x0a,
+	0x06, 0x70, 0x61, 0x72, 0x61, 0x6d, 0x73, 0x18, 0x01, 0x20, 0x01, 0x28, 0x0b, 0x32, 0x18, 0x2e,
+	0x66, 0x66, 0x69, 0x2e, 0x66, 0x66, 0x69, 0x2e, 0x53, 0x47, 0x58, 0x56, 0x4d, 0x43, 0x61, 0x6c,
+	0x6c, 0x50, 0x61, 0x72, 0x61, 0x6d, 0x73, 0x52, 0x06, 0x70, 0x61, 0x72, 0x61, 0x6d, 0x73, 0x12,
+	0x35, 0x0a, 0x07, 0x63, 0x6f, 0x6e, 0x74, 0x65, 0x78, 0x74, 0x18, 0x02, 0x20, 0x01, 0x28, 0x0b,
+	0x32, 0x1b, 0x2e, 0x66, 0x66, 0x69, 0x2e, 0x66, 0x66, 0x69, 0x2e, 0x54, 0x72, 0x61, 0x6e, 0x73,
+	0x61, 0x63, 0x74, 0x69, 0x6f, 0x6e, 0x43, 0x6f, 0x6e, 0x74, 0x65, 0x78, 0x74, 0x52, 0x07, 0x63,
+	0x6f, 0x6e, 0x74, 0x65, 0x78, 0x74, 0x22, 0x54, 0x0a, 0x13, 0x45, 0x73, 0x74, 0x69, 0x6d, 0x61,
+	0x74, 0x65, 0x47, 0x61, 0x73, 0x52, 0x65, 0x73, 0x70, 0x6f, 0x6e, 0x73, 0x65, 0x12, 0x10, 0x0a,
+	0x03, 0x67, 0x61, 0x73, 0x18, 0x01, 0x20, 0x01, 0x28, 0x04, 0x52, 0x03, 0x67, 0x61, 0x73, 0x12,
+	0x19, 0x0a, 0x08, 0x76, 0x6d, 0x5f, 0x65, 0x72, 0x72, 0x6f, 0x72, 0x18, 0x02, 0x20, 0x01, 0x28,
+	0x09, 0x52, 0x07, 0x76, 0x6d, 0x45, 0x72, 0x72, 0x6f, 0x72, 0x12, 0x10, 0x0a, 0x03, 0x72, 0x65,
+	0x74, 0x18, 0x03, 0x20, 0x01, 0x28, 0x0c, 0x52, 0x03, 0x72, 0x65, 0x74, 0x22, 0x80, 0x01, 0x0a,
+	0x15, 0x53, 0x47, 0x58, 0x56, 0x4d, 0x53, 0x74, 0x61, 0x74, 0x65, 0x44, 0x69, 0x66, 0x66, 0x52,
+	0x65, 0x71, 0x75, 0x65, 0x73, 0x74, 0x12, 0x30, 0x0a, 0x06, 0x70, 0x61, 0x72, 0x61, 0x6d, 0x73,
+	0x18, 0x01, 0x20, 0x01, 0x28, 0x0b, 0x32, 0x18, 0x2e, 0x66, 0x66, 0x69, 0x2e, 0x66, 0x66, 0x69,
+	0x2e, 0x53, 0x47, 0x58, 0x56, 0x4d, 0x43, 0x61, 0x6c, 0x6c, 0x50, 0x61, 0x72, 0x61, 0x6d, 0x73,
+	0x52, 0x06, 0x70, 0x61, 0x72, 0x61, 0x6d, 0x73, 0x12, 0x35, 0x0a, 0x07, 0x63, 0x6f, 0x6e, 0x74,
+	0x65, 0x78, 0x74, 0x18, 0x02, 0x20, 0x01, 0x28, 0x0b, 0x32, 0x1b, 0x2e, 0x66, 0x66, 0x69, 0x2e,
+	0x66, 0x66, 0x69, 0x2e, 0x54, 0x72, 0x61, 0x6e, 0x73, 0x61, 0x63, 0x74, 0x69, 0x6f, 0x6e, 0x43,
+	0x6f, 0x6e, 0x74, 0x65, 0x78, 0x74, 0x52, 0x07, 0x63, 0x6f, 0x6e, 0x74, 0x65, 0x78, 0x74, 0x22,
+	0x5b, 0x0a, 0x0b, 0x53, 0x74, 0x6f, 0x72, 0x61, 0x67, 0x65, 0x44, 0x69, 0x66, 0x66, 0x12, 0x10,
+	0x0a, 0x03, 0x6b, 0x65, 0x79, 0x18, 0x01, 0x20, 0x01, 0x28, 0x0c, 0x52, 0x03, 0x6b, 0x65, 0x79,
+	0x12, 0x24, 0x0a, 0x0d, 0x6f, 0x72, 0x69, 0x67, 0x69, 0x6e, 0x61, 0x6c, 0x56, 0x61, 0x6c, 0x75,
+	0x65, 0x18, 0x02, 0x20, 0x01, 0x28, 0x0c, 0x52, 0x0d, 0x6f, 0x72, 0x69, 0x67, 0x69, 0x6e, 0x61,
+	0x6c, 0x56, 0x61, 0x6c, 0x75, 0x65, 0x12, 0x14, 0x0a, 0x05, 0x76, 0x61, 0x6c, 0x75, 0x65, 0x18,
+	0x03, 0x20, 0x01, 0x28, 0x0c, 0x52, 0x05, 0x76, 0x61, 0x6c, 0x75, 0x65, 0x22, 0x91, 0x02, 0x0a,
+	0x0b, 0x41, 0x63, 0x63, 0x6f, 0x75, 0x6e, 0x74, 0x44, 0x69, 0x66, 0x66, 0x12, 0x18, 0x0a, 0x07,
+	0x61, 0x64, 0x64, 0x72, 0x65, 0x73, 0x73, 0x18, 0x01, 0x20, 0x01, 0x28, 0x0c, 0x52, 0x07, 0x61,
+	0x64, 0x64, 0x72, 0x65, 0x73, 0x73, 0x12, 0x24, 0x0a, 0x0d, 0x62, 0x61, 0x6c, 0x61, 0x6e, 0x63,
+	0x65, 0x42, 0x65, 0x66, 0x6f, 0x72, 0x65, 0x18, 0x02, 0x20, 0x01, 0x28, 0x0c, 0x52, 0x0d, 0x62,
+	0x61, 0x6c, 0x61, 0x6e, 0x63, 0x65, 0x42, 0x65, 0x66, 0x6f, 0x72, 0x65, 0x12, 0x22, 0x0a, 0x0c,
+	0x62, 0x61, 0x6c, 0x61, 0x6e, 0x63, 0x65, 0x41, 0x66, 0x74, 0x65, 0x72, 0x18, 0x03, 0x20, 0x01,
+	0x28, 0x0c, 0x52, 0x0c, 0x62, 0x61, 0x6c, 0x61, 0x6e, 0x63, 0x65, 0x41, 0x66, 0x74, 0x65, 0x72,
+	0x12, 0x20, 0x0a, 0x0b, 0x6e, 0x6f, 0x6e, 0x63, 0x65, 0x42, 0x65, 0x66, 0x6f, 0x72, 0x65, 0x18,
+	0x04, 0x20, 0x01, 0x28, 0x04, 0x52, 0x0b, 0x6e, 0x6f, 0x6e, 0x63, 0x65, 0x42, 0x65, 0x66, 0x6f,
+	0x72, 0x65, 0x12, 0x1e, 0x0a, 0x0a, 0x6e, 0x6f, 0x6e, 0x63, 0x65, 0x41, 0x66, 0x74, 0x65, 0x72,
+	0x18, 0x05, 0x20, 0x01, 0x28, 0x04, 0x52, 0x0a, 0x6e, 0x6f, 0x6e, 0x63, 0x65, 0x41, 0x66, 0x74,
+	0x65, 0x72, 0x12, 0x12, 0x0a, 0x04, 0x63, 0x6f, 0x64, 0x65, 0x18, 0x06, 0x20, 0x01, 0x28, 0x0c,
+	0x52, 0x04, 0x63, 0x6f, 0x64, 0x65, 0x12, 0x18, 0x0a, 0x07, 0x64, 0x65, 0x6c, 0x65, 0x74, 0x65,
+	0x64, 0x18, 0x07, 0x20, 0x01, 0x28, 0x08, 0x52, 0x07, 0x64, 0x65, 0x6c, 0x65, 0x74, 0x65, 0x64,
+	0x12, 0x2e, 0x0a, 0x07, 0x73, 0x74, 0x6f, 0x72, 0x61, 0x67, 0x65, 0x18, 0x08, 0x20, 0x03, 0x28,
+	0x0b, 0x32, 0x14, 0x2e, 0x66, 0x66, 0x69, 0x2e, 0x66, 0x66, 0x69, 0x2e, 0x53, 0x74, 0x6f, 0x72,
+	0x61, 0x67, 0x65, 0x44, 0x69, 0x66, 0x66, 0x52, 0x07, 0x73, 0x74, 0x6f, 0x72, 0x61, 0x67, 0x65,
+	0x22, 0x81, 0x01, 0x0a, 0x11, 0x53, 0x74, 0x61, 0x74, 0x65, 0x44, 0x69, 0x66, 0x66, 0x52, 0x65,
+	0x73, 0x70, 0x6f, 0x6e, 0x73, 0x65, 0x12, 0x3a, 0x0a, 0x06, 0x72, 0x65, 0x73, 0x75, 0x6c, 0x74,
+	0x18, 0x01, 0x20, 0x01, 0x28, 0x0b, 0x32, 0x22, 0x2e, 0x66, 0x66, 0x69, 0x2e, 0x66, 0x66, 0x69,
+	0x2e, 0x48, 0x61, 0x6e, 0x64, 0x6c, 0x65, 0x54, 0x72, 0x61, 0x6e, 0x73, 0x61, 0x63, 0x74, 0x69,
+	0x6f, 0x6e, 0x52, 0x65, 0x73, 0x70, 0x6f, 0x6e, 0x73, 0x65, 0x52, 0x06, 0x72, 0x65, 0x73, 0x75,
+	0x6c, 0x74, 0x12, 0x30, 0x0a, 0x08, 0x61, 0x63, 0x63, 0x6f, 0x75, 0x6e, 0x74, 0x73, 0x18, 0x02,
+	0x20, 0x03, 0x28, 0x0b, 0x32, 0x14, 0x2e, 0x66, 0x66, 0x69, 0x2e, 0x66, 0x66, 0x69, 0x2e, 0x41,
+	0x63, 0x63, 0x6f, 0x75, 0x6e, 0x74, 0x44, 0x69, 0x66, 0x66, 0x52, 0x08, 0x61, 0x63, 0x63, 0x6f,
+	0x75, 0x6e, 0x74, 0x73, 0x22, 0x87, 0x01, 0x0a, 0x1c, 0x53, 0x47, 0x58, 0x56, 0x4d, 0x43, 0x72,
+	0x65, 0x61, 0x74, 0x65, 0x41, 0x63, 0x63, 0x65, 0x73, 0x73, 0x4c, 0x69, 0x73, 0x74, 0x52, 0x65,
+	0x71, 0x75, 0x65, 0x73, 0x74, 0x12, 0x30, 0x0a, 0x06, 0x70, 0x61, 0x72, 0x61, 0x6d, 0x73, 0x18,
+	0x01, 0x20, 0x01, 0x28, 0x0b, 0x32, 0x18, 0x2e, 0x66, 0x66, 0x69, 0x2e, 0x66, 0x66, 0x69, 0x2e,
+	0x53, 0x47, 0x58, 0x56, 0x4d, 0x43, 0x61, 0x6c, 0x6c, 0x50, 0x61, 0x72, 0x61, 0x6d, 0x73, 0x52,
+	0x06, 0x70, 0x61, 0x72, 0x61, 0x6d, 0x73, 0x12, 0x35, 0x0a, 0x07, 0x63, 0x6f, 0x6e, 0x74, 0x65,
+	0x78, 0x74, 0x18, 0x02, 0x20, 0x01, 0x28, 0x0b, 0x32, 0x1b, 0x2e, 0x66, 0x66, 0x69, 0x2e, 0x66,
+	0x66, 0x69, 0x2e, 0x54, 0x72, 0x61, 0x6e, 0x73, 0x61, 0x63, 0x74, 0x69, 0x6f, 0x6e, 0x43, 0x6f,
+	0x6e, 0x74, 0x65, 0x78, 0x74, 0x52, 0x07, 0x63, 0x6f, 0x6e, 0x74, 0x65, 0x78, 0x74, 0x22, 0x8f,
+	0x01, 0x0a, 0x18, 0x43, 0x72, 0x65, 0x61, 0x74, 0x65, 0x41, 0x63, 0x63, 0x65, 0x73, 0x73, 0x4c,
+	0x69, 0x73, 0x74, 0x52, 0x65, 0x73, 0x70, 0x6f, 0x6e, 0x73, 0x65, 0x12, 0x3a, 0x0a, 0x06, 0x72,
+	0x65, 0x73, 0x75, 0x6c, 0x74, 0x18, 0x01, 0x20, 0x01, 0x28, 0x0b, 0x32, 0x22, 0x2e, 0x66, 0x66,
+	0x69, 0x2e, 0x66, 0x66, 0x69, 0x2e, 0x48, 0x61, 0x6e, 0x64, 0x6c, 0x65, 0x54, 0x72, 0x61, 0x6e,
+	0x73, 0x61, 0x63, 0x74, 0x69, 0x6f, 0x6e, 0x52, 0x65, 0x73, 0x70, 0x6f, 0x6e, 0x73, 0x65, 0x52,
+	0x06, 0x72, 0x65, 0x73, 0x75, 0x6c, 0x74, 0x12, 0x37, 0x0a, 0x0a, 0x61, 0x63, 0x63, 0x65, 0x73,
+	0x73, 0x4c, 0x69, 0x73, 0x74, 0x18, 0x02, 0x20, 0x03, 0x28, 0x0b, 0x32, 0x17, 0x2e, 0x66, 0x66,
+	0x69, 0x2e, 0x66, 0x66, 0x69, 0x2e, 0x41, 0x63, 0x63, 0x65, 0x73, 0x73, 0x4c, 0x69, 0x73, 0x74,
+	0x49, 0x74, 0x65, 0x6d, 0x52, 0x0a, 0x61, 0x63, 0x63, 0x65, 0x73, 0x73, 0x4c, 0x69, 0x73, 0x74,
+	0x22, 0x83, 0x01, 0x0a, 0x15, 0x53, 0x47, 0x58, 0x56, 0x4d, 0x42, 0x61, 0x74, 0x63, 0x68, 0x54,
+	0x72, 0x61, 0x6e, 0x73, 0x61, 0x63, 0x74, 0x69, 0x6f, 0x6e, 0x12, 0x2e, 0x0a, 0x04, 0x63, 0x61,
+	0x6c, 0x6c, 0x18, 0x01, 0x20, 0x01, 0x28, 0x0b, 0x32, 0x18, 0x2e, 0x66, 0x66, 0x69, 0x2e, 0x66,
+	0x66, 0x69, 0x2e, 0x53, 0x47, 0x58, 0x56, 0x4d, 0x43, 0x61, 0x6c, 0x6c, 0x50, 0x61, 0x72, 0x61,
+	0x6d, 0x73, 0x48, 0x00, 0x52, 0x04, 0x63, 0x61, 0x6c, 0x6c, 0x12, 0x34, 0x0a, 0x06, 0x63, 0x72,
+	0x65, 0x61, 0x74, 0x65, 0x18, 0x02, 0x20, 0x01, 0x28, 0x0b, 0x32, 0x1a, 0x2e, 0x66, 0x66, 0x69,
+	0x2e, 0x66, 0x66, 0x69, 0x2e, 0x53, 0x47, 0x58, 0x56, 0x4d, 0x43, 0x72, 0x65, 0x61, 0x74, 0x65,
+	0x50, 0x61, 0x72, 0x61, 0x6d, 0x73, 0x48, 0x00, 0x52, 0x06, 0x63, 0x72, 0x65, 0x61, 0x74, 0x65,
+	0x42, 0x04, 0x0a, 0x02, 0x74, 0x78, 0x22, 0x8e, 0x01, 0x0a, 0x11, 0x53, 0x47, 0x58, 0x56, 0x4d,
+	0x42, 0x61, 0x74, 0x63, 0x68, 0x52, 0x65, 0x71, 0x75, 0x65, 0x73, 0x74, 0x12, 0x42, 0x0a, 0x0c,
+	0x74, 0x72, 0x61, 0x6e, 0x73, 0x61, 0x63, 0x74, 0x69, 0x6f, 0x6e, 0x73, 0x18, 0x01, 0x20, 0x03,
+	0x28, 0x0b, 0x32, 0x1e, 0x2e, 0x66, 0x66, 0x69, 0x2e, 0x66, 0x66, 0x69, 0x2e, 0x53, 0x47, 0x58,
+	0x56, 0x4d, 0x42, 0x61, 0x74, 0x63, 0x68, 0x54, 0x72, 0x61, 0x6e, 0x73, 0x61, 0x63, 0x74, 0x69,
+	0x6f, 0x6e, 0x52, 0x0c, 0x74, 0x72, 0x61, 0x6e, 0x73, 0x61, 0x63, 0x74, 0x69, 0x6f, 0x6e, 0x73,
+	0x12, 0x35, 0x0a, 0x07, 0x63, 0x6f, 0x6e, 0x74, 0x65, 0x78, 0x74, 0x18, 0x02, 0x20, 0x01, 0x28,
+	0x0b, 0x32, 0x1b, 0x2e, 0x66, 0x66, 0x69, 0x2e, 0x66, 0x66, 0x69, 0x2e, 0x54, 0x72, 0x61, 0x6e,
+	0x73, 0x61, 0x63, 0x74, 0x69, 0x6f, 0x6e, 0x43, 0x6f, 0x6e, 0x74, 0x65, 0x78, 0x74, 0x52, 0x07,
+	0x63, 0x6f, 0x6e, 0x74, 0x65, 0x78, 0x74, 0x22, 0x82, 0x01, 0x0a, 0x16, 0x42, 0x61, 0x74, 0x63,
+	0x68, 0x54, 0x72, 0x61, 0x6e, 0x73, 0x61, 0x63, 0x74, 0x69, 0x6f, 0x6e, 0x52, 0x65, 0x73, 0x75,
+	0x6c, 0x74, 0x12, 0x3a, 0x0a, 0x06, 0x72, 0x65, 0x73, 0x75, 0x6c, 0x74, 0x18, 0x01, 0x20, 0x01,
+	0x28, 0x0b, 0x32, 0x22, 0x2e, 0x66, 0x66, 0x69, 0x2e, 0x66, 0x66, 0x69, 0x2e, 0x48, 0x61, 0x6e,
+	0x64, 0x6c, 0x65, 0x54, 0x72, 0x61, 0x6e, 0x73, 0x61, 0x63, 0x74, 0x69, 0x6f, 0x6e, 0x52, 0x65,
+	0x73, 0x70, 0x6f, 0x6e, 0x73, 0x65, 0x52, 0x06, 0x72, 0x65, 0x73, 0x75, 0x6c, 0x74, 0x12, 0x2c,
+	0x0a, 0x11, 0x63, 0x75, 0x6d, 0x75, 0x6c, 0x61, 0x74, 0x69, 0x76, 0x65, 0x47, 0x61, 0x73, 0x55,
+	0x73, 0x65, 0x64, 0x18, 0x02, 0x20, 0x01, 0x28, 0x04, 0x52, 0x11, 0x63, 0x75, 0x6d, 0x75, 0x6c,
+	0x61, 0x74, 0x69, 0x76, 0x65, 0x47, 0x61, 0x73, 0x55, 0x73, 0x65, 0x64, 0x22, 0x4a, 0x0a, 0x0d,
+	0x42, 0x61, 0x74, 0x63, 0x68, 0x52, 0x65, 0x73, 0x70, 0x6f, 0x6e, 0x73, 0x65, 0x12, 0x39, 0x0a,
+	0x07, 0x72, 0x65, 0x73, 0x75, 0x6c, 0x74, 0x73, 0x18, 0x01, 0x20, 0x03, 0x28, 0x0b, 0x32, 0x1f,
+	0x2e, 0x66, 0x66, 0x69, 0x2e, 0x66, 0x66, 0x69, 0x2e, 0x42, 0x61, 0x74, 0x63, 0x68, 0x54, 0x72,
+	0x61, 0x6e, 0x73, 0x61, 0x63, 0x74, 0x69, 0x6f, 0x6e, 0x52, 0x65, 0x73, 0x75, 0x6c, 0x74, 0x52,
+	0x07, 0x72, 0x65, 0x73, 0x75, 0x6c, 0x74, 0x73, 0x22, 0x96, 0x01, 0x0a, 0x1d, 0x53, 0x47, 0x58,
+	0x56, 0x4d, 0x53, 0x69, 0x67, 0x6e, 0x65, 0x64, 0x54, 0x72, 0x61, 0x6e, 0x73, 0x61, 0x63, 0x74,
+	0x69, 0x6f, 0x6e, 0x52, 0x65, 0x71, 0x75, 0x65, 0x73, 0x74, 0x12, 0x26, 0x0a, 0x0e, 0x72, 0x61,
+	0x77, 0x54, 0x72, 0x61, 0x6e, 0x73, 0x61, 0x63, 0x74, 0x69, 0x6f, 0x6e, 0x18, 0x01, 0x20, 0x01,
+	0x28, 0x0c, 0x52, 0x0e, 0x72, 0x61, 0x77, 0x54, 0x72, 0x61, 0x6e, 0x73, 0x61, 0x63, 0x74, 0x69,
+	0x6f, 0x6e, 0x12, 0x35, 0x0a, 0x07, 0x63, 0x6f, 0x6e, 0x74, 0x65, 0x78, 0x74, 0x18, 0x02, 0x20,
+	0x01, 0x28, 0x0b, 0x32, 0x1b, 0x2e, 0x66, 0x66, 0x69, 0x2e, 0x66, 0x66, 0x69, 0x2e, 0x54, 0x72,
+	0x61, 0x6e, 0x73, 0x61, 0x63, 0x74, 0x69, 0x6f, 0x6e, 0x43, 0x6f, 0x6e, 0x74, 0x65, 0x78, 0x74,
+	0x52, 0x07, 0x63, 0x6f, 0x6e, 0x74, 0x65, 0x78, 0x74, 0x12, 0x16, 0x0a, 0x06, 0x63, 0x6f, 0x6d,
+	0x6d, 0x69, 0x74, 0x18, 0x03, 0x20, 0x01, 0x28, 0x08, 0x52, 0x06, 0x63, 0x6f, 0x6d, 0x6d, 0x69,
+	0x74, 0x22, 0x7f, 0x0a, 0x19, 0x53, 0x69, 0x67, 0x6e, 0x65, 0x64, 0x54, 0x72, 0x61, 0x6e, 0x73,
+	0x61, 0x63, 0x74, 0x69, 0x6f, 0x6e, 0x52, 0x65, 0x73, 0x70, 0x6f, 0x6e, 0x73, 0x65, 0x12, 0x3a,
+	0x0a, 0x06, 0x72, 0x65, 0x73, 0x75, 0x6c, 0x74, 0x18, 0x01, 0x20, 0x01, 0x28, 0x0b, 0x32, 0x22,
+	0x2e, 0x66, 0x66, 0x69, 0x2e, 0x66, 0x66, 0x69, 0x2e, 0x48, 0x61, 0x6e, 0x64, 0x6c, 0x65, 0x54,
+	0x72, 0x61, 0x6e, 0x73, 0x61, 0x63, 0x74, 0x69, 0x6f, 0x6e, 0x52, 0x65, 0x73, 0x70, 0x6f, 0x6e,
+	0x73, 0x65, 0x52, 0x06, 0x72, 0x65, 0x73, 0x75, 0x6c, 0x74, 0x12, 0x12, 0x0a, 0x04, 0x66, 0x72,
+	0x6f, 0x6d, 0x18, 0x02, 0x20, 0x01, 0x28, 0x0c, 0x52, 0x04, 0x66, 0x72, 0x6f, 0x6d, 0x12, 0x12,
+	0x0a, 0x04, 0x68, 0x61, 0x73, 0x68, 0x18, 0x03, 0x20, 0x01, 0x28, 0x0c, 0x52, 0x04, 0x68, 0x61,
+	0x73, 0x68, 0x22, 0x16, 0x0a, 0x14, 0x4e, 0x6f, 0x64, 0x65, 0x50, 0x75, 0x62, 0x6c, 0x69, 0x63,
+	0x4b, 0x65, 0x79, 0x52, 0x65, 0x71, 0x75, 0x65, 0x73, 0x74, 0x22, 0x35, 0x0a, 0x15, 0x4e, 0x6f,
+	0x64, 0x65, 0x50, 0x75, 0x62, 0x6c, 0x69, 0x63, 0x4b, 0x65, 0x79, 0x52, 0x65, 0x73, 0x70, 0x6f,
+	0x6e, 0x73, 0x65, 0x12, 0x1c, 0x0a, 0x09, 0x70, 0x75, 0x62, 0x6c, 0x69, 0x63, 0x4b, 0x65, 0x79,
+	0x18, 0x01, 0x20, 0x01, 0x28, 0x0c, 0x52, 0x09, 0x70, 0x75, 0x62, 0x6c, 0x69, 0x63, 0x4b, 0x65,
+	0x79, 0x22, 0x98, 0x01, 0x0a, 0x12, 0x50, 0x72, 0x65, 0x63, 0x6f, 0x6d, 0x70, 0x69, 0x6c, 0x65,
+	0x4f, 0x76, 0x65, 0x72, 0x72, 0x69, 0x64, 0x65, 0x12, 0x18, 0x0a, 0x07, 0x61, 0x64, 0x64, 0x72,
+	0x65, 0x73, 0x73, 0x18, 0x01, 0x20, 0x01, 0x28, 0x0c, 0x52, 0x07, 0x61, 0x64, 0x64, 0x72, 0x65,
+	0x73, 0x73, 0x12, 0x2a, 0x0a, 0x10, 0x61, 0x63, 0x74, 0x69, 0x76, 0x61, 0x74, 0x69, 0x6f, 0x6e,
+	0x48, 0x65, 0x69, 0x67, 0x68, 0x74, 0x18, 0x02, 0x20, 0x01, 0x28, 0x04, 0x52, 0x10, 0x61, 0x63,
+	0x74, 0x69, 0x76, 0x61, 0x74, 0x69, 0x6f, 0x6e, 0x48, 0x65, 0x69, 0x67, 0x68, 0x74, 0x12, 0x20,
+	0x0a, 0x0b, 0x67, 0x61, 0x73, 0x4f, 0x76, 0x65, 0x72, 0x72, 0x69, 0x64, 0x65, 0x18, 0x03, 0x20,
+	0x01, 0x28, 0x04, 0x52, 0x0b, 0x67, 0x61, 0x73, 0x4f, 0x76, 0x65, 0x72, 0x72, 0x69, 0x64, 0x65,
+	0x12, 0x1a, 0x0a, 0x08, 0x64, 0x69, 0x73, 0x61, 0x62, 0x6c, 0x65, 0x64, 0x18, 0x04, 0x20, 0x01,
+	0x28, 0x08, 0x52, 0x08, 0x64, 0x69, 0x73, 0x61, 0x62, 0x6c, 0x65, 0x64, 0x22, 0x58, 0x0a, 0x1b,
+	0x43, 0x6f, 0x6e, 0x66, 0x69, 0x67, 0x75, 0x72, 0x65, 0x50, 0x72, 0x65, 0x63, 0x6f, 0x6d, 0x70,
+	0x69, 0x6c, 0x65, 0x73, 0x52, 0x65, 0x71, 0x75, 0x65, 0x73, 0x74, 0x12, 0x39, 0x0a, 0x09, 0x6f,
+	0x76, 0x65, 0x72, 0x72, 0x69, 0x64, 0x65, 0x73, 0x18, 0x01, 0x20, 0x03, 0x28, 0x0b, 0x32, 0x1b,
+	0x2e, 0x66, 0x66, 0x69, 0x2e, 0x66, 0x66, 0x69, 0x2e, 0x50, 0x72, 0x65, 0x63, 0x6f, 0x6d, 0x70,
+	0x69, 0x6c, 0x65, 0x4f, 0x76, 0x65, 0x72, 0x72, 0x69, 0x64, 0x65, 0x52, 0x09, 0x6f, 0x76, 0x65,
+	0x72, 0x72, 0x69, 0x64, 0x65, 0x73, 0x22, 0x34, 0x0a, 0x1c, 0x43, 0x6f, 0x6e, 0x66, 0x69, 0x67,
+	0x75, 0x72, 0x65, 0x50, 0x72, 0x65, 0x63, 0x6f, 0x6d, 0x70, 0x69, 0x6c, 0x65, 0x73, 0x52, 0x65,
+	0x73, 0x70, 0x6f, 0x6e, 0x73, 0x65, 0x12, 0x14, 0x0a, 0x05, 0x65, 0x72, 0x72, 0x6f, 0x72, 0x18,
+	0x01, 0x20, 0x01, 0x28, 0x09, 0x52, 0x05, 0x65, 0x72, 0x72, 0x6f, 0x72, 0x22, 0xe1, 0x07, 0x0a,
+	0x0a, 0x46, 0x46, 0x49, 0x52, 0x65, 0x71, 0x75, 0x65, 0x73, 0x74, 0x12, 0x3d, 0x0a, 0x0b, 0x63,
+	0x61, 0x6c, 0x6c, 0x52, 0x65, 0x71, 0x75, 0x65, 0x73, 0x74, 0x18, 0x01, 0x20, 0x01, 0x28, 0x0b,
+	0x32, 0x19, 0x2e, 0x66, 0x66, 0x69, 0x2e, 0x66, 0x66, 0x69, 0x2e, 0x53, 0x47, 0x58, 0x56, 0x4d,
+	0x43, 0x61, 0x6c, 0x6c, 0x52, 0x65, 0x71, 0x75, 0x65, 0x73, 0x74, 0x48, 0x00, 0x52, 0x0b, 0x63,
+	0x61, 0x6c, 0x6c, 0x52, 0x65, 0x71, 0x75, 0x65, 0x73, 0x74, 0x12, 0x43, 0x0a, 0x0d, 0x63, 0x72,
+	0x65, 0x61, 0x74, 0x65, 0x52, 0x65, 0x71, 0x75, 0x65, 0x73, 0x74, 0x18, 0x02, 0x20, 0x01, 0x28,
+	0x0b, 0x32, 0x1b, 0x2e, 0x66, 0x66, 0x69, 0x2e, 0x66, 0x66, 0x69, 0x2e, 0x53, 0x47, 0x58, 0x56,
+	0x4d, 0x43, 0x72, 0x65, 0x61, 0x74, 0x65, 0x52, 0x65, 0x71, 0x75, 0x65, 0x73, 0x74, 0x48, 0x00,
+	0x52, 0x0d, 0x63, 0x72, 0x65, 0x61, 0x74, 0x65, 0x52, 0x65, 0x71, 0x75, 0x65, 0x73, 0x74, 0x12,
+	0x4b, 0x0a, 0x10, 0x70, 0x75, 0x62, 0x6c, 0x69, 0x63, 0x4b, 0x65, 0x79, 0x52, 0x65, 0x71, 0x75,
+	0x65, 0x73, 0x74, 0x18, 0x03, 0x20, 0x01, 0x28, 0x0b, 0x32, 0x1d, 0x2e, 0x66, 0x66, 0x69, 0x2e,
+	0x66, 0x66, 0x69, 0x2e, 0x4e, 0x6f, 0x64, 0x65, 0x50, 0x75, 0x62, 0x6c, 0x69, 0x63, 0x4b, 0x65,
+	0x79, 0x52, 0x65, 0x71, 0x75, 0x65, 0x73, 0x74, 0x48, 0x00, 0x52, 0x10, 0x70, 0x75, 0x62, 0x6c,
+	0x69, 0x63, 0x4b, 0x65, 0x79, 0x52, 0x65, 0x71, 0x75, 0x65, 0x73, 0x74, 0x12, 0x47, 0x0a, 0x10,
+	0x74, 0x72, 0x61, 0x63, 0x65, 0x43, 0x61, 0x6c, 0x6c, 0x52, 0x65, 0x71, 0x75, 0x65, 0x73, 0x74,
+	0x18, 0x04, 0x20, 0x01, 0x28, 0x0b, 0x32, 0x19, 0x2e, 0x66, 0x66, 0x69, 0x2e, 0x66, 0x66, 0x69,
+	0x2e, 0x53, 0x47, 0x58, 0x56, 0x4d, 0x43, 0x61, 0x6c, 0x6c, 0x52, 0x65, 0x71, 0x75, 0x65, 0x73,
+	0x74, 0x48, 0x00, 0x52, 0x10, 0x74, 0x72, 0x61, 0x63, 0x65, 0x43, 0x61, 0x6c, 0x6c, 0x52, 0x65,
+	0x71, 0x75, 0x65, 0x73, 0x74, 0x12, 0x4d, 0x0a, 0x12, 0x74, 0x72, 0x61, 0x63, 0x65, 0x43, 0x72,
+	0x65, 0x61, 0x74, 0x65, 0x52, 0x65, 0x71, 0x75, 0x65, 0x73, 0x74, 0x18, 0x05, 0x20, 0x01, 0x28,
+	0x0b, 0x32, 0x1b, 0x2e, 0x66, 0x66, 0x69, 0x2e, 0x66, 0x66, 0x69, 0x2e, 0x53, 0x47, 0x58, 0x56,
+	0x4d, 0x43, 0x72, 0x65, 0x61, 0x74, 0x65, 0x52, 0x65, 0x71, 0x75, 0x65, 0x73, 0x74, 0x48, 0x00,
+	0x52, 0x12, 0x74, 0x72, 0x61, 0x63, 0x65, 0x43, 0x72, 0x65, 0x61, 0x74, 0x65, 0x52, 0x65, 0x71,
+	0x75, 0x65, 0x73, 0x74, 0x12, 0x4c, 0x0a, 0x10, 0x73, 0x74, 0x72, 0x75, 0x63, 0x74, 0x4c, 0x6f,
+	0x67, 0x52, 0x65, 0x71, 0x75, 0x65, 0x73, 0x74, 0x18, 0x06, 0x20, 0x01, 0x28, 0x0b, 0x32, 0x1e,
+	0x2e, 0x66, 0x66, 0x69, 0x2e, 0x66, 0x66, 0x69, 0x2e, 0x53, 0x47, 0x58, 0x56, 0x4d, 0x53, 0x74,
+	0x72, 0x75, 0x63, 0x74, 0x4c, 0x6f, 0x67, 0x52, 0x65, 0x71, 0x75, 0x65, 0x73, 0x74, 0x48, 0x00,
+	0x52, 0x10, 0x73, 0x74, 0x72, 0x75, 0x63, 0x74, 0x4c, 0x6f, 0x67, 0x52, 0x65, 0x71, 0x75, 0x65,
+	0x73, 0x74, 0x12, 0x52, 0x0a, 0x12, 0x65, 0x73, 0x74, 0x69, 0x6d, 0x61, 0x74, 0x65, 0x47, 0x61,
+	0x73, 0x52, 0x65, 0x71, 0x75, 0x65, 0x73, 0x74, 0x18, 0x07, 0x20, 0x01, 0x28, 0x0b, 0x32, 0x20,
+	0x2e, 0x66, 0x66, 0x69, 0x2e, 0x66, 0x66, 0x69, 0x2e, 0x53, 0x47, 0x58, 0x56, 0x4d, 0x45, 0x73,
+	0x74, 0x69, 0x6d, 0x61, 0x74, 0x65, 0x47, 0x61, 0x73, 0x52, 0x65, 0x71, 0x75, 0x65, 0x73, 0x74,
+	0x48, 0x00, 0x52, 0x12, 0x65, 0x73, 0x74, 0x69, 0x6d, 0x61, 0x74, 0x65, 0x47, 0x61, 0x73, 0x52,
+	0x65, 0x71, 0x75, 0x65, 0x73, 0x74, 0x12, 0x4c, 0x0a, 0x10, 0x73, 0x74, 0x61, 0x74, 0x65, 0x44,
+	0x69, 0x66, 0x66, 0x52, 0x65, 0x71, 0x75, 0x65, 0x73, 0x74, 0x18, 0x08, 0x20, 0x01, 0x28, 0x0b,
+	0x32, 0x1e, 0x2e, 0x66, 0x66, 0x69, 0x2e, 0x66, 0x66, 0x69, 0x2e, 0x53, 0x47, 0x58, 0x56, 0x4d,
+	0x53, 0x74, 0x61, 0x74, 0x65, 0x44, 0x69, 0x66, 0x66, 0x52, 0x65, 0x71, 0x75, 0x65, 0x73, 0x74,
+	0x48, 0x00, 0x52, 0x10, 0x73, 0x74, 0x61, 0x74, 0x65, 0x44, 0x69, 0x66, 0x66, 0x52, 0x65, 0x71,
+	0x75, 0x65, 0x73, 0x74, 0x12, 0x61, 0x0a, 0x17, 0x63, 0x72, 0x65, 0x61, 0x74, 0x65, 0x41, 0x63,
+	0x63, 0x65, 0x73, 0x73, 0x4c, 0x69, 0x73, 0x74, 0x52, 0x65, 0x71, 0x75, 0x65, 0x73, 0x74, 0x18,
+	0x09, 0x20, 0x01, 0x28, 0x0b, 0x32, 0x25, 0x2e, 0x66, 0x66, 0x69, 0x2e, 0x66, 0x66, 0x69, 0x2e,
+	0x53, 0x47, 0x58, 0x56, 0x4d, 0x43, 0x72, 0x65, 0x61, 0x74, 0x65, 0x41, 0x63, 0x63, 0x65, 0x73,
+	0x73, 0x4c, 0x69, 0x73, 0x74, 0x52, 0x65, 0x71, 0x75, 0x65, 0x73, 0x74, 0x48, 0x00, 0x52, 0x17,
+	0x63, 0x72, 0x65, 0x61, 0x74, 0x65, 0x41, 0x63, 0x63, 0x65, 0x73, 0x73, 0x4c, 0x69, 0x73, 0x74,
+	0x52, 0x65, 0x71, 0x75, 0x65, 0x73, 0x74, 0x12, 0x40, 0x0a, 0x0c, 0x62, 0x61, 0x74, 0x63, 0x68,
+	0x52, 0x65, 0x71, 0x75, 0x65, 0x73, 0x74, 0x18, 0x0a, 0x20, 0x01, 0x28, 0x0b, 0x32, 0x1a, 0x2e,
+	0x66, 0x66, 0x69, 0x2e, 0x66, 0x66, 0x69, 0x2e, 0x53, 0x47, 0x58, 0x56, 0x4d, 0x42, 0x61, 0x74,
+	0x63, 0x68, 0x52, 0x65, 0x71, 0x75, 0x65, 0x73, 0x74, 0x48, 0x00, 0x52, 0x0c, 0x62, 0x61, 0x74,
+	0x63, 0x68, 0x52, 0x65, 0x71, 0x75, 0x65, 0x73, 0x74, 0x12, 0x64, 0x0a, 0x18, 0x73, 0x69, 0x67,
+	0x6e, 0x65, 0x64, 0x54, 0x72, 0x61, 0x6e, 0x73, 0x61, 0x63, 0x74, 0x69, 0x6f, 0x6e, 0x52, 0x65,
+	0x71, 0x75, 0x65, 0x73, 0x74, 0x18, 0x0b, 0x20, 0x01, 0x28, 0x0b, 0x32, 0x26, 0x2e, 0x66, 0x66,
+	0x69, 0x2e, 0x66, 0x66, 0x69, 0x2e, 0x53, 0x47, 0x58, 0x56, 0x4d, 0x53, 0x69, 0x67, 0x6e, 0x65,
+	0x64, 0x54, 0x72, 0x61, 0x6e, 0x73, 0x61, 0x63, 0x74, 0x69, 0x6f, 0x6e, 0x52, 0x65, 0x71, 0x75,
+	0x65, 0x73, 0x74, 0x48, 0x00, 0x52, 0x18, 0x73, 0x69, 0x67, 0x6e, 0x65, 0x64, 0x54, 0x72, 0x61,
+	0x6e, 0x73, 0x61, 0x63, 0x74, 0x69, 0x6f, 0x6e, 0x52, 0x65, 0x71, 0x75, 0x65, 0x73, 0x74, 0x12,
+	0x68, 0x0a, 0x1b, 0x63, 0x6f, 0x6e, 0x66, 0x69, 0x67, 0x75, 0x72, 0x65, 0x50, 0x72, 0x65, 0x63,
+	0x6f, 0x6d, 0x70, 0x69, 0x6c, 0x65, 0x73, 0x52, 0x65, 0x71, 0x75, 0x65, 0x73, 0x74, 0x18, 0x0c,
+	0x20, 0x01, 0x28, 0x0b, 0x32, 0x24, 0x2e, 0x66, 0x66, 0x69, 0x2e, 0x66, 0x66, 0x69, 0x2e, 0x43,
+	0x6f, 0x6e, 0x66, 0x69, 0x67, 0x75, 0x72, 0x65, 0x50, 0x72, 0x65, 0x63, 0x6f, 0x6d, 0x70, 0x69,
+	0x6c, 0x65, 0x73, 0x52, 0x65, 0x71, 0x75, 0x65, 0x73, 0x74, 0x48, 0x00, 0x52, 0x1b, 0x63, 0x6f,
+	0x6e, 0x66, 0x69, 0x67, 0x75, 0x72, 0x65, 0x50, 0x72, 0x65, 0x63, 0x6f, 0x6d, 0x70, 0x69, 0x6c,
+	0x65, 0x73, 0x52, 0x65, 0x71, 0x75, 0x65, 0x73, 0x74, 0x42, 0x05, 0x0a, 0x03, 0x72, 0x65, 0x71,
+	0x42, 0x04, 0x5a, 0x02, 0x2e, 0x2f, 0x62, 0x06, 0x70, 0x72, 0x6f, 0x74, 0x6f, 0x33,
+}
+
+var (
+	file_ffi_proto_rawDescOnce sync.Once
+	file_ffi_proto_rawDescData = file_ffi_proto_rawDesc
+)
+
+func file_ffi_proto_rawDescGZIP() []byte {
+	file_ffi_proto_rawDescOnce.Do(func() {
+		file_ffi_proto_rawDescData = protoimpl.X.CompressGZIP(file_ffi_proto_rawDescData)
+	})
+	return file_ffi_proto_rawDescData
+}
+
+var file_ffi_proto_msgTypes = make([]protoimpl.MessageInfo, 70)
+var file_ffi_proto_goTypes = []interface{}{
+	(*AccessListItem)(nil),                     // 0: ffi.ffi.AccessListItem
+	(*TransactionData)(nil),                    // 1: ffi.ffi.TransactionData
+	(*TransactionContext)(nil),                 // 2: ffi.ffi.TransactionContext
+	(*HandleTransactionRequest)(nil),           // 3: ffi.ffi.HandleTransactionRequest
+	(*HandleTransactionResponse)(nil),          // 4: ffi.ffi.HandleTransactionResponse
+	(*Receipt)(nil),                            // 5: ffi.ffi.Receipt
+	(*RevertReason)(nil),                       // 6: ffi.ffi.RevertReason
+	(*Topic)(nil),                              // 7: ffi.ffi.Topic
+	(*Log)(nil),                                // 8: ffi.ffi.Log
+	(*QueryGetAccount)(nil),                    // 9: ffi.ffi.QueryGetAccount
+	(*QueryGetAccountResponse)(nil),            // 10: ffi.ffi.QueryGetAccountResponse
+	(*QueryInsertAccount)(nil),                 // 11: ffi.ffi.QueryInsertAccount
+	(*QueryInsertAccountResponse)(nil),         // 12: ffi.ffi.QueryInsertAccountResponse
+	(*QueryContainsKey)(nil),                   // 13: ffi.ffi.QueryContainsKey
+	(*QueryContainsKeyResponse)(nil),           // 14: ffi.ffi.QueryContainsKeyResponse
+	(*QueryGetAccountStorageCell)(nil),         // 15: ffi.ffi.QueryGetAccountStorageCell
+	(*QueryGetAccountStorageCellResponse)(nil), // 16: ffi.ffi.QueryGetAccountStorageCellResponse
+	(*QueryGetAccountCode)(nil),                // 17: ffi.ffi.QueryGetAccountCode
+	(*QueryGetAccountCodeResponse)(nil),        // 18: ffi.ffi.QueryGetAccountCodeResponse
+	(*QueryInsertAccountCode)(nil),             // 19: ffi.ffi.QueryInsertAccountCode
+	(*QueryInsertAccountCodeResponse)(nil),     // 20: ffi.ffi.QueryInsertAccountCodeResponse
+	(*QueryInsertStorageCell)(nil),             // 21: ffi.ffi.QueryInsertStorageCell
+	(*QueryInsertStorageCellResponse)(nil),     // 22: ffi.ffi.QueryInsertStorageCellResponse
+	(*QueryRemove)(nil),                        // 23: ffi.ffi.QueryRemove
+	(*QueryRemoveResponse)(nil),                // 24: ffi.ffi.QueryRemoveResponse
+	(*QueryRemoveStorageCell)(nil),             // 25: ffi.ffi.QueryRemoveStorageCell
+	(*QueryRemoveStorageCellResponse)(nil),     // 26: ffi.ffi.QueryRemoveStorageCellResponse
+	(*QueryRemoveStorage)(nil),                 // 27: ffi.ffi.QueryRemoveStorage
+	(*QueryRemoveStorageResponse)(nil),         // 28: ffi.ffi.QueryRemoveStorageResponse
+	(*QueryBlockHash)(nil),                     // 29: ffi.ffi.QueryBlockHash
+	(*QueryBlockHashResponse)(nil),             // 30: ffi.ffi.QueryBlockHashResponse
+	(*QueryBatchRead)(nil),                     // 31: ffi.ffi.QueryBatchRead
+	(*QueryBatchReadResponse)(nil),             // 32: ffi.ffi.QueryBatchReadResponse
+	(*QueryCommit)(nil),                        // 33: ffi.ffi.QueryCommit
+	(*QueryCommitResponse)(nil),                // 34: ffi.ffi.QueryCommitResponse
+	(*QueryBankBalance)(nil),                   // 35: ffi.ffi.QueryBankBalance
+	(*QueryBankBalanceResponse)(nil),           // 36: ffi.ffi.QueryBankBalanceResponse
+	(*CosmosRequest)(nil),                      // 37: ffi.ffi.CosmosRequest
+	(*SGXVMCallParams)(nil),                    // 38: ffi.ffi.SGXVMCallParams
+	(*SGXVMCreateParams)(nil),                  // 39: ffi.ffi.SGXVMCreateParams
+	(*SGXVMCallRequest)(nil),                   // 40: ffi.ffi.SGXVMCallRequest
+	(*SGXVMCreateRequest)(nil),                 // 41: ffi.ffi.SGXVMCreateRequest
+	(*CallFrame)(nil),                          // 42: ffi.ffi.CallFrame
+	(*TraceTransactionResponse)(nil),           // 43: ffi.ffi.TraceTransactionResponse
+	(*StructLoggerConfig)(nil),                 // 44: ffi.ffi.StructLoggerConfig
+	(*SGXVMStructLogRequest)(nil),              // 45: ffi.ffi.SGXVMStructLogRequest
+	(*StorageEntry)(nil),                       // 46: ffi.ffi.StorageEntry
+	(*StructLog)(nil),                          // 47: ffi.ffi.StructLog
+	(*StructLogs)(nil),                         // 48: ffi.ffi.StructLogs
+	(*StructLogResponse)(nil),                  // 49: ffi.ffi.StructLogResponse
+	(*SGXVMEstimateGasRequest)(nil),            // 50: ffi.ffi.SGXVMEstimateGasRequest
+	(*EstimateGasResponse)(nil),                // 51: ffi.ffi.EstimateGasResponse
+	(*SGXVMStateDiffRequest)(nil),              // 52: ffi.ffi.SGXVMStateDiffRequest
+	(*StorageDiff)(nil),                        // 53: ffi.ffi.StorageDiff
+	(*AccountDiff)(nil),                        // 54: ffi.ffi.AccountDiff
+	(*StateDiffResponse)(nil),                  // 55: ffi.ffi.StateDiffResponse
+	(*SGXVMCreateAccessListRequest)(nil),       // 56: ffi.ffi.SGXVMCreateAccessListRequest
+	(*CreateAccessListResponse)(nil),           // 57: ffi.ffi.CreateAccessListResponse
+	(*SGXVMBatchTransaction)(nil),              // 58: ffi.ffi.SGXVMBatchTransaction
+	(*SGXVMBatchRequest)(nil),                  // 59: ffi.ffi.SGXVMBatchRequest
+	(*BatchTransactionResult)(nil),             // 60: ffi.ffi.BatchTransactionResult
+	(*BatchResponse)(nil),                      // 61: ffi.ffi.BatchResponse
+	(*SGXVMSignedTransactionRequest)(nil),      // 62: ffi.ffi.SGXVMSignedTransactionRequest
+	(*SignedTransactionResponse)(nil),          // 63: ffi.ffi.SignedTransactionResponse
+	(*NodePublicKeyRequest)(nil),               // 64: ffi.ffi.NodePublicKeyRequest
+	(*NodePublicKeyResponse)(nil),              // 65: ffi.ffi.NodePublicKeyResponse
+	(*PrecompileOverride)(nil),                 // 66: ffi.ffi.PrecompileOverride
+	(*ConfigurePrecompilesRequest)(nil),        // 67: ffi.ffi.ConfigurePrecompilesRequest
+	(*ConfigurePrecompilesResponse)(nil),       // 68: ffi.ffi.ConfigurePrecompilesResponse
+	(*FFIRequest)(nil),                         // 69: ffi.ffi.FFIRequest
+}
+var file_ffi_proto_depIdxs = []int32{
+	0,  // 0: ffi.ffi.TransactionData.accessList:type_name -> ffi.ffi.AccessListItem
+	1,  // 1: ffi.ffi.HandleTransactionRequest.tx_data:type_name -> ffi.ffi.TransactionData
+	2,  // 2: ffi.ffi.HandleTransactionRequest.tx_context:type_name -> ffi.ffi.TransactionContext
+	8,  // 3: ffi.ffi.HandleTransactionResponse.logs:type_name -> ffi.ffi.Log
+	6,  // 4: ffi.ffi.HandleTransactionResponse.revert:type_name -> ffi.ffi.RevertReason
+	5,  // 5: ffi.ffi.HandleTransactionResponse.receipt:type_name -> ffi.ffi.Receipt
+	7,  // 6: ffi.ffi.Log.topics:type_name -> ffi.ffi.Topic
+	9,  // 7: ffi.ffi.QueryBatchRead.accounts:type_name -> ffi.ffi.QueryGetAccount
+	17, // 8: ffi.ffi.QueryBatchRead.codes:type_name -> ffi.ffi.QueryGetAccountCode
+	15, // 9: ffi.ffi.QueryBatchRead.storageCells:type_name -> ffi.ffi.QueryGetAccountStorageCell
+	10, // 10: ffi.ffi.QueryBatchReadResponse.accounts:type_name -> ffi.ffi.QueryGetAccountResponse
+	18, // 11: ffi.ffi.QueryBatchReadResponse.codes:type_name -> ffi.ffi.QueryGetAccountCodeResponse
+	16, // 12: ffi.ffi.QueryBatchReadResponse.storageCells:type_name -> ffi.ffi.QueryGetAccountStorageCellResponse
+	23, // 13: ffi.ffi.QueryCommit.removed:type_name -> ffi.ffi.QueryRemove
+	27, // 14: ffi.ffi.QueryCommit.resetStorage:type_name -> ffi.ffi.QueryRemoveStorage
+	11, // 15: ffi.ffi.QueryCommit.accounts:type_name -> ffi.ffi.QueryInsertAccount
+	19, // 16: ffi.ffi.QueryCommit.codes:type_name -> ffi.ffi.QueryInsertAccountCode
+	21, // 17: ffi.ffi.QueryCommit.storageCells:type_name -> ffi.ffi.QueryInsertStorageCell
+	9,  // 18: ffi.ffi.CosmosRequest.getAccount:type_name -> ffi.ffi.QueryGetAccount
+	11, // 19: ffi.ffi.CosmosRequest.insertAccount:type_name -> ffi.ffi.QueryInsertAccount
+	13, // 20: ffi.ffi.CosmosRequest.containsKey:type_name -> ffi.ffi.QueryContainsKey
+	17, // 21: ffi.ffi.CosmosRequest.accountCode:type_name -> ffi.ffi.QueryGetAccountCode
+	15, // 22: ffi.ffi.CosmosRequest.storageCell:type_name -> ffi.ffi.QueryGetAccountStorageCell
+	19, // 23: ffi.ffi.CosmosRequest.insertAccountCode:type_name -> ffi.ffi.QueryInsertAccountCode
+	21, // 24: ffi.ffi.CosmosRequest.insertStorageCell:type_name -> ffi.ffi.QueryInsertStorageCell
+	23, // 25: ffi.ffi.CosmosRequest.remove:type_name -> ffi.ffi.QueryRemove
+	25, // 26: ffi.ffi.CosmosRequest.removeStorageCell:type_name -> ffi.ffi.QueryRemoveStorageCell
+	27, // 27: ffi.ffi.CosmosRequest.removeStorage:type_name -> ffi.ffi.QueryRemoveStorage
+	29, // 28: ffi.ffi.CosmosRequest.blockHash:type_name -> ffi.ffi.QueryBlockHash
+	31, // 29: ffi.ffi.CosmosRequest.batchRead:type_name -> ffi.ffi.QueryBatchRead
+	33, // 30: ffi.ffi.CosmosRequest.commit:type_name -> ffi.ffi.QueryCommit
+	35, // 31: ffi.ffi.CosmosRequest.bankBalance:type_name -> ffi.ffi.QueryBankBalance
+	0,  // 32: ffi.ffi.SGXVMCallParams.accessList:type_name -> ffi.ffi.AccessListItem
+	0,  // 33: ffi.ffi.SGXVMCreateParams.accessList:type_name -> ffi.ffi.AccessListItem
+	38, // 34: ffi.ffi.SGXVMCallRequest.params:type_name -> ffi.ffi.SGXVMCallParams
+	2,  // 35: ffi.ffi.SGXVMCallRequest.context:type_name -> ffi.ffi.TransactionContext
+	39, // 36: ffi.ffi.SGXVMCreateRequest.params:type_name -> ffi.ffi.SGXVMCreateParams
+	2,  // 37: ffi.ffi.SGXVMCreateRequest.context:type_name -> ffi.ffi.TransactionContext
+	42, // 38: ffi.ffi.CallFrame.calls:type_name -> ffi.ffi.CallFrame
+	4,  // 39: ffi.ffi.TraceTransactionResponse.result:type_name -> ffi.ffi.HandleTransactionResponse
+	42, // 40: ffi.ffi.TraceTransactionResponse.callTrace:type_name -> ffi.ffi.CallFrame
+	38, // 41: ffi.ffi.SGXVMStructLogRequest.params:type_name -> ffi.ffi.SGXVMCallParams
+	2,  // 42: ffi.ffi.SGXVMStructLogRequest.context:type_name -> ffi.ffi.TransactionContext
+	44, // 43: ffi.ffi.SGXVMStructLogRequest.config:type_name -> ffi.ffi.StructLoggerConfig
+	46, // 44: ffi.ffi.StructLog.storage:type_name -> ffi.ffi.StorageEntry
+	47, // 45: ffi.ffi.StructLogs.logs:type_name -> ffi.ffi.StructLog
+	4,  // 46: ffi.ffi.StructLogResponse.result:type_name -> ffi.ffi.HandleTransactionResponse
+	38, // 47: ffi.ffi.SGXVMEstimateGasRequest.params:type_name -> ffi.ffi.SGXVMCallParams
+	2,  // 48: ffi.ffi.SGXVMEstimateGasRequest.context:type_name -> ffi.ffi.TransactionContext
+	38, // 49: ffi.ffi.SGXVMStateDiffRequest.params:type_name -> ffi.ffi.SGXVMCallParams
+	2,  // 50: ffi.ffi.SGXVMStateDiffRequest.context:type_name -> ffi.ffi.TransactionContext
+	53, // 51: ffi.ffi.AccountDiff.storage:type_name -> ffi.ffi.StorageDiff
+	4,  // 52: ffi.ffi.StateDiffResponse.result:type_name -> ffi.ffi.HandleTransactionResponse
+	54, // 53: ffi.ffi.StateDiffResponse.accounts:type_name -> ffi.ffi.AccountDiff
+	38, // 54: ffi.ffi.SGXVMCreateAccessListRequest.params:type_name -> ffi.ffi.SGXVMCallParams
+	2,  // 55: ffi.ffi.SGXVMCreateAccessListRequest.context:type_name -> ffi.ffi.TransactionContext
+	4,  // 56: ffi.ffi.CreateAccessListResponse.result:type_name -> ffi.ffi.HandleTransactionResponse
+	0,  // 57: ffi.ffi.CreateAccessListResponse.accessList:type_name -> ffi.ffi.AccessListItem
+	38, // 58: ffi.ffi.SGXVMBatchTransaction.call:type_name -> ffi.ffi.SGXVMCallParams
+	39, // 59: ffi.ffi.SGXVMBatchTransaction.create:type_name -> ffi.ffi.SGXVMCreateParams
+	58, // 60: ffi.ffi.SGXVMBatchRequest.transactions:type_name -> ffi.ffi.SGXVMBatchTransaction
+	2,  // 61: ffi.ffi.SGXVMBatchRequest.context:type_name -> ffi.ffi.TransactionContext
+	4,  // 62: ffi.ffi.BatchTransactionResult.result:type_name -> ffi.ffi.HandleTransactionResponse
+	60, // 63: ffi.ffi.BatchResponse.results:type_name -> ffi.ffi.BatchTransactionResult
+	2,  // 64: ffi.ffi.SGXVMSignedTransactionRequest.context:type_name -> ffi.ffi.TransactionContext
+	4,  // 65: ffi.ffi.SignedTransactionResponse.result:type_name -> ffi.ffi.HandleTransactionResponse
+	66, // 66: ffi.ffi.ConfigurePrecompilesRequest.overrides:type_name -> ffi.ffi.PrecompileOverride
+	40, // 67: ffi.ffi.FFIRequest.callRequest:type_name -> ffi.ffi.SGXVMCallRequest
+	41, // 68: ffi.ffi.FFIRequest.createRequest:type_name -> ffi.ffi.SGXVMCreateRequest
+	64, // 69: ffi.ffi.FFIRequest.publicKeyRequest:type_name -> ffi.ffi.NodePublicKeyRequest
+	40, // 70: ffi.ffi.FFIRequest.traceCallRequest:type_name -> ffi.ffi.SGXVMCallRequest
+	41, // 71: ffi.ffi.FFIRequest.traceCreateRequest:type_name -> ffi.ffi.SGXVMCreateRequest
+	45, // 72: ffi.ffi.FFIRequest.structLogRequest:type_name -> ffi.ffi.SGXVMStructLogRequest
+	50, // 73: ffi.ffi.FFIRequest.estimateGasRequest:type_name -> ffi.ffi.SGXVMEstimateGasRequest
+	52, // 74: ffi.ffi.FFIRequest.stateDiffRequest:type_name -> ffi.ffi.SGXVMStateDiffRequest
+	56, // 75: ffi.ffi.FFIRequest.createAccessListRequest:type_name -> ffi.ffi.SGXVMCreateAccessListRequest
+	59, // 76: ffi.ffi.FFIRequest.batchRequest:type_name -> ffi.ffi.SGXVMBatchRequest
+	62, // 77: ffi.ffi.FFIRequest.signedTransactionRequest:type_name -> ffi.ffi.SGXVMSignedTransactionRequest
+	67, // 78: ffi.ffi.FFIRequest.configurePrecompilesRequest:type_name -> ffi.ffi.ConfigurePrecompilesRequest
+	79, // [79:79] is the sub-list for method output_type
+	79, // [79:79] is the sub-list for method input_type
+	79, // [79:79] is the sub-list for extension type_name
+	79, // [79:79] is the sub-list for extension extendee
+	0,  // [0:79] is the sub-list for field type_name
+}
+
+func init() { file_ffi_proto_init() }
+func file_ffi_proto_init() {
+	if File_ffi_proto != nil {
+		return
+	}
+	if !protoimpl.UnsafeEnabled {
+		file_ffi_proto_msgTypes[0].Exporter = func(v interface{}, i int) interface{} {
+			switch v := v.(*AccessListItem); i {
+			case 0:
+				return &v.state
+			case 1:
+				return &v.sizeCache
+			case 2:
+				return &v.unknownFields
+			default:
+				return nil
+			}
+		}
+		file_ffi_proto_msgTypes[1].Exporter = func(v interface{}, i int) interface{} {
+			switch v := v.(*TransactionData); i {
+			case 0:
+				return &v.state
+			case 1:
+				return &v.sizeCache
+			case 2:
+				return &v.unknownFields
+			default:
+				return nil
+			}
+		}
+		file_ffi_proto_msgTypes[2].Exporter = func(v interface{}, i int) interface{} {
+			switch v := v.(*TransactionContext); i {
+			case 0:
+				return &v.state
+			case 1:
+				return &v.sizeCache
+			case 2:
+				return &v.unknownFields
+			default:
+				return nil
+			}
+		}
+		file_ffi_proto_msgTypes[3].Exporter = func(v interface{}, i int) interface{} {
+			switch v := v.(*HandleTransactionRequest); i {
+			case 0:
+				return &v.state
+			case 1:
+				return &v.sizeCache
+			case 2:
+				return &v.unknownFields
+			default:
+				return nil
+			}
+		}
+		file_ffi_proto_msgTypes[4].Exporter = func(v interface{}, i int) interface{} {
+			switch v := v.(*HandleTransactionResponse); i {
+			case 0:
+				return &v.state
+			case 1:
+				return &v.sizeCache
+			case 2:
+				return &v.unknownFields
+			default:
+				return nil
+			}
+		}
+		file_ffi_proto_msgTypes[5].Exporter = func(v interface{}, i int) interface{} {
+			switch v := v.(*Receipt); i {
+			case 0:
+				return &v.state
+			case 1:
+				return &v.sizeCache
+			case 2:
+				return &v.unknownFields
+			default:
+				return nil
+			}
+		}
+		file_ffi_proto_msgTypes[6].Exporter = func(v interface{}, i int) interface{} {
+			switch v := v.(*RevertReason); i {
+			case 0:
+				return &v.state
+			case 1:
+				return &v.sizeCache
+			case 2:
+				return &v.unknownFields
+			default:
+				return nil
+			}
+		}
+		file_ffi_proto_msgTypes[7].Exporter = func(v interface{}, i int) interface{} {
+			switch v := v.(*Topic); i {
+			case 0:
+				return &v.state
+			case 1:
+				return &v.sizeCache
+			case 2:
+				return &v.unknownFields
+			default:
+				return nil
+			}
+		}
+		file_ffi_proto_msgTypes[8].Exporter = func(v interface{}, i int) interface{} {
+			switch v := v.(*Log); i {
+			case 0:
+				return &v.state
+			case 1:
+				return &v.sizeCache
+			case 2:
+				return &v.unknownFields
+			default:
+				return nil
+			}
+		}
+		file_ffi_proto_msgTypes[9].Exporter = func(v interface{}, i int) interface{} {
+			switch v := v.(*QueryGetAccount); i {
+			case 0:
+				return &v.state
+			case 1:
+				return &v.sizeCache
+			case 2:
+				return &v.unknownFields
+			default:
+				return nil
+			}
+		}
+		file_ffi_proto_msgTypes[10].Exporter = func(v interface{}, i int) interface{} {
+			switch v := v.(*QueryGetAccountResponse); i {
+			case 0:
+				return &v.state
+			case 1:
+				return &v.sizeCache
+			case 2:
+				return &v.unknownFields
+			default:
+				return nil
+			}
+		}
+		file_ffi_proto_msgTypes[11].Exporter = func(v interface{}, i int) interface{} {
+			switch v := v.(*QueryInsertAccount); i {
+			case 0:
+				return &v.state
+			case 1:
+				return &v.sizeCache
+			case 2:
+				return &v.unknownFields
+			default:
+				return nil
+			}
+		}
+		file_ffi_proto_msgTypes[12].Exporter = func(v interface{}, i int) interface{} {
+			switch v := v.(*QueryInsertAccountResponse); i {
+			case 0:
+				return &v.state
+			case 1:
+				return &v.sizeCache
+			case 2:
+				return &v.unknownFields
+			default:
+				return nil
+			}
+		}
+		file_ffi_proto_msgTypes[13].Exporter = func(v interface{}, i int) interface{} {
+			switch v := v.(*QueryContainsKey); i {
+			case 0:
+				return &v.state
+			case 1:
+				return &v.sizeCache
+			case 2:
+				return &v.unknownFields
+			default:
+				return nil
+			}
+		}
+		file_ffi_proto_msgTypes[14].Exporter = func(v interface{}, i int) interface{} {
+			switch v := v.(*QueryContainsKeyResponse); i {
+			case 0:
+				return &v.state
+			case 1:
+				return &v.sizeCache
+			case 2:
+				return &v.unknownFields
+			default:
+				return nil
+			}
+		}
+		file_ffi_proto_msgTypes[15].Exporter = func(v interface{}, i int) interface{} {
+			switch v := v.(*QueryGetAccountStorageCell); i {
+			case 0:
+				return &v.state
+			case 1:
+				return &v.sizeCache
+			case 2:
+				return &v.unknownFields
+			default:
+				return nil
+			}
+		}
+		file_ffi_proto_msgTypes[16].Exporter = func(v interface{}, i int) interface{} {
+			switch v := v.(*QueryGetAccountStorageCellResponse); i {
+			case 0:
+				return &v.state
+			case 1:
+				return &v.sizeCache
+			case 2:
+				return &v.unknownFields
+			default:
+				return nil
+			}
+		}
+		file_ffi_proto_msgTypes[17].Exporter = func(v interface{}, i int) interface{} {
+			switch v := v.(*QueryGetAccountCode); i {
+			case 0:
+				return &v.state
+			case 1:
+				return &v.sizeCache
+			case 2:
+				return &v.unknownFields
+			default:
+				return nil
+			}
+		}
+		file_ffi_proto_msgTypes[18].Exporter = func(v interface{}, i int) interface{} {
+			switch v := v.(*QueryGetAccountCodeResponse); i {
+			case 0:
+				return &v.state
+			case 1:
+				return &v.sizeCache
+			case 2:
+				return &v.unknownFields
+			default:
+				return nil
+			}
+		}
+		file_ffi_proto_msgTypes[19].Exporter = func(v interface{}, i int) interface{} {
+			switch v := v.(*QueryInsertAccountCode); i {
+			case 0:
+				return &v.state
+			case 1:
+				return &v.sizeCache
+			case 2:
+				return &v.unknownFields
+			default:
+				return nil
+			}
+		}
+		file_ffi_proto_msgTypes[20].Exporter = func(v interface{}, i int) interface{} {
+			switch v := v.(*QueryInsertAccountCodeResponse); i {
+			case 0:
+				return &v.state
+			case 1:
+				return &v.sizeCache
+			case 2:
+				return &v.unknownFields
+			default:
+				return nil
+			}
+		}
+		file_ffi_proto_msgTypes[21].Exporter = func(v interface{}, i int) interface{} {
+			switch v := v.(*QueryInsertStorageCell); i {
+			case 0:
+				return &v.state
+			case 1:
+				return &v.sizeCache
+			case 2:
+				return &v.unknownFields
+			default:
+				return nil
+			}
+		}
+		file_ffi_proto_msgTypes[22].Exporter = func(v interface{}, i int) interface{} {
+			switch v := v.(*QueryInsertStorageCellResponse); i {
+			case 0:
+				return &v.state
+			case 1:
+				return &v.sizeCache
+			case 2:
+				return &v.unknownFields
+			default:
+				return nil
+			}
+		}
+		file_ffi_proto_msgTypes[23].Exporter = func(v interface{}, i int) interface{} {
+			switch v := v.(*QueryRemove); i {
+			case 0:
+				return &v.state
+			case 1:
+				return &v.sizeCache
+			case 2:
+				return &v.unknownFields
+			default:
+				return nil
+			}
+		}
+		file_ffi_proto_msgTypes[24].Exporter = func(v interface{}, i int) interface{} {
+			switch v := v.(*QueryRemoveResponse); i {
+			case 0:
+				return &v.state
+			case 1:
+				return &v.sizeCache
+			case 2:
+				return &v.unknownFields
+			default:
+				return nil
+			}
+		}
+		file_ffi_proto_msgTypes[25].Exporter = func(v interface{}, i int) interface{} {
+			switch v := v.(*QueryRemoveStorageCell); i {
+			case 0:
+				return &v.state
+			case 1:
+				return &v.sizeCache
+			case 2:
+				return &v.unknownFields
+			default:
+				return nil
+			}
+		}
+		file_ffi_proto_msgTypes[26].Exporter = func(v interface{}, i int) interface{} {
+			switch v := v.(*QueryRemoveStorageCellResponse); i {
+			case 0:
+				return &v.state
+			case 1:
+				return &v.sizeCache
+			case 2:
+				return &v.unknownFields
+			default:
+				return nil
+			}
+		}
+		file_ffi_proto_msgTypes[27].Exporter = func(v interface{}, i int) interface{} {
+			switch v := v.(*QueryRemoveStorage); i {
+			case 0:
+				return &v.state
+			case 1:
+				return &v.sizeCache
+			case 2:
+				return &v.unknownFields
+			default:
+				return nil
+			}
+		}
+		file_ffi_proto_msgTypes[28].Exporter = func(v interface{}, i int) interface{} {
+			switch v := v.(*QueryRemoveStorageResponse); i {
+			case 0:
+				return &v.state
+			case 1:
+				return &v.sizeCache
+			case 2:
+				return &v.unknownFields
+			default:
+				return nil
+			}
+		}
+		file_ffi_proto_msgTypes[29].Exporter = func(v interface{}, i int) interface{} {
+			switch v := v.(*QueryBlockHash); i {
+			case 0:
+				return &v.state
+			case 1:
+				return &v.sizeCache
+			case 2:
+				return &v.unknownFields
+			default:
+				return nil
+			}
+		}
+		file_ffi_proto_msgTypes[30].Exporter = func(v interface{}, i int) interface{} {
+			switch v := v.(*QueryBlockHashResponse); i {
 			case 0:
 				return &v.state
 			case 1:
@@ -2609,8 +5869,8 @@ func file_ffi_proto_init() {
 				return nil
 			}
 		}
-		file_ffi_proto_msgTypes[1].Exporter = func(v interface{}, i int) interface{} {
-			switch v := v.(*TransactionData); i {
+		file_ffi_proto_msgTypes[31].Exporter = func(v interface{}, i int) interface{} {
+			switch v := v.(*QueryBatchRead); i {
 			case 0:
 				return &v.state
 			case 1:
@@ -2621,8 +5881,8 @@ func file_ffi_proto_init() {
 				return nil
 			}
 		}
-		file_ffi_proto_msgTypes[2].Exporter = func(v interface{}, i int) interface{} {
-			switch v := v.(*TransactionContext); i {
+		file_ffi_proto_msgTypes[32].Exporter = func(v interface{}, i int) interface{} {
+			switch v := v.(*QueryBatchReadResponse); i {
 			case 0:
 				return &v.state
 			case 1:
@@ -2633,8 +5893,8 @@ func file_ffi_proto_init() {
 				return nil
 			}
 		}
-		file_ffi_proto_msgTypes[3].Exporter = func(v interface{}, i int) interface{} {
-			switch v := v.(*HandleTransactionRequest); i {
+		file_ffi_proto_msgTypes[33].Exporter = func(v interface{}, i int) interface{} {
+			switch v := v.(*QueryCommit); i {
 			case 0:
 				return &v.state
 			case 1:
@@ -2645,8 +5905,8 @@ func file_ffi_proto_init() {
 				return nil
 			}
 		}
-		file_ffi_proto_msgTypes[4].Exporter = func(v interface{}, i int) interface{} {
-			switch v := v.(*HandleTransactionResponse); i {
+		file_ffi_proto_msgTypes[34].Exporter = func(v interface{}, i int) interface{} {
+			switch v := v.(*QueryCommitResponse); i {
 			case 0:
 				return &v.state
 			case 1:
@@ -2657,8 +5917,8 @@ func file_ffi_proto_init() {
 				return nil
 			}
 		}
-		file_ffi_proto_msgTypes[5].Exporter = func(v interface{}, i int) interface{} {
-			switch v := v.(*Topic); i {
+		file_ffi_proto_msgTypes[35].Exporter = func(v interface{}, i int) interface{} {
+			switch v := v.(*QueryBankBalance); i {
 			case 0:
 				return &v.state
 			case 1:
@@ -2669,8 +5929,8 @@ func file_ffi_proto_init() {
 				return nil
 			}
 		}
-		file_ffi_proto_msgTypes[6].Exporter = func(v interface{}, i int) interface{} {
-			switch v := v.(*Log); i {
+		file_ffi_proto_msgTypes[36].Exporter = func(v interface{}, i int) interface{} {
+			switch v := v.(*QueryBankBalanceResponse); i {
 			case 0:
 				return &v.state
 			case 1:
@@ -2681,8 +5941,8 @@ func file_ffi_proto_init() {
 				return nil
 			}
 		}
-		file_ffi_proto_msgTypes[7].Exporter = func(v interface{}, i int) interface{} {
-			switch v := v.(*QueryGetAccount); i {
+		file_ffi_proto_msgTypes[37].Exporter = func(v interface{}, i int) interface{} {
+			switch v := v.(*CosmosRequest); i {
 			case 0:
 				return &v.state
 			case 1:
@@ -2693,8 +5953,8 @@ func file_ffi_proto_init() {
 				return nil
 			}
 		}
-		file_ffi_proto_msgTypes[8].Exporter = func(v interface{}, i int) interface{} {
-			switch v := v.(*QueryGetAccountResponse); i {
+		file_ffi_proto_msgTypes[38].Exporter = func(v interface{}, i int) interface{} {
+			switch v := v.(*SGXVMCallParams); i {
 			case 0:
 				return &v.state
 			case 1:
@@ -2705,8 +5965,8 @@ func file_ffi_proto_init() {
 				return nil
 			}
 		}
-		file_ffi_proto_msgTypes[9].Exporter = func(v interface{}, i int) interface{} {
-			switch v := v.(*QueryInsertAccount); i {
+		file_ffi_proto_msgTypes[39].Exporter = func(v interface{}, i int) interface{} {
+			switch v := v.(*SGXVMCreateParams); i {
 			case 0:
 				return &v.state
 			case 1:
@@ -2717,8 +5977,8 @@ func file_ffi_proto_init() {
 				return nil
 			}
 		}
-		file_ffi_proto_msgTypes[10].Exporter = func(v interface{}, i int) interface{} {
-			switch v := v.(*QueryInsertAccountResponse); i {
+		file_ffi_proto_msgTypes[40].Exporter = func(v interface{}, i int) interface{} {
+			switch v := v.(*SGXVMCallRequest); i {
 			case 0:
 				return &v.state
 			case 1:
@@ -2729,8 +5989,8 @@ func file_ffi_proto_init() {
 				return nil
 			}
 		}
-		file_ffi_proto_msgTypes[11].Exporter = func(v interface{}, i int) interface{} {
-			switch v := v.(*QueryContainsKey); i {
+		file_ffi_proto_msgTypes[41].Exporter = func(v interface{}, i int) interface{} {
+			switch v := v.(*SGXVMCreateRequest); i {
 			case 0:
 				return &v.state
 			case 1:
@@ -2741,8 +6001,8 @@ func file_ffi_proto_init() {
 				return nil
 			}
 		}
-		file_ffi_proto_msgTypes[12].Exporter = func(v interface{}, i int) interface{} {
-			switch v := v.(*QueryContainsKeyResponse); i {
+		file_ffi_proto_msgTypes[42].Exporter = func(v interface{}, i int) interface{} {
+			switch v := v.(*CallFrame); i {
 			case 0:
 				return &v.state
 			case 1:
@@ -2753,8 +6013,8 @@ func file_ffi_proto_init() {
 				return nil
 			}
 		}
-		file_ffi_proto_msgTypes[13].Exporter = func(v interface{}, i int) interface{} {
-			switch v := v.(*QueryGetAccountStorageCell); i {
+		file_ffi_proto_msgTypes[43].Exporter = func(v interface{}, i int) interface{} {
+			switch v := v.(*TraceTransactionResponse); i {
 			case 0:
 				return &v.state
 			case 1:
@@ -2765,8 +6025,8 @@ func file_ffi_proto_init() {
 				return nil
 			}
 		}
-		file_ffi_proto_msgTypes[14].Exporter = func(v interface{}, i int) interface{} {
-			switch v := v.(*QueryGetAccountStorageCellResponse); i {
+		file_ffi_proto_msgTypes[44].Exporter = func(v interface{}, i int) interface{} {
+			switch v := v.(*StructLoggerConfig); i {
 			case 0:
 				return &v.state
 			case 1:
@@ -2777,8 +6037,8 @@ func file_ffi_proto_init() {
 				return nil
 			}
 		}
-		file_ffi_proto_msgTypes[15].Exporter = func(v interface{}, i int) interface{} {
-			switch v := v.(*QueryGetAccountCode); i {
+		file_ffi_proto_msgTypes[45].Exporter = func(v interface{}, i int) interface{} {
+			switch v := v.(*SGXVMStructLogRequest); i {
 			case 0:
 				return &v.state
 			case 1:
@@ -2789,8 +6049,8 @@ func file_ffi_proto_init() {
 				return nil
 			}
 		}
-		file_ffi_proto_msgTypes[16].Exporter = func(v interface{}, i int) interface{} {
-			switch v := v.(*QueryGetAccountCodeResponse); i {
+		file_ffi_proto_msgTypes[46].Exporter = func(v interface{}, i int) interface{} {
+			switch v := v.(*StorageEntry); i {
 			case 0:
 				return &v.state
 			case 1:
@@ -2801,8 +6061,8 @@ func file_ffi_proto_init() {
 				return nil
 			}
 		}
-		file_ffi_proto_msgTypes[17].Exporter = func(v interface{}, i int) interface{} {
-			switch v := v.(*QueryInsertAccountCode); i {
+		file_ffi_proto_msgTypes[47].Exporter = func(v interface{}, i int) interface{} {
+			switch v := v.(*StructLog); i {
 			case 0:
 				return &v.state
 			case 1:
@@ -2813,8 +6073,8 @@ func file_ffi_proto_init() {
 				return nil
 			}
 		}
-		file_ffi_proto_msgTypes[18].Exporter = func(v interface{}, i int) interface{} {
-			switch v := v.(*QueryInsertAccountCodeResponse); i {
+		file_ffi_proto_msgTypes[48].Exporter = func(v interface{}, i int) interface{} {
+			switch v := v.(*StructLogs); i {
 			case 0:
 				return &v.state
 			case 1:
@@ -2825,8 +6085,8 @@ func file_ffi_proto_init() {
 				return nil
 			}
 		}
-		file_ffi_proto_msgTypes[19].Exporter = func(v interface{}, i int) interface{} {
-			switch v := v.(*QueryInsertStorageCell); i {
+		file_ffi_proto_msgTypes[49].Exporter = func(v interface{}, i int) interface{} {
+			switch v := v.(*StructLogResponse); i {
 			case 0:
 				return &v.state
 			case 1:
@@ -2837,8 +6097,8 @@ func file_ffi_proto_init() {
 				return nil
 			}
 		}
-		file_ffi_proto_msgTypes[20].Exporter = func(v interface{}, i int) interface{} {
-			switch v := v.(*QueryInsertStorageCellResponse); i {
+		file_ffi_proto_msgTypes[50].Exporter = func(v interface{}, i int) interface{} {
+			switch v := v.(*SGXVMEstimateGasRequest); i {
 			case 0:
 				return &v.state
 			case 1:
@@ -2849,8 +6109,8 @@ func file_ffi_proto_init() {
 				return nil
 			}
 		}
-		file_ffi_proto_msgTypes[21].Exporter = func(v interface{}, i int) interface{} {
-			switch v := v.(*QueryRemove); i {
+		file_ffi_proto_msgTypes[51].Exporter = func(v interface{}, i int) interface{} {
+			switch v := v.(*EstimateGasResponse); i {
 			case 0:
 				return &v.state
 			case 1:
@@ -2861,8 +6121,8 @@ func file_ffi_proto_init() {
 				return nil
 			}
 		}
-		file_ffi_proto_msgTypes[22].Exporter = func(v interface{}, i int) interface{} {
-			switch v := v.(*QueryRemoveResponse); i {
+		file_ffi_proto_msgTypes[52].Exporter = func(v interface{}, i int) interface{} {
+			switch v := v.(*SGXVMStateDiffRequest); i {
 			case 0:
 				return &v.state
 			case 1:
@@ -2873,8 +6133,8 @@ func file_ffi_proto_init() {
 				return nil
 			}
 		}
-		file_ffi_proto_msgTypes[23].Exporter = func(v interface{}, i int) interface{} {
-			switch v := v.(*QueryRemoveStorageCell); i {
+		file_ffi_proto_msgTypes[53].Exporter = func(v interface{}, i int) interface{} {
+			switch v := v.(*StorageDiff); i {
 			case 0:
 				return &v.state
 			case 1:
@@ -2885,8 +6145,8 @@ func file_ffi_proto_init() {
 				return nil
 			}
 		}
-		file_ffi_proto_msgTypes[24].Exporter = func(v interface{}, i int) interface{} {
-			switch v := v.(*QueryRemoveStorageCellResponse); i {
+		file_ffi_proto_msgTypes[54].Exporter = func(v interface{}, i int) interface{} {
+			switch v := v.(*AccountDiff); i {
 			case 0:
 				return &v.state
 			case 1:
@@ -2897,8 +6157,8 @@ func file_ffi_proto_init() {
 				return nil
 			}
 		}
-		file_ffi_proto_msgTypes[25].Exporter = func(v interface{}, i int) interface{} {
-			switch v := v.(*QueryRemoveStorage); i {
+		file_ffi_proto_msgTypes[55].Exporter = func(v interface{}, i int) interface{} {
+			switch v := v.(*StateDiffResponse); i {
 			case 0:
 				return &v.state
 			case 1:
@@ -2909,8 +6169,8 @@ func file_ffi_proto_init() {
 				return nil
 			}
 		}
-		file_ffi_proto_msgTypes[26].Exporter = func(v interface{}, i int) interface{} {
-			switch v := v.(*QueryRemoveStorageResponse); i {
+		file_ffi_proto_msgTypes[56].Exporter = func(v interface{}, i int) interface{} {
+			switch v := v.(*SGXVMCreateAccessListRequest); i {
 			case 0:
 				return &v.state
 			case 1:
@@ -2921,8 +6181,8 @@ func file_ffi_proto_init() {
 				return nil
 			}
 		}
-		file_ffi_proto_msgTypes[27].Exporter = func(v interface{}, i int) interface{} {
-			switch v := v.(*QueryBlockHash); i {
+		file_ffi_proto_msgTypes[57].Exporter = func(v interface{}, i int) interface{} {
+			switch v := v.(*CreateAccessListResponse); i {
 			case 0:
 				return &v.state
 			case 1:
@@ -2933,8 +6193,8 @@ func file_ffi_proto_init() {
 				return nil
 			}
 		}
-		file_ffi_proto_msgTypes[28].Exporter = func(v interface{}, i int) interface{} {
-			switch v := v.(*QueryBlockHashResponse); i {
+		file_ffi_proto_msgTypes[58].Exporter = func(v interface{}, i int) interface{} {
+			switch v := v.(*SGXVMBatchTransaction); i {
 			case 0:
 				return &v.state
 			case 1:
@@ -2945,8 +6205,8 @@ func file_ffi_proto_init() {
 				return nil
 			}
 		}
-		file_ffi_proto_msgTypes[29].Exporter = func(v interface{}, i int) interface{} {
-			switch v := v.(*CosmosRequest); i {
+		file_ffi_proto_msgTypes[59].Exporter = func(v interface{}, i int) interface{} {
+			switch v := v.(*SGXVMBatchRequest); i {
 			case 0:
 				return &v.state
 			case 1:
@@ -2957,8 +6217,8 @@ func file_ffi_proto_init() {
 				return nil
 			}
 		}
-		file_ffi_proto_msgTypes[30].Exporter = func(v interface{}, i int) interface{} {
-			switch v := v.(*SGXVMCallParams); i {
+		file_ffi_proto_msgTypes[60].Exporter = func(v interface{}, i int) interface{} {
+			switch v := v.(*BatchTransactionResult); i {
 			case 0:
 				return &v.state
 			case 1:
@@ -2969,8 +6229,8 @@ func file_ffi_proto_init() {
 				return nil
 			}
 		}
-		file_ffi_proto_msgTypes[31].Exporter = func(v interface{}, i int) interface{} {
-			switch v := v.(*SGXVMCreateParams); i {
+		file_ffi_proto_msgTypes[61].Exporter = func(v interface{}, i int) interface{} {
+			switch v := v.(*BatchResponse); i {
 			case 0:
 				return &v.state
 			case 1:
@@ -2981,8 +6241,8 @@ func file_ffi_proto_init() {
 				return nil
 			}
 		}
-		file_ffi_proto_msgTypes[32].Exporter = func(v interface{}, i int) interface{} {
-			switch v := v.(*SGXVMCallRequest); i {
+		file_ffi_proto_msgTypes[62].Exporter = func(v interface{}, i int) interface{} {
+			switch v := v.(*SGXVMSignedTransactionRequest); i {
 			case 0:
 				return &v.state
 			case 1:
@@ -2993,8 +6253,8 @@ func file_ffi_proto_init() {
 				return nil
 			}
 		}
-		file_ffi_proto_msgTypes[33].Exporter = func(v interface{}, i int) interface{} {
-			switch v := v.(*SGXVMCreateRequest); i {
+		file_ffi_proto_msgTypes[63].Exporter = func(v interface{}, i int) interface{} {
+			switch v := v.(*SignedTransactionResponse); i {
 			case 0:
 				return &v.state
 			case 1:
@@ -3005,7 +6265,7 @@ func file_ffi_proto_init() {
 				return nil
 			}
 		}
-		file_ffi_proto_msgTypes[34].Exporter = func(v interface{}, i int) interface{} {
+		file_ffi_proto_msgTypes[64].Exporter = func(v interface{}, i int) interface{} {
 			switch v := v.(*NodePublicKeyRequest); i {
 			case 0:
 				return &v.state
@@ -3017,7 +6277,7 @@ func file_ffi_proto_init() {
 				return nil
 			}
 		}
-		file_ffi_proto_msgTypes[35].Exporter = func(v interface{}, i int) interface{} {
+		file_ffi_proto_msgTypes[65].Exporter = func(v interface{}, i int) interface{} {
 			switch v := v.(*NodePublicKeyResponse); i {
 			case 0:
 				return &v.state
@@ -3029,7 +6289,43 @@ func file_ffi_proto_init() {
 				return nil
 			}
 		}
-		file_ffi_proto_msgTypes[36].Exporter = func(v interface{}, i int) interface{} {
+		file_ffi_proto_msgTypes[66].Exporter = func(v interface{}, i int) interface{} {
+			switch v := v.(*PrecompileOverride); i {
+			case 0:
+				return &v.state
+			case 1:
+				return &v.sizeCache
+			case 2:
+				return &v.unknownFields
+			default:
+				return nil
+			}
+		}
+		file_ffi_proto_msgTypes[67].Exporter = func(v interface{}, i int) interface{} {
+			switch v := v.(*ConfigurePrecompilesRequest); i {
+			case 0:
+				return &v.state
+			case 1:
+				return &v.sizeCache
+			case 2:
+				return &v.unknownFields
+			default:
+				return nil
+			}
+		}
+		file_ffi_proto_msgTypes[68].Exporter = func(v interface{}, i int) interface{} {
+			switch v := v.(*ConfigurePrecompilesResponse); i {
+			case 0:
+				return &v.state
+			case 1:
+				return &v.sizeCache
+			case 2:
+				return &v.unknownFields
+			default:
+				return nil
+			}
+		}
+		file_ffi_proto_msgTypes[69].Exporter = func(v interface{}, i int) interface{} {
 			switch v := v.(*FFIRequest); i {
 			case 0:
 				return &v.state
@@ -3042,7 +6338,7 @@ func file_ffi_proto_init() {
 			}
 		}
 	}
-	file_ffi_proto_msgTypes[29].OneofWrappers = []interface{}{
+	file_ffi_proto_msgTypes[37].OneofWrappers = []interface{}{
 		(*CosmosRequest_GetAccount)(nil),
 		(*CosmosRequest_InsertAccount)(nil),
 		(*CosmosRequest_ContainsKey)(nil),
@@ -3054,11 +6350,27 @@ func file_ffi_proto_init() {
 		(*CosmosRequest_RemoveStorageCell)(nil),
 		(*CosmosRequest_RemoveStorage)(nil),
 		(*CosmosRequest_BlockHash)(nil),
+		(*CosmosRequest_BatchRead)(nil),
+		(*CosmosRequest_Commit)(nil),
+		(*CosmosRequest_BankBalance)(nil),
+	}
+	file_ffi_proto_msgTypes[58].OneofWrappers = []interface{}{
+		(*SGXVMBatchTransaction_Call)(nil),
+		(*SGXVMBatchTransaction_Create)(nil),
 	}
-	file_ffi_proto_msgTypes[36].OneofWrappers = []interface{}{
+	file_ffi_proto_msgTypes[69].OneofWrappers = []interface{}{
 		(*FFIRequest_CallRequest)(nil),
 		(*FFIRequest_CreateRequest)(nil),
 		(*FFIRequest_PublicKeyRequest)(nil),
+		(*FFIRequest_TraceCallRequest)(nil),
+		(*FFIRequest_TraceCreateRequest)(nil),
+		(*FFIRequest_StructLogRequest)(nil),
+		(*FFIRequest_EstimateGasRequest)(nil),
+		(*FFIRequest_StateDiffRequest)(nil),
+		(*FFIRequest_CreateAccessListRequest)(nil),
+		(*FFIRequest_BatchRequest)(nil),
+		(*FFIRequest_SignedTransactionRequest)(nil),
+		(*FFIRequest_ConfigurePrecompilesRequest)(nil),
 	}
 	type x struct{}
 	out := protoimpl.TypeBuilder{
@@ -3066,7 +6378,7 @@ func file_ffi_proto_init() {
 			GoPackagePath: reflect.TypeOf(x{}).PkgPath(),
 			RawDescriptor: file_ffi_proto_rawDesc,
 			NumEnums:      0,
-			NumMessages:   37,
+			NumMessages:   70,
 			NumExtensions: 0,
 			NumServices:   0,
 		},
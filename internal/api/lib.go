@@ -5,6 +5,7 @@ package api
 import "C"
 
 import (
+	"errors"
 	"fmt"
 	ethcommon "github.com/ethereum/go-ethereum/common"
 	"golang.org/x/net/netutil"
@@ -258,6 +259,46 @@ func GetNodePublicKey() (*ffi.NodePublicKeyResponse, error) {
 	return &response, nil
 }
 
+// ConfigurePrecompiles sends configuration of precompiles to the enclave
+func ConfigurePrecompiles(overrides []*ffi.PrecompileOverride) error {
+	// Construct mocked querier
+	c := buildEmptyConnector()
+
+	// Create protobuf-encoded request
+	req := &ffi.FFIRequest{ Req: &ffi.FFIRequest_ConfigurePrecompilesRequest{
+		ConfigurePrecompilesRequest: &ffi.ConfigurePrecompilesRequest{ Overrides: overrides },
+	}}
+	reqBytes, err := proto.Marshal(req)
+	if err != nil {
+		log.Fatalln("Failed to encode req:", err)
+		return err
+	}
+
+	// Pass request to Rust
+	d := MakeView(reqBytes)
+	defer runtime.KeepAlive(reqBytes)
+
+	errmsg := NewUnmanagedVector(nil)
+	ptr, err := C.make_pb_request(c, d, &errmsg)
+	if err != nil {
+		return ErrorWithMessage(err, errmsg)
+	}
+
+	// Recover returned value
+	executionResult := CopyAndDestroyUnmanagedVector(ptr)
+	response := ffi.ConfigurePrecompilesResponse{}
+	if err := proto.Unmarshal(executionResult, &response); err != nil {
+		log.Fatalln("Failed to decode configure precompiles result:", err)
+		return err
+	}
+
+	if response.Error != "" {
+		return errors.New(response.Error)
+	}
+
+	return nil
+}
+
 // Call handles incoming call to contract or transfer of value
 func Call(
 	connector Connector,
@@ -373,6 +414,445 @@ func Create(
 	return &response, nil
 }
 
+// TraceCall handles incoming call to contract or transfer of value and returns tree of internal calls.
+// Traced calls are never committed
+func TraceCall(
+	connector Connector,
+	from, to, data, value []byte,
+	accessList ethtypes.AccessList,
+	gasLimit, nonce uint64,
+	txContext *ffi.TransactionContext,
+) (*ffi.TraceTransactionResponse, error) {
+	// Construct mocked querier
+	c := BuildConnector(connector)
+
+	// Create protobuf-encoded transaction data
+	params := &ffi.SGXVMCallParams{
+		From:       from,
+		To:         to,
+		Data:       data,
+		GasLimit:   gasLimit,
+		Value:      value,
+		AccessList: convertAccessList(accessList),
+		Commit:     false,
+		Nonce:      nonce,
+	}
+
+	// Create protobuf encoded request
+	req := ffi.FFIRequest{Req: &ffi.FFIRequest_TraceCallRequest{
+		TraceCallRequest: &ffi.SGXVMCallRequest{
+			Params:  params,
+			Context: txContext,
+		},
+	}}
+	reqBytes, err := proto.Marshal(&req)
+	if err != nil {
+		log.Fatalln("Failed to encode req:", err)
+		return nil, err
+	}
+
+	// Pass request to Rust
+	d := MakeView(reqBytes)
+	defer runtime.KeepAlive(reqBytes)
+
+	errmsg := NewUnmanagedVector(nil)
+	ptr, err := C.make_pb_request(c, d, &errmsg)
+	if err != nil {
+		return &ffi.TraceTransactionResponse{}, ErrorWithMessage(err, errmsg)
+	}
+
+	// Recover returned value
+	executionResult := CopyAndDestroyUnmanagedVector(ptr)
+	response := ffi.TraceTransactionResponse{}
+	if err := proto.Unmarshal(executionResult, &response); err != nil {
+		log.Fatalln("Failed to decode execution result:", err)
+		return nil, err
+	}
+
+	return &response, nil
+}
+
+// TraceCreate handles incoming request for creation of new contract and returns tree of internal calls.
+// Traced contract creations are never committed
+func TraceCreate(
+	connector Connector,
+	from, data, value []byte,
+	accessList ethtypes.AccessList,
+	gasLimit, nonce uint64,
+	txContext *ffi.TransactionContext,
+) (*ffi.TraceTransactionResponse, error) {
+	// Construct mocked querier
+	c := BuildConnector(connector)
+
+	// Create protobuf-encoded transaction data
+	params := &ffi.SGXVMCreateParams{
+		From:       from,
+		Data:       data,
+		GasLimit:   gasLimit,
+		Value:      value,
+		AccessList: convertAccessList(accessList),
+		Commit:     false,
+		Nonce:      nonce,
+	}
+
+	// Create protobuf encoded request
+	req := ffi.FFIRequest{Req: &ffi.FFIRequest_TraceCreateRequest{
+		TraceCreateRequest: &ffi.SGXVMCreateRequest{
+			Params:  params,
+			Context: txContext,
+		},
+	}}
+	reqBytes, err := proto.Marshal(&req)
+	if err != nil {
+		log.Fatalln("Failed to encode req:", err)
+		return nil, err
+	}
+
+	// Pass request to Rust
+	d := MakeView(reqBytes)
+	defer runtime.KeepAlive(reqBytes)
+
+	errmsg := NewUnmanagedVector(nil)
+	ptr, err := C.make_pb_request(c, d, &errmsg)
+	if err != nil {
+		return &ffi.TraceTransactionResponse{}, ErrorWithMessage(err, errmsg)
+	}
+
+	// Recover returned value
+	executionResult := CopyAndDestroyUnmanagedVector(ptr)
+	response := ffi.TraceTransactionResponse{}
+	if err := proto.Unmarshal(executionResult, &response); err != nil {
+		log.Fatalln("Failed to decode execution result:", err)
+		return nil, err
+	}
+
+	return &response, nil
+}
+
+// StructLogCall handles incoming call to contract with attached opcode-level struct logger.
+// Struct logs are returned only for non-commit calls with encrypted data. Requests with
+// enabled memory or storage capture are rejected, since these contain confidential data
+func StructLogCall(
+	connector Connector,
+	from, to, data, value []byte,
+	accessList ethtypes.AccessList,
+	gasLimit, nonce uint64,
+	txContext *ffi.TransactionContext,
+	enableMemory, enableStorage bool,
+) (*ffi.StructLogResponse, error) {
+	// Construct mocked querier
+	c := BuildConnector(connector)
+
+	// Create protobuf-encoded transaction data
+	params := &ffi.SGXVMCallParams{
+		From:       from,
+		To:         to,
+		Data:       data,
+		GasLimit:   gasLimit,
+		Value:      value,
+		AccessList: convertAccessList(accessList),
+		Commit:     false,
+		Nonce:      nonce,
+	}
+
+	// Create protobuf encoded request
+	req := ffi.FFIRequest{Req: &ffi.FFIRequest_StructLogRequest{
+		StructLogRequest: &ffi.SGXVMStructLogRequest{
+			Params:  params,
+			Context: txContext,
+			Config: &ffi.StructLoggerConfig{
+				EnableMemory:  enableMemory,
+				EnableStorage: enableStorage,
+			},
+		},
+	}}
+	reqBytes, err := proto.Marshal(&req)
+	if err != nil {
+		log.Fatalln("Failed to encode req:", err)
+		return nil, err
+	}
+
+	// Pass request to Rust
+	d := MakeView(reqBytes)
+	defer runtime.KeepAlive(reqBytes)
+
+	errmsg := NewUnmanagedVector(nil)
+	ptr, err := C.make_pb_request(c, d, &errmsg)
+	if err != nil {
+		return &ffi.StructLogResponse{}, ErrorWithMessage(err, errmsg)
+	}
+
+	// Recover returned value
+	executionResult := CopyAndDestroyUnmanagedVector(ptr)
+	response := ffi.StructLogResponse{}
+	if err := proto.Unmarshal(executionResult, &response); err != nil {
+		log.Fatalln("Failed to decode execution result:", err)
+		return nil, err
+	}
+
+	return &response, nil
+}
+
+// EstimateGas handles incoming request for estimation of gas required by transaction.
+// If `to` is empty, gas is estimated for contract creation
+func EstimateGas(
+	connector Connector,
+	from, to, data, value []byte,
+	accessList ethtypes.AccessList,
+	gasLimit, nonce uint64,
+	txContext *ffi.TransactionContext,
+) (*ffi.EstimateGasResponse, error) {
+	// Construct mocked querier
+	c := BuildConnector(connector)
+
+	// Create protobuf-encoded transaction data
+	params := &ffi.SGXVMCallParams{
+		From:       from,
+		To:         to,
+		Data:       data,
+		GasLimit:   gasLimit,
+		Value:      value,
+		AccessList: convertAccessList(accessList),
+		Commit:     false,
+		Nonce:      nonce,
+	}
+
+	// Create protobuf encoded request
+	req := ffi.FFIRequest{Req: &ffi.FFIRequest_EstimateGasRequest{
+		EstimateGasRequest: &ffi.SGXVMEstimateGasRequest{
+			Params:  params,
+			Context: txContext,
+		},
+	}}
+	reqBytes, err := proto.Marshal(&req)
+	if err != nil {
+		log.Fatalln("Failed to encode req:", err)
+		return nil, err
+	}
+
+	// Pass request to Rust
+	d := MakeView(reqBytes)
+	defer runtime.KeepAlive(reqBytes)
+
+	errmsg := NewUnmanagedVector(nil)
+	ptr, err := C.make_pb_request(c, d, &errmsg)
+	if err != nil {
+		return &ffi.EstimateGasResponse{}, ErrorWithMessage(err, errmsg)
+	}
+
+	// Recover returned value
+	estimationResult := CopyAndDestroyUnmanagedVector(ptr)
+	response := ffi.EstimateGasResponse{}
+	if err := proto.Unmarshal(estimationResult, &response); err != nil {
+		log.Fatalln("Failed to decode estimation result:", err)
+		return nil, err
+	}
+
+	return &response, nil
+}
+
+func StateDiff(
+	connector Connector,
+	from, to, data, value []byte,
+	accessList ethtypes.AccessList,
+	gasLimit, nonce uint64,
+	txContext *ffi.TransactionContext,
+) (*ffi.StateDiffResponse, error) {
+	// Construct mocked querier
+	c := BuildConnector(connector)
+
+	// Create protobuf-encoded transaction data
+	params := &ffi.SGXVMCallParams{
+		From:       from,
+		To:         to,
+		Data:       data,
+		GasLimit:   gasLimit,
+		Value:      value,
+		AccessList: convertAccessList(accessList),
+		Commit:     false,
+		Nonce:      nonce,
+	}
+
+	// Create protobuf encoded request
+	req := ffi.FFIRequest{Req: &ffi.FFIRequest_StateDiffRequest{
+		StateDiffRequest: &ffi.SGXVMStateDiffRequest{
+			Params:  params,
+			Context: txContext,
+		},
+	}}
+	reqBytes, err := proto.Marshal(&req)
+	if err != nil {
+		log.Fatalln("Failed to encode req:", err)
+		return nil, err
+	}
+
+	// Pass request to Rust
+	d := MakeView(reqBytes)
+	defer runtime.KeepAlive(reqBytes)
+
+	errmsg := NewUnmanagedVector(nil)
+	ptr, err := C.make_pb_request(c, d, &errmsg)
+	if err != nil {
+		return &ffi.StateDiffResponse{}, ErrorWithMessage(err, errmsg)
+	}
+
+	// Recover returned value
+	stateDiffResult := CopyAndDestroyUnmanagedVector(ptr)
+	response := ffi.StateDiffResponse{}
+	if err := proto.Unmarshal(stateDiffResult, &response); err != nil {
+		log.Fatalln("Failed to decode state diff result:", err)
+		return nil, err
+	}
+
+	return &response, nil
+}
+
+func CreateAccessList(
+	connector Connector,
+	from, to, data, value []byte,
+	accessList ethtypes.AccessList,
+	gasLimit, nonce uint64,
+	txContext *ffi.TransactionContext,
+) (*ffi.CreateAccessListResponse, error) {
+	// Construct mocked querier
+	c := BuildConnector(connector)
+
+	// Create protobuf-encoded transaction data
+	params := &ffi.SGXVMCallParams{
+		From:       from,
+		To:         to,
+		Data:       data,
+		GasLimit:   gasLimit,
+		Value:      value,
+		AccessList: convertAccessList(accessList),
+		Commit:     false,
+		Nonce:      nonce,
+	}
+
+	// Create protobuf encoded request
+	req := ffi.FFIRequest{Req: &ffi.FFIRequest_CreateAccessListRequest{
+		CreateAccessListRequest: &ffi.SGXVMCreateAccessListRequest{
+			Params:  params,
+			Context: txContext,
+		},
+	}}
+	reqBytes, err := proto.Marshal(&req)
+	if err != nil {
+		log.Fatalln("Failed to encode req:", err)
+		return nil, err
+	}
+
+	// Pass request to Rust
+	d := MakeView(reqBytes)
+	defer runtime.KeepAlive(reqBytes)
+
+	errmsg := NewUnmanagedVector(nil)
+	ptr, err := C.make_pb_request(c, d, &errmsg)
+	if err != nil {
+		return &ffi.CreateAccessListResponse{}, ErrorWithMessage(err, errmsg)
+	}
+
+	// Recover returned value
+	accessListResult := CopyAndDestroyUnmanagedVector(ptr)
+	response := ffi.CreateAccessListResponse{}
+	if err := proto.Unmarshal(accessListResult, &response); err != nil {
+		log.Fatalln("Failed to decode access list result:", err)
+		return nil, err
+	}
+
+	return &response, nil
+}
+
+// ExecuteBatch executes ordered list of transactions against the same block context
+// within a single request to the enclave
+func ExecuteBatch(
+	connector Connector,
+	transactions []*ffi.SGXVMBatchTransaction,
+	txContext *ffi.TransactionContext,
+) (*ffi.BatchResponse, error) {
+	// Construct mocked querier
+	c := BuildConnector(connector)
+
+	// Create protobuf encoded request
+	req := ffi.FFIRequest{Req: &ffi.FFIRequest_BatchRequest{
+		BatchRequest: &ffi.SGXVMBatchRequest{
+			Transactions: transactions,
+			Context:      txContext,
+		},
+	}}
+	reqBytes, err := proto.Marshal(&req)
+	if err != nil {
+		log.Fatalln("Failed to encode req:", err)
+		return nil, err
+	}
+
+	// Pass request to Rust
+	d := MakeView(reqBytes)
+	defer runtime.KeepAlive(reqBytes)
+
+	errmsg := NewUnmanagedVector(nil)
+	ptr, err := C.make_pb_request(c, d, &errmsg)
+	if err != nil {
+		return &ffi.BatchResponse{}, ErrorWithMessage(err, errmsg)
+	}
+
+	// Recover returned value
+	batchResult := CopyAndDestroyUnmanagedVector(ptr)
+	response := ffi.BatchResponse{}
+	if err := proto.Unmarshal(batchResult, &response); err != nil {
+		log.Fatalln("Failed to decode batch result:", err)
+		return nil, err
+	}
+
+	return &response, nil
+}
+
+// ExecuteSignedTransaction handles RLP-encoded signed Ethereum transaction. Sender is recovered
+// from transaction signature inside the enclave
+func ExecuteSignedTransaction(
+	connector Connector,
+	rawTransaction []byte,
+	txContext *ffi.TransactionContext,
+	commit bool,
+) (*ffi.SignedTransactionResponse, error) {
+	// Construct mocked querier
+	c := BuildConnector(connector)
+
+	// Create protobuf encoded request
+	req := ffi.FFIRequest{Req: &ffi.FFIRequest_SignedTransactionRequest{
+		SignedTransactionRequest: &ffi.SGXVMSignedTransactionRequest{
+			RawTransaction: rawTransaction,
+			Context:        txContext,
+			Commit:         commit,
+		},
+	}}
+	reqBytes, err := proto.Marshal(&req)
+	if err != nil {
+		log.Fatalln("Failed to encode req:", err)
+		return nil, err
+	}
+
+	// Pass request to Rust
+	d := MakeView(reqBytes)
+	defer runtime.KeepAlive(reqBytes)
+
+	errmsg := NewUnmanagedVector(nil)
+	ptr, err := C.make_pb_request(c, d, &errmsg)
+	if err != nil {
+		return &ffi.SignedTransactionResponse{}, ErrorWithMessage(err, errmsg)
+	}
+
+	// Recover returned value
+	executionResult := CopyAndDestroyUnmanagedVector(ptr)
+	response := ffi.SignedTransactionResponse{}
+	if err := proto.Unmarshal(executionResult, &response); err != nil {
+		log.Fatalln("Failed to decode signed transaction result:", err)
+		return nil, err
+	}
+
+	return &response, nil
+}
+
 // Converts AccessList type from ethtypes to protobuf-compatible type
 func convertAccessList(accessList ethtypes.AccessList) []*ffi.AccessListItem {
 	var converted []*ffi.AccessListItem
@@ -38,8 +38,16 @@ type QueryRemove = ffi.QueryRemove
 type QueryRemoveResponse = ffi.QueryRemoveResponse
 type QueryRemoveStorageCell = ffi.QueryRemoveStorageCell
 type QueryRemoveStorageCellResponse = ffi.QueryRemoveStorageCellResponse
+type QueryRemoveStorage = ffi.QueryRemoveStorage
+type QueryRemoveStorageResponse = ffi.QueryRemoveStorageResponse
 type QueryBlockHash = ffi.QueryBlockHash
 type QueryBlockHashResponse = ffi.QueryBlockHashResponse
+type QueryBatchRead = ffi.QueryBatchRead
+type QueryBatchReadResponse = ffi.QueryBatchReadResponse
+type QueryCommit = ffi.QueryCommit
+type QueryCommitResponse = ffi.QueryCommitResponse
+type QueryBankBalance = ffi.QueryBankBalance
+type QueryBankBalanceResponse = ffi.QueryBankBalanceResponse
 
 // Storage requests
 type CosmosRequest_GetAccount = ffi.CosmosRequest_GetAccount
@@ -51,11 +59,36 @@ type CosmosRequest_InsertAccountCode = ffi.CosmosRequest_InsertAccountCode
 type CosmosRequest_InsertStorageCell = ffi.CosmosRequest_InsertStorageCell
 type CosmosRequest_Remove = ffi.CosmosRequest_Remove
 type CosmosRequest_RemoveStorageCell = ffi.CosmosRequest_RemoveStorageCell
+type CosmosRequest_RemoveStorage = ffi.CosmosRequest_RemoveStorage
+type CosmosRequest_BatchRead = ffi.CosmosRequest_BatchRead
+type CosmosRequest_Commit = ffi.CosmosRequest_Commit
 
 // Backend requests
 type CosmosRequest_BlockHash = ffi.CosmosRequest_BlockHash
+type CosmosRequest_BankBalance = ffi.CosmosRequest_BankBalance
 
 type HandleTransactionResponse = ffi.HandleTransactionResponse
+type RevertReason = ffi.RevertReason
+type Receipt = ffi.Receipt
+type TraceTransactionResponse = ffi.TraceTransactionResponse
+type CallFrame = ffi.CallFrame
+type StructLogResponse = ffi.StructLogResponse
+type StructLogs = ffi.StructLogs
+type StructLog = ffi.StructLog
+type EstimateGasResponse = ffi.EstimateGasResponse
+type StateDiffResponse = ffi.StateDiffResponse
+type AccountDiff = ffi.AccountDiff
+type StorageDiff = ffi.StorageDiff
+type CreateAccessListResponse = ffi.CreateAccessListResponse
+type SGXVMBatchTransaction = ffi.SGXVMBatchTransaction
+type SGXVMBatchTransaction_Call = ffi.SGXVMBatchTransaction_Call
+type SGXVMBatchTransaction_Create = ffi.SGXVMBatchTransaction_Create
+type SGXVMCallParams = ffi.SGXVMCallParams
+type SGXVMCreateParams = ffi.SGXVMCreateParams
+type BatchResponse = ffi.BatchResponse
+type BatchTransactionResult = ffi.BatchTransactionResult
+type SignedTransactionResponse = ffi.SignedTransactionResponse
+type PrecompileOverride = ffi.PrecompileOverride
 type NodePublicKeyRequest = ffi.NodePublicKeyRequest
 type NodePublicKeyResponse = ffi.NodePublicKeyResponse
 
@@ -65,6 +98,11 @@ func IsNodeInitialized() (bool, error) {
 }
 
 // Call handles incoming transaction data to transfer value or call some contract
+// If `commit` is set, `nonce` should be equal to nonce of sender account. Nonce is incremented
+// by the enclave even if transaction fails, so it should not be incremented by the caller
+// (e.g. by ante handler) before the transaction is passed to the enclave.
+// Sender of call is not verified by the enclave, so call with encrypted data cannot be committed.
+// Use ExecuteSignedTransaction to bind encrypted data to the sender
 func Call(
 	querier types.Connector,
 	from, to, data, value []byte,
@@ -82,6 +120,9 @@ func Call(
 }
 
 // Create handles incoming transaction data and creates a new smart contract
+// If `commit` is set, `nonce` should be equal to nonce of sender account. Nonce is incremented
+// by the enclave even if transaction fails, so it should not be incremented by the caller
+// (e.g. by ante handler) before the transaction is passed to the enclave
 func Create(
 	querier types.Connector,
 	from, data, value []byte,
@@ -98,6 +139,145 @@ func Create(
 	return executionResult, nil
 }
 
+// TraceCall handles incoming transaction data to transfer value or call some contract
+// and returns tree of internal calls. Traced calls are never committed
+func TraceCall(
+	querier types.Connector,
+	from, to, data, value []byte,
+	accessList ethtypes.AccessList,
+	gasLimit, nonce uint64,
+	txContext *TransactionContext,
+) (*ffi.TraceTransactionResponse, error) {
+	traceResult, err := api.TraceCall(querier, from, to, data, value, accessList, gasLimit, nonce, txContext)
+	if err != nil {
+		return &ffi.TraceTransactionResponse{}, err
+	}
+
+	return traceResult, nil
+}
+
+// TraceCreate handles incoming transaction data to create a new smart contract
+// and returns tree of internal calls. Traced contract creations are never committed
+func TraceCreate(
+	querier types.Connector,
+	from, data, value []byte,
+	accessList ethtypes.AccessList,
+	gasLimit, nonce uint64,
+	txContext *TransactionContext,
+) (*ffi.TraceTransactionResponse, error) {
+	traceResult, err := api.TraceCreate(querier, from, data, value, accessList, gasLimit, nonce, txContext)
+	if err != nil {
+		return &ffi.TraceTransactionResponse{}, err
+	}
+
+	return traceResult, nil
+}
+
+// StructLogCall handles incoming transaction data to call some contract with attached struct logger.
+// Returned struct logs are protobuf-encoded `StructLogs` encrypted to the caller key. They contain
+// only opcodes, gas and depth, so `enableMemory` and `enableStorage` must be false
+func StructLogCall(
+	querier types.Connector,
+	from, to, data, value []byte,
+	accessList ethtypes.AccessList,
+	gasLimit, nonce uint64,
+	txContext *TransactionContext,
+	enableMemory, enableStorage bool,
+) (*ffi.StructLogResponse, error) {
+	structLogResult, err := api.StructLogCall(querier, from, to, data, value, accessList, gasLimit, nonce, txContext, enableMemory, enableStorage)
+	if err != nil {
+		return &ffi.StructLogResponse{}, err
+	}
+
+	return structLogResult, nil
+}
+
+// EstimateGas handles incoming transaction data and returns minimal gas limit required to execute it.
+// If `to` is empty, gas is estimated for contract creation
+func EstimateGas(
+	querier types.Connector,
+	from, to, data, value []byte,
+	accessList ethtypes.AccessList,
+	gasLimit, nonce uint64,
+	txContext *TransactionContext,
+) (*ffi.EstimateGasResponse, error) {
+	estimationResult, err := api.EstimateGas(querier, from, to, data, value, accessList, gasLimit, nonce, txContext)
+	if err != nil {
+		return &ffi.EstimateGasResponse{}, err
+	}
+
+	return estimationResult, nil
+}
+
+// StateDiff handles incoming transaction data in a simulation mode and returns changes,
+// which transaction would apply to the state. If `to` is empty, contract creation is simulated.
+// Only keys of changed storage slots are returned, since contract storage is confidential
+func StateDiff(
+	querier types.Connector,
+	from, to, data, value []byte,
+	accessList ethtypes.AccessList,
+	gasLimit, nonce uint64,
+	txContext *TransactionContext,
+) (*ffi.StateDiffResponse, error) {
+	stateDiffResult, err := api.StateDiff(querier, from, to, data, value, accessList, gasLimit, nonce, txContext)
+	if err != nil {
+		return &ffi.StateDiffResponse{}, err
+	}
+
+	return stateDiffResult, nil
+}
+
+// CreateAccessList handles incoming transaction data and returns EIP-2930 access list with
+// amount of gas used by transaction with that list applied. If `to` is empty, access list is
+// generated for contract creation
+func CreateAccessList(
+	querier types.Connector,
+	from, to, data, value []byte,
+	accessList ethtypes.AccessList,
+	gasLimit, nonce uint64,
+	txContext *TransactionContext,
+) (*ffi.CreateAccessListResponse, error) {
+	accessListResult, err := api.CreateAccessList(querier, from, to, data, value, accessList, gasLimit, nonce, txContext)
+	if err != nil {
+		return &ffi.CreateAccessListResponse{}, err
+	}
+
+	return accessListResult, nil
+}
+
+// ExecuteBatch handles ordered list of call and create transactions, which are executed against
+// the same block context. Transactions, which would exceed block gas limit of the context, are
+// rejected. State changes are written once all transactions are executed
+func ExecuteBatch(
+	querier types.Connector,
+	transactions []*SGXVMBatchTransaction,
+	txContext *TransactionContext,
+) (*ffi.BatchResponse, error) {
+	batchResult, err := api.ExecuteBatch(querier, transactions, txContext)
+	if err != nil {
+		return &ffi.BatchResponse{}, err
+	}
+
+	return batchResult, nil
+}
+
+// ExecuteSignedTransaction handles RLP-encoded signed legacy, EIP-2930 or EIP-1559 transaction.
+// Sender is verified inside the enclave before transaction data is decrypted. Chain id from
+// `txContext` should match chain id the enclave was built for
+func ExecuteSignedTransaction(
+	querier types.Connector,
+	rawTransaction []byte,
+	txContext *TransactionContext,
+	commit bool,
+) (*ffi.SignedTransactionResponse, error) {
+	executionResult, err := api.ExecuteSignedTransaction(querier, rawTransaction, txContext, commit)
+	if err != nil {
+		return &ffi.SignedTransactionResponse{}, err
+	}
+
+	return executionResult, nil
+}
+
 func InitializeMasterKey(shouldReset bool) error {
 	return api.InitializeMasterKey(shouldReset)
 }
@@ -121,6 +301,14 @@ func GetNodePublicKey() (*ffi.NodePublicKeyResponse, error) {
 	return result, nil
 }
 
+// ConfigurePrecompiles overrides activation height and gas cost of precompiles or disables them.
+// Should be called once on node start, before any transaction is executed. Configuration affects
+// results of transactions, therefore it should be the same on every node. Precompiles enabled by
+// enclave build features are not available until they get activation height from this configuration
+func ConfigurePrecompiles(overrides []*PrecompileOverride) error {
+	return api.ConfigurePrecompiles(overrides)
+}
+
 // Libsgx_wrapperVersion returns the version of the loaded library
 // at runtime. This can be used for debugging to verify the loaded version
 // matches the expected version.
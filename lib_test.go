@@ -1,8 +1,10 @@
 package librustgo
 
 import (
+	"bytes"
 	"fmt"
 	"math/big"
+	"strings"
 	"testing"
 
 	"github.com/SigmaGmbH/librustgo/internal/api"
@@ -36,6 +38,7 @@ func TestCreate(t *testing.T) {
 		value,
 		nil,
 		gasLimit,
+		fromAcct.Nonce,
 		txContext,
 		true,
 	)
@@ -88,6 +91,7 @@ func TestCoinTransfer(t *testing.T) {
 		value.Bytes(),
 		nil,
 		gasLimit,
+		senderNonceBefore,
 		txContext,
 		true,
 	)
@@ -125,6 +129,170 @@ func TestCoinTransfer(t *testing.T) {
 	}
 }
 
+func TestNonceValidation(t *testing.T) {
+	db := types.CreateMockedDatabase()
+	from := common.HexToAddress("0x690b9a9e9aa1c9db991c7721a92d351db4fac990")
+	to := common.HexToAddress("0xad60cdbe1d3ceb5f67074303f99ac95af082784d")
+
+	connector := types.MockedConnector{DB: &db}
+	value := big.NewInt(100)
+	gasLimit := uint64(2000000)
+	txContext := types.GetDefaultTxContext()
+
+	if err := db.InsertAccount(from, big.NewInt(100000).Bytes(), 5); err != nil {
+		t.Fatal(err)
+	}
+
+	// Nonce is incremented by the enclave, so the caller passes nonce of sender
+	// account without incrementing it in advance
+	res, err := api.Call(connector, from.Bytes(), to.Bytes(), nil, value.Bytes(), nil, gasLimit, 5, txContext, true)
+	if err != nil {
+		t.Fatal(err)
+	}
+	if res.VmError != "" {
+		t.Fatal(res.VmError)
+	}
+
+	// Replayed transaction is rejected
+	res, err = api.Call(connector, from.Bytes(), to.Bytes(), nil, value.Bytes(), nil, gasLimit, 5, txContext, true)
+	if err != nil {
+		t.Fatal(err)
+	}
+	if !strings.HasPrefix(res.VmError, "nonce too low") {
+		t.Fatalf("unexpected error: %s", res.VmError)
+	}
+
+	// Nonce cannot be skipped
+	res, err = api.Call(connector, from.Bytes(), to.Bytes(), nil, value.Bytes(), nil, gasLimit, 7, txContext, true)
+	if err != nil {
+		t.Fatal(err)
+	}
+	if !strings.HasPrefix(res.VmError, "nonce too high") {
+		t.Fatalf("unexpected error: %s", res.VmError)
+	}
+
+	fromAcct, err := db.GetAccountOrEmpty(from)
+	if err != nil {
+		t.Fatal(err)
+	}
+	if fromAcct.Nonce != 6 {
+		t.Fatalf("unexpected nonce: %d", fromAcct.Nonce)
+	}
+}
+
+func TestFailedTransactionIncrementsNonce(t *testing.T) {
+	db := types.CreateMockedDatabase()
+	from := common.HexToAddress("0x690b9a9e9aa1c9db991c7721a92d351db4fac990")
+
+	connector := types.MockedConnector{DB: &db}
+	gasLimit := uint64(2000000)
+	txContext := types.GetDefaultTxContext()
+
+	// Constructor reverts: REVERT(0, 0)
+	data := common.Hex2Bytes("60006000fd")
+	contractAddress := crypto.CreateAddress(from, 0)
+
+	res, err := api.Create(connector, from.Bytes(), data, nil, nil, gasLimit, 0, txContext, true)
+	if err != nil {
+		t.Fatal(err)
+	}
+	if res.VmError == "" {
+		t.Fatal("contract creation should be reverted")
+	}
+
+	// Reverted transaction consumes nonce as in Ethereum, otherwise it could be executed again
+	fromAcct, err := db.GetAccountOrEmpty(from)
+	if err != nil {
+		t.Fatal(err)
+	}
+	if fromAcct.Nonce != 1 {
+		t.Fatalf("unexpected nonce: %d", fromAcct.Nonce)
+	}
+
+	acct, err := db.GetAccountOrEmpty(contractAddress)
+	if err != nil {
+		t.Fatal(err)
+	}
+	if len(acct.Code) != 0 {
+		t.Fatal("reverted contract should not be deployed")
+	}
+}
+
+func TestBankBalancePrecompile(t *testing.T) {
+	db := types.CreateMockedDatabase()
+	from := common.HexToAddress("0x690b9a9e9aa1c9db991c7721a92d351db4fac990")
+	account := common.HexToAddress("0xad60cdbe1d3ceb5f67074303f99ac95af082784d")
+
+	connector := types.MockedConnector{DB: &db}
+	gasLimit := uint64(2000000)
+	txContext := types.GetDefaultTxContext()
+
+	// Bank balance precompile is not available until it is activated by configuration
+	overrides := []*PrecompileOverride{{Address: common.HexToAddress("0x0800").Bytes(), ActivationHeight: 0}}
+	if err := api.ConfigurePrecompiles(overrides); err != nil {
+		t.Fatal(err)
+	}
+
+	// Mocked bank module returns balance of EVM account
+	balance := big.NewInt(100000)
+	if err := db.InsertAccount(account, balance.Bytes(), 0); err != nil {
+		t.Fatal(err)
+	}
+
+	// ABI-encoded (address account, string denom)
+	input := common.LeftPadBytes(account.Bytes(), 32)
+	input = append(input, common.LeftPadBytes([]byte{0x40}, 32)...)
+	input = append(input, common.LeftPadBytes([]byte{5}, 32)...)
+	input = append(input, common.RightPadBytes([]byte("uswtr"), 32)...)
+
+	// Constructor stores input in memory, calls precompile at 0x800 and returns
+	// its output as code of deployed contract
+	var data []byte
+	for offset := 0; offset < len(input); offset += 32 {
+		// PUSH32 word, PUSH1 offset, MSTORE
+		data = append(data, 0x7f)
+		data = append(data, input[offset:offset+32]...)
+		data = append(data, 0x60, byte(offset), 0x52)
+	}
+	// STATICCALL(gas, 0x800, 0, 128, 0, 32)
+	data = append(data, 0x60, 0x20, 0x60, 0x00, 0x60, 0x80, 0x60, 0x00, 0x61, 0x08, 0x00, 0x5a, 0xfa)
+	// RETURN(0, 32)
+	data = append(data, 0x60, 0x20, 0x60, 0x00, 0xf3)
+
+	fromAcct, err := db.GetAccountOrEmpty(from)
+	if err != nil {
+		t.Fatal(err)
+	}
+	contractAddress := crypto.CreateAddress(from, fromAcct.Nonce)
+
+	res, err := api.Create(
+		connector,
+		from.Bytes(),
+		data,
+		nil,
+		nil,
+		gasLimit,
+		fromAcct.Nonce,
+		txContext,
+		true,
+	)
+	if err != nil {
+		t.Fatal(err)
+	}
+	if res.VmError != "" {
+		t.Fatal(res.VmError)
+	}
+
+	// Code of deployed contract is the balance returned by precompile
+	acct, err := db.GetAccountOrEmpty(contractAddress)
+	if err != nil {
+		t.Fatal(err)
+	}
+	if !bytes.Equal(acct.Code, common.LeftPadBytes(balance.Bytes(), 32)) {
+		t.Fatalf("unexpected balance returned by precompile: %x", acct.Code)
+	}
+}
+
 func TestSeedExchange(t *testing.T) {
 	if err := api.InitializeMasterKey(true); err != nil {
 		t.Fail()
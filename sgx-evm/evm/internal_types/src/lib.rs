@@ -1,18 +1,38 @@
 #![no_std]
 
+#[macro_use]
 extern crate sgx_tstd as std;
 
 use ethereum::Log;
+use evm::ExitReason;
+use primitive_types::H160;
 use std::{vec::Vec, string::String};
 
+pub use crate::nonce::NonceError;
+pub use crate::revert::RevertReason;
+
 pub mod ffi;
+pub mod nonce;
+pub mod revert;
 
 #[derive(Clone, Debug, PartialEq)]
 pub struct ExecutionResult {
     pub logs: Vec<Log>,
     pub data: Vec<u8>,
     pub gas_used: u64,
-    pub vm_error: String
+    pub vm_error: String,
+    // Decoded data returned by reverted transaction
+    pub revert_reason: Option<RevertReason>,
+    // Exit reason returned by EVM. Not set if transaction was not executed
+    pub exit_reason: Option<ExitReason>,
+    // Address of contract created by successful contract creation
+    pub contract_address: Option<H160>,
+    // Gas refund, which is already subtracted from `gas_used`
+    pub gas_refunded: u64,
+    // Gas charged before execution for transaction data and access list
+    pub intrinsic_gas: u64,
+    // Gas consumed by execution before refund, i.e. `gas_used + gas_refunded - intrinsic_gas`
+    pub execution_gas: u64,
 }
 
 impl ExecutionResult {
@@ -22,7 +42,43 @@ impl ExecutionResult {
             logs: Vec::default(),
             data: data,
             gas_used: gas_used.unwrap_or(21000), // This is minimum gas fee to apply the transaction
-            vm_error: reason
+            vm_error: reason,
+            revert_reason: None,
+            exit_reason: None,
+            contract_address: None,
+            gas_refunded: 0,
+            intrinsic_gas: 0,
+            execution_gas: 0,
+        }
+    }
+
+    /// Creates execution result of reverted transaction. Decodes revert reason from returned data
+    pub fn from_revert(data: Vec<u8>, gas_used: u64) -> Self {
+        let revert_reason = RevertReason::decode(&data);
+        let vm_error = match &revert_reason {
+            Some(reason) => format!("execution reverted: {}", reason),
+            None => String::from("execution reverted"),
+        };
+
+        Self {
+            logs: Vec::default(),
+            data,
+            gas_used,
+            vm_error,
+            revert_reason,
+            exit_reason: None,
+            contract_address: None,
+            gas_refunded: 0,
+            intrinsic_gas: 0,
+            execution_gas: 0,
+        }
+    }
+
+    /// Removes decoded revert reason from the result. Should be used for encrypted transactions,
+    /// since revert reason can be obtained only by decryption of returned data
+    pub fn hide_revert_reason(&mut self) {
+        if self.revert_reason.take().is_some() {
+            self.vm_error = String::from("execution reverted");
         }
     }
 }
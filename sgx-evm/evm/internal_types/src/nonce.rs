@@ -0,0 +1,74 @@
+use core::fmt;
+use primitive_types::{H160, U256};
+
+/// Error returned if nonce of transaction does not match nonce of sender account
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum NonceError {
+    /// Transaction nonce is lower than nonce of sender account, i.e. transaction was already executed
+    TooLow { address: H160, tx_nonce: U256, state_nonce: U256 },
+    /// Transaction nonce is higher than nonce of sender account
+    TooHigh { address: H160, tx_nonce: U256, state_nonce: U256 },
+    /// Nonce of sender account cannot be incremented anymore
+    Max { address: H160, state_nonce: U256 },
+}
+
+impl NonceError {
+    /// Checks that transaction nonce is equal to nonce of sender account
+    pub fn validate(address: H160, tx_nonce: U256, state_nonce: U256) -> Result<(), Self> {
+        if state_nonce >= U256::from(u64::MAX) {
+            return Err(NonceError::Max { address, state_nonce });
+        }
+
+        if tx_nonce < state_nonce {
+            return Err(NonceError::TooLow { address, tx_nonce, state_nonce });
+        }
+
+        if tx_nonce > state_nonce {
+            return Err(NonceError::TooHigh { address, tx_nonce, state_nonce });
+        }
+
+        Ok(())
+    }
+}
+
+impl fmt::Display for NonceError {
+    // Messages are compatible with errors returned by go-ethereum
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            NonceError::TooLow { address, tx_nonce, state_nonce } => {
+                write!(f, "nonce too low: address {:?}, tx: {} state: {}", address, tx_nonce, state_nonce)
+            }
+            NonceError::TooHigh { address, tx_nonce, state_nonce } => {
+                write!(f, "nonce too high: address {:?}, tx: {} state: {}", address, tx_nonce, state_nonce)
+            }
+            NonceError::Max { address, state_nonce } => {
+                write!(f, "nonce has max value: address {:?}, nonce: {}", address, state_nonce)
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::NonceError;
+    use primitive_types::{H160, U256};
+    use std::string::ToString;
+
+    #[test]
+    fn test_nonce_validation() {
+        let sender = H160::from_low_u64_be(0xbeef);
+
+        assert_eq!(NonceError::validate(sender, U256::from(3), U256::from(3)), Ok(()));
+
+        let too_low = NonceError::validate(sender, U256::from(2), U256::from(3)).unwrap_err();
+        assert_eq!(too_low, NonceError::TooLow { address: sender, tx_nonce: U256::from(2), state_nonce: U256::from(3) });
+        assert!(too_low.to_string().starts_with("nonce too low"));
+
+        let too_high = NonceError::validate(sender, U256::from(4), U256::from(3)).unwrap_err();
+        assert_eq!(too_high, NonceError::TooHigh { address: sender, tx_nonce: U256::from(4), state_nonce: U256::from(3) });
+        assert!(too_high.to_string().starts_with("nonce too high"));
+
+        let max = NonceError::validate(sender, U256::from(u64::MAX), U256::from(u64::MAX)).unwrap_err();
+        assert!(max.to_string().starts_with("nonce has max value"));
+    }
+}
@@ -0,0 +1,98 @@
+use core::fmt;
+use std::{string::String, vec::Vec};
+
+/// Selector of `Error(string)`
+const ERROR_SELECTOR: [u8; 4] = [0x08, 0xc3, 0x79, 0xa0];
+/// Selector of `Panic(uint256)`
+const PANIC_SELECTOR: [u8; 4] = [0x4e, 0x48, 0x7b, 0x71];
+const WORD_SIZE: usize = 32;
+
+/// Decoded data returned by reverted transaction
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum RevertReason {
+    /// `Error(string)`, raised by `revert("...")` and `require(..., "...")`
+    Error(String),
+    /// `Panic(uint256)`, raised by failed assertions, arithmetic overflows, etc.
+    Panic(u64),
+    /// Custom error with ABI-encoded arguments
+    Custom { selector: [u8; 4], args: Vec<u8> },
+}
+
+impl RevertReason {
+    /// Decodes data returned by reverted transaction. Returns `None` if data does not contain
+    /// error selector. If data of `Error(string)` or `Panic(uint256)` is malformed, it is
+    /// returned as custom error
+    pub fn decode(data: &[u8]) -> Option<Self> {
+        if data.len() < 4 {
+            return None;
+        }
+
+        let mut selector = [0u8; 4];
+        selector.copy_from_slice(&data[..4]);
+        let args = &data[4..];
+
+        let decoded = match selector {
+            ERROR_SELECTOR => decode_string(args).map(RevertReason::Error),
+            PANIC_SELECTOR => decode_u64(args).map(RevertReason::Panic),
+            _ => None,
+        };
+
+        Some(decoded.unwrap_or_else(|| RevertReason::Custom { selector, args: args.to_vec() }))
+    }
+
+    /// Returns human-readable meaning of panic code emitted by Solidity compiler
+    pub fn panic_description(code: u64) -> &'static str {
+        match code {
+            0x00 => "generic compiler panic",
+            0x01 => "assertion failed",
+            0x11 => "arithmetic underflow or overflow",
+            0x12 => "division or modulo by zero",
+            0x21 => "invalid enum value",
+            0x22 => "invalid storage byte array encoding",
+            0x31 => "pop on empty array",
+            0x32 => "array index out of bounds",
+            0x41 => "out of memory",
+            0x51 => "call to uninitialized internal function",
+            _ => "unknown panic code",
+        }
+    }
+}
+
+impl fmt::Display for RevertReason {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            RevertReason::Error(message) => write!(f, "{}", message),
+            RevertReason::Panic(code) => {
+                write!(f, "panic: {} (0x{:02x})", RevertReason::panic_description(*code), code)
+            }
+            RevertReason::Custom { selector, .. } => {
+                write!(f, "custom error 0x")?;
+                for byte in selector {
+                    write!(f, "{:02x}", byte)?;
+                }
+                Ok(())
+            }
+        }
+    }
+}
+
+/// Reads ABI-encoded word as u64. Returns `None` if value does not fit
+fn decode_u64(data: &[u8]) -> Option<u64> {
+    let word = data.get(..WORD_SIZE)?;
+    if word[..WORD_SIZE - 8].iter().any(|byte| *byte != 0) {
+        return None;
+    }
+
+    let mut value = [0u8; 8];
+    value.copy_from_slice(&word[WORD_SIZE - 8..]);
+    Some(u64::from_be_bytes(value))
+}
+
+/// Reads ABI-encoded dynamic string
+fn decode_string(data: &[u8]) -> Option<String> {
+    let offset = decode_u64(data)? as usize;
+    let length_end = offset.checked_add(WORD_SIZE)?;
+    let length = decode_u64(data.get(offset..length_end)?)? as usize;
+    let value = data.get(length_end..length_end.checked_add(length)?)?;
+    String::from_utf8(value.to_vec()).ok()
+}
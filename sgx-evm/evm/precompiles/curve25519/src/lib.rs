@@ -9,7 +9,9 @@ use curve25519_dalek::{
 };
 use precompile_std::{ExitError, ExitSucceed, LinearCostPrecompile, PrecompileFailure};
 
-// Adds at most 10 curve25519 points and returns the CompressedRistretto bytes representation
+/// Adds at most 10 curve25519 points and returns the CompressedRistretto bytes representation.
+/// Invalid points are treated as identity. Registered at 0x403 if `curve25519` feature of `sgxvm`
+/// is enabled. Costs 60 gas plus 12 gas per 32-byte word of input
 pub struct Curve25519Add;
 
 impl LinearCostPrecompile for Curve25519Add {
@@ -52,7 +54,9 @@ impl LinearCostPrecompile for Curve25519Add {
     }
 }
 
-// Multiplies a scalar field element with an elliptic curve point
+/// Multiplies a scalar field element with an elliptic curve point. Invalid point is treated as identity.
+/// Registered at 0x404 if `curve25519` feature of `sgxvm` is enabled. Costs 60 gas plus 12 gas
+/// per 32-byte word of input
 pub struct Curve25519ScalarMul;
 
 impl LinearCostPrecompile for Curve25519ScalarMul {
@@ -246,4 +250,31 @@ mod tests {
             }
         }
     }
+
+    #[test]
+    fn test_invalid_point_is_identity() -> Result<(), PrecompileFailure> {
+        // Not a canonical encoding of Ristretto point
+        let invalid_point = [0xffu8; 32];
+        let mut input = vec![];
+        input.extend_from_slice(&constants::RISTRETTO_BASEPOINT_POINT.compress().to_bytes());
+        input.extend_from_slice(&invalid_point);
+
+        let cost: u64 = 1;
+
+        match Curve25519Add::execute(&input, cost) {
+            Ok((_, out)) => {
+                assert_eq!(out, constants::RISTRETTO_BASEPOINT_POINT.compress().to_bytes());
+                Ok(())
+            }
+            Err(e) => {
+                panic!("Test not expected to fail: {:?}", e);
+            }
+        }
+    }
+
+    #[test]
+    fn test_gas_cost() {
+        assert_eq!((Curve25519Add::BASE, Curve25519Add::WORD), (60, 12));
+        assert_eq!((Curve25519ScalarMul::BASE, Curve25519ScalarMul::WORD), (60, 12));
+    }
 }
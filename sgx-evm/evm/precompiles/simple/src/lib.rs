@@ -5,7 +5,7 @@ extern crate alloc;
 use alloc::vec::Vec;
 use core::cmp::min;
 
-use precompile_std::{ExitSucceed, LinearCostPrecompile, PrecompileFailure};
+use precompile_std::{ExitError, ExitSucceed, LinearCostPrecompile, PrecompileFailure};
 use k256::sha2::{Sha256 as kSha256, Digest};
 use sha3::{Keccak256};
 use k256::{
@@ -112,33 +112,51 @@ impl LinearCostPrecompile for Sha256 {
 
 /// The ECRecoverPublicKey precompile.
 /// Similar to ECRecover, but returns the pubkey (not the corresponding Ethereum address)
-// pub struct ECRecoverPublicKey;
-//
-// impl LinearCostPrecompile for ECRecoverPublicKey {
-//     const BASE: u64 = 3000;
-//     const WORD: u64 = 0;
-//
-//     fn execute(i: &[u8], _: u64) -> Result<(ExitSucceed, Vec<u8>), PrecompileFailure> {
-//         let mut input = [0u8; 128];
-//         input[..min(i.len(), 128)].copy_from_slice(&i[..min(i.len(), 128)]);
-//
-//         let mut msg = [0u8; 32];
-//         let mut sig = [0u8; 65];
-//
-//         msg[0..32].copy_from_slice(&input[0..32]);
-//         sig[0..32].copy_from_slice(&input[64..96]);
-//         sig[32..64].copy_from_slice(&input[96..128]);
-//         sig[64] = input[63];
-//
-//         let pubkey = sp_io::crypto::secp256k1_ecdsa_recover(&sig, &msg).map_err(|_| {
-//             PrecompileFailure::Error {
-//                 exit_status: ExitError::Other("Public key recover failed".into()),
-//             }
-//         })?;
-//
-//         Ok((ExitSucceed::Returned, pubkey.to_vec()))
-//     }
-// }
+pub struct ECRecoverPublicKey;
+
+impl LinearCostPrecompile for ECRecoverPublicKey {
+    const BASE: u64 = 3000;
+    const WORD: u64 = 0;
+
+    fn execute(i: &[u8], _: u64) -> Result<(ExitSucceed, Vec<u8>), PrecompileFailure> {
+        let mut input = [0u8; 128];
+        input[..min(i.len(), 128)].copy_from_slice(&i[..min(i.len(), 128)]);
+
+        let mut msg = [0u8; 32];
+        let mut sig = [0u8; 65];
+
+        msg.copy_from_slice(&input[0..32]);
+        sig[0..64].copy_from_slice(&input[64..]);
+
+        // EIP-155
+        sig[64] = if input[63] > 26 {
+            input[63] - 27
+        } else {
+            input[63]
+        };
+
+        let recover_failed = || PrecompileFailure::Error {
+            exit_status: ExitError::Other("Public key recover failed".into()),
+        };
+
+        if input[32..63] != [0u8; 31] {
+            return Err(recover_failed());
+        }
+
+        let signature = recoverable::Signature::try_from(&sig[..]).map_err(|_| recover_failed())?;
+        if signature.s().is_high().into() {
+            return Err(recover_failed());
+        }
+
+        let recovered_key = signature
+            .recover_verifying_key_from_digest_bytes(&msg.into())
+            .map_err(|_| recover_failed())?;
+
+        // Uncompressed public key without 0x04 prefix
+        let pubkey = recovered_key.to_encoded_point(false);
+        Ok((ExitSucceed::Returned, pubkey.as_bytes()[1..].to_vec()))
+    }
+}
 
 #[cfg(test)]
 mod tests {
@@ -163,4 +181,22 @@ mod tests {
         test_precompile_test_vectors::<Ripemd160>("../testdata/common_ripemd.json")?;
         Ok(())
     }
+
+    #[test]
+    fn test_ecrecover_public_key() {
+        let input = hex::decode("38d18acb67d25c8bb9942764b62f18e17054f66a817bd4295423adf9ed98873e000000000000000000000000000000000000000000000000000000000000001b38d18acb67d25c8bb9942764b62f18e17054f66a817bd4295423adf9ed98873e789d1dd423d25f0772d2748d60f7e4b81bb14d086eba8e8e8efb6dcff8a4ae02").unwrap();
+        let expected = hex::decode("861254a4cf8dfd2d60e2a33e3143eac6285886f0aed91711ab7e2c013f265c55fd456667f3b08657795fe6754b6fbc11186714c4e4f48d5b856800e3e81c30c8").unwrap();
+
+        let (_, pubkey) = ECRecoverPublicKey::execute(&input, 0).unwrap();
+        assert_eq!(pubkey, expected);
+
+        // Public key corresponds to address returned by ECRecover
+        let (_, address) = ECRecover::execute(&input, 0).unwrap();
+        assert_eq!(&address[12..], &Keccak256::digest(&pubkey)[12..]);
+
+        // Invalid recovery id
+        let mut invalid = input.clone();
+        invalid[63] = 0x1f;
+        assert!(ECRecoverPublicKey::execute(&invalid, 0).is_err());
+    }
 }
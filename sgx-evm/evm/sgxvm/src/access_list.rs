@@ -0,0 +1,174 @@
+use evm::backend::{
+    Apply,
+    Backend as EvmBackend,
+    Basic,
+    Log
+};
+use primitive_types::{H160, H256, U256};
+use sha3::{Digest, Keccak256};
+
+use std::{
+    cell::RefCell,
+    collections::{BTreeMap, BTreeSet},
+    string::String,
+    vec::Vec,
+};
+
+use crate::backend::{EvmFork, ExtendedBackend};
+use crate::precompiles::PrecompileRegistry;
+use crate::storage::BatchReadRequest;
+
+/// Backend wrapper, which records every account and storage slot read during execution.
+/// Addresses from `excluded` are warm by default and are not recorded
+pub struct AccessListTracer<'a, B: ExtendedBackend> {
+    inner: &'a mut B,
+    excluded: BTreeSet<H160>,
+    touched: RefCell<BTreeMap<H160, BTreeSet<H256>>>,
+}
+
+impl<'a, B: ExtendedBackend> AccessListTracer<'a, B> {
+    /// Creates tracer, which already contains entries of provided access list
+    pub fn new(inner: &'a mut B, excluded: BTreeSet<H160>, access_list: &[(H160, Vec<H256>)]) -> Self {
+        let tracer = Self {
+            inner,
+            excluded,
+            touched: RefCell::new(BTreeMap::new()),
+        };
+
+        for (address, slots) in access_list {
+            tracer.touch_account(*address);
+            for slot in slots {
+                tracer.touch_slot(*address, *slot);
+            }
+        }
+
+        tracer
+    }
+
+    /// Returns collected access list, sorted by address and slot
+    pub fn access_list(&self) -> Vec<(H160, Vec<H256>)> {
+        self.touched
+            .borrow()
+            .iter()
+            .map(|(address, slots)| (*address, slots.iter().copied().collect()))
+            .collect()
+    }
+
+    fn touch_account(&self, address: H160) {
+        if self.excluded.contains(&address) {
+            return;
+        }
+        self.touched.borrow_mut().entry(address).or_default();
+    }
+
+    fn touch_slot(&self, address: H160, index: H256) {
+        // Storage slots of excluded accounts are still recorded, since only accounts are warm
+        self.touched.borrow_mut().entry(address).or_default().insert(index);
+    }
+}
+
+impl<'a, B: ExtendedBackend> ExtendedBackend for AccessListTracer<'a, B> {
+    fn get_logs(&self) -> Vec<Log> {
+        self.inner.get_logs()
+    }
+
+    fn get_evm_fork(&self) -> EvmFork {
+        self.inner.get_evm_fork()
+    }
+
+    fn prefetch(&self, request: &BatchReadRequest) {
+        self.inner.prefetch(request)
+    }
+
+    fn precompiles(&self) -> &PrecompileRegistry {
+        self.inner.precompiles()
+    }
+
+    fn apply_changes<A, I, L>(&mut self, values: A, logs: L, delete_empty: bool) -> Result<(), String>
+    where
+        A: IntoIterator<Item = Apply<I>>,
+        I: IntoIterator<Item = (H256, H256)>,
+        L: IntoIterator<Item = Log>,
+    {
+        self.inner.apply_changes(values, logs, delete_empty)
+    }
+}
+
+impl<'a, B: ExtendedBackend> EvmBackend for AccessListTracer<'a, B> {
+    fn gas_price(&self) -> U256 {
+        self.inner.gas_price()
+    }
+
+    fn origin(&self) -> H160 {
+        self.inner.origin()
+    }
+
+    fn block_hash(&self, number: U256) -> H256 {
+        self.inner.block_hash(number)
+    }
+
+    fn block_number(&self) -> U256 {
+        self.inner.block_number()
+    }
+
+    fn block_coinbase(&self) -> H160 {
+        self.inner.block_coinbase()
+    }
+
+    fn block_timestamp(&self) -> U256 {
+        self.inner.block_timestamp()
+    }
+
+    fn block_difficulty(&self) -> U256 {
+        self.inner.block_difficulty()
+    }
+
+    fn block_gas_limit(&self) -> U256 {
+        self.inner.block_gas_limit()
+    }
+
+    fn block_base_fee_per_gas(&self) -> U256 {
+        self.inner.block_base_fee_per_gas()
+    }
+
+    fn chain_id(&self) -> U256 {
+        self.inner.chain_id()
+    }
+
+    fn original_storage(&self, address: H160, index: H256) -> Option<H256> {
+        self.touch_slot(address, index);
+        self.inner.original_storage(address, index)
+    }
+
+    fn block_randomness(&self) -> Option<H256> {
+        self.inner.block_randomness()
+    }
+
+    fn basic(&self, address: H160) -> Basic {
+        self.touch_account(address);
+        self.inner.basic(address)
+    }
+
+    fn code(&self, address: H160) -> Vec<u8> {
+        self.touch_account(address);
+        self.inner.code(address)
+    }
+
+    fn storage(&self, address: H160, index: H256) -> H256 {
+        self.touch_slot(address, index);
+        self.inner.storage(address, index)
+    }
+
+    fn exists(&self, address: H160) -> bool {
+        self.touch_account(address);
+        self.inner.exists(address)
+    }
+}
+
+/// Returns address of contract, created by `sender` with provided nonce using CREATE
+pub fn create_address(sender: H160, nonce: U256) -> H160 {
+    let mut stream = rlp::RlpStream::new_list(2);
+    stream.append(&sender);
+    stream.append(&nonce);
+    H256::from_slice(Keccak256::digest(&stream.out()).as_slice()).into()
+}
@@ -1,6 +1,5 @@
 use evm::backend::{
     Apply,
-    ApplyBackend as EvmApplyBackend,
     Backend as EvmBackend,
     Basic,
     Log
@@ -8,22 +7,84 @@ use evm::backend::{
 use evm::Config;
 use primitive_types::{H160, H256, U256};
 
-use std::vec::Vec;
+use std::{cell::RefCell, collections::BTreeMap, string::String, vec::Vec};
 
-use crate::storage::Storage;
+use crate::precompiles::PrecompileRegistry;
+use crate::storage::{BatchReadRequest, Storage};
+use crate::validate_supply_invariant;
 
-pub static GASOMETER_CONFIG: Config = Config::london();
+pub static LONDON_CONFIG: Config = Config::london();
+pub static SHANGHAI_CONFIG: Config = Config::shanghai();
+
+/// EVM hard fork, which rules should be used during transaction execution.
+/// Cancun is not supported yet, since evm 0.39 does not implement transient
+/// storage (EIP-1153) and MCOPY (EIP-5656)
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum EvmFork {
+    London,
+    Shanghai,
+    Unknown(u32),
+}
+
+impl Default for EvmFork {
+    fn default() -> Self {
+        EvmFork::London
+    }
+}
+
+impl From<u32> for EvmFork {
+    fn from(value: u32) -> Self {
+        match value {
+            0 => EvmFork::London,
+            1 => EvmFork::Shanghai,
+            other => EvmFork::Unknown(other),
+        }
+    }
+}
+
+impl EvmFork {
+    /// Hard forks, which can be used to execute transactions
+    pub const SUPPORTED: [EvmFork; 2] = [EvmFork::London, EvmFork::Shanghai];
+
+    /// Returns gasometer and opcode configuration for selected hard fork
+    pub fn config(&self) -> Result<&'static Config, String> {
+        match self {
+            EvmFork::London => Ok(&LONDON_CONFIG),
+            EvmFork::Shanghai => Ok(&SHANGHAI_CONFIG),
+            EvmFork::Unknown(id) => Err(format!("unknown evm fork: {}", id)),
+        }
+    }
+}
 
 /// Information required by the evm
 #[derive(Clone, Default, PartialEq, Eq)]
 pub struct Vicinity {
     pub origin: H160,
-    pub nonce: U256,
 }
 
-/// Supertrait for our version of EVM Backend
-pub trait ExtendedBackend: EvmBackend + EvmApplyBackend {
+/// Supertrait for our version of EVM Backend. `ApplyBackend` of evm crate is not implemented,
+/// since it cannot report errors, so all changes are written through `apply_changes`
+pub trait ExtendedBackend: EvmBackend {
     fn get_logs(&self) -> Vec<Log>;
+
+    /// Returns hard fork which should be used to execute transaction
+    fn get_evm_fork(&self) -> EvmFork;
+
+    /// Reads provided accounts, codes and storage cells in advance, so later reads
+    /// during execution are served from cache
+    fn prefetch(&self, request: &BatchReadRequest);
+
+    /// Returns precompiles available during execution of transaction. Registry is built
+    /// once together with backend, so it is not rebuilt for every call
+    fn precompiles(&self) -> &PrecompileRegistry;
+
+    /// Applies changes produced by executor. Changes are validated before any write,
+    /// therefore nothing is written if they violate supply invariant
+    fn apply_changes<A, I, L>(&mut self, values: A, logs: L, delete_empty: bool) -> Result<(), String>
+    where
+        A: IntoIterator<Item = Apply<I>>,
+        I: IntoIterator<Item = (H256, H256)>,
+        L: IntoIterator<Item = Log>;
 }
 
 /// Backend for EVM that allows access to storage
@@ -34,12 +95,100 @@ pub struct Backend<'state> {
 	pub state: &'state mut dyn Storage,
     // Emitted events
     pub logs: Vec<Log>,
+    // Hard fork used for execution
+    pub fork: EvmFork,
+    // Precompiles available during execution
+    pub precompiles: PrecompileRegistry,
+    // Values of storage slots at the beginning of transaction
+    original_storage_journal: RefCell<BTreeMap<(H160, H256), H256>>,
 }
 
 impl<'state> ExtendedBackend for Backend<'state> {
     fn get_logs(&self) -> Vec<Log> {
         self.logs.clone()
     }
+
+    fn get_evm_fork(&self) -> EvmFork {
+        self.fork
+    }
+
+    fn precompiles(&self) -> &PrecompileRegistry {
+        &self.precompiles
+    }
+
+    fn prefetch(&self, request: &BatchReadRequest) {
+        self.state.get_batch(request);
+    }
+
+	fn apply_changes<A, I, L>(&mut self, values: A, logs: L, delete_empty: bool) -> Result<(), String>
+	where
+		A: IntoIterator<Item = Apply<I>>,
+		I: IntoIterator<Item = (H256, H256)>,
+		L: IntoIterator<Item = Log>,
+	{
+        // Used to avoid corrupting state via invariant violation
+        let values: Vec<Apply<I>> = values.into_iter().collect();
+        validate_supply_invariant(&*self, &values)?;
+
+		for apply in values {
+			match apply {
+				Apply::Modify {
+					address,
+					basic,
+					code,
+					storage,
+					reset_storage,
+				} => {
+                    // Storage of account should be cleared, if account was recreated
+                    // (e.g. by CREATE2 after SELFDESTRUCT)
+                    if reset_storage {
+                        self.state.remove_storage(&address);
+                    }
+
+                    // Update account balance and nonce
+                    let is_empty_account = basic.balance.is_zero() && basic.nonce.is_zero();
+                    self.state.insert_account(address, basic);
+
+                    // Handle contract updates
+                    if let Some(code) = code {
+                        self.state.insert_account_code(address, code);
+                    }
+
+                    // Handle storage updates
+                    for (index, value) in storage {
+                        if value == H256::default() {
+                            self.state.remove_storage_cell(&address, &index);
+                        } else {
+                            self.state.insert_storage_cell(address, index, value);
+                        }
+                    }
+
+                    // Remove touched accounts, which became empty (EIP-161)
+                    if delete_empty && is_empty_account {
+                        let has_code = self.state
+                            .get_account_code(&address)
+                            .map_or(false, |code| !code.is_empty());
+                        if !has_code {
+                            self.state.remove(&address);
+                        }
+                    }
+				},
+                // Used by SELFDESTRUCT opcode
+				Apply::Delete { address } => {
+					self.state.remove(&address);
+				}
+			}
+		}
+
+		for log in logs {
+			self.logs.push(log);
+		}
+
+        // Applied changes finish transaction, therefore original values are no longer valid
+        self.original_storage_journal.get_mut().clear();
+
+        Ok(())
+	}
 }
 
 /// Implementation of trait `Backend` provided by evm crate
@@ -85,8 +234,8 @@ impl<'state> EvmBackend for Backend<'state> {
         U256::one()
     }
 
-    fn original_storage(&self, _address: H160, _index: H256) -> Option<H256> {
-        None
+    fn original_storage(&self, address: H160, index: H256) -> Option<H256> {
+        Some(self.journaled_storage(address, index))
     }
 
     fn block_randomness(&self) -> Option<H256> {
@@ -108,9 +257,7 @@ impl<'state> EvmBackend for Backend<'state> {
         address: H160,
         index: H256,
     ) -> H256 {
-        self.state
-            .get_account_storage_cell(&address, &index)
-            .unwrap_or_default()
+        self.journaled_storage(address, index)
     }
 
 	fn exists(&self, address: H160) -> bool {
@@ -118,73 +265,29 @@ impl<'state> EvmBackend for Backend<'state> {
     }
 }
 
-/// Implementation of trait `Apply` provided by evm crate
-/// This trait declares write operations for the backend
-impl<'state> EvmApplyBackend for Backend<'state> {
-	fn apply<A, I, L>(&mut self, values: A, logs: L, _delete_empty: bool)
-	where
-		A: IntoIterator<Item = Apply<I>>,
-		I: IntoIterator<Item = (H256, H256)>,
-		L: IntoIterator<Item = Log>,
-	{
-        let mut total_supply_add = U256::zero();
-        let mut total_supply_sub = U256::zero();
-
-		for apply in values {
-			match apply {
-				Apply::Modify {
-					address,
-					basic,
-					code,
-					storage,
-                    ..
-				} => {
-                    // Reset storage is ignored since storage cannot be efficiently reset as this
-                    // would require iterating over all of the storage keys
-
-                    // Update account balance and nonce
-                    let previous_account_data = self.state.get_account(&address);
-
-                    if basic.balance > previous_account_data.balance {
-                        total_supply_add =
-                            total_supply_add.checked_add(basic.balance - previous_account_data.balance).unwrap();
-                    } else {
-                        total_supply_sub =
-                            total_supply_sub.checked_add(previous_account_data.balance - basic.balance).unwrap();
-                    }
-                    self.state.insert_account(address, basic);
-
-                    // Handle contract updates
-                    if let Some(code) = code {
-                        self.state.insert_account_code(address, code);
-                    }
-
-                    // Handle storage updates
-                    for (index, value) in storage {
-                        if value == H256::default() {
-                            self.state.remove_storage_cell(&address, &index);
-                        } else {
-                            self.state.insert_storage_cell(address, index, value);
-                        }
-                    }
-				},
-                // Used by SELFDESTRUCT opcode
-				Apply::Delete { address } => {
-					self.state.remove(&address);
-				}
-			}
-		}
+impl<'state> Backend<'state> {
+    pub fn new(vicinity: Vicinity, state: &'state mut dyn Storage) -> Self {
+        Self {
+            vicinity,
+            state,
+            logs: vec![],
+            fork: EvmFork::default(),
+            precompiles: PrecompileRegistry::for_fork(EvmFork::default()),
+            original_storage_journal: RefCell::new(BTreeMap::new()),
+        }
+    }
 
-        // Used to avoid corrupting state via invariant violation
-        assert!(
-            total_supply_add == total_supply_sub,
-            "evm execution would lead to invariant violation ({} != {})",
-            total_supply_add,
-            total_supply_sub
-        );
+    /// Returns value of storage slot at the beginning of transaction. Since state is not changed
+    /// until transaction is applied, each slot is read from storage only once and then kept in journal
+    fn journaled_storage(&self, address: H160, index: H256) -> H256 {
+        if let Some(value) = self.original_storage_journal.borrow().get(&(address, index)) {
+            return *value;
+        }
 
-		for log in logs {
-			self.logs.push(log);
-		}
-	}
+        let value = self.state
+            .get_account_storage_cell(&address, &index)
+            .unwrap_or_default();
+        self.original_storage_journal.borrow_mut().insert((address, index), value);
+        value
+    }
 }
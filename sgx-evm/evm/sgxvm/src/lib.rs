@@ -4,24 +4,34 @@
 extern crate sgx_tstd as std;
 
 use backend::ExtendedBackend;
-use internal_types::ExecutionResult;
+use internal_types::{ExecutionResult, NonceError};
 pub use ethereum;
 pub use evm;
-use evm::executor::stack::{MemoryStackState, StackExecutor, StackSubstateMetadata};
-use evm::ExitReason;
+use evm::executor::stack::{MemoryStackState, StackExecutor, StackState, StackSubstateMetadata};
+use evm_gasometer::Gasometer;
+use evm::backend::{Apply, Backend as EvmBackend};
+use evm::{Config, CreateScheme, ExitFatal, ExitReason};
 pub use primitive_types;
 use primitive_types::{H160, H256, U256};
 
-use std::{string::String, string::ToString, vec::Vec};
+use std::{collections::BTreeSet, string::{String, ToString}, vec::Vec};
 
-use crate::backend::{Backend, GASOMETER_CONFIG};
-pub use crate::backend::Vicinity;
+use crate::access_list::{create_address, AccessListTracer};
+pub use crate::backend::{EvmFork, Vicinity};
 use crate::precompiles::EVMPrecompiles;
+use crate::storage::BatchReadRequest;
+pub use crate::state_diff::StateDiff;
 
+pub mod access_list;
 pub mod backend;
+pub mod receipt;
+pub mod signed_tx;
+pub mod state_diff;
 pub mod storage;
+#[cfg(feature = "tracing")]
+pub mod tracing;
 
-mod precompiles;
+pub mod precompiles;
 
 /// Handles incoming request for calling some contract / funds transfer
 pub fn handle_sgxvm_call(
@@ -34,79 +44,316 @@ pub fn handle_sgxvm_call(
     access_list: Vec<(H160, Vec<H256>)>,
     commit: bool,
 ) -> ExecutionResult {
-    let metadata = StackSubstateMetadata::new(gas_limit, &GASOMETER_CONFIG);
-    let state = MemoryStackState::new(metadata, backend);
-    let precompiles = EVMPrecompiles::<Backend>::new();
+    let (result, _) = execute_transaction(
+        backend, gas_limit, from, Some(to), value, data, access_list, commit, false,
+    );
+    result
+}
 
-    let mut executor = StackExecutor::new_with_precompiles(state, &GASOMETER_CONFIG, &precompiles);
-    let (exit_reason, ret) = executor.transact_call(from, to, value, data, gas_limit, access_list);
+/// Handles incoming request for creation of a new contract
+pub fn handle_sgxvm_create(
+    backend: &mut impl ExtendedBackend,
+    gas_limit: u64,
+    from: H160,
+    value: U256,
+    data: Vec<u8>,
+    access_list: Vec<(H160, Vec<H256>)>,
+    commit: bool,
+) -> ExecutionResult {
+    let (result, _) = execute_transaction(
+        backend, gas_limit, from, None, value, data, access_list, commit, false,
+    );
+    result
+}
 
-    let gas_used = executor.used_gas();
-    let exit_value = match handle_evm_result(exit_reason, ret) {
-        Ok(data) => data,
-        Err((err, data)) => {
-            return ExecutionResult::from_error(err, data, Some(gas_used))
-        }
+/// Handles incoming request for execution of transaction in a simulation mode.
+/// Returns result of execution and changes, which transaction would apply to the state.
+/// State diff is not returned for failed transactions.
+/// If `to` is not provided, simulates contract creation
+pub fn handle_sgxvm_state_diff(
+    backend: &mut impl ExtendedBackend,
+    gas_limit: u64,
+    from: H160,
+    to: Option<H160>,
+    value: U256,
+    data: Vec<u8>,
+    access_list: Vec<(H160, Vec<H256>)>,
+) -> (ExecutionResult, Option<StateDiff>) {
+    execute_transaction(backend, gas_limit, from, to, value, data, access_list, false, true)
+}
+
+/// Handles incoming request for estimation of gas required by transaction.
+/// Performs binary search over gas limit in range up to `gas_cap` and returns minimal gas limit,
+/// with which transaction succeeds, and result of execution with the highest failed gas limit.
+/// If transaction fails even with `gas_cap`, returns zero gas and result of that execution.
+/// If `to` is not provided, estimates gas required for contract creation
+pub fn handle_sgxvm_estimate_gas(
+    backend: &mut impl ExtendedBackend,
+    gas_cap: u64,
+    from: H160,
+    to: Option<H160>,
+    value: U256,
+    data: Vec<u8>,
+    access_list: Vec<(H160, Vec<H256>)>,
+) -> (u64, Option<ExecutionResult>) {
+    let mut execute = |gas_limit: u64| {
+        let (result, _) = execute_transaction(
+            backend, gas_limit, from, to, value, data.clone(), access_list.clone(), false, false,
+        );
+        result
     };
 
-    if commit {
-        let (vals, logs) = executor.into_state().deconstruct();
-        backend.apply(vals, logs, false);
+    let result = execute(gas_cap);
+    if !result.vm_error.is_empty() {
+        return (0, Some(result));
+    }
+
+    // Transaction cannot succeed with gas limit lower than amount of gas it used
+    let mut lo = result.gas_used.saturating_sub(1);
+    let mut hi = gas_cap;
+    let mut failed_result = None;
+
+    while lo + 1 < hi {
+        let mid = lo + (hi - lo) / 2;
+        let result = execute(mid);
+        if result.vm_error.is_empty() {
+            hi = mid;
+        } else {
+            lo = mid;
+            failed_result = Some(result);
+        }
     }
 
-    ExecutionResult {
-        logs: backend.get_logs(),
-        data: exit_value,
-        gas_used,
-        vm_error: "".to_string(),
+    (hi, failed_result)
+}
+
+/// Handles incoming request for generation of EIP-2930 access list for transaction.
+/// Transaction is executed in a simulation mode until the set of touched accounts and storage
+/// slots stops changing. Returns generated access list and result of execution with that list
+/// applied. Sender, recipient and precompiles are not included, since they are warm by default.
+/// If `to` is not provided, access list is generated for contract creation
+pub fn handle_sgxvm_create_access_list(
+    backend: &mut impl ExtendedBackend,
+    gas_limit: u64,
+    from: H160,
+    to: Option<H160>,
+    value: U256,
+    data: Vec<u8>,
+    access_list: Vec<(H160, Vec<H256>)>,
+) -> (ExecutionResult, Vec<(H160, Vec<H256>)>) {
+    let recipient = match to {
+        Some(to) => to,
+        None => create_address(from, backend.basic(from).nonce),
+    };
+    let mut excluded: BTreeSet<H160> = backend
+        .precompiles()
+        .active_addresses(backend.block_number())
+        .into_iter()
+        .collect();
+    excluded.insert(from);
+    excluded.insert(recipient);
+
+    let mut access_list = access_list;
+    loop {
+        let mut tracer = AccessListTracer::new(backend, excluded.clone(), &access_list);
+        let (result, _) = execute_transaction(
+            &mut tracer, gas_limit, from, to, value, data.clone(), access_list.clone(), false, false,
+        );
+
+        // Accounts and slots are only added to the list, so it stabilizes after several runs
+        let touched = tracer.access_list();
+        if touched == access_list {
+            return (result, access_list);
+        }
+        access_list = touched;
     }
 }
 
-/// Handles incoming request for creation of a new contract
-pub fn handle_sgxvm_create(
+/// Executes contract call or, if `to` is not provided, contract creation.
+/// If `commit` is set, applies state changes to the backend. Otherwise, if `collect_state_diff`
+/// is set, returns changes, which successful transaction would apply to the state
+fn execute_transaction(
     backend: &mut impl ExtendedBackend,
     gas_limit: u64,
     from: H160,
+    to: Option<H160>,
     value: U256,
     data: Vec<u8>,
     access_list: Vec<(H160, Vec<H256>)>,
     commit: bool,
-) -> ExecutionResult {
-    let metadata = StackSubstateMetadata::new(gas_limit, &GASOMETER_CONFIG);
+    collect_state_diff: bool,
+) -> (ExecutionResult, Option<StateDiff>) {
+    let config = match backend.get_evm_fork().config() {
+        Ok(config) => config,
+        Err(err) => return (ExecutionResult::from_error(err, Vec::default(), None), None),
+    };
+
+    // Accounts and slots, which will be definitely accessed, are fetched within a single request
+    backend.prefetch(&prefetch_request(from, to, &access_list));
+
+    let metadata = StackSubstateMetadata::new(gas_limit, config);
     let state = MemoryStackState::new(metadata, backend);
-    let precompiles = EVMPrecompiles::<Backend>::new();
+    let precompiles = EVMPrecompiles::new(backend.precompiles(), backend.block_number());
 
-    let mut executor = StackExecutor::new_with_precompiles(state, &GASOMETER_CONFIG, &precompiles);
-    let (exit_reason, ret) = executor.transact_create(from, value, data, gas_limit, access_list);
+    let intrinsic_gas = intrinsic_gas(config, &data, &access_list, to.is_none());
 
-    let gas_used = executor.used_gas();
-    let exit_value = match handle_evm_result(exit_reason, ret) {
-        Ok(data) => data,
-        Err((err, data)) => {
-            return ExecutionResult::from_error(err, data, Some(gas_used))
-        }
+    let mut executor = StackExecutor::new_with_precompiles(state, config, &precompiles);
+    // Address of created contract depends on sender nonce, therefore it is obtained before execution
+    let contract_address = match to {
+        Some(_) => None,
+        None => Some(executor.create_address(CreateScheme::Legacy { caller: from })),
+    };
+    let (exit_reason, ret) = match to {
+        Some(to) => executor.transact_call(from, to, value, data, gas_limit, access_list),
+        None => executor.transact_create(from, value, data, gas_limit, access_list),
     };
 
-    if commit {
+    let gas_used = executor.used_gas();
+    // Used gas returned by executor already includes refund
+    let gas_refunded = executor
+        .state()
+        .metadata()
+        .gasometer()
+        .total_used_gas()
+        .saturating_sub(gas_used);
+
+    let evm_result = handle_evm_result(exit_reason.clone(), ret, gas_used);
+    let changes = if commit {
+        // Changes of failed transaction are applied as well, since they contain incremented nonce
+        // of the sender. Transaction, which cannot pay intrinsic gas, does not increment it
         let (vals, logs) = executor.into_state().deconstruct();
-        backend.apply(vals, logs, false);
+        // Since Spurious Dragon, touched empty accounts are removed (EIP-161)
+        backend
+            .apply_changes(vals, logs, !config.empty_considered_exists)
+            .map(|_| None)
+    } else if collect_state_diff && evm_result.is_ok() {
+        let (vals, _) = executor.into_state().deconstruct();
+        Ok(Some(StateDiff::from_changes(&*backend, vals)))
+    } else {
+        Ok(None)
+    };
+
+    let (mut result, state_diff) = match (evm_result, changes) {
+        (_, Err(err)) => {
+            let mut result = ExecutionResult::from_error(err.clone(), Vec::default(), Some(gas_used));
+            result.exit_reason = Some(ExitReason::Fatal(ExitFatal::Other(err.into())));
+            (result, None)
+        },
+        (Ok(exit_value), Ok(state_diff)) => {
+            let result = ExecutionResult {
+                logs: backend.get_logs(),
+                data: exit_value,
+                gas_used,
+                vm_error: "".to_string(),
+                revert_reason: None,
+                exit_reason: None,
+                contract_address,
+                gas_refunded: 0,
+                intrinsic_gas: 0,
+                execution_gas: 0,
+            };
+            (result, state_diff)
+        },
+        (Err(result), Ok(_)) => (result, None),
+    };
+
+    // Exit reason is already set, if changes of executed transaction were rejected
+    result.exit_reason.get_or_insert(exit_reason);
+    result.gas_refunded = gas_refunded;
+    result.intrinsic_gas = intrinsic_gas;
+    result.execution_gas = (gas_used + gas_refunded).saturating_sub(intrinsic_gas);
+
+    (result, state_diff)
+}
+
+/// Collects sender, recipient and entries of access list, which should be prefetched before execution
+fn prefetch_request(from: H160, to: Option<H160>, access_list: &[(H160, Vec<H256>)]) -> BatchReadRequest {
+    let mut accounts = BTreeSet::new();
+    let mut codes = BTreeSet::new();
+    let mut storage_cells = BTreeSet::new();
+
+    accounts.insert(from);
+    if let Some(to) = to {
+        accounts.insert(to);
+        codes.insert(to);
+    }
+
+    for (address, slots) in access_list {
+        accounts.insert(*address);
+        codes.insert(*address);
+        storage_cells.extend(slots.iter().map(|slot| (*address, *slot)));
+    }
+
+    BatchReadRequest {
+        accounts: accounts.into_iter().collect(),
+        codes: codes.into_iter().collect(),
+        storage_cells: storage_cells.into_iter().collect(),
+    }
+}
+
+/// Returns gas charged before execution of transaction for its data and access list.
+/// Cost is calculated by gasometer the same way as executor charges it
+fn intrinsic_gas(config: &Config, data: &[u8], access_list: &[(H160, Vec<H256>)], is_create: bool) -> u64 {
+    let cost = match is_create {
+        true => evm_gasometer::create_transaction_cost(data, access_list),
+        false => evm_gasometer::call_transaction_cost(data, access_list),
+    };
+
+    let mut gasometer = Gasometer::new(u64::MAX, config);
+    match gasometer.record_transaction(cost) {
+        Ok(()) => gasometer.total_used_gas(),
+        // Cost does not fit into u64, so transaction cannot be executed with any gas limit
+        Err(_) => u64::MAX,
+    }
+}
+
+/// Checks that nonce of committed transaction is equal to nonce of sender account.
+/// Nonce is incremented during execution of both successful and failed transactions,
+/// therefore it should not be incremented by the caller
+pub fn validate_nonce(backend: &impl EvmBackend, from: H160, nonce: u64) -> Result<(), ExecutionResult> {
+    let state_nonce = backend.basic(from).nonce;
+    NonceError::validate(from, U256::from(nonce), state_nonce)
+        .map_err(|err| ExecutionResult::from_error(err.to_string(), Vec::default(), None))
+}
+
+/// Checks that changes produced by executor do not mint or burn native tokens,
+/// i.e. total balance increase is equal to total balance decrease
+pub fn validate_supply_invariant<B: EvmBackend, I>(backend: &B, values: &[Apply<I>]) -> Result<(), String> {
+    let mut total_supply_add = U256::zero();
+    let mut total_supply_sub = U256::zero();
+    let overflow_err = || "evm execution would lead to invariant violation (balance overflow)".to_string();
+
+    for apply in values {
+        let (previous_balance, new_balance) = match apply {
+            Apply::Modify { address, basic, .. } => (backend.basic(*address).balance, basic.balance),
+            // Balance of deleted account is removed together with account
+            Apply::Delete { address } => (backend.basic(*address).balance, U256::zero()),
+        };
+
+        if new_balance > previous_balance {
+            total_supply_add = total_supply_add.checked_add(new_balance - previous_balance).ok_or_else(overflow_err)?;
+        } else {
+            total_supply_sub = total_supply_sub.checked_add(previous_balance - new_balance).ok_or_else(overflow_err)?;
+        }
     }
 
-    ExecutionResult {
-        logs: backend.get_logs(),
-        data: exit_value,
-        gas_used,
-        vm_error: "".to_string(),
+    match total_supply_add == total_supply_sub {
+        true => Ok(()),
+        false => Err(format!(
+            "evm execution would lead to invariant violation ({} != {})",
+            total_supply_add, total_supply_sub
+        )),
     }
 }
 
-/// Handles an EVM result to return either a successful result or a (readable) error reason.
-fn handle_evm_result(exit_reason: ExitReason, data: Vec<u8>) -> Result<Vec<u8>, (String, Vec<u8>)> {
+/// Handles an EVM result to return either a successful result or a failed execution result
+/// with (readable) error reason. Reason of reverted transaction is decoded from returned data
+fn handle_evm_result(exit_reason: ExitReason, data: Vec<u8>, gas_used: u64) -> Result<Vec<u8>, ExecutionResult> {
     match exit_reason {
         ExitReason::Succeed(_) => Ok(data),
-        ExitReason::Revert(err) => Err((format!("execution reverted: {:?}", err), data)),
-        ExitReason::Error(err) => Err((format!("evm error: {:?}", err), data)),
-        ExitReason::Fatal(err) => Err((format!("fatal evm error: {:?}", err), data)),
+        ExitReason::Revert(_) => Err(ExecutionResult::from_revert(data, gas_used)),
+        ExitReason::Error(err) => Err(ExecutionResult::from_error(format!("evm error: {:?}", err), data, Some(gas_used))),
+        ExitReason::Fatal(err) => Err(ExecutionResult::from_error(format!("fatal evm error: {:?}", err), data, Some(gas_used))),
     }
 }
 
@@ -115,9 +362,21 @@ mod tests {
     use core::ops::{Add, Sub};
     use primitive_types::{H160, U256, H256};
     use sha3::{Digest, Keccak256};
-    use crate::backend::Backend;
+    use crate::backend::{Backend, ExtendedBackend};
+    use crate::storage::buffered_storage::BufferedStorage;
+    use crate::storage::cached_storage::CachedStorage;
     use crate::storage::mocked_storage::MockedStorage;
-    use crate::{handle_sgxvm_call, handle_sgxvm_create, Vicinity};
+    use crate::storage::{BatchReadRequest, BatchReadResponse, Storage, WriteSet};
+    use crate::{
+        handle_sgxvm_call, handle_sgxvm_create, handle_sgxvm_create_access_list, handle_sgxvm_estimate_gas,
+        handle_sgxvm_state_diff, validate_nonce, validate_supply_invariant, EvmFork, Vicinity,
+    };
+    #[cfg(feature = "tracing")]
+    use crate::tracing::{trace_calls, trace_steps, StructLoggerConfig};
+    use internal_types::RevertReason;
+    use evm::{ExitError, ExitReason, ExitSucceed};
+    use evm::backend::{Apply, Backend as EvmBackend, Basic, Log};
+    use std::{string::{String, ToString}, vec::Vec};
 
     fn create_address(address: H160, nonce: u64) -> H160 {
         let mut stream = rlp::RlpStream::new_list(2);
@@ -126,21 +385,34 @@ mod tests {
         H256::from_slice(Keccak256::digest(&stream.out()).as_slice()).into()
     }
 
+    /// Returns sender funded by `MockedStorage::default` and vicinity of its transaction
+    fn setup_sender() -> (H160, Vicinity) {
+        let sender = H160::from_slice(&hex::decode("8c3FfC3600bCb365F7141EAf47b5921aEfB7917a").unwrap());
+        let vicinity = Vicinity { origin: sender };
+        (sender, vicinity)
+    }
+
+    /// Deployment data of contract, which stores value passed to its methods at slot 0 and emits logs.
+    /// Deployment data was taken from solidity tests from `chain` repo
+    const STORAGE_CONTRACT: &str = "608060405234801561001057600080fd5b50610280806100206000396000f3fe608060405234801561001057600080fd5b50600436106100365760003560e01c80632933c3c91461003b5780636057361d14610057575b600080fd5b61005560048036038101906100509190610168565b610073565b005b610071600480360381019061006c9190610168565b610123565b005b806000819055507f87199fbf46fb4529ad34a05f4a4704392dd5527b5c0e6f29591e4fccb7fd2717816040516100a991906101a4565b60405180910390a17fe409dd6b927a692d5f15854e2af1f02b98987acf9c5c4dbe265f2826e64b336b816040516100e0919061021c565b60405180910390a1807f932182c87b2d9b135ef769772728a1da9de5b81063424f9dbd99333f717f2cc382604051610118919061021c565b60405180910390a250565b8060008190555050565b600080fd5b6000819050919050565b61014581610132565b811461015057600080fd5b50565b6000813590506101628161013c565b92915050565b60006020828403121561017e5761017d61012d565b5b600061018c84828501610153565b91505092915050565b61019e81610132565b82525050565b60006020820190506101b96000830184610195565b92915050565b600082825260208201905092915050565b7f546573744d736700000000000000000000000000000000000000000000000000600082015250565b60006102066007836101bf565b9150610211826101d0565b602082019050919050565b60006040820190508181036000830152610235816101f9565b90506102446020830184610195565b9291505056fea2646970667358221220da89886bcfc76a0346e37a726a7b282db80890d5ec6d8b6f87d5222c72c6bfc464736f6c63430008110033";
+
+    /// Deploys `STORAGE_CONTRACT` on behalf of sender and returns its address
+    fn deploy_storage_contract(backend: &mut Backend, sender: H160) -> H160 {
+        let contract_address = create_address(sender, backend.state.get_account(&sender).nonce.as_u64());
+        let result = handle_sgxvm_create(backend, 200000, sender, U256::zero(), hex::decode(STORAGE_CONTRACT).unwrap(), vec![], true);
+        assert_eq!(result.vm_error, "");
+        contract_address
+    }
+
     #[test]
     fn test_contract_deployment() {
         // Prepare environment
         let sender = H160::from_slice(
             &hex::decode("8c3FfC3600bCb365F7141EAf47b5921aEfB7917a").unwrap()
         );
-        let vicinity = Vicinity {
-            origin: sender.clone(),
-        };
+        let vicinity = Vicinity { origin: sender.clone() };
         let mut storage = MockedStorage::default();
-        let mut backend = Backend {
-            vicinity,
-            state: &mut storage,
-            logs: vec![],
-        };
+        let mut backend = Backend::new(vicinity, &mut storage);
 
         // Deploy contract which emits logs
         // Deployment data was taken from solidity tests from `chain` repo
@@ -167,15 +439,9 @@ mod tests {
         let sender = H160::from_slice(
             &hex::decode("8c3FfC3600bCb365F7141EAf47b5921aEfB7917a").unwrap()
         );
-        let vicinity = Vicinity {
-            origin: sender.clone(),
-        };
+        let vicinity = Vicinity { origin: sender.clone() };
         let mut storage = MockedStorage::default();
-        let mut backend = Backend {
-            vicinity,
-            state: &mut storage,
-            logs: vec![],
-        };
+        let mut backend = Backend::new(vicinity, &mut storage);
 
         // Deploy contract which emits logs
         // Deployment data was taken from solidity tests from `chain` repo
@@ -221,15 +487,9 @@ mod tests {
         let sender = H160::from_slice(
             &hex::decode("8c3FfC3600bCb365F7141EAf47b5921aEfB7917a").unwrap()
         );
-        let vicinity = Vicinity {
-            origin: sender.clone(),
-        };
+        let vicinity = Vicinity { origin: sender.clone() };
         let mut storage = MockedStorage::default();
-        let mut backend = Backend {
-            vicinity,
-            state: &mut storage,
-            logs: vec![],
-        };
+        let mut backend = Backend::new(vicinity, &mut storage);
 
         let sender_nonce_before = backend.state.get_account(&sender.clone()).nonce.as_u64();
 
@@ -260,15 +520,9 @@ mod tests {
         // Prepare environment
         let sender = H160::from_slice(&hex::decode("8c3FfC3600bCb365F7141EAf47b5921aEfB7917a").unwrap());
         let receiver = H160::from_slice(&hex::decode("0000000000000000000000000000000000000000").unwrap());
-        let vicinity = Vicinity {
-            origin: sender.clone(),
-        };
+        let vicinity = Vicinity { origin: sender.clone() };
         let mut storage = MockedStorage::default();
-        let mut backend = Backend {
-            vicinity,
-            state: &mut storage,
-            logs: vec![],
-        };
+        let mut backend = Backend::new(vicinity, &mut storage);
 
         let sender_account_before = backend.state.get_account(&sender);
         let receiver_account_before = backend.state.get_account(&receiver);
@@ -299,15 +553,9 @@ mod tests {
         // Prepare environment
         let sender = H160::from_slice(&hex::decode("8c3FfC3600bCb365F7141EAf47b5921aEfB7917a").unwrap());
         let receiver = H160::from_slice(&hex::decode("0000000000000000000000000000000000000000").unwrap());
-        let vicinity = Vicinity {
-            origin: sender.clone(),
-        };
+        let vicinity = Vicinity { origin: sender.clone() };
         let mut storage = MockedStorage::default();
-        let mut backend = Backend {
-            vicinity,
-            state: &mut storage,
-            logs: vec![],
-        };
+        let mut backend = Backend::new(vicinity, &mut storage);
 
         let sender_account_before = backend.state.get_account(&sender);
         let receiver_account_before = backend.state.get_account(&receiver);
@@ -332,4 +580,1227 @@ mod tests {
         assert_eq!(receiver_account_after.balance, receiver_account_before.balance);
         assert_eq!(sender_account_after.nonce, sender_account_before.nonce);
     }
+
+    #[test]
+    fn test_push0_depends_on_fork() {
+        let (sender, vicinity) = setup_sender();
+        let mut storage = MockedStorage::default();
+        let mut backend = Backend::new(vicinity, &mut storage);
+
+        // Init code `PUSH0 PUSH0 RETURN`, which deploys contract with empty code
+        let deployment_data = hex::decode("5f5ff3").unwrap();
+
+        // PUSH0 is not available before Shanghai
+        let london_result = handle_sgxvm_create(
+            &mut backend,
+            200000,
+            sender.clone(),
+            U256::zero(),
+            deployment_data.clone(),
+            vec![],
+            false
+        );
+        assert!(!london_result.vm_error.is_empty());
+
+        backend.fork = EvmFork::Shanghai;
+        let shanghai_result = handle_sgxvm_create(
+            &mut backend,
+            200000,
+            sender.clone(),
+            U256::zero(),
+            deployment_data.clone(),
+            vec![],
+            false
+        );
+        assert_eq!(shanghai_result.vm_error, "");
+
+        // Cancun is not supported yet, so its id is treated as unknown fork
+        backend.fork = EvmFork::from(2);
+        let cancun_result = handle_sgxvm_create(
+            &mut backend,
+            200000,
+            sender,
+            U256::zero(),
+            deployment_data,
+            vec![],
+            false
+        );
+        assert_eq!(cancun_result.vm_error, "unknown evm fork: 2");
+    }
+
+    #[cfg(feature = "tracing")]
+    #[test]
+    fn test_trace_transfer() {
+        let (sender, vicinity) = setup_sender();
+        let receiver = H160::zero();
+        let mut storage = MockedStorage::default();
+        let mut backend = Backend::new(vicinity, &mut storage);
+
+        let amount_to_send = 10000;
+        let (result, trace) = trace_calls(|| handle_sgxvm_call(
+            &mut backend,
+            200000,
+            sender.clone(),
+            receiver.clone(),
+            U256::from(amount_to_send),
+            vec![],
+            vec![],
+            false
+        ));
+        assert_eq!(result.vm_error, "");
+
+        // Plain transfer should produce single frame without nested calls
+        let trace = trace.expect("call trace should be collected");
+        assert_eq!(trace.call_type, "CALL");
+        assert_eq!(trace.from, sender);
+        assert_eq!(trace.to, receiver);
+        assert_eq!(trace.value, U256::from(amount_to_send));
+        assert_eq!(trace.error, None);
+        assert!(trace.calls.is_empty());
+    }
+
+    #[cfg(feature = "tracing")]
+    #[test]
+    fn test_struct_logger() {
+        let (sender, vicinity) = setup_sender();
+        let mut storage = MockedStorage::default();
+        let mut backend = Backend::new(vicinity, &mut storage);
+
+        // Deploy contract which emits logs and writes to storage
+        let contract_address = deploy_storage_contract(&mut backend, sender);
+
+        let config = StructLoggerConfig {
+            enable_stack: true,
+            enable_memory: true,
+            enable_storage: true,
+            ..Default::default()
+        };
+        let transaction_data = hex::decode("2933c3c90000000000000000000000000000000000000000000000000000000000000378").unwrap();
+        let (result, struct_logs) = trace_steps(config, || handle_sgxvm_call(
+            &mut backend,
+            200000,
+            sender,
+            contract_address,
+            U256::zero(),
+            transaction_data,
+            vec![],
+            false
+        ));
+        assert_eq!(result.vm_error, "");
+
+        // Solidity contracts start with setting up free memory pointer
+        assert!(!struct_logs.is_empty());
+        assert_eq!(struct_logs[0].pc, 0);
+        assert_eq!(struct_logs[0].op, "PUSH1");
+        assert_eq!(struct_logs[0].depth, 1);
+        assert!(struct_logs[0].gas > struct_logs[1].gas);
+
+        // Written storage slot should be recorded
+        let sstore = struct_logs.iter().find(|log| log.op == "SSTORE").expect("SSTORE should be executed");
+        let slot_value = H256::from_low_u64_be(0x378);
+        assert!(sstore.storage.as_ref().unwrap().values().any(|value| *value == slot_value));
+        assert!(sstore.memory.is_some());
+    }
+
+    #[cfg(feature = "tracing")]
+    #[test]
+    fn test_struct_logger_limits() {
+        let (sender, vicinity) = setup_sender();
+        let mut storage = MockedStorage::default();
+        let mut backend = Backend::new(vicinity, &mut storage);
+
+        // Contract which loops forever: JUMPDEST PUSH1 0x00 JUMP
+        let contract_address = H160::from_low_u64_be(0xdead);
+        backend.state.insert_account_code(contract_address, hex::decode("5b600056").unwrap());
+
+        let config = StructLoggerConfig {
+            enable_stack: true,
+            max_steps: 10,
+            ..Default::default()
+        };
+        let (_, struct_logs) = trace_steps(config, || handle_sgxvm_call(
+            &mut backend,
+            100000,
+            sender,
+            contract_address,
+            U256::zero(),
+            vec![],
+            vec![],
+            false
+        ));
+        assert_eq!(struct_logs.len(), 10);
+        assert_eq!(struct_logs[8].op, "JUMP");
+        assert_eq!(struct_logs[8].gas_cost, 8);
+        // Last collected step should keep its own gas cost
+        assert_eq!(struct_logs[9].op, "JUMPDEST");
+        assert_eq!(struct_logs[9].gas_cost, 1);
+
+        let config = StructLoggerConfig {
+            enable_stack: true,
+            max_bytes: 1,
+            ..Default::default()
+        };
+        let (_, struct_logs) = trace_steps(config, || handle_sgxvm_call(
+            &mut backend,
+            100000,
+            sender,
+            contract_address,
+            U256::zero(),
+            vec![],
+            vec![],
+            false
+        ));
+        assert_eq!(struct_logs.len(), 1);
+    }
+
+    #[test]
+    fn test_estimate_gas_for_transfer() {
+        let (sender, vicinity) = setup_sender();
+        let receiver = H160::zero();
+        let mut storage = MockedStorage::default();
+        let mut backend = Backend::new(vicinity, &mut storage);
+
+        let (gas, failed_result) = handle_sgxvm_estimate_gas(
+            &mut backend,
+            200000,
+            sender,
+            Some(receiver),
+            U256::from(10000),
+            vec![],
+            vec![],
+        );
+
+        // Plain transfer costs exactly intrinsic gas, so there are no failed attempts
+        assert_eq!(gas, 21000);
+        assert!(failed_result.is_none());
+    }
+
+    #[test]
+    fn test_estimate_gas_for_contract_call() {
+        let (sender, vicinity) = setup_sender();
+        let mut storage = MockedStorage::default();
+        let mut backend = Backend::new(vicinity, &mut storage);
+
+        // Estimate gas for contract deployment
+        let contract_address = create_address(sender.clone(), backend.state.get_account(&sender.clone()).nonce.as_u64());
+        let deployment_data = hex::decode(STORAGE_CONTRACT).unwrap();
+        let (deployment_gas, _) = handle_sgxvm_estimate_gas(
+            &mut backend,
+            1000000,
+            sender.clone(),
+            None,
+            U256::zero(),
+            deployment_data.clone(),
+            vec![],
+        );
+        assert!(deployment_gas > 21000);
+
+        // Deployment should fail with gas limit lower than estimated one
+        let deployment_result = handle_sgxvm_create(
+            &mut backend,
+            deployment_gas - 1,
+            sender.clone(),
+            U256::zero(),
+            deployment_data.clone(),
+            vec![],
+            false
+        );
+        assert!(!deployment_result.vm_error.is_empty());
+
+        // Deployment should succeed with estimated gas limit
+        let deployment_result = handle_sgxvm_create(
+            &mut backend,
+            deployment_gas,
+            sender.clone(),
+            U256::zero(),
+            deployment_data,
+            vec![],
+            true
+        );
+        assert_eq!(deployment_result.vm_error, "");
+
+        // Estimate gas for contract call
+        let transaction_data = hex::decode("2933c3c90000000000000000000000000000000000000000000000000000000000000378").unwrap();
+        let (call_gas, _) = handle_sgxvm_estimate_gas(
+            &mut backend,
+            1000000,
+            sender.clone(),
+            Some(contract_address),
+            U256::zero(),
+            transaction_data.clone(),
+            vec![],
+        );
+
+        let call_result = handle_sgxvm_call(
+            &mut backend,
+            call_gas,
+            sender,
+            contract_address,
+            U256::zero(),
+            transaction_data,
+            vec![],
+            false
+        );
+        assert_eq!(call_result.vm_error, "");
+        assert!(call_gas >= call_result.gas_used);
+    }
+
+    #[test]
+    fn test_estimate_gas_for_failing_transaction() {
+        let (sender, vicinity) = setup_sender();
+        let mut storage = MockedStorage::default();
+        let mut backend = Backend::new(vicinity, &mut storage);
+
+        // Init code `PUSH1 0 PUSH1 0 REVERT`
+        let (gas, failed_result) = handle_sgxvm_estimate_gas(
+            &mut backend,
+            200000,
+            sender,
+            None,
+            U256::zero(),
+            hex::decode("60006000fd").unwrap(),
+            vec![],
+        );
+
+        assert_eq!(gas, 0);
+        assert!(failed_result.unwrap().vm_error.starts_with("execution reverted"));
+    }
+
+    #[test]
+    fn test_state_diff_for_transfer() {
+        let (sender, vicinity) = setup_sender();
+        let receiver = H160::zero();
+        let mut storage = MockedStorage::default();
+        let mut backend = Backend::new(vicinity, &mut storage);
+
+        let sender_account_before = backend.state.get_account(&sender);
+        let receiver_account_before = backend.state.get_account(&receiver);
+
+        let amount_to_send = 10000;
+        let (result, state_diff) = handle_sgxvm_state_diff(
+            &mut backend,
+            200000,
+            sender.clone(),
+            Some(receiver.clone()),
+            U256::from(amount_to_send),
+            vec![],
+            vec![],
+        );
+        assert_eq!(result.vm_error, "");
+
+        let state_diff = state_diff.unwrap();
+        let sender_diff = state_diff.accounts.iter().find(|diff| diff.address == sender).unwrap();
+        assert_eq!(sender_diff.balance_before, sender_account_before.balance);
+        assert_eq!(sender_diff.balance_after, sender_account_before.balance.sub(amount_to_send));
+        assert_eq!(sender_diff.nonce_after, sender_account_before.nonce.add(1));
+
+        let receiver_diff = state_diff.accounts.iter().find(|diff| diff.address == receiver).unwrap();
+        assert_eq!(receiver_diff.balance_after, receiver_account_before.balance.add(amount_to_send));
+
+        // State should not be changed
+        assert_eq!(backend.state.get_account(&sender), sender_account_before);
+        assert_eq!(backend.state.get_account(&receiver), receiver_account_before);
+    }
+
+    #[test]
+    fn test_state_diff_for_contract_call() {
+        let (sender, vicinity) = setup_sender();
+        let mut storage = MockedStorage::default();
+        let mut backend = Backend::new(vicinity, &mut storage);
+
+        // Deploy contract which stores value
+        let contract_address = deploy_storage_contract(&mut backend, sender);
+
+        // Simulate transaction, which stores 0x378 at slot 0
+        let transaction_data = hex::decode("2933c3c90000000000000000000000000000000000000000000000000000000000000378").unwrap();
+        let (result, state_diff) = handle_sgxvm_state_diff(
+            &mut backend,
+            200000,
+            sender,
+            Some(contract_address),
+            U256::zero(),
+            transaction_data,
+            vec![],
+        );
+        assert_eq!(result.vm_error, "");
+
+        let state_diff = state_diff.unwrap();
+        let contract_diff = state_diff.accounts.iter().find(|diff| diff.address == contract_address).unwrap();
+        assert!(contract_diff.code.is_none());
+        assert_eq!(contract_diff.storage.len(), 1);
+        assert_eq!(contract_diff.storage[0].index, H256::zero());
+        assert_eq!(contract_diff.storage[0].original_value, H256::zero());
+        assert_eq!(contract_diff.storage[0].value, H256::from_low_u64_be(0x378));
+
+        // Storage should not be changed
+        assert!(backend.state.get_account_storage_cell(&contract_address, &H256::zero()).is_none());
+    }
+
+    #[test]
+    fn test_access_list_for_transfer() {
+        let (sender, vicinity) = setup_sender();
+        let receiver = H160::zero();
+        let mut storage = MockedStorage::default();
+        let mut backend = Backend::new(vicinity, &mut storage);
+
+        let (result, access_list) = handle_sgxvm_create_access_list(
+            &mut backend,
+            200000,
+            sender,
+            Some(receiver),
+            U256::from(10000),
+            vec![],
+            vec![],
+        );
+
+        // Sender and recipient are warm by default, so access list should be empty
+        assert_eq!(result.vm_error, "");
+        assert_eq!(result.gas_used, 21000);
+        assert!(access_list.is_empty());
+    }
+
+    #[test]
+    fn test_access_list_for_contract_call() {
+        let (sender, vicinity) = setup_sender();
+        let mut storage = MockedStorage::default();
+        let mut backend = Backend::new(vicinity, &mut storage);
+
+        // Deploy contract which stores value
+        let contract_address = deploy_storage_contract(&mut backend, sender);
+
+        // Transaction stores 0x378 at slot 0
+        let transaction_data = hex::decode("2933c3c90000000000000000000000000000000000000000000000000000000000000378").unwrap();
+        let (result, access_list) = handle_sgxvm_create_access_list(
+            &mut backend,
+            200000,
+            sender,
+            Some(contract_address),
+            U256::zero(),
+            transaction_data.clone(),
+            vec![],
+        );
+        assert_eq!(result.vm_error, "");
+        assert_eq!(access_list, vec![(contract_address, vec![H256::zero()])]);
+
+        // Returned gas should be used by transaction with generated access list
+        let call_result = handle_sgxvm_call(
+            &mut backend,
+            200000,
+            sender,
+            contract_address,
+            U256::zero(),
+            transaction_data,
+            access_list,
+            false
+        );
+        assert_eq!(result.gas_used, call_result.gas_used);
+    }
+
+    #[test]
+    fn test_decode_revert_with_error_string() {
+        let (sender, vicinity) = setup_sender();
+        let mut storage = MockedStorage::default();
+        let mut backend = Backend::new(vicinity, &mut storage);
+
+        // Init code, which reverts with `Error("abc")`
+        let init_code = hex::decode(concat!(
+            "7f08c379a000000000000000000000000000000000000000000000000000000000", "600052",
+            "7f0000002000000000000000000000000000000000000000000000000000000000", "602052",
+            "7f0000000361626300000000000000000000000000000000000000000000000000", "604052",
+            "60646000fd",
+        )).unwrap();
+        let result = handle_sgxvm_create(
+            &mut backend,
+            200000,
+            sender,
+            U256::zero(),
+            init_code,
+            vec![],
+            false
+        );
+
+        assert_eq!(result.revert_reason, Some(RevertReason::Error("abc".to_string())));
+        assert_eq!(result.vm_error, "execution reverted: abc");
+    }
+
+    #[test]
+    fn test_decode_revert_with_panic_code() {
+        let (sender, vicinity) = setup_sender();
+        let mut storage = MockedStorage::default();
+        let mut backend = Backend::new(vicinity, &mut storage);
+
+        // Init code, which reverts with `Panic(0x11)`
+        let init_code = hex::decode(concat!(
+            "7f4e487b7100000000000000000000000000000000000000000000000000000000", "600052",
+            "7f0000001100000000000000000000000000000000000000000000000000000000", "602052",
+            "60246000fd",
+        )).unwrap();
+        let result = handle_sgxvm_create(
+            &mut backend,
+            200000,
+            sender,
+            U256::zero(),
+            init_code,
+            vec![],
+            false
+        );
+
+        assert_eq!(result.revert_reason, Some(RevertReason::Panic(0x11)));
+        assert_eq!(result.vm_error, "execution reverted: panic: arithmetic underflow or overflow (0x11)");
+    }
+
+    #[test]
+    fn test_decode_revert_with_custom_error() {
+        let (sender, vicinity) = setup_sender();
+        let mut storage = MockedStorage::default();
+        let mut backend = Backend::new(vicinity, &mut storage);
+
+        // Init code, which reverts with selector `0xdeadbeef` and single zero argument
+        let init_code = hex::decode(concat!(
+            "7fdeadbeef00000000000000000000000000000000000000000000000000000000", "600052",
+            "60246000fd",
+        )).unwrap();
+        let result = handle_sgxvm_create(
+            &mut backend,
+            200000,
+            sender,
+            U256::zero(),
+            init_code,
+            vec![],
+            false
+        );
+
+        assert_eq!(
+            result.revert_reason,
+            Some(RevertReason::Custom { selector: [0xde, 0xad, 0xbe, 0xef], args: vec![0u8; 32] })
+        );
+        assert_eq!(result.vm_error, "execution reverted: custom error 0xdeadbeef");
+    }
+
+    #[test]
+    fn test_gas_breakdown_for_transfer() {
+        let (sender, vicinity) = setup_sender();
+        let receiver = H160::zero();
+        let mut storage = MockedStorage::default();
+        let mut backend = Backend::new(vicinity, &mut storage);
+
+        let result = handle_sgxvm_call(
+            &mut backend,
+            200000,
+            sender,
+            receiver,
+            U256::from(10000),
+            vec![],
+            vec![],
+            false
+        );
+
+        assert!(matches!(result.exit_reason, Some(ExitReason::Succeed(_))));
+        assert!(result.contract_address.is_none());
+        assert_eq!(result.intrinsic_gas, 21000);
+        assert_eq!(result.execution_gas, 0);
+        assert_eq!(result.gas_refunded, 0);
+    }
+
+    #[test]
+    fn test_created_contract_address() {
+        let (sender, vicinity) = setup_sender();
+        let mut storage = MockedStorage::default();
+        let mut backend = Backend::new(vicinity, &mut storage);
+
+        let contract_address = create_address(sender, backend.state.get_account(&sender).nonce.as_u64());
+        let deployment_data = hex::decode(STORAGE_CONTRACT).unwrap();
+        let result = handle_sgxvm_create(
+            &mut backend,
+            200000,
+            sender,
+            U256::zero(),
+            deployment_data,
+            vec![],
+            true
+        );
+
+        assert_eq!(result.exit_reason, Some(ExitReason::Succeed(ExitSucceed::Returned)));
+        assert_eq!(result.contract_address, Some(contract_address));
+        assert!(result.intrinsic_gas > 53000);
+        assert_eq!(result.intrinsic_gas + result.execution_gas, result.gas_used + result.gas_refunded);
+    }
+
+    #[test]
+    fn test_failed_contract_creation_has_no_address() {
+        let (sender, vicinity) = setup_sender();
+        let mut storage = MockedStorage::default();
+        let mut backend = Backend::new(vicinity, &mut storage);
+
+        // Init code `PUSH1 0 PUSH1 0 REVERT`
+        let result = handle_sgxvm_create(
+            &mut backend,
+            200000,
+            sender,
+            U256::zero(),
+            hex::decode("60006000fd").unwrap(),
+            vec![],
+            false
+        );
+
+        assert!(matches!(result.exit_reason, Some(ExitReason::Revert(_))));
+        assert!(result.contract_address.is_none());
+    }
+
+    #[test]
+    fn test_buffered_storage_flushes_changes_at_once() {
+        let (sender, vicinity) = setup_sender();
+        let receiver = H160::zero();
+        let mut storage = MockedStorage::default();
+        let sender_account_before = storage.get_account(&sender);
+
+        let amount_to_send = 10000;
+        let mut buffered_storage = BufferedStorage::new(&mut storage);
+        for _ in 0..2 {
+            let mut backend = Backend::new(vicinity.clone(), &mut buffered_storage);
+            let result = handle_sgxvm_call(
+                &mut backend,
+                200000,
+                sender.clone(),
+                receiver.clone(),
+                U256::from(amount_to_send),
+                vec![],
+                vec![],
+                true
+            );
+            assert_eq!(result.vm_error, "");
+        }
+
+        // Second transaction should see changes of the first one
+        let buffered_sender_account = buffered_storage.get_account(&sender);
+        assert_eq!(buffered_sender_account.balance, sender_account_before.balance.sub(2 * amount_to_send));
+        assert_eq!(buffered_sender_account.nonce, sender_account_before.nonce.add(2));
+
+        buffered_storage.flush().unwrap();
+        drop(buffered_storage);
+
+        assert_eq!(storage.get_account(&sender).balance, sender_account_before.balance.sub(2 * amount_to_send));
+        assert_eq!(storage.get_account(&receiver).balance, U256::from(2 * amount_to_send));
+    }
+
+    #[test]
+    fn test_failed_transaction_increments_nonce() {
+        let (sender, vicinity) = setup_sender();
+        let reverting_contract = H160::from_low_u64_be(0xdead);
+        let looping_contract = H160::from_low_u64_be(0xbeef);
+        let mut storage = MockedStorage::default();
+        // PUSH1 0x00 PUSH1 0x00 REVERT
+        storage.insert_account_code(reverting_contract, hex::decode("60006000fd").unwrap());
+        // JUMPDEST PUSH1 0x00 JUMP
+        storage.insert_account_code(looping_contract, hex::decode("5b600056").unwrap());
+        let mut backend = Backend::new(vicinity, &mut storage);
+
+        assert!(validate_nonce(&backend, sender, 0).is_ok());
+        let result = handle_sgxvm_call(&mut backend, 100000, sender, reverting_contract, U256::zero(), vec![], vec![], true);
+        assert!(matches!(result.exit_reason, Some(ExitReason::Revert(_))));
+        assert_eq!(backend.basic(sender).nonce, U256::one());
+
+        // Reverted transaction cannot be replayed, while the next one is accepted
+        assert!(validate_nonce(&backend, sender, 0).is_err());
+        assert!(validate_nonce(&backend, sender, 1).is_ok());
+
+        let result = handle_sgxvm_call(&mut backend, 30000, sender, looping_contract, U256::zero(), vec![], vec![], true);
+        assert_eq!(result.exit_reason, Some(ExitReason::Error(ExitError::OutOfGas)));
+        assert_eq!(backend.basic(sender).nonce, U256::from(2));
+        assert!(validate_nonce(&backend, sender, 2).is_ok());
+    }
+
+    #[test]
+    fn test_batch_transactions_of_same_sender() {
+        let receiver = H160::from_low_u64_be(0xbeef);
+        let mut storage = MockedStorage::default();
+        let mut buffered_storage = BufferedStorage::new(&mut storage);
+
+        for nonce in 0..2u64 {
+            let (sender, vicinity) = setup_sender();
+            let mut backend = Backend::new(vicinity, &mut buffered_storage);
+            // Nonce of the second transaction is validated against changes of the first one
+            assert!(validate_nonce(&backend, sender, nonce).is_ok());
+            let result = handle_sgxvm_call(&mut backend, 100000, sender, receiver, U256::from(10), vec![], vec![], true);
+            assert_eq!(result.vm_error, "");
+            assert!(validate_nonce(&backend, sender, nonce).is_err());
+        }
+
+        buffered_storage.flush().unwrap();
+        drop(buffered_storage);
+
+        let (sender, _) = setup_sender();
+        assert_eq!(storage.get_account(&sender).nonce, U256::from(2));
+    }
+
+    #[test]
+    fn test_reset_storage_removes_stale_cells() {
+        let (_, vicinity) = setup_sender();
+        let contract_address = H160::from_low_u64_be(0x1234);
+        let mut storage = MockedStorage::default();
+        storage.insert_account(contract_address, Basic::default());
+        storage.insert_storage_cell(contract_address, H256::from_low_u64_be(1), H256::from_low_u64_be(1));
+        storage.insert_storage_cell(contract_address, H256::from_low_u64_be(2), H256::from_low_u64_be(2));
+        let mut backend = Backend::new(vicinity, &mut storage);
+
+        // Recreated account has only cells written after reset
+        let changes: Vec<Apply<Vec<(H256, H256)>>> = vec![Apply::Modify {
+            address: contract_address,
+            basic: Basic::default(),
+            code: None,
+            storage: vec![(H256::from_low_u64_be(2), H256::from_low_u64_be(3))],
+            reset_storage: true,
+        }];
+        backend.apply_changes(changes, Vec::<Log>::new(), false).unwrap();
+
+        assert!(backend.state.get_account_storage_cell(&contract_address, &H256::from_low_u64_be(1)).is_none());
+        assert_eq!(
+            backend.state.get_account_storage_cell(&contract_address, &H256::from_low_u64_be(2)),
+            Some(H256::from_low_u64_be(3))
+        );
+    }
+
+    #[test]
+    fn test_touched_empty_account_is_removed() {
+        let (sender, vicinity) = setup_sender();
+        let receiver = H160::from_low_u64_be(0xdead);
+        let mut storage = MockedStorage::default();
+        let mut backend = Backend::new(vicinity, &mut storage);
+
+        // Zero-value transfer touches receiver, but leaves it empty
+        let result = handle_sgxvm_call(
+            &mut backend,
+            200000,
+            sender.clone(),
+            receiver.clone(),
+            U256::zero(),
+            vec![],
+            vec![],
+            true
+        );
+        assert_eq!(result.vm_error, "");
+
+        assert!(!backend.state.contains_key(&receiver));
+        assert!(backend.state.contains_key(&sender));
+    }
+
+    #[test]
+    fn test_empty_account_is_kept_without_delete_empty() {
+        let (_, vicinity) = setup_sender();
+        let empty_account = H160::from_low_u64_be(0xdead);
+        let mut storage = MockedStorage::default();
+        let mut backend = Backend::new(vicinity, &mut storage);
+
+        let changes = |address: H160| -> Vec<Apply<Vec<(H256, H256)>>> {
+            vec![Apply::Modify {
+                address,
+                basic: Basic::default(),
+                code: None,
+                storage: vec![],
+                reset_storage: false,
+            }]
+        };
+
+        backend.apply_changes(changes(empty_account), Vec::<Log>::new(), false).unwrap();
+        assert!(backend.state.contains_key(&empty_account));
+
+        backend.apply_changes(changes(empty_account), Vec::<Log>::new(), true).unwrap();
+        assert!(!backend.state.contains_key(&empty_account));
+    }
+
+    #[test]
+    fn test_sstore_gas_uses_original_value() {
+        let (sender, vicinity) = setup_sender();
+        let mut storage = MockedStorage::default();
+        let mut backend = Backend::new(vicinity, &mut storage);
+
+        // Deploy contract which stores value
+        let contract_address = deploy_storage_contract(&mut backend, sender);
+
+        // Store 0x378 into empty slot
+        let first_result = handle_sgxvm_call(
+            &mut backend,
+            200000,
+            sender,
+            contract_address,
+            U256::zero(),
+            hex::decode("2933c3c90000000000000000000000000000000000000000000000000000000000000378").unwrap(),
+            vec![],
+            true
+        );
+
+        // Overwrite 0x378 with 0x379
+        let second_result = handle_sgxvm_call(
+            &mut backend,
+            200000,
+            sender,
+            contract_address,
+            U256::zero(),
+            hex::decode("2933c3c90000000000000000000000000000000000000000000000000000000000000379").unwrap(),
+            vec![],
+            true
+        );
+
+        // Setting empty slot costs 20000 gas, while updating non-empty slot costs 2900 gas (EIP-2929)
+        assert_eq!(first_result.gas_used - second_result.gas_used, 20000 - 2900);
+    }
+
+    #[test]
+    fn test_supply_invariant_violation_is_rejected() {
+        let (sender, vicinity) = setup_sender();
+        let receiver = H160::from_low_u64_be(0xbeef);
+        let mut storage = MockedStorage::default();
+        let backend = Backend::new(vicinity, &mut storage);
+        let sender_balance = backend.basic(sender).balance;
+
+        let modify = |address: H160, balance: U256| -> Apply<Vec<(H256, H256)>> {
+            Apply::Modify {
+                address,
+                basic: Basic { balance, nonce: U256::zero() },
+                code: None,
+                storage: vec![],
+                reset_storage: false,
+            }
+        };
+
+        // Transfer keeps total supply unchanged
+        let transfer = vec![
+            modify(sender, sender_balance.sub(U256::from(10))),
+            modify(receiver, U256::from(10)),
+        ];
+        assert!(validate_supply_invariant(&backend, &transfer).is_ok());
+
+        // Minting is rejected
+        let mint = vec![modify(receiver, U256::from(10))];
+        let err = validate_supply_invariant(&backend, &mint).unwrap_err();
+        assert!(err.contains("invariant violation"));
+
+        // Balance of deleted account is burnt together with it
+        let delete = vec![Apply::Delete { address: sender }, modify(receiver, sender_balance)];
+        assert!(validate_supply_invariant(&backend, &delete).is_ok());
+    }
+
+    #[test]
+    fn test_apply_rejects_supply_invariant_violation() {
+        let (sender, vicinity) = setup_sender();
+        let receiver = H160::from_low_u64_be(0xbeef);
+        let mut storage = MockedStorage::default();
+        let mut backend = Backend::new(vicinity, &mut storage);
+        let sender_balance = backend.basic(sender).balance;
+
+        // Changes are partially valid, but nothing should be written
+        let changes = vec![
+            Apply::Modify {
+                address: sender,
+                basic: Basic { balance: sender_balance, nonce: U256::one() },
+                code: None,
+                storage: Vec::<(H256, H256)>::new(),
+                reset_storage: false,
+            },
+            Apply::Modify {
+                address: receiver,
+                basic: Basic { balance: U256::from(10), nonce: U256::zero() },
+                code: None,
+                storage: Vec::<(H256, H256)>::new(),
+                reset_storage: false,
+            },
+        ];
+        let err = backend.apply_changes(changes, Vec::<Log>::new(), false).unwrap_err();
+        assert!(err.contains("invariant violation"));
+        assert_eq!(backend.basic(sender).nonce, U256::zero());
+        assert_eq!(backend.basic(receiver).balance, U256::zero());
+    }
+
+    /// Storage wrapper, which counts reads reaching inner storage
+    struct CountingStorage<'a> {
+        inner: &'a mut dyn Storage,
+        reads: core::cell::Cell<usize>,
+        storage_cell_reads: core::cell::Cell<usize>,
+        batch_reads: core::cell::Cell<usize>,
+        commits: usize,
+        // If set, commits are rejected without writing anything
+        fail_commits: bool,
+    }
+
+    impl<'a> CountingStorage<'a> {
+        fn new(inner: &'a mut dyn Storage) -> Self {
+            Self { inner, reads: Default::default(), storage_cell_reads: Default::default(), batch_reads: Default::default(), commits: 0, fail_commits: false }
+        }
+    }
+
+    impl<'a> Storage for CountingStorage<'a> {
+        fn contains_key(&self, key: &H160) -> bool {
+            self.reads.set(self.reads.get() + 1);
+            self.inner.contains_key(key)
+        }
+
+        fn get_account_storage_cell(&self, key: &H160, index: &H256) -> Option<H256> {
+            self.reads.set(self.reads.get() + 1);
+            self.storage_cell_reads.set(self.storage_cell_reads.get() + 1);
+            self.inner.get_account_storage_cell(key, index)
+        }
+
+        fn get_account_code(&self, key: &H160) -> Option<Vec<u8>> {
+            self.reads.set(self.reads.get() + 1);
+            self.inner.get_account_code(key)
+        }
+
+        fn get_account(&self, account: &H160) -> Basic {
+            self.reads.set(self.reads.get() + 1);
+            self.inner.get_account(account)
+        }
+
+        fn insert_account(&mut self, key: H160, data: Basic) {
+            self.inner.insert_account(key, data)
+        }
+
+        fn insert_account_code(&mut self, key: H160, code: Vec<u8>) {
+            self.inner.insert_account_code(key, code)
+        }
+
+        fn insert_storage_cell(&mut self, key: H160, index: H256, value: H256) {
+            self.inner.insert_storage_cell(key, index, value)
+        }
+
+        fn remove(&mut self, key: &H160) {
+            self.inner.remove(key)
+        }
+
+        fn remove_storage_cell(&mut self, key: &H160, index: &H256) {
+            self.inner.remove_storage_cell(key, index)
+        }
+
+        fn remove_storage(&mut self, key: &H160) {
+            self.inner.remove_storage(key)
+        }
+
+        fn get_batch(&self, request: &BatchReadRequest) -> BatchReadResponse {
+            self.batch_reads.set(self.batch_reads.get() + 1);
+            self.inner.get_batch(request)
+        }
+
+        fn commit(&mut self, write_set: WriteSet) -> Result<(), String> {
+            self.commits += 1;
+            match self.fail_commits {
+                true => Err("commit rejected".to_string()),
+                false => self.inner.commit(write_set),
+            }
+        }
+    }
+
+    #[test]
+    fn test_buffered_storage_returns_commit_error() {
+        let address = H160::from_low_u64_be(0xbeef);
+        let mut mocked_storage = MockedStorage::default();
+        let mut counting_storage = CountingStorage::new(&mut mocked_storage);
+        counting_storage.fail_commits = true;
+
+        let mut buffered_storage = BufferedStorage::new(&mut counting_storage);
+        buffered_storage.insert_account(address, Basic { balance: U256::one(), nonce: U256::one() });
+        assert_eq!(buffered_storage.flush(), Err("commit rejected".to_string()));
+        drop(buffered_storage);
+
+        assert_eq!(counting_storage.commits, 1);
+        assert_eq!(counting_storage.get_account(&address).nonce, U256::zero());
+    }
+
+    #[test]
+    fn test_cached_storage_returns_commit_error() {
+        let address = H160::from_low_u64_be(0xbeef);
+        let mut mocked_storage = MockedStorage::default();
+        let mut counting_storage = CountingStorage::new(&mut mocked_storage);
+        counting_storage.fail_commits = true;
+
+        let mut cached_storage = CachedStorage::new(&mut counting_storage);
+        let mut buffered_storage = BufferedStorage::new(&mut cached_storage);
+        buffered_storage.insert_account(address, Basic { balance: U256::one(), nonce: U256::one() });
+        assert_eq!(buffered_storage.flush(), Err("commit rejected".to_string()));
+        drop(buffered_storage);
+
+        assert_eq!(cached_storage.get_account(&address).nonce, U256::zero());
+        drop(cached_storage);
+        assert_eq!(counting_storage.commits, 1);
+    }
+
+    #[test]
+    fn test_cached_storage_memoizes_reads() {
+        let address = H160::from_low_u64_be(0xbeef);
+        let index = H256::from_low_u64_be(1);
+        let mut mocked_storage = MockedStorage::default();
+        let mut counting_storage = CountingStorage::new(&mut mocked_storage);
+        let mut cached_storage = CachedStorage::new(&mut counting_storage);
+
+        // Repeated reads are served from cache
+        for _ in 0..3 {
+            assert_eq!(cached_storage.get_account_storage_cell(&address, &index), None);
+            assert_eq!(cached_storage.get_account(&address).balance, U256::zero());
+            assert_eq!(cached_storage.get_account_code(&address), None);
+        }
+
+        // Writes invalidate cached entries
+        cached_storage.insert_storage_cell(address, index, H256::from_low_u64_be(2));
+        cached_storage.insert_account(address, Basic { balance: U256::from(10), nonce: U256::one() });
+        assert_eq!(cached_storage.get_account_storage_cell(&address, &index), Some(H256::from_low_u64_be(2)));
+        assert_eq!(cached_storage.get_account(&address).balance, U256::from(10));
+
+        cached_storage.remove(&address);
+        assert_eq!(cached_storage.get_account_storage_cell(&address, &index), None);
+        assert_eq!(cached_storage.get_account(&address).balance, U256::zero());
+
+        drop(cached_storage);
+        assert_eq!(counting_storage.reads.get(), 7);
+    }
+
+    #[test]
+    fn test_cached_storage_invalidates_contains() {
+        let address = H160::from_low_u64_be(0xbeef);
+        let index = H256::from_low_u64_be(1);
+        let mut mocked_storage = MockedStorage::default();
+        let mut counting_storage = CountingStorage::new(&mut mocked_storage);
+        let mut cached_storage = CachedStorage::new(&mut counting_storage);
+
+        assert!(!cached_storage.contains_key(&address));
+        assert!(!cached_storage.contains_key(&address));
+
+        // Every write to the account makes its existence to be read again
+        cached_storage.insert_storage_cell(address, index, H256::from_low_u64_be(2));
+        assert!(!cached_storage.contains_key(&address));
+        cached_storage.remove_storage_cell(&address, &index);
+        assert!(!cached_storage.contains_key(&address));
+        cached_storage.remove_storage(&address);
+        assert!(!cached_storage.contains_key(&address));
+        let write_set = WriteSet {
+            storage_cells: vec![(address, index, Some(H256::from_low_u64_be(3)))],
+            ..Default::default()
+        };
+        cached_storage.commit(write_set).unwrap();
+        assert!(!cached_storage.contains_key(&address));
+
+        drop(cached_storage);
+        assert_eq!(counting_storage.reads.get(), 5);
+    }
+
+    #[test]
+    fn test_cached_storage_sees_committed_changes() {
+        let contract = H160::from_low_u64_be(0xc0de);
+        let mut mocked_storage = MockedStorage::default();
+        let mut cached_storage = CachedStorage::new(&mut mocked_storage);
+
+        // Contract increments value of slot 0: SSTORE(0, SLOAD(0) + 1)
+        cached_storage.insert_account_code(contract, hex::decode("60005460010160005500").unwrap());
+        cached_storage.insert_account(contract, Basic { balance: U256::zero(), nonce: U256::one() });
+        cached_storage.insert_storage_cell(contract, H256::zero(), H256::from_low_u64_be(5));
+
+        // Each transaction uses new backend on top of the same cache
+        for _ in 0..2 {
+            let (sender, vicinity) = setup_sender();
+            let mut backend = Backend::new(vicinity, &mut cached_storage);
+            let result = handle_sgxvm_call(&mut backend, 100_000, sender, contract, U256::zero(), vec![], vec![], true);
+            assert_eq!(result.vm_error, "");
+        }
+
+        assert_eq!(
+            cached_storage.get_account_storage_cell(&contract, &H256::zero()),
+            Some(H256::from_low_u64_be(7))
+        );
+        drop(cached_storage);
+        assert_eq!(
+            mocked_storage.get_account_storage_cell(&contract, &H256::zero()),
+            Some(H256::from_low_u64_be(7))
+        );
+    }
+
+    #[test]
+    fn test_batch_read() {
+        let address = H160::from_low_u64_be(0xc0de);
+        let removed = H160::from_low_u64_be(0xdead);
+        let index = H256::from_low_u64_be(1);
+        let mut mocked_storage = MockedStorage::default();
+        mocked_storage.insert_account(address, Basic { balance: U256::from(10), nonce: U256::one() });
+        mocked_storage.insert_account_code(address, vec![0x00]);
+        mocked_storage.insert_storage_cell(address, index, H256::from_low_u64_be(2));
+        mocked_storage.insert_account(removed, Basic { balance: U256::from(20), nonce: U256::one() });
+
+        let mut counting_storage = CountingStorage::new(&mut mocked_storage);
+        let mut cached_storage = CachedStorage::new(&mut counting_storage);
+        let mut buffered_storage = BufferedStorage::new(&mut cached_storage);
+        buffered_storage.remove(&removed);
+        buffered_storage.insert_storage_cell(address, H256::from_low_u64_be(3), H256::from_low_u64_be(4));
+
+        let request = BatchReadRequest {
+            accounts: vec![address, removed],
+            codes: vec![address],
+            storage_cells: vec![(address, index), (address, H256::from_low_u64_be(3)), (removed, index)],
+        };
+        let response = buffered_storage.get_batch(&request);
+        assert_eq!(response.accounts[0].balance, U256::from(10));
+        assert_eq!(response.accounts[1], Basic::default());
+        assert_eq!(response.codes, vec![Some(vec![0x00])]);
+        assert_eq!(
+            response.storage_cells,
+            vec![Some(H256::from_low_u64_be(2)), Some(H256::from_low_u64_be(4)), None]
+        );
+
+        // Values are fetched once and then served from cache
+        assert_eq!(buffered_storage.get_batch(&request), response);
+        assert_eq!(buffered_storage.get_account(&address).balance, U256::from(10));
+        assert_eq!(buffered_storage.get_account_storage_cell(&address, &index), Some(H256::from_low_u64_be(2)));
+
+        drop(buffered_storage);
+        drop(cached_storage);
+        assert_eq!(counting_storage.batch_reads.get(), 1);
+        assert_eq!(counting_storage.reads.get(), 0);
+    }
+
+    #[test]
+    fn test_access_list_is_prefetched() {
+        let (sender, vicinity) = setup_sender();
+        let contract = H160::from_low_u64_be(0xc0de);
+        let mut mocked_storage = MockedStorage::default();
+        // Contract reads slots 0 and 1: SLOAD(0), SLOAD(1), STOP
+        mocked_storage.insert_account_code(contract, hex::decode("600054600154").unwrap());
+        mocked_storage.insert_account(contract, Basic { balance: U256::zero(), nonce: U256::one() });
+        mocked_storage.insert_storage_cell(contract, H256::zero(), H256::from_low_u64_be(5));
+
+        let mut counting_storage = CountingStorage::new(&mut mocked_storage);
+        let mut cached_storage = CachedStorage::new(&mut counting_storage);
+        let mut backend = Backend::new(vicinity, &mut cached_storage);
+
+        let access_list = vec![(contract, vec![H256::zero(), H256::from_low_u64_be(1)])];
+        let result = handle_sgxvm_call(&mut backend, 100_000, sender, contract, U256::zero(), vec![], access_list, false);
+        assert_eq!(result.vm_error, "");
+
+        // All slots were obtained by single batch read before execution
+        drop(backend);
+        drop(cached_storage);
+        assert_eq!(counting_storage.batch_reads.get(), 1);
+        assert_eq!(counting_storage.storage_cell_reads.get(), 0);
+    }
+
+    #[test]
+    fn test_changes_are_committed_at_once() {
+        let contract = H160::from_low_u64_be(0xc0de);
+        let removed = H160::from_low_u64_be(0xdead);
+        let mut mocked_storage = MockedStorage::default();
+        mocked_storage.insert_account(removed, Basic { balance: U256::from(20), nonce: U256::one() });
+        mocked_storage.insert_storage_cell(contract, H256::from_low_u64_be(2), H256::from_low_u64_be(2));
+
+        let mut counting_storage = CountingStorage::new(&mut mocked_storage);
+        let mut cached_storage = CachedStorage::new(&mut counting_storage);
+        let mut buffered_storage = BufferedStorage::new(&mut cached_storage);
+
+        buffered_storage.remove(&removed);
+        buffered_storage.insert_account(contract, Basic { balance: U256::zero(), nonce: U256::one() });
+        buffered_storage.insert_account_code(contract, vec![0x00]);
+        buffered_storage.insert_storage_cell(contract, H256::from_low_u64_be(1), H256::from_low_u64_be(1));
+        buffered_storage.remove_storage_cell(&contract, &H256::from_low_u64_be(2));
+        buffered_storage.flush().unwrap();
+
+        // Nothing is left to write
+        buffered_storage.flush().unwrap();
+
+        // Changes are visible through the cache
+        assert!(!buffered_storage.contains_key(&removed));
+        assert_eq!(buffered_storage.get_account_storage_cell(&contract, &H256::from_low_u64_be(2)), None);
+
+        drop(buffered_storage);
+        drop(cached_storage);
+        assert_eq!(counting_storage.commits, 1);
+        drop(counting_storage);
+
+        assert!(!mocked_storage.contains_key(&removed));
+        assert_eq!(mocked_storage.get_account_code(&contract), Some(vec![0x00]));
+        assert_eq!(
+            mocked_storage.get_account_storage_cell(&contract, &H256::from_low_u64_be(1)),
+            Some(H256::from_low_u64_be(1))
+        );
+        assert_eq!(mocked_storage.get_account_storage_cell(&contract, &H256::from_low_u64_be(2)), None);
+    }
+
+    #[test]
+    fn test_ecrecover_public_key_precompile() {
+        let (sender, vicinity) = setup_sender();
+        let mut storage = MockedStorage::default();
+        let mut backend = Backend::new(vicinity, &mut storage);
+
+        // Message hash, v, r, s
+        let input = hex::decode("38d18acb67d25c8bb9942764b62f18e17054f66a817bd4295423adf9ed98873e000000000000000000000000000000000000000000000000000000000000001b38d18acb67d25c8bb9942764b62f18e17054f66a817bd4295423adf9ed98873e789d1dd423d25f0772d2748d60f7e4b81bb14d086eba8e8e8efb6dcff8a4ae02").unwrap();
+        let result = handle_sgxvm_call(&mut backend, 100_000, sender, H160::from_low_u64_be(1026), U256::zero(), input, vec![], false);
+        assert_eq!(result.vm_error, "");
+        assert_eq!(
+            hex::encode(&result.data),
+            "861254a4cf8dfd2d60e2a33e3143eac6285886f0aed91711ab7e2c013f265c55fd456667f3b08657795fe6754b6fbc11186714c4e4f48d5b856800e3e81c30c8"
+        );
+        // Precompile costs 3000 gas on top of intrinsic gas
+        assert_eq!(result.gas_used, 21000 + 3000 + 16 * 97 + 4 * 31);
+
+        // Zeroed signature cannot be recovered
+        let result = handle_sgxvm_call(&mut backend, 100_000, sender, H160::from_low_u64_be(1026), U256::zero(), vec![0u8; 128], vec![], false);
+        assert_ne!(result.vm_error, "");
+    }
+
+    #[cfg(feature = "sha3fips")]
+    #[test]
+    fn test_sha3fips_precompiles() {
+        let (sender, vicinity) = setup_sender();
+        let mut storage = MockedStorage::default();
+        let mut backend = Backend::new(vicinity, &mut storage);
+
+        // Precompiles enabled by cargo features are not available until they are activated
+        let result = handle_sgxvm_call(&mut backend, 100_000, sender, H160::from_low_u64_be(1024), U256::zero(), vec![], vec![], false);
+        assert_eq!(result.vm_error, "");
+        assert!(result.data.is_empty());
+
+        let mut config = crate::precompiles::PrecompileConfig::default();
+        for address in [1024, 1025] {
+            config.overrides.insert(H160::from_low_u64_be(address), Default::default());
+        }
+        backend.precompiles.configure(&config).unwrap();
+
+        // SHA3-256 of empty input
+        let result = handle_sgxvm_call(&mut backend, 100_000, sender, H160::from_low_u64_be(1024), U256::zero(), vec![], vec![], false);
+        assert_eq!(result.vm_error, "");
+        assert_eq!(hex::encode(result.data), "a7ffc6f8bf1ed76651c14756a061d662f580ff4de43b49fa82d80a4b80f8434a");
+
+        let result = handle_sgxvm_call(&mut backend, 100_000, sender, H160::from_low_u64_be(1025), U256::zero(), b"hello".to_vec(), vec![], false);
+        assert_eq!(result.vm_error, "");
+        assert_eq!(result.data.len(), 64);
+    }
+
+    #[cfg(feature = "curve25519")]
+    #[test]
+    fn test_curve25519_precompiles() {
+        let (sender, vicinity) = setup_sender();
+        let mut storage = MockedStorage::default();
+        let mut backend = Backend::new(vicinity, &mut storage);
+
+        // Precompiles enabled by cargo features are not available until they are activated
+        let result = handle_sgxvm_call(&mut backend, 100_000, sender, H160::from_low_u64_be(1027), U256::zero(), vec![], vec![], false);
+        assert_eq!(result.vm_error, "");
+        assert!(result.data.is_empty());
+
+        let mut config = crate::precompiles::PrecompileConfig::default();
+        for address in [1027, 1028] {
+            config.overrides.insert(H160::from_low_u64_be(address), Default::default());
+        }
+        backend.precompiles.configure(&config).unwrap();
+
+        // Compressed Ristretto basepoint
+        let basepoint = hex::decode("e2f2ae0a6abc4e71a884a961c500515f58e30b6aa582dd8db6a65945e08d2d76").unwrap();
+
+        // B + B equals 2 * B
+        let result = handle_sgxvm_call(&mut backend, 100_000, sender, H160::from_low_u64_be(1027), U256::zero(), [basepoint.clone(), basepoint.clone()].concat(), vec![], false);
+        assert_eq!(result.vm_error, "");
+        let sum = result.data;
+
+        let mut scalar = vec![0u8; 32];
+        scalar[0] = 2;
+        let result = handle_sgxvm_call(&mut backend, 100_000, sender, H160::from_low_u64_be(1028), U256::zero(), [scalar, basepoint].concat(), vec![], false);
+        assert_eq!(result.vm_error, "");
+        assert_eq!(result.data, sum);
+
+        // Invalid input length is rejected
+        let result = handle_sgxvm_call(&mut backend, 100_000, sender, H160::from_low_u64_be(1028), U256::zero(), vec![0u8; 33], vec![], false);
+        assert_ne!(result.vm_error, "");
+    }
 }
@@ -1,73 +1,401 @@
-use core::marker::PhantomData;
-
 use evm_precompile_blake2f::Blake2F;
 use evm_precompile_bn128::{Bn128Add, Bn128Mul, Bn128Pairing};
 use evm_precompile_modexp::Modexp;
-// use evm_precompile_curve25519::{Curve25519Add, Curve25519ScalarMul};
-use evm_precompile_simple::{ECRecover, Identity, Ripemd160, Sha256};
+#[cfg(feature = "curve25519")]
+use evm_precompile_curve25519::{Curve25519Add, Curve25519ScalarMul};
+use evm_precompile_simple::{ECRecover, ECRecoverPublicKey, Identity, Ripemd160, Sha256};
+#[cfg(feature = "sha3fips")]
+use evm_precompile_sha3fips::{Sha3FIPS256, Sha3FIPS512};
+use evm::{Context, ExitError, ExitReason, Transfer};
 use precompile_std::{Precompile, PrecompileHandle, PrecompileResult, PrecompileSet, IsPrecompileResult};
-use primitive_types::H160;
-
-// use evm_precompile_sha3fips::{Sha3FIPS256, Sha3FIPS512};
-
-pub struct EVMPrecompiles<R>(PhantomData<R>);
-
-impl<R> EVMPrecompiles<R>
-    where
-        R: evm::backend::Backend,
-{
-    pub fn new() -> Self {
-        Self(Default::default())
-    }
-    pub fn used_addresses() -> [H160; 9] {
-        [
-            hash(1),
-            hash(2),
-            hash(3),
-            hash(4),
-            hash(5),
-            hash(6),
-            hash(7),
-            hash(8),
-            hash(9),
-            // hash(1024),
-            // hash(1025),
-        ]
+use primitive_types::{H160, H256, U256};
+use std::{collections::BTreeMap, rc::Rc, string::String, vec::Vec};
+
+use crate::backend::EvmFork;
+
+/// Type-erased entry point of precompile. Stateful precompiles capture their context in closure
+pub type PrecompileFn = Rc<dyn Fn(&mut PrecompileHandleRef) -> PrecompileResult>;
+
+/// Precompile registered in `PrecompileRegistry`
+#[derive(Clone)]
+pub struct PrecompileEntry {
+    pub execute: PrecompileFn,
+    /// Block number starting from which precompile is available. Precompile without
+    /// activation height is not available until it is activated by `PrecompileConfig`
+    pub activation_height: Option<u64>,
+    /// If set, precompile is charged with this fixed amount of gas instead of its own cost
+    pub gas_override: Option<u64>,
+}
+
+impl PrecompileEntry {
+    pub fn new<P: Precompile>() -> Self {
+        Self::from_fn(execute_precompile::<P>)
+    }
+
+    /// Creates entry from arbitrary function, which allows to register precompiles with context
+    pub fn from_fn(execute: impl Fn(&mut PrecompileHandleRef) -> PrecompileResult + 'static) -> Self {
+        Self {
+            execute: Rc::new(execute),
+            activation_height: Some(0),
+            gas_override: None,
+        }
+    }
+
+    pub fn with_activation_height(mut self, activation_height: u64) -> Self {
+        self.activation_height = Some(activation_height);
+        self
+    }
+
+    /// Makes precompile unavailable until its activation height is set by `PrecompileConfig`
+    pub fn inactive(mut self) -> Self {
+        self.activation_height = None;
+        self
+    }
+
+    pub fn with_gas_override(mut self, gas: u64) -> Self {
+        self.gas_override = Some(gas);
+        self
+    }
+
+    fn is_active(&self, block_number: U256) -> bool {
+        match self.activation_height {
+            Some(activation_height) => block_number >= U256::from(activation_height),
+            None => false,
+        }
     }
 }
-impl<R> PrecompileSet for EVMPrecompiles<R>
-    where
-        R: evm::backend::Backend,
-{
-    fn execute(&self, handle: &mut impl PrecompileHandle) -> Option<PrecompileResult> {
-        match handle.code_address() {
+
+/// Parameters of registered precompile, which can be changed by node configuration
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct PrecompileOverride {
+    /// Block number starting from which precompile is available
+    pub activation_height: u64,
+    /// If set, precompile is charged with this fixed amount of gas instead of its own cost
+    pub gas_override: Option<u64>,
+    /// If set, precompile is removed from registry
+    pub disabled: bool,
+}
+
+/// Configuration of precompiles. Should be the same on every node, since it affects
+/// results of transactions
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct PrecompileConfig {
+    pub overrides: BTreeMap<H160, PrecompileOverride>,
+}
+
+/// Mapping from address to precompile available at that address
+#[derive(Clone, Default)]
+pub struct PrecompileRegistry {
+    entries: BTreeMap<H160, PrecompileEntry>,
+}
+
+impl PrecompileRegistry {
+    /// Returns set of precompiles available for provided EVM fork.
+    ///
+    /// Besides Ethereum precompiles, ECRecoverPublicKey is available at 0x402. It costs 3000 gas
+    /// and returns 64-byte uncompressed secp256k1 public key of signer.
+    ///
+    /// Following precompiles can be enabled with cargo features:
+    /// * `sha3fips`: SHA3-256 (FIPS202) at 0x400 and SHA3-512 (FIPS202) at 0x401.
+    ///   Both cost 60 gas plus 12 gas per 32-byte word of input
+    /// * `curve25519`: Ristretto point addition (up to 10 points) at 0x403 and Ristretto
+    ///   scalar multiplication at 0x404. Both cost 60 gas plus 12 gas per 32-byte word of input
+    ///
+    /// They are not available until they are activated by `PrecompileConfig`, so nodes built
+    /// with different features execute transactions the same way. Configuration, which activates
+    /// precompile missing in the build, is rejected
+    ///
+    /// Forks, which cannot be executed (see `EvmFork::config`), have no precompiles
+    pub fn for_fork(fork: EvmFork) -> Self {
+        match fork {
+            // Precompiles were not changed between London and Shanghai
+            EvmFork::London | EvmFork::Shanghai => Self::base(),
+            EvmFork::Unknown(_) => Self::default(),
+        }
+    }
+
+    /// Returns Ethereum precompiles available since Istanbul and precompiles specific for Swisstronik
+    fn base() -> Self {
+        #[allow(unused_mut)]
+        let mut registry = Self::default()
             // Ethereum precompiles:
-            a if a == hash(1) => Some(ECRecover::execute(handle)),
-            a if a == hash(2) => Some(Sha256::execute(handle)),
-            a if a == hash(3) => Some(Ripemd160::execute(handle)),
-            a if a == hash(4) => Some(Identity::execute(handle)),
-            a if a == hash(5) => Some(Modexp::execute(handle)),
-            a if a == hash(6) => Some(Bn128Add::execute(handle)),
-            a if a == hash(7) => Some(Bn128Mul::execute(handle)),
-            a if a == hash(8) => Some(Bn128Pairing::execute(handle)),
-            a if a == hash(9) => Some(Blake2F::execute(handle)),
+            .with(hash(1), PrecompileEntry::new::<ECRecover>())
+            .with(hash(2), PrecompileEntry::new::<Sha256>())
+            .with(hash(3), PrecompileEntry::new::<Ripemd160>())
+            .with(hash(4), PrecompileEntry::new::<Identity>())
+            .with(hash(5), PrecompileEntry::new::<Modexp>())
+            .with(hash(6), PrecompileEntry::new::<Bn128Add>())
+            .with(hash(7), PrecompileEntry::new::<Bn128Mul>())
+            .with(hash(8), PrecompileEntry::new::<Bn128Pairing>())
+            .with(hash(9), PrecompileEntry::new::<Blake2F>())
             // Non-Frontier specific nor Ethereum precompiles :
-            // a if a == hash(1024) => Some(Sha3FIPS256::execute(handle)),
-            // a if a == hash(1025) => Some(Sha3FIPS512::execute(handle)),
-            // a if a == hash(1026) => Some(ECRecoverPublicKey::execute(handle)),
-            _ => None,
+            .with(hash(1026), PrecompileEntry::new::<ECRecoverPublicKey>());
+
+        #[cfg(feature = "sha3fips")]
+        {
+            registry.register(hash(1024), PrecompileEntry::new::<Sha3FIPS256>().inactive());
+            registry.register(hash(1025), PrecompileEntry::new::<Sha3FIPS512>().inactive());
+        }
+
+        #[cfg(feature = "curve25519")]
+        {
+            registry.register(hash(1027), PrecompileEntry::new::<Curve25519Add>().inactive());
+            registry.register(hash(1028), PrecompileEntry::new::<Curve25519ScalarMul>().inactive());
+        }
+
+        registry
+    }
+
+    /// Applies provided configuration. Returns error if configuration refers
+    /// to precompile, which is not registered
+    pub fn configure(&mut self, config: &PrecompileConfig) -> Result<(), String> {
+        for (address, params) in config.overrides.iter() {
+            if params.disabled {
+                self.entries.remove(address);
+                continue;
+            }
+
+            let entry = self
+                .entries
+                .get_mut(address)
+                .ok_or_else(|| format!("cannot configure precompile {:?}: precompile is not registered", address))?;
+            entry.activation_height = Some(params.activation_height);
+            entry.gas_override = params.gas_override;
         }
+
+        Ok(())
+    }
+
+    /// Registers precompile at provided address. Previously registered precompile is replaced
+    pub fn with(mut self, address: H160, entry: PrecompileEntry) -> Self {
+        self.register(address, entry);
+        self
+    }
+
+    /// Registers precompile at provided address. Previously registered precompile is replaced
+    pub fn register(&mut self, address: H160, entry: PrecompileEntry) {
+        self.entries.insert(address, entry);
+    }
+
+    /// Checks if precompile is registered at provided address regardless of its activation height
+    pub fn contains(&self, address: &H160) -> bool {
+        self.entries.contains_key(address)
+    }
+
+    /// Removes precompile from provided address
+    pub fn remove(&mut self, address: &H160) -> Option<PrecompileEntry> {
+        self.entries.remove(address)
+    }
+
+    /// Returns precompile available at provided address and block
+    pub fn get(&self, address: &H160, block_number: U256) -> Option<&PrecompileEntry> {
+        self.entries.get(address).filter(|entry| entry.is_active(block_number))
+    }
+
+    /// Returns addresses of all precompiles available at provided block
+    pub fn active_addresses(&self, block_number: U256) -> Vec<H160> {
+        self.entries
+            .iter()
+            .filter(|(_, entry)| entry.is_active(block_number))
+            .map(|(address, _)| *address)
+            .collect()
+    }
+}
+
+/// Set of precompiles used by EVM during execution of single transaction
+pub struct EVMPrecompiles<'a> {
+    registry: &'a PrecompileRegistry,
+    block_number: U256,
+}
+
+impl<'a> EVMPrecompiles<'a> {
+    pub fn new(registry: &'a PrecompileRegistry, block_number: U256) -> Self {
+        Self { registry, block_number }
+    }
+}
+
+impl<'a> PrecompileSet for EVMPrecompiles<'a> {
+    fn execute(&self, handle: &mut impl PrecompileHandle) -> Option<PrecompileResult> {
+        let entry = self.registry.get(&handle.code_address(), self.block_number)?;
+        if let Some(gas) = entry.gas_override {
+            if let Err(exit_status) = handle.record_cost(gas) {
+                return Some(Err(exit_status.into()));
+            }
+        }
+
+        let mut handle = PrecompileHandleRef {
+            inner: handle,
+            charge_cost: entry.gas_override.is_none(),
+        };
+        Some((entry.execute)(&mut handle))
     }
 
     fn is_precompile(&self, address: H160, _gas: u64) -> IsPrecompileResult {
 		IsPrecompileResult::Answer {
-			is_precompile: Self::used_addresses().contains(&address),
+			is_precompile: self.registry.get(&address, self.block_number).is_some(),
 			extra_cost: 0,
 		}
     }
 }
 
+/// Sized wrapper around handle provided by executor, which allows to store precompiles
+/// as plain function pointers. If precompile has gas override, costs recorded by precompile
+/// itself are ignored
+pub struct PrecompileHandleRef<'a> {
+    inner: &'a mut dyn PrecompileHandle,
+    charge_cost: bool,
+}
+
+impl<'a> PrecompileHandle for PrecompileHandleRef<'a> {
+    fn call(
+        &mut self,
+        to: H160,
+        transfer: Option<Transfer>,
+        input: Vec<u8>,
+        gas_limit: Option<u64>,
+        is_static: bool,
+        context: &Context,
+    ) -> (ExitReason, Vec<u8>) {
+        self.inner.call(to, transfer, input, gas_limit, is_static, context)
+    }
+
+    fn record_cost(&mut self, cost: u64) -> Result<(), ExitError> {
+        match self.charge_cost {
+            true => self.inner.record_cost(cost),
+            false => Ok(()),
+        }
+    }
+
+    fn record_external_cost(&mut self, ref_time: Option<u64>, proof_size: Option<u64>) -> Result<(), ExitError> {
+        self.inner.record_external_cost(ref_time, proof_size)
+    }
+
+    fn refund_external_cost(&mut self, ref_time: Option<u64>, proof_size: Option<u64>) {
+        self.inner.refund_external_cost(ref_time, proof_size)
+    }
+
+    fn remaining_gas(&self) -> u64 {
+        self.inner.remaining_gas()
+    }
+
+    fn log(&mut self, address: H160, topics: Vec<H256>, data: Vec<u8>) -> Result<(), ExitError> {
+        self.inner.log(address, topics, data)
+    }
+
+    fn code_address(&self) -> H160 {
+        self.inner.code_address()
+    }
+
+    fn input(&self) -> &[u8] {
+        self.inner.input()
+    }
+
+    fn context(&self) -> &Context {
+        self.inner.context()
+    }
+
+    fn is_static(&self) -> bool {
+        self.inner.is_static()
+    }
+
+    fn gas_limit(&self) -> Option<u64> {
+        // Cost of overridden precompile is already charged, so precompile should not check it
+        match self.charge_cost {
+            true => self.inner.gas_limit(),
+            false => None,
+        }
+    }
+}
+
+fn execute_precompile<P: Precompile>(handle: &mut PrecompileHandleRef) -> PrecompileResult {
+    P::execute(handle)
+}
+
 #[inline]
 fn hash(a: u64) -> H160 {
     H160::from_low_u64_be(a)
 }
+
+#[cfg(test)]
+mod test {
+    use super::{PrecompileConfig, PrecompileEntry, PrecompileOverride, PrecompileRegistry};
+    use crate::backend::{Backend, EvmFork};
+    use crate::storage::mocked_storage::MockedStorage;
+    use crate::{handle_sgxvm_call, Vicinity};
+    use evm_precompile_simple::Identity;
+    use primitive_types::{H160, U256};
+
+    #[test]
+    fn test_precompile_registry() {
+        let identity = H160::from_low_u64_be(4);
+        let custom = H160::from_low_u64_be(0x0c00);
+
+        let mut registry = PrecompileRegistry::for_fork(EvmFork::default());
+        let active = registry.active_addresses(U256::zero()).len();
+        assert!(registry.get(&identity, U256::zero()).is_some());
+
+        // Forks, which cannot be executed, have no precompiles
+        assert!(PrecompileRegistry::for_fork(EvmFork::Unknown(10)).active_addresses(U256::zero()).is_empty());
+
+        // Precompile is not available before activation height
+        registry.register(custom, PrecompileEntry::new::<Identity>().with_activation_height(100).with_gas_override(10));
+        assert!(registry.get(&custom, U256::from(99)).is_none());
+        assert_eq!(registry.get(&custom, U256::from(100)).unwrap().gas_override, Some(10));
+        assert_eq!(registry.active_addresses(U256::from(100)).len(), active + 1);
+
+        registry.remove(&identity);
+        assert!(registry.get(&identity, U256::zero()).is_none());
+        assert_eq!(PrecompileEntry::new::<Identity>().activation_height, Some(0));
+
+        // Inactive precompile is available only after it is activated by configuration
+        registry.register(custom, PrecompileEntry::new::<Identity>().inactive());
+        assert!(registry.get(&custom, U256::from(u64::MAX)).is_none());
+        assert!(registry.contains(&custom));
+        let mut config = PrecompileConfig::default();
+        config.overrides.insert(custom, PrecompileOverride { activation_height: 5, ..Default::default() });
+        registry.configure(&config).unwrap();
+        assert!(registry.get(&custom, U256::from(4)).is_none());
+        assert!(registry.get(&custom, U256::from(5)).is_some());
+    }
+
+    #[test]
+    fn test_precompile_config() {
+        let identity = H160::from_low_u64_be(4);
+        let sha256 = H160::from_low_u64_be(2);
+
+        let mut config = PrecompileConfig::default();
+        config.overrides.insert(
+            identity,
+            PrecompileOverride { activation_height: 10, gas_override: Some(100), disabled: false },
+        );
+        config.overrides.insert(sha256, PrecompileOverride { disabled: true, ..Default::default() });
+
+        let mut registry = PrecompileRegistry::for_fork(EvmFork::default());
+        registry.configure(&config).unwrap();
+        assert!(registry.get(&identity, U256::from(9)).is_none());
+        assert_eq!(registry.get(&identity, U256::from(10)).unwrap().gas_override, Some(100));
+        assert!(registry.get(&sha256, U256::from(10)).is_none());
+
+        // Precompile, which is not registered, cannot be configured
+        let mut config = PrecompileConfig::default();
+        config.overrides.insert(H160::from_low_u64_be(0x0c00), PrecompileOverride::default());
+        assert!(registry.configure(&config).is_err());
+    }
+
+    #[test]
+    fn test_execution_uses_backend_registry() {
+        let sender = H160::from_low_u64_be(0xbeef);
+        let custom = H160::from_low_u64_be(0x0c00);
+        let vicinity = Vicinity { origin: sender };
+        let mut storage = MockedStorage::default();
+        let mut backend = Backend::new(vicinity, &mut storage);
+        backend.precompiles.register(custom, PrecompileEntry::new::<Identity>().with_gas_override(1000));
+
+        let input = hex::decode("deadbeef").unwrap();
+        let result = handle_sgxvm_call(&mut backend, 100_000, sender, custom, U256::zero(), input.clone(), vec![], false);
+        assert_eq!(result.vm_error, "");
+        assert_eq!(result.data, input);
+        // Overridden cost is charged instead of own cost of precompile
+        assert_eq!(result.gas_used, 21000 + 16 * 4 + 1000);
+    }
+}
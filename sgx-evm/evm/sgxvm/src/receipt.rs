@@ -0,0 +1,175 @@
+use ethereum::{EIP658ReceiptData, EnvelopedEncodable, Log, ReceiptV3};
+use evm::ExitReason;
+use internal_types::ExecutionResult;
+use primitive_types::U256;
+use sha3::{Digest, Keccak256};
+
+use std::{string::String, vec::Vec};
+
+/// Size of logs bloom in bytes (2048 bits)
+pub const BLOOM_SIZE: usize = 256;
+
+/// Type of legacy transaction
+pub const LEGACY_TX_TYPE: u8 = 0;
+/// Type of EIP-2930 transaction
+pub const EIP2930_TX_TYPE: u8 = 1;
+/// Type of EIP-1559 transaction
+pub const EIP1559_TX_TYPE: u8 = 2;
+
+/// Receipt of executed transaction
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Receipt {
+    /// Type of transaction: 0 - legacy, 1 - EIP-2930, 2 - EIP-1559
+    pub tx_type: u8,
+    /// `true` if transaction was executed successfully
+    pub status: bool,
+    /// Gas used by this and all previous transactions of the block
+    pub cumulative_gas_used: u64,
+    pub logs_bloom: [u8; BLOOM_SIZE],
+    pub logs: Vec<Log>,
+}
+
+impl Receipt {
+    /// Builds receipt from result of transaction execution
+    pub fn new(tx_type: u8, execution_result: &ExecutionResult, cumulative_gas_used: u64) -> Self {
+        Self {
+            tx_type,
+            status: matches!(execution_result.exit_reason, Some(ExitReason::Succeed(_))),
+            cumulative_gas_used,
+            logs_bloom: logs_bloom(&execution_result.logs),
+            logs: execution_result.logs.clone(),
+        }
+    }
+
+    /// Returns RLP-encoded receipt. Receipts of typed transactions are prefixed with
+    /// transaction type (EIP-2718). Transaction type is expected to be checked by `parse_tx_type`
+    pub fn encode(&self) -> Vec<u8> {
+        let data = EIP658ReceiptData {
+            status_code: self.status as u8,
+            used_gas: U256::from(self.cumulative_gas_used),
+            logs_bloom: self.logs_bloom.into(),
+            logs: self.logs.clone(),
+        };
+
+        let receipt = match self.tx_type {
+            EIP2930_TX_TYPE => ReceiptV3::EIP2930(data),
+            EIP1559_TX_TYPE => ReceiptV3::EIP1559(data),
+            _ => ReceiptV3::Legacy(data),
+        };
+
+        receipt.encode().to_vec()
+    }
+}
+
+/// Converts transaction type provided by the host. Only legacy, EIP-2930 and EIP-1559
+/// transactions are supported, other types are rejected
+pub fn parse_tx_type(tx_type: u32) -> Result<u8, String> {
+    match tx_type {
+        0 => Ok(LEGACY_TX_TYPE),
+        1 => Ok(EIP2930_TX_TYPE),
+        2 => Ok(EIP1559_TX_TYPE),
+        _ => Err(format!("unsupported transaction type: {}", tx_type)),
+    }
+}
+
+/// Calculates 2048-bit bloom filter of log addresses and topics
+pub fn logs_bloom(logs: &[Log]) -> [u8; BLOOM_SIZE] {
+    let mut bloom = [0u8; BLOOM_SIZE];
+    for log in logs {
+        accrue_bloom(&mut bloom, log.address.as_bytes());
+        for topic in log.topics.iter() {
+            accrue_bloom(&mut bloom, topic.as_bytes());
+        }
+    }
+
+    bloom
+}
+
+/// Sets 3 bits of bloom, which are selected by first 6 bytes of keccak256 hash of input
+fn accrue_bloom(bloom: &mut [u8; BLOOM_SIZE], input: &[u8]) {
+    let hash = Keccak256::digest(input);
+    for i in [0usize, 2, 4] {
+        let bit = (((hash[i] as usize) << 8) | hash[i + 1] as usize) & (BLOOM_SIZE * 8 - 1);
+        bloom[BLOOM_SIZE - 1 - bit / 8] |= 1 << (bit % 8);
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::{logs_bloom, parse_tx_type, Receipt, BLOOM_SIZE, EIP1559_TX_TYPE, EIP2930_TX_TYPE, LEGACY_TX_TYPE};
+    use ethereum::Log;
+    use evm::{ExitReason, ExitRevert, ExitSucceed};
+    use internal_types::ExecutionResult;
+    use primitive_types::{H160, H256};
+    use std::{string::String, vec::Vec};
+
+    /// Result of successful transaction, which emitted single log with one topic
+    fn execution_result() -> ExecutionResult {
+        let mut result = ExecutionResult::from_error(String::default(), Vec::default(), Some(22000));
+        result.exit_reason = Some(ExitReason::Succeed(ExitSucceed::Stopped));
+        result.logs = vec![Log {
+            address: H160::from_low_u64_be(0xc0de),
+            topics: vec![H256::from_low_u64_be(0xaa)],
+            data: Vec::default(),
+        }];
+        result
+    }
+
+    #[test]
+    fn test_logs_bloom() {
+        assert_eq!(logs_bloom(&[]), [0u8; BLOOM_SIZE]);
+
+        // Address sets bits 282, 992, 1513 and topic sets bits 910, 947, 1293
+        let mut expected = [0u8; BLOOM_SIZE];
+        expected[66] = 0x02;
+        expected[94] = 0x20;
+        expected[131] = 0x01;
+        expected[137] = 0x08;
+        expected[142] = 0x40;
+        expected[220] = 0x04;
+        assert_eq!(logs_bloom(&execution_result().logs), expected);
+    }
+
+    #[test]
+    fn test_receipt_encoding() {
+        let result = execution_result();
+        // Transaction is the first one in block, so cumulative gas is equal to its gas
+        let receipt = Receipt::new(LEGACY_TX_TYPE, &result, result.gas_used);
+        assert!(receipt.status);
+        assert_eq!(receipt.cumulative_gas_used, 22000);
+
+        let expected = hex::decode(
+            "f90143018255f0b9010000000000000000000000000000000000000000000000000000000000000000000000\
+             0000000000000000000000000000000000000000000000000000000000000000020000000000000000000000\
+             0000000000000000000000000000000020000000000000000000000000000000000000000000000000000000\
+             0000000000000000000100000000000800000000400000000000000000000000000000000000000000000000\
+             0000000000000000000000000000000000000000000000000000000000000000000000000000000000000000\
+             0000000000000000000004000000000000000000000000000000000000000000000000000000000000000000\
+             0000f83af83894000000000000000000000000000000000000c0dee1a0000000000000000000000000000000\
+             00000000000000000000000000000000aa80"
+        ).unwrap();
+        assert_eq!(receipt.encode(), expected);
+
+        // Typed receipts are prefixed with transaction type
+        for tx_type in [EIP2930_TX_TYPE, EIP1559_TX_TYPE] {
+            let encoded = Receipt::new(tx_type, &result, result.gas_used).encode();
+            assert_eq!(encoded[0], tx_type);
+            assert_eq!(&encoded[1..], &expected[..]);
+        }
+
+        // Reverted transaction has zero status
+        let mut reverted = result.clone();
+        reverted.exit_reason = Some(ExitReason::Revert(ExitRevert::Reverted));
+        assert!(!Receipt::new(LEGACY_TX_TYPE, &reverted, reverted.gas_used).status);
+    }
+
+    #[test]
+    fn test_parse_tx_type() {
+        assert_eq!(parse_tx_type(0), Ok(LEGACY_TX_TYPE));
+        assert_eq!(parse_tx_type(1), Ok(EIP2930_TX_TYPE));
+        assert_eq!(parse_tx_type(2), Ok(EIP1559_TX_TYPE));
+        assert!(parse_tx_type(3).is_err());
+        // Type is not truncated to u8
+        assert!(parse_tx_type(0x102).is_err());
+    }
+}
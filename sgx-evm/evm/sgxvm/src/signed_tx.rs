@@ -0,0 +1,275 @@
+use core::fmt;
+use ethereum::{
+    AccessListItem, EIP1559TransactionMessage, EIP2930TransactionMessage, EnvelopedDecodable,
+    LegacyTransactionMessage, TransactionAction, TransactionV2,
+};
+use k256::ecdsa::recoverable;
+use primitive_types::{H160, H256, U256};
+use sha3::{Digest, Keccak256};
+
+use std::vec::Vec;
+
+use crate::receipt::{EIP1559_TX_TYPE, EIP2930_TX_TYPE};
+
+/// Transaction recovered from RLP-encoded signed Ethereum transaction
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct SignedTransaction {
+    /// Type of transaction: 0 - legacy, 1 - EIP-2930, 2 - EIP-1559
+    pub tx_type: u8,
+    /// Hash of signed transaction
+    pub hash: H256,
+    /// Sender recovered from signature
+    pub from: H160,
+    /// Recipient. `None` for contract creation
+    pub to: Option<H160>,
+    pub nonce: U256,
+    pub gas_limit: u64,
+    pub value: U256,
+    pub data: Vec<u8>,
+    pub access_list: Vec<(H160, Vec<H256>)>,
+}
+
+/// Error returned if signed transaction cannot be accepted
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum SignedTransactionError {
+    /// Transaction is not valid RLP-encoded legacy, EIP-2930 or EIP-1559 transaction
+    Decode,
+    /// Legacy transaction without chain id (not protected by EIP-155)
+    UnprotectedTransaction,
+    /// Transaction was signed for another chain
+    InvalidChainId { expected: u64, got: u64 },
+    /// Signature is malformed, has high `s` value or sender cannot be recovered
+    InvalidSignature,
+    /// Gas limit does not fit into u64
+    GasLimitOverflow,
+}
+
+impl fmt::Display for SignedTransactionError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            SignedTransactionError::Decode => write!(f, "cannot decode signed transaction"),
+            SignedTransactionError::UnprotectedTransaction => {
+                write!(f, "only replay-protected (EIP-155) transactions allowed")
+            }
+            SignedTransactionError::InvalidChainId { expected, got } => {
+                write!(f, "invalid chain id: have {} want {}", got, expected)
+            }
+            SignedTransactionError::InvalidSignature => write!(f, "invalid transaction signature"),
+            SignedTransactionError::GasLimitOverflow => write!(f, "gas limit overflow"),
+        }
+    }
+}
+
+impl SignedTransaction {
+    /// Decodes RLP-encoded signed legacy, EIP-2930 or EIP-1559 transaction, checks its chain id
+    /// and recovers sender from signature
+    pub fn recover(raw: &[u8], chain_id: u64) -> Result<Self, SignedTransactionError> {
+        let transaction = TransactionV2::decode(raw).map_err(|_| SignedTransactionError::Decode)?;
+        let hash = transaction.hash();
+
+        let (mut signed_tx, tx_chain_id, message_hash, signature) = match transaction {
+            TransactionV2::Legacy(tx) => {
+                let tx_chain_id = tx.signature.chain_id().ok_or(SignedTransactionError::UnprotectedTransaction)?;
+                let signature = encode_signature(tx.signature.r(), tx.signature.s(), tx.signature.standard_v());
+                let signed_tx = Self::without_sender(hash, tx.nonce, tx.gas_limit, &tx.action, tx.value, &tx.input, &[])?;
+                (signed_tx, tx_chain_id, LegacyTransactionMessage::from(tx).hash(), signature)
+            }
+            TransactionV2::EIP2930(tx) => {
+                let signature = encode_signature(&tx.r, &tx.s, tx.odd_y_parity as u8);
+                let mut signed_tx = Self::without_sender(
+                    hash, tx.nonce, tx.gas_limit, &tx.action, tx.value, &tx.input, &tx.access_list,
+                )?;
+                signed_tx.tx_type = EIP2930_TX_TYPE;
+                (signed_tx, tx.chain_id, EIP2930TransactionMessage::from(tx).hash(), signature)
+            }
+            TransactionV2::EIP1559(tx) => {
+                let signature = encode_signature(&tx.r, &tx.s, tx.odd_y_parity as u8);
+                let mut signed_tx = Self::without_sender(
+                    hash, tx.nonce, tx.gas_limit, &tx.action, tx.value, &tx.input, &tx.access_list,
+                )?;
+                signed_tx.tx_type = EIP1559_TX_TYPE;
+                (signed_tx, tx.chain_id, EIP1559TransactionMessage::from(tx).hash(), signature)
+            }
+        };
+
+        if tx_chain_id != chain_id {
+            return Err(SignedTransactionError::InvalidChainId { expected: chain_id, got: tx_chain_id });
+        }
+
+        signed_tx.from = recover_signer(&signature, message_hash)?;
+        Ok(signed_tx)
+    }
+
+    /// Converts fields of decoded transaction. Sender should be set after signature verification
+    fn without_sender(
+        hash: H256,
+        nonce: U256,
+        gas_limit: U256,
+        action: &TransactionAction,
+        value: U256,
+        input: &[u8],
+        access_list: &[AccessListItem],
+    ) -> Result<Self, SignedTransactionError> {
+        if gas_limit > U256::from(u64::MAX) {
+            return Err(SignedTransactionError::GasLimitOverflow);
+        }
+
+        let to = match action {
+            TransactionAction::Call(to) => Some(*to),
+            TransactionAction::Create => None,
+        };
+
+        Ok(Self {
+            tx_type: 0,
+            hash,
+            from: H160::zero(),
+            to,
+            nonce,
+            gas_limit: gas_limit.as_u64(),
+            value,
+            data: input.to_vec(),
+            access_list: access_list.iter().map(|item| (item.address, item.storage_keys.clone())).collect(),
+        })
+    }
+}
+
+/// Encodes signature as `r || s || v`, where `v` is recovery id (0 or 1)
+fn encode_signature(r: &H256, s: &H256, recovery_id: u8) -> [u8; 65] {
+    let mut signature = [0u8; 65];
+    signature[0..32].copy_from_slice(r.as_bytes());
+    signature[32..64].copy_from_slice(s.as_bytes());
+    signature[64] = recovery_id;
+    signature
+}
+
+/// Recovers address of account, which signed provided message hash
+fn recover_signer(signature: &[u8; 65], message_hash: H256) -> Result<H160, SignedTransactionError> {
+    let signature =
+        recoverable::Signature::try_from(&signature[..]).map_err(|_| SignedTransactionError::InvalidSignature)?;
+
+    // Signatures with high `s` value are malleable and rejected since Homestead (EIP-2)
+    if signature.s().is_high().into() {
+        return Err(SignedTransactionError::InvalidSignature);
+    }
+
+    let verifying_key = signature
+        .recover_verifying_key_from_digest_bytes(&message_hash.to_fixed_bytes().into())
+        .map_err(|_| SignedTransactionError::InvalidSignature)?;
+
+    let public_key = verifying_key.to_encoded_point(false);
+    let hash = Keccak256::digest(&public_key.as_bytes()[1..]);
+    Ok(H160::from_slice(&hash[12..]))
+}
+
+#[cfg(test)]
+mod test {
+    use super::{SignedTransaction, SignedTransactionError};
+    use crate::receipt::{EIP1559_TX_TYPE, EIP2930_TX_TYPE};
+    use primitive_types::{H160, H256, U256};
+
+    /// Address of private key 0x4646...46, which was used to sign all test transactions
+    fn signer() -> H160 {
+        H160::from_slice(&hex::decode("9d8a62f656a8d1615c1294fd71e9cfb3e4855a4f").unwrap())
+    }
+
+    #[test]
+    fn test_legacy_transaction() {
+        // Example transaction from EIP-155
+        let raw = hex::decode(
+            "f86c098504a817c800825208943535353535353535353535353535353535353535880de0b6b3a7640000\
+             8025a028ef61340bd939bc2195fe537567866003e1a15d3c71ff63e1590620aa636276a067cbe9d8997f76\
+             1aecb703304b3800ccf555c9f3dc64214b297fb1966a3b6d83"
+        ).unwrap();
+
+        let transaction = SignedTransaction::recover(&raw, 1).unwrap();
+        assert_eq!(transaction.tx_type, 0);
+        assert_eq!(transaction.from, signer());
+        assert_eq!(transaction.to, Some(H160::from_slice(&[0x35u8; 20])));
+        assert_eq!(transaction.nonce, U256::from(9));
+        assert_eq!(transaction.gas_limit, 21000);
+        assert_eq!(transaction.value, U256::from(1_000_000_000_000_000_000u64));
+        assert!(transaction.data.is_empty());
+
+        // Transaction signed for another chain is rejected
+        assert_eq!(
+            SignedTransaction::recover(&raw, 2).unwrap_err(),
+            SignedTransactionError::InvalidChainId { expected: 2, got: 1 }
+        );
+
+        // Signature of transaction with modified nonce recovers another sender
+        let mut modified = raw.clone();
+        modified[2] = 0x0a;
+        let modified_transaction = SignedTransaction::recover(&modified, 1).unwrap();
+        assert_eq!(modified_transaction.nonce, U256::from(10));
+        assert_eq!(
+            modified_transaction.from,
+            H160::from_slice(&hex::decode("a27eeb918f11c192c0ecaf22037d84b87c831d68").unwrap())
+        );
+
+        assert_eq!(SignedTransaction::recover(&[0x01, 0x02], 1).unwrap_err(), SignedTransactionError::Decode);
+    }
+
+    #[test]
+    fn test_eip2930_transaction() {
+        let raw = hex::decode(
+            "01f8a282050b01843b9aca0082753094353535353535353535353535353535353535353501821234f838f7\
+             941111111111111111111111111111111111111111e1a00000000000000000000000000000000000000000\
+             00000000000000000000000101a0ff23c8df7435ec1a40a0d0148c48e8fed8b4efc9c04d1d9ff9ae916e5a\
+             54a29ea027e68eab641b482c939806250038e007578a558a0ab825e487d7acd3b5470a10"
+        ).unwrap();
+
+        let transaction = SignedTransaction::recover(&raw, 1291).unwrap();
+        assert_eq!(transaction.tx_type, EIP2930_TX_TYPE);
+        assert_eq!(
+            transaction.hash,
+            H256::from_slice(&hex::decode("b6e421d0a437e889fbafcd4c674b23c209d819a61964871b209be1d93ecf8afe").unwrap())
+        );
+        assert_eq!(transaction.from, signer());
+        assert_eq!(transaction.to, Some(H160::from_slice(&[0x35u8; 20])));
+        assert_eq!(transaction.nonce, U256::one());
+        assert_eq!(transaction.gas_limit, 30000);
+        assert_eq!(transaction.value, U256::one());
+        assert_eq!(transaction.data, vec![0x12, 0x34]);
+        assert_eq!(
+            transaction.access_list,
+            vec![(H160::from_slice(&[0x11u8; 20]), vec![H256::from_low_u64_be(1)])]
+        );
+
+        assert_eq!(
+            SignedTransaction::recover(&raw, 1).unwrap_err(),
+            SignedTransactionError::InvalidChainId { expected: 1, got: 1291 }
+        );
+    }
+
+    #[test]
+    fn test_eip1559_transaction() {
+        let raw = hex::decode(
+            "02f85a82050b02843b9aca00847735940082ea608080826000c080a09881b1ec35477ca542ae8231c17134\
+             9dcfd318b007242fdd3241c038c3a2f8f4a06de2d61336d23f399624ad6e5da5a2cbc23d6d0e566e5a1dc3\
+             84e37901314fcd"
+        ).unwrap();
+
+        let transaction = SignedTransaction::recover(&raw, 1291).unwrap();
+        assert_eq!(transaction.tx_type, EIP1559_TX_TYPE);
+        assert_eq!(transaction.from, signer());
+        assert_eq!(transaction.to, None);
+        assert_eq!(transaction.nonce, U256::from(2));
+        assert_eq!(transaction.gas_limit, 60000);
+        assert_eq!(transaction.value, U256::zero());
+        assert_eq!(transaction.data, vec![0x60, 0x00]);
+        assert!(transaction.access_list.is_empty());
+    }
+
+    #[test]
+    fn test_high_s_signature_rejected() {
+        // Same EIP-1559 transaction with malleated signature (s' = n - s, flipped y parity),
+        // which recovers the same sender
+        let raw = hex::decode(
+            "02f85a82050b02843b9aca00847735940082ea608080826000c001a09881b1ec35477ca542ae8231c17134\
+             9dcfd318b007242fdd3241c038c3a2f8f4a0921d29ecc92dc0c669db5291a25a5d32f8716fd858da461dfc\
+             4d7b13cf04f174"
+        ).unwrap();
+
+        assert_eq!(SignedTransaction::recover(&raw, 1291).unwrap_err(), SignedTransactionError::InvalidSignature);
+    }
+}
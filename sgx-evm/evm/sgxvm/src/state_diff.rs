@@ -0,0 +1,93 @@
+use evm::backend::{Apply, Backend as EvmBackend};
+use primitive_types::{H160, H256, U256};
+
+use std::vec::Vec;
+
+/// Change of single storage slot
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct StorageDiff {
+    pub index: H256,
+    pub original_value: H256,
+    pub value: H256,
+}
+
+/// Changes of single account, which transaction would apply to the state
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct AccountDiff {
+    pub address: H160,
+    pub balance_before: U256,
+    pub balance_after: U256,
+    pub nonce_before: U256,
+    pub nonce_after: U256,
+    // New contract bytecode, if it was changed
+    pub code: Option<Vec<u8>>,
+    // Set if account would be removed (SELFDESTRUCT)
+    pub deleted: bool,
+    // Storage slots touched by transaction
+    pub storage: Vec<StorageDiff>,
+}
+
+/// Set of changes, which transaction would apply to the state
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct StateDiff {
+    pub accounts: Vec<AccountDiff>,
+}
+
+impl StateDiff {
+    /// Builds state diff by comparing changes produced by executor with current state of the backend
+    pub fn from_changes<B, A, I>(backend: &B, values: A) -> Self
+    where
+        B: EvmBackend,
+        A: IntoIterator<Item = Apply<I>>,
+        I: IntoIterator<Item = (H256, H256)>,
+    {
+        let mut accounts = Vec::new();
+
+        for apply in values {
+            match apply {
+                Apply::Modify { address, basic, code, storage, .. } => {
+                    let previous_account_data = backend.basic(address);
+                    let storage: Vec<StorageDiff> = storage
+                        .into_iter()
+                        .map(|(index, value)| StorageDiff {
+                            index,
+                            original_value: backend.storage(address, index),
+                            value,
+                        })
+                        .collect();
+
+                    let is_changed = basic.balance != previous_account_data.balance
+                        || basic.nonce != previous_account_data.nonce
+                        || code.is_some()
+                        || !storage.is_empty();
+                    if !is_changed {
+                        continue;
+                    }
+
+                    accounts.push(AccountDiff {
+                        address,
+                        balance_before: previous_account_data.balance,
+                        balance_after: basic.balance,
+                        nonce_before: previous_account_data.nonce,
+                        nonce_after: basic.nonce,
+                        code,
+                        deleted: false,
+                        storage,
+                    });
+                }
+                Apply::Delete { address } => {
+                    let previous_account_data = backend.basic(address);
+                    accounts.push(AccountDiff {
+                        address,
+                        balance_before: previous_account_data.balance,
+                        nonce_before: previous_account_data.nonce,
+                        deleted: true,
+                        ..Default::default()
+                    });
+                }
+            }
+        }
+
+        Self { accounts }
+    }
+}
@@ -0,0 +1,191 @@
+use evm::backend::Basic;
+use primitive_types::{H160, H256};
+
+use std::{
+    collections::{BTreeMap, BTreeSet},
+    string::String,
+    vec::Vec,
+};
+
+use super::{BatchReadRequest, BatchReadResponse, Storage, WriteSet};
+
+/// Storage wrapper, which keeps all writes in memory until `flush` is called.
+/// Allows to execute several transactions against the same state and write
+/// their changes into inner storage at once
+pub struct BufferedStorage<'a> {
+    inner: &'a mut dyn Storage,
+    accounts: BTreeMap<H160, Basic>,
+    codes: BTreeMap<H160, Vec<u8>>,
+    // `None` marks removed storage cell
+    storage_cells: BTreeMap<(H160, H256), Option<H256>>,
+    removed: BTreeSet<H160>,
+    // Accounts, which storage was reset
+    reset_storage: BTreeSet<H160>,
+}
+
+impl<'a> BufferedStorage<'a> {
+    pub fn new(inner: &'a mut dyn Storage) -> Self {
+        Self {
+            inner,
+            accounts: BTreeMap::new(),
+            codes: BTreeMap::new(),
+            storage_cells: BTreeMap::new(),
+            removed: BTreeSet::new(),
+            reset_storage: BTreeSet::new(),
+        }
+    }
+
+    /// Writes all buffered changes into inner storage at once. Buffer is emptied
+    /// even if inner storage fails to write changes
+    pub fn flush(&mut self) -> Result<(), String> {
+        let write_set = WriteSet {
+            removed: core::mem::take(&mut self.removed).into_iter().collect(),
+            reset_storage: core::mem::take(&mut self.reset_storage).into_iter().collect(),
+            accounts: core::mem::take(&mut self.accounts).into_iter().collect(),
+            codes: core::mem::take(&mut self.codes).into_iter().collect(),
+            storage_cells: core::mem::take(&mut self.storage_cells)
+                .into_iter()
+                .map(|((key, index), value)| (key, index, value))
+                .collect(),
+        };
+
+        match write_set.is_empty() {
+            true => Ok(()),
+            false => self.inner.commit(write_set),
+        }
+    }
+}
+
+impl<'a> Storage for BufferedStorage<'a> {
+    fn contains_key(&self, key: &H160) -> bool {
+        if self.accounts.contains_key(key) || self.codes.contains_key(key) {
+            return true;
+        }
+
+        !self.removed.contains(key) && self.inner.contains_key(key)
+    }
+
+    fn get_account_storage_cell(&self, key: &H160, index: &H256) -> Option<H256> {
+        if let Some(value) = self.storage_cells.get(&(*key, *index)) {
+            return *value;
+        }
+
+        match self.removed.contains(key) || self.reset_storage.contains(key) {
+            true => None,
+            false => self.inner.get_account_storage_cell(key, index),
+        }
+    }
+
+    fn get_account_code(&self, key: &H160) -> Option<Vec<u8>> {
+        if let Some(code) = self.codes.get(key) {
+            return Some(code.clone());
+        }
+
+        match self.removed.contains(key) {
+            true => None,
+            false => self.inner.get_account_code(key),
+        }
+    }
+
+    fn get_account(&self, account: &H160) -> Basic {
+        if let Some(basic) = self.accounts.get(account) {
+            return basic.clone();
+        }
+
+        match self.removed.contains(account) {
+            true => Basic::default(),
+            false => self.inner.get_account(account),
+        }
+    }
+
+    fn insert_account(&mut self, key: H160, data: Basic) {
+        self.accounts.insert(key, data);
+    }
+
+    fn insert_account_code(&mut self, key: H160, code: Vec<u8>) {
+        self.codes.insert(key, code);
+    }
+
+    fn insert_storage_cell(&mut self, key: H160, index: H256, value: H256) {
+        self.storage_cells.insert((key, index), Some(value));
+    }
+
+    fn remove(&mut self, key: &H160) {
+        self.accounts.remove(key);
+        self.codes.remove(key);
+        self.storage_cells.retain(|(address, _), _| address != key);
+        self.removed.insert(*key);
+    }
+
+    fn remove_storage_cell(&mut self, key: &H160, index: &H256) {
+        self.storage_cells.insert((*key, *index), None);
+    }
+
+    fn remove_storage(&mut self, key: &H160) {
+        self.storage_cells.retain(|(address, _), _| address != key);
+        self.reset_storage.insert(*key);
+    }
+
+    fn get_batch(&self, request: &BatchReadRequest) -> BatchReadResponse {
+        // Entries, which are not overridden by buffered writes, are read from inner storage at once
+        let inner_request = BatchReadRequest {
+            accounts: request
+                .accounts
+                .iter()
+                .filter(|key| !self.accounts.contains_key(*key) && !self.removed.contains(*key))
+                .copied()
+                .collect(),
+            codes: request
+                .codes
+                .iter()
+                .filter(|key| !self.codes.contains_key(*key) && !self.removed.contains(*key))
+                .copied()
+                .collect(),
+            storage_cells: request
+                .storage_cells
+                .iter()
+                .filter(|(key, index)| {
+                    !self.storage_cells.contains_key(&(*key, *index))
+                        && !self.removed.contains(key)
+                        && !self.reset_storage.contains(key)
+                })
+                .copied()
+                .collect(),
+        };
+
+        let fetched = match inner_request.is_empty() {
+            true => BatchReadResponse::default(),
+            false => self.inner.get_batch(&inner_request),
+        };
+        let accounts: BTreeMap<_, _> = inner_request.accounts.into_iter().zip(fetched.accounts).collect();
+        let codes: BTreeMap<_, _> = inner_request.codes.into_iter().zip(fetched.codes).collect();
+        let storage_cells: BTreeMap<_, _> = inner_request
+            .storage_cells
+            .into_iter()
+            .zip(fetched.storage_cells)
+            .collect();
+
+        BatchReadResponse {
+            accounts: request
+                .accounts
+                .iter()
+                .map(|key| accounts.get(key).cloned().unwrap_or_else(|| self.get_account(key)))
+                .collect(),
+            codes: request
+                .codes
+                .iter()
+                .map(|key| codes.get(key).cloned().unwrap_or_else(|| self.get_account_code(key)))
+                .collect(),
+            storage_cells: request
+                .storage_cells
+                .iter()
+                .map(|(key, index)| {
+                    storage_cells
+                        .get(&(*key, *index))
+                        .copied()
+                        .unwrap_or_else(|| self.get_account_storage_cell(key, index))
+                })
+                .collect(),
+        }
+    }
+}
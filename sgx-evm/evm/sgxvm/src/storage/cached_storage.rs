@@ -0,0 +1,169 @@
+use evm::backend::Basic;
+use primitive_types::{H160, H256};
+
+use std::{cell::RefCell, collections::BTreeMap, string::String, vec::Vec};
+
+use super::{BatchReadRequest, BatchReadResponse, Storage, WriteSet};
+
+/// Storage wrapper, which memoizes results of reads from inner storage.
+/// Cached entries are invalidated on writes
+pub struct CachedStorage<'a> {
+    inner: &'a mut dyn Storage,
+    contains: RefCell<BTreeMap<H160, bool>>,
+    accounts: RefCell<BTreeMap<H160, Basic>>,
+    codes: RefCell<BTreeMap<H160, Option<Vec<u8>>>>,
+    storage_cells: RefCell<BTreeMap<(H160, H256), Option<H256>>>,
+}
+
+impl<'a> CachedStorage<'a> {
+    pub fn new(inner: &'a mut dyn Storage) -> Self {
+        Self {
+            inner,
+            contains: RefCell::new(BTreeMap::new()),
+            accounts: RefCell::new(BTreeMap::new()),
+            codes: RefCell::new(BTreeMap::new()),
+            storage_cells: RefCell::new(BTreeMap::new()),
+        }
+    }
+
+    /// Removes all cached entries related to provided account
+    fn invalidate_account(&mut self, key: &H160) {
+        self.contains.get_mut().remove(key);
+        self.accounts.get_mut().remove(key);
+        self.codes.get_mut().remove(key);
+        self.storage_cells.get_mut().retain(|(address, _), _| address != key);
+    }
+}
+
+impl<'a> Storage for CachedStorage<'a> {
+    fn contains_key(&self, key: &H160) -> bool {
+        if let Some(contains) = self.contains.borrow().get(key) {
+            return *contains;
+        }
+
+        let contains = self.inner.contains_key(key);
+        self.contains.borrow_mut().insert(*key, contains);
+        contains
+    }
+
+    fn get_account_storage_cell(&self, key: &H160, index: &H256) -> Option<H256> {
+        if let Some(value) = self.storage_cells.borrow().get(&(*key, *index)) {
+            return *value;
+        }
+
+        let value = self.inner.get_account_storage_cell(key, index);
+        self.storage_cells.borrow_mut().insert((*key, *index), value);
+        value
+    }
+
+    fn get_account_code(&self, key: &H160) -> Option<Vec<u8>> {
+        if let Some(code) = self.codes.borrow().get(key) {
+            return code.clone();
+        }
+
+        let code = self.inner.get_account_code(key);
+        self.codes.borrow_mut().insert(*key, code.clone());
+        code
+    }
+
+    fn get_account(&self, account: &H160) -> Basic {
+        if let Some(basic) = self.accounts.borrow().get(account) {
+            return basic.clone();
+        }
+
+        let basic = self.inner.get_account(account);
+        self.accounts.borrow_mut().insert(*account, basic.clone());
+        basic
+    }
+
+    fn insert_account(&mut self, key: H160, data: Basic) {
+        self.contains.get_mut().remove(&key);
+        self.accounts.get_mut().remove(&key);
+        self.inner.insert_account(key, data);
+    }
+
+    fn insert_account_code(&mut self, key: H160, code: Vec<u8>) {
+        self.contains.get_mut().remove(&key);
+        self.codes.get_mut().remove(&key);
+        self.inner.insert_account_code(key, code);
+    }
+
+    fn insert_storage_cell(&mut self, key: H160, index: H256, value: H256) {
+        self.contains.get_mut().remove(&key);
+        self.storage_cells.get_mut().remove(&(key, index));
+        self.inner.insert_storage_cell(key, index, value);
+    }
+
+    fn remove(&mut self, key: &H160) {
+        self.invalidate_account(key);
+        self.inner.remove(key);
+    }
+
+    fn remove_storage_cell(&mut self, key: &H160, index: &H256) {
+        self.contains.get_mut().remove(key);
+        self.storage_cells.get_mut().remove(&(*key, *index));
+        self.inner.remove_storage_cell(key, index);
+    }
+
+    fn remove_storage(&mut self, key: &H160) {
+        self.contains.get_mut().remove(key);
+        self.storage_cells.get_mut().retain(|(address, _), _| address != key);
+        self.inner.remove_storage(key);
+    }
+
+    fn get_batch(&self, request: &BatchReadRequest) -> BatchReadResponse {
+        // Only entries, which are not cached yet, are requested from inner storage
+        let missing = {
+            let accounts = self.accounts.borrow();
+            let codes = self.codes.borrow();
+            let storage_cells = self.storage_cells.borrow();
+            BatchReadRequest {
+                accounts: request.accounts.iter().filter(|key| !accounts.contains_key(*key)).copied().collect(),
+                codes: request.codes.iter().filter(|key| !codes.contains_key(*key)).copied().collect(),
+                storage_cells: request
+                    .storage_cells
+                    .iter()
+                    .filter(|key| !storage_cells.contains_key(*key))
+                    .copied()
+                    .collect(),
+            }
+        };
+
+        if !missing.is_empty() {
+            let fetched = self.inner.get_batch(&missing);
+            self.accounts.borrow_mut().extend(missing.accounts.into_iter().zip(fetched.accounts));
+            self.codes.borrow_mut().extend(missing.codes.into_iter().zip(fetched.codes));
+            self.storage_cells.borrow_mut().extend(missing.storage_cells.into_iter().zip(fetched.storage_cells));
+        }
+
+        BatchReadResponse {
+            accounts: request.accounts.iter().map(|key| self.get_account(key)).collect(),
+            codes: request.codes.iter().map(|key| self.get_account_code(key)).collect(),
+            storage_cells: request
+                .storage_cells
+                .iter()
+                .map(|(key, index)| self.get_account_storage_cell(key, index))
+                .collect(),
+        }
+    }
+
+    fn commit(&mut self, write_set: WriteSet) -> Result<(), String> {
+        for key in write_set.removed.iter().chain(write_set.reset_storage.iter()) {
+            self.invalidate_account(key);
+        }
+        for (key, _) in write_set.accounts.iter() {
+            self.contains.get_mut().remove(key);
+            self.accounts.get_mut().remove(key);
+        }
+        for (key, _) in write_set.codes.iter() {
+            self.contains.get_mut().remove(key);
+            self.codes.get_mut().remove(key);
+        }
+        for (key, index, _) in write_set.storage_cells.iter() {
+            self.contains.get_mut().remove(key);
+            self.storage_cells.get_mut().remove(&(*key, *index));
+        }
+
+        self.inner.commit(write_set)
+    }
+}
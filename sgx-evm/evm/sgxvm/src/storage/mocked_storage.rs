@@ -68,6 +68,10 @@ impl Storage for MockedStorage {
     fn remove_storage_cell(&mut self, key: &H160, index: &H256) {
         self.storage.entry(*key).and_modify(|inner| { inner.remove(index); });
     }
+
+    fn remove_storage(&mut self, key: &H160) {
+        self.storage.remove(key);
+    }
 }
 
 impl Default for MockedStorage {
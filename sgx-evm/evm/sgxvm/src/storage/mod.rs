@@ -1,10 +1,56 @@
 use evm::backend::Basic;
 use primitive_types::{H160, H256};
 
-use std::vec::Vec;
+use std::{string::String, vec::Vec};
 
+pub mod buffered_storage;
+pub mod cached_storage;
 pub mod mocked_storage;
 
+/// Keys of accounts, contract codes and storage cells, which should be read at once
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct BatchReadRequest {
+    pub accounts: Vec<H160>,
+    pub codes: Vec<H160>,
+    pub storage_cells: Vec<(H160, H256)>,
+}
+
+impl BatchReadRequest {
+    pub fn is_empty(&self) -> bool {
+        self.accounts.is_empty() && self.codes.is_empty() && self.storage_cells.is_empty()
+    }
+}
+
+/// Values obtained by batch read. Items are in the same order as keys of `BatchReadRequest`
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct BatchReadResponse {
+    pub accounts: Vec<Basic>,
+    pub codes: Vec<Option<Vec<u8>>>,
+    pub storage_cells: Vec<Option<H256>>,
+}
+
+/// All changes, which should be written to storage at once. Changes are applied in the
+/// following order: removed accounts, reset storages, accounts, codes, storage cells
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct WriteSet {
+    pub removed: Vec<H160>,
+    pub reset_storage: Vec<H160>,
+    pub accounts: Vec<(H160, Basic)>,
+    pub codes: Vec<(H160, Vec<u8>)>,
+    // `None` marks removed storage cell
+    pub storage_cells: Vec<(H160, H256, Option<H256>)>,
+}
+
+impl WriteSet {
+    pub fn is_empty(&self) -> bool {
+        self.removed.is_empty()
+            && self.reset_storage.is_empty()
+            && self.accounts.is_empty()
+            && self.codes.is_empty()
+            && self.storage_cells.is_empty()
+    }
+}
+
 /// A key-value storage trait
 pub trait Storage {
     /// Checks if there is entity with such key exists in DB
@@ -33,4 +79,51 @@ pub trait Storage {
 
     /// Removes storage cell value
     fn remove_storage_cell(&mut self, key: &H160, index: &H256);
+
+    /// Removes all storage cells of account
+    fn remove_storage(&mut self, key: &H160);
+
+    /// Reads several accounts, contract codes and storage cells at once. Storages backed by
+    /// external keeper override it to obtain all values within a single request
+    fn get_batch(&self, request: &BatchReadRequest) -> BatchReadResponse {
+        BatchReadResponse {
+            accounts: request.accounts.iter().map(|key| self.get_account(key)).collect(),
+            codes: request.codes.iter().map(|key| self.get_account_code(key)).collect(),
+            storage_cells: request
+                .storage_cells
+                .iter()
+                .map(|(key, index)| self.get_account_storage_cell(key, index))
+                .collect(),
+        }
+    }
+
+    /// Writes all provided changes. Storages backed by external keeper override it
+    /// to apply the whole write set within a single request. Returns error, if changes
+    /// could not be written
+    fn commit(&mut self, write_set: WriteSet) -> Result<(), String> {
+        for key in write_set.removed {
+            self.remove(&key);
+        }
+
+        for key in write_set.reset_storage {
+            self.remove_storage(&key);
+        }
+
+        for (key, data) in write_set.accounts {
+            self.insert_account(key, data);
+        }
+
+        for (key, code) in write_set.codes {
+            self.insert_account_code(key, code);
+        }
+
+        for (key, index, value) in write_set.storage_cells {
+            match value {
+                Some(value) => self.insert_storage_cell(key, index, value),
+                None => self.remove_storage_cell(&key, &index),
+            }
+        }
+
+        Ok(())
+    }
 }
@@ -0,0 +1,433 @@
+use evm::tracing::{Event, EventListener};
+use evm::{Capture, CreateScheme, ExitReason};
+use evm_gasometer::tracing::{Event as GasometerEvent, EventListener as GasometerEventListener};
+use evm_runtime::tracing::{Event as RuntimeEvent, EventListener as RuntimeEventListener};
+use primitive_types::{H160, H256, U256};
+
+use std::{
+    cell::RefCell, collections::BTreeMap, rc::Rc, string::String, string::ToString, vec::Vec,
+};
+
+/// Single frame of the internal call tree (callTracer-style)
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct CallFrame {
+    // CALL, STATICCALL, DELEGATECALL, CALLCODE, CREATE, CREATE2 or SELFDESTRUCT
+    pub call_type: String,
+    pub from: H160,
+    pub to: H160,
+    pub value: U256,
+    // Gas provided to the frame
+    pub gas: u64,
+    pub input: Vec<u8>,
+    pub output: Vec<u8>,
+    pub error: Option<String>,
+    // Nested calls made by this frame
+    pub calls: Vec<CallFrame>,
+}
+
+/// Listener for evm events, which collects nested call frames
+#[derive(Default)]
+pub struct CallTracer {
+    // Frames which were entered, but not exited yet
+    stack: Vec<CallFrame>,
+    // Root frame of the transaction
+    root: Option<CallFrame>,
+}
+
+impl CallTracer {
+    /// Returns root frame of collected call tree
+    pub fn finish(mut self) -> Option<CallFrame> {
+        // If some frames were not closed by exit events, attach them to their parents
+        while let Some(frame) = self.stack.pop() {
+            self.close_frame(frame);
+        }
+        self.root
+    }
+
+    fn close_frame(&mut self, frame: CallFrame) {
+        match self.stack.last_mut() {
+            Some(parent) => parent.calls.push(frame),
+            None => self.root = Some(frame),
+        }
+    }
+}
+
+impl EventListener for CallTracer {
+    fn event(&mut self, event: Event) {
+        match event {
+            Event::Call { code_address, transfer, input, target_gas, is_static, context } => {
+                let (call_type, from) = if is_static {
+                    ("STATICCALL", context.caller)
+                } else if code_address != context.address {
+                    // Code of other contract is executed in context of the caller
+                    match transfer {
+                        Some(_) => ("CALLCODE", context.address),
+                        None => ("DELEGATECALL", context.address),
+                    }
+                } else {
+                    ("CALL", context.caller)
+                };
+
+                self.stack.push(CallFrame {
+                    call_type: call_type.to_string(),
+                    from,
+                    to: code_address,
+                    value: transfer.as_ref().map(|t| t.value).unwrap_or_default(),
+                    gas: target_gas.unwrap_or_default(),
+                    input: input.to_vec(),
+                    ..Default::default()
+                });
+            }
+            Event::Create { caller, address, scheme, value, init_code, target_gas } => {
+                let call_type = match scheme {
+                    CreateScheme::Create2 { .. } => "CREATE2",
+                    _ => "CREATE",
+                };
+
+                self.stack.push(CallFrame {
+                    call_type: call_type.to_string(),
+                    from: caller,
+                    to: address,
+                    value,
+                    gas: target_gas.unwrap_or_default(),
+                    input: init_code.to_vec(),
+                    ..Default::default()
+                });
+            }
+            Event::Suicide { address, target, balance } => {
+                let frame = CallFrame {
+                    call_type: "SELFDESTRUCT".to_string(),
+                    from: address,
+                    to: target,
+                    value: balance,
+                    ..Default::default()
+                };
+                self.close_frame(frame);
+            }
+            Event::Exit { reason, return_value } => {
+                if let Some(mut frame) = self.stack.pop() {
+                    frame.output = return_value.to_vec();
+                    frame.error = exit_reason_to_error(reason);
+                    self.close_frame(frame);
+                }
+            }
+            _ => {}
+        }
+    }
+}
+
+/// Runs provided closure with attached call tracer and returns collected call tree.
+/// Closure is expected to execute `handle_sgxvm_call` or `handle_sgxvm_create`
+pub fn trace_calls<R, F: FnOnce() -> R>(f: F) -> (R, Option<CallFrame>) {
+    let mut tracer = CallTracer::default();
+    let result = evm::tracing::using(&mut tracer, f);
+    (result, tracer.finish())
+}
+
+fn exit_reason_to_error(reason: &ExitReason) -> Option<String> {
+    match reason {
+        ExitReason::Succeed(_) => None,
+        ExitReason::Revert(_) => Some("execution reverted".to_string()),
+        ExitReason::Error(err) => Some(format!("evm error: {:?}", err)),
+        ExitReason::Fatal(err) => Some(format!("fatal evm error: {:?}", err)),
+    }
+}
+
+/// Options of opcode-level struct logger
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct StructLoggerConfig {
+    pub enable_stack: bool,
+    pub enable_memory: bool,
+    pub enable_storage: bool,
+    // Maximum amount of collected steps. Zero means no limit
+    pub max_steps: usize,
+    // Maximum approximate size of collected steps in bytes. Zero means no limit
+    pub max_bytes: usize,
+}
+
+/// Single step of evm execution (geth-style struct log)
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct StructLog {
+    pub pc: u64,
+    pub op: String,
+    // Gas left before execution of the opcode
+    pub gas: u64,
+    pub gas_cost: u64,
+    pub depth: u64,
+    pub stack: Vec<H256>,
+    pub memory: Option<Vec<u8>>,
+    // Storage slots of current contract, accessed so far
+    pub storage: Option<BTreeMap<H256, H256>>,
+    pub error: Option<String>,
+}
+
+// Size of stack item or storage key
+const WORD_SIZE: usize = 32;
+// Size of storage key and value
+const STORAGE_ENTRY_SIZE: usize = 2 * WORD_SIZE;
+
+impl StructLog {
+    /// Returns approximate amount of memory occupied by collected data of the step
+    fn approximate_size(&self) -> usize {
+        let stack = self.stack.len() * WORD_SIZE;
+        let memory = self.memory.as_ref().map(Vec::len).unwrap_or_default();
+        let storage = self.storage.as_ref().map(BTreeMap::len).unwrap_or_default() * STORAGE_ENTRY_SIZE;
+        core::mem::size_of::<Self>() + self.op.len() + stack + memory + storage
+    }
+}
+
+/// Collects struct logs from runtime, gasometer and executor events
+#[derive(Default)]
+pub struct StructLogger {
+    config: StructLoggerConfig,
+    logs: Vec<StructLog>,
+    // Current call depth
+    depth: u64,
+    // Set if gas cost of the latest step was already recorded
+    cost_recorded: bool,
+    // Storage slots accessed by each contract
+    storage: BTreeMap<H160, BTreeMap<H256, H256>>,
+    // Approximate size of collected logs in bytes
+    collected_bytes: usize,
+    // Set if one of the limits was reached. Further steps are not collected
+    finished: bool,
+}
+
+impl StructLogger {
+    pub fn new(config: StructLoggerConfig) -> Self {
+        Self { config, ..Default::default() }
+    }
+
+    fn limit_reached(&self) -> bool {
+        let steps_exceeded = self.config.max_steps != 0 && self.logs.len() >= self.config.max_steps;
+        let bytes_exceeded = self.config.max_bytes != 0 && self.collected_bytes >= self.config.max_bytes;
+        steps_exceeded || bytes_exceeded
+    }
+
+    fn runtime_event(&mut self, event: RuntimeEvent) {
+        if self.finished {
+            return;
+        }
+
+        match event {
+            RuntimeEvent::Step { context, opcode, position, stack, memory } => {
+                if self.limit_reached() {
+                    self.finished = true;
+                    return;
+                }
+
+                let storage = match self.config.enable_storage {
+                    true => Some(self.storage.get(&context.address).cloned().unwrap_or_default()),
+                    false => None,
+                };
+
+                let log = StructLog {
+                    pc: position.as_ref().map(|pc| *pc as u64).unwrap_or_default(),
+                    op: opcode_name(opcode.as_u8()),
+                    depth: self.depth,
+                    stack: match self.config.enable_stack {
+                        true => stack.data().clone(),
+                        false => Vec::new(),
+                    },
+                    memory: match self.config.enable_memory {
+                        true => Some(memory.data().clone()),
+                        false => None,
+                    },
+                    storage,
+                    ..Default::default()
+                };
+                self.collected_bytes = self.collected_bytes.saturating_add(log.approximate_size());
+                self.logs.push(log);
+                self.cost_recorded = false;
+            }
+            RuntimeEvent::StepResult { result, .. } => {
+                if let Err(Capture::Exit(reason)) = result {
+                    if let Some(log) = self.logs.last_mut() {
+                        log.error = exit_reason_to_error(reason).filter(|_| !reason.is_revert());
+                    }
+                }
+            }
+            RuntimeEvent::SLoad { address, index, value } | RuntimeEvent::SStore { address, index, value } => {
+                if !self.config.enable_storage {
+                    return;
+                }
+
+                let contract_storage = self.storage.entry(address).or_default();
+                if contract_storage.insert(index, value).is_none() {
+                    self.collected_bytes = self.collected_bytes.saturating_add(STORAGE_ENTRY_SIZE);
+                }
+                if let Some(log) = self.logs.last_mut() {
+                    log.storage = Some(contract_storage.clone());
+                }
+            }
+        }
+    }
+
+    fn gasometer_event(&mut self, event: GasometerEvent) {
+        let (cost, snapshot) = match event {
+            GasometerEvent::RecordCost { cost, snapshot } => (cost, snapshot),
+            GasometerEvent::RecordDynamicCost { gas_cost, memory_gas, snapshot, .. } => {
+                // Memory expansion cost is included into the cost of the opcode
+                let memory_cost = snapshot
+                    .map(|snapshot| memory_gas.saturating_sub(snapshot.memory_gas))
+                    .unwrap_or_default();
+                (gas_cost.saturating_add(memory_cost), snapshot)
+            }
+            _ => return,
+        };
+
+        if self.cost_recorded || self.finished {
+            return;
+        }
+
+        if let Some(log) = self.logs.last_mut() {
+            if let Some(snapshot) = snapshot {
+                log.gas = snapshot
+                    .gas_limit
+                    .saturating_sub(snapshot.used_gas)
+                    .saturating_sub(snapshot.memory_gas);
+            }
+            log.gas_cost = cost;
+            self.cost_recorded = true;
+        }
+    }
+
+    fn executor_event(&mut self, event: Event) {
+        match event {
+            Event::Call { .. } | Event::Create { .. } => self.depth += 1,
+            Event::Exit { .. } => self.depth = self.depth.saturating_sub(1),
+            _ => {}
+        }
+    }
+}
+
+struct StructLoggerRuntimeListener(Rc<RefCell<StructLogger>>);
+
+impl RuntimeEventListener for StructLoggerRuntimeListener {
+    fn event(&mut self, event: RuntimeEvent) {
+        self.0.borrow_mut().runtime_event(event);
+    }
+}
+
+struct StructLoggerGasometerListener(Rc<RefCell<StructLogger>>);
+
+impl GasometerEventListener for StructLoggerGasometerListener {
+    fn event(&mut self, event: GasometerEvent) {
+        self.0.borrow_mut().gasometer_event(event);
+    }
+}
+
+struct StructLoggerExecutorListener(Rc<RefCell<StructLogger>>);
+
+impl EventListener for StructLoggerExecutorListener {
+    fn event(&mut self, event: Event) {
+        self.0.borrow_mut().executor_event(event);
+    }
+}
+
+/// Runs provided closure with attached struct logger and returns collected struct logs.
+/// If one of the limits set in `config` is reached, remaining steps are not collected.
+/// Closure is expected to execute `handle_sgxvm_call` or `handle_sgxvm_create`
+pub fn trace_steps<R, F: FnOnce() -> R>(config: StructLoggerConfig, f: F) -> (R, Vec<StructLog>) {
+    let logger = Rc::new(RefCell::new(StructLogger::new(config)));
+    let mut executor_listener = StructLoggerExecutorListener(logger.clone());
+    let mut runtime_listener = StructLoggerRuntimeListener(logger.clone());
+    let mut gasometer_listener = StructLoggerGasometerListener(logger.clone());
+
+    let result = evm::tracing::using(&mut executor_listener, || {
+        evm_runtime::tracing::using(&mut runtime_listener, || {
+            evm_gasometer::tracing::using(&mut gasometer_listener, f)
+        })
+    });
+
+    let logs = core::mem::take(&mut logger.borrow_mut().logs);
+    (result, logs)
+}
+
+/// Returns mnemonic of provided opcode
+fn opcode_name(opcode: u8) -> String {
+    let name = match opcode {
+        0x00 => "STOP",
+        0x01 => "ADD",
+        0x02 => "MUL",
+        0x03 => "SUB",
+        0x04 => "DIV",
+        0x05 => "SDIV",
+        0x06 => "MOD",
+        0x07 => "SMOD",
+        0x08 => "ADDMOD",
+        0x09 => "MULMOD",
+        0x0a => "EXP",
+        0x0b => "SIGNEXTEND",
+        0x10 => "LT",
+        0x11 => "GT",
+        0x12 => "SLT",
+        0x13 => "SGT",
+        0x14 => "EQ",
+        0x15 => "ISZERO",
+        0x16 => "AND",
+        0x17 => "OR",
+        0x18 => "XOR",
+        0x19 => "NOT",
+        0x1a => "BYTE",
+        0x1b => "SHL",
+        0x1c => "SHR",
+        0x1d => "SAR",
+        0x20 => "SHA3",
+        0x30 => "ADDRESS",
+        0x31 => "BALANCE",
+        0x32 => "ORIGIN",
+        0x33 => "CALLER",
+        0x34 => "CALLVALUE",
+        0x35 => "CALLDATALOAD",
+        0x36 => "CALLDATASIZE",
+        0x37 => "CALLDATACOPY",
+        0x38 => "CODESIZE",
+        0x39 => "CODECOPY",
+        0x3a => "GASPRICE",
+        0x3b => "EXTCODESIZE",
+        0x3c => "EXTCODECOPY",
+        0x3d => "RETURNDATASIZE",
+        0x3e => "RETURNDATACOPY",
+        0x3f => "EXTCODEHASH",
+        0x40 => "BLOCKHASH",
+        0x41 => "COINBASE",
+        0x42 => "TIMESTAMP",
+        0x43 => "NUMBER",
+        0x44 => "DIFFICULTY",
+        0x45 => "GASLIMIT",
+        0x46 => "CHAINID",
+        0x47 => "SELFBALANCE",
+        0x48 => "BASEFEE",
+        0x50 => "POP",
+        0x51 => "MLOAD",
+        0x52 => "MSTORE",
+        0x53 => "MSTORE8",
+        0x54 => "SLOAD",
+        0x55 => "SSTORE",
+        0x56 => "JUMP",
+        0x57 => "JUMPI",
+        0x58 => "PC",
+        0x59 => "MSIZE",
+        0x5a => "GAS",
+        0x5b => "JUMPDEST",
+        0x5f => "PUSH0",
+        0x60..=0x7f => return format!("PUSH{}", opcode - 0x5f),
+        0x80..=0x8f => return format!("DUP{}", opcode - 0x7f),
+        0x90..=0x9f => return format!("SWAP{}", opcode - 0x8f),
+        0xa0..=0xa4 => return format!("LOG{}", opcode - 0xa0),
+        0xf0 => "CREATE",
+        0xf1 => "CALL",
+        0xf2 => "CALLCODE",
+        0xf3 => "RETURN",
+        0xf4 => "DELEGATECALL",
+        0xf5 => "CREATE2",
+        0xfa => "STATICCALL",
+        0xfd => "REVERT",
+        0xfe => "INVALID",
+        0xff => "SELFDESTRUCT",
+        _ => return format!("opcode {:#04x} not defined", opcode),
+    };
+
+    name.to_string()
+}
@@ -1,17 +1,21 @@
 use sgxvm::ethereum::Log;
-use sgxvm::backend::ExtendedBackend;
-use sgxvm::evm::backend::{Backend as EvmBackend, ApplyBackend as EvmApplyBackend, Basic, Apply};
+use sgxvm::backend::{EvmFork, ExtendedBackend};
+use sgxvm::evm::backend::{Backend as EvmBackend, Basic, Apply};
 
 use sgxvm::primitive_types::{H160, H256, U256};
-use sgxvm::storage::Storage;
+use sgxvm::precompiles::PrecompileRegistry;
+use sgxvm::storage::buffered_storage::BufferedStorage;
+use sgxvm::storage::{BatchReadRequest, Storage};
 use sgxvm::Vicinity;
-use std::vec::Vec;
+use std::{cell::RefCell, collections::BTreeMap, string::String, vec::Vec};
 
 use crate::{coder, GoQuerier};
 use crate::ocall;
+use crate::precompiles;
 use crate::protobuf_generated::ffi;
 
 /// Contains context of the transaction such as gas price, block hash, block timestamp, etc.
+#[derive(Clone)]
 pub struct TxContext {
     pub chain_id: U256,
     pub gas_price: U256,
@@ -19,7 +23,9 @@ pub struct TxContext {
     pub timestamp: U256,
     pub block_gas_limit: U256,
     pub block_base_fee_per_gas: U256,
-    pub block_coinbase: H160
+    pub block_coinbase: H160,
+    pub evm_fork: EvmFork,
+    pub prev_randao: Option<H256>,
 }
 
 pub struct FFIBackend<'state> {
@@ -32,13 +38,104 @@ pub struct FFIBackend<'state> {
     // Emitted events
     pub logs: Vec<Log>,
     // Transaction context
-    pub tx_context: TxContext
+    pub tx_context: TxContext,
+    // Values of storage slots at the beginning of transaction
+    original_storage_journal: RefCell<BTreeMap<(H160, H256), H256>>,
+    // Precompiles available during execution. Built once for the backend
+    precompiles: PrecompileRegistry,
 }
 
 impl<'state> ExtendedBackend for FFIBackend<'state> {
     fn get_logs(&self) -> Vec<Log> {
         self.logs.clone()
     }
+
+    fn get_evm_fork(&self) -> EvmFork {
+        self.tx_context.evm_fork
+    }
+
+    fn prefetch(&self, request: &BatchReadRequest) {
+        self.state.get_batch(request);
+    }
+
+    fn precompiles(&self) -> &PrecompileRegistry {
+        &self.precompiles
+    }
+
+    fn apply_changes<A, I, L>(&mut self, values: A, logs: L, delete_empty: bool) -> Result<(), String>
+        where
+            A: IntoIterator<Item = Apply<I>>,
+            I: IntoIterator<Item = (H256, H256)>,
+            L: IntoIterator<Item = Log>,
+    {
+        // Used to avoid corrupting state via invariant violation
+        let values: Vec<Apply<I>> = values.into_iter().collect();
+        sgxvm::validate_supply_invariant(&*self, &values)?;
+
+        // All changes are collected in memory and written by single request,
+        // so state cannot be partially written
+        let mut changes = BufferedStorage::new(&mut *self.state);
+        for apply in values {
+            match apply {
+                Apply::Modify {
+                    address,
+                    basic,
+                    code,
+                    storage,
+                    reset_storage,
+                } => {
+                    // Storage of account should be cleared, if account was recreated
+                    // (e.g. by CREATE2 after SELFDESTRUCT)
+                    if reset_storage {
+                        changes.remove_storage(&address);
+                    }
+
+                    // Update account balance and nonce
+                    let is_empty_account = basic.balance.is_zero() && basic.nonce.is_zero();
+                    changes.insert_account(address, basic);
+
+                    // Handle contract updates
+                    if let Some(code) = code {
+                        changes.insert_account_code(address, code);
+                    }
+
+                    // Handle storage updates
+                    for (index, value) in storage {
+                        if value == H256::default() {
+                            changes.remove_storage_cell(&address, &index);
+                        } else {
+                            changes.insert_storage_cell(address, index, value);
+                        }
+                    }
+
+                    // Remove touched accounts, which became empty (EIP-161)
+                    if delete_empty && is_empty_account {
+                        let has_code = changes
+                            .get_account_code(&address)
+                            .map_or(false, |code| !code.is_empty());
+                        if !has_code {
+                            changes.remove(&address);
+                        }
+                    }
+                },
+                // Used by SELFDESTRUCT opcode
+                Apply::Delete { address } => {
+                    changes.remove(&address);
+                }
+            }
+        }
+
+        changes.flush()?;
+
+        for log in logs {
+            self.logs.push(log);
+        }
+
+        // Applied changes finish transaction, therefore original values are no longer valid
+        self.original_storage_journal.get_mut().clear();
+
+        Ok(())
+    }
 }
 
 impl<'state> EvmBackend for FFIBackend<'state> {
@@ -104,19 +201,7 @@ impl<'state> EvmBackend for FFIBackend<'state> {
     }
 
     fn basic(&self, address: H160) -> Basic {
-        if address == self.vicinity.origin {
-            let mut account_data = self.state.get_account(&address);
-            let updated_nonce = account_data.nonce.checked_sub(U256::from(1u8)).unwrap_or(U256::zero());
-            if updated_nonce > self.vicinity.nonce {
-                account_data.nonce = updated_nonce;
-            } else {
-                account_data.nonce = self.vicinity.nonce;
-            }
-
-            account_data
-        } else {
-            self.state.get_account(&address)
-        }
+        self.state.get_account(&address)
     }
 
     fn code(&self, address: H160) -> Vec<u8> {
@@ -130,97 +215,49 @@ impl<'state> EvmBackend for FFIBackend<'state> {
         address: H160,
         index: H256,
     ) -> H256 {
-        self.state
-            .get_account_storage_cell(&address, &index)
-            .unwrap_or_default()
+        self.journaled_storage(address, index)
     }
 
-    fn original_storage(&self, _address: H160, _index: H256) -> Option<H256> {
-        None
+    fn original_storage(&self, address: H160, index: H256) -> Option<H256> {
+        Some(self.journaled_storage(address, index))
     }
 
     fn block_randomness(&self) -> Option<H256> {
-        None
-    }
-}
-
-/// Implementation of trait `Apply` provided by evm crate
-/// This trait declares write operations for the backend
-impl<'state> EvmApplyBackend for FFIBackend<'state> {
-    fn apply<A, I, L>(&mut self, values: A, logs: L, _delete_empty: bool)
-        where
-            A: IntoIterator<Item = Apply<I>>,
-            I: IntoIterator<Item = (H256, H256)>,
-            L: IntoIterator<Item = Log>,
-    {
-        let mut total_supply_add = U256::zero();
-        let mut total_supply_sub = U256::zero();
-
-        for apply in values {
-            match apply {
-                Apply::Modify {
-                    address,
-                    basic,
-                    code,
-                    storage,
-                    ..
-                } => {
-                    // Reset storage is ignored since storage cannot be efficiently reset as this
-                    // would require iterating over all of the storage keys
-
-                    // Update account balance and nonce
-                    let previous_account_data = self.state.get_account(&address);
-
-                    if basic.balance > previous_account_data.balance {
-                        total_supply_add =
-                            total_supply_add.checked_add(basic.balance - previous_account_data.balance).unwrap();
-                    } else {
-                        total_supply_sub =
-                            total_supply_sub.checked_add(previous_account_data.balance - basic.balance).unwrap();
-                    }
-                    self.state.insert_account(address, basic);
-
-                    // Handle contract updates
-                    if let Some(code) = code {
-                        self.state.insert_account_code(address, code);
-                    }
-
-                    // Handle storage updates
-                    for (index, value) in storage {
-                        if value == H256::default() {
-                            self.state.remove_storage_cell(&address, &index);
-                        } else {
-                            self.state.insert_storage_cell(address, index, value);
-                        }
-                    }
-                },
-                // Used by SELFDESTRUCT opcode
-                Apply::Delete { address } => {
-                    self.state.remove(&address);
-                }
-            }
-        }
-
-        // Used to avoid corrupting state via invariant violation
-        assert_eq!(
-            total_supply_add,
-            total_supply_sub,
-            "evm execution would lead to invariant violation ({} != {})", total_supply_add, total_supply_sub
-        );
-
-        for log in logs {
-            self.logs.push(log);
-        }
+        self.tx_context.prev_randao
     }
 }
 
 impl<'state> FFIBackend<'state> {
+    /// Creates backend for single transaction. Returns error if precompiles for hard fork
+    /// of transaction context cannot be obtained
     pub fn new(
         querier: *mut GoQuerier,
         storage: &'state mut dyn Storage,
         vicinity: Vicinity,
         tx_context: TxContext,
-    ) -> Self {
-        Self { querier, vicinity, state: storage, logs: vec![], tx_context }
+    ) -> Result<Self, String> {
+        Ok(Self {
+            querier,
+            vicinity,
+            state: storage,
+            logs: vec![],
+            precompiles: precompiles::registry(tx_context.evm_fork, querier)?,
+            tx_context,
+            original_storage_journal: RefCell::new(BTreeMap::new()),
+        })
+    }
+
+    /// Returns value of storage slot at the beginning of transaction. Since state is not changed
+    /// until transaction is applied, each slot is read from storage only once and then kept in journal
+    fn journaled_storage(&self, address: H160, index: H256) -> H256 {
+        if let Some(value) = self.original_storage_journal.borrow().get(&(address, index)) {
+            return *value;
+        }
+
+        let value = self.state
+            .get_account_storage_cell(&address, &index)
+            .unwrap_or_default();
+        self.original_storage_journal.borrow_mut().insert((address, index), value);
+        value
     }
 }
@@ -1,10 +1,12 @@
 use sgxvm::evm::backend::Basic;
 use sgxvm::primitive_types::{H160, U256, H256};
+use sgxvm::storage::BatchReadRequest;
 use protobuf::Message;
 use crate::protobuf_generated::ffi;
+use std::string::ToString;
 use std::vec::Vec;
 
-fn u256_to_vec(value: U256) -> Vec<u8> {
+pub fn u256_to_vec(value: U256) -> Vec<u8> {
     let mut buffer = [0u8; 32];
     value.to_big_endian(&mut buffer);
     buffer.to_vec()
@@ -88,6 +90,14 @@ pub fn encode_remove(account_address: &H160) -> Vec<u8> {
     cosmos_request.write_to_bytes().unwrap()
 }
 
+pub fn encode_remove_storage(account_address: &H160) -> Vec<u8> {
+    let mut cosmos_request = ffi::CosmosRequest::new();
+    let mut request = ffi::QueryRemoveStorage::new();
+    request.set_address(account_address.as_bytes().to_vec());
+    cosmos_request.set_removeStorage(request);
+    cosmos_request.write_to_bytes().unwrap()
+}
+
 pub fn encode_remove_storage_cell(account_address: &H160, index: &H256) -> Vec<u8> {
     let mut cosmos_request = ffi::CosmosRequest::new();
     let mut request = ffi::QueryRemoveStorageCell::new();
@@ -97,3 +107,98 @@ pub fn encode_remove_storage_cell(account_address: &H160, index: &H256) -> Vec<u
     cosmos_request.write_to_bytes().unwrap()
 }
 
+
+pub fn encode_query_bank_balance(account_address: &H160, denom: &str) -> Vec<u8> {
+    let mut cosmos_request = ffi::CosmosRequest::new();
+    let mut request = ffi::QueryBankBalance::new();
+    request.set_address(account_address.as_bytes().to_vec());
+    request.set_denom(denom.to_string());
+    cosmos_request.set_bankBalance(request);
+    cosmos_request.write_to_bytes().unwrap()
+}
+
+pub fn encode_batch_read(request: &BatchReadRequest) -> Vec<u8> {
+    let mut cosmos_request = ffi::CosmosRequest::new();
+    let mut batch_request = ffi::QueryBatchRead::new();
+
+    let accounts = request.accounts.iter().map(|address| {
+        let mut request = ffi::QueryGetAccount::new();
+        request.set_address(address.as_bytes().to_vec());
+        request
+    }).collect();
+    batch_request.set_accounts(accounts);
+
+    let codes = request.codes.iter().map(|address| {
+        let mut request = ffi::QueryGetAccountCode::new();
+        request.set_address(address.as_bytes().to_vec());
+        request
+    }).collect();
+    batch_request.set_codes(codes);
+
+    let storage_cells = request.storage_cells.iter().map(|(address, index)| {
+        let mut request = ffi::QueryGetAccountStorageCell::new();
+        request.set_address(address.as_bytes().to_vec());
+        request.set_index(index.as_bytes().to_vec());
+        request
+    }).collect();
+    batch_request.set_storageCells(storage_cells);
+
+    cosmos_request.set_batchRead(batch_request);
+    cosmos_request.write_to_bytes().unwrap()
+}
+
+/// Encodes whole write set of transaction. Storage cell values should be already encrypted,
+/// empty value marks removed storage cell
+pub fn encode_commit(
+    removed: &[H160],
+    reset_storage: &[H160],
+    accounts: &[(H160, Basic)],
+    codes: Vec<(H160, Vec<u8>)>,
+    storage_cells: Vec<(H160, H256, Vec<u8>)>,
+) -> Vec<u8> {
+    let mut cosmos_request = ffi::CosmosRequest::new();
+    let mut commit_request = ffi::QueryCommit::new();
+
+    let removed = removed.iter().map(|address| {
+        let mut request = ffi::QueryRemove::new();
+        request.set_address(address.as_bytes().to_vec());
+        request
+    }).collect();
+    commit_request.set_removed(removed);
+
+    let reset_storage = reset_storage.iter().map(|address| {
+        let mut request = ffi::QueryRemoveStorage::new();
+        request.set_address(address.as_bytes().to_vec());
+        request
+    }).collect();
+    commit_request.set_resetStorage(reset_storage);
+
+    let accounts = accounts.iter().map(|(address, data)| {
+        let mut request = ffi::QueryInsertAccount::new();
+        request.set_address(address.as_bytes().to_vec());
+        request.set_balance(u256_to_vec(data.balance));
+        request.set_nonce(data.nonce.as_u64());
+        request
+    }).collect();
+    commit_request.set_accounts(accounts);
+
+    let codes = codes.into_iter().map(|(address, code)| {
+        let mut request = ffi::QueryInsertAccountCode::new();
+        request.set_address(address.as_bytes().to_vec());
+        request.set_code(code);
+        request
+    }).collect();
+    commit_request.set_codes(codes);
+
+    let storage_cells = storage_cells.into_iter().map(|(address, index, value)| {
+        let mut request = ffi::QueryInsertStorageCell::new();
+        request.set_address(address.as_bytes().to_vec());
+        request.set_index(index.as_bytes().to_vec());
+        request.set_value(value);
+        request
+    }).collect();
+    commit_request.set_storageCells(storage_cells);
+
+    cosmos_request.set_commit(commit_request);
+    cosmos_request.write_to_bytes().unwrap()
+}
@@ -0,0 +1,85 @@
+use crate::AllocationWithResult;
+use crate::backend;
+use crate::protobuf_generated::ffi::{AccessListItem, CreateAccessListResponse, SGXVMCreateAccessListRequest};
+use crate::GoQuerier;
+use internal_types::ExecutionResult;
+use sgxvm::primitive_types::{H160, H256, U256};
+use sgxvm::storage::cached_storage::CachedStorage;
+use sgxvm::{self, Vicinity};
+use std::{string::String, vec::Vec};
+
+use super::tx::{build_transaction_context, decrypt_call_data, encrypt_call_output, parse_access_list};
+
+/// Handles incoming request for generation of EIP-2930 access list for call or contract creation.
+/// State reads are cached between runs, so every account and slot is requested only once
+pub fn handle_create_access_list_request(querier: *mut GoQuerier, data: SGXVMCreateAccessListRequest) -> AllocationWithResult {
+    let params = data.params.unwrap();
+    let context = data.context.unwrap();
+
+    let to = match params.to.len() {
+        0 => None,
+        _ => Some(H160::from_slice(&params.to)),
+    };
+
+    let (user_public_key, tx_data) = match decrypt_call_data(to.is_some(), params.data) {
+        Ok(res) => res,
+        Err(err) => return post_access_list_error(err),
+    };
+
+    let tx_context = match build_transaction_context(context) {
+        Ok(tx_context) => tx_context,
+        Err(err) => return post_access_list_error(err),
+    };
+
+    let vicinity = Vicinity { origin: H160::from_slice(&params.from) };
+    let mut storage = crate::storage::FFIStorage::new(querier);
+    let mut cached_storage = CachedStorage::new(&mut storage);
+    let mut backend = match backend::FFIBackend::new(querier, &mut cached_storage, vicinity, tx_context) {
+        Ok(backend) => backend,
+        Err(err) => return post_access_list_error(err),
+    };
+
+    let (mut execution_result, access_list) = sgxvm::handle_sgxvm_create_access_list(
+        &mut backend,
+        params.gasLimit,
+        H160::from_slice(&params.from),
+        to,
+        U256::from_big_endian(&params.value),
+        tx_data,
+        parse_access_list(params.accessList),
+    );
+
+    if let Err(err) = encrypt_call_output(&mut execution_result, &user_public_key) {
+        return post_access_list_error(err);
+    }
+
+    post_access_list_handling(execution_result, access_list)
+}
+
+fn post_access_list_error(err: String) -> AllocationWithResult {
+    let execution_result = ExecutionResult::from_error(err, Vec::default(), None);
+    post_access_list_handling(execution_result, Vec::default())
+}
+
+fn post_access_list_handling(execution_result: ExecutionResult, access_list: Vec<(H160, Vec<H256>)>) -> AllocationWithResult {
+    let mut response = CreateAccessListResponse::new();
+    response.set_result(super::tx::build_transaction_response(execution_result));
+    response.set_accessList(convert_access_list(access_list).into());
+
+    super::encode_response(response)
+}
+
+/// Converts access list into protobuf-compatible format
+fn convert_access_list(access_list: Vec<(H160, Vec<H256>)>) -> Vec<AccessListItem> {
+    access_list
+        .into_iter()
+        .map(|(address, slots)| {
+            let slots: Vec<Vec<u8>> = slots.into_iter().map(|slot| slot.as_bytes().to_vec()).collect();
+
+            let mut item = AccessListItem::new();
+            item.set_address(address.as_bytes().to_vec());
+            item.set_storageSlot(slots.into());
+            item
+        })
+        .collect()
+}
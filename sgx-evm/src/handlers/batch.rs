@@ -0,0 +1,121 @@
+use crate::AllocationWithResult;
+use crate::backend;
+use crate::protobuf_generated::ffi::{
+    BatchResponse, BatchTransactionResult, SGXVMBatchRequest, SGXVMBatchTransaction,
+    SGXVMBatchTransaction_oneof_tx,
+};
+use crate::GoQuerier;
+use internal_types::ExecutionResult;
+use sgxvm::primitive_types::H160;
+use sgxvm::receipt::{parse_tx_type, LEGACY_TX_TYPE};
+use sgxvm::storage::buffered_storage::BufferedStorage;
+use sgxvm::storage::cached_storage::CachedStorage;
+use sgxvm::evm::{ExitFatal, ExitReason};
+use sgxvm::Vicinity;
+use std::{string::ToString, vec::Vec};
+
+use super::tx::{build_receipt, build_transaction_context, build_transaction_response, execute_call, execute_create};
+
+/// Handles incoming request for execution of ordered list of transactions within a single ecall.
+/// All transactions share the same block context and see changes of previous transactions.
+/// Transactions, which would exceed block gas limit, are rejected without execution.
+/// State is read through in-enclave cache and changes are written to the keeper once
+/// all transactions are executed
+pub fn handle_batch_request(querier: *mut GoQuerier, data: SGXVMBatchRequest) -> AllocationWithResult {
+    let context = data.context.into_option().ok_or_else(|| "empty batch context".to_string());
+    let (block_gas_used, block_gas_limit) = match &context {
+        Ok(context) => (context.block_gas_used, context.block_gas_limit),
+        Err(_) => (0, 0),
+    };
+    // All transactions share the same context, therefore it is converted only once
+    let tx_context = context.and_then(build_transaction_context);
+
+    let mut storage = crate::storage::FFIStorage::new(querier);
+    let mut cached_storage = CachedStorage::new(&mut storage);
+    let mut buffered_storage = BufferedStorage::new(&mut cached_storage);
+
+    let mut executed = Vec::with_capacity(data.transactions.len());
+    let mut gas_used = block_gas_used;
+    for transaction in data.transactions.into_iter() {
+        let validation = validate_transaction(&transaction, gas_used, block_gas_limit);
+        let tx_type = validation.clone().unwrap_or(LEGACY_TX_TYPE);
+        let execution_result = match (&tx_context, &validation, transaction.tx) {
+            (Err(err), _, _) | (_, Err(err), _) => ExecutionResult::from_error(err.clone(), Vec::default(), None),
+            (Ok(tx_context), Ok(_), Some(SGXVMBatchTransaction_oneof_tx::call(params))) => {
+                let vicinity = Vicinity { origin: H160::from_slice(&params.from) };
+                match backend::FFIBackend::new(querier, &mut buffered_storage, vicinity, tx_context.clone()) {
+                    Ok(mut backend) => execute_call(&mut backend, params, false),
+                    Err(err) => ExecutionResult::from_error(err, Vec::default(), None),
+                }
+            },
+            (Ok(tx_context), Ok(_), Some(SGXVMBatchTransaction_oneof_tx::create(params))) => {
+                let vicinity = Vicinity { origin: H160::from_slice(&params.from) };
+                match backend::FFIBackend::new(querier, &mut buffered_storage, vicinity, tx_context.clone()) {
+                    Ok(mut backend) => execute_create(&mut backend, params),
+                    Err(err) => ExecutionResult::from_error(err, Vec::default(), None),
+                }
+            },
+            (Ok(_), Ok(_), None) => ExecutionResult::from_error("empty batch transaction".to_string(), Vec::default(), None),
+        };
+
+        gas_used = gas_used.saturating_add(execution_result.gas_used);
+        executed.push((execution_result, tx_type));
+    }
+
+    // Changes of all transactions are written at once. If they cannot be written,
+    // none of transactions is applied
+    if let Err(err) = buffered_storage.flush() {
+        executed = executed
+            .into_iter()
+            .map(|(execution_result, tx_type)| {
+                let mut failed_result = ExecutionResult::from_error(err.clone(), Vec::default(), Some(execution_result.gas_used));
+                failed_result.exit_reason = Some(ExitReason::Fatal(ExitFatal::Other(err.clone().into())));
+                (failed_result, tx_type)
+            })
+            .collect();
+    }
+
+    let mut results = Vec::with_capacity(executed.len());
+    let mut cumulative_gas_used = block_gas_used;
+    for (execution_result, tx_type) in executed {
+        cumulative_gas_used = cumulative_gas_used.saturating_add(execution_result.gas_used);
+
+        let receipt = build_receipt(&execution_result, tx_type, cumulative_gas_used);
+        let mut transaction_response = build_transaction_response(execution_result);
+        transaction_response.set_receipt(receipt);
+
+        let mut result = BatchTransactionResult::new();
+        result.set_result(transaction_response);
+        result.set_cumulativeGasUsed(cumulative_gas_used);
+        results.push(result);
+    }
+
+    let mut response = BatchResponse::new();
+    response.set_results(results.into());
+
+    super::encode_response(response)
+}
+
+/// Checks that transaction can be executed within the batch. Returns type of the transaction
+fn validate_transaction(transaction: &SGXVMBatchTransaction, gas_used: u64, block_gas_limit: u64) -> Result<u8, String> {
+    let (tx_type, gas_limit, from) = match transaction.tx.as_ref() {
+        Some(SGXVMBatchTransaction_oneof_tx::call(params)) => (params.txType, params.gasLimit, &params.from),
+        Some(SGXVMBatchTransaction_oneof_tx::create(params)) => (params.txType, params.gasLimit, &params.from),
+        None => return Err("empty batch transaction".to_string()),
+    };
+
+    let tx_type = parse_tx_type(tx_type)?;
+
+    if from.len() != H160::len_bytes() {
+        return Err(format!("invalid sender address length: {}", from.len()));
+    }
+
+    if gas_used.saturating_add(gas_limit) > block_gas_limit {
+        return Err(format!(
+            "gas limit reached: {} gas is used, block gas limit is {}, transaction gas limit is {}",
+            gas_used, block_gas_limit, gas_limit
+        ));
+    }
+
+    Ok(tx_type)
+}
@@ -0,0 +1,77 @@
+use crate::AllocationWithResult;
+use crate::backend;
+use crate::protobuf_generated::ffi::{EstimateGasResponse, SGXVMEstimateGasRequest};
+use crate::GoQuerier;
+use sgxvm::primitive_types::{H160, U256};
+use sgxvm::storage::cached_storage::CachedStorage;
+use sgxvm::{self, Vicinity};
+use std::{string::String, vec::Vec};
+
+use super::tx::{build_transaction_context, decrypt_call_data, encrypt_call_output, parse_access_list};
+
+/// Handles incoming request for estimation of gas required by call or contract creation.
+/// Transaction data is decrypted only once and state reads are cached between attempts
+pub fn handle_estimate_gas_request(querier: *mut GoQuerier, data: SGXVMEstimateGasRequest) -> AllocationWithResult {
+    let params = data.params.unwrap();
+    let context = data.context.unwrap();
+
+    // If gas limit was not provided, use block gas limit as the highest bound
+    let gas_cap = match params.gasLimit {
+        0 => context.block_gas_limit,
+        gas_limit => gas_limit,
+    };
+
+    let to = match params.to.len() {
+        0 => None,
+        _ => Some(H160::from_slice(&params.to)),
+    };
+
+    let (user_public_key, tx_data) = match decrypt_call_data(to.is_some(), params.data) {
+        Ok(res) => res,
+        Err(err) => return post_estimate_handling(0, err, Vec::default()),
+    };
+
+    let tx_context = match build_transaction_context(context) {
+        Ok(tx_context) => tx_context,
+        Err(err) => return post_estimate_handling(0, err, Vec::default()),
+    };
+
+    let vicinity = Vicinity { origin: H160::from_slice(&params.from) };
+    let mut storage = crate::storage::FFIStorage::new(querier);
+    let mut cached_storage = CachedStorage::new(&mut storage);
+    let mut backend = match backend::FFIBackend::new(querier, &mut cached_storage, vicinity, tx_context) {
+        Ok(backend) => backend,
+        Err(err) => return post_estimate_handling(0, err, Vec::default()),
+    };
+
+    let (gas, failed_result) = sgxvm::handle_sgxvm_estimate_gas(
+        &mut backend,
+        gas_cap,
+        H160::from_slice(&params.from),
+        to,
+        U256::from_big_endian(&params.value),
+        tx_data,
+        parse_access_list(params.accessList),
+    );
+
+    let (vm_error, ret) = match failed_result {
+        Some(mut result) => {
+            if let Err(err) = encrypt_call_output(&mut result, &user_public_key) {
+                return post_estimate_handling(0, err, Vec::default());
+            }
+            (result.vm_error, result.data)
+        },
+        None => (Default::default(), Vec::default()),
+    };
+
+    post_estimate_handling(gas, vm_error, ret)
+}
+
+fn post_estimate_handling(gas: u64, vm_error: String, ret: Vec<u8>) -> AllocationWithResult {
+    let mut response = EstimateGasResponse::new();
+    response.set_gas(gas);
+    response.set_vm_error(vm_error);
+    response.set_ret(ret);
+
+    super::encode_response(response)
+}
@@ -1,10 +1,31 @@
 use crate::{AllocationWithResult, Allocation};
+use protobuf::Message;
 use std::vec::Vec;
 use sgx_types::sgx_status_t;
 use crate::ocall;
 
 pub mod tx;
 pub mod node;
+#[cfg(feature = "tracing")]
+pub mod trace;
+pub mod estimate;
+pub mod state_diff;
+pub mod access_list;
+pub mod batch;
+pub mod signed_tx;
+
+/// Encodes provided protobuf response and allocates it outside of enclave
+pub fn encode_response(response: impl Message) -> AllocationWithResult {
+    let encoded_response = match response.write_to_bytes() {
+        Ok(res) => res,
+        Err(err) => {
+            println!("Cannot encode protobuf result. Reason: {:?}", err);
+            return AllocationWithResult::default();
+        }
+    };
+
+    allocate_inner(encoded_response)
+}
 
 /// Allocates provided data outside of enclave
 pub fn allocate_inner(data: Vec<u8>) -> AllocationWithResult {
@@ -1,7 +1,12 @@
-use protobuf::Message;
-use crate::protobuf_generated::ffi::NodePublicKeyResponse;
+use crate::protobuf_generated::ffi::{
+    ConfigurePrecompilesRequest, ConfigurePrecompilesResponse, NodePublicKeyResponse, PrecompileOverride as ProtoPrecompileOverride,
+};
 use crate::AllocationWithResult;
 use crate::key_manager::KeyManager;
+use crate::precompiles;
+use sgxvm::precompiles::{PrecompileConfig, PrecompileOverride};
+use sgxvm::primitive_types::H160;
+use std::string::{String, ToString};
 
 /// Handles incoming request for node public key
 pub fn handle_public_key_request() -> AllocationWithResult {
@@ -17,13 +22,38 @@ pub fn handle_public_key_request() -> AllocationWithResult {
     let mut response = NodePublicKeyResponse::new();
     response.set_publicKey(public_key);
 
-    let encoded_response = match response.write_to_bytes() {
-        Ok(res) => res,
-        Err(err) => {
-            println!("Cannot encode protobuf result. Reason: {:?}", err);
-            return AllocationWithResult::default();
-        }
+    super::encode_response(response)
+}
+/// Handles incoming request for configuration of precompiles. Configuration is accepted only once
+pub fn handle_configure_precompiles_request(data: ConfigurePrecompilesRequest) -> AllocationWithResult {
+    let mut response = ConfigurePrecompilesResponse::new();
+    if let Err(err) = convert_precompile_config(data).and_then(precompiles::configure) {
+        response.set_error(err);
+    }
+
+    super::encode_response(response)
+}
+
+fn convert_precompile_config(data: ConfigurePrecompilesRequest) -> Result<PrecompileConfig, String> {
+    let mut config = PrecompileConfig::default();
+    for item in data.overrides.into_iter() {
+        let (address, params) = convert_precompile_override(item)?;
+        config.overrides.insert(address, params);
+    }
+
+    Ok(config)
+}
+
+fn convert_precompile_override(item: ProtoPrecompileOverride) -> Result<(H160, PrecompileOverride), String> {
+    if item.address.len() != 20 {
+        return Err("invalid precompile address".to_string());
+    }
+
+    let params = PrecompileOverride {
+        activation_height: item.activationHeight,
+        gas_override: Some(item.gasOverride).filter(|gas| *gas != 0),
+        disabled: item.disabled,
     };
-    
-    super::allocate_inner(encoded_response)
-}
\ No newline at end of file
+
+    Ok((H160::from_slice(&item.address), params))
+}
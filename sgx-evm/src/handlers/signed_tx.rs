@@ -0,0 +1,156 @@
+use crate::AllocationWithResult;
+use crate::backend;
+use crate::coder::u256_to_vec;
+use crate::protobuf_generated::ffi::{
+    AccessListItem, SGXVMCallParams, SGXVMCreateParams, SGXVMSignedTransactionRequest, SignedTransactionResponse,
+    TransactionContext as ProtoTransactionContext,
+};
+use crate::GoQuerier;
+use internal_types::ExecutionResult;
+use sgxvm::primitive_types::U256;
+use sgxvm::receipt::LEGACY_TX_TYPE;
+use sgxvm::signed_tx::SignedTransaction;
+use sgxvm::storage::cached_storage::CachedStorage;
+use sgxvm::Vicinity;
+use std::{string::{String, ToString}, vec::Vec};
+
+use super::tx::{build_receipt, build_transaction_context, build_transaction_response, execute_call, execute_create};
+
+/// Chain id of network, which signed transactions are accepted for. It is set at enclave build
+/// time, so the host cannot make enclave accept transactions signed for another chain.
+/// Enclave cannot be built without it
+const ENCLAVE_CHAIN_ID: u64 = parse_chain_id(env!("ENCLAVE_CHAIN_ID"));
+
+/// Parses decimal chain id during compilation
+const fn parse_chain_id(value: &str) -> u64 {
+    let bytes = value.as_bytes();
+    if bytes.is_empty() {
+        core::panic!("ENCLAVE_CHAIN_ID is empty");
+    }
+
+    let mut chain_id: u64 = 0;
+    let mut i = 0;
+    while i < bytes.len() {
+        if !bytes[i].is_ascii_digit() {
+            core::panic!("ENCLAVE_CHAIN_ID should be a decimal number");
+        }
+        chain_id = match chain_id.checked_mul(10) {
+            Some(value) => value,
+            None => core::panic!("ENCLAVE_CHAIN_ID does not fit into u64"),
+        };
+        chain_id = match chain_id.checked_add((bytes[i] - b'0') as u64) {
+            Some(value) => value,
+            None => core::panic!("ENCLAVE_CHAIN_ID does not fit into u64"),
+        };
+        i += 1;
+    }
+
+    chain_id
+}
+
+/// Handles incoming request for execution of signed Ethereum transaction. Sender is recovered
+/// from signature, so it cannot be forged by the host
+pub fn handle_signed_transaction_request(
+    querier: *mut GoQuerier,
+    data: SGXVMSignedTransactionRequest,
+) -> AllocationWithResult {
+    let context = data.context.unwrap();
+    let block_gas_used = context.block_gas_used;
+    let mut response = SignedTransactionResponse::new();
+
+    let recovered = enclave_chain_id(context.chain_id)
+        .and_then(|chain_id| SignedTransaction::recover(&data.rawTransaction, chain_id).map_err(|err| err.to_string()));
+
+    let (execution_result, tx_type) = match recovered {
+        Ok(transaction) => {
+            response.set_from(transaction.from.as_bytes().to_vec());
+            response.set_hash(transaction.hash.as_bytes().to_vec());
+            let tx_type = transaction.tx_type;
+            (execute_signed_transaction(querier, transaction, context, data.commit), tx_type)
+        },
+        Err(err) => (ExecutionResult::from_error(err, Vec::default(), None), LEGACY_TX_TYPE),
+    };
+
+    let cumulative_gas_used = block_gas_used.saturating_add(execution_result.gas_used);
+    let receipt = build_receipt(&execution_result, tx_type, cumulative_gas_used);
+    let mut transaction_response = build_transaction_response(execution_result);
+    transaction_response.set_receipt(receipt);
+    response.set_result(transaction_response);
+
+    super::encode_response(response)
+}
+
+/// Returns chain id pinned in the enclave. Chain id provided by the host in transaction context
+/// should match it, otherwise block environment would differ from the one transaction was signed for
+fn enclave_chain_id(context_chain_id: u64) -> Result<u64, String> {
+    if ENCLAVE_CHAIN_ID != context_chain_id {
+        return Err(format!("chain id mismatch: enclave {} context {}", ENCLAVE_CHAIN_ID, context_chain_id));
+    }
+
+    Ok(ENCLAVE_CHAIN_ID)
+}
+
+/// Executes transaction with verified sender. Transaction data is handled the same way
+/// as data of regular call or create request
+fn execute_signed_transaction(
+    querier: *mut GoQuerier,
+    transaction: SignedTransaction,
+    context: ProtoTransactionContext,
+    commit: bool,
+) -> ExecutionResult {
+    if transaction.nonce >= U256::from(u64::MAX) {
+        return ExecutionResult::from_error("nonce has max value".to_string(), Vec::default(), None);
+    }
+
+    let tx_context = match build_transaction_context(context) {
+        Ok(tx_context) => tx_context,
+        Err(err) => return ExecutionResult::from_error(err, Vec::default(), None),
+    };
+
+    let vicinity = Vicinity { origin: transaction.from };
+    let mut storage = crate::storage::FFIStorage::new(querier);
+    let mut cached_storage = CachedStorage::new(&mut storage);
+    let mut backend = match backend::FFIBackend::new(querier, &mut cached_storage, vicinity, tx_context) {
+        Ok(backend) => backend,
+        Err(err) => return ExecutionResult::from_error(err, Vec::default(), None),
+    };
+
+    let access_list = transaction
+        .access_list
+        .into_iter()
+        .map(|(address, slots)| {
+            let mut item = AccessListItem::new();
+            item.set_address(address.as_bytes().to_vec());
+            item.set_storageSlot(slots.into_iter().map(|slot| slot.as_bytes().to_vec()).collect());
+            item
+        })
+        .collect();
+
+    match transaction.to {
+        Some(to) => {
+            let mut params = SGXVMCallParams::new();
+            params.set_from(transaction.from.as_bytes().to_vec());
+            params.set_to(to.as_bytes().to_vec());
+            params.set_data(transaction.data);
+            params.set_gasLimit(transaction.gas_limit);
+            params.set_value(u256_to_vec(transaction.value));
+            params.set_accessList(access_list);
+            params.set_commit(commit);
+            params.set_nonce(transaction.nonce.as_u64());
+            params.set_txType(transaction.tx_type as u32);
+            execute_call(&mut backend, params, true)
+        },
+        None => {
+            let mut params = SGXVMCreateParams::new();
+            params.set_from(transaction.from.as_bytes().to_vec());
+            params.set_data(transaction.data);
+            params.set_gasLimit(transaction.gas_limit);
+            params.set_value(u256_to_vec(transaction.value));
+            params.set_accessList(access_list);
+            params.set_commit(commit);
+            params.set_nonce(transaction.nonce.as_u64());
+            params.set_txType(transaction.tx_type as u32);
+            execute_create(&mut backend, params)
+        },
+    }
+}
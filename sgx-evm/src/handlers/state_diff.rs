@@ -0,0 +1,106 @@
+use crate::AllocationWithResult;
+use crate::backend;
+use crate::coder::u256_to_vec;
+use crate::protobuf_generated::ffi::{
+    AccountDiff as ProtoAccountDiff, SGXVMStateDiffRequest, StateDiffResponse,
+    StorageDiff as ProtoStorageDiff,
+};
+use crate::GoQuerier;
+use internal_types::ExecutionResult;
+use sgxvm::primitive_types::{H160, U256};
+use sgxvm::state_diff::AccountDiff;
+use sgxvm::storage::cached_storage::CachedStorage;
+use sgxvm::{self, Vicinity};
+use std::{string::String, vec::Vec};
+
+use super::tx::{build_transaction_context, decrypt_call_data, encrypt_call_output, parse_access_list};
+
+/// Handles incoming request for simulation of transaction, which returns changes
+/// transaction would apply to the state. Contract storage is confidential and the caller
+/// does not prove any access rights to it, therefore only keys of changed slots are returned
+pub fn handle_state_diff_request(querier: *mut GoQuerier, data: SGXVMStateDiffRequest) -> AllocationWithResult {
+    let params = data.params.unwrap();
+    let context = data.context.unwrap();
+
+    let to = match params.to.len() {
+        0 => None,
+        _ => Some(H160::from_slice(&params.to)),
+    };
+
+    let (user_public_key, tx_data) = match decrypt_call_data(to.is_some(), params.data) {
+        Ok(res) => res,
+        Err(err) => return post_state_diff_error(err),
+    };
+
+    let tx_context = match build_transaction_context(context) {
+        Ok(tx_context) => tx_context,
+        Err(err) => return post_state_diff_error(err),
+    };
+
+    let vicinity = Vicinity { origin: H160::from_slice(&params.from) };
+    let mut storage = crate::storage::FFIStorage::new(querier);
+    // Repeated reads of the same account or slot are served from the enclave memory
+    let mut cached_storage = CachedStorage::new(&mut storage);
+    let mut backend = match backend::FFIBackend::new(querier, &mut cached_storage, vicinity, tx_context) {
+        Ok(backend) => backend,
+        Err(err) => return post_state_diff_error(err),
+    };
+
+    let (mut execution_result, state_diff) = sgxvm::handle_sgxvm_state_diff(
+        &mut backend,
+        params.gasLimit,
+        H160::from_slice(&params.from),
+        to,
+        U256::from_big_endian(&params.value),
+        tx_data,
+        parse_access_list(params.accessList),
+    );
+
+    if let Err(err) = encrypt_call_output(&mut execution_result, &user_public_key) {
+        return post_state_diff_error(err);
+    }
+
+    let accounts = state_diff
+        .map(|state_diff| state_diff.accounts.into_iter().map(convert_account_diff).collect())
+        .unwrap_or_default();
+
+    post_state_diff_handling(execution_result, accounts)
+}
+
+fn post_state_diff_error(err: String) -> AllocationWithResult {
+    let execution_result = ExecutionResult::from_error(err, Vec::default(), None);
+    post_state_diff_handling(execution_result, Vec::default())
+}
+
+fn post_state_diff_handling(execution_result: ExecutionResult, accounts: Vec<ProtoAccountDiff>) -> AllocationWithResult {
+    let mut response = StateDiffResponse::new();
+    response.set_result(super::tx::build_transaction_response(execution_result));
+    response.set_accounts(accounts.into());
+
+    super::encode_response(response)
+}
+
+/// Converts account diff into protobuf-compatible format. Storage values are omitted
+fn convert_account_diff(account: AccountDiff) -> ProtoAccountDiff {
+    let storage: Vec<ProtoStorageDiff> = account
+        .storage
+        .into_iter()
+        .map(|cell| {
+            let mut proto_cell = ProtoStorageDiff::new();
+            proto_cell.set_key(cell.index.as_bytes().to_vec());
+            proto_cell
+        })
+        .collect();
+
+    let mut proto_account = ProtoAccountDiff::new();
+    proto_account.set_address(account.address.as_bytes().to_vec());
+    proto_account.set_balanceBefore(u256_to_vec(account.balance_before));
+    proto_account.set_balanceAfter(u256_to_vec(account.balance_after));
+    proto_account.set_nonceBefore(account.nonce_before.low_u64());
+    proto_account.set_nonceAfter(account.nonce_after.low_u64());
+    proto_account.set_code(account.code.unwrap_or_default());
+    proto_account.set_deleted(account.deleted);
+    proto_account.set_storage(storage.into());
+
+    proto_account
+}
@@ -0,0 +1,262 @@
+use crate::AllocationWithResult;
+use crate::coder::u256_to_vec;
+use crate::encryption::{encrypt_transaction_data, extract_public_key_and_data};
+use crate::error::Error;
+use crate::protobuf_generated::ffi::{
+    CallFrame as ProtoCallFrame, FFIRequest, FFIRequest_oneof_req, SGXVMCallRequest,
+    SGXVMCreateRequest, SGXVMStructLogRequest, StructLog as ProtoStructLog,
+    StructLogResponse, StructLogs, TraceTransactionResponse,
+};
+use crate::GoQuerier;
+use internal_types::ExecutionResult;
+use lazy_static::lazy_static;
+use protobuf::Message;
+use sgxvm::tracing::{trace_calls, trace_steps, CallFrame, StructLog, StructLoggerConfig};
+use std::{
+    string::ToString,
+    sync::{SgxRwLock, SgxRwLockReadGuard, SgxRwLockWriteGuard},
+    vec::Vec,
+};
+
+lazy_static! {
+    /// Held for writing during traced execution and for reading during any other request
+    static ref EXECUTION_LOCK: SgxRwLock<()> = SgxRwLock::new(());
+}
+
+/// Guard, which prevents other requests from emitting events into attached tracer
+pub enum ExecutionGuard<'a> {
+    Shared(SgxRwLockReadGuard<'a, ()>),
+    Exclusive(SgxRwLockWriteGuard<'a, ()>),
+}
+
+impl ExecutionGuard<'static> {
+    /// Acquires exclusive guard for trace requests and shared guard for other requests
+    pub fn acquire(request: &FFIRequest) -> Self {
+        match request.req {
+            Some(FFIRequest_oneof_req::traceCallRequest(_))
+            | Some(FFIRequest_oneof_req::traceCreateRequest(_))
+            | Some(FFIRequest_oneof_req::structLogRequest(_)) => {
+                ExecutionGuard::Exclusive(EXECUTION_LOCK.write().unwrap_or_else(|err| err.into_inner()))
+            }
+            _ => ExecutionGuard::Shared(EXECUTION_LOCK.read().unwrap_or_else(|err| err.into_inner())),
+        }
+    }
+}
+
+/// Handles incoming request for calling contract with attached call tracer.
+/// Traced calls are never committed
+pub fn handle_trace_call_request(querier: *mut GoQuerier, mut data: SGXVMCallRequest) -> AllocationWithResult {
+    data.mut_params().set_commit(false);
+
+    // Inputs and outputs of encrypted transaction should be encrypted to the caller key
+    let tx_data = data.get_params().data.clone();
+    let is_encrypted = !tx_data.is_empty();
+    let user_public_key = match is_encrypted {
+        false => None,
+        true => extract_public_key_and_data(tx_data).ok().map(|(public_key, _)| public_key),
+    };
+
+    let (execution_result, call_trace) = trace_calls(|| {
+        super::tx::handle_call_request_inner(querier, data)
+    });
+
+    post_trace_handling(execution_result, call_trace, user_public_key, is_encrypted)
+}
+
+/// Handles incoming request for creation of a new contract with attached call tracer.
+/// Traced contract creations are never committed
+pub fn handle_trace_create_request(querier: *mut GoQuerier, mut data: SGXVMCreateRequest) -> AllocationWithResult {
+    data.mut_params().set_commit(false);
+
+    let (execution_result, call_trace) = trace_calls(|| {
+        super::tx::handle_create_request_inner(querier, data)
+    });
+
+    post_trace_handling(execution_result, call_trace, None, false)
+}
+
+/// Maximum amount of steps collected by struct logger
+const MAX_STRUCT_LOG_STEPS: usize = 100_000;
+/// Maximum approximate size of steps collected by struct logger
+const MAX_STRUCT_LOG_BYTES: usize = 16 * 1024 * 1024;
+
+/// Handles incoming request for calling contract with attached opcode-level struct logger.
+/// Stack, memory and storage contain decrypted contract state, which cannot be revealed to the
+/// caller since it does not prove any access rights to it. Therefore, struct logs contain only
+/// opcodes, gas and depth of each step. They are returned only for non-commit calls with encrypted
+/// data and are encrypted to the caller key
+pub fn handle_struct_log_request(querier: *mut GoQuerier, mut data: SGXVMStructLogRequest) -> AllocationWithResult {
+    let tx_data = data.get_params().data.clone();
+    let user_public_key = match tx_data.len() {
+        0 => None,
+        _ => extract_public_key_and_data(tx_data).ok().map(|(public_key, _)| public_key),
+    };
+
+    let user_public_key = match user_public_key {
+        Some(public_key) if !data.get_params().commit => public_key,
+        _ => {
+            let execution_result = ExecutionResult::from_error(
+                "struct logs are available only for non-commit calls with encrypted data".to_string(),
+                Vec::default(),
+                None,
+            );
+            return post_struct_log_handling(execution_result, Vec::default());
+        }
+    };
+
+    if data.get_config().enableMemory || data.get_config().enableStorage {
+        let execution_result = ExecutionResult::from_error(
+            "memory and storage cannot be included into struct logs, since they contain confidential data".to_string(),
+            Vec::default(),
+            None,
+        );
+        return post_struct_log_handling(execution_result, Vec::default());
+    }
+
+    let config = StructLoggerConfig {
+        max_steps: MAX_STRUCT_LOG_STEPS,
+        max_bytes: MAX_STRUCT_LOG_BYTES,
+        ..Default::default()
+    };
+
+    let mut call_request = SGXVMCallRequest::new();
+    call_request.set_params(data.take_params());
+    call_request.set_context(data.take_context());
+
+    let (execution_result, struct_logs) = trace_steps(config, || {
+        super::tx::handle_call_request_inner(querier, call_request)
+    });
+
+    let mut proto_struct_logs = StructLogs::new();
+    proto_struct_logs.set_logs(struct_logs.into_iter().map(convert_struct_log).collect());
+
+    let encoded_struct_logs = match proto_struct_logs.write_to_bytes() {
+        Ok(res) => res,
+        Err(err) => {
+            println!("Cannot encode struct logs. Reason: {:?}", err);
+            return AllocationWithResult::default();
+        }
+    };
+
+    let encrypted_struct_logs = match encrypt_transaction_data(encoded_struct_logs, user_public_key) {
+        Ok(res) => res,
+        Err(err) => {
+            println!("Cannot encrypt struct logs. Reason: {:?}", err);
+            return AllocationWithResult::default();
+        }
+    };
+
+    post_struct_log_handling(execution_result, encrypted_struct_logs)
+}
+
+fn post_struct_log_handling(execution_result: ExecutionResult, struct_logs: Vec<u8>) -> AllocationWithResult {
+    let mut response = StructLogResponse::new();
+    response.set_result(super::tx::build_transaction_response(execution_result));
+    response.set_structLogs(struct_logs);
+
+    super::encode_response(response)
+}
+
+fn post_trace_handling(
+    execution_result: ExecutionResult,
+    call_trace: Option<CallFrame>,
+    user_public_key: Option<Vec<u8>>,
+    is_encrypted: bool,
+) -> AllocationWithResult {
+    let mut response = TraceTransactionResponse::new();
+    response.set_result(super::tx::build_transaction_response(execution_result));
+
+    if let Some(call_trace) = call_trace {
+        match convert_call_frame(call_trace, &user_public_key, is_encrypted) {
+            Ok(call_trace) => response.set_callTrace(call_trace),
+            Err(err) => {
+                println!("Cannot encrypt call trace. Reason: {:?}", err);
+                return AllocationWithResult::default();
+            }
+        }
+    }
+
+    super::encode_response(response)
+}
+
+/// Converts call frame and its nested calls into protobuf-compatible format. Inputs and outputs
+/// of all frames are encrypted to the user public key, if it was provided. If transaction was
+/// encrypted, but public key is not available, inputs and outputs are not revealed
+fn convert_call_frame(
+    frame: CallFrame,
+    user_public_key: &Option<Vec<u8>>,
+    is_encrypted: bool,
+) -> Result<ProtoCallFrame, Error> {
+    let calls = frame
+        .calls
+        .into_iter()
+        .map(|call| convert_call_frame(call, user_public_key, is_encrypted))
+        .collect::<Result<Vec<ProtoCallFrame>, Error>>()?;
+
+    let (input, output) = match user_public_key {
+        Some(public_key) => (
+            encrypt_transaction_data(frame.input, public_key.clone())?,
+            encrypt_transaction_data(frame.output, public_key.clone())?,
+        ),
+        None if is_encrypted => (Vec::default(), Vec::default()),
+        None => (frame.input, frame.output),
+    };
+
+    let mut proto_frame = ProtoCallFrame::new();
+    proto_frame.set_field_type(frame.call_type);
+    proto_frame.set_from(frame.from.as_bytes().to_vec());
+    proto_frame.set_to(frame.to.as_bytes().to_vec());
+    proto_frame.set_value(u256_to_vec(frame.value));
+    proto_frame.set_gas(frame.gas);
+    proto_frame.set_input(input);
+    proto_frame.set_output(output);
+    proto_frame.set_error(frame.error.unwrap_or_default());
+    proto_frame.set_calls(calls.into());
+
+    Ok(proto_frame)
+}
+
+/// Converts struct log into protobuf-compatible format. Stack, memory and storage
+/// contain decrypted contract state, so they are dropped even if they were collected
+fn convert_struct_log(log: StructLog) -> ProtoStructLog {
+    let mut proto_log = ProtoStructLog::new();
+    proto_log.set_pc(log.pc);
+    proto_log.set_op(log.op);
+    proto_log.set_gas(log.gas);
+    proto_log.set_gasCost(log.gas_cost);
+    proto_log.set_depth(log.depth);
+    proto_log.set_error(log.error.unwrap_or_default());
+    proto_log
+}
+
+#[cfg(test)]
+mod test {
+    use super::convert_struct_log;
+    use sgxvm::primitive_types::H256;
+    use sgxvm::tracing::StructLog;
+    use std::{collections::BTreeMap, string::ToString};
+
+    #[test]
+    fn test_struct_log_contains_no_state() {
+        let mut storage = BTreeMap::new();
+        storage.insert(H256::from_low_u64_be(1), H256::from_low_u64_be(2));
+        let log = StructLog {
+            pc: 2,
+            op: "SLOAD".to_string(),
+            gas: 1000,
+            gas_cost: 2100,
+            depth: 1,
+            stack: vec![H256::from_low_u64_be(1)],
+            memory: Some(vec![0xff; 32]),
+            storage: Some(storage),
+            error: None,
+        };
+
+        let proto_log = convert_struct_log(log);
+        assert_eq!(proto_log.get_op(), "SLOAD");
+        assert_eq!(proto_log.get_gasCost(), 2100);
+        assert!(proto_log.get_stack().is_empty());
+        assert!(proto_log.get_memory().is_empty());
+        assert!(proto_log.get_storage().is_empty());
+    }
+}
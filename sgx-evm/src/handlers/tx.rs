@@ -1,31 +1,51 @@
 use crate::AllocationWithResult;
 use crate::encryption::{decrypt_transaction_data, extract_public_key_and_data, ENCRYPTED_DATA_LEN, encrypt_transaction_data};
 use crate::protobuf_generated::ffi::{
-    AccessListItem, HandleTransactionResponse, Log,
-    SGXVMCallRequest, SGXVMCreateRequest, Topic, TransactionContext as ProtoTransactionContext,
+    AccessListItem, HandleTransactionResponse, Log, Receipt as ProtoReceipt, RevertReason as ProtoRevertReason,
+    SGXVMCallParams, SGXVMCallRequest, SGXVMCreateParams, SGXVMCreateRequest, Topic, TransactionContext as ProtoTransactionContext,
 };
-use protobuf::Message;
 use sgxvm::primitive_types::{H160, H256, U256};
-use std::vec::Vec;
+use std::{string::{String, ToString}, vec::Vec};
+use sgxvm::receipt::{parse_tx_type, Receipt, LEGACY_TX_TYPE};
+use sgxvm::storage::cached_storage::CachedStorage;
 use sgxvm::{self, Vicinity};
-use internal_types::ExecutionResult;
+use sgxvm::evm::ExitReason;
+use internal_types::{ExecutionResult, RevertReason};
 use crate::backend;
 use crate::GoQuerier;
+use crate::key_manager::{KeyManager, UNSEALED_KEY_MANAGER};
 use protobuf::RepeatedField;
 
 /// Handles incoming request for calling contract or transferring value
 pub fn handle_call_request(querier: *mut GoQuerier, data: SGXVMCallRequest) -> AllocationWithResult {
-    let res = handle_call_request_inner(querier, data);
-    post_transaction_handling(res)
+    let block_gas_used = data.get_context().block_gas_used;
+    let (res, tx_type) = match parse_tx_type(data.get_params().txType) {
+        Ok(tx_type) => (handle_call_request_inner(querier, data), tx_type),
+        Err(err) => (ExecutionResult::from_error(err, Vec::default(), None), LEGACY_TX_TYPE),
+    };
+    post_transaction_handling(res, tx_type, block_gas_used)
 }
 
 /// Handles incoming request for creation of a new contract
 pub fn handle_create_request(querier: *mut GoQuerier, data: SGXVMCreateRequest) -> AllocationWithResult {
-    let res = handle_create_request_inner(querier, data);
-    post_transaction_handling(res)
+    let block_gas_used = data.get_context().block_gas_used;
+    let (res, tx_type) = match parse_tx_type(data.get_params().txType) {
+        Ok(tx_type) => (handle_create_request_inner(querier, data), tx_type),
+        Err(err) => (ExecutionResult::from_error(err, Vec::default(), None), LEGACY_TX_TYPE),
+    };
+    post_transaction_handling(res, tx_type, block_gas_used)
 }
 
-fn post_transaction_handling(execution_result: ExecutionResult) -> AllocationWithResult {
+fn post_transaction_handling(execution_result: ExecutionResult, tx_type: u8, block_gas_used: u64) -> AllocationWithResult {
+    let cumulative_gas_used = block_gas_used.saturating_add(execution_result.gas_used);
+    let receipt = build_receipt(&execution_result, tx_type, cumulative_gas_used);
+    let mut response = build_transaction_response(execution_result);
+    response.set_receipt(receipt);
+    super::encode_response(response)
+}
+
+/// Converts execution result into protobuf-compatible response
+pub fn build_transaction_response(execution_result: ExecutionResult) -> HandleTransactionResponse {
     let mut response = HandleTransactionResponse::new();
     response.set_gas_used(execution_result.gas_used);
     response.set_vm_error(execution_result.vm_error);
@@ -50,122 +70,193 @@ fn post_transaction_handling(execution_result: ExecutionResult) -> AllocationWit
 
     response.set_logs(converted_logs);
 
-    let encoded_response = match response.write_to_bytes() {
-        Ok(res) => res,
-        Err(err) => {
-            println!("Cannot encode protobuf result. Reason: {:?}", err);
-            return AllocationWithResult::default();
-        }
-    };
+    if let Some(revert_reason) = execution_result.revert_reason {
+        response.set_revert(convert_revert_reason(revert_reason));
+    }
+
+    if let Some(exit_reason) = execution_result.exit_reason {
+        let (exit_status, exit_reason) = match exit_reason {
+            ExitReason::Succeed(reason) => ("succeed", format!("{:?}", reason)),
+            ExitReason::Revert(reason) => ("revert", format!("{:?}", reason)),
+            ExitReason::Error(reason) => ("error", format!("{:?}", reason)),
+            ExitReason::Fatal(reason) => ("fatal", format!("{:?}", reason)),
+        };
+        response.set_exitStatus(exit_status.to_string());
+        response.set_exitReason(exit_reason);
+    }
 
-    super::allocate_inner(encoded_response)
+    if let Some(contract_address) = execution_result.contract_address {
+        response.set_contractAddress(contract_address.as_bytes().to_vec());
+    }
+
+    response.set_gasRefunded(execution_result.gas_refunded);
+    response.set_intrinsicGas(execution_result.intrinsic_gas);
+    response.set_executionGas(execution_result.execution_gas);
+
+    response
 }
 
-fn handle_call_request_inner(querier: *mut GoQuerier, data: SGXVMCallRequest) -> ExecutionResult {
+/// Builds receipt of executed transaction
+pub fn build_receipt(execution_result: &ExecutionResult, tx_type: u8, cumulative_gas_used: u64) -> ProtoReceipt {
+    let receipt = Receipt::new(tx_type, execution_result, cumulative_gas_used);
+
+    let mut proto_receipt = ProtoReceipt::new();
+    proto_receipt.set_status(receipt.status as u32);
+    proto_receipt.set_cumulativeGasUsed(receipt.cumulative_gas_used);
+    proto_receipt.set_logsBloom(receipt.logs_bloom.to_vec());
+    proto_receipt.set_encoded(receipt.encode());
+
+    proto_receipt
+}
+
+/// Converts decoded revert reason into protobuf-compatible format
+fn convert_revert_reason(revert_reason: RevertReason) -> ProtoRevertReason {
+    let mut proto_reason = ProtoRevertReason::new();
+    match revert_reason {
+        RevertReason::Error(message) => {
+            proto_reason.set_kind("error".to_string());
+            proto_reason.set_message(message);
+        },
+        RevertReason::Panic(code) => {
+            proto_reason.set_kind("panic".to_string());
+            proto_reason.set_message(RevertReason::panic_description(code).to_string());
+            proto_reason.set_panicCode(code);
+        },
+        RevertReason::Custom { selector, args } => {
+            proto_reason.set_kind("custom".to_string());
+            proto_reason.set_selector(selector.to_vec());
+            proto_reason.set_args(args);
+        },
+    }
+
+    proto_reason
+}
+
+pub fn handle_call_request_inner(querier: *mut GoQuerier, data: SGXVMCallRequest) -> ExecutionResult {
     let params = data.params.unwrap();
     let context = data.context.unwrap();
 
-    let vicinity = Vicinity {
-        origin: H160::from_slice(&params.from),
-        nonce: U256::from(params.nonce),
+    let tx_context = match build_transaction_context(context) {
+        Ok(tx_context) => tx_context,
+        Err(err) => return ExecutionResult::from_error(err, Vec::default(), None),
     };
+
+    let vicinity = Vicinity { origin: H160::from_slice(&params.from) };
     let mut storage = crate::storage::FFIStorage::new(querier);
-    let mut backend = backend::FFIBackend::new(
-        querier,
-        &mut storage,
-        vicinity,
-        build_transaction_context(context),
-    );
+    // Repeated reads of the same account or slot are served from the enclave memory
+    let mut cached_storage = CachedStorage::new(&mut storage);
+    let mut backend = match backend::FFIBackend::new(querier, &mut cached_storage, vicinity, tx_context) {
+        Ok(backend) => backend,
+        Err(err) => return ExecutionResult::from_error(err, Vec::default(), None),
+    };
 
-    // If data is empty, there should be no encryption of result. Otherwise we should try
-    // to extract user public key and encrypted data
-    match params.data.len() {
-        0 => {
-            sgxvm::handle_sgxvm_call(
-                &mut backend,
-                params.gasLimit,
-                H160::from_slice(&params.from),
-                H160::from_slice(&params.to),
-                U256::from_big_endian(&params.value),
-                params.data,
-                parse_access_list(params.accessList),
-                params.commit,
-            )
-        },
-        _ => {
-            // Extract user public key from transaction data
-            let (user_public_key, data) = match extract_public_key_and_data(params.data) {
-                Ok((user_public_key, data)) => (user_public_key, data),
-                Err(err) => {
-                    return ExecutionResult::from_error(
-                        format!("{:?}", err),
-                        Vec::default(),
-                        None
-                    );
-                }
-            };
-
-            // If encrypted data presents, decrypt it
-            let decrypted_data = if !data.is_empty() {
-                match decrypt_transaction_data(data, user_public_key.clone()) {
-                    Ok(decrypted_data) => decrypted_data,
-                    Err(err) => {
-                        return ExecutionResult::from_error(
-                            format!("{:?}", err),
-                            Vec::default(),
-                            None
-                        );
-                    }
-                }
-            } else { Vec::default() };
-
-            let mut exec_result = sgxvm::handle_sgxvm_call(
-                &mut backend,
-                params.gasLimit,
-                H160::from_slice(&params.from),
-                H160::from_slice(&params.to),
-                U256::from_big_endian(&params.value),
-                decrypted_data,
-                parse_access_list(params.accessList),
-                params.commit,
-            );
+    execute_call(&mut backend, params, false)
+}
 
-            // Encrypt transaction data output
-            let encrypted_data = match encrypt_transaction_data(exec_result.data, user_public_key) {
-                Ok(data) => data,
-                Err(err) => {
-                    return ExecutionResult::from_error(
-                        format!("{:?}", err),
-                        Vec::default(),
-                        None
-                    );
-                }
-            };
-
-            exec_result.data = encrypted_data;
-            exec_result
+/// Executes call using provided backend. If call data is not empty, it is decrypted
+/// before execution and returned data is encrypted to the caller key. Encrypted data
+/// is committed only if sender was recovered from transaction signature
+pub fn execute_call(backend: &mut backend::FFIBackend, params: SGXVMCallParams, sender_verified: bool) -> ExecutionResult {
+    if params.commit {
+        if let Err(err) = sgxvm::validate_nonce(&*backend, H160::from_slice(&params.from), params.nonce) {
+            return err;
+        }
+
+        // Otherwise the host could apply encrypted data of one user on behalf of another account
+        if !sender_verified && !params.data.is_empty() {
+            return ExecutionResult::from_error(
+                "encrypted data can be committed only within signed transaction".to_string(),
+                Vec::default(),
+                None,
+            );
         }
     }
+
+    let (user_public_key, data) = match decrypt_call_data(true, params.data) {
+        Ok(res) => res,
+        Err(err) => return ExecutionResult::from_error(err, Vec::default(), None),
+    };
+
+    let mut exec_result = sgxvm::handle_sgxvm_call(
+        backend,
+        params.gasLimit,
+        H160::from_slice(&params.from),
+        H160::from_slice(&params.to),
+        U256::from_big_endian(&params.value),
+        data,
+        parse_access_list(params.accessList),
+        params.commit,
+    );
+
+    match encrypt_call_output(&mut exec_result, &user_public_key) {
+        Ok(()) => exec_result,
+        Err(err) => ExecutionResult::from_error(err, Vec::default(), None),
+    }
 }
 
-fn handle_create_request_inner(querier: *mut GoQuerier, data: SGXVMCreateRequest) -> ExecutionResult {
+/// Decrypts data of contract call. Data of calls is encrypted, if it is not empty.
+/// Contract creation data is not encrypted. Returns public key of the caller, if data
+/// was encrypted, and data to execute.
+/// Sender of call and create requests is not verified, so the host is able to execute
+/// encrypted data on behalf of any account. Therefore, encrypted data of unsigned calls
+/// is decrypted only for non-commit calls, see `execute_call`
+pub fn decrypt_call_data(is_call: bool, data: Vec<u8>) -> Result<(Option<Vec<u8>>, Vec<u8>), String> {
+    if !is_call || data.is_empty() {
+        return Ok((None, data));
+    }
+
+    let (user_public_key, data) = extract_public_key_and_data(data).map_err(|err| format!("{:?}", err))?;
+    let decrypted_data = match data.is_empty() {
+        true => Vec::default(),
+        false => decrypt_transaction_data(data, user_public_key.clone()).map_err(|err| format!("{:?}", err))?,
+    };
+
+    Ok((Some(user_public_key), decrypted_data))
+}
+
+/// Encrypts data returned by encrypted call to the caller key. Revert reason of encrypted
+/// call is hidden, since it can be obtained only from decrypted data
+pub fn encrypt_call_output(execution_result: &mut ExecutionResult, user_public_key: &Option<Vec<u8>>) -> Result<(), String> {
+    if let Some(user_public_key) = user_public_key {
+        let data = core::mem::take(&mut execution_result.data);
+        execution_result.data = encrypt_transaction_data(data, user_public_key.clone()).map_err(|err| format!("{:?}", err))?;
+        execution_result.hide_revert_reason();
+    }
+
+    Ok(())
+}
+
+pub fn handle_create_request_inner(querier: *mut GoQuerier, data: SGXVMCreateRequest) -> ExecutionResult {
     let params = data.params.unwrap();
     let context = data.context.unwrap();
 
-    let vicinity = Vicinity {
-        origin: H160::from_slice(&params.from),
-        nonce: U256::from(params.nonce),
+    let tx_context = match build_transaction_context(context) {
+        Ok(tx_context) => tx_context,
+        Err(err) => return ExecutionResult::from_error(err, Vec::default(), None),
     };
+
+    let vicinity = Vicinity { origin: H160::from_slice(&params.from) };
     let mut storage = crate::storage::FFIStorage::new(querier);
-    let mut backend = backend::FFIBackend::new(
-        querier,
-        &mut storage,
-        vicinity,
-        build_transaction_context(context),
-    );
+    // Repeated reads of the same account or slot are served from the enclave memory
+    let mut cached_storage = CachedStorage::new(&mut storage);
+    let mut backend = match backend::FFIBackend::new(querier, &mut cached_storage, vicinity, tx_context) {
+        Ok(backend) => backend,
+        Err(err) => return ExecutionResult::from_error(err, Vec::default(), None),
+    };
+
+    execute_create(&mut backend, params)
+}
+
+/// Executes contract creation using provided backend
+pub fn execute_create(backend: &mut backend::FFIBackend, params: SGXVMCreateParams) -> ExecutionResult {
+    if params.commit {
+        if let Err(err) = sgxvm::validate_nonce(&*backend, H160::from_slice(&params.from), params.nonce) {
+            return err;
+        }
+    }
 
     sgxvm::handle_sgxvm_create(
-        &mut backend,
+        backend,
         params.gasLimit,
         H160::from_slice(&params.from),
         U256::from_big_endian(&params.value),
@@ -175,7 +266,7 @@ fn handle_create_request_inner(querier: *mut GoQuerier, data: SGXVMCreateRequest
     )
 }
 
-fn parse_access_list(data: RepeatedField<AccessListItem>) -> Vec<(H160, Vec<H256>)> {
+pub fn parse_access_list(data: RepeatedField<AccessListItem>) -> Vec<(H160, Vec<H256>)> {
     let mut access_list = Vec::default();
     for access_list_item in data.to_vec() {
         let address = H160::from_slice(&access_list_item.address);
@@ -192,8 +283,9 @@ fn parse_access_list(data: RepeatedField<AccessListItem>) -> Vec<(H160, Vec<H256
     access_list
 }
 
-fn build_transaction_context(context: ProtoTransactionContext) -> backend::TxContext {
-    backend::TxContext {
+/// Converts transaction context provided by Go side into context used by backend
+pub fn build_transaction_context(context: ProtoTransactionContext) -> Result<backend::TxContext, String> {
+    Ok(backend::TxContext {
         chain_id: U256::from(context.chain_id),
         gas_price: U256::from_big_endian(&context.gas_price),
         block_number: U256::from(context.block_number),
@@ -201,6 +293,22 @@ fn build_transaction_context(context: ProtoTransactionContext) -> backend::TxCon
         block_gas_limit: U256::from(context.block_gas_limit),
         block_base_fee_per_gas: U256::from_big_endian(&context.block_base_fee_per_gas),
         block_coinbase: H160::from_slice(&context.block_coinbase),
+        evm_fork: sgxvm::EvmFork::from(context.evm_fork),
+        prev_randao: parse_prev_randao(&context.prev_randao, (&*UNSEALED_KEY_MANAGER).as_ref())?,
+    })
+}
+
+/// Converts block randomness provided by Go side into value returned by PREVRANDAO opcode.
+/// Randomness is mixed with secret derived from master key. Since value returned by the opcode
+/// should be the same on every node, it cannot be obtained without master key
+fn parse_prev_randao(prev_randao: &[u8], key_manager: Option<&KeyManager>) -> Result<Option<H256>, String> {
+    if prev_randao.is_empty() {
+        return Ok(None);
+    }
+
+    match key_manager {
+        Some(key_manager) => Ok(Some(H256::from(key_manager.derive_block_randomness(prev_randao)))),
+        None => Err("cannot derive block randomness: master key is not initialized".to_string()),
     }
 }
 
@@ -210,3 +318,28 @@ fn convert_topic_to_proto(topic: H256) -> Topic {
 
     protobuf_topic
 }
+
+#[cfg(test)]
+mod test {
+    use super::parse_prev_randao;
+    use crate::key_manager::KeyManager;
+
+    #[test]
+    fn test_parse_prev_randao() {
+        let seed = [0x11u8; 32];
+
+        // Randomness is not derived without master key, since result would differ between nodes
+        assert!(parse_prev_randao(&seed, None).is_err());
+        assert_eq!(parse_prev_randao(&[], None), Ok(None));
+
+        let key_manager = KeyManager::random().unwrap();
+        let randomness = parse_prev_randao(&seed, Some(&key_manager)).unwrap().unwrap();
+        assert_ne!(randomness.as_bytes(), &seed);
+        assert_eq!(parse_prev_randao(&seed, Some(&key_manager)).unwrap(), Some(randomness));
+        assert_ne!(parse_prev_randao(&[0x22u8; 32], Some(&key_manager)).unwrap(), Some(randomness));
+
+        // Randomness depends on master key
+        let other_key_manager = KeyManager::random().unwrap();
+        assert_ne!(parse_prev_randao(&seed, Some(&other_key_manager)).unwrap(), Some(randomness));
+    }
+}
@@ -222,6 +222,19 @@ impl KeyManager {
         KeyManager::decrypt_deoxys(&contract_key, encrypted_value)
     }
 
+    /// Mixes provided block randomness with secret derived from master key, so it cannot be
+    /// computed outside of the enclave. It is not unpredictable to validators: anyone running
+    /// an enclave with the master key can obtain it for a known seed by dry-running a transaction
+    pub fn derive_block_randomness(&self, seed: &[u8]) -> [u8; 32] {
+        let randomness_key = KeyManager::derive_key(&self.master_key, b"BlockRandomnessKeyV1");
+        let mut mac = Hmac::<sha2::Sha256>::new_from_slice(&randomness_key).expect("Unable to create HMAC");
+        mac.update(seed);
+        let mut randomness = [0u8; 32];
+        randomness.copy_from_slice(&mac.finalize().into_bytes()[..32]);
+
+        randomness
+    }
+
     /// Encrypts provided plaintext using DEOXYS-II
     fn encrypt_deoxys(encryption_key: &[u8; PRIVATE_KEY_SIZE], plaintext: Vec<u8>) -> Result<Vec<u8>, Error> {
         // For now, nonce and ad values is deterministic. In future releases we will make
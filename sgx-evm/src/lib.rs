@@ -25,6 +25,7 @@ mod encryption;
 mod attestation;
 mod key_manager;
 mod handlers;
+mod precompiles;
 
 pub const MAX_RESULT_LEN: usize = 4096;
 
@@ -94,6 +95,11 @@ pub extern "C" fn handle_request(
         }
     };
 
+    // Event listeners of evm are global inside the enclave, so traced execution
+    // cannot run simultaneously with any other request
+    #[cfg(feature = "tracing")]
+    let _execution_guard = handlers::trace::ExecutionGuard::acquire(&ffi_request);
+
     match ffi_request.req {
         Some(req) => {
             match req {
@@ -105,6 +111,43 @@ pub extern "C" fn handle_request(
                 },
                 FFIRequest_oneof_req::publicKeyRequest(_) => {
                     handlers::node::handle_public_key_request()
+                },
+                #[cfg(feature = "tracing")]
+                FFIRequest_oneof_req::traceCallRequest(data) => {
+                    handlers::trace::handle_trace_call_request(querier, data)
+                },
+                #[cfg(feature = "tracing")]
+                FFIRequest_oneof_req::traceCreateRequest(data) => {
+                    handlers::trace::handle_trace_create_request(querier, data)
+                },
+                #[cfg(feature = "tracing")]
+                FFIRequest_oneof_req::structLogRequest(data) => {
+                    handlers::trace::handle_struct_log_request(querier, data)
+                },
+                #[cfg(not(feature = "tracing"))]
+                FFIRequest_oneof_req::traceCallRequest(_)
+                | FFIRequest_oneof_req::traceCreateRequest(_)
+                | FFIRequest_oneof_req::structLogRequest(_) => {
+                    println!("Tracing is not enabled in this enclave build");
+                    AllocationWithResult::default()
+                },
+                FFIRequest_oneof_req::estimateGasRequest(data) => {
+                    handlers::estimate::handle_estimate_gas_request(querier, data)
+                },
+                FFIRequest_oneof_req::stateDiffRequest(data) => {
+                    handlers::state_diff::handle_state_diff_request(querier, data)
+                },
+                FFIRequest_oneof_req::createAccessListRequest(data) => {
+                    handlers::access_list::handle_create_access_list_request(querier, data)
+                },
+                FFIRequest_oneof_req::batchRequest(data) => {
+                    handlers::batch::handle_batch_request(querier, data)
+                },
+                FFIRequest_oneof_req::signedTransactionRequest(data) => {
+                    handlers::signed_tx::handle_signed_transaction_request(querier, data)
+                },
+                FFIRequest_oneof_req::configurePrecompilesRequest(data) => {
+                    handlers::node::handle_configure_precompiles_request(data)
                 }
             }
         }
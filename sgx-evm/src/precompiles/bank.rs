@@ -0,0 +1,108 @@
+use sgxvm::evm::executor::stack::{PrecompileHandle, PrecompileOutput, PrecompileFailure};
+use sgxvm::evm::ExitSucceed;
+use sgxvm::primitive_types::{H160, U256};
+use std::string::String;
+
+use crate::coder;
+use crate::precompiles::{precompile_error, PrecompileContext, PrecompileResult, StatefulPrecompile};
+use crate::protobuf_generated::ffi;
+
+/// Cost of bank balance query. Equals to the cost of cold account access
+pub const BANK_BALANCE_GAS: u64 = 2600;
+
+/// Returns balance of Cosmos account in provided denomination from bank module.
+/// Input is ABI-encoded `(address account, string denom)`, output is ABI-encoded `uint256` balance.
+///
+/// Balance is read from committed state of bank module. Balance changes made by the current
+/// transaction or by previous transactions of the same batch are buffered inside the enclave
+/// until they are written, so they are not visible to this precompile.
+///
+/// Precompile is available only after it is activated by precompile configuration of the node
+pub struct BankBalance;
+
+impl StatefulPrecompile for BankBalance {
+    fn execute(context: &PrecompileContext, handle: &mut impl PrecompileHandle) -> PrecompileResult {
+        handle.record_cost(BANK_BALANCE_GAS)?;
+
+        let (account, denom) = decode_input(handle.input())?;
+        let response = context.query::<ffi::QueryBankBalanceResponse>(
+            coder::encode_query_bank_balance(&account, &denom)
+        )?;
+        if response.balance.len() > 32 {
+            return Err(precompile_error("Bank balance does not fit into uint256"));
+        }
+
+        let balance = U256::from_big_endian(response.balance.as_slice());
+        Ok(PrecompileOutput {
+            exit_status: ExitSucceed::Returned,
+            output: coder::u256_to_vec(balance),
+        })
+    }
+}
+
+/// Decodes ABI-encoded `(address, string)` input
+fn decode_input(input: &[u8]) -> Result<(H160, String), PrecompileFailure> {
+    let invalid_input = || precompile_error("Invalid input. Expected ABI-encoded (address, string)");
+
+    if input.len() < 96 || input[..12] != [0u8; 12] {
+        return Err(invalid_input());
+    }
+    let account = H160::from_slice(&input[12..32]);
+
+    let offset = read_usize(&input[32..64]).ok_or_else(invalid_input)?;
+    let length_end = offset.checked_add(32).ok_or_else(invalid_input)?;
+    let length = input
+        .get(offset..length_end)
+        .and_then(read_usize)
+        .ok_or_else(invalid_input)?;
+    let denom_end = length_end.checked_add(length).ok_or_else(invalid_input)?;
+    let denom = input.get(length_end..denom_end).ok_or_else(invalid_input)?;
+    let denom = String::from_utf8(denom.to_vec()).map_err(|_| invalid_input())?;
+
+    Ok((account, denom))
+}
+
+/// Reads ABI-encoded `uint256`, which should fit into `usize`
+fn read_usize(word: &[u8]) -> Option<usize> {
+    let value = U256::from_big_endian(word);
+    match value > U256::from(usize::MAX) {
+        true => None,
+        false => Some(value.as_usize()),
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::decode_input;
+    use sgxvm::primitive_types::H160;
+    use std::vec::Vec;
+
+    #[test]
+    fn test_decode_input() {
+        let account = H160::from_low_u64_be(0xabcd);
+        let denom = "uswtr";
+
+        let mut input = Vec::new();
+        input.extend_from_slice(&[0u8; 12]);
+        input.extend_from_slice(account.as_bytes());
+        let mut word = [0u8; 32];
+        word[31] = 64;
+        input.extend_from_slice(&word);
+        word[31] = denom.len() as u8;
+        input.extend_from_slice(&word);
+        let mut denom_word = [0u8; 32];
+        denom_word[..denom.len()].copy_from_slice(denom.as_bytes());
+        input.extend_from_slice(&denom_word);
+
+        let (decoded_account, decoded_denom) = decode_input(&input).unwrap();
+        assert_eq!(decoded_account, account);
+        assert_eq!(decoded_denom, denom);
+
+        // Truncated input
+        assert!(decode_input(&input[..96]).is_err());
+        // Dirty address padding
+        let mut dirty = input.clone();
+        dirty[0] = 1;
+        assert!(decode_input(&dirty).is_err());
+    }
+}
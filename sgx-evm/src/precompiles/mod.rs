@@ -0,0 +1,134 @@
+use sgxvm::evm::executor::stack::{PrecompileFailure, PrecompileHandle, PrecompileOutput};
+use sgxvm::evm::ExitError;
+use lazy_static::lazy_static;
+use sgxvm::precompiles::{PrecompileConfig, PrecompileEntry, PrecompileRegistry};
+use sgxvm::primitive_types::H160;
+use sgxvm::EvmFork;
+use std::{string::{String, ToString}, sync::SgxRwLock, vec::Vec};
+
+use crate::key_manager::{KeyManager, UNSEALED_KEY_MANAGER};
+use crate::ocall;
+use crate::querier::GoQuerier;
+
+pub mod bank;
+
+use bank::BankBalance;
+
+/// Address of precompile, which returns balance of Cosmos account from bank module
+pub const BANK_BALANCE_ADDRESS: u64 = 0x0800;
+
+pub type PrecompileResult = Result<PrecompileOutput, PrecompileFailure>;
+
+lazy_static! {
+    /// Configuration of precompiles provided by the node on start. Until it is set,
+    /// precompiles are used with their default parameters
+    static ref PRECOMPILE_CONFIG: SgxRwLock<Option<PrecompileConfig>> = SgxRwLock::new(None);
+}
+
+/// Context available to stateful precompiles during execution
+#[derive(Clone, Copy)]
+pub struct PrecompileContext {
+    // Querier to make requests to Cosmos modules
+    pub querier: *mut GoQuerier,
+    // Key manager of the enclave. Empty if master key is not initialized yet
+    pub key_manager: Option<&'static KeyManager>,
+}
+
+impl PrecompileContext {
+    pub fn new(querier: *mut GoQuerier) -> Self {
+        Self {
+            querier,
+            key_manager: (&*UNSEALED_KEY_MANAGER).as_ref(),
+        }
+    }
+
+    /// Sends encoded `CosmosRequest` to Go side and decodes response of provided type
+    pub fn query<T: protobuf::Message>(&self, request: Vec<u8>) -> Result<T, PrecompileFailure> {
+        let response = ocall::make_request(self.querier, request)
+            .ok_or_else(|| precompile_error("Cosmos query failed. Empty response"))?;
+
+        protobuf::parse_from_bytes::<T>(response.as_slice()).map_err(|err| {
+            println!("Cannot decode protobuf response: {:?}", err);
+            precompile_error("Cannot decode Cosmos query response")
+        })
+    }
+}
+
+/// Precompile, which has access to Cosmos state and key material of the enclave
+pub trait StatefulPrecompile {
+    /// Try to execute the precompile with given `handle` which provides all call data
+    /// and allow to register costs and logs. `context` allows to query Cosmos modules
+    fn execute(context: &PrecompileContext, handle: &mut impl PrecompileHandle) -> PrecompileResult;
+}
+
+/// Creates registry entry for stateful precompile bound to provided context
+pub fn stateful_entry<P: StatefulPrecompile>(context: PrecompileContext) -> PrecompileEntry {
+    PrecompileEntry::from_fn(move |handle| P::execute(&context, handle))
+}
+
+/// Returns precompiles available for transactions executed within the enclave.
+/// Besides precompiles provided by `sgxvm`, contains precompiles bridging EVM with Cosmos modules:
+/// * Bank balance at 0x800. Returns committed balance, see `BankBalance`
+///
+/// Precompiles bridging EVM with Cosmos modules are not available until node activates them
+/// by configuration, since node should provide requested module to the enclave
+///
+/// Configuration of precompiles provided by the node is applied to returned registry.
+/// Returns error if fork cannot be executed or configuration cannot be applied
+pub fn registry(fork: EvmFork, querier: *mut GoQuerier) -> Result<PrecompileRegistry, String> {
+    fork.config()?;
+
+    let mut registry = default_registry(fork, querier);
+    let config = PRECOMPILE_CONFIG
+        .read()
+        .map_err(|_| "cannot access precompile configuration".to_string())?;
+    if let Some(config) = config.as_ref() {
+        // Configuration can refer to precompiles of other forks, see `configure`
+        let overrides = config
+            .overrides
+            .iter()
+            .filter(|(address, _)| registry.contains(address))
+            .map(|(address, params)| (*address, params.clone()))
+            .collect();
+        registry.configure(&PrecompileConfig { overrides })?;
+    }
+
+    Ok(registry)
+}
+
+/// Sets configuration of precompiles. Configuration can be set only once, so it cannot be
+/// changed while node is running
+pub fn configure(config: PrecompileConfig) -> Result<(), String> {
+    // Check that configuration refers only to precompiles registered for one of supported forks
+    let registries: Vec<PrecompileRegistry> = EvmFork::SUPPORTED
+        .iter()
+        .map(|fork| default_registry(*fork, std::ptr::null_mut()))
+        .collect();
+    for address in config.overrides.keys() {
+        if !registries.iter().any(|registry| registry.contains(address)) {
+            return Err(format!("cannot configure precompile {:?}: precompile is not supported", address));
+        }
+    }
+
+    let mut stored_config = PRECOMPILE_CONFIG
+        .write()
+        .map_err(|_| "cannot access precompile configuration".to_string())?;
+    if stored_config.is_some() {
+        return Err("precompiles are already configured".to_string());
+    }
+    *stored_config = Some(config);
+
+    Ok(())
+}
+
+fn default_registry(fork: EvmFork, querier: *mut GoQuerier) -> PrecompileRegistry {
+    let context = PrecompileContext::new(querier);
+    PrecompileRegistry::for_fork(fork)
+        .with(H160::from_low_u64_be(BANK_BALANCE_ADDRESS), stateful_entry::<BankBalance>(context).inactive())
+}
+
+pub fn precompile_error(reason: &'static str) -> PrecompileFailure {
+    PrecompileFailure::Error {
+        exit_status: ExitError::Other(reason.into()),
+    }
+}
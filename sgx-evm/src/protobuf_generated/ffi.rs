@@ -628,6 +628,9 @@ pub struct TransactionContext {
     pub block_base_fee_per_gas: ::std::vec::Vec<u8>,
     pub block_coinbase: ::std::vec::Vec<u8>,
     pub block_number: u64,
+    pub evm_fork: u32,
+    pub prev_randao: ::std::vec::Vec<u8>,
+    pub block_gas_used: u64,
     // special fields
     pub unknown_fields: ::protobuf::UnknownFields,
     pub cached_size: ::protobuf::CachedSize,
@@ -781,6 +784,62 @@ impl TransactionContext {
     pub fn set_block_number(&mut self, v: u64) {
         self.block_number = v;
     }
+
+    // uint32 evm_fork = 8;
+
+
+    pub fn get_evm_fork(&self) -> u32 {
+        self.evm_fork
+    }
+    pub fn clear_evm_fork(&mut self) {
+        self.evm_fork = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_evm_fork(&mut self, v: u32) {
+        self.evm_fork = v;
+    }
+
+    // bytes prev_randao = 9;
+
+
+    pub fn get_prev_randao(&self) -> &[u8] {
+        &self.prev_randao
+    }
+    pub fn clear_prev_randao(&mut self) {
+        self.prev_randao.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_prev_randao(&mut self, v: ::std::vec::Vec<u8>) {
+        self.prev_randao = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_prev_randao(&mut self) -> &mut ::std::vec::Vec<u8> {
+        &mut self.prev_randao
+    }
+
+    // Take field
+    pub fn take_prev_randao(&mut self) -> ::std::vec::Vec<u8> {
+        ::std::mem::replace(&mut self.prev_randao, ::std::vec::Vec::new())
+    }
+
+    // uint64 block_gas_used = 10;
+
+
+    pub fn get_block_gas_used(&self) -> u64 {
+        self.block_gas_used
+    }
+    pub fn clear_block_gas_used(&mut self) {
+        self.block_gas_used = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_block_gas_used(&mut self, v: u64) {
+        self.block_gas_used = v;
+    }
 }
 
 impl ::protobuf::Message for TransactionContext {
@@ -829,6 +888,23 @@ impl ::protobuf::Message for TransactionContext {
                     let tmp = is.read_uint64()?;
                     self.block_number = tmp;
                 },
+                8 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_uint32()?;
+                    self.evm_fork = tmp;
+                },
+                9 => {
+                    ::protobuf::rt::read_singular_proto3_bytes_into(wire_type, is, &mut self.prev_randao)?;
+                },
+                10 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_uint64()?;
+                    self.block_gas_used = tmp;
+                },
                 _ => {
                     ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
                 },
@@ -862,6 +938,15 @@ impl ::protobuf::Message for TransactionContext {
         if self.block_number != 0 {
             my_size += ::protobuf::rt::value_size(7, self.block_number, ::protobuf::wire_format::WireTypeVarint);
         }
+        if self.evm_fork != 0 {
+            my_size += ::protobuf::rt::value_size(8, self.evm_fork, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if !self.prev_randao.is_empty() {
+            my_size += ::protobuf::rt::bytes_size(9, &self.prev_randao);
+        }
+        if self.block_gas_used != 0 {
+            my_size += ::protobuf::rt::value_size(10, self.block_gas_used, ::protobuf::wire_format::WireTypeVarint);
+        }
         my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
         self.cached_size.set(my_size);
         my_size
@@ -889,6 +974,15 @@ impl ::protobuf::Message for TransactionContext {
         if self.block_number != 0 {
             os.write_uint64(7, self.block_number)?;
         }
+        if self.evm_fork != 0 {
+            os.write_uint32(8, self.evm_fork)?;
+        }
+        if !self.prev_randao.is_empty() {
+            os.write_bytes(9, &self.prev_randao)?;
+        }
+        if self.block_gas_used != 0 {
+            os.write_uint64(10, self.block_gas_used)?;
+        }
         os.write_unknown_fields(self.get_unknown_fields())?;
         ::std::result::Result::Ok(())
     }
@@ -966,6 +1060,21 @@ impl ::protobuf::Message for TransactionContext {
                     |m: &TransactionContext| { &m.block_number },
                     |m: &mut TransactionContext| { &mut m.block_number },
                 ));
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeUint32>(
+                    "evm_fork",
+                    |m: &TransactionContext| { &m.evm_fork },
+                    |m: &mut TransactionContext| { &mut m.evm_fork },
+                ));
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeBytes>(
+                    "prev_randao",
+                    |m: &TransactionContext| { &m.prev_randao },
+                    |m: &mut TransactionContext| { &mut m.prev_randao },
+                ));
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeUint64>(
+                    "block_gas_used",
+                    |m: &TransactionContext| { &m.block_gas_used },
+                    |m: &mut TransactionContext| { &mut m.block_gas_used },
+                ));
                 ::protobuf::reflect::MessageDescriptor::new::<TransactionContext>(
                     "TransactionContext",
                     fields,
@@ -995,6 +1104,9 @@ impl ::protobuf::Clear for TransactionContext {
         self.block_base_fee_per_gas.clear();
         self.block_coinbase.clear();
         self.block_number = 0;
+        self.evm_fork = 0;
+        self.prev_randao.clear();
+        self.block_gas_used = 0;
         self.unknown_fields.clear();
     }
 }
@@ -1259,6 +1371,14 @@ pub struct HandleTransactionResponse {
     pub ret: ::std::vec::Vec<u8>,
     pub vm_error: ::std::string::String,
     pub gas_used: u64,
+    pub revert: ::protobuf::SingularPtrField<RevertReason>,
+    pub exitStatus: ::std::string::String,
+    pub exitReason: ::std::string::String,
+    pub contractAddress: ::std::vec::Vec<u8>,
+    pub gasRefunded: u64,
+    pub intrinsicGas: u64,
+    pub executionGas: u64,
+    pub receipt: ::protobuf::SingularPtrField<Receipt>,
     // special fields
     pub unknown_fields: ::protobuf::UnknownFields,
     pub cached_size: ::protobuf::CachedSize,
@@ -1366,6 +1486,195 @@ impl HandleTransactionResponse {
     pub fn set_gas_used(&mut self, v: u64) {
         self.gas_used = v;
     }
+
+    // .ffi.ffi.RevertReason revert = 6;
+
+
+    pub fn get_revert(&self) -> &RevertReason {
+        self.revert.as_ref().unwrap_or_else(|| RevertReason::default_instance())
+    }
+    pub fn clear_revert(&mut self) {
+        self.revert.clear();
+    }
+
+    pub fn has_revert(&self) -> bool {
+        self.revert.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_revert(&mut self, v: RevertReason) {
+        self.revert = ::protobuf::SingularPtrField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_revert(&mut self) -> &mut RevertReason {
+        if self.revert.is_none() {
+            self.revert.set_default();
+        }
+        self.revert.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_revert(&mut self) -> RevertReason {
+        self.revert.take().unwrap_or_else(|| RevertReason::new())
+    }
+
+    // string exitStatus = 7;
+
+
+    pub fn get_exitStatus(&self) -> &str {
+        &self.exitStatus
+    }
+    pub fn clear_exitStatus(&mut self) {
+        self.exitStatus.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_exitStatus(&mut self, v: ::std::string::String) {
+        self.exitStatus = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_exitStatus(&mut self) -> &mut ::std::string::String {
+        &mut self.exitStatus
+    }
+
+    // Take field
+    pub fn take_exitStatus(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.exitStatus, ::std::string::String::new())
+    }
+
+    // string exitReason = 8;
+
+
+    pub fn get_exitReason(&self) -> &str {
+        &self.exitReason
+    }
+    pub fn clear_exitReason(&mut self) {
+        self.exitReason.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_exitReason(&mut self, v: ::std::string::String) {
+        self.exitReason = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_exitReason(&mut self) -> &mut ::std::string::String {
+        &mut self.exitReason
+    }
+
+    // Take field
+    pub fn take_exitReason(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.exitReason, ::std::string::String::new())
+    }
+
+    // bytes contractAddress = 9;
+
+
+    pub fn get_contractAddress(&self) -> &[u8] {
+        &self.contractAddress
+    }
+    pub fn clear_contractAddress(&mut self) {
+        self.contractAddress.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_contractAddress(&mut self, v: ::std::vec::Vec<u8>) {
+        self.contractAddress = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_contractAddress(&mut self) -> &mut ::std::vec::Vec<u8> {
+        &mut self.contractAddress
+    }
+
+    // Take field
+    pub fn take_contractAddress(&mut self) -> ::std::vec::Vec<u8> {
+        ::std::mem::replace(&mut self.contractAddress, ::std::vec::Vec::new())
+    }
+
+    // uint64 gasRefunded = 10;
+
+
+    pub fn get_gasRefunded(&self) -> u64 {
+        self.gasRefunded
+    }
+    pub fn clear_gasRefunded(&mut self) {
+        self.gasRefunded = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_gasRefunded(&mut self, v: u64) {
+        self.gasRefunded = v;
+    }
+
+    // uint64 intrinsicGas = 11;
+
+
+    pub fn get_intrinsicGas(&self) -> u64 {
+        self.intrinsicGas
+    }
+    pub fn clear_intrinsicGas(&mut self) {
+        self.intrinsicGas = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_intrinsicGas(&mut self, v: u64) {
+        self.intrinsicGas = v;
+    }
+
+    // uint64 executionGas = 12;
+
+
+    pub fn get_executionGas(&self) -> u64 {
+        self.executionGas
+    }
+    pub fn clear_executionGas(&mut self) {
+        self.executionGas = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_executionGas(&mut self, v: u64) {
+        self.executionGas = v;
+    }
+
+    // .ffi.ffi.Receipt receipt = 13;
+
+
+    pub fn get_receipt(&self) -> &Receipt {
+        self.receipt.as_ref().unwrap_or_else(|| Receipt::default_instance())
+    }
+    pub fn clear_receipt(&mut self) {
+        self.receipt.clear();
+    }
+
+    pub fn has_receipt(&self) -> bool {
+        self.receipt.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_receipt(&mut self, v: Receipt) {
+        self.receipt = ::protobuf::SingularPtrField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_receipt(&mut self) -> &mut Receipt {
+        if self.receipt.is_none() {
+            self.receipt.set_default();
+        }
+        self.receipt.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_receipt(&mut self) -> Receipt {
+        self.receipt.take().unwrap_or_else(|| Receipt::new())
+    }
 }
 
 impl ::protobuf::Message for HandleTransactionResponse {
@@ -1375,6 +1684,16 @@ impl ::protobuf::Message for HandleTransactionResponse {
                 return false;
             }
         };
+        for v in &self.revert {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        for v in &self.receipt {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
         true
     }
 
@@ -1398,6 +1717,42 @@ impl ::protobuf::Message for HandleTransactionResponse {
                     let tmp = is.read_uint64()?;
                     self.gas_used = tmp;
                 },
+                6 => {
+                    ::protobuf::rt::read_singular_message_into(wire_type, is, &mut self.revert)?;
+                },
+                7 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.exitStatus)?;
+                },
+                8 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.exitReason)?;
+                },
+                9 => {
+                    ::protobuf::rt::read_singular_proto3_bytes_into(wire_type, is, &mut self.contractAddress)?;
+                },
+                10 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_uint64()?;
+                    self.gasRefunded = tmp;
+                },
+                11 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_uint64()?;
+                    self.intrinsicGas = tmp;
+                },
+                12 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_uint64()?;
+                    self.executionGas = tmp;
+                },
+                13 => {
+                    ::protobuf::rt::read_singular_message_into(wire_type, is, &mut self.receipt)?;
+                },
                 _ => {
                     ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
                 },
@@ -1423,6 +1778,32 @@ impl ::protobuf::Message for HandleTransactionResponse {
         if self.gas_used != 0 {
             my_size += ::protobuf::rt::value_size(5, self.gas_used, ::protobuf::wire_format::WireTypeVarint);
         }
+        if let Some(ref v) = self.revert.as_ref() {
+            let len = v.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        }
+        if !self.exitStatus.is_empty() {
+            my_size += ::protobuf::rt::string_size(7, &self.exitStatus);
+        }
+        if !self.exitReason.is_empty() {
+            my_size += ::protobuf::rt::string_size(8, &self.exitReason);
+        }
+        if !self.contractAddress.is_empty() {
+            my_size += ::protobuf::rt::bytes_size(9, &self.contractAddress);
+        }
+        if self.gasRefunded != 0 {
+            my_size += ::protobuf::rt::value_size(10, self.gasRefunded, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if self.intrinsicGas != 0 {
+            my_size += ::protobuf::rt::value_size(11, self.intrinsicGas, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if self.executionGas != 0 {
+            my_size += ::protobuf::rt::value_size(12, self.executionGas, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if let Some(ref v) = self.receipt.as_ref() {
+            let len = v.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        }
         my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
         self.cached_size.set(my_size);
         my_size
@@ -1443,6 +1824,34 @@ impl ::protobuf::Message for HandleTransactionResponse {
         if self.gas_used != 0 {
             os.write_uint64(5, self.gas_used)?;
         }
+        if let Some(ref v) = self.revert.as_ref() {
+            os.write_tag(6, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        }
+        if !self.exitStatus.is_empty() {
+            os.write_string(7, &self.exitStatus)?;
+        }
+        if !self.exitReason.is_empty() {
+            os.write_string(8, &self.exitReason)?;
+        }
+        if !self.contractAddress.is_empty() {
+            os.write_bytes(9, &self.contractAddress)?;
+        }
+        if self.gasRefunded != 0 {
+            os.write_uint64(10, self.gasRefunded)?;
+        }
+        if self.intrinsicGas != 0 {
+            os.write_uint64(11, self.intrinsicGas)?;
+        }
+        if self.executionGas != 0 {
+            os.write_uint64(12, self.executionGas)?;
+        }
+        if let Some(ref v) = self.receipt.as_ref() {
+            os.write_tag(13, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        }
         os.write_unknown_fields(self.get_unknown_fields())?;
         ::std::result::Result::Ok(())
     }
@@ -1505,6 +1914,46 @@ impl ::protobuf::Message for HandleTransactionResponse {
                     |m: &HandleTransactionResponse| { &m.gas_used },
                     |m: &mut HandleTransactionResponse| { &mut m.gas_used },
                 ));
+                fields.push(::protobuf::reflect::accessor::make_singular_ptr_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<RevertReason>>(
+                    "revert",
+                    |m: &HandleTransactionResponse| { &m.revert },
+                    |m: &mut HandleTransactionResponse| { &mut m.revert },
+                ));
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                    "exitStatus",
+                    |m: &HandleTransactionResponse| { &m.exitStatus },
+                    |m: &mut HandleTransactionResponse| { &mut m.exitStatus },
+                ));
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                    "exitReason",
+                    |m: &HandleTransactionResponse| { &m.exitReason },
+                    |m: &mut HandleTransactionResponse| { &mut m.exitReason },
+                ));
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeBytes>(
+                    "contractAddress",
+                    |m: &HandleTransactionResponse| { &m.contractAddress },
+                    |m: &mut HandleTransactionResponse| { &mut m.contractAddress },
+                ));
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeUint64>(
+                    "gasRefunded",
+                    |m: &HandleTransactionResponse| { &m.gasRefunded },
+                    |m: &mut HandleTransactionResponse| { &mut m.gasRefunded },
+                ));
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeUint64>(
+                    "intrinsicGas",
+                    |m: &HandleTransactionResponse| { &m.intrinsicGas },
+                    |m: &mut HandleTransactionResponse| { &mut m.intrinsicGas },
+                ));
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeUint64>(
+                    "executionGas",
+                    |m: &HandleTransactionResponse| { &m.executionGas },
+                    |m: &mut HandleTransactionResponse| { &mut m.executionGas },
+                ));
+                fields.push(::protobuf::reflect::accessor::make_singular_ptr_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<Receipt>>(
+                    "receipt",
+                    |m: &HandleTransactionResponse| { &m.receipt },
+                    |m: &mut HandleTransactionResponse| { &mut m.receipt },
+                ));
                 ::protobuf::reflect::MessageDescriptor::new::<HandleTransactionResponse>(
                     "HandleTransactionResponse",
                     fields,
@@ -1531,6 +1980,14 @@ impl ::protobuf::Clear for HandleTransactionResponse {
         self.ret.clear();
         self.vm_error.clear();
         self.gas_used = 0;
+        self.revert.clear();
+        self.exitStatus.clear();
+        self.exitReason.clear();
+        self.contractAddress.clear();
+        self.gasRefunded = 0;
+        self.intrinsicGas = 0;
+        self.executionGas = 0;
+        self.receipt.clear();
         self.unknown_fields.clear();
     }
 }
@@ -1548,53 +2005,112 @@ impl ::protobuf::reflect::ProtobufValue for HandleTransactionResponse {
 }
 
 #[derive(PartialEq,Clone,Default)]
-pub struct Topic {
+pub struct Receipt {
     // message fields
-    pub inner: ::std::vec::Vec<u8>,
+    pub status: u32,
+    pub cumulativeGasUsed: u64,
+    pub logsBloom: ::std::vec::Vec<u8>,
+    pub encoded: ::std::vec::Vec<u8>,
     // special fields
     pub unknown_fields: ::protobuf::UnknownFields,
     pub cached_size: ::protobuf::CachedSize,
 }
 
-impl<'a> ::std::default::Default for &'a Topic {
-    fn default() -> &'a Topic {
-        <Topic as ::protobuf::Message>::default_instance()
+impl<'a> ::std::default::Default for &'a Receipt {
+    fn default() -> &'a Receipt {
+        <Receipt as ::protobuf::Message>::default_instance()
     }
 }
 
-impl Topic {
-    pub fn new() -> Topic {
+impl Receipt {
+    pub fn new() -> Receipt {
         ::std::default::Default::default()
     }
 
-    // bytes inner = 1;
+    // uint32 status = 1;
 
 
-    pub fn get_inner(&self) -> &[u8] {
-        &self.inner
+    pub fn get_status(&self) -> u32 {
+        self.status
     }
-    pub fn clear_inner(&mut self) {
-        self.inner.clear();
+    pub fn clear_status(&mut self) {
+        self.status = 0;
     }
 
     // Param is passed by value, moved
-    pub fn set_inner(&mut self, v: ::std::vec::Vec<u8>) {
-        self.inner = v;
+    pub fn set_status(&mut self, v: u32) {
+        self.status = v;
     }
 
-    // Mutable pointer to the field.
-    // If field is not initialized, it is initialized with default value first.
-    pub fn mut_inner(&mut self) -> &mut ::std::vec::Vec<u8> {
-        &mut self.inner
-    }
+    // uint64 cumulativeGasUsed = 2;
 
-    // Take field
-    pub fn take_inner(&mut self) -> ::std::vec::Vec<u8> {
-        ::std::mem::replace(&mut self.inner, ::std::vec::Vec::new())
+
+    pub fn get_cumulativeGasUsed(&self) -> u64 {
+        self.cumulativeGasUsed
+    }
+    pub fn clear_cumulativeGasUsed(&mut self) {
+        self.cumulativeGasUsed = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_cumulativeGasUsed(&mut self, v: u64) {
+        self.cumulativeGasUsed = v;
+    }
+
+    // bytes logsBloom = 3;
+
+
+    pub fn get_logsBloom(&self) -> &[u8] {
+        &self.logsBloom
+    }
+    pub fn clear_logsBloom(&mut self) {
+        self.logsBloom.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_logsBloom(&mut self, v: ::std::vec::Vec<u8>) {
+        self.logsBloom = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_logsBloom(&mut self) -> &mut ::std::vec::Vec<u8> {
+        &mut self.logsBloom
+    }
+
+    // Take field
+    pub fn take_logsBloom(&mut self) -> ::std::vec::Vec<u8> {
+        ::std::mem::replace(&mut self.logsBloom, ::std::vec::Vec::new())
+    }
+
+    // bytes encoded = 4;
+
+
+    pub fn get_encoded(&self) -> &[u8] {
+        &self.encoded
+    }
+    pub fn clear_encoded(&mut self) {
+        self.encoded.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_encoded(&mut self, v: ::std::vec::Vec<u8>) {
+        self.encoded = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_encoded(&mut self) -> &mut ::std::vec::Vec<u8> {
+        &mut self.encoded
+    }
+
+    // Take field
+    pub fn take_encoded(&mut self) -> ::std::vec::Vec<u8> {
+        ::std::mem::replace(&mut self.encoded, ::std::vec::Vec::new())
     }
 }
 
-impl ::protobuf::Message for Topic {
+impl ::protobuf::Message for Receipt {
     fn is_initialized(&self) -> bool {
         true
     }
@@ -1604,7 +2120,24 @@ impl ::protobuf::Message for Topic {
             let (field_number, wire_type) = is.read_tag_unpack()?;
             match field_number {
                 1 => {
-                    ::protobuf::rt::read_singular_proto3_bytes_into(wire_type, is, &mut self.inner)?;
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_uint32()?;
+                    self.status = tmp;
+                },
+                2 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_uint64()?;
+                    self.cumulativeGasUsed = tmp;
+                },
+                3 => {
+                    ::protobuf::rt::read_singular_proto3_bytes_into(wire_type, is, &mut self.logsBloom)?;
+                },
+                4 => {
+                    ::protobuf::rt::read_singular_proto3_bytes_into(wire_type, is, &mut self.encoded)?;
                 },
                 _ => {
                     ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
@@ -1618,8 +2151,17 @@ impl ::protobuf::Message for Topic {
     #[allow(unused_variables)]
     fn compute_size(&self) -> u32 {
         let mut my_size = 0;
-        if !self.inner.is_empty() {
-            my_size += ::protobuf::rt::bytes_size(1, &self.inner);
+        if self.status != 0 {
+            my_size += ::protobuf::rt::value_size(1, self.status, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if self.cumulativeGasUsed != 0 {
+            my_size += ::protobuf::rt::value_size(2, self.cumulativeGasUsed, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if !self.logsBloom.is_empty() {
+            my_size += ::protobuf::rt::bytes_size(3, &self.logsBloom);
+        }
+        if !self.encoded.is_empty() {
+            my_size += ::protobuf::rt::bytes_size(4, &self.encoded);
         }
         my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
         self.cached_size.set(my_size);
@@ -1627,8 +2169,17 @@ impl ::protobuf::Message for Topic {
     }
 
     fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
-        if !self.inner.is_empty() {
-            os.write_bytes(1, &self.inner)?;
+        if self.status != 0 {
+            os.write_uint32(1, self.status)?;
+        }
+        if self.cumulativeGasUsed != 0 {
+            os.write_uint64(2, self.cumulativeGasUsed)?;
+        }
+        if !self.logsBloom.is_empty() {
+            os.write_bytes(3, &self.logsBloom)?;
+        }
+        if !self.encoded.is_empty() {
+            os.write_bytes(4, &self.encoded)?;
         }
         os.write_unknown_fields(self.get_unknown_fields())?;
         ::std::result::Result::Ok(())
@@ -1660,8 +2211,8 @@ impl ::protobuf::Message for Topic {
         Self::descriptor_static()
     }
 
-    fn new() -> Topic {
-        Topic::new()
+    fn new() -> Receipt {
+        Receipt::new()
     }
 
     fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
@@ -1672,13 +2223,28 @@ impl ::protobuf::Message for Topic {
         unsafe {
             descriptor.get(|| {
                 let mut fields = ::std::vec::Vec::new();
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeUint32>(
+                    "status",
+                    |m: &Receipt| { &m.status },
+                    |m: &mut Receipt| { &mut m.status },
+                ));
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeUint64>(
+                    "cumulativeGasUsed",
+                    |m: &Receipt| { &m.cumulativeGasUsed },
+                    |m: &mut Receipt| { &mut m.cumulativeGasUsed },
+                ));
                 fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeBytes>(
-                    "inner",
-                    |m: &Topic| { &m.inner },
-                    |m: &mut Topic| { &mut m.inner },
+                    "logsBloom",
+                    |m: &Receipt| { &m.logsBloom },
+                    |m: &mut Receipt| { &mut m.logsBloom },
                 ));
-                ::protobuf::reflect::MessageDescriptor::new::<Topic>(
-                    "Topic",
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeBytes>(
+                    "encoded",
+                    |m: &Receipt| { &m.encoded },
+                    |m: &mut Receipt| { &mut m.encoded },
+                ));
+                ::protobuf::reflect::MessageDescriptor::new::<Receipt>(
+                    "Receipt",
                     fields,
                     file_descriptor_proto()
                 )
@@ -1686,143 +2252,185 @@ impl ::protobuf::Message for Topic {
         }
     }
 
-    fn default_instance() -> &'static Topic {
-        static mut instance: ::protobuf::lazy::Lazy<Topic> = ::protobuf::lazy::Lazy {
+    fn default_instance() -> &'static Receipt {
+        static mut instance: ::protobuf::lazy::Lazy<Receipt> = ::protobuf::lazy::Lazy {
             lock: ::protobuf::lazy::ONCE_INIT,
-            ptr: 0 as *const Topic,
+            ptr: 0 as *const Receipt,
         };
         unsafe {
-            instance.get(Topic::new)
+            instance.get(Receipt::new)
         }
     }
 }
 
-impl ::protobuf::Clear for Topic {
+impl ::protobuf::Clear for Receipt {
     fn clear(&mut self) {
-        self.inner.clear();
+        self.status = 0;
+        self.cumulativeGasUsed = 0;
+        self.logsBloom.clear();
+        self.encoded.clear();
         self.unknown_fields.clear();
     }
 }
 
-impl ::std::fmt::Debug for Topic {
+impl ::std::fmt::Debug for Receipt {
     fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
         ::protobuf::text_format::fmt(self, f)
     }
 }
 
-impl ::protobuf::reflect::ProtobufValue for Topic {
+impl ::protobuf::reflect::ProtobufValue for Receipt {
     fn as_ref(&self) -> ::protobuf::reflect::ProtobufValueRef {
         ::protobuf::reflect::ProtobufValueRef::Message(self)
     }
 }
 
 #[derive(PartialEq,Clone,Default)]
-pub struct Log {
+pub struct RevertReason {
     // message fields
-    pub address: ::std::vec::Vec<u8>,
-    pub topics: ::protobuf::RepeatedField<Topic>,
-    pub data: ::std::vec::Vec<u8>,
+    pub kind: ::std::string::String,
+    pub message: ::std::string::String,
+    pub panicCode: u64,
+    pub selector: ::std::vec::Vec<u8>,
+    pub args: ::std::vec::Vec<u8>,
     // special fields
     pub unknown_fields: ::protobuf::UnknownFields,
     pub cached_size: ::protobuf::CachedSize,
 }
 
-impl<'a> ::std::default::Default for &'a Log {
-    fn default() -> &'a Log {
-        <Log as ::protobuf::Message>::default_instance()
+impl<'a> ::std::default::Default for &'a RevertReason {
+    fn default() -> &'a RevertReason {
+        <RevertReason as ::protobuf::Message>::default_instance()
     }
 }
 
-impl Log {
-    pub fn new() -> Log {
+impl RevertReason {
+    pub fn new() -> RevertReason {
         ::std::default::Default::default()
     }
 
-    // bytes address = 1;
+    // string kind = 1;
 
 
-    pub fn get_address(&self) -> &[u8] {
-        &self.address
+    pub fn get_kind(&self) -> &str {
+        &self.kind
     }
-    pub fn clear_address(&mut self) {
-        self.address.clear();
+    pub fn clear_kind(&mut self) {
+        self.kind.clear();
     }
 
     // Param is passed by value, moved
-    pub fn set_address(&mut self, v: ::std::vec::Vec<u8>) {
-        self.address = v;
+    pub fn set_kind(&mut self, v: ::std::string::String) {
+        self.kind = v;
     }
 
     // Mutable pointer to the field.
     // If field is not initialized, it is initialized with default value first.
-    pub fn mut_address(&mut self) -> &mut ::std::vec::Vec<u8> {
-        &mut self.address
+    pub fn mut_kind(&mut self) -> &mut ::std::string::String {
+        &mut self.kind
     }
 
     // Take field
-    pub fn take_address(&mut self) -> ::std::vec::Vec<u8> {
-        ::std::mem::replace(&mut self.address, ::std::vec::Vec::new())
+    pub fn take_kind(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.kind, ::std::string::String::new())
     }
 
-    // repeated .ffi.ffi.Topic topics = 2;
+    // string message = 2;
 
 
-    pub fn get_topics(&self) -> &[Topic] {
-        &self.topics
+    pub fn get_message(&self) -> &str {
+        &self.message
     }
-    pub fn clear_topics(&mut self) {
-        self.topics.clear();
+    pub fn clear_message(&mut self) {
+        self.message.clear();
     }
 
     // Param is passed by value, moved
-    pub fn set_topics(&mut self, v: ::protobuf::RepeatedField<Topic>) {
-        self.topics = v;
+    pub fn set_message(&mut self, v: ::std::string::String) {
+        self.message = v;
     }
 
     // Mutable pointer to the field.
-    pub fn mut_topics(&mut self) -> &mut ::protobuf::RepeatedField<Topic> {
-        &mut self.topics
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_message(&mut self) -> &mut ::std::string::String {
+        &mut self.message
     }
 
     // Take field
-    pub fn take_topics(&mut self) -> ::protobuf::RepeatedField<Topic> {
-        ::std::mem::replace(&mut self.topics, ::protobuf::RepeatedField::new())
+    pub fn take_message(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.message, ::std::string::String::new())
     }
 
-    // bytes data = 3;
+    // uint64 panicCode = 3;
 
 
-    pub fn get_data(&self) -> &[u8] {
-        &self.data
+    pub fn get_panicCode(&self) -> u64 {
+        self.panicCode
     }
-    pub fn clear_data(&mut self) {
-        self.data.clear();
+    pub fn clear_panicCode(&mut self) {
+        self.panicCode = 0;
     }
 
     // Param is passed by value, moved
-    pub fn set_data(&mut self, v: ::std::vec::Vec<u8>) {
-        self.data = v;
+    pub fn set_panicCode(&mut self, v: u64) {
+        self.panicCode = v;
+    }
+
+    // bytes selector = 4;
+
+
+    pub fn get_selector(&self) -> &[u8] {
+        &self.selector
+    }
+    pub fn clear_selector(&mut self) {
+        self.selector.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_selector(&mut self, v: ::std::vec::Vec<u8>) {
+        self.selector = v;
     }
 
     // Mutable pointer to the field.
     // If field is not initialized, it is initialized with default value first.
-    pub fn mut_data(&mut self) -> &mut ::std::vec::Vec<u8> {
-        &mut self.data
+    pub fn mut_selector(&mut self) -> &mut ::std::vec::Vec<u8> {
+        &mut self.selector
     }
 
     // Take field
-    pub fn take_data(&mut self) -> ::std::vec::Vec<u8> {
-        ::std::mem::replace(&mut self.data, ::std::vec::Vec::new())
+    pub fn take_selector(&mut self) -> ::std::vec::Vec<u8> {
+        ::std::mem::replace(&mut self.selector, ::std::vec::Vec::new())
+    }
+
+    // bytes args = 5;
+
+
+    pub fn get_args(&self) -> &[u8] {
+        &self.args
+    }
+    pub fn clear_args(&mut self) {
+        self.args.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_args(&mut self, v: ::std::vec::Vec<u8>) {
+        self.args = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_args(&mut self) -> &mut ::std::vec::Vec<u8> {
+        &mut self.args
+    }
+
+    // Take field
+    pub fn take_args(&mut self) -> ::std::vec::Vec<u8> {
+        ::std::mem::replace(&mut self.args, ::std::vec::Vec::new())
     }
 }
 
-impl ::protobuf::Message for Log {
+impl ::protobuf::Message for RevertReason {
     fn is_initialized(&self) -> bool {
-        for v in &self.topics {
-            if !v.is_initialized() {
-                return false;
-            }
-        };
         true
     }
 
@@ -1831,13 +2439,23 @@ impl ::protobuf::Message for Log {
             let (field_number, wire_type) = is.read_tag_unpack()?;
             match field_number {
                 1 => {
-                    ::protobuf::rt::read_singular_proto3_bytes_into(wire_type, is, &mut self.address)?;
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.kind)?;
                 },
                 2 => {
-                    ::protobuf::rt::read_repeated_message_into(wire_type, is, &mut self.topics)?;
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.message)?;
                 },
                 3 => {
-                    ::protobuf::rt::read_singular_proto3_bytes_into(wire_type, is, &mut self.data)?;
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_uint64()?;
+                    self.panicCode = tmp;
+                },
+                4 => {
+                    ::protobuf::rt::read_singular_proto3_bytes_into(wire_type, is, &mut self.selector)?;
+                },
+                5 => {
+                    ::protobuf::rt::read_singular_proto3_bytes_into(wire_type, is, &mut self.args)?;
                 },
                 _ => {
                     ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
@@ -1851,15 +2469,20 @@ impl ::protobuf::Message for Log {
     #[allow(unused_variables)]
     fn compute_size(&self) -> u32 {
         let mut my_size = 0;
-        if !self.address.is_empty() {
-            my_size += ::protobuf::rt::bytes_size(1, &self.address);
+        if !self.kind.is_empty() {
+            my_size += ::protobuf::rt::string_size(1, &self.kind);
         }
-        for value in &self.topics {
-            let len = value.compute_size();
-            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
-        };
-        if !self.data.is_empty() {
-            my_size += ::protobuf::rt::bytes_size(3, &self.data);
+        if !self.message.is_empty() {
+            my_size += ::protobuf::rt::string_size(2, &self.message);
+        }
+        if self.panicCode != 0 {
+            my_size += ::protobuf::rt::value_size(3, self.panicCode, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if !self.selector.is_empty() {
+            my_size += ::protobuf::rt::bytes_size(4, &self.selector);
+        }
+        if !self.args.is_empty() {
+            my_size += ::protobuf::rt::bytes_size(5, &self.args);
         }
         my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
         self.cached_size.set(my_size);
@@ -1867,16 +2490,20 @@ impl ::protobuf::Message for Log {
     }
 
     fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
-        if !self.address.is_empty() {
-            os.write_bytes(1, &self.address)?;
+        if !self.kind.is_empty() {
+            os.write_string(1, &self.kind)?;
         }
-        for v in &self.topics {
-            os.write_tag(2, ::protobuf::wire_format::WireTypeLengthDelimited)?;
-            os.write_raw_varint32(v.get_cached_size())?;
-            v.write_to_with_cached_sizes(os)?;
-        };
-        if !self.data.is_empty() {
-            os.write_bytes(3, &self.data)?;
+        if !self.message.is_empty() {
+            os.write_string(2, &self.message)?;
+        }
+        if self.panicCode != 0 {
+            os.write_uint64(3, self.panicCode)?;
+        }
+        if !self.selector.is_empty() {
+            os.write_bytes(4, &self.selector)?;
+        }
+        if !self.args.is_empty() {
+            os.write_bytes(5, &self.args)?;
         }
         os.write_unknown_fields(self.get_unknown_fields())?;
         ::std::result::Result::Ok(())
@@ -1908,8 +2535,8 @@ impl ::protobuf::Message for Log {
         Self::descriptor_static()
     }
 
-    fn new() -> Log {
-        Log::new()
+    fn new() -> RevertReason {
+        RevertReason::new()
     }
 
     fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
@@ -1920,23 +2547,33 @@ impl ::protobuf::Message for Log {
         unsafe {
             descriptor.get(|| {
                 let mut fields = ::std::vec::Vec::new();
-                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeBytes>(
-                    "address",
-                    |m: &Log| { &m.address },
-                    |m: &mut Log| { &mut m.address },
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                    "kind",
+                    |m: &RevertReason| { &m.kind },
+                    |m: &mut RevertReason| { &mut m.kind },
                 ));
-                fields.push(::protobuf::reflect::accessor::make_repeated_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<Topic>>(
-                    "topics",
-                    |m: &Log| { &m.topics },
-                    |m: &mut Log| { &mut m.topics },
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                    "message",
+                    |m: &RevertReason| { &m.message },
+                    |m: &mut RevertReason| { &mut m.message },
+                ));
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeUint64>(
+                    "panicCode",
+                    |m: &RevertReason| { &m.panicCode },
+                    |m: &mut RevertReason| { &mut m.panicCode },
                 ));
                 fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeBytes>(
-                    "data",
-                    |m: &Log| { &m.data },
-                    |m: &mut Log| { &mut m.data },
+                    "selector",
+                    |m: &RevertReason| { &m.selector },
+                    |m: &mut RevertReason| { &mut m.selector },
                 ));
-                ::protobuf::reflect::MessageDescriptor::new::<Log>(
-                    "Log",
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeBytes>(
+                    "args",
+                    |m: &RevertReason| { &m.args },
+                    |m: &mut RevertReason| { &mut m.args },
+                ));
+                ::protobuf::reflect::MessageDescriptor::new::<RevertReason>(
+                    "RevertReason",
                     fields,
                     file_descriptor_proto()
                 )
@@ -1944,86 +2581,88 @@ impl ::protobuf::Message for Log {
         }
     }
 
-    fn default_instance() -> &'static Log {
-        static mut instance: ::protobuf::lazy::Lazy<Log> = ::protobuf::lazy::Lazy {
+    fn default_instance() -> &'static RevertReason {
+        static mut instance: ::protobuf::lazy::Lazy<RevertReason> = ::protobuf::lazy::Lazy {
             lock: ::protobuf::lazy::ONCE_INIT,
-            ptr: 0 as *const Log,
+            ptr: 0 as *const RevertReason,
         };
         unsafe {
-            instance.get(Log::new)
+            instance.get(RevertReason::new)
         }
     }
 }
 
-impl ::protobuf::Clear for Log {
+impl ::protobuf::Clear for RevertReason {
     fn clear(&mut self) {
-        self.address.clear();
-        self.topics.clear();
-        self.data.clear();
+        self.kind.clear();
+        self.message.clear();
+        self.panicCode = 0;
+        self.selector.clear();
+        self.args.clear();
         self.unknown_fields.clear();
     }
 }
 
-impl ::std::fmt::Debug for Log {
+impl ::std::fmt::Debug for RevertReason {
     fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
         ::protobuf::text_format::fmt(self, f)
     }
 }
 
-impl ::protobuf::reflect::ProtobufValue for Log {
+impl ::protobuf::reflect::ProtobufValue for RevertReason {
     fn as_ref(&self) -> ::protobuf::reflect::ProtobufValueRef {
         ::protobuf::reflect::ProtobufValueRef::Message(self)
     }
 }
 
 #[derive(PartialEq,Clone,Default)]
-pub struct QueryGetAccount {
+pub struct Topic {
     // message fields
-    pub address: ::std::vec::Vec<u8>,
+    pub inner: ::std::vec::Vec<u8>,
     // special fields
     pub unknown_fields: ::protobuf::UnknownFields,
     pub cached_size: ::protobuf::CachedSize,
 }
 
-impl<'a> ::std::default::Default for &'a QueryGetAccount {
-    fn default() -> &'a QueryGetAccount {
-        <QueryGetAccount as ::protobuf::Message>::default_instance()
+impl<'a> ::std::default::Default for &'a Topic {
+    fn default() -> &'a Topic {
+        <Topic as ::protobuf::Message>::default_instance()
     }
 }
 
-impl QueryGetAccount {
-    pub fn new() -> QueryGetAccount {
+impl Topic {
+    pub fn new() -> Topic {
         ::std::default::Default::default()
     }
 
-    // bytes address = 1;
+    // bytes inner = 1;
 
 
-    pub fn get_address(&self) -> &[u8] {
-        &self.address
+    pub fn get_inner(&self) -> &[u8] {
+        &self.inner
     }
-    pub fn clear_address(&mut self) {
-        self.address.clear();
+    pub fn clear_inner(&mut self) {
+        self.inner.clear();
     }
 
     // Param is passed by value, moved
-    pub fn set_address(&mut self, v: ::std::vec::Vec<u8>) {
-        self.address = v;
+    pub fn set_inner(&mut self, v: ::std::vec::Vec<u8>) {
+        self.inner = v;
     }
 
     // Mutable pointer to the field.
     // If field is not initialized, it is initialized with default value first.
-    pub fn mut_address(&mut self) -> &mut ::std::vec::Vec<u8> {
-        &mut self.address
+    pub fn mut_inner(&mut self) -> &mut ::std::vec::Vec<u8> {
+        &mut self.inner
     }
 
     // Take field
-    pub fn take_address(&mut self) -> ::std::vec::Vec<u8> {
-        ::std::mem::replace(&mut self.address, ::std::vec::Vec::new())
+    pub fn take_inner(&mut self) -> ::std::vec::Vec<u8> {
+        ::std::mem::replace(&mut self.inner, ::std::vec::Vec::new())
     }
 }
 
-impl ::protobuf::Message for QueryGetAccount {
+impl ::protobuf::Message for Topic {
     fn is_initialized(&self) -> bool {
         true
     }
@@ -2033,7 +2672,7 @@ impl ::protobuf::Message for QueryGetAccount {
             let (field_number, wire_type) = is.read_tag_unpack()?;
             match field_number {
                 1 => {
-                    ::protobuf::rt::read_singular_proto3_bytes_into(wire_type, is, &mut self.address)?;
+                    ::protobuf::rt::read_singular_proto3_bytes_into(wire_type, is, &mut self.inner)?;
                 },
                 _ => {
                     ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
@@ -2047,8 +2686,8 @@ impl ::protobuf::Message for QueryGetAccount {
     #[allow(unused_variables)]
     fn compute_size(&self) -> u32 {
         let mut my_size = 0;
-        if !self.address.is_empty() {
-            my_size += ::protobuf::rt::bytes_size(1, &self.address);
+        if !self.inner.is_empty() {
+            my_size += ::protobuf::rt::bytes_size(1, &self.inner);
         }
         my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
         self.cached_size.set(my_size);
@@ -2056,8 +2695,8 @@ impl ::protobuf::Message for QueryGetAccount {
     }
 
     fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
-        if !self.address.is_empty() {
-            os.write_bytes(1, &self.address)?;
+        if !self.inner.is_empty() {
+            os.write_bytes(1, &self.inner)?;
         }
         os.write_unknown_fields(self.get_unknown_fields())?;
         ::std::result::Result::Ok(())
@@ -2089,8 +2728,8 @@ impl ::protobuf::Message for QueryGetAccount {
         Self::descriptor_static()
     }
 
-    fn new() -> QueryGetAccount {
-        QueryGetAccount::new()
+    fn new() -> Topic {
+        Topic::new()
     }
 
     fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
@@ -2102,12 +2741,12 @@ impl ::protobuf::Message for QueryGetAccount {
             descriptor.get(|| {
                 let mut fields = ::std::vec::Vec::new();
                 fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeBytes>(
-                    "address",
-                    |m: &QueryGetAccount| { &m.address },
-                    |m: &mut QueryGetAccount| { &mut m.address },
+                    "inner",
+                    |m: &Topic| { &m.inner },
+                    |m: &mut Topic| { &mut m.inner },
                 ));
-                ::protobuf::reflect::MessageDescriptor::new::<QueryGetAccount>(
-                    "QueryGetAccount",
+                ::protobuf::reflect::MessageDescriptor::new::<Topic>(
+                    "Topic",
                     fields,
                     file_descriptor_proto()
                 )
@@ -2115,101 +2754,143 @@ impl ::protobuf::Message for QueryGetAccount {
         }
     }
 
-    fn default_instance() -> &'static QueryGetAccount {
-        static mut instance: ::protobuf::lazy::Lazy<QueryGetAccount> = ::protobuf::lazy::Lazy {
+    fn default_instance() -> &'static Topic {
+        static mut instance: ::protobuf::lazy::Lazy<Topic> = ::protobuf::lazy::Lazy {
             lock: ::protobuf::lazy::ONCE_INIT,
-            ptr: 0 as *const QueryGetAccount,
+            ptr: 0 as *const Topic,
         };
         unsafe {
-            instance.get(QueryGetAccount::new)
+            instance.get(Topic::new)
         }
     }
 }
 
-impl ::protobuf::Clear for QueryGetAccount {
+impl ::protobuf::Clear for Topic {
     fn clear(&mut self) {
-        self.address.clear();
+        self.inner.clear();
         self.unknown_fields.clear();
     }
 }
 
-impl ::std::fmt::Debug for QueryGetAccount {
+impl ::std::fmt::Debug for Topic {
     fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
         ::protobuf::text_format::fmt(self, f)
     }
 }
 
-impl ::protobuf::reflect::ProtobufValue for QueryGetAccount {
+impl ::protobuf::reflect::ProtobufValue for Topic {
     fn as_ref(&self) -> ::protobuf::reflect::ProtobufValueRef {
         ::protobuf::reflect::ProtobufValueRef::Message(self)
     }
 }
 
 #[derive(PartialEq,Clone,Default)]
-pub struct QueryGetAccountResponse {
+pub struct Log {
     // message fields
-    pub balance: ::std::vec::Vec<u8>,
-    pub nonce: u64,
+    pub address: ::std::vec::Vec<u8>,
+    pub topics: ::protobuf::RepeatedField<Topic>,
+    pub data: ::std::vec::Vec<u8>,
     // special fields
     pub unknown_fields: ::protobuf::UnknownFields,
     pub cached_size: ::protobuf::CachedSize,
 }
 
-impl<'a> ::std::default::Default for &'a QueryGetAccountResponse {
-    fn default() -> &'a QueryGetAccountResponse {
-        <QueryGetAccountResponse as ::protobuf::Message>::default_instance()
+impl<'a> ::std::default::Default for &'a Log {
+    fn default() -> &'a Log {
+        <Log as ::protobuf::Message>::default_instance()
     }
 }
 
-impl QueryGetAccountResponse {
-    pub fn new() -> QueryGetAccountResponse {
+impl Log {
+    pub fn new() -> Log {
         ::std::default::Default::default()
     }
 
-    // bytes balance = 1;
+    // bytes address = 1;
 
 
-    pub fn get_balance(&self) -> &[u8] {
-        &self.balance
+    pub fn get_address(&self) -> &[u8] {
+        &self.address
     }
-    pub fn clear_balance(&mut self) {
-        self.balance.clear();
+    pub fn clear_address(&mut self) {
+        self.address.clear();
     }
 
     // Param is passed by value, moved
-    pub fn set_balance(&mut self, v: ::std::vec::Vec<u8>) {
-        self.balance = v;
+    pub fn set_address(&mut self, v: ::std::vec::Vec<u8>) {
+        self.address = v;
     }
 
     // Mutable pointer to the field.
     // If field is not initialized, it is initialized with default value first.
-    pub fn mut_balance(&mut self) -> &mut ::std::vec::Vec<u8> {
-        &mut self.balance
+    pub fn mut_address(&mut self) -> &mut ::std::vec::Vec<u8> {
+        &mut self.address
     }
 
     // Take field
-    pub fn take_balance(&mut self) -> ::std::vec::Vec<u8> {
-        ::std::mem::replace(&mut self.balance, ::std::vec::Vec::new())
+    pub fn take_address(&mut self) -> ::std::vec::Vec<u8> {
+        ::std::mem::replace(&mut self.address, ::std::vec::Vec::new())
     }
 
-    // uint64 nonce = 2;
+    // repeated .ffi.ffi.Topic topics = 2;
 
 
-    pub fn get_nonce(&self) -> u64 {
-        self.nonce
+    pub fn get_topics(&self) -> &[Topic] {
+        &self.topics
     }
-    pub fn clear_nonce(&mut self) {
-        self.nonce = 0;
+    pub fn clear_topics(&mut self) {
+        self.topics.clear();
     }
 
     // Param is passed by value, moved
-    pub fn set_nonce(&mut self, v: u64) {
-        self.nonce = v;
+    pub fn set_topics(&mut self, v: ::protobuf::RepeatedField<Topic>) {
+        self.topics = v;
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_topics(&mut self) -> &mut ::protobuf::RepeatedField<Topic> {
+        &mut self.topics
+    }
+
+    // Take field
+    pub fn take_topics(&mut self) -> ::protobuf::RepeatedField<Topic> {
+        ::std::mem::replace(&mut self.topics, ::protobuf::RepeatedField::new())
+    }
+
+    // bytes data = 3;
+
+
+    pub fn get_data(&self) -> &[u8] {
+        &self.data
+    }
+    pub fn clear_data(&mut self) {
+        self.data.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_data(&mut self, v: ::std::vec::Vec<u8>) {
+        self.data = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_data(&mut self) -> &mut ::std::vec::Vec<u8> {
+        &mut self.data
+    }
+
+    // Take field
+    pub fn take_data(&mut self) -> ::std::vec::Vec<u8> {
+        ::std::mem::replace(&mut self.data, ::std::vec::Vec::new())
     }
 }
 
-impl ::protobuf::Message for QueryGetAccountResponse {
+impl ::protobuf::Message for Log {
     fn is_initialized(&self) -> bool {
+        for v in &self.topics {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
         true
     }
 
@@ -2218,14 +2899,13 @@ impl ::protobuf::Message for QueryGetAccountResponse {
             let (field_number, wire_type) = is.read_tag_unpack()?;
             match field_number {
                 1 => {
-                    ::protobuf::rt::read_singular_proto3_bytes_into(wire_type, is, &mut self.balance)?;
+                    ::protobuf::rt::read_singular_proto3_bytes_into(wire_type, is, &mut self.address)?;
                 },
                 2 => {
-                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
-                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
-                    }
-                    let tmp = is.read_uint64()?;
-                    self.nonce = tmp;
+                    ::protobuf::rt::read_repeated_message_into(wire_type, is, &mut self.topics)?;
+                },
+                3 => {
+                    ::protobuf::rt::read_singular_proto3_bytes_into(wire_type, is, &mut self.data)?;
                 },
                 _ => {
                     ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
@@ -2239,11 +2919,15 @@ impl ::protobuf::Message for QueryGetAccountResponse {
     #[allow(unused_variables)]
     fn compute_size(&self) -> u32 {
         let mut my_size = 0;
-        if !self.balance.is_empty() {
-            my_size += ::protobuf::rt::bytes_size(1, &self.balance);
+        if !self.address.is_empty() {
+            my_size += ::protobuf::rt::bytes_size(1, &self.address);
         }
-        if self.nonce != 0 {
-            my_size += ::protobuf::rt::value_size(2, self.nonce, ::protobuf::wire_format::WireTypeVarint);
+        for value in &self.topics {
+            let len = value.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        };
+        if !self.data.is_empty() {
+            my_size += ::protobuf::rt::bytes_size(3, &self.data);
         }
         my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
         self.cached_size.set(my_size);
@@ -2251,11 +2935,16 @@ impl ::protobuf::Message for QueryGetAccountResponse {
     }
 
     fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
-        if !self.balance.is_empty() {
-            os.write_bytes(1, &self.balance)?;
+        if !self.address.is_empty() {
+            os.write_bytes(1, &self.address)?;
         }
-        if self.nonce != 0 {
-            os.write_uint64(2, self.nonce)?;
+        for v in &self.topics {
+            os.write_tag(2, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        };
+        if !self.data.is_empty() {
+            os.write_bytes(3, &self.data)?;
         }
         os.write_unknown_fields(self.get_unknown_fields())?;
         ::std::result::Result::Ok(())
@@ -2287,8 +2976,8 @@ impl ::protobuf::Message for QueryGetAccountResponse {
         Self::descriptor_static()
     }
 
-    fn new() -> QueryGetAccountResponse {
-        QueryGetAccountResponse::new()
+    fn new() -> Log {
+        Log::new()
     }
 
     fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
@@ -2300,17 +2989,22 @@ impl ::protobuf::Message for QueryGetAccountResponse {
             descriptor.get(|| {
                 let mut fields = ::std::vec::Vec::new();
                 fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeBytes>(
-                    "balance",
-                    |m: &QueryGetAccountResponse| { &m.balance },
-                    |m: &mut QueryGetAccountResponse| { &mut m.balance },
+                    "address",
+                    |m: &Log| { &m.address },
+                    |m: &mut Log| { &mut m.address },
                 ));
-                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeUint64>(
-                    "nonce",
-                    |m: &QueryGetAccountResponse| { &m.nonce },
-                    |m: &mut QueryGetAccountResponse| { &mut m.nonce },
+                fields.push(::protobuf::reflect::accessor::make_repeated_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<Topic>>(
+                    "topics",
+                    |m: &Log| { &m.topics },
+                    |m: &mut Log| { &mut m.topics },
                 ));
-                ::protobuf::reflect::MessageDescriptor::new::<QueryGetAccountResponse>(
-                    "QueryGetAccountResponse",
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeBytes>(
+                    "data",
+                    |m: &Log| { &m.data },
+                    |m: &mut Log| { &mut m.data },
+                ));
+                ::protobuf::reflect::MessageDescriptor::new::<Log>(
+                    "Log",
                     fields,
                     file_descriptor_proto()
                 )
@@ -2318,56 +3012,55 @@ impl ::protobuf::Message for QueryGetAccountResponse {
         }
     }
 
-    fn default_instance() -> &'static QueryGetAccountResponse {
-        static mut instance: ::protobuf::lazy::Lazy<QueryGetAccountResponse> = ::protobuf::lazy::Lazy {
+    fn default_instance() -> &'static Log {
+        static mut instance: ::protobuf::lazy::Lazy<Log> = ::protobuf::lazy::Lazy {
             lock: ::protobuf::lazy::ONCE_INIT,
-            ptr: 0 as *const QueryGetAccountResponse,
+            ptr: 0 as *const Log,
         };
         unsafe {
-            instance.get(QueryGetAccountResponse::new)
+            instance.get(Log::new)
         }
     }
 }
 
-impl ::protobuf::Clear for QueryGetAccountResponse {
+impl ::protobuf::Clear for Log {
     fn clear(&mut self) {
-        self.balance.clear();
-        self.nonce = 0;
+        self.address.clear();
+        self.topics.clear();
+        self.data.clear();
         self.unknown_fields.clear();
     }
 }
 
-impl ::std::fmt::Debug for QueryGetAccountResponse {
+impl ::std::fmt::Debug for Log {
     fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
         ::protobuf::text_format::fmt(self, f)
     }
 }
 
-impl ::protobuf::reflect::ProtobufValue for QueryGetAccountResponse {
+impl ::protobuf::reflect::ProtobufValue for Log {
     fn as_ref(&self) -> ::protobuf::reflect::ProtobufValueRef {
         ::protobuf::reflect::ProtobufValueRef::Message(self)
     }
 }
 
 #[derive(PartialEq,Clone,Default)]
-pub struct QueryInsertAccount {
+pub struct QueryGetAccount {
     // message fields
     pub address: ::std::vec::Vec<u8>,
-    pub balance: ::std::vec::Vec<u8>,
-    pub nonce: u64,
     // special fields
     pub unknown_fields: ::protobuf::UnknownFields,
     pub cached_size: ::protobuf::CachedSize,
 }
 
-impl<'a> ::std::default::Default for &'a QueryInsertAccount {
-    fn default() -> &'a QueryInsertAccount {
-        <QueryInsertAccount as ::protobuf::Message>::default_instance()
+impl<'a> ::std::default::Default for &'a QueryGetAccount {
+    fn default() -> &'a QueryGetAccount {
+        <QueryGetAccount as ::protobuf::Message>::default_instance()
     }
 }
 
-impl QueryInsertAccount {
-    pub fn new() -> QueryInsertAccount {
+impl QueryGetAccount {
+    pub fn new() -> QueryGetAccount {
         ::std::default::Default::default()
     }
 
@@ -2396,77 +3089,26 @@ impl QueryInsertAccount {
     pub fn take_address(&mut self) -> ::std::vec::Vec<u8> {
         ::std::mem::replace(&mut self.address, ::std::vec::Vec::new())
     }
+}
 
-    // bytes balance = 2;
-
-
-    pub fn get_balance(&self) -> &[u8] {
-        &self.balance
+impl ::protobuf::Message for QueryGetAccount {
+    fn is_initialized(&self) -> bool {
+        true
     }
-    pub fn clear_balance(&mut self) {
-        self.balance.clear();
-    }
-
-    // Param is passed by value, moved
-    pub fn set_balance(&mut self, v: ::std::vec::Vec<u8>) {
-        self.balance = v;
-    }
-
-    // Mutable pointer to the field.
-    // If field is not initialized, it is initialized with default value first.
-    pub fn mut_balance(&mut self) -> &mut ::std::vec::Vec<u8> {
-        &mut self.balance
-    }
-
-    // Take field
-    pub fn take_balance(&mut self) -> ::std::vec::Vec<u8> {
-        ::std::mem::replace(&mut self.balance, ::std::vec::Vec::new())
-    }
-
-    // uint64 nonce = 3;
-
-
-    pub fn get_nonce(&self) -> u64 {
-        self.nonce
-    }
-    pub fn clear_nonce(&mut self) {
-        self.nonce = 0;
-    }
-
-    // Param is passed by value, moved
-    pub fn set_nonce(&mut self, v: u64) {
-        self.nonce = v;
-    }
-}
-
-impl ::protobuf::Message for QueryInsertAccount {
-    fn is_initialized(&self) -> bool {
-        true
-    }
-
-    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
-        while !is.eof()? {
-            let (field_number, wire_type) = is.read_tag_unpack()?;
-            match field_number {
-                1 => {
-                    ::protobuf::rt::read_singular_proto3_bytes_into(wire_type, is, &mut self.address)?;
-                },
-                2 => {
-                    ::protobuf::rt::read_singular_proto3_bytes_into(wire_type, is, &mut self.balance)?;
-                },
-                3 => {
-                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
-                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
-                    }
-                    let tmp = is.read_uint64()?;
-                    self.nonce = tmp;
-                },
-                _ => {
-                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
-                },
-            };
-        }
-        ::std::result::Result::Ok(())
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_singular_proto3_bytes_into(wire_type, is, &mut self.address)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
     }
 
     // Compute sizes of nested messages
@@ -2476,12 +3118,6 @@ impl ::protobuf::Message for QueryInsertAccount {
         if !self.address.is_empty() {
             my_size += ::protobuf::rt::bytes_size(1, &self.address);
         }
-        if !self.balance.is_empty() {
-            my_size += ::protobuf::rt::bytes_size(2, &self.balance);
-        }
-        if self.nonce != 0 {
-            my_size += ::protobuf::rt::value_size(3, self.nonce, ::protobuf::wire_format::WireTypeVarint);
-        }
         my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
         self.cached_size.set(my_size);
         my_size
@@ -2491,12 +3127,6 @@ impl ::protobuf::Message for QueryInsertAccount {
         if !self.address.is_empty() {
             os.write_bytes(1, &self.address)?;
         }
-        if !self.balance.is_empty() {
-            os.write_bytes(2, &self.balance)?;
-        }
-        if self.nonce != 0 {
-            os.write_uint64(3, self.nonce)?;
-        }
         os.write_unknown_fields(self.get_unknown_fields())?;
         ::std::result::Result::Ok(())
     }
@@ -2527,8 +3157,8 @@ impl ::protobuf::Message for QueryInsertAccount {
         Self::descriptor_static()
     }
 
-    fn new() -> QueryInsertAccount {
-        QueryInsertAccount::new()
+    fn new() -> QueryGetAccount {
+        QueryGetAccount::new()
     }
 
     fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
@@ -2541,21 +3171,11 @@ impl ::protobuf::Message for QueryInsertAccount {
                 let mut fields = ::std::vec::Vec::new();
                 fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeBytes>(
                     "address",
-                    |m: &QueryInsertAccount| { &m.address },
-                    |m: &mut QueryInsertAccount| { &mut m.address },
-                ));
-                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeBytes>(
-                    "balance",
-                    |m: &QueryInsertAccount| { &m.balance },
-                    |m: &mut QueryInsertAccount| { &mut m.balance },
-                ));
-                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeUint64>(
-                    "nonce",
-                    |m: &QueryInsertAccount| { &m.nonce },
-                    |m: &mut QueryInsertAccount| { &mut m.nonce },
+                    |m: &QueryGetAccount| { &m.address },
+                    |m: &mut QueryGetAccount| { &mut m.address },
                 ));
-                ::protobuf::reflect::MessageDescriptor::new::<QueryInsertAccount>(
-                    "QueryInsertAccount",
+                ::protobuf::reflect::MessageDescriptor::new::<QueryGetAccount>(
+                    "QueryGetAccount",
                     fields,
                     file_descriptor_proto()
                 )
@@ -2563,58 +3183,100 @@ impl ::protobuf::Message for QueryInsertAccount {
         }
     }
 
-    fn default_instance() -> &'static QueryInsertAccount {
-        static mut instance: ::protobuf::lazy::Lazy<QueryInsertAccount> = ::protobuf::lazy::Lazy {
+    fn default_instance() -> &'static QueryGetAccount {
+        static mut instance: ::protobuf::lazy::Lazy<QueryGetAccount> = ::protobuf::lazy::Lazy {
             lock: ::protobuf::lazy::ONCE_INIT,
-            ptr: 0 as *const QueryInsertAccount,
+            ptr: 0 as *const QueryGetAccount,
         };
         unsafe {
-            instance.get(QueryInsertAccount::new)
+            instance.get(QueryGetAccount::new)
         }
     }
 }
 
-impl ::protobuf::Clear for QueryInsertAccount {
+impl ::protobuf::Clear for QueryGetAccount {
     fn clear(&mut self) {
         self.address.clear();
-        self.balance.clear();
-        self.nonce = 0;
         self.unknown_fields.clear();
     }
 }
 
-impl ::std::fmt::Debug for QueryInsertAccount {
+impl ::std::fmt::Debug for QueryGetAccount {
     fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
         ::protobuf::text_format::fmt(self, f)
     }
 }
 
-impl ::protobuf::reflect::ProtobufValue for QueryInsertAccount {
+impl ::protobuf::reflect::ProtobufValue for QueryGetAccount {
     fn as_ref(&self) -> ::protobuf::reflect::ProtobufValueRef {
         ::protobuf::reflect::ProtobufValueRef::Message(self)
     }
 }
 
 #[derive(PartialEq,Clone,Default)]
-pub struct QueryInsertAccountResponse {
+pub struct QueryGetAccountResponse {
+    // message fields
+    pub balance: ::std::vec::Vec<u8>,
+    pub nonce: u64,
     // special fields
     pub unknown_fields: ::protobuf::UnknownFields,
     pub cached_size: ::protobuf::CachedSize,
 }
 
-impl<'a> ::std::default::Default for &'a QueryInsertAccountResponse {
-    fn default() -> &'a QueryInsertAccountResponse {
-        <QueryInsertAccountResponse as ::protobuf::Message>::default_instance()
+impl<'a> ::std::default::Default for &'a QueryGetAccountResponse {
+    fn default() -> &'a QueryGetAccountResponse {
+        <QueryGetAccountResponse as ::protobuf::Message>::default_instance()
     }
 }
 
-impl QueryInsertAccountResponse {
-    pub fn new() -> QueryInsertAccountResponse {
+impl QueryGetAccountResponse {
+    pub fn new() -> QueryGetAccountResponse {
         ::std::default::Default::default()
     }
+
+    // bytes balance = 1;
+
+
+    pub fn get_balance(&self) -> &[u8] {
+        &self.balance
+    }
+    pub fn clear_balance(&mut self) {
+        self.balance.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_balance(&mut self, v: ::std::vec::Vec<u8>) {
+        self.balance = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_balance(&mut self) -> &mut ::std::vec::Vec<u8> {
+        &mut self.balance
+    }
+
+    // Take field
+    pub fn take_balance(&mut self) -> ::std::vec::Vec<u8> {
+        ::std::mem::replace(&mut self.balance, ::std::vec::Vec::new())
+    }
+
+    // uint64 nonce = 2;
+
+
+    pub fn get_nonce(&self) -> u64 {
+        self.nonce
+    }
+    pub fn clear_nonce(&mut self) {
+        self.nonce = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_nonce(&mut self, v: u64) {
+        self.nonce = v;
+    }
 }
 
-impl ::protobuf::Message for QueryInsertAccountResponse {
+impl ::protobuf::Message for QueryGetAccountResponse {
     fn is_initialized(&self) -> bool {
         true
     }
@@ -2623,6 +3285,16 @@ impl ::protobuf::Message for QueryInsertAccountResponse {
         while !is.eof()? {
             let (field_number, wire_type) = is.read_tag_unpack()?;
             match field_number {
+                1 => {
+                    ::protobuf::rt::read_singular_proto3_bytes_into(wire_type, is, &mut self.balance)?;
+                },
+                2 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_uint64()?;
+                    self.nonce = tmp;
+                },
                 _ => {
                     ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
                 },
@@ -2635,12 +3307,24 @@ impl ::protobuf::Message for QueryInsertAccountResponse {
     #[allow(unused_variables)]
     fn compute_size(&self) -> u32 {
         let mut my_size = 0;
+        if !self.balance.is_empty() {
+            my_size += ::protobuf::rt::bytes_size(1, &self.balance);
+        }
+        if self.nonce != 0 {
+            my_size += ::protobuf::rt::value_size(2, self.nonce, ::protobuf::wire_format::WireTypeVarint);
+        }
         my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
         self.cached_size.set(my_size);
         my_size
     }
 
     fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if !self.balance.is_empty() {
+            os.write_bytes(1, &self.balance)?;
+        }
+        if self.nonce != 0 {
+            os.write_uint64(2, self.nonce)?;
+        }
         os.write_unknown_fields(self.get_unknown_fields())?;
         ::std::result::Result::Ok(())
     }
@@ -2671,8 +3355,8 @@ impl ::protobuf::Message for QueryInsertAccountResponse {
         Self::descriptor_static()
     }
 
-    fn new() -> QueryInsertAccountResponse {
-        QueryInsertAccountResponse::new()
+    fn new() -> QueryGetAccountResponse {
+        QueryGetAccountResponse::new()
     }
 
     fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
@@ -2682,9 +3366,19 @@ impl ::protobuf::Message for QueryInsertAccountResponse {
         };
         unsafe {
             descriptor.get(|| {
-                let fields = ::std::vec::Vec::new();
-                ::protobuf::reflect::MessageDescriptor::new::<QueryInsertAccountResponse>(
-                    "QueryInsertAccountResponse",
+                let mut fields = ::std::vec::Vec::new();
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeBytes>(
+                    "balance",
+                    |m: &QueryGetAccountResponse| { &m.balance },
+                    |m: &mut QueryGetAccountResponse| { &mut m.balance },
+                ));
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeUint64>(
+                    "nonce",
+                    |m: &QueryGetAccountResponse| { &m.nonce },
+                    |m: &mut QueryGetAccountResponse| { &mut m.nonce },
+                ));
+                ::protobuf::reflect::MessageDescriptor::new::<QueryGetAccountResponse>(
+                    "QueryGetAccountResponse",
                     fields,
                     file_descriptor_proto()
                 )
@@ -2692,83 +3386,128 @@ impl ::protobuf::Message for QueryInsertAccountResponse {
         }
     }
 
-    fn default_instance() -> &'static QueryInsertAccountResponse {
-        static mut instance: ::protobuf::lazy::Lazy<QueryInsertAccountResponse> = ::protobuf::lazy::Lazy {
+    fn default_instance() -> &'static QueryGetAccountResponse {
+        static mut instance: ::protobuf::lazy::Lazy<QueryGetAccountResponse> = ::protobuf::lazy::Lazy {
             lock: ::protobuf::lazy::ONCE_INIT,
-            ptr: 0 as *const QueryInsertAccountResponse,
+            ptr: 0 as *const QueryGetAccountResponse,
         };
         unsafe {
-            instance.get(QueryInsertAccountResponse::new)
+            instance.get(QueryGetAccountResponse::new)
         }
     }
 }
 
-impl ::protobuf::Clear for QueryInsertAccountResponse {
+impl ::protobuf::Clear for QueryGetAccountResponse {
     fn clear(&mut self) {
+        self.balance.clear();
+        self.nonce = 0;
         self.unknown_fields.clear();
     }
 }
 
-impl ::std::fmt::Debug for QueryInsertAccountResponse {
+impl ::std::fmt::Debug for QueryGetAccountResponse {
     fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
         ::protobuf::text_format::fmt(self, f)
     }
 }
 
-impl ::protobuf::reflect::ProtobufValue for QueryInsertAccountResponse {
+impl ::protobuf::reflect::ProtobufValue for QueryGetAccountResponse {
     fn as_ref(&self) -> ::protobuf::reflect::ProtobufValueRef {
         ::protobuf::reflect::ProtobufValueRef::Message(self)
     }
 }
 
 #[derive(PartialEq,Clone,Default)]
-pub struct QueryContainsKey {
+pub struct QueryInsertAccount {
     // message fields
-    pub key: ::std::vec::Vec<u8>,
+    pub address: ::std::vec::Vec<u8>,
+    pub balance: ::std::vec::Vec<u8>,
+    pub nonce: u64,
     // special fields
     pub unknown_fields: ::protobuf::UnknownFields,
     pub cached_size: ::protobuf::CachedSize,
 }
 
-impl<'a> ::std::default::Default for &'a QueryContainsKey {
-    fn default() -> &'a QueryContainsKey {
-        <QueryContainsKey as ::protobuf::Message>::default_instance()
+impl<'a> ::std::default::Default for &'a QueryInsertAccount {
+    fn default() -> &'a QueryInsertAccount {
+        <QueryInsertAccount as ::protobuf::Message>::default_instance()
     }
 }
 
-impl QueryContainsKey {
-    pub fn new() -> QueryContainsKey {
+impl QueryInsertAccount {
+    pub fn new() -> QueryInsertAccount {
         ::std::default::Default::default()
     }
 
-    // bytes key = 1;
+    // bytes address = 1;
 
 
-    pub fn get_key(&self) -> &[u8] {
-        &self.key
+    pub fn get_address(&self) -> &[u8] {
+        &self.address
     }
-    pub fn clear_key(&mut self) {
-        self.key.clear();
+    pub fn clear_address(&mut self) {
+        self.address.clear();
     }
 
     // Param is passed by value, moved
-    pub fn set_key(&mut self, v: ::std::vec::Vec<u8>) {
-        self.key = v;
-    }
+    pub fn set_address(&mut self, v: ::std::vec::Vec<u8>) {
+        self.address = v;
+    }
 
     // Mutable pointer to the field.
     // If field is not initialized, it is initialized with default value first.
-    pub fn mut_key(&mut self) -> &mut ::std::vec::Vec<u8> {
-        &mut self.key
+    pub fn mut_address(&mut self) -> &mut ::std::vec::Vec<u8> {
+        &mut self.address
     }
 
     // Take field
-    pub fn take_key(&mut self) -> ::std::vec::Vec<u8> {
-        ::std::mem::replace(&mut self.key, ::std::vec::Vec::new())
+    pub fn take_address(&mut self) -> ::std::vec::Vec<u8> {
+        ::std::mem::replace(&mut self.address, ::std::vec::Vec::new())
+    }
+
+    // bytes balance = 2;
+
+
+    pub fn get_balance(&self) -> &[u8] {
+        &self.balance
+    }
+    pub fn clear_balance(&mut self) {
+        self.balance.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_balance(&mut self, v: ::std::vec::Vec<u8>) {
+        self.balance = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_balance(&mut self) -> &mut ::std::vec::Vec<u8> {
+        &mut self.balance
+    }
+
+    // Take field
+    pub fn take_balance(&mut self) -> ::std::vec::Vec<u8> {
+        ::std::mem::replace(&mut self.balance, ::std::vec::Vec::new())
+    }
+
+    // uint64 nonce = 3;
+
+
+    pub fn get_nonce(&self) -> u64 {
+        self.nonce
+    }
+    pub fn clear_nonce(&mut self) {
+        self.nonce = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_nonce(&mut self, v: u64) {
+        self.nonce = v;
     }
 }
 
-impl ::protobuf::Message for QueryContainsKey {
+impl ::protobuf::Message for QueryInsertAccount {
     fn is_initialized(&self) -> bool {
         true
     }
@@ -2778,7 +3517,17 @@ impl ::protobuf::Message for QueryContainsKey {
             let (field_number, wire_type) = is.read_tag_unpack()?;
             match field_number {
                 1 => {
-                    ::protobuf::rt::read_singular_proto3_bytes_into(wire_type, is, &mut self.key)?;
+                    ::protobuf::rt::read_singular_proto3_bytes_into(wire_type, is, &mut self.address)?;
+                },
+                2 => {
+                    ::protobuf::rt::read_singular_proto3_bytes_into(wire_type, is, &mut self.balance)?;
+                },
+                3 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_uint64()?;
+                    self.nonce = tmp;
                 },
                 _ => {
                     ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
@@ -2792,8 +3541,14 @@ impl ::protobuf::Message for QueryContainsKey {
     #[allow(unused_variables)]
     fn compute_size(&self) -> u32 {
         let mut my_size = 0;
-        if !self.key.is_empty() {
-            my_size += ::protobuf::rt::bytes_size(1, &self.key);
+        if !self.address.is_empty() {
+            my_size += ::protobuf::rt::bytes_size(1, &self.address);
+        }
+        if !self.balance.is_empty() {
+            my_size += ::protobuf::rt::bytes_size(2, &self.balance);
+        }
+        if self.nonce != 0 {
+            my_size += ::protobuf::rt::value_size(3, self.nonce, ::protobuf::wire_format::WireTypeVarint);
         }
         my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
         self.cached_size.set(my_size);
@@ -2801,8 +3556,14 @@ impl ::protobuf::Message for QueryContainsKey {
     }
 
     fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
-        if !self.key.is_empty() {
-            os.write_bytes(1, &self.key)?;
+        if !self.address.is_empty() {
+            os.write_bytes(1, &self.address)?;
+        }
+        if !self.balance.is_empty() {
+            os.write_bytes(2, &self.balance)?;
+        }
+        if self.nonce != 0 {
+            os.write_uint64(3, self.nonce)?;
         }
         os.write_unknown_fields(self.get_unknown_fields())?;
         ::std::result::Result::Ok(())
@@ -2834,8 +3595,8 @@ impl ::protobuf::Message for QueryContainsKey {
         Self::descriptor_static()
     }
 
-    fn new() -> QueryContainsKey {
-        QueryContainsKey::new()
+    fn new() -> QueryInsertAccount {
+        QueryInsertAccount::new()
     }
 
     fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
@@ -2847,12 +3608,22 @@ impl ::protobuf::Message for QueryContainsKey {
             descriptor.get(|| {
                 let mut fields = ::std::vec::Vec::new();
                 fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeBytes>(
-                    "key",
-                    |m: &QueryContainsKey| { &m.key },
-                    |m: &mut QueryContainsKey| { &mut m.key },
+                    "address",
+                    |m: &QueryInsertAccount| { &m.address },
+                    |m: &mut QueryInsertAccount| { &mut m.address },
                 ));
-                ::protobuf::reflect::MessageDescriptor::new::<QueryContainsKey>(
-                    "QueryContainsKey",
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeBytes>(
+                    "balance",
+                    |m: &QueryInsertAccount| { &m.balance },
+                    |m: &mut QueryInsertAccount| { &mut m.balance },
+                ));
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeUint64>(
+                    "nonce",
+                    |m: &QueryInsertAccount| { &m.nonce },
+                    |m: &mut QueryInsertAccount| { &mut m.nonce },
+                ));
+                ::protobuf::reflect::MessageDescriptor::new::<QueryInsertAccount>(
+                    "QueryInsertAccount",
                     fields,
                     file_descriptor_proto()
                 )
@@ -2860,73 +3631,58 @@ impl ::protobuf::Message for QueryContainsKey {
         }
     }
 
-    fn default_instance() -> &'static QueryContainsKey {
-        static mut instance: ::protobuf::lazy::Lazy<QueryContainsKey> = ::protobuf::lazy::Lazy {
+    fn default_instance() -> &'static QueryInsertAccount {
+        static mut instance: ::protobuf::lazy::Lazy<QueryInsertAccount> = ::protobuf::lazy::Lazy {
             lock: ::protobuf::lazy::ONCE_INIT,
-            ptr: 0 as *const QueryContainsKey,
+            ptr: 0 as *const QueryInsertAccount,
         };
         unsafe {
-            instance.get(QueryContainsKey::new)
+            instance.get(QueryInsertAccount::new)
         }
     }
 }
 
-impl ::protobuf::Clear for QueryContainsKey {
+impl ::protobuf::Clear for QueryInsertAccount {
     fn clear(&mut self) {
-        self.key.clear();
+        self.address.clear();
+        self.balance.clear();
+        self.nonce = 0;
         self.unknown_fields.clear();
     }
 }
 
-impl ::std::fmt::Debug for QueryContainsKey {
+impl ::std::fmt::Debug for QueryInsertAccount {
     fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
         ::protobuf::text_format::fmt(self, f)
     }
 }
 
-impl ::protobuf::reflect::ProtobufValue for QueryContainsKey {
+impl ::protobuf::reflect::ProtobufValue for QueryInsertAccount {
     fn as_ref(&self) -> ::protobuf::reflect::ProtobufValueRef {
         ::protobuf::reflect::ProtobufValueRef::Message(self)
     }
 }
 
 #[derive(PartialEq,Clone,Default)]
-pub struct QueryContainsKeyResponse {
-    // message fields
-    pub contains: bool,
+pub struct QueryInsertAccountResponse {
     // special fields
     pub unknown_fields: ::protobuf::UnknownFields,
     pub cached_size: ::protobuf::CachedSize,
 }
 
-impl<'a> ::std::default::Default for &'a QueryContainsKeyResponse {
-    fn default() -> &'a QueryContainsKeyResponse {
-        <QueryContainsKeyResponse as ::protobuf::Message>::default_instance()
+impl<'a> ::std::default::Default for &'a QueryInsertAccountResponse {
+    fn default() -> &'a QueryInsertAccountResponse {
+        <QueryInsertAccountResponse as ::protobuf::Message>::default_instance()
     }
 }
 
-impl QueryContainsKeyResponse {
-    pub fn new() -> QueryContainsKeyResponse {
+impl QueryInsertAccountResponse {
+    pub fn new() -> QueryInsertAccountResponse {
         ::std::default::Default::default()
     }
-
-    // bool contains = 1;
-
-
-    pub fn get_contains(&self) -> bool {
-        self.contains
-    }
-    pub fn clear_contains(&mut self) {
-        self.contains = false;
-    }
-
-    // Param is passed by value, moved
-    pub fn set_contains(&mut self, v: bool) {
-        self.contains = v;
-    }
 }
 
-impl ::protobuf::Message for QueryContainsKeyResponse {
+impl ::protobuf::Message for QueryInsertAccountResponse {
     fn is_initialized(&self) -> bool {
         true
     }
@@ -2935,13 +3691,6 @@ impl ::protobuf::Message for QueryContainsKeyResponse {
         while !is.eof()? {
             let (field_number, wire_type) = is.read_tag_unpack()?;
             match field_number {
-                1 => {
-                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
-                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
-                    }
-                    let tmp = is.read_bool()?;
-                    self.contains = tmp;
-                },
                 _ => {
                     ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
                 },
@@ -2954,18 +3703,12 @@ impl ::protobuf::Message for QueryContainsKeyResponse {
     #[allow(unused_variables)]
     fn compute_size(&self) -> u32 {
         let mut my_size = 0;
-        if self.contains != false {
-            my_size += 2;
-        }
         my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
         self.cached_size.set(my_size);
         my_size
     }
 
     fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
-        if self.contains != false {
-            os.write_bool(1, self.contains)?;
-        }
         os.write_unknown_fields(self.get_unknown_fields())?;
         ::std::result::Result::Ok(())
     }
@@ -2996,8 +3739,8 @@ impl ::protobuf::Message for QueryContainsKeyResponse {
         Self::descriptor_static()
     }
 
-    fn new() -> QueryContainsKeyResponse {
-        QueryContainsKeyResponse::new()
+    fn new() -> QueryInsertAccountResponse {
+        QueryInsertAccountResponse::new()
     }
 
     fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
@@ -3007,14 +3750,9 @@ impl ::protobuf::Message for QueryContainsKeyResponse {
         };
         unsafe {
             descriptor.get(|| {
-                let mut fields = ::std::vec::Vec::new();
-                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeBool>(
-                    "contains",
-                    |m: &QueryContainsKeyResponse| { &m.contains },
-                    |m: &mut QueryContainsKeyResponse| { &mut m.contains },
-                ));
-                ::protobuf::reflect::MessageDescriptor::new::<QueryContainsKeyResponse>(
-                    "QueryContainsKeyResponse",
+                let fields = ::std::vec::Vec::new();
+                ::protobuf::reflect::MessageDescriptor::new::<QueryInsertAccountResponse>(
+                    "QueryInsertAccountResponse",
                     fields,
                     file_descriptor_proto()
                 )
@@ -3022,113 +3760,85 @@ impl ::protobuf::Message for QueryContainsKeyResponse {
         }
     }
 
-    fn default_instance() -> &'static QueryContainsKeyResponse {
-        static mut instance: ::protobuf::lazy::Lazy<QueryContainsKeyResponse> = ::protobuf::lazy::Lazy {
+    fn default_instance() -> &'static QueryInsertAccountResponse {
+        static mut instance: ::protobuf::lazy::Lazy<QueryInsertAccountResponse> = ::protobuf::lazy::Lazy {
             lock: ::protobuf::lazy::ONCE_INIT,
-            ptr: 0 as *const QueryContainsKeyResponse,
+            ptr: 0 as *const QueryInsertAccountResponse,
         };
         unsafe {
-            instance.get(QueryContainsKeyResponse::new)
+            instance.get(QueryInsertAccountResponse::new)
         }
     }
 }
 
-impl ::protobuf::Clear for QueryContainsKeyResponse {
+impl ::protobuf::Clear for QueryInsertAccountResponse {
     fn clear(&mut self) {
-        self.contains = false;
         self.unknown_fields.clear();
     }
 }
 
-impl ::std::fmt::Debug for QueryContainsKeyResponse {
+impl ::std::fmt::Debug for QueryInsertAccountResponse {
     fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
         ::protobuf::text_format::fmt(self, f)
     }
 }
 
-impl ::protobuf::reflect::ProtobufValue for QueryContainsKeyResponse {
+impl ::protobuf::reflect::ProtobufValue for QueryInsertAccountResponse {
     fn as_ref(&self) -> ::protobuf::reflect::ProtobufValueRef {
         ::protobuf::reflect::ProtobufValueRef::Message(self)
     }
 }
 
 #[derive(PartialEq,Clone,Default)]
-pub struct QueryGetAccountStorageCell {
+pub struct QueryContainsKey {
     // message fields
-    pub address: ::std::vec::Vec<u8>,
-    pub index: ::std::vec::Vec<u8>,
+    pub key: ::std::vec::Vec<u8>,
     // special fields
     pub unknown_fields: ::protobuf::UnknownFields,
     pub cached_size: ::protobuf::CachedSize,
 }
 
-impl<'a> ::std::default::Default for &'a QueryGetAccountStorageCell {
-    fn default() -> &'a QueryGetAccountStorageCell {
-        <QueryGetAccountStorageCell as ::protobuf::Message>::default_instance()
+impl<'a> ::std::default::Default for &'a QueryContainsKey {
+    fn default() -> &'a QueryContainsKey {
+        <QueryContainsKey as ::protobuf::Message>::default_instance()
     }
 }
 
-impl QueryGetAccountStorageCell {
-    pub fn new() -> QueryGetAccountStorageCell {
+impl QueryContainsKey {
+    pub fn new() -> QueryContainsKey {
         ::std::default::Default::default()
     }
 
-    // bytes address = 1;
+    // bytes key = 1;
 
 
-    pub fn get_address(&self) -> &[u8] {
-        &self.address
+    pub fn get_key(&self) -> &[u8] {
+        &self.key
     }
-    pub fn clear_address(&mut self) {
-        self.address.clear();
+    pub fn clear_key(&mut self) {
+        self.key.clear();
     }
 
     // Param is passed by value, moved
-    pub fn set_address(&mut self, v: ::std::vec::Vec<u8>) {
-        self.address = v;
+    pub fn set_key(&mut self, v: ::std::vec::Vec<u8>) {
+        self.key = v;
     }
 
     // Mutable pointer to the field.
     // If field is not initialized, it is initialized with default value first.
-    pub fn mut_address(&mut self) -> &mut ::std::vec::Vec<u8> {
-        &mut self.address
+    pub fn mut_key(&mut self) -> &mut ::std::vec::Vec<u8> {
+        &mut self.key
     }
 
     // Take field
-    pub fn take_address(&mut self) -> ::std::vec::Vec<u8> {
-        ::std::mem::replace(&mut self.address, ::std::vec::Vec::new())
-    }
-
-    // bytes index = 2;
-
-
-    pub fn get_index(&self) -> &[u8] {
-        &self.index
-    }
-    pub fn clear_index(&mut self) {
-        self.index.clear();
+    pub fn take_key(&mut self) -> ::std::vec::Vec<u8> {
+        ::std::mem::replace(&mut self.key, ::std::vec::Vec::new())
     }
+}
 
-    // Param is passed by value, moved
-    pub fn set_index(&mut self, v: ::std::vec::Vec<u8>) {
-        self.index = v;
-    }
-
-    // Mutable pointer to the field.
-    // If field is not initialized, it is initialized with default value first.
-    pub fn mut_index(&mut self) -> &mut ::std::vec::Vec<u8> {
-        &mut self.index
-    }
-
-    // Take field
-    pub fn take_index(&mut self) -> ::std::vec::Vec<u8> {
-        ::std::mem::replace(&mut self.index, ::std::vec::Vec::new())
-    }
-}
-
-impl ::protobuf::Message for QueryGetAccountStorageCell {
-    fn is_initialized(&self) -> bool {
-        true
+impl ::protobuf::Message for QueryContainsKey {
+    fn is_initialized(&self) -> bool {
+        true
     }
 
     fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
@@ -3136,10 +3846,7 @@ impl ::protobuf::Message for QueryGetAccountStorageCell {
             let (field_number, wire_type) = is.read_tag_unpack()?;
             match field_number {
                 1 => {
-                    ::protobuf::rt::read_singular_proto3_bytes_into(wire_type, is, &mut self.address)?;
-                },
-                2 => {
-                    ::protobuf::rt::read_singular_proto3_bytes_into(wire_type, is, &mut self.index)?;
+                    ::protobuf::rt::read_singular_proto3_bytes_into(wire_type, is, &mut self.key)?;
                 },
                 _ => {
                     ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
@@ -3153,11 +3860,8 @@ impl ::protobuf::Message for QueryGetAccountStorageCell {
     #[allow(unused_variables)]
     fn compute_size(&self) -> u32 {
         let mut my_size = 0;
-        if !self.address.is_empty() {
-            my_size += ::protobuf::rt::bytes_size(1, &self.address);
-        }
-        if !self.index.is_empty() {
-            my_size += ::protobuf::rt::bytes_size(2, &self.index);
+        if !self.key.is_empty() {
+            my_size += ::protobuf::rt::bytes_size(1, &self.key);
         }
         my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
         self.cached_size.set(my_size);
@@ -3165,11 +3869,8 @@ impl ::protobuf::Message for QueryGetAccountStorageCell {
     }
 
     fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
-        if !self.address.is_empty() {
-            os.write_bytes(1, &self.address)?;
-        }
-        if !self.index.is_empty() {
-            os.write_bytes(2, &self.index)?;
+        if !self.key.is_empty() {
+            os.write_bytes(1, &self.key)?;
         }
         os.write_unknown_fields(self.get_unknown_fields())?;
         ::std::result::Result::Ok(())
@@ -3201,8 +3902,8 @@ impl ::protobuf::Message for QueryGetAccountStorageCell {
         Self::descriptor_static()
     }
 
-    fn new() -> QueryGetAccountStorageCell {
-        QueryGetAccountStorageCell::new()
+    fn new() -> QueryContainsKey {
+        QueryContainsKey::new()
     }
 
     fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
@@ -3214,17 +3915,12 @@ impl ::protobuf::Message for QueryGetAccountStorageCell {
             descriptor.get(|| {
                 let mut fields = ::std::vec::Vec::new();
                 fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeBytes>(
-                    "address",
-                    |m: &QueryGetAccountStorageCell| { &m.address },
-                    |m: &mut QueryGetAccountStorageCell| { &mut m.address },
-                ));
-                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeBytes>(
-                    "index",
-                    |m: &QueryGetAccountStorageCell| { &m.index },
-                    |m: &mut QueryGetAccountStorageCell| { &mut m.index },
+                    "key",
+                    |m: &QueryContainsKey| { &m.key },
+                    |m: &mut QueryContainsKey| { &mut m.key },
                 ));
-                ::protobuf::reflect::MessageDescriptor::new::<QueryGetAccountStorageCell>(
-                    "QueryGetAccountStorageCell",
+                ::protobuf::reflect::MessageDescriptor::new::<QueryContainsKey>(
+                    "QueryContainsKey",
                     fields,
                     file_descriptor_proto()
                 )
@@ -3232,85 +3928,73 @@ impl ::protobuf::Message for QueryGetAccountStorageCell {
         }
     }
 
-    fn default_instance() -> &'static QueryGetAccountStorageCell {
-        static mut instance: ::protobuf::lazy::Lazy<QueryGetAccountStorageCell> = ::protobuf::lazy::Lazy {
+    fn default_instance() -> &'static QueryContainsKey {
+        static mut instance: ::protobuf::lazy::Lazy<QueryContainsKey> = ::protobuf::lazy::Lazy {
             lock: ::protobuf::lazy::ONCE_INIT,
-            ptr: 0 as *const QueryGetAccountStorageCell,
+            ptr: 0 as *const QueryContainsKey,
         };
         unsafe {
-            instance.get(QueryGetAccountStorageCell::new)
+            instance.get(QueryContainsKey::new)
         }
     }
 }
 
-impl ::protobuf::Clear for QueryGetAccountStorageCell {
+impl ::protobuf::Clear for QueryContainsKey {
     fn clear(&mut self) {
-        self.address.clear();
-        self.index.clear();
+        self.key.clear();
         self.unknown_fields.clear();
     }
 }
 
-impl ::std::fmt::Debug for QueryGetAccountStorageCell {
+impl ::std::fmt::Debug for QueryContainsKey {
     fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
         ::protobuf::text_format::fmt(self, f)
     }
 }
 
-impl ::protobuf::reflect::ProtobufValue for QueryGetAccountStorageCell {
+impl ::protobuf::reflect::ProtobufValue for QueryContainsKey {
     fn as_ref(&self) -> ::protobuf::reflect::ProtobufValueRef {
         ::protobuf::reflect::ProtobufValueRef::Message(self)
     }
 }
 
 #[derive(PartialEq,Clone,Default)]
-pub struct QueryGetAccountStorageCellResponse {
+pub struct QueryContainsKeyResponse {
     // message fields
-    pub value: ::std::vec::Vec<u8>,
+    pub contains: bool,
     // special fields
     pub unknown_fields: ::protobuf::UnknownFields,
     pub cached_size: ::protobuf::CachedSize,
 }
 
-impl<'a> ::std::default::Default for &'a QueryGetAccountStorageCellResponse {
-    fn default() -> &'a QueryGetAccountStorageCellResponse {
-        <QueryGetAccountStorageCellResponse as ::protobuf::Message>::default_instance()
+impl<'a> ::std::default::Default for &'a QueryContainsKeyResponse {
+    fn default() -> &'a QueryContainsKeyResponse {
+        <QueryContainsKeyResponse as ::protobuf::Message>::default_instance()
     }
 }
 
-impl QueryGetAccountStorageCellResponse {
-    pub fn new() -> QueryGetAccountStorageCellResponse {
+impl QueryContainsKeyResponse {
+    pub fn new() -> QueryContainsKeyResponse {
         ::std::default::Default::default()
     }
 
-    // bytes value = 1;
+    // bool contains = 1;
 
 
-    pub fn get_value(&self) -> &[u8] {
-        &self.value
+    pub fn get_contains(&self) -> bool {
+        self.contains
     }
-    pub fn clear_value(&mut self) {
-        self.value.clear();
+    pub fn clear_contains(&mut self) {
+        self.contains = false;
     }
 
     // Param is passed by value, moved
-    pub fn set_value(&mut self, v: ::std::vec::Vec<u8>) {
-        self.value = v;
-    }
-
-    // Mutable pointer to the field.
-    // If field is not initialized, it is initialized with default value first.
-    pub fn mut_value(&mut self) -> &mut ::std::vec::Vec<u8> {
-        &mut self.value
-    }
-
-    // Take field
-    pub fn take_value(&mut self) -> ::std::vec::Vec<u8> {
-        ::std::mem::replace(&mut self.value, ::std::vec::Vec::new())
+    pub fn set_contains(&mut self, v: bool) {
+        self.contains = v;
     }
 }
 
-impl ::protobuf::Message for QueryGetAccountStorageCellResponse {
+impl ::protobuf::Message for QueryContainsKeyResponse {
     fn is_initialized(&self) -> bool {
         true
     }
@@ -3320,7 +4004,11 @@ impl ::protobuf::Message for QueryGetAccountStorageCellResponse {
             let (field_number, wire_type) = is.read_tag_unpack()?;
             match field_number {
                 1 => {
-                    ::protobuf::rt::read_singular_proto3_bytes_into(wire_type, is, &mut self.value)?;
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_bool()?;
+                    self.contains = tmp;
                 },
                 _ => {
                     ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
@@ -3334,8 +4022,8 @@ impl ::protobuf::Message for QueryGetAccountStorageCellResponse {
     #[allow(unused_variables)]
     fn compute_size(&self) -> u32 {
         let mut my_size = 0;
-        if !self.value.is_empty() {
-            my_size += ::protobuf::rt::bytes_size(1, &self.value);
+        if self.contains != false {
+            my_size += 2;
         }
         my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
         self.cached_size.set(my_size);
@@ -3343,8 +4031,8 @@ impl ::protobuf::Message for QueryGetAccountStorageCellResponse {
     }
 
     fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
-        if !self.value.is_empty() {
-            os.write_bytes(1, &self.value)?;
+        if self.contains != false {
+            os.write_bool(1, self.contains)?;
         }
         os.write_unknown_fields(self.get_unknown_fields())?;
         ::std::result::Result::Ok(())
@@ -3376,8 +4064,8 @@ impl ::protobuf::Message for QueryGetAccountStorageCellResponse {
         Self::descriptor_static()
     }
 
-    fn new() -> QueryGetAccountStorageCellResponse {
-        QueryGetAccountStorageCellResponse::new()
+    fn new() -> QueryContainsKeyResponse {
+        QueryContainsKeyResponse::new()
     }
 
     fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
@@ -3388,13 +4076,13 @@ impl ::protobuf::Message for QueryGetAccountStorageCellResponse {
         unsafe {
             descriptor.get(|| {
                 let mut fields = ::std::vec::Vec::new();
-                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeBytes>(
-                    "value",
-                    |m: &QueryGetAccountStorageCellResponse| { &m.value },
-                    |m: &mut QueryGetAccountStorageCellResponse| { &mut m.value },
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeBool>(
+                    "contains",
+                    |m: &QueryContainsKeyResponse| { &m.contains },
+                    |m: &mut QueryContainsKeyResponse| { &mut m.contains },
                 ));
-                ::protobuf::reflect::MessageDescriptor::new::<QueryGetAccountStorageCellResponse>(
-                    "QueryGetAccountStorageCellResponse",
+                ::protobuf::reflect::MessageDescriptor::new::<QueryContainsKeyResponse>(
+                    "QueryContainsKeyResponse",
                     fields,
                     file_descriptor_proto()
                 )
@@ -3402,53 +4090,54 @@ impl ::protobuf::Message for QueryGetAccountStorageCellResponse {
         }
     }
 
-    fn default_instance() -> &'static QueryGetAccountStorageCellResponse {
-        static mut instance: ::protobuf::lazy::Lazy<QueryGetAccountStorageCellResponse> = ::protobuf::lazy::Lazy {
+    fn default_instance() -> &'static QueryContainsKeyResponse {
+        static mut instance: ::protobuf::lazy::Lazy<QueryContainsKeyResponse> = ::protobuf::lazy::Lazy {
             lock: ::protobuf::lazy::ONCE_INIT,
-            ptr: 0 as *const QueryGetAccountStorageCellResponse,
+            ptr: 0 as *const QueryContainsKeyResponse,
         };
         unsafe {
-            instance.get(QueryGetAccountStorageCellResponse::new)
+            instance.get(QueryContainsKeyResponse::new)
         }
     }
 }
 
-impl ::protobuf::Clear for QueryGetAccountStorageCellResponse {
+impl ::protobuf::Clear for QueryContainsKeyResponse {
     fn clear(&mut self) {
-        self.value.clear();
+        self.contains = false;
         self.unknown_fields.clear();
     }
 }
 
-impl ::std::fmt::Debug for QueryGetAccountStorageCellResponse {
+impl ::std::fmt::Debug for QueryContainsKeyResponse {
     fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
         ::protobuf::text_format::fmt(self, f)
     }
 }
 
-impl ::protobuf::reflect::ProtobufValue for QueryGetAccountStorageCellResponse {
+impl ::protobuf::reflect::ProtobufValue for QueryContainsKeyResponse {
     fn as_ref(&self) -> ::protobuf::reflect::ProtobufValueRef {
         ::protobuf::reflect::ProtobufValueRef::Message(self)
     }
 }
 
 #[derive(PartialEq,Clone,Default)]
-pub struct QueryGetAccountCode {
+pub struct QueryGetAccountStorageCell {
     // message fields
     pub address: ::std::vec::Vec<u8>,
+    pub index: ::std::vec::Vec<u8>,
     // special fields
     pub unknown_fields: ::protobuf::UnknownFields,
     pub cached_size: ::protobuf::CachedSize,
 }
 
-impl<'a> ::std::default::Default for &'a QueryGetAccountCode {
-    fn default() -> &'a QueryGetAccountCode {
-        <QueryGetAccountCode as ::protobuf::Message>::default_instance()
+impl<'a> ::std::default::Default for &'a QueryGetAccountStorageCell {
+    fn default() -> &'a QueryGetAccountStorageCell {
+        <QueryGetAccountStorageCell as ::protobuf::Message>::default_instance()
     }
 }
 
-impl QueryGetAccountCode {
-    pub fn new() -> QueryGetAccountCode {
+impl QueryGetAccountStorageCell {
+    pub fn new() -> QueryGetAccountStorageCell {
         ::std::default::Default::default()
     }
 
@@ -3477,9 +4166,35 @@ impl QueryGetAccountCode {
     pub fn take_address(&mut self) -> ::std::vec::Vec<u8> {
         ::std::mem::replace(&mut self.address, ::std::vec::Vec::new())
     }
+
+    // bytes index = 2;
+
+
+    pub fn get_index(&self) -> &[u8] {
+        &self.index
+    }
+    pub fn clear_index(&mut self) {
+        self.index.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_index(&mut self, v: ::std::vec::Vec<u8>) {
+        self.index = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_index(&mut self) -> &mut ::std::vec::Vec<u8> {
+        &mut self.index
+    }
+
+    // Take field
+    pub fn take_index(&mut self) -> ::std::vec::Vec<u8> {
+        ::std::mem::replace(&mut self.index, ::std::vec::Vec::new())
+    }
 }
 
-impl ::protobuf::Message for QueryGetAccountCode {
+impl ::protobuf::Message for QueryGetAccountStorageCell {
     fn is_initialized(&self) -> bool {
         true
     }
@@ -3491,6 +4206,9 @@ impl ::protobuf::Message for QueryGetAccountCode {
                 1 => {
                     ::protobuf::rt::read_singular_proto3_bytes_into(wire_type, is, &mut self.address)?;
                 },
+                2 => {
+                    ::protobuf::rt::read_singular_proto3_bytes_into(wire_type, is, &mut self.index)?;
+                },
                 _ => {
                     ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
                 },
@@ -3506,6 +4224,9 @@ impl ::protobuf::Message for QueryGetAccountCode {
         if !self.address.is_empty() {
             my_size += ::protobuf::rt::bytes_size(1, &self.address);
         }
+        if !self.index.is_empty() {
+            my_size += ::protobuf::rt::bytes_size(2, &self.index);
+        }
         my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
         self.cached_size.set(my_size);
         my_size
@@ -3515,6 +4236,9 @@ impl ::protobuf::Message for QueryGetAccountCode {
         if !self.address.is_empty() {
             os.write_bytes(1, &self.address)?;
         }
+        if !self.index.is_empty() {
+            os.write_bytes(2, &self.index)?;
+        }
         os.write_unknown_fields(self.get_unknown_fields())?;
         ::std::result::Result::Ok(())
     }
@@ -3545,8 +4269,8 @@ impl ::protobuf::Message for QueryGetAccountCode {
         Self::descriptor_static()
     }
 
-    fn new() -> QueryGetAccountCode {
-        QueryGetAccountCode::new()
+    fn new() -> QueryGetAccountStorageCell {
+        QueryGetAccountStorageCell::new()
     }
 
     fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
@@ -3559,11 +4283,16 @@ impl ::protobuf::Message for QueryGetAccountCode {
                 let mut fields = ::std::vec::Vec::new();
                 fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeBytes>(
                     "address",
-                    |m: &QueryGetAccountCode| { &m.address },
-                    |m: &mut QueryGetAccountCode| { &mut m.address },
+                    |m: &QueryGetAccountStorageCell| { &m.address },
+                    |m: &mut QueryGetAccountStorageCell| { &mut m.address },
                 ));
-                ::protobuf::reflect::MessageDescriptor::new::<QueryGetAccountCode>(
-                    "QueryGetAccountCode",
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeBytes>(
+                    "index",
+                    |m: &QueryGetAccountStorageCell| { &m.index },
+                    |m: &mut QueryGetAccountStorageCell| { &mut m.index },
+                ));
+                ::protobuf::reflect::MessageDescriptor::new::<QueryGetAccountStorageCell>(
+                    "QueryGetAccountStorageCell",
                     fields,
                     file_descriptor_proto()
                 )
@@ -3571,84 +4300,85 @@ impl ::protobuf::Message for QueryGetAccountCode {
         }
     }
 
-    fn default_instance() -> &'static QueryGetAccountCode {
-        static mut instance: ::protobuf::lazy::Lazy<QueryGetAccountCode> = ::protobuf::lazy::Lazy {
+    fn default_instance() -> &'static QueryGetAccountStorageCell {
+        static mut instance: ::protobuf::lazy::Lazy<QueryGetAccountStorageCell> = ::protobuf::lazy::Lazy {
             lock: ::protobuf::lazy::ONCE_INIT,
-            ptr: 0 as *const QueryGetAccountCode,
+            ptr: 0 as *const QueryGetAccountStorageCell,
         };
         unsafe {
-            instance.get(QueryGetAccountCode::new)
+            instance.get(QueryGetAccountStorageCell::new)
         }
     }
 }
 
-impl ::protobuf::Clear for QueryGetAccountCode {
+impl ::protobuf::Clear for QueryGetAccountStorageCell {
     fn clear(&mut self) {
         self.address.clear();
+        self.index.clear();
         self.unknown_fields.clear();
     }
 }
 
-impl ::std::fmt::Debug for QueryGetAccountCode {
+impl ::std::fmt::Debug for QueryGetAccountStorageCell {
     fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
         ::protobuf::text_format::fmt(self, f)
     }
 }
 
-impl ::protobuf::reflect::ProtobufValue for QueryGetAccountCode {
+impl ::protobuf::reflect::ProtobufValue for QueryGetAccountStorageCell {
     fn as_ref(&self) -> ::protobuf::reflect::ProtobufValueRef {
         ::protobuf::reflect::ProtobufValueRef::Message(self)
     }
 }
 
 #[derive(PartialEq,Clone,Default)]
-pub struct QueryGetAccountCodeResponse {
+pub struct QueryGetAccountStorageCellResponse {
     // message fields
-    pub code: ::std::vec::Vec<u8>,
+    pub value: ::std::vec::Vec<u8>,
     // special fields
     pub unknown_fields: ::protobuf::UnknownFields,
     pub cached_size: ::protobuf::CachedSize,
 }
 
-impl<'a> ::std::default::Default for &'a QueryGetAccountCodeResponse {
-    fn default() -> &'a QueryGetAccountCodeResponse {
-        <QueryGetAccountCodeResponse as ::protobuf::Message>::default_instance()
+impl<'a> ::std::default::Default for &'a QueryGetAccountStorageCellResponse {
+    fn default() -> &'a QueryGetAccountStorageCellResponse {
+        <QueryGetAccountStorageCellResponse as ::protobuf::Message>::default_instance()
     }
 }
 
-impl QueryGetAccountCodeResponse {
-    pub fn new() -> QueryGetAccountCodeResponse {
+impl QueryGetAccountStorageCellResponse {
+    pub fn new() -> QueryGetAccountStorageCellResponse {
         ::std::default::Default::default()
     }
 
-    // bytes code = 1;
+    // bytes value = 1;
 
 
-    pub fn get_code(&self) -> &[u8] {
-        &self.code
+    pub fn get_value(&self) -> &[u8] {
+        &self.value
     }
-    pub fn clear_code(&mut self) {
-        self.code.clear();
+    pub fn clear_value(&mut self) {
+        self.value.clear();
     }
 
     // Param is passed by value, moved
-    pub fn set_code(&mut self, v: ::std::vec::Vec<u8>) {
-        self.code = v;
+    pub fn set_value(&mut self, v: ::std::vec::Vec<u8>) {
+        self.value = v;
     }
 
     // Mutable pointer to the field.
     // If field is not initialized, it is initialized with default value first.
-    pub fn mut_code(&mut self) -> &mut ::std::vec::Vec<u8> {
-        &mut self.code
+    pub fn mut_value(&mut self) -> &mut ::std::vec::Vec<u8> {
+        &mut self.value
     }
 
     // Take field
-    pub fn take_code(&mut self) -> ::std::vec::Vec<u8> {
-        ::std::mem::replace(&mut self.code, ::std::vec::Vec::new())
+    pub fn take_value(&mut self) -> ::std::vec::Vec<u8> {
+        ::std::mem::replace(&mut self.value, ::std::vec::Vec::new())
     }
 }
 
-impl ::protobuf::Message for QueryGetAccountCodeResponse {
+impl ::protobuf::Message for QueryGetAccountStorageCellResponse {
     fn is_initialized(&self) -> bool {
         true
     }
@@ -3658,7 +4388,7 @@ impl ::protobuf::Message for QueryGetAccountCodeResponse {
             let (field_number, wire_type) = is.read_tag_unpack()?;
             match field_number {
                 1 => {
-                    ::protobuf::rt::read_singular_proto3_bytes_into(wire_type, is, &mut self.code)?;
+                    ::protobuf::rt::read_singular_proto3_bytes_into(wire_type, is, &mut self.value)?;
                 },
                 _ => {
                     ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
@@ -3672,8 +4402,8 @@ impl ::protobuf::Message for QueryGetAccountCodeResponse {
     #[allow(unused_variables)]
     fn compute_size(&self) -> u32 {
         let mut my_size = 0;
-        if !self.code.is_empty() {
-            my_size += ::protobuf::rt::bytes_size(1, &self.code);
+        if !self.value.is_empty() {
+            my_size += ::protobuf::rt::bytes_size(1, &self.value);
         }
         my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
         self.cached_size.set(my_size);
@@ -3681,8 +4411,8 @@ impl ::protobuf::Message for QueryGetAccountCodeResponse {
     }
 
     fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
-        if !self.code.is_empty() {
-            os.write_bytes(1, &self.code)?;
+        if !self.value.is_empty() {
+            os.write_bytes(1, &self.value)?;
         }
         os.write_unknown_fields(self.get_unknown_fields())?;
         ::std::result::Result::Ok(())
@@ -3714,8 +4444,8 @@ impl ::protobuf::Message for QueryGetAccountCodeResponse {
         Self::descriptor_static()
     }
 
-    fn new() -> QueryGetAccountCodeResponse {
-        QueryGetAccountCodeResponse::new()
+    fn new() -> QueryGetAccountStorageCellResponse {
+        QueryGetAccountStorageCellResponse::new()
     }
 
     fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
@@ -3727,12 +4457,12 @@ impl ::protobuf::Message for QueryGetAccountCodeResponse {
             descriptor.get(|| {
                 let mut fields = ::std::vec::Vec::new();
                 fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeBytes>(
-                    "code",
-                    |m: &QueryGetAccountCodeResponse| { &m.code },
-                    |m: &mut QueryGetAccountCodeResponse| { &mut m.code },
+                    "value",
+                    |m: &QueryGetAccountStorageCellResponse| { &m.value },
+                    |m: &mut QueryGetAccountStorageCellResponse| { &mut m.value },
                 ));
-                ::protobuf::reflect::MessageDescriptor::new::<QueryGetAccountCodeResponse>(
-                    "QueryGetAccountCodeResponse",
+                ::protobuf::reflect::MessageDescriptor::new::<QueryGetAccountStorageCellResponse>(
+                    "QueryGetAccountStorageCellResponse",
                     fields,
                     file_descriptor_proto()
                 )
@@ -3740,54 +4470,53 @@ impl ::protobuf::Message for QueryGetAccountCodeResponse {
         }
     }
 
-    fn default_instance() -> &'static QueryGetAccountCodeResponse {
-        static mut instance: ::protobuf::lazy::Lazy<QueryGetAccountCodeResponse> = ::protobuf::lazy::Lazy {
+    fn default_instance() -> &'static QueryGetAccountStorageCellResponse {
+        static mut instance: ::protobuf::lazy::Lazy<QueryGetAccountStorageCellResponse> = ::protobuf::lazy::Lazy {
             lock: ::protobuf::lazy::ONCE_INIT,
-            ptr: 0 as *const QueryGetAccountCodeResponse,
+            ptr: 0 as *const QueryGetAccountStorageCellResponse,
         };
         unsafe {
-            instance.get(QueryGetAccountCodeResponse::new)
+            instance.get(QueryGetAccountStorageCellResponse::new)
         }
     }
 }
 
-impl ::protobuf::Clear for QueryGetAccountCodeResponse {
+impl ::protobuf::Clear for QueryGetAccountStorageCellResponse {
     fn clear(&mut self) {
-        self.code.clear();
+        self.value.clear();
         self.unknown_fields.clear();
     }
 }
 
-impl ::std::fmt::Debug for QueryGetAccountCodeResponse {
+impl ::std::fmt::Debug for QueryGetAccountStorageCellResponse {
     fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
         ::protobuf::text_format::fmt(self, f)
     }
 }
 
-impl ::protobuf::reflect::ProtobufValue for QueryGetAccountCodeResponse {
+impl ::protobuf::reflect::ProtobufValue for QueryGetAccountStorageCellResponse {
     fn as_ref(&self) -> ::protobuf::reflect::ProtobufValueRef {
         ::protobuf::reflect::ProtobufValueRef::Message(self)
     }
 }
 
 #[derive(PartialEq,Clone,Default)]
-pub struct QueryInsertAccountCode {
+pub struct QueryGetAccountCode {
     // message fields
     pub address: ::std::vec::Vec<u8>,
-    pub code: ::std::vec::Vec<u8>,
     // special fields
     pub unknown_fields: ::protobuf::UnknownFields,
     pub cached_size: ::protobuf::CachedSize,
 }
 
-impl<'a> ::std::default::Default for &'a QueryInsertAccountCode {
-    fn default() -> &'a QueryInsertAccountCode {
-        <QueryInsertAccountCode as ::protobuf::Message>::default_instance()
+impl<'a> ::std::default::Default for &'a QueryGetAccountCode {
+    fn default() -> &'a QueryGetAccountCode {
+        <QueryGetAccountCode as ::protobuf::Message>::default_instance()
     }
 }
 
-impl QueryInsertAccountCode {
-    pub fn new() -> QueryInsertAccountCode {
+impl QueryGetAccountCode {
+    pub fn new() -> QueryGetAccountCode {
         ::std::default::Default::default()
     }
 
@@ -3816,35 +4545,9 @@ impl QueryInsertAccountCode {
     pub fn take_address(&mut self) -> ::std::vec::Vec<u8> {
         ::std::mem::replace(&mut self.address, ::std::vec::Vec::new())
     }
-
-    // bytes code = 2;
-
-
-    pub fn get_code(&self) -> &[u8] {
-        &self.code
-    }
-    pub fn clear_code(&mut self) {
-        self.code.clear();
-    }
-
-    // Param is passed by value, moved
-    pub fn set_code(&mut self, v: ::std::vec::Vec<u8>) {
-        self.code = v;
-    }
-
-    // Mutable pointer to the field.
-    // If field is not initialized, it is initialized with default value first.
-    pub fn mut_code(&mut self) -> &mut ::std::vec::Vec<u8> {
-        &mut self.code
-    }
-
-    // Take field
-    pub fn take_code(&mut self) -> ::std::vec::Vec<u8> {
-        ::std::mem::replace(&mut self.code, ::std::vec::Vec::new())
-    }
 }
 
-impl ::protobuf::Message for QueryInsertAccountCode {
+impl ::protobuf::Message for QueryGetAccountCode {
     fn is_initialized(&self) -> bool {
         true
     }
@@ -3856,9 +4559,6 @@ impl ::protobuf::Message for QueryInsertAccountCode {
                 1 => {
                     ::protobuf::rt::read_singular_proto3_bytes_into(wire_type, is, &mut self.address)?;
                 },
-                2 => {
-                    ::protobuf::rt::read_singular_proto3_bytes_into(wire_type, is, &mut self.code)?;
-                },
                 _ => {
                     ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
                 },
@@ -3874,9 +4574,6 @@ impl ::protobuf::Message for QueryInsertAccountCode {
         if !self.address.is_empty() {
             my_size += ::protobuf::rt::bytes_size(1, &self.address);
         }
-        if !self.code.is_empty() {
-            my_size += ::protobuf::rt::bytes_size(2, &self.code);
-        }
         my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
         self.cached_size.set(my_size);
         my_size
@@ -3886,9 +4583,6 @@ impl ::protobuf::Message for QueryInsertAccountCode {
         if !self.address.is_empty() {
             os.write_bytes(1, &self.address)?;
         }
-        if !self.code.is_empty() {
-            os.write_bytes(2, &self.code)?;
-        }
         os.write_unknown_fields(self.get_unknown_fields())?;
         ::std::result::Result::Ok(())
     }
@@ -3919,8 +4613,8 @@ impl ::protobuf::Message for QueryInsertAccountCode {
         Self::descriptor_static()
     }
 
-    fn new() -> QueryInsertAccountCode {
-        QueryInsertAccountCode::new()
+    fn new() -> QueryGetAccountCode {
+        QueryGetAccountCode::new()
     }
 
     fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
@@ -3933,16 +4627,11 @@ impl ::protobuf::Message for QueryInsertAccountCode {
                 let mut fields = ::std::vec::Vec::new();
                 fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeBytes>(
                     "address",
-                    |m: &QueryInsertAccountCode| { &m.address },
-                    |m: &mut QueryInsertAccountCode| { &mut m.address },
-                ));
-                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeBytes>(
-                    "code",
-                    |m: &QueryInsertAccountCode| { &m.code },
-                    |m: &mut QueryInsertAccountCode| { &mut m.code },
+                    |m: &QueryGetAccountCode| { &m.address },
+                    |m: &mut QueryGetAccountCode| { &mut m.address },
                 ));
-                ::protobuf::reflect::MessageDescriptor::new::<QueryInsertAccountCode>(
-                    "QueryInsertAccountCode",
+                ::protobuf::reflect::MessageDescriptor::new::<QueryGetAccountCode>(
+                    "QueryGetAccountCode",
                     fields,
                     file_descriptor_proto()
                 )
@@ -3950,57 +4639,84 @@ impl ::protobuf::Message for QueryInsertAccountCode {
         }
     }
 
-    fn default_instance() -> &'static QueryInsertAccountCode {
-        static mut instance: ::protobuf::lazy::Lazy<QueryInsertAccountCode> = ::protobuf::lazy::Lazy {
+    fn default_instance() -> &'static QueryGetAccountCode {
+        static mut instance: ::protobuf::lazy::Lazy<QueryGetAccountCode> = ::protobuf::lazy::Lazy {
             lock: ::protobuf::lazy::ONCE_INIT,
-            ptr: 0 as *const QueryInsertAccountCode,
+            ptr: 0 as *const QueryGetAccountCode,
         };
         unsafe {
-            instance.get(QueryInsertAccountCode::new)
+            instance.get(QueryGetAccountCode::new)
         }
     }
 }
 
-impl ::protobuf::Clear for QueryInsertAccountCode {
+impl ::protobuf::Clear for QueryGetAccountCode {
     fn clear(&mut self) {
         self.address.clear();
-        self.code.clear();
         self.unknown_fields.clear();
     }
 }
 
-impl ::std::fmt::Debug for QueryInsertAccountCode {
+impl ::std::fmt::Debug for QueryGetAccountCode {
     fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
         ::protobuf::text_format::fmt(self, f)
     }
 }
 
-impl ::protobuf::reflect::ProtobufValue for QueryInsertAccountCode {
+impl ::protobuf::reflect::ProtobufValue for QueryGetAccountCode {
     fn as_ref(&self) -> ::protobuf::reflect::ProtobufValueRef {
         ::protobuf::reflect::ProtobufValueRef::Message(self)
     }
 }
 
 #[derive(PartialEq,Clone,Default)]
-pub struct QueryInsertAccountCodeResponse {
+pub struct QueryGetAccountCodeResponse {
+    // message fields
+    pub code: ::std::vec::Vec<u8>,
     // special fields
     pub unknown_fields: ::protobuf::UnknownFields,
     pub cached_size: ::protobuf::CachedSize,
 }
 
-impl<'a> ::std::default::Default for &'a QueryInsertAccountCodeResponse {
-    fn default() -> &'a QueryInsertAccountCodeResponse {
-        <QueryInsertAccountCodeResponse as ::protobuf::Message>::default_instance()
+impl<'a> ::std::default::Default for &'a QueryGetAccountCodeResponse {
+    fn default() -> &'a QueryGetAccountCodeResponse {
+        <QueryGetAccountCodeResponse as ::protobuf::Message>::default_instance()
     }
 }
 
-impl QueryInsertAccountCodeResponse {
-    pub fn new() -> QueryInsertAccountCodeResponse {
+impl QueryGetAccountCodeResponse {
+    pub fn new() -> QueryGetAccountCodeResponse {
         ::std::default::Default::default()
     }
+
+    // bytes code = 1;
+
+
+    pub fn get_code(&self) -> &[u8] {
+        &self.code
+    }
+    pub fn clear_code(&mut self) {
+        self.code.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_code(&mut self, v: ::std::vec::Vec<u8>) {
+        self.code = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_code(&mut self) -> &mut ::std::vec::Vec<u8> {
+        &mut self.code
+    }
+
+    // Take field
+    pub fn take_code(&mut self) -> ::std::vec::Vec<u8> {
+        ::std::mem::replace(&mut self.code, ::std::vec::Vec::new())
+    }
 }
 
-impl ::protobuf::Message for QueryInsertAccountCodeResponse {
+impl ::protobuf::Message for QueryGetAccountCodeResponse {
     fn is_initialized(&self) -> bool {
         true
     }
@@ -4009,6 +4725,9 @@ impl ::protobuf::Message for QueryInsertAccountCodeResponse {
         while !is.eof()? {
             let (field_number, wire_type) = is.read_tag_unpack()?;
             match field_number {
+                1 => {
+                    ::protobuf::rt::read_singular_proto3_bytes_into(wire_type, is, &mut self.code)?;
+                },
                 _ => {
                     ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
                 },
@@ -4021,12 +4740,18 @@ impl ::protobuf::Message for QueryInsertAccountCodeResponse {
     #[allow(unused_variables)]
     fn compute_size(&self) -> u32 {
         let mut my_size = 0;
+        if !self.code.is_empty() {
+            my_size += ::protobuf::rt::bytes_size(1, &self.code);
+        }
         my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
         self.cached_size.set(my_size);
         my_size
     }
 
     fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if !self.code.is_empty() {
+            os.write_bytes(1, &self.code)?;
+        }
         os.write_unknown_fields(self.get_unknown_fields())?;
         ::std::result::Result::Ok(())
     }
@@ -4057,8 +4782,8 @@ impl ::protobuf::Message for QueryInsertAccountCodeResponse {
         Self::descriptor_static()
     }
 
-    fn new() -> QueryInsertAccountCodeResponse {
-        QueryInsertAccountCodeResponse::new()
+    fn new() -> QueryGetAccountCodeResponse {
+        QueryGetAccountCodeResponse::new()
     }
 
     fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
@@ -4068,9 +4793,14 @@ impl ::protobuf::Message for QueryInsertAccountCodeResponse {
         };
         unsafe {
             descriptor.get(|| {
-                let fields = ::std::vec::Vec::new();
-                ::protobuf::reflect::MessageDescriptor::new::<QueryInsertAccountCodeResponse>(
-                    "QueryInsertAccountCodeResponse",
+                let mut fields = ::std::vec::Vec::new();
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeBytes>(
+                    "code",
+                    |m: &QueryGetAccountCodeResponse| { &m.code },
+                    |m: &mut QueryGetAccountCodeResponse| { &mut m.code },
+                ));
+                ::protobuf::reflect::MessageDescriptor::new::<QueryGetAccountCodeResponse>(
+                    "QueryGetAccountCodeResponse",
                     fields,
                     file_descriptor_proto()
                 )
@@ -4078,54 +4808,54 @@ impl ::protobuf::Message for QueryInsertAccountCodeResponse {
         }
     }
 
-    fn default_instance() -> &'static QueryInsertAccountCodeResponse {
-        static mut instance: ::protobuf::lazy::Lazy<QueryInsertAccountCodeResponse> = ::protobuf::lazy::Lazy {
+    fn default_instance() -> &'static QueryGetAccountCodeResponse {
+        static mut instance: ::protobuf::lazy::Lazy<QueryGetAccountCodeResponse> = ::protobuf::lazy::Lazy {
             lock: ::protobuf::lazy::ONCE_INIT,
-            ptr: 0 as *const QueryInsertAccountCodeResponse,
+            ptr: 0 as *const QueryGetAccountCodeResponse,
         };
         unsafe {
-            instance.get(QueryInsertAccountCodeResponse::new)
+            instance.get(QueryGetAccountCodeResponse::new)
         }
     }
 }
 
-impl ::protobuf::Clear for QueryInsertAccountCodeResponse {
+impl ::protobuf::Clear for QueryGetAccountCodeResponse {
     fn clear(&mut self) {
+        self.code.clear();
         self.unknown_fields.clear();
     }
 }
 
-impl ::std::fmt::Debug for QueryInsertAccountCodeResponse {
+impl ::std::fmt::Debug for QueryGetAccountCodeResponse {
     fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
         ::protobuf::text_format::fmt(self, f)
     }
 }
 
-impl ::protobuf::reflect::ProtobufValue for QueryInsertAccountCodeResponse {
+impl ::protobuf::reflect::ProtobufValue for QueryGetAccountCodeResponse {
     fn as_ref(&self) -> ::protobuf::reflect::ProtobufValueRef {
         ::protobuf::reflect::ProtobufValueRef::Message(self)
     }
 }
 
 #[derive(PartialEq,Clone,Default)]
-pub struct QueryInsertStorageCell {
+pub struct QueryInsertAccountCode {
     // message fields
     pub address: ::std::vec::Vec<u8>,
-    pub index: ::std::vec::Vec<u8>,
-    pub value: ::std::vec::Vec<u8>,
+    pub code: ::std::vec::Vec<u8>,
     // special fields
     pub unknown_fields: ::protobuf::UnknownFields,
     pub cached_size: ::protobuf::CachedSize,
 }
 
-impl<'a> ::std::default::Default for &'a QueryInsertStorageCell {
-    fn default() -> &'a QueryInsertStorageCell {
-        <QueryInsertStorageCell as ::protobuf::Message>::default_instance()
+impl<'a> ::std::default::Default for &'a QueryInsertAccountCode {
+    fn default() -> &'a QueryInsertAccountCode {
+        <QueryInsertAccountCode as ::protobuf::Message>::default_instance()
     }
 }
 
-impl QueryInsertStorageCell {
-    pub fn new() -> QueryInsertStorageCell {
+impl QueryInsertAccountCode {
+    pub fn new() -> QueryInsertAccountCode {
         ::std::default::Default::default()
     }
 
@@ -4155,60 +4885,34 @@ impl QueryInsertStorageCell {
         ::std::mem::replace(&mut self.address, ::std::vec::Vec::new())
     }
 
-    // bytes index = 2;
+    // bytes code = 2;
 
 
-    pub fn get_index(&self) -> &[u8] {
-        &self.index
+    pub fn get_code(&self) -> &[u8] {
+        &self.code
     }
-    pub fn clear_index(&mut self) {
-        self.index.clear();
+    pub fn clear_code(&mut self) {
+        self.code.clear();
     }
 
     // Param is passed by value, moved
-    pub fn set_index(&mut self, v: ::std::vec::Vec<u8>) {
-        self.index = v;
+    pub fn set_code(&mut self, v: ::std::vec::Vec<u8>) {
+        self.code = v;
     }
 
     // Mutable pointer to the field.
     // If field is not initialized, it is initialized with default value first.
-    pub fn mut_index(&mut self) -> &mut ::std::vec::Vec<u8> {
-        &mut self.index
-    }
-
-    // Take field
-    pub fn take_index(&mut self) -> ::std::vec::Vec<u8> {
-        ::std::mem::replace(&mut self.index, ::std::vec::Vec::new())
-    }
-
-    // bytes value = 3;
-
-
-    pub fn get_value(&self) -> &[u8] {
-        &self.value
-    }
-    pub fn clear_value(&mut self) {
-        self.value.clear();
-    }
-
-    // Param is passed by value, moved
-    pub fn set_value(&mut self, v: ::std::vec::Vec<u8>) {
-        self.value = v;
-    }
-
-    // Mutable pointer to the field.
-    // If field is not initialized, it is initialized with default value first.
-    pub fn mut_value(&mut self) -> &mut ::std::vec::Vec<u8> {
-        &mut self.value
+    pub fn mut_code(&mut self) -> &mut ::std::vec::Vec<u8> {
+        &mut self.code
     }
 
     // Take field
-    pub fn take_value(&mut self) -> ::std::vec::Vec<u8> {
-        ::std::mem::replace(&mut self.value, ::std::vec::Vec::new())
+    pub fn take_code(&mut self) -> ::std::vec::Vec<u8> {
+        ::std::mem::replace(&mut self.code, ::std::vec::Vec::new())
     }
 }
 
-impl ::protobuf::Message for QueryInsertStorageCell {
+impl ::protobuf::Message for QueryInsertAccountCode {
     fn is_initialized(&self) -> bool {
         true
     }
@@ -4221,10 +4925,7 @@ impl ::protobuf::Message for QueryInsertStorageCell {
                     ::protobuf::rt::read_singular_proto3_bytes_into(wire_type, is, &mut self.address)?;
                 },
                 2 => {
-                    ::protobuf::rt::read_singular_proto3_bytes_into(wire_type, is, &mut self.index)?;
-                },
-                3 => {
-                    ::protobuf::rt::read_singular_proto3_bytes_into(wire_type, is, &mut self.value)?;
+                    ::protobuf::rt::read_singular_proto3_bytes_into(wire_type, is, &mut self.code)?;
                 },
                 _ => {
                     ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
@@ -4241,11 +4942,8 @@ impl ::protobuf::Message for QueryInsertStorageCell {
         if !self.address.is_empty() {
             my_size += ::protobuf::rt::bytes_size(1, &self.address);
         }
-        if !self.index.is_empty() {
-            my_size += ::protobuf::rt::bytes_size(2, &self.index);
-        }
-        if !self.value.is_empty() {
-            my_size += ::protobuf::rt::bytes_size(3, &self.value);
+        if !self.code.is_empty() {
+            my_size += ::protobuf::rt::bytes_size(2, &self.code);
         }
         my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
         self.cached_size.set(my_size);
@@ -4256,11 +4954,8 @@ impl ::protobuf::Message for QueryInsertStorageCell {
         if !self.address.is_empty() {
             os.write_bytes(1, &self.address)?;
         }
-        if !self.index.is_empty() {
-            os.write_bytes(2, &self.index)?;
-        }
-        if !self.value.is_empty() {
-            os.write_bytes(3, &self.value)?;
+        if !self.code.is_empty() {
+            os.write_bytes(2, &self.code)?;
         }
         os.write_unknown_fields(self.get_unknown_fields())?;
         ::std::result::Result::Ok(())
@@ -4292,8 +4987,8 @@ impl ::protobuf::Message for QueryInsertStorageCell {
         Self::descriptor_static()
     }
 
-    fn new() -> QueryInsertStorageCell {
-        QueryInsertStorageCell::new()
+    fn new() -> QueryInsertAccountCode {
+        QueryInsertAccountCode::new()
     }
 
     fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
@@ -4306,21 +5001,16 @@ impl ::protobuf::Message for QueryInsertStorageCell {
                 let mut fields = ::std::vec::Vec::new();
                 fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeBytes>(
                     "address",
-                    |m: &QueryInsertStorageCell| { &m.address },
-                    |m: &mut QueryInsertStorageCell| { &mut m.address },
-                ));
-                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeBytes>(
-                    "index",
-                    |m: &QueryInsertStorageCell| { &m.index },
-                    |m: &mut QueryInsertStorageCell| { &mut m.index },
+                    |m: &QueryInsertAccountCode| { &m.address },
+                    |m: &mut QueryInsertAccountCode| { &mut m.address },
                 ));
                 fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeBytes>(
-                    "value",
-                    |m: &QueryInsertStorageCell| { &m.value },
-                    |m: &mut QueryInsertStorageCell| { &mut m.value },
+                    "code",
+                    |m: &QueryInsertAccountCode| { &m.code },
+                    |m: &mut QueryInsertAccountCode| { &mut m.code },
                 ));
-                ::protobuf::reflect::MessageDescriptor::new::<QueryInsertStorageCell>(
-                    "QueryInsertStorageCell",
+                ::protobuf::reflect::MessageDescriptor::new::<QueryInsertAccountCode>(
+                    "QueryInsertAccountCode",
                     fields,
                     file_descriptor_proto()
                 )
@@ -4328,58 +5018,57 @@ impl ::protobuf::Message for QueryInsertStorageCell {
         }
     }
 
-    fn default_instance() -> &'static QueryInsertStorageCell {
-        static mut instance: ::protobuf::lazy::Lazy<QueryInsertStorageCell> = ::protobuf::lazy::Lazy {
+    fn default_instance() -> &'static QueryInsertAccountCode {
+        static mut instance: ::protobuf::lazy::Lazy<QueryInsertAccountCode> = ::protobuf::lazy::Lazy {
             lock: ::protobuf::lazy::ONCE_INIT,
-            ptr: 0 as *const QueryInsertStorageCell,
+            ptr: 0 as *const QueryInsertAccountCode,
         };
         unsafe {
-            instance.get(QueryInsertStorageCell::new)
+            instance.get(QueryInsertAccountCode::new)
         }
     }
 }
 
-impl ::protobuf::Clear for QueryInsertStorageCell {
+impl ::protobuf::Clear for QueryInsertAccountCode {
     fn clear(&mut self) {
         self.address.clear();
-        self.index.clear();
-        self.value.clear();
+        self.code.clear();
         self.unknown_fields.clear();
     }
 }
 
-impl ::std::fmt::Debug for QueryInsertStorageCell {
+impl ::std::fmt::Debug for QueryInsertAccountCode {
     fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
         ::protobuf::text_format::fmt(self, f)
     }
 }
 
-impl ::protobuf::reflect::ProtobufValue for QueryInsertStorageCell {
+impl ::protobuf::reflect::ProtobufValue for QueryInsertAccountCode {
     fn as_ref(&self) -> ::protobuf::reflect::ProtobufValueRef {
         ::protobuf::reflect::ProtobufValueRef::Message(self)
     }
 }
 
 #[derive(PartialEq,Clone,Default)]
-pub struct QueryInsertStorageCellResponse {
+pub struct QueryInsertAccountCodeResponse {
     // special fields
     pub unknown_fields: ::protobuf::UnknownFields,
     pub cached_size: ::protobuf::CachedSize,
 }
 
-impl<'a> ::std::default::Default for &'a QueryInsertStorageCellResponse {
-    fn default() -> &'a QueryInsertStorageCellResponse {
-        <QueryInsertStorageCellResponse as ::protobuf::Message>::default_instance()
+impl<'a> ::std::default::Default for &'a QueryInsertAccountCodeResponse {
+    fn default() -> &'a QueryInsertAccountCodeResponse {
+        <QueryInsertAccountCodeResponse as ::protobuf::Message>::default_instance()
     }
 }
 
-impl QueryInsertStorageCellResponse {
-    pub fn new() -> QueryInsertStorageCellResponse {
+impl QueryInsertAccountCodeResponse {
+    pub fn new() -> QueryInsertAccountCodeResponse {
         ::std::default::Default::default()
     }
 }
 
-impl ::protobuf::Message for QueryInsertStorageCellResponse {
+impl ::protobuf::Message for QueryInsertAccountCodeResponse {
     fn is_initialized(&self) -> bool {
         true
     }
@@ -4436,8 +5125,8 @@ impl ::protobuf::Message for QueryInsertStorageCellResponse {
         Self::descriptor_static()
     }
 
-    fn new() -> QueryInsertStorageCellResponse {
-        QueryInsertStorageCellResponse::new()
+    fn new() -> QueryInsertAccountCodeResponse {
+        QueryInsertAccountCodeResponse::new()
     }
 
     fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
@@ -4448,8 +5137,8 @@ impl ::protobuf::Message for QueryInsertStorageCellResponse {
         unsafe {
             descriptor.get(|| {
                 let fields = ::std::vec::Vec::new();
-                ::protobuf::reflect::MessageDescriptor::new::<QueryInsertStorageCellResponse>(
-                    "QueryInsertStorageCellResponse",
+                ::protobuf::reflect::MessageDescriptor::new::<QueryInsertAccountCodeResponse>(
+                    "QueryInsertAccountCodeResponse",
                     fields,
                     file_descriptor_proto()
                 )
@@ -4457,52 +5146,54 @@ impl ::protobuf::Message for QueryInsertStorageCellResponse {
         }
     }
 
-    fn default_instance() -> &'static QueryInsertStorageCellResponse {
-        static mut instance: ::protobuf::lazy::Lazy<QueryInsertStorageCellResponse> = ::protobuf::lazy::Lazy {
+    fn default_instance() -> &'static QueryInsertAccountCodeResponse {
+        static mut instance: ::protobuf::lazy::Lazy<QueryInsertAccountCodeResponse> = ::protobuf::lazy::Lazy {
             lock: ::protobuf::lazy::ONCE_INIT,
-            ptr: 0 as *const QueryInsertStorageCellResponse,
+            ptr: 0 as *const QueryInsertAccountCodeResponse,
         };
         unsafe {
-            instance.get(QueryInsertStorageCellResponse::new)
+            instance.get(QueryInsertAccountCodeResponse::new)
         }
     }
 }
 
-impl ::protobuf::Clear for QueryInsertStorageCellResponse {
+impl ::protobuf::Clear for QueryInsertAccountCodeResponse {
     fn clear(&mut self) {
         self.unknown_fields.clear();
     }
 }
 
-impl ::std::fmt::Debug for QueryInsertStorageCellResponse {
+impl ::std::fmt::Debug for QueryInsertAccountCodeResponse {
     fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
         ::protobuf::text_format::fmt(self, f)
     }
 }
 
-impl ::protobuf::reflect::ProtobufValue for QueryInsertStorageCellResponse {
+impl ::protobuf::reflect::ProtobufValue for QueryInsertAccountCodeResponse {
     fn as_ref(&self) -> ::protobuf::reflect::ProtobufValueRef {
         ::protobuf::reflect::ProtobufValueRef::Message(self)
     }
 }
 
 #[derive(PartialEq,Clone,Default)]
-pub struct QueryRemove {
+pub struct QueryInsertStorageCell {
     // message fields
     pub address: ::std::vec::Vec<u8>,
+    pub index: ::std::vec::Vec<u8>,
+    pub value: ::std::vec::Vec<u8>,
     // special fields
     pub unknown_fields: ::protobuf::UnknownFields,
     pub cached_size: ::protobuf::CachedSize,
 }
 
-impl<'a> ::std::default::Default for &'a QueryRemove {
-    fn default() -> &'a QueryRemove {
-        <QueryRemove as ::protobuf::Message>::default_instance()
+impl<'a> ::std::default::Default for &'a QueryInsertStorageCell {
+    fn default() -> &'a QueryInsertStorageCell {
+        <QueryInsertStorageCell as ::protobuf::Message>::default_instance()
     }
 }
 
-impl QueryRemove {
-    pub fn new() -> QueryRemove {
+impl QueryInsertStorageCell {
+    pub fn new() -> QueryInsertStorageCell {
         ::std::default::Default::default()
     }
 
@@ -4531,9 +5222,61 @@ impl QueryRemove {
     pub fn take_address(&mut self) -> ::std::vec::Vec<u8> {
         ::std::mem::replace(&mut self.address, ::std::vec::Vec::new())
     }
+
+    // bytes index = 2;
+
+
+    pub fn get_index(&self) -> &[u8] {
+        &self.index
+    }
+    pub fn clear_index(&mut self) {
+        self.index.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_index(&mut self, v: ::std::vec::Vec<u8>) {
+        self.index = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_index(&mut self) -> &mut ::std::vec::Vec<u8> {
+        &mut self.index
+    }
+
+    // Take field
+    pub fn take_index(&mut self) -> ::std::vec::Vec<u8> {
+        ::std::mem::replace(&mut self.index, ::std::vec::Vec::new())
+    }
+
+    // bytes value = 3;
+
+
+    pub fn get_value(&self) -> &[u8] {
+        &self.value
+    }
+    pub fn clear_value(&mut self) {
+        self.value.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_value(&mut self, v: ::std::vec::Vec<u8>) {
+        self.value = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_value(&mut self) -> &mut ::std::vec::Vec<u8> {
+        &mut self.value
+    }
+
+    // Take field
+    pub fn take_value(&mut self) -> ::std::vec::Vec<u8> {
+        ::std::mem::replace(&mut self.value, ::std::vec::Vec::new())
+    }
 }
 
-impl ::protobuf::Message for QueryRemove {
+impl ::protobuf::Message for QueryInsertStorageCell {
     fn is_initialized(&self) -> bool {
         true
     }
@@ -4545,6 +5288,12 @@ impl ::protobuf::Message for QueryRemove {
                 1 => {
                     ::protobuf::rt::read_singular_proto3_bytes_into(wire_type, is, &mut self.address)?;
                 },
+                2 => {
+                    ::protobuf::rt::read_singular_proto3_bytes_into(wire_type, is, &mut self.index)?;
+                },
+                3 => {
+                    ::protobuf::rt::read_singular_proto3_bytes_into(wire_type, is, &mut self.value)?;
+                },
                 _ => {
                     ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
                 },
@@ -4560,6 +5309,12 @@ impl ::protobuf::Message for QueryRemove {
         if !self.address.is_empty() {
             my_size += ::protobuf::rt::bytes_size(1, &self.address);
         }
+        if !self.index.is_empty() {
+            my_size += ::protobuf::rt::bytes_size(2, &self.index);
+        }
+        if !self.value.is_empty() {
+            my_size += ::protobuf::rt::bytes_size(3, &self.value);
+        }
         my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
         self.cached_size.set(my_size);
         my_size
@@ -4569,6 +5324,12 @@ impl ::protobuf::Message for QueryRemove {
         if !self.address.is_empty() {
             os.write_bytes(1, &self.address)?;
         }
+        if !self.index.is_empty() {
+            os.write_bytes(2, &self.index)?;
+        }
+        if !self.value.is_empty() {
+            os.write_bytes(3, &self.value)?;
+        }
         os.write_unknown_fields(self.get_unknown_fields())?;
         ::std::result::Result::Ok(())
     }
@@ -4599,8 +5360,8 @@ impl ::protobuf::Message for QueryRemove {
         Self::descriptor_static()
     }
 
-    fn new() -> QueryRemove {
-        QueryRemove::new()
+    fn new() -> QueryInsertStorageCell {
+        QueryInsertStorageCell::new()
     }
 
     fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
@@ -4613,11 +5374,21 @@ impl ::protobuf::Message for QueryRemove {
                 let mut fields = ::std::vec::Vec::new();
                 fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeBytes>(
                     "address",
-                    |m: &QueryRemove| { &m.address },
-                    |m: &mut QueryRemove| { &mut m.address },
+                    |m: &QueryInsertStorageCell| { &m.address },
+                    |m: &mut QueryInsertStorageCell| { &mut m.address },
                 ));
-                ::protobuf::reflect::MessageDescriptor::new::<QueryRemove>(
-                    "QueryRemove",
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeBytes>(
+                    "index",
+                    |m: &QueryInsertStorageCell| { &m.index },
+                    |m: &mut QueryInsertStorageCell| { &mut m.index },
+                ));
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeBytes>(
+                    "value",
+                    |m: &QueryInsertStorageCell| { &m.value },
+                    |m: &mut QueryInsertStorageCell| { &mut m.value },
+                ));
+                ::protobuf::reflect::MessageDescriptor::new::<QueryInsertStorageCell>(
+                    "QueryInsertStorageCell",
                     fields,
                     file_descriptor_proto()
                 )
@@ -4625,56 +5396,58 @@ impl ::protobuf::Message for QueryRemove {
         }
     }
 
-    fn default_instance() -> &'static QueryRemove {
-        static mut instance: ::protobuf::lazy::Lazy<QueryRemove> = ::protobuf::lazy::Lazy {
+    fn default_instance() -> &'static QueryInsertStorageCell {
+        static mut instance: ::protobuf::lazy::Lazy<QueryInsertStorageCell> = ::protobuf::lazy::Lazy {
             lock: ::protobuf::lazy::ONCE_INIT,
-            ptr: 0 as *const QueryRemove,
+            ptr: 0 as *const QueryInsertStorageCell,
         };
         unsafe {
-            instance.get(QueryRemove::new)
+            instance.get(QueryInsertStorageCell::new)
         }
     }
 }
 
-impl ::protobuf::Clear for QueryRemove {
+impl ::protobuf::Clear for QueryInsertStorageCell {
     fn clear(&mut self) {
         self.address.clear();
+        self.index.clear();
+        self.value.clear();
         self.unknown_fields.clear();
     }
 }
 
-impl ::std::fmt::Debug for QueryRemove {
+impl ::std::fmt::Debug for QueryInsertStorageCell {
     fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
         ::protobuf::text_format::fmt(self, f)
     }
 }
 
-impl ::protobuf::reflect::ProtobufValue for QueryRemove {
+impl ::protobuf::reflect::ProtobufValue for QueryInsertStorageCell {
     fn as_ref(&self) -> ::protobuf::reflect::ProtobufValueRef {
         ::protobuf::reflect::ProtobufValueRef::Message(self)
     }
 }
 
 #[derive(PartialEq,Clone,Default)]
-pub struct QueryRemoveResponse {
+pub struct QueryInsertStorageCellResponse {
     // special fields
     pub unknown_fields: ::protobuf::UnknownFields,
     pub cached_size: ::protobuf::CachedSize,
 }
 
-impl<'a> ::std::default::Default for &'a QueryRemoveResponse {
-    fn default() -> &'a QueryRemoveResponse {
-        <QueryRemoveResponse as ::protobuf::Message>::default_instance()
+impl<'a> ::std::default::Default for &'a QueryInsertStorageCellResponse {
+    fn default() -> &'a QueryInsertStorageCellResponse {
+        <QueryInsertStorageCellResponse as ::protobuf::Message>::default_instance()
     }
 }
 
-impl QueryRemoveResponse {
-    pub fn new() -> QueryRemoveResponse {
+impl QueryInsertStorageCellResponse {
+    pub fn new() -> QueryInsertStorageCellResponse {
         ::std::default::Default::default()
     }
 }
 
-impl ::protobuf::Message for QueryRemoveResponse {
+impl ::protobuf::Message for QueryInsertStorageCellResponse {
     fn is_initialized(&self) -> bool {
         true
     }
@@ -4731,8 +5504,8 @@ impl ::protobuf::Message for QueryRemoveResponse {
         Self::descriptor_static()
     }
 
-    fn new() -> QueryRemoveResponse {
-        QueryRemoveResponse::new()
+    fn new() -> QueryInsertStorageCellResponse {
+        QueryInsertStorageCellResponse::new()
     }
 
     fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
@@ -4743,8 +5516,8 @@ impl ::protobuf::Message for QueryRemoveResponse {
         unsafe {
             descriptor.get(|| {
                 let fields = ::std::vec::Vec::new();
-                ::protobuf::reflect::MessageDescriptor::new::<QueryRemoveResponse>(
-                    "QueryRemoveResponse",
+                ::protobuf::reflect::MessageDescriptor::new::<QueryInsertStorageCellResponse>(
+                    "QueryInsertStorageCellResponse",
                     fields,
                     file_descriptor_proto()
                 )
@@ -4752,53 +5525,52 @@ impl ::protobuf::Message for QueryRemoveResponse {
         }
     }
 
-    fn default_instance() -> &'static QueryRemoveResponse {
-        static mut instance: ::protobuf::lazy::Lazy<QueryRemoveResponse> = ::protobuf::lazy::Lazy {
+    fn default_instance() -> &'static QueryInsertStorageCellResponse {
+        static mut instance: ::protobuf::lazy::Lazy<QueryInsertStorageCellResponse> = ::protobuf::lazy::Lazy {
             lock: ::protobuf::lazy::ONCE_INIT,
-            ptr: 0 as *const QueryRemoveResponse,
+            ptr: 0 as *const QueryInsertStorageCellResponse,
         };
         unsafe {
-            instance.get(QueryRemoveResponse::new)
+            instance.get(QueryInsertStorageCellResponse::new)
         }
     }
 }
 
-impl ::protobuf::Clear for QueryRemoveResponse {
+impl ::protobuf::Clear for QueryInsertStorageCellResponse {
     fn clear(&mut self) {
         self.unknown_fields.clear();
     }
 }
 
-impl ::std::fmt::Debug for QueryRemoveResponse {
+impl ::std::fmt::Debug for QueryInsertStorageCellResponse {
     fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
         ::protobuf::text_format::fmt(self, f)
     }
 }
 
-impl ::protobuf::reflect::ProtobufValue for QueryRemoveResponse {
+impl ::protobuf::reflect::ProtobufValue for QueryInsertStorageCellResponse {
     fn as_ref(&self) -> ::protobuf::reflect::ProtobufValueRef {
         ::protobuf::reflect::ProtobufValueRef::Message(self)
     }
 }
 
 #[derive(PartialEq,Clone,Default)]
-pub struct QueryRemoveStorageCell {
+pub struct QueryRemove {
     // message fields
     pub address: ::std::vec::Vec<u8>,
-    pub index: ::std::vec::Vec<u8>,
     // special fields
     pub unknown_fields: ::protobuf::UnknownFields,
     pub cached_size: ::protobuf::CachedSize,
 }
 
-impl<'a> ::std::default::Default for &'a QueryRemoveStorageCell {
-    fn default() -> &'a QueryRemoveStorageCell {
-        <QueryRemoveStorageCell as ::protobuf::Message>::default_instance()
+impl<'a> ::std::default::Default for &'a QueryRemove {
+    fn default() -> &'a QueryRemove {
+        <QueryRemove as ::protobuf::Message>::default_instance()
     }
 }
 
-impl QueryRemoveStorageCell {
-    pub fn new() -> QueryRemoveStorageCell {
+impl QueryRemove {
+    pub fn new() -> QueryRemove {
         ::std::default::Default::default()
     }
 
@@ -4827,35 +5599,9 @@ impl QueryRemoveStorageCell {
     pub fn take_address(&mut self) -> ::std::vec::Vec<u8> {
         ::std::mem::replace(&mut self.address, ::std::vec::Vec::new())
     }
-
-    // bytes index = 2;
-
-
-    pub fn get_index(&self) -> &[u8] {
-        &self.index
-    }
-    pub fn clear_index(&mut self) {
-        self.index.clear();
-    }
-
-    // Param is passed by value, moved
-    pub fn set_index(&mut self, v: ::std::vec::Vec<u8>) {
-        self.index = v;
-    }
-
-    // Mutable pointer to the field.
-    // If field is not initialized, it is initialized with default value first.
-    pub fn mut_index(&mut self) -> &mut ::std::vec::Vec<u8> {
-        &mut self.index
-    }
-
-    // Take field
-    pub fn take_index(&mut self) -> ::std::vec::Vec<u8> {
-        ::std::mem::replace(&mut self.index, ::std::vec::Vec::new())
-    }
 }
 
-impl ::protobuf::Message for QueryRemoveStorageCell {
+impl ::protobuf::Message for QueryRemove {
     fn is_initialized(&self) -> bool {
         true
     }
@@ -4867,9 +5613,6 @@ impl ::protobuf::Message for QueryRemoveStorageCell {
                 1 => {
                     ::protobuf::rt::read_singular_proto3_bytes_into(wire_type, is, &mut self.address)?;
                 },
-                2 => {
-                    ::protobuf::rt::read_singular_proto3_bytes_into(wire_type, is, &mut self.index)?;
-                },
                 _ => {
                     ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
                 },
@@ -4885,9 +5628,6 @@ impl ::protobuf::Message for QueryRemoveStorageCell {
         if !self.address.is_empty() {
             my_size += ::protobuf::rt::bytes_size(1, &self.address);
         }
-        if !self.index.is_empty() {
-            my_size += ::protobuf::rt::bytes_size(2, &self.index);
-        }
         my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
         self.cached_size.set(my_size);
         my_size
@@ -4897,9 +5637,6 @@ impl ::protobuf::Message for QueryRemoveStorageCell {
         if !self.address.is_empty() {
             os.write_bytes(1, &self.address)?;
         }
-        if !self.index.is_empty() {
-            os.write_bytes(2, &self.index)?;
-        }
         os.write_unknown_fields(self.get_unknown_fields())?;
         ::std::result::Result::Ok(())
     }
@@ -4930,8 +5667,8 @@ impl ::protobuf::Message for QueryRemoveStorageCell {
         Self::descriptor_static()
     }
 
-    fn new() -> QueryRemoveStorageCell {
-        QueryRemoveStorageCell::new()
+    fn new() -> QueryRemove {
+        QueryRemove::new()
     }
 
     fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
@@ -4944,16 +5681,11 @@ impl ::protobuf::Message for QueryRemoveStorageCell {
                 let mut fields = ::std::vec::Vec::new();
                 fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeBytes>(
                     "address",
-                    |m: &QueryRemoveStorageCell| { &m.address },
-                    |m: &mut QueryRemoveStorageCell| { &mut m.address },
-                ));
-                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeBytes>(
-                    "index",
-                    |m: &QueryRemoveStorageCell| { &m.index },
-                    |m: &mut QueryRemoveStorageCell| { &mut m.index },
+                    |m: &QueryRemove| { &m.address },
+                    |m: &mut QueryRemove| { &mut m.address },
                 ));
-                ::protobuf::reflect::MessageDescriptor::new::<QueryRemoveStorageCell>(
-                    "QueryRemoveStorageCell",
+                ::protobuf::reflect::MessageDescriptor::new::<QueryRemove>(
+                    "QueryRemove",
                     fields,
                     file_descriptor_proto()
                 )
@@ -4961,57 +5693,56 @@ impl ::protobuf::Message for QueryRemoveStorageCell {
         }
     }
 
-    fn default_instance() -> &'static QueryRemoveStorageCell {
-        static mut instance: ::protobuf::lazy::Lazy<QueryRemoveStorageCell> = ::protobuf::lazy::Lazy {
+    fn default_instance() -> &'static QueryRemove {
+        static mut instance: ::protobuf::lazy::Lazy<QueryRemove> = ::protobuf::lazy::Lazy {
             lock: ::protobuf::lazy::ONCE_INIT,
-            ptr: 0 as *const QueryRemoveStorageCell,
+            ptr: 0 as *const QueryRemove,
         };
         unsafe {
-            instance.get(QueryRemoveStorageCell::new)
+            instance.get(QueryRemove::new)
         }
     }
 }
 
-impl ::protobuf::Clear for QueryRemoveStorageCell {
+impl ::protobuf::Clear for QueryRemove {
     fn clear(&mut self) {
         self.address.clear();
-        self.index.clear();
         self.unknown_fields.clear();
     }
 }
 
-impl ::std::fmt::Debug for QueryRemoveStorageCell {
+impl ::std::fmt::Debug for QueryRemove {
     fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
         ::protobuf::text_format::fmt(self, f)
     }
 }
 
-impl ::protobuf::reflect::ProtobufValue for QueryRemoveStorageCell {
+impl ::protobuf::reflect::ProtobufValue for QueryRemove {
     fn as_ref(&self) -> ::protobuf::reflect::ProtobufValueRef {
         ::protobuf::reflect::ProtobufValueRef::Message(self)
     }
 }
 
 #[derive(PartialEq,Clone,Default)]
-pub struct QueryRemoveStorageCellResponse {
+pub struct QueryRemoveResponse {
     // special fields
     pub unknown_fields: ::protobuf::UnknownFields,
     pub cached_size: ::protobuf::CachedSize,
 }
 
-impl<'a> ::std::default::Default for &'a QueryRemoveStorageCellResponse {
-    fn default() -> &'a QueryRemoveStorageCellResponse {
-        <QueryRemoveStorageCellResponse as ::protobuf::Message>::default_instance()
+impl<'a> ::std::default::Default for &'a QueryRemoveResponse {
+    fn default() -> &'a QueryRemoveResponse {
+        <QueryRemoveResponse as ::protobuf::Message>::default_instance()
     }
 }
 
-impl QueryRemoveStorageCellResponse {
-    pub fn new() -> QueryRemoveStorageCellResponse {
+impl QueryRemoveResponse {
+    pub fn new() -> QueryRemoveResponse {
         ::std::default::Default::default()
     }
 }
 
-impl ::protobuf::Message for QueryRemoveStorageCellResponse {
+impl ::protobuf::Message for QueryRemoveResponse {
     fn is_initialized(&self) -> bool {
         true
     }
@@ -5068,8 +5799,8 @@ impl ::protobuf::Message for QueryRemoveStorageCellResponse {
         Self::descriptor_static()
     }
 
-    fn new() -> QueryRemoveStorageCellResponse {
-        QueryRemoveStorageCellResponse::new()
+    fn new() -> QueryRemoveResponse {
+        QueryRemoveResponse::new()
     }
 
     fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
@@ -5080,8 +5811,8 @@ impl ::protobuf::Message for QueryRemoveStorageCellResponse {
         unsafe {
             descriptor.get(|| {
                 let fields = ::std::vec::Vec::new();
-                ::protobuf::reflect::MessageDescriptor::new::<QueryRemoveStorageCellResponse>(
-                    "QueryRemoveStorageCellResponse",
+                ::protobuf::reflect::MessageDescriptor::new::<QueryRemoveResponse>(
+                    "QueryRemoveResponse",
                     fields,
                     file_descriptor_proto()
                 )
@@ -5089,52 +5820,53 @@ impl ::protobuf::Message for QueryRemoveStorageCellResponse {
         }
     }
 
-    fn default_instance() -> &'static QueryRemoveStorageCellResponse {
-        static mut instance: ::protobuf::lazy::Lazy<QueryRemoveStorageCellResponse> = ::protobuf::lazy::Lazy {
+    fn default_instance() -> &'static QueryRemoveResponse {
+        static mut instance: ::protobuf::lazy::Lazy<QueryRemoveResponse> = ::protobuf::lazy::Lazy {
             lock: ::protobuf::lazy::ONCE_INIT,
-            ptr: 0 as *const QueryRemoveStorageCellResponse,
+            ptr: 0 as *const QueryRemoveResponse,
         };
         unsafe {
-            instance.get(QueryRemoveStorageCellResponse::new)
+            instance.get(QueryRemoveResponse::new)
         }
     }
 }
 
-impl ::protobuf::Clear for QueryRemoveStorageCellResponse {
+impl ::protobuf::Clear for QueryRemoveResponse {
     fn clear(&mut self) {
         self.unknown_fields.clear();
     }
 }
 
-impl ::std::fmt::Debug for QueryRemoveStorageCellResponse {
+impl ::std::fmt::Debug for QueryRemoveResponse {
     fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
         ::protobuf::text_format::fmt(self, f)
     }
 }
 
-impl ::protobuf::reflect::ProtobufValue for QueryRemoveStorageCellResponse {
+impl ::protobuf::reflect::ProtobufValue for QueryRemoveResponse {
     fn as_ref(&self) -> ::protobuf::reflect::ProtobufValueRef {
         ::protobuf::reflect::ProtobufValueRef::Message(self)
     }
 }
 
 #[derive(PartialEq,Clone,Default)]
-pub struct QueryRemoveStorage {
+pub struct QueryRemoveStorageCell {
     // message fields
     pub address: ::std::vec::Vec<u8>,
+    pub index: ::std::vec::Vec<u8>,
     // special fields
     pub unknown_fields: ::protobuf::UnknownFields,
     pub cached_size: ::protobuf::CachedSize,
 }
 
-impl<'a> ::std::default::Default for &'a QueryRemoveStorage {
-    fn default() -> &'a QueryRemoveStorage {
-        <QueryRemoveStorage as ::protobuf::Message>::default_instance()
+impl<'a> ::std::default::Default for &'a QueryRemoveStorageCell {
+    fn default() -> &'a QueryRemoveStorageCell {
+        <QueryRemoveStorageCell as ::protobuf::Message>::default_instance()
     }
 }
 
-impl QueryRemoveStorage {
-    pub fn new() -> QueryRemoveStorage {
+impl QueryRemoveStorageCell {
+    pub fn new() -> QueryRemoveStorageCell {
         ::std::default::Default::default()
     }
 
@@ -5163,9 +5895,35 @@ impl QueryRemoveStorage {
     pub fn take_address(&mut self) -> ::std::vec::Vec<u8> {
         ::std::mem::replace(&mut self.address, ::std::vec::Vec::new())
     }
+
+    // bytes index = 2;
+
+
+    pub fn get_index(&self) -> &[u8] {
+        &self.index
+    }
+    pub fn clear_index(&mut self) {
+        self.index.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_index(&mut self, v: ::std::vec::Vec<u8>) {
+        self.index = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_index(&mut self) -> &mut ::std::vec::Vec<u8> {
+        &mut self.index
+    }
+
+    // Take field
+    pub fn take_index(&mut self) -> ::std::vec::Vec<u8> {
+        ::std::mem::replace(&mut self.index, ::std::vec::Vec::new())
+    }
 }
 
-impl ::protobuf::Message for QueryRemoveStorage {
+impl ::protobuf::Message for QueryRemoveStorageCell {
     fn is_initialized(&self) -> bool {
         true
     }
@@ -5177,6 +5935,9 @@ impl ::protobuf::Message for QueryRemoveStorage {
                 1 => {
                     ::protobuf::rt::read_singular_proto3_bytes_into(wire_type, is, &mut self.address)?;
                 },
+                2 => {
+                    ::protobuf::rt::read_singular_proto3_bytes_into(wire_type, is, &mut self.index)?;
+                },
                 _ => {
                     ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
                 },
@@ -5192,6 +5953,9 @@ impl ::protobuf::Message for QueryRemoveStorage {
         if !self.address.is_empty() {
             my_size += ::protobuf::rt::bytes_size(1, &self.address);
         }
+        if !self.index.is_empty() {
+            my_size += ::protobuf::rt::bytes_size(2, &self.index);
+        }
         my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
         self.cached_size.set(my_size);
         my_size
@@ -5201,6 +5965,9 @@ impl ::protobuf::Message for QueryRemoveStorage {
         if !self.address.is_empty() {
             os.write_bytes(1, &self.address)?;
         }
+        if !self.index.is_empty() {
+            os.write_bytes(2, &self.index)?;
+        }
         os.write_unknown_fields(self.get_unknown_fields())?;
         ::std::result::Result::Ok(())
     }
@@ -5231,8 +5998,8 @@ impl ::protobuf::Message for QueryRemoveStorage {
         Self::descriptor_static()
     }
 
-    fn new() -> QueryRemoveStorage {
-        QueryRemoveStorage::new()
+    fn new() -> QueryRemoveStorageCell {
+        QueryRemoveStorageCell::new()
     }
 
     fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
@@ -5245,11 +6012,16 @@ impl ::protobuf::Message for QueryRemoveStorage {
                 let mut fields = ::std::vec::Vec::new();
                 fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeBytes>(
                     "address",
-                    |m: &QueryRemoveStorage| { &m.address },
-                    |m: &mut QueryRemoveStorage| { &mut m.address },
+                    |m: &QueryRemoveStorageCell| { &m.address },
+                    |m: &mut QueryRemoveStorageCell| { &mut m.address },
                 ));
-                ::protobuf::reflect::MessageDescriptor::new::<QueryRemoveStorage>(
-                    "QueryRemoveStorage",
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeBytes>(
+                    "index",
+                    |m: &QueryRemoveStorageCell| { &m.index },
+                    |m: &mut QueryRemoveStorageCell| { &mut m.index },
+                ));
+                ::protobuf::reflect::MessageDescriptor::new::<QueryRemoveStorageCell>(
+                    "QueryRemoveStorageCell",
                     fields,
                     file_descriptor_proto()
                 )
@@ -5257,56 +6029,57 @@ impl ::protobuf::Message for QueryRemoveStorage {
         }
     }
 
-    fn default_instance() -> &'static QueryRemoveStorage {
-        static mut instance: ::protobuf::lazy::Lazy<QueryRemoveStorage> = ::protobuf::lazy::Lazy {
+    fn default_instance() -> &'static QueryRemoveStorageCell {
+        static mut instance: ::protobuf::lazy::Lazy<QueryRemoveStorageCell> = ::protobuf::lazy::Lazy {
             lock: ::protobuf::lazy::ONCE_INIT,
-            ptr: 0 as *const QueryRemoveStorage,
+            ptr: 0 as *const QueryRemoveStorageCell,
         };
         unsafe {
-            instance.get(QueryRemoveStorage::new)
+            instance.get(QueryRemoveStorageCell::new)
         }
     }
 }
 
-impl ::protobuf::Clear for QueryRemoveStorage {
+impl ::protobuf::Clear for QueryRemoveStorageCell {
     fn clear(&mut self) {
         self.address.clear();
+        self.index.clear();
         self.unknown_fields.clear();
     }
 }
 
-impl ::std::fmt::Debug for QueryRemoveStorage {
+impl ::std::fmt::Debug for QueryRemoveStorageCell {
     fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
         ::protobuf::text_format::fmt(self, f)
     }
 }
 
-impl ::protobuf::reflect::ProtobufValue for QueryRemoveStorage {
+impl ::protobuf::reflect::ProtobufValue for QueryRemoveStorageCell {
     fn as_ref(&self) -> ::protobuf::reflect::ProtobufValueRef {
         ::protobuf::reflect::ProtobufValueRef::Message(self)
     }
 }
 
 #[derive(PartialEq,Clone,Default)]
-pub struct QueryRemoveStorageResponse {
+pub struct QueryRemoveStorageCellResponse {
     // special fields
     pub unknown_fields: ::protobuf::UnknownFields,
     pub cached_size: ::protobuf::CachedSize,
 }
 
-impl<'a> ::std::default::Default for &'a QueryRemoveStorageResponse {
-    fn default() -> &'a QueryRemoveStorageResponse {
-        <QueryRemoveStorageResponse as ::protobuf::Message>::default_instance()
+impl<'a> ::std::default::Default for &'a QueryRemoveStorageCellResponse {
+    fn default() -> &'a QueryRemoveStorageCellResponse {
+        <QueryRemoveStorageCellResponse as ::protobuf::Message>::default_instance()
     }
 }
 
-impl QueryRemoveStorageResponse {
-    pub fn new() -> QueryRemoveStorageResponse {
+impl QueryRemoveStorageCellResponse {
+    pub fn new() -> QueryRemoveStorageCellResponse {
         ::std::default::Default::default()
     }
 }
 
-impl ::protobuf::Message for QueryRemoveStorageResponse {
+impl ::protobuf::Message for QueryRemoveStorageCellResponse {
     fn is_initialized(&self) -> bool {
         true
     }
@@ -5363,8 +6136,8 @@ impl ::protobuf::Message for QueryRemoveStorageResponse {
         Self::descriptor_static()
     }
 
-    fn new() -> QueryRemoveStorageResponse {
-        QueryRemoveStorageResponse::new()
+    fn new() -> QueryRemoveStorageCellResponse {
+        QueryRemoveStorageCellResponse::new()
     }
 
     fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
@@ -5375,8 +6148,8 @@ impl ::protobuf::Message for QueryRemoveStorageResponse {
         unsafe {
             descriptor.get(|| {
                 let fields = ::std::vec::Vec::new();
-                ::protobuf::reflect::MessageDescriptor::new::<QueryRemoveStorageResponse>(
-                    "QueryRemoveStorageResponse",
+                ::protobuf::reflect::MessageDescriptor::new::<QueryRemoveStorageCellResponse>(
+                    "QueryRemoveStorageCellResponse",
                     fields,
                     file_descriptor_proto()
                 )
@@ -5384,83 +6157,83 @@ impl ::protobuf::Message for QueryRemoveStorageResponse {
         }
     }
 
-    fn default_instance() -> &'static QueryRemoveStorageResponse {
-        static mut instance: ::protobuf::lazy::Lazy<QueryRemoveStorageResponse> = ::protobuf::lazy::Lazy {
+    fn default_instance() -> &'static QueryRemoveStorageCellResponse {
+        static mut instance: ::protobuf::lazy::Lazy<QueryRemoveStorageCellResponse> = ::protobuf::lazy::Lazy {
             lock: ::protobuf::lazy::ONCE_INIT,
-            ptr: 0 as *const QueryRemoveStorageResponse,
+            ptr: 0 as *const QueryRemoveStorageCellResponse,
         };
         unsafe {
-            instance.get(QueryRemoveStorageResponse::new)
+            instance.get(QueryRemoveStorageCellResponse::new)
         }
     }
 }
 
-impl ::protobuf::Clear for QueryRemoveStorageResponse {
+impl ::protobuf::Clear for QueryRemoveStorageCellResponse {
     fn clear(&mut self) {
         self.unknown_fields.clear();
     }
 }
 
-impl ::std::fmt::Debug for QueryRemoveStorageResponse {
+impl ::std::fmt::Debug for QueryRemoveStorageCellResponse {
     fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
         ::protobuf::text_format::fmt(self, f)
     }
 }
 
-impl ::protobuf::reflect::ProtobufValue for QueryRemoveStorageResponse {
+impl ::protobuf::reflect::ProtobufValue for QueryRemoveStorageCellResponse {
     fn as_ref(&self) -> ::protobuf::reflect::ProtobufValueRef {
         ::protobuf::reflect::ProtobufValueRef::Message(self)
     }
 }
 
 #[derive(PartialEq,Clone,Default)]
-pub struct QueryBlockHash {
+pub struct QueryRemoveStorage {
     // message fields
-    pub number: ::std::vec::Vec<u8>,
+    pub address: ::std::vec::Vec<u8>,
     // special fields
     pub unknown_fields: ::protobuf::UnknownFields,
     pub cached_size: ::protobuf::CachedSize,
 }
 
-impl<'a> ::std::default::Default for &'a QueryBlockHash {
-    fn default() -> &'a QueryBlockHash {
-        <QueryBlockHash as ::protobuf::Message>::default_instance()
+impl<'a> ::std::default::Default for &'a QueryRemoveStorage {
+    fn default() -> &'a QueryRemoveStorage {
+        <QueryRemoveStorage as ::protobuf::Message>::default_instance()
     }
 }
 
-impl QueryBlockHash {
-    pub fn new() -> QueryBlockHash {
+impl QueryRemoveStorage {
+    pub fn new() -> QueryRemoveStorage {
         ::std::default::Default::default()
     }
 
-    // bytes number = 1;
+    // bytes address = 1;
 
 
-    pub fn get_number(&self) -> &[u8] {
-        &self.number
+    pub fn get_address(&self) -> &[u8] {
+        &self.address
     }
-    pub fn clear_number(&mut self) {
-        self.number.clear();
+    pub fn clear_address(&mut self) {
+        self.address.clear();
     }
 
     // Param is passed by value, moved
-    pub fn set_number(&mut self, v: ::std::vec::Vec<u8>) {
-        self.number = v;
+    pub fn set_address(&mut self, v: ::std::vec::Vec<u8>) {
+        self.address = v;
     }
 
     // Mutable pointer to the field.
     // If field is not initialized, it is initialized with default value first.
-    pub fn mut_number(&mut self) -> &mut ::std::vec::Vec<u8> {
-        &mut self.number
+    pub fn mut_address(&mut self) -> &mut ::std::vec::Vec<u8> {
+        &mut self.address
     }
 
     // Take field
-    pub fn take_number(&mut self) -> ::std::vec::Vec<u8> {
-        ::std::mem::replace(&mut self.number, ::std::vec::Vec::new())
+    pub fn take_address(&mut self) -> ::std::vec::Vec<u8> {
+        ::std::mem::replace(&mut self.address, ::std::vec::Vec::new())
     }
 }
 
-impl ::protobuf::Message for QueryBlockHash {
+impl ::protobuf::Message for QueryRemoveStorage {
     fn is_initialized(&self) -> bool {
         true
     }
@@ -5470,7 +6243,7 @@ impl ::protobuf::Message for QueryBlockHash {
             let (field_number, wire_type) = is.read_tag_unpack()?;
             match field_number {
                 1 => {
-                    ::protobuf::rt::read_singular_proto3_bytes_into(wire_type, is, &mut self.number)?;
+                    ::protobuf::rt::read_singular_proto3_bytes_into(wire_type, is, &mut self.address)?;
                 },
                 _ => {
                     ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
@@ -5484,8 +6257,8 @@ impl ::protobuf::Message for QueryBlockHash {
     #[allow(unused_variables)]
     fn compute_size(&self) -> u32 {
         let mut my_size = 0;
-        if !self.number.is_empty() {
-            my_size += ::protobuf::rt::bytes_size(1, &self.number);
+        if !self.address.is_empty() {
+            my_size += ::protobuf::rt::bytes_size(1, &self.address);
         }
         my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
         self.cached_size.set(my_size);
@@ -5493,8 +6266,8 @@ impl ::protobuf::Message for QueryBlockHash {
     }
 
     fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
-        if !self.number.is_empty() {
-            os.write_bytes(1, &self.number)?;
+        if !self.address.is_empty() {
+            os.write_bytes(1, &self.address)?;
         }
         os.write_unknown_fields(self.get_unknown_fields())?;
         ::std::result::Result::Ok(())
@@ -5526,8 +6299,8 @@ impl ::protobuf::Message for QueryBlockHash {
         Self::descriptor_static()
     }
 
-    fn new() -> QueryBlockHash {
-        QueryBlockHash::new()
+    fn new() -> QueryRemoveStorage {
+        QueryRemoveStorage::new()
     }
 
     fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
@@ -5539,12 +6312,12 @@ impl ::protobuf::Message for QueryBlockHash {
             descriptor.get(|| {
                 let mut fields = ::std::vec::Vec::new();
                 fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeBytes>(
-                    "number",
-                    |m: &QueryBlockHash| { &m.number },
-                    |m: &mut QueryBlockHash| { &mut m.number },
+                    "address",
+                    |m: &QueryRemoveStorage| { &m.address },
+                    |m: &mut QueryRemoveStorage| { &mut m.address },
                 ));
-                ::protobuf::reflect::MessageDescriptor::new::<QueryBlockHash>(
-                    "QueryBlockHash",
+                ::protobuf::reflect::MessageDescriptor::new::<QueryRemoveStorage>(
+                    "QueryRemoveStorage",
                     fields,
                     file_descriptor_proto()
                 )
@@ -5552,84 +6325,56 @@ impl ::protobuf::Message for QueryBlockHash {
         }
     }
 
-    fn default_instance() -> &'static QueryBlockHash {
-        static mut instance: ::protobuf::lazy::Lazy<QueryBlockHash> = ::protobuf::lazy::Lazy {
+    fn default_instance() -> &'static QueryRemoveStorage {
+        static mut instance: ::protobuf::lazy::Lazy<QueryRemoveStorage> = ::protobuf::lazy::Lazy {
             lock: ::protobuf::lazy::ONCE_INIT,
-            ptr: 0 as *const QueryBlockHash,
+            ptr: 0 as *const QueryRemoveStorage,
         };
         unsafe {
-            instance.get(QueryBlockHash::new)
+            instance.get(QueryRemoveStorage::new)
         }
     }
 }
 
-impl ::protobuf::Clear for QueryBlockHash {
+impl ::protobuf::Clear for QueryRemoveStorage {
     fn clear(&mut self) {
-        self.number.clear();
+        self.address.clear();
         self.unknown_fields.clear();
     }
 }
 
-impl ::std::fmt::Debug for QueryBlockHash {
+impl ::std::fmt::Debug for QueryRemoveStorage {
     fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
         ::protobuf::text_format::fmt(self, f)
     }
 }
 
-impl ::protobuf::reflect::ProtobufValue for QueryBlockHash {
+impl ::protobuf::reflect::ProtobufValue for QueryRemoveStorage {
     fn as_ref(&self) -> ::protobuf::reflect::ProtobufValueRef {
         ::protobuf::reflect::ProtobufValueRef::Message(self)
     }
 }
 
 #[derive(PartialEq,Clone,Default)]
-pub struct QueryBlockHashResponse {
-    // message fields
-    pub hash: ::std::vec::Vec<u8>,
+pub struct QueryRemoveStorageResponse {
     // special fields
     pub unknown_fields: ::protobuf::UnknownFields,
     pub cached_size: ::protobuf::CachedSize,
 }
 
-impl<'a> ::std::default::Default for &'a QueryBlockHashResponse {
-    fn default() -> &'a QueryBlockHashResponse {
-        <QueryBlockHashResponse as ::protobuf::Message>::default_instance()
+impl<'a> ::std::default::Default for &'a QueryRemoveStorageResponse {
+    fn default() -> &'a QueryRemoveStorageResponse {
+        <QueryRemoveStorageResponse as ::protobuf::Message>::default_instance()
     }
 }
 
-impl QueryBlockHashResponse {
-    pub fn new() -> QueryBlockHashResponse {
+impl QueryRemoveStorageResponse {
+    pub fn new() -> QueryRemoveStorageResponse {
         ::std::default::Default::default()
     }
-
-    // bytes hash = 1;
-
-
-    pub fn get_hash(&self) -> &[u8] {
-        &self.hash
-    }
-    pub fn clear_hash(&mut self) {
-        self.hash.clear();
-    }
-
-    // Param is passed by value, moved
-    pub fn set_hash(&mut self, v: ::std::vec::Vec<u8>) {
-        self.hash = v;
-    }
-
-    // Mutable pointer to the field.
-    // If field is not initialized, it is initialized with default value first.
-    pub fn mut_hash(&mut self) -> &mut ::std::vec::Vec<u8> {
-        &mut self.hash
-    }
-
-    // Take field
-    pub fn take_hash(&mut self) -> ::std::vec::Vec<u8> {
-        ::std::mem::replace(&mut self.hash, ::std::vec::Vec::new())
-    }
 }
 
-impl ::protobuf::Message for QueryBlockHashResponse {
+impl ::protobuf::Message for QueryRemoveStorageResponse {
     fn is_initialized(&self) -> bool {
         true
     }
@@ -5638,9 +6383,6 @@ impl ::protobuf::Message for QueryBlockHashResponse {
         while !is.eof()? {
             let (field_number, wire_type) = is.read_tag_unpack()?;
             match field_number {
-                1 => {
-                    ::protobuf::rt::read_singular_proto3_bytes_into(wire_type, is, &mut self.hash)?;
-                },
                 _ => {
                     ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
                 },
@@ -5653,18 +6395,12 @@ impl ::protobuf::Message for QueryBlockHashResponse {
     #[allow(unused_variables)]
     fn compute_size(&self) -> u32 {
         let mut my_size = 0;
-        if !self.hash.is_empty() {
-            my_size += ::protobuf::rt::bytes_size(1, &self.hash);
-        }
         my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
         self.cached_size.set(my_size);
         my_size
     }
 
     fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
-        if !self.hash.is_empty() {
-            os.write_bytes(1, &self.hash)?;
-        }
         os.write_unknown_fields(self.get_unknown_fields())?;
         ::std::result::Result::Ok(())
     }
@@ -5695,8 +6431,8 @@ impl ::protobuf::Message for QueryBlockHashResponse {
         Self::descriptor_static()
     }
 
-    fn new() -> QueryBlockHashResponse {
-        QueryBlockHashResponse::new()
+    fn new() -> QueryRemoveStorageResponse {
+        QueryRemoveStorageResponse::new()
     }
 
     fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
@@ -5706,14 +6442,9 @@ impl ::protobuf::Message for QueryBlockHashResponse {
         };
         unsafe {
             descriptor.get(|| {
-                let mut fields = ::std::vec::Vec::new();
-                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeBytes>(
-                    "hash",
-                    |m: &QueryBlockHashResponse| { &m.hash },
-                    |m: &mut QueryBlockHashResponse| { &mut m.hash },
-                ));
-                ::protobuf::reflect::MessageDescriptor::new::<QueryBlockHashResponse>(
-                    "QueryBlockHashResponse",
+                let fields = ::std::vec::Vec::new();
+                ::protobuf::reflect::MessageDescriptor::new::<QueryRemoveStorageResponse>(
+                    "QueryRemoveStorageResponse",
                     fields,
                     file_descriptor_proto()
                 )
@@ -5721,668 +6452,488 @@ impl ::protobuf::Message for QueryBlockHashResponse {
         }
     }
 
-    fn default_instance() -> &'static QueryBlockHashResponse {
-        static mut instance: ::protobuf::lazy::Lazy<QueryBlockHashResponse> = ::protobuf::lazy::Lazy {
+    fn default_instance() -> &'static QueryRemoveStorageResponse {
+        static mut instance: ::protobuf::lazy::Lazy<QueryRemoveStorageResponse> = ::protobuf::lazy::Lazy {
             lock: ::protobuf::lazy::ONCE_INIT,
-            ptr: 0 as *const QueryBlockHashResponse,
+            ptr: 0 as *const QueryRemoveStorageResponse,
         };
         unsafe {
-            instance.get(QueryBlockHashResponse::new)
+            instance.get(QueryRemoveStorageResponse::new)
         }
     }
 }
 
-impl ::protobuf::Clear for QueryBlockHashResponse {
+impl ::protobuf::Clear for QueryRemoveStorageResponse {
     fn clear(&mut self) {
-        self.hash.clear();
         self.unknown_fields.clear();
     }
 }
 
-impl ::std::fmt::Debug for QueryBlockHashResponse {
+impl ::std::fmt::Debug for QueryRemoveStorageResponse {
     fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
         ::protobuf::text_format::fmt(self, f)
     }
 }
 
-impl ::protobuf::reflect::ProtobufValue for QueryBlockHashResponse {
+impl ::protobuf::reflect::ProtobufValue for QueryRemoveStorageResponse {
     fn as_ref(&self) -> ::protobuf::reflect::ProtobufValueRef {
         ::protobuf::reflect::ProtobufValueRef::Message(self)
     }
 }
 
 #[derive(PartialEq,Clone,Default)]
-pub struct CosmosRequest {
-    // message oneof groups
-    pub req: ::std::option::Option<CosmosRequest_oneof_req>,
+pub struct QueryBlockHash {
+    // message fields
+    pub number: ::std::vec::Vec<u8>,
     // special fields
     pub unknown_fields: ::protobuf::UnknownFields,
     pub cached_size: ::protobuf::CachedSize,
 }
 
-impl<'a> ::std::default::Default for &'a CosmosRequest {
-    fn default() -> &'a CosmosRequest {
-        <CosmosRequest as ::protobuf::Message>::default_instance()
+impl<'a> ::std::default::Default for &'a QueryBlockHash {
+    fn default() -> &'a QueryBlockHash {
+        <QueryBlockHash as ::protobuf::Message>::default_instance()
     }
 }
 
-#[derive(Clone,PartialEq,Debug)]
-pub enum CosmosRequest_oneof_req {
-    getAccount(QueryGetAccount),
-    insertAccount(QueryInsertAccount),
-    containsKey(QueryContainsKey),
-    accountCode(QueryGetAccountCode),
-    storageCell(QueryGetAccountStorageCell),
-    insertAccountCode(QueryInsertAccountCode),
-    insertStorageCell(QueryInsertStorageCell),
-    remove(QueryRemove),
-    removeStorageCell(QueryRemoveStorageCell),
-    removeStorage(QueryRemoveStorage),
-    blockHash(QueryBlockHash),
-}
-
-impl CosmosRequest {
-    pub fn new() -> CosmosRequest {
+impl QueryBlockHash {
+    pub fn new() -> QueryBlockHash {
         ::std::default::Default::default()
     }
 
-    // .ffi.ffi.QueryGetAccount getAccount = 1;
+    // bytes number = 1;
 
 
-    pub fn get_getAccount(&self) -> &QueryGetAccount {
-        match self.req {
-            ::std::option::Option::Some(CosmosRequest_oneof_req::getAccount(ref v)) => v,
-            _ => QueryGetAccount::default_instance(),
-        }
-    }
-    pub fn clear_getAccount(&mut self) {
-        self.req = ::std::option::Option::None;
+    pub fn get_number(&self) -> &[u8] {
+        &self.number
     }
-
-    pub fn has_getAccount(&self) -> bool {
-        match self.req {
-            ::std::option::Option::Some(CosmosRequest_oneof_req::getAccount(..)) => true,
-            _ => false,
-        }
+    pub fn clear_number(&mut self) {
+        self.number.clear();
     }
 
     // Param is passed by value, moved
-    pub fn set_getAccount(&mut self, v: QueryGetAccount) {
-        self.req = ::std::option::Option::Some(CosmosRequest_oneof_req::getAccount(v))
+    pub fn set_number(&mut self, v: ::std::vec::Vec<u8>) {
+        self.number = v;
     }
 
     // Mutable pointer to the field.
-    pub fn mut_getAccount(&mut self) -> &mut QueryGetAccount {
-        if let ::std::option::Option::Some(CosmosRequest_oneof_req::getAccount(_)) = self.req {
-        } else {
-            self.req = ::std::option::Option::Some(CosmosRequest_oneof_req::getAccount(QueryGetAccount::new()));
-        }
-        match self.req {
-            ::std::option::Option::Some(CosmosRequest_oneof_req::getAccount(ref mut v)) => v,
-            _ => panic!(),
-        }
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_number(&mut self) -> &mut ::std::vec::Vec<u8> {
+        &mut self.number
     }
 
     // Take field
-    pub fn take_getAccount(&mut self) -> QueryGetAccount {
-        if self.has_getAccount() {
-            match self.req.take() {
-                ::std::option::Option::Some(CosmosRequest_oneof_req::getAccount(v)) => v,
-                _ => panic!(),
-            }
-        } else {
-            QueryGetAccount::new()
-        }
+    pub fn take_number(&mut self) -> ::std::vec::Vec<u8> {
+        ::std::mem::replace(&mut self.number, ::std::vec::Vec::new())
     }
+}
 
-    // .ffi.ffi.QueryInsertAccount insertAccount = 2;
-
-
-    pub fn get_insertAccount(&self) -> &QueryInsertAccount {
-        match self.req {
-            ::std::option::Option::Some(CosmosRequest_oneof_req::insertAccount(ref v)) => v,
-            _ => QueryInsertAccount::default_instance(),
-        }
-    }
-    pub fn clear_insertAccount(&mut self) {
-        self.req = ::std::option::Option::None;
+impl ::protobuf::Message for QueryBlockHash {
+    fn is_initialized(&self) -> bool {
+        true
     }
 
-    pub fn has_insertAccount(&self) -> bool {
-        match self.req {
-            ::std::option::Option::Some(CosmosRequest_oneof_req::insertAccount(..)) => true,
-            _ => false,
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_singular_proto3_bytes_into(wire_type, is, &mut self.number)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
         }
+        ::std::result::Result::Ok(())
     }
 
-    // Param is passed by value, moved
-    pub fn set_insertAccount(&mut self, v: QueryInsertAccount) {
-        self.req = ::std::option::Option::Some(CosmosRequest_oneof_req::insertAccount(v))
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if !self.number.is_empty() {
+            my_size += ::protobuf::rt::bytes_size(1, &self.number);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
     }
 
-    // Mutable pointer to the field.
-    pub fn mut_insertAccount(&mut self) -> &mut QueryInsertAccount {
-        if let ::std::option::Option::Some(CosmosRequest_oneof_req::insertAccount(_)) = self.req {
-        } else {
-            self.req = ::std::option::Option::Some(CosmosRequest_oneof_req::insertAccount(QueryInsertAccount::new()));
-        }
-        match self.req {
-            ::std::option::Option::Some(CosmosRequest_oneof_req::insertAccount(ref mut v)) => v,
-            _ => panic!(),
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if !self.number.is_empty() {
+            os.write_bytes(1, &self.number)?;
         }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
     }
 
-    // Take field
-    pub fn take_insertAccount(&mut self) -> QueryInsertAccount {
-        if self.has_insertAccount() {
-            match self.req.take() {
-                ::std::option::Option::Some(CosmosRequest_oneof_req::insertAccount(v)) => v,
-                _ => panic!(),
-            }
-        } else {
-            QueryInsertAccount::new()
-        }
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
     }
 
-    // .ffi.ffi.QueryContainsKey containsKey = 3;
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
 
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
 
-    pub fn get_containsKey(&self) -> &QueryContainsKey {
-        match self.req {
-            ::std::option::Option::Some(CosmosRequest_oneof_req::containsKey(ref v)) => v,
-            _ => QueryContainsKey::default_instance(),
-        }
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
     }
-    pub fn clear_containsKey(&mut self) {
-        self.req = ::std::option::Option::None;
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
     }
-
-    pub fn has_containsKey(&self) -> bool {
-        match self.req {
-            ::std::option::Option::Some(CosmosRequest_oneof_req::containsKey(..)) => true,
-            _ => false,
-        }
+    fn into_any(self: Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
     }
 
-    // Param is passed by value, moved
-    pub fn set_containsKey(&mut self, v: QueryContainsKey) {
-        self.req = ::std::option::Option::Some(CosmosRequest_oneof_req::containsKey(v))
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
     }
 
-    // Mutable pointer to the field.
-    pub fn mut_containsKey(&mut self) -> &mut QueryContainsKey {
-        if let ::std::option::Option::Some(CosmosRequest_oneof_req::containsKey(_)) = self.req {
-        } else {
-            self.req = ::std::option::Option::Some(CosmosRequest_oneof_req::containsKey(QueryContainsKey::new()));
-        }
-        match self.req {
-            ::std::option::Option::Some(CosmosRequest_oneof_req::containsKey(ref mut v)) => v,
-            _ => panic!(),
-        }
+    fn new() -> QueryBlockHash {
+        QueryBlockHash::new()
     }
 
-    // Take field
-    pub fn take_containsKey(&mut self) -> QueryContainsKey {
-        if self.has_containsKey() {
-            match self.req.take() {
-                ::std::option::Option::Some(CosmosRequest_oneof_req::containsKey(v)) => v,
-                _ => panic!(),
-            }
-        } else {
-            QueryContainsKey::new()
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const ::protobuf::reflect::MessageDescriptor,
+        };
+        unsafe {
+            descriptor.get(|| {
+                let mut fields = ::std::vec::Vec::new();
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeBytes>(
+                    "number",
+                    |m: &QueryBlockHash| { &m.number },
+                    |m: &mut QueryBlockHash| { &mut m.number },
+                ));
+                ::protobuf::reflect::MessageDescriptor::new::<QueryBlockHash>(
+                    "QueryBlockHash",
+                    fields,
+                    file_descriptor_proto()
+                )
+            })
         }
     }
 
-    // .ffi.ffi.QueryGetAccountCode accountCode = 4;
-
-
-    pub fn get_accountCode(&self) -> &QueryGetAccountCode {
-        match self.req {
-            ::std::option::Option::Some(CosmosRequest_oneof_req::accountCode(ref v)) => v,
-            _ => QueryGetAccountCode::default_instance(),
+    fn default_instance() -> &'static QueryBlockHash {
+        static mut instance: ::protobuf::lazy::Lazy<QueryBlockHash> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const QueryBlockHash,
+        };
+        unsafe {
+            instance.get(QueryBlockHash::new)
         }
     }
-    pub fn clear_accountCode(&mut self) {
-        self.req = ::std::option::Option::None;
+}
+
+impl ::protobuf::Clear for QueryBlockHash {
+    fn clear(&mut self) {
+        self.number.clear();
+        self.unknown_fields.clear();
     }
+}
 
-    pub fn has_accountCode(&self) -> bool {
-        match self.req {
-            ::std::option::Option::Some(CosmosRequest_oneof_req::accountCode(..)) => true,
-            _ => false,
-        }
+impl ::std::fmt::Debug for QueryBlockHash {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
     }
+}
 
-    // Param is passed by value, moved
-    pub fn set_accountCode(&mut self, v: QueryGetAccountCode) {
-        self.req = ::std::option::Option::Some(CosmosRequest_oneof_req::accountCode(v))
+impl ::protobuf::reflect::ProtobufValue for QueryBlockHash {
+    fn as_ref(&self) -> ::protobuf::reflect::ProtobufValueRef {
+        ::protobuf::reflect::ProtobufValueRef::Message(self)
     }
+}
 
-    // Mutable pointer to the field.
-    pub fn mut_accountCode(&mut self) -> &mut QueryGetAccountCode {
-        if let ::std::option::Option::Some(CosmosRequest_oneof_req::accountCode(_)) = self.req {
-        } else {
-            self.req = ::std::option::Option::Some(CosmosRequest_oneof_req::accountCode(QueryGetAccountCode::new()));
-        }
-        match self.req {
-            ::std::option::Option::Some(CosmosRequest_oneof_req::accountCode(ref mut v)) => v,
-            _ => panic!(),
-        }
+#[derive(PartialEq,Clone,Default)]
+pub struct QueryBlockHashResponse {
+    // message fields
+    pub hash: ::std::vec::Vec<u8>,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a QueryBlockHashResponse {
+    fn default() -> &'a QueryBlockHashResponse {
+        <QueryBlockHashResponse as ::protobuf::Message>::default_instance()
     }
+}
 
-    // Take field
-    pub fn take_accountCode(&mut self) -> QueryGetAccountCode {
-        if self.has_accountCode() {
-            match self.req.take() {
-                ::std::option::Option::Some(CosmosRequest_oneof_req::accountCode(v)) => v,
-                _ => panic!(),
-            }
-        } else {
-            QueryGetAccountCode::new()
-        }
+impl QueryBlockHashResponse {
+    pub fn new() -> QueryBlockHashResponse {
+        ::std::default::Default::default()
     }
 
-    // .ffi.ffi.QueryGetAccountStorageCell storageCell = 5;
+    // bytes hash = 1;
 
 
-    pub fn get_storageCell(&self) -> &QueryGetAccountStorageCell {
-        match self.req {
-            ::std::option::Option::Some(CosmosRequest_oneof_req::storageCell(ref v)) => v,
-            _ => QueryGetAccountStorageCell::default_instance(),
-        }
-    }
-    pub fn clear_storageCell(&mut self) {
-        self.req = ::std::option::Option::None;
+    pub fn get_hash(&self) -> &[u8] {
+        &self.hash
     }
-
-    pub fn has_storageCell(&self) -> bool {
-        match self.req {
-            ::std::option::Option::Some(CosmosRequest_oneof_req::storageCell(..)) => true,
-            _ => false,
-        }
+    pub fn clear_hash(&mut self) {
+        self.hash.clear();
     }
 
     // Param is passed by value, moved
-    pub fn set_storageCell(&mut self, v: QueryGetAccountStorageCell) {
-        self.req = ::std::option::Option::Some(CosmosRequest_oneof_req::storageCell(v))
+    pub fn set_hash(&mut self, v: ::std::vec::Vec<u8>) {
+        self.hash = v;
     }
 
     // Mutable pointer to the field.
-    pub fn mut_storageCell(&mut self) -> &mut QueryGetAccountStorageCell {
-        if let ::std::option::Option::Some(CosmosRequest_oneof_req::storageCell(_)) = self.req {
-        } else {
-            self.req = ::std::option::Option::Some(CosmosRequest_oneof_req::storageCell(QueryGetAccountStorageCell::new()));
-        }
-        match self.req {
-            ::std::option::Option::Some(CosmosRequest_oneof_req::storageCell(ref mut v)) => v,
-            _ => panic!(),
-        }
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_hash(&mut self) -> &mut ::std::vec::Vec<u8> {
+        &mut self.hash
     }
 
     // Take field
-    pub fn take_storageCell(&mut self) -> QueryGetAccountStorageCell {
-        if self.has_storageCell() {
-            match self.req.take() {
-                ::std::option::Option::Some(CosmosRequest_oneof_req::storageCell(v)) => v,
-                _ => panic!(),
-            }
-        } else {
-            QueryGetAccountStorageCell::new()
-        }
+    pub fn take_hash(&mut self) -> ::std::vec::Vec<u8> {
+        ::std::mem::replace(&mut self.hash, ::std::vec::Vec::new())
     }
+}
 
-    // .ffi.ffi.QueryInsertAccountCode insertAccountCode = 6;
-
-
-    pub fn get_insertAccountCode(&self) -> &QueryInsertAccountCode {
-        match self.req {
-            ::std::option::Option::Some(CosmosRequest_oneof_req::insertAccountCode(ref v)) => v,
-            _ => QueryInsertAccountCode::default_instance(),
-        }
-    }
-    pub fn clear_insertAccountCode(&mut self) {
-        self.req = ::std::option::Option::None;
+impl ::protobuf::Message for QueryBlockHashResponse {
+    fn is_initialized(&self) -> bool {
+        true
     }
 
-    pub fn has_insertAccountCode(&self) -> bool {
-        match self.req {
-            ::std::option::Option::Some(CosmosRequest_oneof_req::insertAccountCode(..)) => true,
-            _ => false,
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_singular_proto3_bytes_into(wire_type, is, &mut self.hash)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
         }
+        ::std::result::Result::Ok(())
     }
 
-    // Param is passed by value, moved
-    pub fn set_insertAccountCode(&mut self, v: QueryInsertAccountCode) {
-        self.req = ::std::option::Option::Some(CosmosRequest_oneof_req::insertAccountCode(v))
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if !self.hash.is_empty() {
+            my_size += ::protobuf::rt::bytes_size(1, &self.hash);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
     }
 
-    // Mutable pointer to the field.
-    pub fn mut_insertAccountCode(&mut self) -> &mut QueryInsertAccountCode {
-        if let ::std::option::Option::Some(CosmosRequest_oneof_req::insertAccountCode(_)) = self.req {
-        } else {
-            self.req = ::std::option::Option::Some(CosmosRequest_oneof_req::insertAccountCode(QueryInsertAccountCode::new()));
-        }
-        match self.req {
-            ::std::option::Option::Some(CosmosRequest_oneof_req::insertAccountCode(ref mut v)) => v,
-            _ => panic!(),
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if !self.hash.is_empty() {
+            os.write_bytes(1, &self.hash)?;
         }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
     }
 
-    // Take field
-    pub fn take_insertAccountCode(&mut self) -> QueryInsertAccountCode {
-        if self.has_insertAccountCode() {
-            match self.req.take() {
-                ::std::option::Option::Some(CosmosRequest_oneof_req::insertAccountCode(v)) => v,
-                _ => panic!(),
-            }
-        } else {
-            QueryInsertAccountCode::new()
-        }
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
     }
 
-    // .ffi.ffi.QueryInsertStorageCell insertStorageCell = 7;
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
 
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
 
-    pub fn get_insertStorageCell(&self) -> &QueryInsertStorageCell {
-        match self.req {
-            ::std::option::Option::Some(CosmosRequest_oneof_req::insertStorageCell(ref v)) => v,
-            _ => QueryInsertStorageCell::default_instance(),
-        }
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
     }
-    pub fn clear_insertStorageCell(&mut self) {
-        self.req = ::std::option::Option::None;
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
     }
 
-    pub fn has_insertStorageCell(&self) -> bool {
-        match self.req {
-            ::std::option::Option::Some(CosmosRequest_oneof_req::insertStorageCell(..)) => true,
-            _ => false,
-        }
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
     }
 
-    // Param is passed by value, moved
-    pub fn set_insertStorageCell(&mut self, v: QueryInsertStorageCell) {
-        self.req = ::std::option::Option::Some(CosmosRequest_oneof_req::insertStorageCell(v))
+    fn new() -> QueryBlockHashResponse {
+        QueryBlockHashResponse::new()
     }
 
-    // Mutable pointer to the field.
-    pub fn mut_insertStorageCell(&mut self) -> &mut QueryInsertStorageCell {
-        if let ::std::option::Option::Some(CosmosRequest_oneof_req::insertStorageCell(_)) = self.req {
-        } else {
-            self.req = ::std::option::Option::Some(CosmosRequest_oneof_req::insertStorageCell(QueryInsertStorageCell::new()));
-        }
-        match self.req {
-            ::std::option::Option::Some(CosmosRequest_oneof_req::insertStorageCell(ref mut v)) => v,
-            _ => panic!(),
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const ::protobuf::reflect::MessageDescriptor,
+        };
+        unsafe {
+            descriptor.get(|| {
+                let mut fields = ::std::vec::Vec::new();
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeBytes>(
+                    "hash",
+                    |m: &QueryBlockHashResponse| { &m.hash },
+                    |m: &mut QueryBlockHashResponse| { &mut m.hash },
+                ));
+                ::protobuf::reflect::MessageDescriptor::new::<QueryBlockHashResponse>(
+                    "QueryBlockHashResponse",
+                    fields,
+                    file_descriptor_proto()
+                )
+            })
         }
     }
 
-    // Take field
-    pub fn take_insertStorageCell(&mut self) -> QueryInsertStorageCell {
-        if self.has_insertStorageCell() {
-            match self.req.take() {
-                ::std::option::Option::Some(CosmosRequest_oneof_req::insertStorageCell(v)) => v,
-                _ => panic!(),
-            }
-        } else {
-            QueryInsertStorageCell::new()
+    fn default_instance() -> &'static QueryBlockHashResponse {
+        static mut instance: ::protobuf::lazy::Lazy<QueryBlockHashResponse> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const QueryBlockHashResponse,
+        };
+        unsafe {
+            instance.get(QueryBlockHashResponse::new)
         }
     }
+}
 
-    // .ffi.ffi.QueryRemove remove = 8;
+impl ::protobuf::Clear for QueryBlockHashResponse {
+    fn clear(&mut self) {
+        self.hash.clear();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for QueryBlockHashResponse {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
 
+impl ::protobuf::reflect::ProtobufValue for QueryBlockHashResponse {
+    fn as_ref(&self) -> ::protobuf::reflect::ProtobufValueRef {
+        ::protobuf::reflect::ProtobufValueRef::Message(self)
+    }
+}
 
-    pub fn get_remove(&self) -> &QueryRemove {
-        match self.req {
-            ::std::option::Option::Some(CosmosRequest_oneof_req::remove(ref v)) => v,
-            _ => QueryRemove::default_instance(),
-        }
+#[derive(PartialEq,Clone,Default)]
+pub struct QueryBatchRead {
+    // message fields
+    pub accounts: ::protobuf::RepeatedField<QueryGetAccount>,
+    pub codes: ::protobuf::RepeatedField<QueryGetAccountCode>,
+    pub storageCells: ::protobuf::RepeatedField<QueryGetAccountStorageCell>,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a QueryBatchRead {
+    fn default() -> &'a QueryBatchRead {
+        <QueryBatchRead as ::protobuf::Message>::default_instance()
     }
-    pub fn clear_remove(&mut self) {
-        self.req = ::std::option::Option::None;
+}
+
+impl QueryBatchRead {
+    pub fn new() -> QueryBatchRead {
+        ::std::default::Default::default()
     }
 
-    pub fn has_remove(&self) -> bool {
-        match self.req {
-            ::std::option::Option::Some(CosmosRequest_oneof_req::remove(..)) => true,
-            _ => false,
-        }
+    // repeated .ffi.ffi.QueryGetAccount accounts = 1;
+
+
+    pub fn get_accounts(&self) -> &[QueryGetAccount] {
+        &self.accounts
+    }
+    pub fn clear_accounts(&mut self) {
+        self.accounts.clear();
     }
 
     // Param is passed by value, moved
-    pub fn set_remove(&mut self, v: QueryRemove) {
-        self.req = ::std::option::Option::Some(CosmosRequest_oneof_req::remove(v))
+    pub fn set_accounts(&mut self, v: ::protobuf::RepeatedField<QueryGetAccount>) {
+        self.accounts = v;
     }
 
     // Mutable pointer to the field.
-    pub fn mut_remove(&mut self) -> &mut QueryRemove {
-        if let ::std::option::Option::Some(CosmosRequest_oneof_req::remove(_)) = self.req {
-        } else {
-            self.req = ::std::option::Option::Some(CosmosRequest_oneof_req::remove(QueryRemove::new()));
-        }
-        match self.req {
-            ::std::option::Option::Some(CosmosRequest_oneof_req::remove(ref mut v)) => v,
-            _ => panic!(),
-        }
+    pub fn mut_accounts(&mut self) -> &mut ::protobuf::RepeatedField<QueryGetAccount> {
+        &mut self.accounts
     }
 
     // Take field
-    pub fn take_remove(&mut self) -> QueryRemove {
-        if self.has_remove() {
-            match self.req.take() {
-                ::std::option::Option::Some(CosmosRequest_oneof_req::remove(v)) => v,
-                _ => panic!(),
-            }
-        } else {
-            QueryRemove::new()
-        }
+    pub fn take_accounts(&mut self) -> ::protobuf::RepeatedField<QueryGetAccount> {
+        ::std::mem::replace(&mut self.accounts, ::protobuf::RepeatedField::new())
     }
 
-    // .ffi.ffi.QueryRemoveStorageCell removeStorageCell = 9;
+    // repeated .ffi.ffi.QueryGetAccountCode codes = 2;
 
 
-    pub fn get_removeStorageCell(&self) -> &QueryRemoveStorageCell {
-        match self.req {
-            ::std::option::Option::Some(CosmosRequest_oneof_req::removeStorageCell(ref v)) => v,
-            _ => QueryRemoveStorageCell::default_instance(),
-        }
-    }
-    pub fn clear_removeStorageCell(&mut self) {
-        self.req = ::std::option::Option::None;
+    pub fn get_codes(&self) -> &[QueryGetAccountCode] {
+        &self.codes
     }
-
-    pub fn has_removeStorageCell(&self) -> bool {
-        match self.req {
-            ::std::option::Option::Some(CosmosRequest_oneof_req::removeStorageCell(..)) => true,
-            _ => false,
-        }
+    pub fn clear_codes(&mut self) {
+        self.codes.clear();
     }
 
     // Param is passed by value, moved
-    pub fn set_removeStorageCell(&mut self, v: QueryRemoveStorageCell) {
-        self.req = ::std::option::Option::Some(CosmosRequest_oneof_req::removeStorageCell(v))
+    pub fn set_codes(&mut self, v: ::protobuf::RepeatedField<QueryGetAccountCode>) {
+        self.codes = v;
     }
 
     // Mutable pointer to the field.
-    pub fn mut_removeStorageCell(&mut self) -> &mut QueryRemoveStorageCell {
-        if let ::std::option::Option::Some(CosmosRequest_oneof_req::removeStorageCell(_)) = self.req {
-        } else {
-            self.req = ::std::option::Option::Some(CosmosRequest_oneof_req::removeStorageCell(QueryRemoveStorageCell::new()));
-        }
-        match self.req {
-            ::std::option::Option::Some(CosmosRequest_oneof_req::removeStorageCell(ref mut v)) => v,
-            _ => panic!(),
-        }
+    pub fn mut_codes(&mut self) -> &mut ::protobuf::RepeatedField<QueryGetAccountCode> {
+        &mut self.codes
     }
 
     // Take field
-    pub fn take_removeStorageCell(&mut self) -> QueryRemoveStorageCell {
-        if self.has_removeStorageCell() {
-            match self.req.take() {
-                ::std::option::Option::Some(CosmosRequest_oneof_req::removeStorageCell(v)) => v,
-                _ => panic!(),
-            }
-        } else {
-            QueryRemoveStorageCell::new()
-        }
+    pub fn take_codes(&mut self) -> ::protobuf::RepeatedField<QueryGetAccountCode> {
+        ::std::mem::replace(&mut self.codes, ::protobuf::RepeatedField::new())
     }
 
-    // .ffi.ffi.QueryRemoveStorage removeStorage = 10;
+    // repeated .ffi.ffi.QueryGetAccountStorageCell storageCells = 3;
 
 
-    pub fn get_removeStorage(&self) -> &QueryRemoveStorage {
-        match self.req {
-            ::std::option::Option::Some(CosmosRequest_oneof_req::removeStorage(ref v)) => v,
-            _ => QueryRemoveStorage::default_instance(),
-        }
+    pub fn get_storageCells(&self) -> &[QueryGetAccountStorageCell] {
+        &self.storageCells
     }
-    pub fn clear_removeStorage(&mut self) {
-        self.req = ::std::option::Option::None;
-    }
-
-    pub fn has_removeStorage(&self) -> bool {
-        match self.req {
-            ::std::option::Option::Some(CosmosRequest_oneof_req::removeStorage(..)) => true,
-            _ => false,
-        }
-    }
-
-    // Param is passed by value, moved
-    pub fn set_removeStorage(&mut self, v: QueryRemoveStorage) {
-        self.req = ::std::option::Option::Some(CosmosRequest_oneof_req::removeStorage(v))
-    }
-
-    // Mutable pointer to the field.
-    pub fn mut_removeStorage(&mut self) -> &mut QueryRemoveStorage {
-        if let ::std::option::Option::Some(CosmosRequest_oneof_req::removeStorage(_)) = self.req {
-        } else {
-            self.req = ::std::option::Option::Some(CosmosRequest_oneof_req::removeStorage(QueryRemoveStorage::new()));
-        }
-        match self.req {
-            ::std::option::Option::Some(CosmosRequest_oneof_req::removeStorage(ref mut v)) => v,
-            _ => panic!(),
-        }
-    }
-
-    // Take field
-    pub fn take_removeStorage(&mut self) -> QueryRemoveStorage {
-        if self.has_removeStorage() {
-            match self.req.take() {
-                ::std::option::Option::Some(CosmosRequest_oneof_req::removeStorage(v)) => v,
-                _ => panic!(),
-            }
-        } else {
-            QueryRemoveStorage::new()
-        }
-    }
-
-    // .ffi.ffi.QueryBlockHash blockHash = 11;
-
-
-    pub fn get_blockHash(&self) -> &QueryBlockHash {
-        match self.req {
-            ::std::option::Option::Some(CosmosRequest_oneof_req::blockHash(ref v)) => v,
-            _ => QueryBlockHash::default_instance(),
-        }
-    }
-    pub fn clear_blockHash(&mut self) {
-        self.req = ::std::option::Option::None;
-    }
-
-    pub fn has_blockHash(&self) -> bool {
-        match self.req {
-            ::std::option::Option::Some(CosmosRequest_oneof_req::blockHash(..)) => true,
-            _ => false,
-        }
+    pub fn clear_storageCells(&mut self) {
+        self.storageCells.clear();
     }
 
     // Param is passed by value, moved
-    pub fn set_blockHash(&mut self, v: QueryBlockHash) {
-        self.req = ::std::option::Option::Some(CosmosRequest_oneof_req::blockHash(v))
+    pub fn set_storageCells(&mut self, v: ::protobuf::RepeatedField<QueryGetAccountStorageCell>) {
+        self.storageCells = v;
     }
 
     // Mutable pointer to the field.
-    pub fn mut_blockHash(&mut self) -> &mut QueryBlockHash {
-        if let ::std::option::Option::Some(CosmosRequest_oneof_req::blockHash(_)) = self.req {
-        } else {
-            self.req = ::std::option::Option::Some(CosmosRequest_oneof_req::blockHash(QueryBlockHash::new()));
-        }
-        match self.req {
-            ::std::option::Option::Some(CosmosRequest_oneof_req::blockHash(ref mut v)) => v,
-            _ => panic!(),
-        }
+    pub fn mut_storageCells(&mut self) -> &mut ::protobuf::RepeatedField<QueryGetAccountStorageCell> {
+        &mut self.storageCells
     }
 
     // Take field
-    pub fn take_blockHash(&mut self) -> QueryBlockHash {
-        if self.has_blockHash() {
-            match self.req.take() {
-                ::std::option::Option::Some(CosmosRequest_oneof_req::blockHash(v)) => v,
-                _ => panic!(),
-            }
-        } else {
-            QueryBlockHash::new()
-        }
+    pub fn take_storageCells(&mut self) -> ::protobuf::RepeatedField<QueryGetAccountStorageCell> {
+        ::std::mem::replace(&mut self.storageCells, ::protobuf::RepeatedField::new())
     }
 }
 
-impl ::protobuf::Message for CosmosRequest {
+impl ::protobuf::Message for QueryBatchRead {
     fn is_initialized(&self) -> bool {
-        if let Some(CosmosRequest_oneof_req::getAccount(ref v)) = self.req {
-            if !v.is_initialized() {
-                return false;
-            }
-        }
-        if let Some(CosmosRequest_oneof_req::insertAccount(ref v)) = self.req {
-            if !v.is_initialized() {
-                return false;
-            }
-        }
-        if let Some(CosmosRequest_oneof_req::containsKey(ref v)) = self.req {
-            if !v.is_initialized() {
-                return false;
-            }
-        }
-        if let Some(CosmosRequest_oneof_req::accountCode(ref v)) = self.req {
-            if !v.is_initialized() {
-                return false;
-            }
-        }
-        if let Some(CosmosRequest_oneof_req::storageCell(ref v)) = self.req {
-            if !v.is_initialized() {
-                return false;
-            }
-        }
-        if let Some(CosmosRequest_oneof_req::insertAccountCode(ref v)) = self.req {
-            if !v.is_initialized() {
-                return false;
-            }
-        }
-        if let Some(CosmosRequest_oneof_req::insertStorageCell(ref v)) = self.req {
-            if !v.is_initialized() {
-                return false;
-            }
-        }
-        if let Some(CosmosRequest_oneof_req::remove(ref v)) = self.req {
-            if !v.is_initialized() {
-                return false;
-            }
-        }
-        if let Some(CosmosRequest_oneof_req::removeStorageCell(ref v)) = self.req {
+        for v in &self.accounts {
             if !v.is_initialized() {
                 return false;
             }
-        }
-        if let Some(CosmosRequest_oneof_req::removeStorage(ref v)) = self.req {
+        };
+        for v in &self.codes {
             if !v.is_initialized() {
                 return false;
             }
-        }
-        if let Some(CosmosRequest_oneof_req::blockHash(ref v)) = self.req {
+        };
+        for v in &self.storageCells {
             if !v.is_initialized() {
                 return false;
             }
-        }
+        };
         true
     }
 
@@ -6391,70 +6942,13 @@ impl ::protobuf::Message for CosmosRequest {
             let (field_number, wire_type) = is.read_tag_unpack()?;
             match field_number {
                 1 => {
-                    if wire_type != ::protobuf::wire_format::WireTypeLengthDelimited {
-                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
-                    }
-                    self.req = ::std::option::Option::Some(CosmosRequest_oneof_req::getAccount(is.read_message()?));
+                    ::protobuf::rt::read_repeated_message_into(wire_type, is, &mut self.accounts)?;
                 },
                 2 => {
-                    if wire_type != ::protobuf::wire_format::WireTypeLengthDelimited {
-                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
-                    }
-                    self.req = ::std::option::Option::Some(CosmosRequest_oneof_req::insertAccount(is.read_message()?));
+                    ::protobuf::rt::read_repeated_message_into(wire_type, is, &mut self.codes)?;
                 },
                 3 => {
-                    if wire_type != ::protobuf::wire_format::WireTypeLengthDelimited {
-                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
-                    }
-                    self.req = ::std::option::Option::Some(CosmosRequest_oneof_req::containsKey(is.read_message()?));
-                },
-                4 => {
-                    if wire_type != ::protobuf::wire_format::WireTypeLengthDelimited {
-                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
-                    }
-                    self.req = ::std::option::Option::Some(CosmosRequest_oneof_req::accountCode(is.read_message()?));
-                },
-                5 => {
-                    if wire_type != ::protobuf::wire_format::WireTypeLengthDelimited {
-                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
-                    }
-                    self.req = ::std::option::Option::Some(CosmosRequest_oneof_req::storageCell(is.read_message()?));
-                },
-                6 => {
-                    if wire_type != ::protobuf::wire_format::WireTypeLengthDelimited {
-                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
-                    }
-                    self.req = ::std::option::Option::Some(CosmosRequest_oneof_req::insertAccountCode(is.read_message()?));
-                },
-                7 => {
-                    if wire_type != ::protobuf::wire_format::WireTypeLengthDelimited {
-                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
-                    }
-                    self.req = ::std::option::Option::Some(CosmosRequest_oneof_req::insertStorageCell(is.read_message()?));
-                },
-                8 => {
-                    if wire_type != ::protobuf::wire_format::WireTypeLengthDelimited {
-                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
-                    }
-                    self.req = ::std::option::Option::Some(CosmosRequest_oneof_req::remove(is.read_message()?));
-                },
-                9 => {
-                    if wire_type != ::protobuf::wire_format::WireTypeLengthDelimited {
-                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
-                    }
-                    self.req = ::std::option::Option::Some(CosmosRequest_oneof_req::removeStorageCell(is.read_message()?));
-                },
-                10 => {
-                    if wire_type != ::protobuf::wire_format::WireTypeLengthDelimited {
-                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
-                    }
-                    self.req = ::std::option::Option::Some(CosmosRequest_oneof_req::removeStorage(is.read_message()?));
-                },
-                11 => {
-                    if wire_type != ::protobuf::wire_format::WireTypeLengthDelimited {
-                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
-                    }
-                    self.req = ::std::option::Option::Some(CosmosRequest_oneof_req::blockHash(is.read_message()?));
+                    ::protobuf::rt::read_repeated_message_into(wire_type, is, &mut self.storageCells)?;
                 },
                 _ => {
                     ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
@@ -6468,119 +6962,9726 @@ impl ::protobuf::Message for CosmosRequest {
     #[allow(unused_variables)]
     fn compute_size(&self) -> u32 {
         let mut my_size = 0;
-        if let ::std::option::Option::Some(ref v) = self.req {
-            match v {
-                &CosmosRequest_oneof_req::getAccount(ref v) => {
-                    let len = v.compute_size();
-                    my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
-                },
-                &CosmosRequest_oneof_req::insertAccount(ref v) => {
-                    let len = v.compute_size();
-                    my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
-                },
-                &CosmosRequest_oneof_req::containsKey(ref v) => {
-                    let len = v.compute_size();
-                    my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
-                },
-                &CosmosRequest_oneof_req::accountCode(ref v) => {
-                    let len = v.compute_size();
-                    my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
-                },
-                &CosmosRequest_oneof_req::storageCell(ref v) => {
-                    let len = v.compute_size();
-                    my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
-                },
-                &CosmosRequest_oneof_req::insertAccountCode(ref v) => {
-                    let len = v.compute_size();
-                    my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
-                },
-                &CosmosRequest_oneof_req::insertStorageCell(ref v) => {
-                    let len = v.compute_size();
-                    my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
-                },
-                &CosmosRequest_oneof_req::remove(ref v) => {
-                    let len = v.compute_size();
-                    my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
-                },
-                &CosmosRequest_oneof_req::removeStorageCell(ref v) => {
-                    let len = v.compute_size();
-                    my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
-                },
-                &CosmosRequest_oneof_req::removeStorage(ref v) => {
-                    let len = v.compute_size();
-                    my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
-                },
-                &CosmosRequest_oneof_req::blockHash(ref v) => {
-                    let len = v.compute_size();
-                    my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
-                },
-            };
-        }
+        for value in &self.accounts {
+            let len = value.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        };
+        for value in &self.codes {
+            let len = value.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        };
+        for value in &self.storageCells {
+            let len = value.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        };
         my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
         self.cached_size.set(my_size);
         my_size
     }
 
     fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
-        if let ::std::option::Option::Some(ref v) = self.req {
-            match v {
-                &CosmosRequest_oneof_req::getAccount(ref v) => {
-                    os.write_tag(1, ::protobuf::wire_format::WireTypeLengthDelimited)?;
-                    os.write_raw_varint32(v.get_cached_size())?;
-                    v.write_to_with_cached_sizes(os)?;
-                },
-                &CosmosRequest_oneof_req::insertAccount(ref v) => {
-                    os.write_tag(2, ::protobuf::wire_format::WireTypeLengthDelimited)?;
-                    os.write_raw_varint32(v.get_cached_size())?;
-                    v.write_to_with_cached_sizes(os)?;
-                },
-                &CosmosRequest_oneof_req::containsKey(ref v) => {
+        for v in &self.accounts {
+            os.write_tag(1, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        };
+        for v in &self.codes {
+            os.write_tag(2, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        };
+        for v in &self.storageCells {
+            os.write_tag(3, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        };
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> QueryBatchRead {
+        QueryBatchRead::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const ::protobuf::reflect::MessageDescriptor,
+        };
+        unsafe {
+            descriptor.get(|| {
+                let mut fields = ::std::vec::Vec::new();
+                fields.push(::protobuf::reflect::accessor::make_repeated_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<QueryGetAccount>>(
+                    "accounts",
+                    |m: &QueryBatchRead| { &m.accounts },
+                    |m: &mut QueryBatchRead| { &mut m.accounts },
+                ));
+                fields.push(::protobuf::reflect::accessor::make_repeated_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<QueryGetAccountCode>>(
+                    "codes",
+                    |m: &QueryBatchRead| { &m.codes },
+                    |m: &mut QueryBatchRead| { &mut m.codes },
+                ));
+                fields.push(::protobuf::reflect::accessor::make_repeated_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<QueryGetAccountStorageCell>>(
+                    "storageCells",
+                    |m: &QueryBatchRead| { &m.storageCells },
+                    |m: &mut QueryBatchRead| { &mut m.storageCells },
+                ));
+                ::protobuf::reflect::MessageDescriptor::new::<QueryBatchRead>(
+                    "QueryBatchRead",
+                    fields,
+                    file_descriptor_proto()
+                )
+            })
+        }
+    }
+
+    fn default_instance() -> &'static QueryBatchRead {
+        static mut instance: ::protobuf::lazy::Lazy<QueryBatchRead> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const QueryBatchRead,
+        };
+        unsafe {
+            instance.get(QueryBatchRead::new)
+        }
+    }
+}
+
+impl ::protobuf::Clear for QueryBatchRead {
+    fn clear(&mut self) {
+        self.accounts.clear();
+        self.codes.clear();
+        self.storageCells.clear();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for QueryBatchRead {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for QueryBatchRead {
+    fn as_ref(&self) -> ::protobuf::reflect::ProtobufValueRef {
+        ::protobuf::reflect::ProtobufValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct QueryBatchReadResponse {
+    // message fields
+    pub accounts: ::protobuf::RepeatedField<QueryGetAccountResponse>,
+    pub codes: ::protobuf::RepeatedField<QueryGetAccountCodeResponse>,
+    pub storageCells: ::protobuf::RepeatedField<QueryGetAccountStorageCellResponse>,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a QueryBatchReadResponse {
+    fn default() -> &'a QueryBatchReadResponse {
+        <QueryBatchReadResponse as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl QueryBatchReadResponse {
+    pub fn new() -> QueryBatchReadResponse {
+        ::std::default::Default::default()
+    }
+
+    // repeated .ffi.ffi.QueryGetAccountResponse accounts = 1;
+
+
+    pub fn get_accounts(&self) -> &[QueryGetAccountResponse] {
+        &self.accounts
+    }
+    pub fn clear_accounts(&mut self) {
+        self.accounts.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_accounts(&mut self, v: ::protobuf::RepeatedField<QueryGetAccountResponse>) {
+        self.accounts = v;
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_accounts(&mut self) -> &mut ::protobuf::RepeatedField<QueryGetAccountResponse> {
+        &mut self.accounts
+    }
+
+    // Take field
+    pub fn take_accounts(&mut self) -> ::protobuf::RepeatedField<QueryGetAccountResponse> {
+        ::std::mem::replace(&mut self.accounts, ::protobuf::RepeatedField::new())
+    }
+
+    // repeated .ffi.ffi.QueryGetAccountCodeResponse codes = 2;
+
+
+    pub fn get_codes(&self) -> &[QueryGetAccountCodeResponse] {
+        &self.codes
+    }
+    pub fn clear_codes(&mut self) {
+        self.codes.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_codes(&mut self, v: ::protobuf::RepeatedField<QueryGetAccountCodeResponse>) {
+        self.codes = v;
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_codes(&mut self) -> &mut ::protobuf::RepeatedField<QueryGetAccountCodeResponse> {
+        &mut self.codes
+    }
+
+    // Take field
+    pub fn take_codes(&mut self) -> ::protobuf::RepeatedField<QueryGetAccountCodeResponse> {
+        ::std::mem::replace(&mut self.codes, ::protobuf::RepeatedField::new())
+    }
+
+    // repeated .ffi.ffi.QueryGetAccountStorageCellResponse storageCells = 3;
+
+
+    pub fn get_storageCells(&self) -> &[QueryGetAccountStorageCellResponse] {
+        &self.storageCells
+    }
+    pub fn clear_storageCells(&mut self) {
+        self.storageCells.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_storageCells(&mut self, v: ::protobuf::RepeatedField<QueryGetAccountStorageCellResponse>) {
+        self.storageCells = v;
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_storageCells(&mut self) -> &mut ::protobuf::RepeatedField<QueryGetAccountStorageCellResponse> {
+        &mut self.storageCells
+    }
+
+    // Take field
+    pub fn take_storageCells(&mut self) -> ::protobuf::RepeatedField<QueryGetAccountStorageCellResponse> {
+        ::std::mem::replace(&mut self.storageCells, ::protobuf::RepeatedField::new())
+    }
+}
+
+impl ::protobuf::Message for QueryBatchReadResponse {
+    fn is_initialized(&self) -> bool {
+        for v in &self.accounts {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        for v in &self.codes {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        for v in &self.storageCells {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_repeated_message_into(wire_type, is, &mut self.accounts)?;
+                },
+                2 => {
+                    ::protobuf::rt::read_repeated_message_into(wire_type, is, &mut self.codes)?;
+                },
+                3 => {
+                    ::protobuf::rt::read_repeated_message_into(wire_type, is, &mut self.storageCells)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        for value in &self.accounts {
+            let len = value.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        };
+        for value in &self.codes {
+            let len = value.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        };
+        for value in &self.storageCells {
+            let len = value.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        };
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        for v in &self.accounts {
+            os.write_tag(1, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        };
+        for v in &self.codes {
+            os.write_tag(2, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        };
+        for v in &self.storageCells {
+            os.write_tag(3, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        };
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> QueryBatchReadResponse {
+        QueryBatchReadResponse::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const ::protobuf::reflect::MessageDescriptor,
+        };
+        unsafe {
+            descriptor.get(|| {
+                let mut fields = ::std::vec::Vec::new();
+                fields.push(::protobuf::reflect::accessor::make_repeated_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<QueryGetAccountResponse>>(
+                    "accounts",
+                    |m: &QueryBatchReadResponse| { &m.accounts },
+                    |m: &mut QueryBatchReadResponse| { &mut m.accounts },
+                ));
+                fields.push(::protobuf::reflect::accessor::make_repeated_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<QueryGetAccountCodeResponse>>(
+                    "codes",
+                    |m: &QueryBatchReadResponse| { &m.codes },
+                    |m: &mut QueryBatchReadResponse| { &mut m.codes },
+                ));
+                fields.push(::protobuf::reflect::accessor::make_repeated_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<QueryGetAccountStorageCellResponse>>(
+                    "storageCells",
+                    |m: &QueryBatchReadResponse| { &m.storageCells },
+                    |m: &mut QueryBatchReadResponse| { &mut m.storageCells },
+                ));
+                ::protobuf::reflect::MessageDescriptor::new::<QueryBatchReadResponse>(
+                    "QueryBatchReadResponse",
+                    fields,
+                    file_descriptor_proto()
+                )
+            })
+        }
+    }
+
+    fn default_instance() -> &'static QueryBatchReadResponse {
+        static mut instance: ::protobuf::lazy::Lazy<QueryBatchReadResponse> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const QueryBatchReadResponse,
+        };
+        unsafe {
+            instance.get(QueryBatchReadResponse::new)
+        }
+    }
+}
+
+impl ::protobuf::Clear for QueryBatchReadResponse {
+    fn clear(&mut self) {
+        self.accounts.clear();
+        self.codes.clear();
+        self.storageCells.clear();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for QueryBatchReadResponse {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for QueryBatchReadResponse {
+    fn as_ref(&self) -> ::protobuf::reflect::ProtobufValueRef {
+        ::protobuf::reflect::ProtobufValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct QueryCommit {
+    // message fields
+    pub removed: ::protobuf::RepeatedField<QueryRemove>,
+    pub resetStorage: ::protobuf::RepeatedField<QueryRemoveStorage>,
+    pub accounts: ::protobuf::RepeatedField<QueryInsertAccount>,
+    pub codes: ::protobuf::RepeatedField<QueryInsertAccountCode>,
+    pub storageCells: ::protobuf::RepeatedField<QueryInsertStorageCell>,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a QueryCommit {
+    fn default() -> &'a QueryCommit {
+        <QueryCommit as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl QueryCommit {
+    pub fn new() -> QueryCommit {
+        ::std::default::Default::default()
+    }
+
+    // repeated .ffi.ffi.QueryRemove removed = 1;
+
+
+    pub fn get_removed(&self) -> &[QueryRemove] {
+        &self.removed
+    }
+    pub fn clear_removed(&mut self) {
+        self.removed.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_removed(&mut self, v: ::protobuf::RepeatedField<QueryRemove>) {
+        self.removed = v;
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_removed(&mut self) -> &mut ::protobuf::RepeatedField<QueryRemove> {
+        &mut self.removed
+    }
+
+    // Take field
+    pub fn take_removed(&mut self) -> ::protobuf::RepeatedField<QueryRemove> {
+        ::std::mem::replace(&mut self.removed, ::protobuf::RepeatedField::new())
+    }
+
+    // repeated .ffi.ffi.QueryRemoveStorage resetStorage = 2;
+
+
+    pub fn get_resetStorage(&self) -> &[QueryRemoveStorage] {
+        &self.resetStorage
+    }
+    pub fn clear_resetStorage(&mut self) {
+        self.resetStorage.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_resetStorage(&mut self, v: ::protobuf::RepeatedField<QueryRemoveStorage>) {
+        self.resetStorage = v;
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_resetStorage(&mut self) -> &mut ::protobuf::RepeatedField<QueryRemoveStorage> {
+        &mut self.resetStorage
+    }
+
+    // Take field
+    pub fn take_resetStorage(&mut self) -> ::protobuf::RepeatedField<QueryRemoveStorage> {
+        ::std::mem::replace(&mut self.resetStorage, ::protobuf::RepeatedField::new())
+    }
+
+    // repeated .ffi.ffi.QueryInsertAccount accounts = 3;
+
+
+    pub fn get_accounts(&self) -> &[QueryInsertAccount] {
+        &self.accounts
+    }
+    pub fn clear_accounts(&mut self) {
+        self.accounts.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_accounts(&mut self, v: ::protobuf::RepeatedField<QueryInsertAccount>) {
+        self.accounts = v;
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_accounts(&mut self) -> &mut ::protobuf::RepeatedField<QueryInsertAccount> {
+        &mut self.accounts
+    }
+
+    // Take field
+    pub fn take_accounts(&mut self) -> ::protobuf::RepeatedField<QueryInsertAccount> {
+        ::std::mem::replace(&mut self.accounts, ::protobuf::RepeatedField::new())
+    }
+
+    // repeated .ffi.ffi.QueryInsertAccountCode codes = 4;
+
+
+    pub fn get_codes(&self) -> &[QueryInsertAccountCode] {
+        &self.codes
+    }
+    pub fn clear_codes(&mut self) {
+        self.codes.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_codes(&mut self, v: ::protobuf::RepeatedField<QueryInsertAccountCode>) {
+        self.codes = v;
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_codes(&mut self) -> &mut ::protobuf::RepeatedField<QueryInsertAccountCode> {
+        &mut self.codes
+    }
+
+    // Take field
+    pub fn take_codes(&mut self) -> ::protobuf::RepeatedField<QueryInsertAccountCode> {
+        ::std::mem::replace(&mut self.codes, ::protobuf::RepeatedField::new())
+    }
+
+    // repeated .ffi.ffi.QueryInsertStorageCell storageCells = 5;
+
+
+    pub fn get_storageCells(&self) -> &[QueryInsertStorageCell] {
+        &self.storageCells
+    }
+    pub fn clear_storageCells(&mut self) {
+        self.storageCells.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_storageCells(&mut self, v: ::protobuf::RepeatedField<QueryInsertStorageCell>) {
+        self.storageCells = v;
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_storageCells(&mut self) -> &mut ::protobuf::RepeatedField<QueryInsertStorageCell> {
+        &mut self.storageCells
+    }
+
+    // Take field
+    pub fn take_storageCells(&mut self) -> ::protobuf::RepeatedField<QueryInsertStorageCell> {
+        ::std::mem::replace(&mut self.storageCells, ::protobuf::RepeatedField::new())
+    }
+}
+
+impl ::protobuf::Message for QueryCommit {
+    fn is_initialized(&self) -> bool {
+        for v in &self.removed {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        for v in &self.resetStorage {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        for v in &self.accounts {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        for v in &self.codes {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        for v in &self.storageCells {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_repeated_message_into(wire_type, is, &mut self.removed)?;
+                },
+                2 => {
+                    ::protobuf::rt::read_repeated_message_into(wire_type, is, &mut self.resetStorage)?;
+                },
+                3 => {
+                    ::protobuf::rt::read_repeated_message_into(wire_type, is, &mut self.accounts)?;
+                },
+                4 => {
+                    ::protobuf::rt::read_repeated_message_into(wire_type, is, &mut self.codes)?;
+                },
+                5 => {
+                    ::protobuf::rt::read_repeated_message_into(wire_type, is, &mut self.storageCells)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        for value in &self.removed {
+            let len = value.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        };
+        for value in &self.resetStorage {
+            let len = value.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        };
+        for value in &self.accounts {
+            let len = value.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        };
+        for value in &self.codes {
+            let len = value.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        };
+        for value in &self.storageCells {
+            let len = value.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        };
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        for v in &self.removed {
+            os.write_tag(1, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        };
+        for v in &self.resetStorage {
+            os.write_tag(2, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        };
+        for v in &self.accounts {
+            os.write_tag(3, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        };
+        for v in &self.codes {
+            os.write_tag(4, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        };
+        for v in &self.storageCells {
+            os.write_tag(5, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        };
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> QueryCommit {
+        QueryCommit::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const ::protobuf::reflect::MessageDescriptor,
+        };
+        unsafe {
+            descriptor.get(|| {
+                let mut fields = ::std::vec::Vec::new();
+                fields.push(::protobuf::reflect::accessor::make_repeated_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<QueryRemove>>(
+                    "removed",
+                    |m: &QueryCommit| { &m.removed },
+                    |m: &mut QueryCommit| { &mut m.removed },
+                ));
+                fields.push(::protobuf::reflect::accessor::make_repeated_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<QueryRemoveStorage>>(
+                    "resetStorage",
+                    |m: &QueryCommit| { &m.resetStorage },
+                    |m: &mut QueryCommit| { &mut m.resetStorage },
+                ));
+                fields.push(::protobuf::reflect::accessor::make_repeated_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<QueryInsertAccount>>(
+                    "accounts",
+                    |m: &QueryCommit| { &m.accounts },
+                    |m: &mut QueryCommit| { &mut m.accounts },
+                ));
+                fields.push(::protobuf::reflect::accessor::make_repeated_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<QueryInsertAccountCode>>(
+                    "codes",
+                    |m: &QueryCommit| { &m.codes },
+                    |m: &mut QueryCommit| { &mut m.codes },
+                ));
+                fields.push(::protobuf::reflect::accessor::make_repeated_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<QueryInsertStorageCell>>(
+                    "storageCells",
+                    |m: &QueryCommit| { &m.storageCells },
+                    |m: &mut QueryCommit| { &mut m.storageCells },
+                ));
+                ::protobuf::reflect::MessageDescriptor::new::<QueryCommit>(
+                    "QueryCommit",
+                    fields,
+                    file_descriptor_proto()
+                )
+            })
+        }
+    }
+
+    fn default_instance() -> &'static QueryCommit {
+        static mut instance: ::protobuf::lazy::Lazy<QueryCommit> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const QueryCommit,
+        };
+        unsafe {
+            instance.get(QueryCommit::new)
+        }
+    }
+}
+
+impl ::protobuf::Clear for QueryCommit {
+    fn clear(&mut self) {
+        self.removed.clear();
+        self.resetStorage.clear();
+        self.accounts.clear();
+        self.codes.clear();
+        self.storageCells.clear();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for QueryCommit {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for QueryCommit {
+    fn as_ref(&self) -> ::protobuf::reflect::ProtobufValueRef {
+        ::protobuf::reflect::ProtobufValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct QueryCommitResponse {
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a QueryCommitResponse {
+    fn default() -> &'a QueryCommitResponse {
+        <QueryCommitResponse as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl QueryCommitResponse {
+    pub fn new() -> QueryCommitResponse {
+        ::std::default::Default::default()
+    }
+}
+
+impl ::protobuf::Message for QueryCommitResponse {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> QueryCommitResponse {
+        QueryCommitResponse::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const ::protobuf::reflect::MessageDescriptor,
+        };
+        unsafe {
+            descriptor.get(|| {
+                let fields = ::std::vec::Vec::new();
+                ::protobuf::reflect::MessageDescriptor::new::<QueryCommitResponse>(
+                    "QueryCommitResponse",
+                    fields,
+                    file_descriptor_proto()
+                )
+            })
+        }
+    }
+
+    fn default_instance() -> &'static QueryCommitResponse {
+        static mut instance: ::protobuf::lazy::Lazy<QueryCommitResponse> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const QueryCommitResponse,
+        };
+        unsafe {
+            instance.get(QueryCommitResponse::new)
+        }
+    }
+}
+
+impl ::protobuf::Clear for QueryCommitResponse {
+    fn clear(&mut self) {
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for QueryCommitResponse {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for QueryCommitResponse {
+    fn as_ref(&self) -> ::protobuf::reflect::ProtobufValueRef {
+        ::protobuf::reflect::ProtobufValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct QueryBankBalance {
+    // message fields
+    pub address: ::std::vec::Vec<u8>,
+    pub denom: ::std::string::String,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a QueryBankBalance {
+    fn default() -> &'a QueryBankBalance {
+        <QueryBankBalance as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl QueryBankBalance {
+    pub fn new() -> QueryBankBalance {
+        ::std::default::Default::default()
+    }
+
+    // bytes address = 1;
+
+
+    pub fn get_address(&self) -> &[u8] {
+        &self.address
+    }
+    pub fn clear_address(&mut self) {
+        self.address.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_address(&mut self, v: ::std::vec::Vec<u8>) {
+        self.address = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_address(&mut self) -> &mut ::std::vec::Vec<u8> {
+        &mut self.address
+    }
+
+    // Take field
+    pub fn take_address(&mut self) -> ::std::vec::Vec<u8> {
+        ::std::mem::replace(&mut self.address, ::std::vec::Vec::new())
+    }
+
+    // string denom = 2;
+
+
+    pub fn get_denom(&self) -> &str {
+        &self.denom
+    }
+    pub fn clear_denom(&mut self) {
+        self.denom.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_denom(&mut self, v: ::std::string::String) {
+        self.denom = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_denom(&mut self) -> &mut ::std::string::String {
+        &mut self.denom
+    }
+
+    // Take field
+    pub fn take_denom(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.denom, ::std::string::String::new())
+    }
+}
+
+impl ::protobuf::Message for QueryBankBalance {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_singular_proto3_bytes_into(wire_type, is, &mut self.address)?;
+                },
+                2 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.denom)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if !self.address.is_empty() {
+            my_size += ::protobuf::rt::bytes_size(1, &self.address);
+        }
+        if !self.denom.is_empty() {
+            my_size += ::protobuf::rt::string_size(2, &self.denom);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if !self.address.is_empty() {
+            os.write_bytes(1, &self.address)?;
+        }
+        if !self.denom.is_empty() {
+            os.write_string(2, &self.denom)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> QueryBankBalance {
+        QueryBankBalance::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const ::protobuf::reflect::MessageDescriptor,
+        };
+        unsafe {
+            descriptor.get(|| {
+                let mut fields = ::std::vec::Vec::new();
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeBytes>(
+                    "address",
+                    |m: &QueryBankBalance| { &m.address },
+                    |m: &mut QueryBankBalance| { &mut m.address },
+                ));
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                    "denom",
+                    |m: &QueryBankBalance| { &m.denom },
+                    |m: &mut QueryBankBalance| { &mut m.denom },
+                ));
+                ::protobuf::reflect::MessageDescriptor::new::<QueryBankBalance>(
+                    "QueryBankBalance",
+                    fields,
+                    file_descriptor_proto()
+                )
+            })
+        }
+    }
+
+    fn default_instance() -> &'static QueryBankBalance {
+        static mut instance: ::protobuf::lazy::Lazy<QueryBankBalance> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const QueryBankBalance,
+        };
+        unsafe {
+            instance.get(QueryBankBalance::new)
+        }
+    }
+}
+
+impl ::protobuf::Clear for QueryBankBalance {
+    fn clear(&mut self) {
+        self.address.clear();
+        self.denom.clear();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for QueryBankBalance {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for QueryBankBalance {
+    fn as_ref(&self) -> ::protobuf::reflect::ProtobufValueRef {
+        ::protobuf::reflect::ProtobufValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct QueryBankBalanceResponse {
+    // message fields
+    pub balance: ::std::vec::Vec<u8>,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a QueryBankBalanceResponse {
+    fn default() -> &'a QueryBankBalanceResponse {
+        <QueryBankBalanceResponse as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl QueryBankBalanceResponse {
+    pub fn new() -> QueryBankBalanceResponse {
+        ::std::default::Default::default()
+    }
+
+    // bytes balance = 1;
+
+
+    pub fn get_balance(&self) -> &[u8] {
+        &self.balance
+    }
+    pub fn clear_balance(&mut self) {
+        self.balance.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_balance(&mut self, v: ::std::vec::Vec<u8>) {
+        self.balance = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_balance(&mut self) -> &mut ::std::vec::Vec<u8> {
+        &mut self.balance
+    }
+
+    // Take field
+    pub fn take_balance(&mut self) -> ::std::vec::Vec<u8> {
+        ::std::mem::replace(&mut self.balance, ::std::vec::Vec::new())
+    }
+}
+
+impl ::protobuf::Message for QueryBankBalanceResponse {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_singular_proto3_bytes_into(wire_type, is, &mut self.balance)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if !self.balance.is_empty() {
+            my_size += ::protobuf::rt::bytes_size(1, &self.balance);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if !self.balance.is_empty() {
+            os.write_bytes(1, &self.balance)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> QueryBankBalanceResponse {
+        QueryBankBalanceResponse::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const ::protobuf::reflect::MessageDescriptor,
+        };
+        unsafe {
+            descriptor.get(|| {
+                let mut fields = ::std::vec::Vec::new();
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeBytes>(
+                    "balance",
+                    |m: &QueryBankBalanceResponse| { &m.balance },
+                    |m: &mut QueryBankBalanceResponse| { &mut m.balance },
+                ));
+                ::protobuf::reflect::MessageDescriptor::new::<QueryBankBalanceResponse>(
+                    "QueryBankBalanceResponse",
+                    fields,
+                    file_descriptor_proto()
+                )
+            })
+        }
+    }
+
+    fn default_instance() -> &'static QueryBankBalanceResponse {
+        static mut instance: ::protobuf::lazy::Lazy<QueryBankBalanceResponse> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const QueryBankBalanceResponse,
+        };
+        unsafe {
+            instance.get(QueryBankBalanceResponse::new)
+        }
+    }
+}
+
+impl ::protobuf::Clear for QueryBankBalanceResponse {
+    fn clear(&mut self) {
+        self.balance.clear();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for QueryBankBalanceResponse {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for QueryBankBalanceResponse {
+    fn as_ref(&self) -> ::protobuf::reflect::ProtobufValueRef {
+        ::protobuf::reflect::ProtobufValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct CosmosRequest {
+    // message oneof groups
+    pub req: ::std::option::Option<CosmosRequest_oneof_req>,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a CosmosRequest {
+    fn default() -> &'a CosmosRequest {
+        <CosmosRequest as ::protobuf::Message>::default_instance()
+    }
+}
+
+#[derive(Clone,PartialEq,Debug)]
+pub enum CosmosRequest_oneof_req {
+    getAccount(QueryGetAccount),
+    insertAccount(QueryInsertAccount),
+    containsKey(QueryContainsKey),
+    accountCode(QueryGetAccountCode),
+    storageCell(QueryGetAccountStorageCell),
+    insertAccountCode(QueryInsertAccountCode),
+    insertStorageCell(QueryInsertStorageCell),
+    remove(QueryRemove),
+    removeStorageCell(QueryRemoveStorageCell),
+    removeStorage(QueryRemoveStorage),
+    blockHash(QueryBlockHash),
+    batchRead(QueryBatchRead),
+    commit(QueryCommit),
+    bankBalance(QueryBankBalance),
+}
+
+impl CosmosRequest {
+    pub fn new() -> CosmosRequest {
+        ::std::default::Default::default()
+    }
+
+    // .ffi.ffi.QueryGetAccount getAccount = 1;
+
+
+    pub fn get_getAccount(&self) -> &QueryGetAccount {
+        match self.req {
+            ::std::option::Option::Some(CosmosRequest_oneof_req::getAccount(ref v)) => v,
+            _ => QueryGetAccount::default_instance(),
+        }
+    }
+    pub fn clear_getAccount(&mut self) {
+        self.req = ::std::option::Option::None;
+    }
+
+    pub fn has_getAccount(&self) -> bool {
+        match self.req {
+            ::std::option::Option::Some(CosmosRequest_oneof_req::getAccount(..)) => true,
+            _ => false,
+        }
+    }
+
+    // Param is passed by value, moved
+    pub fn set_getAccount(&mut self, v: QueryGetAccount) {
+        self.req = ::std::option::Option::Some(CosmosRequest_oneof_req::getAccount(v))
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_getAccount(&mut self) -> &mut QueryGetAccount {
+        if let ::std::option::Option::Some(CosmosRequest_oneof_req::getAccount(_)) = self.req {
+        } else {
+            self.req = ::std::option::Option::Some(CosmosRequest_oneof_req::getAccount(QueryGetAccount::new()));
+        }
+        match self.req {
+            ::std::option::Option::Some(CosmosRequest_oneof_req::getAccount(ref mut v)) => v,
+            _ => panic!(),
+        }
+    }
+
+    // Take field
+    pub fn take_getAccount(&mut self) -> QueryGetAccount {
+        if self.has_getAccount() {
+            match self.req.take() {
+                ::std::option::Option::Some(CosmosRequest_oneof_req::getAccount(v)) => v,
+                _ => panic!(),
+            }
+        } else {
+            QueryGetAccount::new()
+        }
+    }
+
+    // .ffi.ffi.QueryInsertAccount insertAccount = 2;
+
+
+    pub fn get_insertAccount(&self) -> &QueryInsertAccount {
+        match self.req {
+            ::std::option::Option::Some(CosmosRequest_oneof_req::insertAccount(ref v)) => v,
+            _ => QueryInsertAccount::default_instance(),
+        }
+    }
+    pub fn clear_insertAccount(&mut self) {
+        self.req = ::std::option::Option::None;
+    }
+
+    pub fn has_insertAccount(&self) -> bool {
+        match self.req {
+            ::std::option::Option::Some(CosmosRequest_oneof_req::insertAccount(..)) => true,
+            _ => false,
+        }
+    }
+
+    // Param is passed by value, moved
+    pub fn set_insertAccount(&mut self, v: QueryInsertAccount) {
+        self.req = ::std::option::Option::Some(CosmosRequest_oneof_req::insertAccount(v))
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_insertAccount(&mut self) -> &mut QueryInsertAccount {
+        if let ::std::option::Option::Some(CosmosRequest_oneof_req::insertAccount(_)) = self.req {
+        } else {
+            self.req = ::std::option::Option::Some(CosmosRequest_oneof_req::insertAccount(QueryInsertAccount::new()));
+        }
+        match self.req {
+            ::std::option::Option::Some(CosmosRequest_oneof_req::insertAccount(ref mut v)) => v,
+            _ => panic!(),
+        }
+    }
+
+    // Take field
+    pub fn take_insertAccount(&mut self) -> QueryInsertAccount {
+        if self.has_insertAccount() {
+            match self.req.take() {
+                ::std::option::Option::Some(CosmosRequest_oneof_req::insertAccount(v)) => v,
+                _ => panic!(),
+            }
+        } else {
+            QueryInsertAccount::new()
+        }
+    }
+
+    // .ffi.ffi.QueryContainsKey containsKey = 3;
+
+
+    pub fn get_containsKey(&self) -> &QueryContainsKey {
+        match self.req {
+            ::std::option::Option::Some(CosmosRequest_oneof_req::containsKey(ref v)) => v,
+            _ => QueryContainsKey::default_instance(),
+        }
+    }
+    pub fn clear_containsKey(&mut self) {
+        self.req = ::std::option::Option::None;
+    }
+
+    pub fn has_containsKey(&self) -> bool {
+        match self.req {
+            ::std::option::Option::Some(CosmosRequest_oneof_req::containsKey(..)) => true,
+            _ => false,
+        }
+    }
+
+    // Param is passed by value, moved
+    pub fn set_containsKey(&mut self, v: QueryContainsKey) {
+        self.req = ::std::option::Option::Some(CosmosRequest_oneof_req::containsKey(v))
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_containsKey(&mut self) -> &mut QueryContainsKey {
+        if let ::std::option::Option::Some(CosmosRequest_oneof_req::containsKey(_)) = self.req {
+        } else {
+            self.req = ::std::option::Option::Some(CosmosRequest_oneof_req::containsKey(QueryContainsKey::new()));
+        }
+        match self.req {
+            ::std::option::Option::Some(CosmosRequest_oneof_req::containsKey(ref mut v)) => v,
+            _ => panic!(),
+        }
+    }
+
+    // Take field
+    pub fn take_containsKey(&mut self) -> QueryContainsKey {
+        if self.has_containsKey() {
+            match self.req.take() {
+                ::std::option::Option::Some(CosmosRequest_oneof_req::containsKey(v)) => v,
+                _ => panic!(),
+            }
+        } else {
+            QueryContainsKey::new()
+        }
+    }
+
+    // .ffi.ffi.QueryGetAccountCode accountCode = 4;
+
+
+    pub fn get_accountCode(&self) -> &QueryGetAccountCode {
+        match self.req {
+            ::std::option::Option::Some(CosmosRequest_oneof_req::accountCode(ref v)) => v,
+            _ => QueryGetAccountCode::default_instance(),
+        }
+    }
+    pub fn clear_accountCode(&mut self) {
+        self.req = ::std::option::Option::None;
+    }
+
+    pub fn has_accountCode(&self) -> bool {
+        match self.req {
+            ::std::option::Option::Some(CosmosRequest_oneof_req::accountCode(..)) => true,
+            _ => false,
+        }
+    }
+
+    // Param is passed by value, moved
+    pub fn set_accountCode(&mut self, v: QueryGetAccountCode) {
+        self.req = ::std::option::Option::Some(CosmosRequest_oneof_req::accountCode(v))
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_accountCode(&mut self) -> &mut QueryGetAccountCode {
+        if let ::std::option::Option::Some(CosmosRequest_oneof_req::accountCode(_)) = self.req {
+        } else {
+            self.req = ::std::option::Option::Some(CosmosRequest_oneof_req::accountCode(QueryGetAccountCode::new()));
+        }
+        match self.req {
+            ::std::option::Option::Some(CosmosRequest_oneof_req::accountCode(ref mut v)) => v,
+            _ => panic!(),
+        }
+    }
+
+    // Take field
+    pub fn take_accountCode(&mut self) -> QueryGetAccountCode {
+        if self.has_accountCode() {
+            match self.req.take() {
+                ::std::option::Option::Some(CosmosRequest_oneof_req::accountCode(v)) => v,
+                _ => panic!(),
+            }
+        } else {
+            QueryGetAccountCode::new()
+        }
+    }
+
+    // .ffi.ffi.QueryGetAccountStorageCell storageCell = 5;
+
+
+    pub fn get_storageCell(&self) -> &QueryGetAccountStorageCell {
+        match self.req {
+            ::std::option::Option::Some(CosmosRequest_oneof_req::storageCell(ref v)) => v,
+            _ => QueryGetAccountStorageCell::default_instance(),
+        }
+    }
+    pub fn clear_storageCell(&mut self) {
+        self.req = ::std::option::Option::None;
+    }
+
+    pub fn has_storageCell(&self) -> bool {
+        match self.req {
+            ::std::option::Option::Some(CosmosRequest_oneof_req::storageCell(..)) => true,
+            _ => false,
+        }
+    }
+
+    // Param is passed by value, moved
+    pub fn set_storageCell(&mut self, v: QueryGetAccountStorageCell) {
+        self.req = ::std::option::Option::Some(CosmosRequest_oneof_req::storageCell(v))
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_storageCell(&mut self) -> &mut QueryGetAccountStorageCell {
+        if let ::std::option::Option::Some(CosmosRequest_oneof_req::storageCell(_)) = self.req {
+        } else {
+            self.req = ::std::option::Option::Some(CosmosRequest_oneof_req::storageCell(QueryGetAccountStorageCell::new()));
+        }
+        match self.req {
+            ::std::option::Option::Some(CosmosRequest_oneof_req::storageCell(ref mut v)) => v,
+            _ => panic!(),
+        }
+    }
+
+    // Take field
+    pub fn take_storageCell(&mut self) -> QueryGetAccountStorageCell {
+        if self.has_storageCell() {
+            match self.req.take() {
+                ::std::option::Option::Some(CosmosRequest_oneof_req::storageCell(v)) => v,
+                _ => panic!(),
+            }
+        } else {
+            QueryGetAccountStorageCell::new()
+        }
+    }
+
+    // .ffi.ffi.QueryInsertAccountCode insertAccountCode = 6;
+
+
+    pub fn get_insertAccountCode(&self) -> &QueryInsertAccountCode {
+        match self.req {
+            ::std::option::Option::Some(CosmosRequest_oneof_req::insertAccountCode(ref v)) => v,
+            _ => QueryInsertAccountCode::default_instance(),
+        }
+    }
+    pub fn clear_insertAccountCode(&mut self) {
+        self.req = ::std::option::Option::None;
+    }
+
+    pub fn has_insertAccountCode(&self) -> bool {
+        match self.req {
+            ::std::option::Option::Some(CosmosRequest_oneof_req::insertAccountCode(..)) => true,
+            _ => false,
+        }
+    }
+
+    // Param is passed by value, moved
+    pub fn set_insertAccountCode(&mut self, v: QueryInsertAccountCode) {
+        self.req = ::std::option::Option::Some(CosmosRequest_oneof_req::insertAccountCode(v))
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_insertAccountCode(&mut self) -> &mut QueryInsertAccountCode {
+        if let ::std::option::Option::Some(CosmosRequest_oneof_req::insertAccountCode(_)) = self.req {
+        } else {
+            self.req = ::std::option::Option::Some(CosmosRequest_oneof_req::insertAccountCode(QueryInsertAccountCode::new()));
+        }
+        match self.req {
+            ::std::option::Option::Some(CosmosRequest_oneof_req::insertAccountCode(ref mut v)) => v,
+            _ => panic!(),
+        }
+    }
+
+    // Take field
+    pub fn take_insertAccountCode(&mut self) -> QueryInsertAccountCode {
+        if self.has_insertAccountCode() {
+            match self.req.take() {
+                ::std::option::Option::Some(CosmosRequest_oneof_req::insertAccountCode(v)) => v,
+                _ => panic!(),
+            }
+        } else {
+            QueryInsertAccountCode::new()
+        }
+    }
+
+    // .ffi.ffi.QueryInsertStorageCell insertStorageCell = 7;
+
+
+    pub fn get_insertStorageCell(&self) -> &QueryInsertStorageCell {
+        match self.req {
+            ::std::option::Option::Some(CosmosRequest_oneof_req::insertStorageCell(ref v)) => v,
+            _ => QueryInsertStorageCell::default_instance(),
+        }
+    }
+    pub fn clear_insertStorageCell(&mut self) {
+        self.req = ::std::option::Option::None;
+    }
+
+    pub fn has_insertStorageCell(&self) -> bool {
+        match self.req {
+            ::std::option::Option::Some(CosmosRequest_oneof_req::insertStorageCell(..)) => true,
+            _ => false,
+        }
+    }
+
+    // Param is passed by value, moved
+    pub fn set_insertStorageCell(&mut self, v: QueryInsertStorageCell) {
+        self.req = ::std::option::Option::Some(CosmosRequest_oneof_req::insertStorageCell(v))
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_insertStorageCell(&mut self) -> &mut QueryInsertStorageCell {
+        if let ::std::option::Option::Some(CosmosRequest_oneof_req::insertStorageCell(_)) = self.req {
+        } else {
+            self.req = ::std::option::Option::Some(CosmosRequest_oneof_req::insertStorageCell(QueryInsertStorageCell::new()));
+        }
+        match self.req {
+            ::std::option::Option::Some(CosmosRequest_oneof_req::insertStorageCell(ref mut v)) => v,
+            _ => panic!(),
+        }
+    }
+
+    // Take field
+    pub fn take_insertStorageCell(&mut self) -> QueryInsertStorageCell {
+        if self.has_insertStorageCell() {
+            match self.req.take() {
+                ::std::option::Option::Some(CosmosRequest_oneof_req::insertStorageCell(v)) => v,
+                _ => panic!(),
+            }
+        } else {
+            QueryInsertStorageCell::new()
+        }
+    }
+
+    // .ffi.ffi.QueryRemove remove = 8;
+
+
+    pub fn get_remove(&self) -> &QueryRemove {
+        match self.req {
+            ::std::option::Option::Some(CosmosRequest_oneof_req::remove(ref v)) => v,
+            _ => QueryRemove::default_instance(),
+        }
+    }
+    pub fn clear_remove(&mut self) {
+        self.req = ::std::option::Option::None;
+    }
+
+    pub fn has_remove(&self) -> bool {
+        match self.req {
+            ::std::option::Option::Some(CosmosRequest_oneof_req::remove(..)) => true,
+            _ => false,
+        }
+    }
+
+    // Param is passed by value, moved
+    pub fn set_remove(&mut self, v: QueryRemove) {
+        self.req = ::std::option::Option::Some(CosmosRequest_oneof_req::remove(v))
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_remove(&mut self) -> &mut QueryRemove {
+        if let ::std::option::Option::Some(CosmosRequest_oneof_req::remove(_)) = self.req {
+        } else {
+            self.req = ::std::option::Option::Some(CosmosRequest_oneof_req::remove(QueryRemove::new()));
+        }
+        match self.req {
+            ::std::option::Option::Some(CosmosRequest_oneof_req::remove(ref mut v)) => v,
+            _ => panic!(),
+        }
+    }
+
+    // Take field
+    pub fn take_remove(&mut self) -> QueryRemove {
+        if self.has_remove() {
+            match self.req.take() {
+                ::std::option::Option::Some(CosmosRequest_oneof_req::remove(v)) => v,
+                _ => panic!(),
+            }
+        } else {
+            QueryRemove::new()
+        }
+    }
+
+    // .ffi.ffi.QueryRemoveStorageCell removeStorageCell = 9;
+
+
+    pub fn get_removeStorageCell(&self) -> &QueryRemoveStorageCell {
+        match self.req {
+            ::std::option::Option::Some(CosmosRequest_oneof_req::removeStorageCell(ref v)) => v,
+            _ => QueryRemoveStorageCell::default_instance(),
+        }
+    }
+    pub fn clear_removeStorageCell(&mut self) {
+        self.req = ::std::option::Option::None;
+    }
+
+    pub fn has_removeStorageCell(&self) -> bool {
+        match self.req {
+            ::std::option::Option::Some(CosmosRequest_oneof_req::removeStorageCell(..)) => true,
+            _ => false,
+        }
+    }
+
+    // Param is passed by value, moved
+    pub fn set_removeStorageCell(&mut self, v: QueryRemoveStorageCell) {
+        self.req = ::std::option::Option::Some(CosmosRequest_oneof_req::removeStorageCell(v))
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_removeStorageCell(&mut self) -> &mut QueryRemoveStorageCell {
+        if let ::std::option::Option::Some(CosmosRequest_oneof_req::removeStorageCell(_)) = self.req {
+        } else {
+            self.req = ::std::option::Option::Some(CosmosRequest_oneof_req::removeStorageCell(QueryRemoveStorageCell::new()));
+        }
+        match self.req {
+            ::std::option::Option::Some(CosmosRequest_oneof_req::removeStorageCell(ref mut v)) => v,
+            _ => panic!(),
+        }
+    }
+
+    // Take field
+    pub fn take_removeStorageCell(&mut self) -> QueryRemoveStorageCell {
+        if self.has_removeStorageCell() {
+            match self.req.take() {
+                ::std::option::Option::Some(CosmosRequest_oneof_req::removeStorageCell(v)) => v,
+                _ => panic!(),
+            }
+        } else {
+            QueryRemoveStorageCell::new()
+        }
+    }
+
+    // .ffi.ffi.QueryRemoveStorage removeStorage = 10;
+
+
+    pub fn get_removeStorage(&self) -> &QueryRemoveStorage {
+        match self.req {
+            ::std::option::Option::Some(CosmosRequest_oneof_req::removeStorage(ref v)) => v,
+            _ => QueryRemoveStorage::default_instance(),
+        }
+    }
+    pub fn clear_removeStorage(&mut self) {
+        self.req = ::std::option::Option::None;
+    }
+
+    pub fn has_removeStorage(&self) -> bool {
+        match self.req {
+            ::std::option::Option::Some(CosmosRequest_oneof_req::removeStorage(..)) => true,
+            _ => false,
+        }
+    }
+
+    // Param is passed by value, moved
+    pub fn set_removeStorage(&mut self, v: QueryRemoveStorage) {
+        self.req = ::std::option::Option::Some(CosmosRequest_oneof_req::removeStorage(v))
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_removeStorage(&mut self) -> &mut QueryRemoveStorage {
+        if let ::std::option::Option::Some(CosmosRequest_oneof_req::removeStorage(_)) = self.req {
+        } else {
+            self.req = ::std::option::Option::Some(CosmosRequest_oneof_req::removeStorage(QueryRemoveStorage::new()));
+        }
+        match self.req {
+            ::std::option::Option::Some(CosmosRequest_oneof_req::removeStorage(ref mut v)) => v,
+            _ => panic!(),
+        }
+    }
+
+    // Take field
+    pub fn take_removeStorage(&mut self) -> QueryRemoveStorage {
+        if self.has_removeStorage() {
+            match self.req.take() {
+                ::std::option::Option::Some(CosmosRequest_oneof_req::removeStorage(v)) => v,
+                _ => panic!(),
+            }
+        } else {
+            QueryRemoveStorage::new()
+        }
+    }
+
+    // .ffi.ffi.QueryBlockHash blockHash = 11;
+
+
+    pub fn get_blockHash(&self) -> &QueryBlockHash {
+        match self.req {
+            ::std::option::Option::Some(CosmosRequest_oneof_req::blockHash(ref v)) => v,
+            _ => QueryBlockHash::default_instance(),
+        }
+    }
+    pub fn clear_blockHash(&mut self) {
+        self.req = ::std::option::Option::None;
+    }
+
+    pub fn has_blockHash(&self) -> bool {
+        match self.req {
+            ::std::option::Option::Some(CosmosRequest_oneof_req::blockHash(..)) => true,
+            _ => false,
+        }
+    }
+
+    // Param is passed by value, moved
+    pub fn set_blockHash(&mut self, v: QueryBlockHash) {
+        self.req = ::std::option::Option::Some(CosmosRequest_oneof_req::blockHash(v))
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_blockHash(&mut self) -> &mut QueryBlockHash {
+        if let ::std::option::Option::Some(CosmosRequest_oneof_req::blockHash(_)) = self.req {
+        } else {
+            self.req = ::std::option::Option::Some(CosmosRequest_oneof_req::blockHash(QueryBlockHash::new()));
+        }
+        match self.req {
+            ::std::option::Option::Some(CosmosRequest_oneof_req::blockHash(ref mut v)) => v,
+            _ => panic!(),
+        }
+    }
+
+    // Take field
+    pub fn take_blockHash(&mut self) -> QueryBlockHash {
+        if self.has_blockHash() {
+            match self.req.take() {
+                ::std::option::Option::Some(CosmosRequest_oneof_req::blockHash(v)) => v,
+                _ => panic!(),
+            }
+        } else {
+            QueryBlockHash::new()
+        }
+    }
+
+    // .ffi.ffi.QueryBatchRead batchRead = 12;
+
+
+    pub fn get_batchRead(&self) -> &QueryBatchRead {
+        match self.req {
+            ::std::option::Option::Some(CosmosRequest_oneof_req::batchRead(ref v)) => v,
+            _ => QueryBatchRead::default_instance(),
+        }
+    }
+    pub fn clear_batchRead(&mut self) {
+        self.req = ::std::option::Option::None;
+    }
+
+    pub fn has_batchRead(&self) -> bool {
+        match self.req {
+            ::std::option::Option::Some(CosmosRequest_oneof_req::batchRead(..)) => true,
+            _ => false,
+        }
+    }
+
+    // Param is passed by value, moved
+    pub fn set_batchRead(&mut self, v: QueryBatchRead) {
+        self.req = ::std::option::Option::Some(CosmosRequest_oneof_req::batchRead(v))
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_batchRead(&mut self) -> &mut QueryBatchRead {
+        if let ::std::option::Option::Some(CosmosRequest_oneof_req::batchRead(_)) = self.req {
+        } else {
+            self.req = ::std::option::Option::Some(CosmosRequest_oneof_req::batchRead(QueryBatchRead::new()));
+        }
+        match self.req {
+            ::std::option::Option::Some(CosmosRequest_oneof_req::batchRead(ref mut v)) => v,
+            _ => panic!(),
+        }
+    }
+
+    // Take field
+    pub fn take_batchRead(&mut self) -> QueryBatchRead {
+        if self.has_batchRead() {
+            match self.req.take() {
+                ::std::option::Option::Some(CosmosRequest_oneof_req::batchRead(v)) => v,
+                _ => panic!(),
+            }
+        } else {
+            QueryBatchRead::new()
+        }
+    }
+
+    // .ffi.ffi.QueryCommit commit = 13;
+
+
+    pub fn get_commit(&self) -> &QueryCommit {
+        match self.req {
+            ::std::option::Option::Some(CosmosRequest_oneof_req::commit(ref v)) => v,
+            _ => QueryCommit::default_instance(),
+        }
+    }
+    pub fn clear_commit(&mut self) {
+        self.req = ::std::option::Option::None;
+    }
+
+    pub fn has_commit(&self) -> bool {
+        match self.req {
+            ::std::option::Option::Some(CosmosRequest_oneof_req::commit(..)) => true,
+            _ => false,
+        }
+    }
+
+    // Param is passed by value, moved
+    pub fn set_commit(&mut self, v: QueryCommit) {
+        self.req = ::std::option::Option::Some(CosmosRequest_oneof_req::commit(v))
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_commit(&mut self) -> &mut QueryCommit {
+        if let ::std::option::Option::Some(CosmosRequest_oneof_req::commit(_)) = self.req {
+        } else {
+            self.req = ::std::option::Option::Some(CosmosRequest_oneof_req::commit(QueryCommit::new()));
+        }
+        match self.req {
+            ::std::option::Option::Some(CosmosRequest_oneof_req::commit(ref mut v)) => v,
+            _ => panic!(),
+        }
+    }
+
+    // Take field
+    pub fn take_commit(&mut self) -> QueryCommit {
+        if self.has_commit() {
+            match self.req.take() {
+                ::std::option::Option::Some(CosmosRequest_oneof_req::commit(v)) => v,
+                _ => panic!(),
+            }
+        } else {
+            QueryCommit::new()
+        }
+    }
+
+    // .ffi.ffi.QueryBankBalance bankBalance = 14;
+
+
+    pub fn get_bankBalance(&self) -> &QueryBankBalance {
+        match self.req {
+            ::std::option::Option::Some(CosmosRequest_oneof_req::bankBalance(ref v)) => v,
+            _ => QueryBankBalance::default_instance(),
+        }
+    }
+    pub fn clear_bankBalance(&mut self) {
+        self.req = ::std::option::Option::None;
+    }
+
+    pub fn has_bankBalance(&self) -> bool {
+        match self.req {
+            ::std::option::Option::Some(CosmosRequest_oneof_req::bankBalance(..)) => true,
+            _ => false,
+        }
+    }
+
+    // Param is passed by value, moved
+    pub fn set_bankBalance(&mut self, v: QueryBankBalance) {
+        self.req = ::std::option::Option::Some(CosmosRequest_oneof_req::bankBalance(v))
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_bankBalance(&mut self) -> &mut QueryBankBalance {
+        if let ::std::option::Option::Some(CosmosRequest_oneof_req::bankBalance(_)) = self.req {
+        } else {
+            self.req = ::std::option::Option::Some(CosmosRequest_oneof_req::bankBalance(QueryBankBalance::new()));
+        }
+        match self.req {
+            ::std::option::Option::Some(CosmosRequest_oneof_req::bankBalance(ref mut v)) => v,
+            _ => panic!(),
+        }
+    }
+
+    // Take field
+    pub fn take_bankBalance(&mut self) -> QueryBankBalance {
+        if self.has_bankBalance() {
+            match self.req.take() {
+                ::std::option::Option::Some(CosmosRequest_oneof_req::bankBalance(v)) => v,
+                _ => panic!(),
+            }
+        } else {
+            QueryBankBalance::new()
+        }
+    }
+}
+
+impl ::protobuf::Message for CosmosRequest {
+    fn is_initialized(&self) -> bool {
+        if let Some(CosmosRequest_oneof_req::getAccount(ref v)) = self.req {
+            if !v.is_initialized() {
+                return false;
+            }
+        }
+        if let Some(CosmosRequest_oneof_req::insertAccount(ref v)) = self.req {
+            if !v.is_initialized() {
+                return false;
+            }
+        }
+        if let Some(CosmosRequest_oneof_req::containsKey(ref v)) = self.req {
+            if !v.is_initialized() {
+                return false;
+            }
+        }
+        if let Some(CosmosRequest_oneof_req::accountCode(ref v)) = self.req {
+            if !v.is_initialized() {
+                return false;
+            }
+        }
+        if let Some(CosmosRequest_oneof_req::storageCell(ref v)) = self.req {
+            if !v.is_initialized() {
+                return false;
+            }
+        }
+        if let Some(CosmosRequest_oneof_req::insertAccountCode(ref v)) = self.req {
+            if !v.is_initialized() {
+                return false;
+            }
+        }
+        if let Some(CosmosRequest_oneof_req::insertStorageCell(ref v)) = self.req {
+            if !v.is_initialized() {
+                return false;
+            }
+        }
+        if let Some(CosmosRequest_oneof_req::remove(ref v)) = self.req {
+            if !v.is_initialized() {
+                return false;
+            }
+        }
+        if let Some(CosmosRequest_oneof_req::removeStorageCell(ref v)) = self.req {
+            if !v.is_initialized() {
+                return false;
+            }
+        }
+        if let Some(CosmosRequest_oneof_req::removeStorage(ref v)) = self.req {
+            if !v.is_initialized() {
+                return false;
+            }
+        }
+        if let Some(CosmosRequest_oneof_req::blockHash(ref v)) = self.req {
+            if !v.is_initialized() {
+                return false;
+            }
+        }
+        if let Some(CosmosRequest_oneof_req::batchRead(ref v)) = self.req {
+            if !v.is_initialized() {
+                return false;
+            }
+        }
+        if let Some(CosmosRequest_oneof_req::commit(ref v)) = self.req {
+            if !v.is_initialized() {
+                return false;
+            }
+        }
+        if let Some(CosmosRequest_oneof_req::bankBalance(ref v)) = self.req {
+            if !v.is_initialized() {
+                return false;
+            }
+        }
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeLengthDelimited {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    self.req = ::std::option::Option::Some(CosmosRequest_oneof_req::getAccount(is.read_message()?));
+                },
+                2 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeLengthDelimited {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    self.req = ::std::option::Option::Some(CosmosRequest_oneof_req::insertAccount(is.read_message()?));
+                },
+                3 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeLengthDelimited {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    self.req = ::std::option::Option::Some(CosmosRequest_oneof_req::containsKey(is.read_message()?));
+                },
+                4 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeLengthDelimited {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    self.req = ::std::option::Option::Some(CosmosRequest_oneof_req::accountCode(is.read_message()?));
+                },
+                5 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeLengthDelimited {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    self.req = ::std::option::Option::Some(CosmosRequest_oneof_req::storageCell(is.read_message()?));
+                },
+                6 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeLengthDelimited {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    self.req = ::std::option::Option::Some(CosmosRequest_oneof_req::insertAccountCode(is.read_message()?));
+                },
+                7 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeLengthDelimited {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    self.req = ::std::option::Option::Some(CosmosRequest_oneof_req::insertStorageCell(is.read_message()?));
+                },
+                8 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeLengthDelimited {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    self.req = ::std::option::Option::Some(CosmosRequest_oneof_req::remove(is.read_message()?));
+                },
+                9 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeLengthDelimited {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    self.req = ::std::option::Option::Some(CosmosRequest_oneof_req::removeStorageCell(is.read_message()?));
+                },
+                10 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeLengthDelimited {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    self.req = ::std::option::Option::Some(CosmosRequest_oneof_req::removeStorage(is.read_message()?));
+                },
+                11 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeLengthDelimited {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    self.req = ::std::option::Option::Some(CosmosRequest_oneof_req::blockHash(is.read_message()?));
+                },
+                12 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeLengthDelimited {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    self.req = ::std::option::Option::Some(CosmosRequest_oneof_req::batchRead(is.read_message()?));
+                },
+                13 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeLengthDelimited {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    self.req = ::std::option::Option::Some(CosmosRequest_oneof_req::commit(is.read_message()?));
+                },
+                14 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeLengthDelimited {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    self.req = ::std::option::Option::Some(CosmosRequest_oneof_req::bankBalance(is.read_message()?));
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if let ::std::option::Option::Some(ref v) = self.req {
+            match v {
+                &CosmosRequest_oneof_req::getAccount(ref v) => {
+                    let len = v.compute_size();
+                    my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+                },
+                &CosmosRequest_oneof_req::insertAccount(ref v) => {
+                    let len = v.compute_size();
+                    my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+                },
+                &CosmosRequest_oneof_req::containsKey(ref v) => {
+                    let len = v.compute_size();
+                    my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+                },
+                &CosmosRequest_oneof_req::accountCode(ref v) => {
+                    let len = v.compute_size();
+                    my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+                },
+                &CosmosRequest_oneof_req::storageCell(ref v) => {
+                    let len = v.compute_size();
+                    my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+                },
+                &CosmosRequest_oneof_req::insertAccountCode(ref v) => {
+                    let len = v.compute_size();
+                    my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+                },
+                &CosmosRequest_oneof_req::insertStorageCell(ref v) => {
+                    let len = v.compute_size();
+                    my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+                },
+                &CosmosRequest_oneof_req::remove(ref v) => {
+                    let len = v.compute_size();
+                    my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+                },
+                &CosmosRequest_oneof_req::removeStorageCell(ref v) => {
+                    let len = v.compute_size();
+                    my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+                },
+                &CosmosRequest_oneof_req::removeStorage(ref v) => {
+                    let len = v.compute_size();
+                    my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+                },
+                &CosmosRequest_oneof_req::blockHash(ref v) => {
+                    let len = v.compute_size();
+                    my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+                },
+                &CosmosRequest_oneof_req::batchRead(ref v) => {
+                    let len = v.compute_size();
+                    my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+                },
+                &CosmosRequest_oneof_req::commit(ref v) => {
+                    let len = v.compute_size();
+                    my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+                },
+                &CosmosRequest_oneof_req::bankBalance(ref v) => {
+                    let len = v.compute_size();
+                    my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+                },
+            };
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if let ::std::option::Option::Some(ref v) = self.req {
+            match v {
+                &CosmosRequest_oneof_req::getAccount(ref v) => {
+                    os.write_tag(1, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+                    os.write_raw_varint32(v.get_cached_size())?;
+                    v.write_to_with_cached_sizes(os)?;
+                },
+                &CosmosRequest_oneof_req::insertAccount(ref v) => {
+                    os.write_tag(2, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+                    os.write_raw_varint32(v.get_cached_size())?;
+                    v.write_to_with_cached_sizes(os)?;
+                },
+                &CosmosRequest_oneof_req::containsKey(ref v) => {
                     os.write_tag(3, ::protobuf::wire_format::WireTypeLengthDelimited)?;
                     os.write_raw_varint32(v.get_cached_size())?;
                     v.write_to_with_cached_sizes(os)?;
                 },
-                &CosmosRequest_oneof_req::accountCode(ref v) => {
-                    os.write_tag(4, ::protobuf::wire_format::WireTypeLengthDelimited)?;
-                    os.write_raw_varint32(v.get_cached_size())?;
-                    v.write_to_with_cached_sizes(os)?;
+                &CosmosRequest_oneof_req::accountCode(ref v) => {
+                    os.write_tag(4, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+                    os.write_raw_varint32(v.get_cached_size())?;
+                    v.write_to_with_cached_sizes(os)?;
+                },
+                &CosmosRequest_oneof_req::storageCell(ref v) => {
+                    os.write_tag(5, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+                    os.write_raw_varint32(v.get_cached_size())?;
+                    v.write_to_with_cached_sizes(os)?;
+                },
+                &CosmosRequest_oneof_req::insertAccountCode(ref v) => {
+                    os.write_tag(6, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+                    os.write_raw_varint32(v.get_cached_size())?;
+                    v.write_to_with_cached_sizes(os)?;
+                },
+                &CosmosRequest_oneof_req::insertStorageCell(ref v) => {
+                    os.write_tag(7, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+                    os.write_raw_varint32(v.get_cached_size())?;
+                    v.write_to_with_cached_sizes(os)?;
+                },
+                &CosmosRequest_oneof_req::remove(ref v) => {
+                    os.write_tag(8, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+                    os.write_raw_varint32(v.get_cached_size())?;
+                    v.write_to_with_cached_sizes(os)?;
+                },
+                &CosmosRequest_oneof_req::removeStorageCell(ref v) => {
+                    os.write_tag(9, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+                    os.write_raw_varint32(v.get_cached_size())?;
+                    v.write_to_with_cached_sizes(os)?;
+                },
+                &CosmosRequest_oneof_req::removeStorage(ref v) => {
+                    os.write_tag(10, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+                    os.write_raw_varint32(v.get_cached_size())?;
+                    v.write_to_with_cached_sizes(os)?;
+                },
+                &CosmosRequest_oneof_req::blockHash(ref v) => {
+                    os.write_tag(11, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+                    os.write_raw_varint32(v.get_cached_size())?;
+                    v.write_to_with_cached_sizes(os)?;
+                },
+                &CosmosRequest_oneof_req::batchRead(ref v) => {
+                    os.write_tag(12, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+                    os.write_raw_varint32(v.get_cached_size())?;
+                    v.write_to_with_cached_sizes(os)?;
+                },
+                &CosmosRequest_oneof_req::commit(ref v) => {
+                    os.write_tag(13, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+                    os.write_raw_varint32(v.get_cached_size())?;
+                    v.write_to_with_cached_sizes(os)?;
+                },
+                &CosmosRequest_oneof_req::bankBalance(ref v) => {
+                    os.write_tag(14, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+                    os.write_raw_varint32(v.get_cached_size())?;
+                    v.write_to_with_cached_sizes(os)?;
+                },
+            };
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> CosmosRequest {
+        CosmosRequest::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const ::protobuf::reflect::MessageDescriptor,
+        };
+        unsafe {
+            descriptor.get(|| {
+                let mut fields = ::std::vec::Vec::new();
+                fields.push(::protobuf::reflect::accessor::make_singular_message_accessor::<_, QueryGetAccount>(
+                    "getAccount",
+                    CosmosRequest::has_getAccount,
+                    CosmosRequest::get_getAccount,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_singular_message_accessor::<_, QueryInsertAccount>(
+                    "insertAccount",
+                    CosmosRequest::has_insertAccount,
+                    CosmosRequest::get_insertAccount,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_singular_message_accessor::<_, QueryContainsKey>(
+                    "containsKey",
+                    CosmosRequest::has_containsKey,
+                    CosmosRequest::get_containsKey,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_singular_message_accessor::<_, QueryGetAccountCode>(
+                    "accountCode",
+                    CosmosRequest::has_accountCode,
+                    CosmosRequest::get_accountCode,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_singular_message_accessor::<_, QueryGetAccountStorageCell>(
+                    "storageCell",
+                    CosmosRequest::has_storageCell,
+                    CosmosRequest::get_storageCell,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_singular_message_accessor::<_, QueryInsertAccountCode>(
+                    "insertAccountCode",
+                    CosmosRequest::has_insertAccountCode,
+                    CosmosRequest::get_insertAccountCode,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_singular_message_accessor::<_, QueryInsertStorageCell>(
+                    "insertStorageCell",
+                    CosmosRequest::has_insertStorageCell,
+                    CosmosRequest::get_insertStorageCell,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_singular_message_accessor::<_, QueryRemove>(
+                    "remove",
+                    CosmosRequest::has_remove,
+                    CosmosRequest::get_remove,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_singular_message_accessor::<_, QueryRemoveStorageCell>(
+                    "removeStorageCell",
+                    CosmosRequest::has_removeStorageCell,
+                    CosmosRequest::get_removeStorageCell,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_singular_message_accessor::<_, QueryRemoveStorage>(
+                    "removeStorage",
+                    CosmosRequest::has_removeStorage,
+                    CosmosRequest::get_removeStorage,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_singular_message_accessor::<_, QueryBlockHash>(
+                    "blockHash",
+                    CosmosRequest::has_blockHash,
+                    CosmosRequest::get_blockHash,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_singular_message_accessor::<_, QueryBatchRead>(
+                    "batchRead",
+                    CosmosRequest::has_batchRead,
+                    CosmosRequest::get_batchRead,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_singular_message_accessor::<_, QueryCommit>(
+                    "commit",
+                    CosmosRequest::has_commit,
+                    CosmosRequest::get_commit,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_singular_message_accessor::<_, QueryBankBalance>(
+                    "bankBalance",
+                    CosmosRequest::has_bankBalance,
+                    CosmosRequest::get_bankBalance,
+                ));
+                ::protobuf::reflect::MessageDescriptor::new::<CosmosRequest>(
+                    "CosmosRequest",
+                    fields,
+                    file_descriptor_proto()
+                )
+            })
+        }
+    }
+
+    fn default_instance() -> &'static CosmosRequest {
+        static mut instance: ::protobuf::lazy::Lazy<CosmosRequest> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const CosmosRequest,
+        };
+        unsafe {
+            instance.get(CosmosRequest::new)
+        }
+    }
+}
+
+impl ::protobuf::Clear for CosmosRequest {
+    fn clear(&mut self) {
+        self.req = ::std::option::Option::None;
+        self.req = ::std::option::Option::None;
+        self.req = ::std::option::Option::None;
+        self.req = ::std::option::Option::None;
+        self.req = ::std::option::Option::None;
+        self.req = ::std::option::Option::None;
+        self.req = ::std::option::Option::None;
+        self.req = ::std::option::Option::None;
+        self.req = ::std::option::Option::None;
+        self.req = ::std::option::Option::None;
+        self.req = ::std::option::Option::None;
+        self.req = ::std::option::Option::None;
+        self.req = ::std::option::Option::None;
+        self.req = ::std::option::Option::None;
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for CosmosRequest {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for CosmosRequest {
+    fn as_ref(&self) -> ::protobuf::reflect::ProtobufValueRef {
+        ::protobuf::reflect::ProtobufValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct SGXVMCallParams {
+    // message fields
+    pub from: ::std::vec::Vec<u8>,
+    pub to: ::std::vec::Vec<u8>,
+    pub data: ::std::vec::Vec<u8>,
+    pub gasLimit: u64,
+    pub value: ::std::vec::Vec<u8>,
+    pub accessList: ::protobuf::RepeatedField<AccessListItem>,
+    pub commit: bool,
+    pub nonce: u64,
+    pub txType: u32,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a SGXVMCallParams {
+    fn default() -> &'a SGXVMCallParams {
+        <SGXVMCallParams as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl SGXVMCallParams {
+    pub fn new() -> SGXVMCallParams {
+        ::std::default::Default::default()
+    }
+
+    // bytes from = 1;
+
+
+    pub fn get_from(&self) -> &[u8] {
+        &self.from
+    }
+    pub fn clear_from(&mut self) {
+        self.from.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_from(&mut self, v: ::std::vec::Vec<u8>) {
+        self.from = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_from(&mut self) -> &mut ::std::vec::Vec<u8> {
+        &mut self.from
+    }
+
+    // Take field
+    pub fn take_from(&mut self) -> ::std::vec::Vec<u8> {
+        ::std::mem::replace(&mut self.from, ::std::vec::Vec::new())
+    }
+
+    // bytes to = 2;
+
+
+    pub fn get_to(&self) -> &[u8] {
+        &self.to
+    }
+    pub fn clear_to(&mut self) {
+        self.to.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_to(&mut self, v: ::std::vec::Vec<u8>) {
+        self.to = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_to(&mut self) -> &mut ::std::vec::Vec<u8> {
+        &mut self.to
+    }
+
+    // Take field
+    pub fn take_to(&mut self) -> ::std::vec::Vec<u8> {
+        ::std::mem::replace(&mut self.to, ::std::vec::Vec::new())
+    }
+
+    // bytes data = 3;
+
+
+    pub fn get_data(&self) -> &[u8] {
+        &self.data
+    }
+    pub fn clear_data(&mut self) {
+        self.data.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_data(&mut self, v: ::std::vec::Vec<u8>) {
+        self.data = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_data(&mut self) -> &mut ::std::vec::Vec<u8> {
+        &mut self.data
+    }
+
+    // Take field
+    pub fn take_data(&mut self) -> ::std::vec::Vec<u8> {
+        ::std::mem::replace(&mut self.data, ::std::vec::Vec::new())
+    }
+
+    // uint64 gasLimit = 4;
+
+
+    pub fn get_gasLimit(&self) -> u64 {
+        self.gasLimit
+    }
+    pub fn clear_gasLimit(&mut self) {
+        self.gasLimit = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_gasLimit(&mut self, v: u64) {
+        self.gasLimit = v;
+    }
+
+    // bytes value = 5;
+
+
+    pub fn get_value(&self) -> &[u8] {
+        &self.value
+    }
+    pub fn clear_value(&mut self) {
+        self.value.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_value(&mut self, v: ::std::vec::Vec<u8>) {
+        self.value = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_value(&mut self) -> &mut ::std::vec::Vec<u8> {
+        &mut self.value
+    }
+
+    // Take field
+    pub fn take_value(&mut self) -> ::std::vec::Vec<u8> {
+        ::std::mem::replace(&mut self.value, ::std::vec::Vec::new())
+    }
+
+    // repeated .ffi.ffi.AccessListItem accessList = 6;
+
+
+    pub fn get_accessList(&self) -> &[AccessListItem] {
+        &self.accessList
+    }
+    pub fn clear_accessList(&mut self) {
+        self.accessList.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_accessList(&mut self, v: ::protobuf::RepeatedField<AccessListItem>) {
+        self.accessList = v;
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_accessList(&mut self) -> &mut ::protobuf::RepeatedField<AccessListItem> {
+        &mut self.accessList
+    }
+
+    // Take field
+    pub fn take_accessList(&mut self) -> ::protobuf::RepeatedField<AccessListItem> {
+        ::std::mem::replace(&mut self.accessList, ::protobuf::RepeatedField::new())
+    }
+
+    // bool commit = 7;
+
+
+    pub fn get_commit(&self) -> bool {
+        self.commit
+    }
+    pub fn clear_commit(&mut self) {
+        self.commit = false;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_commit(&mut self, v: bool) {
+        self.commit = v;
+    }
+
+    // uint64 nonce = 8;
+
+
+    pub fn get_nonce(&self) -> u64 {
+        self.nonce
+    }
+    pub fn clear_nonce(&mut self) {
+        self.nonce = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_nonce(&mut self, v: u64) {
+        self.nonce = v;
+    }
+
+    // uint32 txType = 9;
+
+
+    pub fn get_txType(&self) -> u32 {
+        self.txType
+    }
+    pub fn clear_txType(&mut self) {
+        self.txType = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_txType(&mut self, v: u32) {
+        self.txType = v;
+    }
+}
+
+impl ::protobuf::Message for SGXVMCallParams {
+    fn is_initialized(&self) -> bool {
+        for v in &self.accessList {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_singular_proto3_bytes_into(wire_type, is, &mut self.from)?;
+                },
+                2 => {
+                    ::protobuf::rt::read_singular_proto3_bytes_into(wire_type, is, &mut self.to)?;
+                },
+                3 => {
+                    ::protobuf::rt::read_singular_proto3_bytes_into(wire_type, is, &mut self.data)?;
+                },
+                4 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_uint64()?;
+                    self.gasLimit = tmp;
+                },
+                5 => {
+                    ::protobuf::rt::read_singular_proto3_bytes_into(wire_type, is, &mut self.value)?;
+                },
+                6 => {
+                    ::protobuf::rt::read_repeated_message_into(wire_type, is, &mut self.accessList)?;
+                },
+                7 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_bool()?;
+                    self.commit = tmp;
+                },
+                8 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_uint64()?;
+                    self.nonce = tmp;
+                },
+                9 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_uint32()?;
+                    self.txType = tmp;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if !self.from.is_empty() {
+            my_size += ::protobuf::rt::bytes_size(1, &self.from);
+        }
+        if !self.to.is_empty() {
+            my_size += ::protobuf::rt::bytes_size(2, &self.to);
+        }
+        if !self.data.is_empty() {
+            my_size += ::protobuf::rt::bytes_size(3, &self.data);
+        }
+        if self.gasLimit != 0 {
+            my_size += ::protobuf::rt::value_size(4, self.gasLimit, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if !self.value.is_empty() {
+            my_size += ::protobuf::rt::bytes_size(5, &self.value);
+        }
+        for value in &self.accessList {
+            let len = value.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        };
+        if self.commit != false {
+            my_size += 2;
+        }
+        if self.nonce != 0 {
+            my_size += ::protobuf::rt::value_size(8, self.nonce, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if self.txType != 0 {
+            my_size += ::protobuf::rt::value_size(9, self.txType, ::protobuf::wire_format::WireTypeVarint);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if !self.from.is_empty() {
+            os.write_bytes(1, &self.from)?;
+        }
+        if !self.to.is_empty() {
+            os.write_bytes(2, &self.to)?;
+        }
+        if !self.data.is_empty() {
+            os.write_bytes(3, &self.data)?;
+        }
+        if self.gasLimit != 0 {
+            os.write_uint64(4, self.gasLimit)?;
+        }
+        if !self.value.is_empty() {
+            os.write_bytes(5, &self.value)?;
+        }
+        for v in &self.accessList {
+            os.write_tag(6, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        };
+        if self.commit != false {
+            os.write_bool(7, self.commit)?;
+        }
+        if self.nonce != 0 {
+            os.write_uint64(8, self.nonce)?;
+        }
+        if self.txType != 0 {
+            os.write_uint32(9, self.txType)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> SGXVMCallParams {
+        SGXVMCallParams::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const ::protobuf::reflect::MessageDescriptor,
+        };
+        unsafe {
+            descriptor.get(|| {
+                let mut fields = ::std::vec::Vec::new();
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeBytes>(
+                    "from",
+                    |m: &SGXVMCallParams| { &m.from },
+                    |m: &mut SGXVMCallParams| { &mut m.from },
+                ));
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeBytes>(
+                    "to",
+                    |m: &SGXVMCallParams| { &m.to },
+                    |m: &mut SGXVMCallParams| { &mut m.to },
+                ));
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeBytes>(
+                    "data",
+                    |m: &SGXVMCallParams| { &m.data },
+                    |m: &mut SGXVMCallParams| { &mut m.data },
+                ));
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeUint64>(
+                    "gasLimit",
+                    |m: &SGXVMCallParams| { &m.gasLimit },
+                    |m: &mut SGXVMCallParams| { &mut m.gasLimit },
+                ));
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeBytes>(
+                    "value",
+                    |m: &SGXVMCallParams| { &m.value },
+                    |m: &mut SGXVMCallParams| { &mut m.value },
+                ));
+                fields.push(::protobuf::reflect::accessor::make_repeated_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<AccessListItem>>(
+                    "accessList",
+                    |m: &SGXVMCallParams| { &m.accessList },
+                    |m: &mut SGXVMCallParams| { &mut m.accessList },
+                ));
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeBool>(
+                    "commit",
+                    |m: &SGXVMCallParams| { &m.commit },
+                    |m: &mut SGXVMCallParams| { &mut m.commit },
+                ));
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeUint64>(
+                    "nonce",
+                    |m: &SGXVMCallParams| { &m.nonce },
+                    |m: &mut SGXVMCallParams| { &mut m.nonce },
+                ));
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeUint32>(
+                    "txType",
+                    |m: &SGXVMCallParams| { &m.txType },
+                    |m: &mut SGXVMCallParams| { &mut m.txType },
+                ));
+                ::protobuf::reflect::MessageDescriptor::new::<SGXVMCallParams>(
+                    "SGXVMCallParams",
+                    fields,
+                    file_descriptor_proto()
+                )
+            })
+        }
+    }
+
+    fn default_instance() -> &'static SGXVMCallParams {
+        static mut instance: ::protobuf::lazy::Lazy<SGXVMCallParams> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const SGXVMCallParams,
+        };
+        unsafe {
+            instance.get(SGXVMCallParams::new)
+        }
+    }
+}
+
+impl ::protobuf::Clear for SGXVMCallParams {
+    fn clear(&mut self) {
+        self.from.clear();
+        self.to.clear();
+        self.data.clear();
+        self.gasLimit = 0;
+        self.value.clear();
+        self.accessList.clear();
+        self.commit = false;
+        self.nonce = 0;
+        self.txType = 0;
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for SGXVMCallParams {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for SGXVMCallParams {
+    fn as_ref(&self) -> ::protobuf::reflect::ProtobufValueRef {
+        ::protobuf::reflect::ProtobufValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct SGXVMCreateParams {
+    // message fields
+    pub from: ::std::vec::Vec<u8>,
+    pub data: ::std::vec::Vec<u8>,
+    pub gasLimit: u64,
+    pub value: ::std::vec::Vec<u8>,
+    pub accessList: ::protobuf::RepeatedField<AccessListItem>,
+    pub commit: bool,
+    pub nonce: u64,
+    pub txType: u32,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a SGXVMCreateParams {
+    fn default() -> &'a SGXVMCreateParams {
+        <SGXVMCreateParams as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl SGXVMCreateParams {
+    pub fn new() -> SGXVMCreateParams {
+        ::std::default::Default::default()
+    }
+
+    // bytes from = 1;
+
+
+    pub fn get_from(&self) -> &[u8] {
+        &self.from
+    }
+    pub fn clear_from(&mut self) {
+        self.from.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_from(&mut self, v: ::std::vec::Vec<u8>) {
+        self.from = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_from(&mut self) -> &mut ::std::vec::Vec<u8> {
+        &mut self.from
+    }
+
+    // Take field
+    pub fn take_from(&mut self) -> ::std::vec::Vec<u8> {
+        ::std::mem::replace(&mut self.from, ::std::vec::Vec::new())
+    }
+
+    // bytes data = 2;
+
+
+    pub fn get_data(&self) -> &[u8] {
+        &self.data
+    }
+    pub fn clear_data(&mut self) {
+        self.data.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_data(&mut self, v: ::std::vec::Vec<u8>) {
+        self.data = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_data(&mut self) -> &mut ::std::vec::Vec<u8> {
+        &mut self.data
+    }
+
+    // Take field
+    pub fn take_data(&mut self) -> ::std::vec::Vec<u8> {
+        ::std::mem::replace(&mut self.data, ::std::vec::Vec::new())
+    }
+
+    // uint64 gasLimit = 3;
+
+
+    pub fn get_gasLimit(&self) -> u64 {
+        self.gasLimit
+    }
+    pub fn clear_gasLimit(&mut self) {
+        self.gasLimit = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_gasLimit(&mut self, v: u64) {
+        self.gasLimit = v;
+    }
+
+    // bytes value = 4;
+
+
+    pub fn get_value(&self) -> &[u8] {
+        &self.value
+    }
+    pub fn clear_value(&mut self) {
+        self.value.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_value(&mut self, v: ::std::vec::Vec<u8>) {
+        self.value = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_value(&mut self) -> &mut ::std::vec::Vec<u8> {
+        &mut self.value
+    }
+
+    // Take field
+    pub fn take_value(&mut self) -> ::std::vec::Vec<u8> {
+        ::std::mem::replace(&mut self.value, ::std::vec::Vec::new())
+    }
+
+    // repeated .ffi.ffi.AccessListItem accessList = 5;
+
+
+    pub fn get_accessList(&self) -> &[AccessListItem] {
+        &self.accessList
+    }
+    pub fn clear_accessList(&mut self) {
+        self.accessList.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_accessList(&mut self, v: ::protobuf::RepeatedField<AccessListItem>) {
+        self.accessList = v;
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_accessList(&mut self) -> &mut ::protobuf::RepeatedField<AccessListItem> {
+        &mut self.accessList
+    }
+
+    // Take field
+    pub fn take_accessList(&mut self) -> ::protobuf::RepeatedField<AccessListItem> {
+        ::std::mem::replace(&mut self.accessList, ::protobuf::RepeatedField::new())
+    }
+
+    // bool commit = 6;
+
+
+    pub fn get_commit(&self) -> bool {
+        self.commit
+    }
+    pub fn clear_commit(&mut self) {
+        self.commit = false;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_commit(&mut self, v: bool) {
+        self.commit = v;
+    }
+
+    // uint64 nonce = 7;
+
+
+    pub fn get_nonce(&self) -> u64 {
+        self.nonce
+    }
+    pub fn clear_nonce(&mut self) {
+        self.nonce = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_nonce(&mut self, v: u64) {
+        self.nonce = v;
+    }
+
+    // uint32 txType = 8;
+
+
+    pub fn get_txType(&self) -> u32 {
+        self.txType
+    }
+    pub fn clear_txType(&mut self) {
+        self.txType = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_txType(&mut self, v: u32) {
+        self.txType = v;
+    }
+}
+
+impl ::protobuf::Message for SGXVMCreateParams {
+    fn is_initialized(&self) -> bool {
+        for v in &self.accessList {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_singular_proto3_bytes_into(wire_type, is, &mut self.from)?;
+                },
+                2 => {
+                    ::protobuf::rt::read_singular_proto3_bytes_into(wire_type, is, &mut self.data)?;
+                },
+                3 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_uint64()?;
+                    self.gasLimit = tmp;
+                },
+                4 => {
+                    ::protobuf::rt::read_singular_proto3_bytes_into(wire_type, is, &mut self.value)?;
+                },
+                5 => {
+                    ::protobuf::rt::read_repeated_message_into(wire_type, is, &mut self.accessList)?;
+                },
+                6 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_bool()?;
+                    self.commit = tmp;
+                },
+                7 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_uint64()?;
+                    self.nonce = tmp;
+                },
+                8 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_uint32()?;
+                    self.txType = tmp;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if !self.from.is_empty() {
+            my_size += ::protobuf::rt::bytes_size(1, &self.from);
+        }
+        if !self.data.is_empty() {
+            my_size += ::protobuf::rt::bytes_size(2, &self.data);
+        }
+        if self.gasLimit != 0 {
+            my_size += ::protobuf::rt::value_size(3, self.gasLimit, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if !self.value.is_empty() {
+            my_size += ::protobuf::rt::bytes_size(4, &self.value);
+        }
+        for value in &self.accessList {
+            let len = value.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        };
+        if self.commit != false {
+            my_size += 2;
+        }
+        if self.nonce != 0 {
+            my_size += ::protobuf::rt::value_size(7, self.nonce, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if self.txType != 0 {
+            my_size += ::protobuf::rt::value_size(8, self.txType, ::protobuf::wire_format::WireTypeVarint);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if !self.from.is_empty() {
+            os.write_bytes(1, &self.from)?;
+        }
+        if !self.data.is_empty() {
+            os.write_bytes(2, &self.data)?;
+        }
+        if self.gasLimit != 0 {
+            os.write_uint64(3, self.gasLimit)?;
+        }
+        if !self.value.is_empty() {
+            os.write_bytes(4, &self.value)?;
+        }
+        for v in &self.accessList {
+            os.write_tag(5, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        };
+        if self.commit != false {
+            os.write_bool(6, self.commit)?;
+        }
+        if self.nonce != 0 {
+            os.write_uint64(7, self.nonce)?;
+        }
+        if self.txType != 0 {
+            os.write_uint32(8, self.txType)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> SGXVMCreateParams {
+        SGXVMCreateParams::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const ::protobuf::reflect::MessageDescriptor,
+        };
+        unsafe {
+            descriptor.get(|| {
+                let mut fields = ::std::vec::Vec::new();
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeBytes>(
+                    "from",
+                    |m: &SGXVMCreateParams| { &m.from },
+                    |m: &mut SGXVMCreateParams| { &mut m.from },
+                ));
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeBytes>(
+                    "data",
+                    |m: &SGXVMCreateParams| { &m.data },
+                    |m: &mut SGXVMCreateParams| { &mut m.data },
+                ));
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeUint64>(
+                    "gasLimit",
+                    |m: &SGXVMCreateParams| { &m.gasLimit },
+                    |m: &mut SGXVMCreateParams| { &mut m.gasLimit },
+                ));
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeBytes>(
+                    "value",
+                    |m: &SGXVMCreateParams| { &m.value },
+                    |m: &mut SGXVMCreateParams| { &mut m.value },
+                ));
+                fields.push(::protobuf::reflect::accessor::make_repeated_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<AccessListItem>>(
+                    "accessList",
+                    |m: &SGXVMCreateParams| { &m.accessList },
+                    |m: &mut SGXVMCreateParams| { &mut m.accessList },
+                ));
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeBool>(
+                    "commit",
+                    |m: &SGXVMCreateParams| { &m.commit },
+                    |m: &mut SGXVMCreateParams| { &mut m.commit },
+                ));
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeUint64>(
+                    "nonce",
+                    |m: &SGXVMCreateParams| { &m.nonce },
+                    |m: &mut SGXVMCreateParams| { &mut m.nonce },
+                ));
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeUint32>(
+                    "txType",
+                    |m: &SGXVMCreateParams| { &m.txType },
+                    |m: &mut SGXVMCreateParams| { &mut m.txType },
+                ));
+                ::protobuf::reflect::MessageDescriptor::new::<SGXVMCreateParams>(
+                    "SGXVMCreateParams",
+                    fields,
+                    file_descriptor_proto()
+                )
+            })
+        }
+    }
+
+    fn default_instance() -> &'static SGXVMCreateParams {
+        static mut instance: ::protobuf::lazy::Lazy<SGXVMCreateParams> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const SGXVMCreateParams,
+        };
+        unsafe {
+            instance.get(SGXVMCreateParams::new)
+        }
+    }
+}
+
+impl ::protobuf::Clear for SGXVMCreateParams {
+    fn clear(&mut self) {
+        self.from.clear();
+        self.data.clear();
+        self.gasLimit = 0;
+        self.value.clear();
+        self.accessList.clear();
+        self.commit = false;
+        self.nonce = 0;
+        self.txType = 0;
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for SGXVMCreateParams {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for SGXVMCreateParams {
+    fn as_ref(&self) -> ::protobuf::reflect::ProtobufValueRef {
+        ::protobuf::reflect::ProtobufValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct SGXVMCallRequest {
+    // message fields
+    pub params: ::protobuf::SingularPtrField<SGXVMCallParams>,
+    pub context: ::protobuf::SingularPtrField<TransactionContext>,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a SGXVMCallRequest {
+    fn default() -> &'a SGXVMCallRequest {
+        <SGXVMCallRequest as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl SGXVMCallRequest {
+    pub fn new() -> SGXVMCallRequest {
+        ::std::default::Default::default()
+    }
+
+    // .ffi.ffi.SGXVMCallParams params = 1;
+
+
+    pub fn get_params(&self) -> &SGXVMCallParams {
+        self.params.as_ref().unwrap_or_else(|| SGXVMCallParams::default_instance())
+    }
+    pub fn clear_params(&mut self) {
+        self.params.clear();
+    }
+
+    pub fn has_params(&self) -> bool {
+        self.params.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_params(&mut self, v: SGXVMCallParams) {
+        self.params = ::protobuf::SingularPtrField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_params(&mut self) -> &mut SGXVMCallParams {
+        if self.params.is_none() {
+            self.params.set_default();
+        }
+        self.params.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_params(&mut self) -> SGXVMCallParams {
+        self.params.take().unwrap_or_else(|| SGXVMCallParams::new())
+    }
+
+    // .ffi.ffi.TransactionContext context = 2;
+
+
+    pub fn get_context(&self) -> &TransactionContext {
+        self.context.as_ref().unwrap_or_else(|| TransactionContext::default_instance())
+    }
+    pub fn clear_context(&mut self) {
+        self.context.clear();
+    }
+
+    pub fn has_context(&self) -> bool {
+        self.context.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_context(&mut self, v: TransactionContext) {
+        self.context = ::protobuf::SingularPtrField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_context(&mut self) -> &mut TransactionContext {
+        if self.context.is_none() {
+            self.context.set_default();
+        }
+        self.context.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_context(&mut self) -> TransactionContext {
+        self.context.take().unwrap_or_else(|| TransactionContext::new())
+    }
+}
+
+impl ::protobuf::Message for SGXVMCallRequest {
+    fn is_initialized(&self) -> bool {
+        for v in &self.params {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        for v in &self.context {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_singular_message_into(wire_type, is, &mut self.params)?;
+                },
+                2 => {
+                    ::protobuf::rt::read_singular_message_into(wire_type, is, &mut self.context)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if let Some(ref v) = self.params.as_ref() {
+            let len = v.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        }
+        if let Some(ref v) = self.context.as_ref() {
+            let len = v.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if let Some(ref v) = self.params.as_ref() {
+            os.write_tag(1, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        }
+        if let Some(ref v) = self.context.as_ref() {
+            os.write_tag(2, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> SGXVMCallRequest {
+        SGXVMCallRequest::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const ::protobuf::reflect::MessageDescriptor,
+        };
+        unsafe {
+            descriptor.get(|| {
+                let mut fields = ::std::vec::Vec::new();
+                fields.push(::protobuf::reflect::accessor::make_singular_ptr_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<SGXVMCallParams>>(
+                    "params",
+                    |m: &SGXVMCallRequest| { &m.params },
+                    |m: &mut SGXVMCallRequest| { &mut m.params },
+                ));
+                fields.push(::protobuf::reflect::accessor::make_singular_ptr_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<TransactionContext>>(
+                    "context",
+                    |m: &SGXVMCallRequest| { &m.context },
+                    |m: &mut SGXVMCallRequest| { &mut m.context },
+                ));
+                ::protobuf::reflect::MessageDescriptor::new::<SGXVMCallRequest>(
+                    "SGXVMCallRequest",
+                    fields,
+                    file_descriptor_proto()
+                )
+            })
+        }
+    }
+
+    fn default_instance() -> &'static SGXVMCallRequest {
+        static mut instance: ::protobuf::lazy::Lazy<SGXVMCallRequest> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const SGXVMCallRequest,
+        };
+        unsafe {
+            instance.get(SGXVMCallRequest::new)
+        }
+    }
+}
+
+impl ::protobuf::Clear for SGXVMCallRequest {
+    fn clear(&mut self) {
+        self.params.clear();
+        self.context.clear();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for SGXVMCallRequest {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for SGXVMCallRequest {
+    fn as_ref(&self) -> ::protobuf::reflect::ProtobufValueRef {
+        ::protobuf::reflect::ProtobufValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct SGXVMCreateRequest {
+    // message fields
+    pub params: ::protobuf::SingularPtrField<SGXVMCreateParams>,
+    pub context: ::protobuf::SingularPtrField<TransactionContext>,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a SGXVMCreateRequest {
+    fn default() -> &'a SGXVMCreateRequest {
+        <SGXVMCreateRequest as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl SGXVMCreateRequest {
+    pub fn new() -> SGXVMCreateRequest {
+        ::std::default::Default::default()
+    }
+
+    // .ffi.ffi.SGXVMCreateParams params = 1;
+
+
+    pub fn get_params(&self) -> &SGXVMCreateParams {
+        self.params.as_ref().unwrap_or_else(|| SGXVMCreateParams::default_instance())
+    }
+    pub fn clear_params(&mut self) {
+        self.params.clear();
+    }
+
+    pub fn has_params(&self) -> bool {
+        self.params.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_params(&mut self, v: SGXVMCreateParams) {
+        self.params = ::protobuf::SingularPtrField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_params(&mut self) -> &mut SGXVMCreateParams {
+        if self.params.is_none() {
+            self.params.set_default();
+        }
+        self.params.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_params(&mut self) -> SGXVMCreateParams {
+        self.params.take().unwrap_or_else(|| SGXVMCreateParams::new())
+    }
+
+    // .ffi.ffi.TransactionContext context = 2;
+
+
+    pub fn get_context(&self) -> &TransactionContext {
+        self.context.as_ref().unwrap_or_else(|| TransactionContext::default_instance())
+    }
+    pub fn clear_context(&mut self) {
+        self.context.clear();
+    }
+
+    pub fn has_context(&self) -> bool {
+        self.context.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_context(&mut self, v: TransactionContext) {
+        self.context = ::protobuf::SingularPtrField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_context(&mut self) -> &mut TransactionContext {
+        if self.context.is_none() {
+            self.context.set_default();
+        }
+        self.context.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_context(&mut self) -> TransactionContext {
+        self.context.take().unwrap_or_else(|| TransactionContext::new())
+    }
+}
+
+impl ::protobuf::Message for SGXVMCreateRequest {
+    fn is_initialized(&self) -> bool {
+        for v in &self.params {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        for v in &self.context {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_singular_message_into(wire_type, is, &mut self.params)?;
+                },
+                2 => {
+                    ::protobuf::rt::read_singular_message_into(wire_type, is, &mut self.context)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if let Some(ref v) = self.params.as_ref() {
+            let len = v.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        }
+        if let Some(ref v) = self.context.as_ref() {
+            let len = v.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if let Some(ref v) = self.params.as_ref() {
+            os.write_tag(1, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        }
+        if let Some(ref v) = self.context.as_ref() {
+            os.write_tag(2, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> SGXVMCreateRequest {
+        SGXVMCreateRequest::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const ::protobuf::reflect::MessageDescriptor,
+        };
+        unsafe {
+            descriptor.get(|| {
+                let mut fields = ::std::vec::Vec::new();
+                fields.push(::protobuf::reflect::accessor::make_singular_ptr_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<SGXVMCreateParams>>(
+                    "params",
+                    |m: &SGXVMCreateRequest| { &m.params },
+                    |m: &mut SGXVMCreateRequest| { &mut m.params },
+                ));
+                fields.push(::protobuf::reflect::accessor::make_singular_ptr_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<TransactionContext>>(
+                    "context",
+                    |m: &SGXVMCreateRequest| { &m.context },
+                    |m: &mut SGXVMCreateRequest| { &mut m.context },
+                ));
+                ::protobuf::reflect::MessageDescriptor::new::<SGXVMCreateRequest>(
+                    "SGXVMCreateRequest",
+                    fields,
+                    file_descriptor_proto()
+                )
+            })
+        }
+    }
+
+    fn default_instance() -> &'static SGXVMCreateRequest {
+        static mut instance: ::protobuf::lazy::Lazy<SGXVMCreateRequest> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const SGXVMCreateRequest,
+        };
+        unsafe {
+            instance.get(SGXVMCreateRequest::new)
+        }
+    }
+}
+
+impl ::protobuf::Clear for SGXVMCreateRequest {
+    fn clear(&mut self) {
+        self.params.clear();
+        self.context.clear();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for SGXVMCreateRequest {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for SGXVMCreateRequest {
+    fn as_ref(&self) -> ::protobuf::reflect::ProtobufValueRef {
+        ::protobuf::reflect::ProtobufValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct CallFrame {
+    // message fields
+    pub field_type: ::std::string::String,
+    pub from: ::std::vec::Vec<u8>,
+    pub to: ::std::vec::Vec<u8>,
+    pub value: ::std::vec::Vec<u8>,
+    pub gas: u64,
+    pub input: ::std::vec::Vec<u8>,
+    pub output: ::std::vec::Vec<u8>,
+    pub error: ::std::string::String,
+    pub calls: ::protobuf::RepeatedField<CallFrame>,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a CallFrame {
+    fn default() -> &'a CallFrame {
+        <CallFrame as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl CallFrame {
+    pub fn new() -> CallFrame {
+        ::std::default::Default::default()
+    }
+
+    // string type = 1;
+
+
+    pub fn get_field_type(&self) -> &str {
+        &self.field_type
+    }
+    pub fn clear_field_type(&mut self) {
+        self.field_type.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_field_type(&mut self, v: ::std::string::String) {
+        self.field_type = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_field_type(&mut self) -> &mut ::std::string::String {
+        &mut self.field_type
+    }
+
+    // Take field
+    pub fn take_field_type(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.field_type, ::std::string::String::new())
+    }
+
+    // bytes from = 2;
+
+
+    pub fn get_from(&self) -> &[u8] {
+        &self.from
+    }
+    pub fn clear_from(&mut self) {
+        self.from.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_from(&mut self, v: ::std::vec::Vec<u8>) {
+        self.from = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_from(&mut self) -> &mut ::std::vec::Vec<u8> {
+        &mut self.from
+    }
+
+    // Take field
+    pub fn take_from(&mut self) -> ::std::vec::Vec<u8> {
+        ::std::mem::replace(&mut self.from, ::std::vec::Vec::new())
+    }
+
+    // bytes to = 3;
+
+
+    pub fn get_to(&self) -> &[u8] {
+        &self.to
+    }
+    pub fn clear_to(&mut self) {
+        self.to.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_to(&mut self, v: ::std::vec::Vec<u8>) {
+        self.to = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_to(&mut self) -> &mut ::std::vec::Vec<u8> {
+        &mut self.to
+    }
+
+    // Take field
+    pub fn take_to(&mut self) -> ::std::vec::Vec<u8> {
+        ::std::mem::replace(&mut self.to, ::std::vec::Vec::new())
+    }
+
+    // bytes value = 4;
+
+
+    pub fn get_value(&self) -> &[u8] {
+        &self.value
+    }
+    pub fn clear_value(&mut self) {
+        self.value.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_value(&mut self, v: ::std::vec::Vec<u8>) {
+        self.value = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_value(&mut self) -> &mut ::std::vec::Vec<u8> {
+        &mut self.value
+    }
+
+    // Take field
+    pub fn take_value(&mut self) -> ::std::vec::Vec<u8> {
+        ::std::mem::replace(&mut self.value, ::std::vec::Vec::new())
+    }
+
+    // uint64 gas = 5;
+
+
+    pub fn get_gas(&self) -> u64 {
+        self.gas
+    }
+    pub fn clear_gas(&mut self) {
+        self.gas = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_gas(&mut self, v: u64) {
+        self.gas = v;
+    }
+
+    // bytes input = 6;
+
+
+    pub fn get_input(&self) -> &[u8] {
+        &self.input
+    }
+    pub fn clear_input(&mut self) {
+        self.input.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_input(&mut self, v: ::std::vec::Vec<u8>) {
+        self.input = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_input(&mut self) -> &mut ::std::vec::Vec<u8> {
+        &mut self.input
+    }
+
+    // Take field
+    pub fn take_input(&mut self) -> ::std::vec::Vec<u8> {
+        ::std::mem::replace(&mut self.input, ::std::vec::Vec::new())
+    }
+
+    // bytes output = 7;
+
+
+    pub fn get_output(&self) -> &[u8] {
+        &self.output
+    }
+    pub fn clear_output(&mut self) {
+        self.output.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_output(&mut self, v: ::std::vec::Vec<u8>) {
+        self.output = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_output(&mut self) -> &mut ::std::vec::Vec<u8> {
+        &mut self.output
+    }
+
+    // Take field
+    pub fn take_output(&mut self) -> ::std::vec::Vec<u8> {
+        ::std::mem::replace(&mut self.output, ::std::vec::Vec::new())
+    }
+
+    // string error = 8;
+
+
+    pub fn get_error(&self) -> &str {
+        &self.error
+    }
+    pub fn clear_error(&mut self) {
+        self.error.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_error(&mut self, v: ::std::string::String) {
+        self.error = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_error(&mut self) -> &mut ::std::string::String {
+        &mut self.error
+    }
+
+    // Take field
+    pub fn take_error(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.error, ::std::string::String::new())
+    }
+
+    // repeated .ffi.ffi.CallFrame calls = 9;
+
+
+    pub fn get_calls(&self) -> &[CallFrame] {
+        &self.calls
+    }
+    pub fn clear_calls(&mut self) {
+        self.calls.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_calls(&mut self, v: ::protobuf::RepeatedField<CallFrame>) {
+        self.calls = v;
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_calls(&mut self) -> &mut ::protobuf::RepeatedField<CallFrame> {
+        &mut self.calls
+    }
+
+    // Take field
+    pub fn take_calls(&mut self) -> ::protobuf::RepeatedField<CallFrame> {
+        ::std::mem::replace(&mut self.calls, ::protobuf::RepeatedField::new())
+    }
+}
+
+impl ::protobuf::Message for CallFrame {
+    fn is_initialized(&self) -> bool {
+        for v in &self.calls {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.field_type)?;
+                },
+                2 => {
+                    ::protobuf::rt::read_singular_proto3_bytes_into(wire_type, is, &mut self.from)?;
+                },
+                3 => {
+                    ::protobuf::rt::read_singular_proto3_bytes_into(wire_type, is, &mut self.to)?;
+                },
+                4 => {
+                    ::protobuf::rt::read_singular_proto3_bytes_into(wire_type, is, &mut self.value)?;
+                },
+                5 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_uint64()?;
+                    self.gas = tmp;
+                },
+                6 => {
+                    ::protobuf::rt::read_singular_proto3_bytes_into(wire_type, is, &mut self.input)?;
+                },
+                7 => {
+                    ::protobuf::rt::read_singular_proto3_bytes_into(wire_type, is, &mut self.output)?;
+                },
+                8 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.error)?;
+                },
+                9 => {
+                    ::protobuf::rt::read_repeated_message_into(wire_type, is, &mut self.calls)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if !self.field_type.is_empty() {
+            my_size += ::protobuf::rt::string_size(1, &self.field_type);
+        }
+        if !self.from.is_empty() {
+            my_size += ::protobuf::rt::bytes_size(2, &self.from);
+        }
+        if !self.to.is_empty() {
+            my_size += ::protobuf::rt::bytes_size(3, &self.to);
+        }
+        if !self.value.is_empty() {
+            my_size += ::protobuf::rt::bytes_size(4, &self.value);
+        }
+        if self.gas != 0 {
+            my_size += ::protobuf::rt::value_size(5, self.gas, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if !self.input.is_empty() {
+            my_size += ::protobuf::rt::bytes_size(6, &self.input);
+        }
+        if !self.output.is_empty() {
+            my_size += ::protobuf::rt::bytes_size(7, &self.output);
+        }
+        if !self.error.is_empty() {
+            my_size += ::protobuf::rt::string_size(8, &self.error);
+        }
+        for value in &self.calls {
+            let len = value.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        };
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if !self.field_type.is_empty() {
+            os.write_string(1, &self.field_type)?;
+        }
+        if !self.from.is_empty() {
+            os.write_bytes(2, &self.from)?;
+        }
+        if !self.to.is_empty() {
+            os.write_bytes(3, &self.to)?;
+        }
+        if !self.value.is_empty() {
+            os.write_bytes(4, &self.value)?;
+        }
+        if self.gas != 0 {
+            os.write_uint64(5, self.gas)?;
+        }
+        if !self.input.is_empty() {
+            os.write_bytes(6, &self.input)?;
+        }
+        if !self.output.is_empty() {
+            os.write_bytes(7, &self.output)?;
+        }
+        if !self.error.is_empty() {
+            os.write_string(8, &self.error)?;
+        }
+        for v in &self.calls {
+            os.write_tag(9, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        };
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> CallFrame {
+        CallFrame::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const ::protobuf::reflect::MessageDescriptor,
+        };
+        unsafe {
+            descriptor.get(|| {
+                let mut fields = ::std::vec::Vec::new();
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                    "type",
+                    |m: &CallFrame| { &m.field_type },
+                    |m: &mut CallFrame| { &mut m.field_type },
+                ));
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeBytes>(
+                    "from",
+                    |m: &CallFrame| { &m.from },
+                    |m: &mut CallFrame| { &mut m.from },
+                ));
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeBytes>(
+                    "to",
+                    |m: &CallFrame| { &m.to },
+                    |m: &mut CallFrame| { &mut m.to },
+                ));
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeBytes>(
+                    "value",
+                    |m: &CallFrame| { &m.value },
+                    |m: &mut CallFrame| { &mut m.value },
+                ));
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeUint64>(
+                    "gas",
+                    |m: &CallFrame| { &m.gas },
+                    |m: &mut CallFrame| { &mut m.gas },
+                ));
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeBytes>(
+                    "input",
+                    |m: &CallFrame| { &m.input },
+                    |m: &mut CallFrame| { &mut m.input },
+                ));
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeBytes>(
+                    "output",
+                    |m: &CallFrame| { &m.output },
+                    |m: &mut CallFrame| { &mut m.output },
+                ));
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                    "error",
+                    |m: &CallFrame| { &m.error },
+                    |m: &mut CallFrame| { &mut m.error },
+                ));
+                fields.push(::protobuf::reflect::accessor::make_repeated_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<CallFrame>>(
+                    "calls",
+                    |m: &CallFrame| { &m.calls },
+                    |m: &mut CallFrame| { &mut m.calls },
+                ));
+                ::protobuf::reflect::MessageDescriptor::new::<CallFrame>(
+                    "CallFrame",
+                    fields,
+                    file_descriptor_proto()
+                )
+            })
+        }
+    }
+
+    fn default_instance() -> &'static CallFrame {
+        static mut instance: ::protobuf::lazy::Lazy<CallFrame> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const CallFrame,
+        };
+        unsafe {
+            instance.get(CallFrame::new)
+        }
+    }
+}
+
+impl ::protobuf::Clear for CallFrame {
+    fn clear(&mut self) {
+        self.field_type.clear();
+        self.from.clear();
+        self.to.clear();
+        self.value.clear();
+        self.gas = 0;
+        self.input.clear();
+        self.output.clear();
+        self.error.clear();
+        self.calls.clear();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for CallFrame {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for CallFrame {
+    fn as_ref(&self) -> ::protobuf::reflect::ProtobufValueRef {
+        ::protobuf::reflect::ProtobufValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct TraceTransactionResponse {
+    // message fields
+    pub result: ::protobuf::SingularPtrField<HandleTransactionResponse>,
+    pub callTrace: ::protobuf::SingularPtrField<CallFrame>,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a TraceTransactionResponse {
+    fn default() -> &'a TraceTransactionResponse {
+        <TraceTransactionResponse as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl TraceTransactionResponse {
+    pub fn new() -> TraceTransactionResponse {
+        ::std::default::Default::default()
+    }
+
+    // .ffi.ffi.HandleTransactionResponse result = 1;
+
+
+    pub fn get_result(&self) -> &HandleTransactionResponse {
+        self.result.as_ref().unwrap_or_else(|| HandleTransactionResponse::default_instance())
+    }
+    pub fn clear_result(&mut self) {
+        self.result.clear();
+    }
+
+    pub fn has_result(&self) -> bool {
+        self.result.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_result(&mut self, v: HandleTransactionResponse) {
+        self.result = ::protobuf::SingularPtrField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_result(&mut self) -> &mut HandleTransactionResponse {
+        if self.result.is_none() {
+            self.result.set_default();
+        }
+        self.result.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_result(&mut self) -> HandleTransactionResponse {
+        self.result.take().unwrap_or_else(|| HandleTransactionResponse::new())
+    }
+
+    // .ffi.ffi.CallFrame callTrace = 2;
+
+
+    pub fn get_callTrace(&self) -> &CallFrame {
+        self.callTrace.as_ref().unwrap_or_else(|| CallFrame::default_instance())
+    }
+    pub fn clear_callTrace(&mut self) {
+        self.callTrace.clear();
+    }
+
+    pub fn has_callTrace(&self) -> bool {
+        self.callTrace.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_callTrace(&mut self, v: CallFrame) {
+        self.callTrace = ::protobuf::SingularPtrField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_callTrace(&mut self) -> &mut CallFrame {
+        if self.callTrace.is_none() {
+            self.callTrace.set_default();
+        }
+        self.callTrace.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_callTrace(&mut self) -> CallFrame {
+        self.callTrace.take().unwrap_or_else(|| CallFrame::new())
+    }
+}
+
+impl ::protobuf::Message for TraceTransactionResponse {
+    fn is_initialized(&self) -> bool {
+        for v in &self.result {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        for v in &self.callTrace {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_singular_message_into(wire_type, is, &mut self.result)?;
+                },
+                2 => {
+                    ::protobuf::rt::read_singular_message_into(wire_type, is, &mut self.callTrace)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if let Some(ref v) = self.result.as_ref() {
+            let len = v.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        }
+        if let Some(ref v) = self.callTrace.as_ref() {
+            let len = v.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if let Some(ref v) = self.result.as_ref() {
+            os.write_tag(1, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        }
+        if let Some(ref v) = self.callTrace.as_ref() {
+            os.write_tag(2, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> TraceTransactionResponse {
+        TraceTransactionResponse::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const ::protobuf::reflect::MessageDescriptor,
+        };
+        unsafe {
+            descriptor.get(|| {
+                let mut fields = ::std::vec::Vec::new();
+                fields.push(::protobuf::reflect::accessor::make_singular_ptr_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<HandleTransactionResponse>>(
+                    "result",
+                    |m: &TraceTransactionResponse| { &m.result },
+                    |m: &mut TraceTransactionResponse| { &mut m.result },
+                ));
+                fields.push(::protobuf::reflect::accessor::make_singular_ptr_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<CallFrame>>(
+                    "callTrace",
+                    |m: &TraceTransactionResponse| { &m.callTrace },
+                    |m: &mut TraceTransactionResponse| { &mut m.callTrace },
+                ));
+                ::protobuf::reflect::MessageDescriptor::new::<TraceTransactionResponse>(
+                    "TraceTransactionResponse",
+                    fields,
+                    file_descriptor_proto()
+                )
+            })
+        }
+    }
+
+    fn default_instance() -> &'static TraceTransactionResponse {
+        static mut instance: ::protobuf::lazy::Lazy<TraceTransactionResponse> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const TraceTransactionResponse,
+        };
+        unsafe {
+            instance.get(TraceTransactionResponse::new)
+        }
+    }
+}
+
+impl ::protobuf::Clear for TraceTransactionResponse {
+    fn clear(&mut self) {
+        self.result.clear();
+        self.callTrace.clear();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for TraceTransactionResponse {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for TraceTransactionResponse {
+    fn as_ref(&self) -> ::protobuf::reflect::ProtobufValueRef {
+        ::protobuf::reflect::ProtobufValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct StructLoggerConfig {
+    // message fields
+    pub enableMemory: bool,
+    pub enableStorage: bool,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a StructLoggerConfig {
+    fn default() -> &'a StructLoggerConfig {
+        <StructLoggerConfig as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl StructLoggerConfig {
+    pub fn new() -> StructLoggerConfig {
+        ::std::default::Default::default()
+    }
+
+    // bool enableMemory = 1;
+
+
+    pub fn get_enableMemory(&self) -> bool {
+        self.enableMemory
+    }
+    pub fn clear_enableMemory(&mut self) {
+        self.enableMemory = false;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_enableMemory(&mut self, v: bool) {
+        self.enableMemory = v;
+    }
+
+    // bool enableStorage = 2;
+
+
+    pub fn get_enableStorage(&self) -> bool {
+        self.enableStorage
+    }
+    pub fn clear_enableStorage(&mut self) {
+        self.enableStorage = false;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_enableStorage(&mut self, v: bool) {
+        self.enableStorage = v;
+    }
+}
+
+impl ::protobuf::Message for StructLoggerConfig {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_bool()?;
+                    self.enableMemory = tmp;
+                },
+                2 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_bool()?;
+                    self.enableStorage = tmp;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if self.enableMemory != false {
+            my_size += 2;
+        }
+        if self.enableStorage != false {
+            my_size += 2;
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if self.enableMemory != false {
+            os.write_bool(1, self.enableMemory)?;
+        }
+        if self.enableStorage != false {
+            os.write_bool(2, self.enableStorage)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> StructLoggerConfig {
+        StructLoggerConfig::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const ::protobuf::reflect::MessageDescriptor,
+        };
+        unsafe {
+            descriptor.get(|| {
+                let mut fields = ::std::vec::Vec::new();
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeBool>(
+                    "enableMemory",
+                    |m: &StructLoggerConfig| { &m.enableMemory },
+                    |m: &mut StructLoggerConfig| { &mut m.enableMemory },
+                ));
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeBool>(
+                    "enableStorage",
+                    |m: &StructLoggerConfig| { &m.enableStorage },
+                    |m: &mut StructLoggerConfig| { &mut m.enableStorage },
+                ));
+                ::protobuf::reflect::MessageDescriptor::new::<StructLoggerConfig>(
+                    "StructLoggerConfig",
+                    fields,
+                    file_descriptor_proto()
+                )
+            })
+        }
+    }
+
+    fn default_instance() -> &'static StructLoggerConfig {
+        static mut instance: ::protobuf::lazy::Lazy<StructLoggerConfig> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const StructLoggerConfig,
+        };
+        unsafe {
+            instance.get(StructLoggerConfig::new)
+        }
+    }
+}
+
+impl ::protobuf::Clear for StructLoggerConfig {
+    fn clear(&mut self) {
+        self.enableMemory = false;
+        self.enableStorage = false;
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for StructLoggerConfig {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for StructLoggerConfig {
+    fn as_ref(&self) -> ::protobuf::reflect::ProtobufValueRef {
+        ::protobuf::reflect::ProtobufValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct SGXVMStructLogRequest {
+    // message fields
+    pub params: ::protobuf::SingularPtrField<SGXVMCallParams>,
+    pub context: ::protobuf::SingularPtrField<TransactionContext>,
+    pub config: ::protobuf::SingularPtrField<StructLoggerConfig>,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a SGXVMStructLogRequest {
+    fn default() -> &'a SGXVMStructLogRequest {
+        <SGXVMStructLogRequest as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl SGXVMStructLogRequest {
+    pub fn new() -> SGXVMStructLogRequest {
+        ::std::default::Default::default()
+    }
+
+    // .ffi.ffi.SGXVMCallParams params = 1;
+
+
+    pub fn get_params(&self) -> &SGXVMCallParams {
+        self.params.as_ref().unwrap_or_else(|| SGXVMCallParams::default_instance())
+    }
+    pub fn clear_params(&mut self) {
+        self.params.clear();
+    }
+
+    pub fn has_params(&self) -> bool {
+        self.params.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_params(&mut self, v: SGXVMCallParams) {
+        self.params = ::protobuf::SingularPtrField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_params(&mut self) -> &mut SGXVMCallParams {
+        if self.params.is_none() {
+            self.params.set_default();
+        }
+        self.params.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_params(&mut self) -> SGXVMCallParams {
+        self.params.take().unwrap_or_else(|| SGXVMCallParams::new())
+    }
+
+    // .ffi.ffi.TransactionContext context = 2;
+
+
+    pub fn get_context(&self) -> &TransactionContext {
+        self.context.as_ref().unwrap_or_else(|| TransactionContext::default_instance())
+    }
+    pub fn clear_context(&mut self) {
+        self.context.clear();
+    }
+
+    pub fn has_context(&self) -> bool {
+        self.context.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_context(&mut self, v: TransactionContext) {
+        self.context = ::protobuf::SingularPtrField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_context(&mut self) -> &mut TransactionContext {
+        if self.context.is_none() {
+            self.context.set_default();
+        }
+        self.context.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_context(&mut self) -> TransactionContext {
+        self.context.take().unwrap_or_else(|| TransactionContext::new())
+    }
+
+    // .ffi.ffi.StructLoggerConfig config = 3;
+
+
+    pub fn get_config(&self) -> &StructLoggerConfig {
+        self.config.as_ref().unwrap_or_else(|| StructLoggerConfig::default_instance())
+    }
+    pub fn clear_config(&mut self) {
+        self.config.clear();
+    }
+
+    pub fn has_config(&self) -> bool {
+        self.config.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_config(&mut self, v: StructLoggerConfig) {
+        self.config = ::protobuf::SingularPtrField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_config(&mut self) -> &mut StructLoggerConfig {
+        if self.config.is_none() {
+            self.config.set_default();
+        }
+        self.config.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_config(&mut self) -> StructLoggerConfig {
+        self.config.take().unwrap_or_else(|| StructLoggerConfig::new())
+    }
+}
+
+impl ::protobuf::Message for SGXVMStructLogRequest {
+    fn is_initialized(&self) -> bool {
+        for v in &self.params {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        for v in &self.context {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        for v in &self.config {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_singular_message_into(wire_type, is, &mut self.params)?;
+                },
+                2 => {
+                    ::protobuf::rt::read_singular_message_into(wire_type, is, &mut self.context)?;
+                },
+                3 => {
+                    ::protobuf::rt::read_singular_message_into(wire_type, is, &mut self.config)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if let Some(ref v) = self.params.as_ref() {
+            let len = v.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        }
+        if let Some(ref v) = self.context.as_ref() {
+            let len = v.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        }
+        if let Some(ref v) = self.config.as_ref() {
+            let len = v.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if let Some(ref v) = self.params.as_ref() {
+            os.write_tag(1, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        }
+        if let Some(ref v) = self.context.as_ref() {
+            os.write_tag(2, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        }
+        if let Some(ref v) = self.config.as_ref() {
+            os.write_tag(3, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> SGXVMStructLogRequest {
+        SGXVMStructLogRequest::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const ::protobuf::reflect::MessageDescriptor,
+        };
+        unsafe {
+            descriptor.get(|| {
+                let mut fields = ::std::vec::Vec::new();
+                fields.push(::protobuf::reflect::accessor::make_singular_ptr_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<SGXVMCallParams>>(
+                    "params",
+                    |m: &SGXVMStructLogRequest| { &m.params },
+                    |m: &mut SGXVMStructLogRequest| { &mut m.params },
+                ));
+                fields.push(::protobuf::reflect::accessor::make_singular_ptr_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<TransactionContext>>(
+                    "context",
+                    |m: &SGXVMStructLogRequest| { &m.context },
+                    |m: &mut SGXVMStructLogRequest| { &mut m.context },
+                ));
+                fields.push(::protobuf::reflect::accessor::make_singular_ptr_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<StructLoggerConfig>>(
+                    "config",
+                    |m: &SGXVMStructLogRequest| { &m.config },
+                    |m: &mut SGXVMStructLogRequest| { &mut m.config },
+                ));
+                ::protobuf::reflect::MessageDescriptor::new::<SGXVMStructLogRequest>(
+                    "SGXVMStructLogRequest",
+                    fields,
+                    file_descriptor_proto()
+                )
+            })
+        }
+    }
+
+    fn default_instance() -> &'static SGXVMStructLogRequest {
+        static mut instance: ::protobuf::lazy::Lazy<SGXVMStructLogRequest> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const SGXVMStructLogRequest,
+        };
+        unsafe {
+            instance.get(SGXVMStructLogRequest::new)
+        }
+    }
+}
+
+impl ::protobuf::Clear for SGXVMStructLogRequest {
+    fn clear(&mut self) {
+        self.params.clear();
+        self.context.clear();
+        self.config.clear();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for SGXVMStructLogRequest {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for SGXVMStructLogRequest {
+    fn as_ref(&self) -> ::protobuf::reflect::ProtobufValueRef {
+        ::protobuf::reflect::ProtobufValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct StorageEntry {
+    // message fields
+    pub key: ::std::vec::Vec<u8>,
+    pub value: ::std::vec::Vec<u8>,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a StorageEntry {
+    fn default() -> &'a StorageEntry {
+        <StorageEntry as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl StorageEntry {
+    pub fn new() -> StorageEntry {
+        ::std::default::Default::default()
+    }
+
+    // bytes key = 1;
+
+
+    pub fn get_key(&self) -> &[u8] {
+        &self.key
+    }
+    pub fn clear_key(&mut self) {
+        self.key.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_key(&mut self, v: ::std::vec::Vec<u8>) {
+        self.key = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_key(&mut self) -> &mut ::std::vec::Vec<u8> {
+        &mut self.key
+    }
+
+    // Take field
+    pub fn take_key(&mut self) -> ::std::vec::Vec<u8> {
+        ::std::mem::replace(&mut self.key, ::std::vec::Vec::new())
+    }
+
+    // bytes value = 2;
+
+
+    pub fn get_value(&self) -> &[u8] {
+        &self.value
+    }
+    pub fn clear_value(&mut self) {
+        self.value.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_value(&mut self, v: ::std::vec::Vec<u8>) {
+        self.value = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_value(&mut self) -> &mut ::std::vec::Vec<u8> {
+        &mut self.value
+    }
+
+    // Take field
+    pub fn take_value(&mut self) -> ::std::vec::Vec<u8> {
+        ::std::mem::replace(&mut self.value, ::std::vec::Vec::new())
+    }
+}
+
+impl ::protobuf::Message for StorageEntry {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_singular_proto3_bytes_into(wire_type, is, &mut self.key)?;
+                },
+                2 => {
+                    ::protobuf::rt::read_singular_proto3_bytes_into(wire_type, is, &mut self.value)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if !self.key.is_empty() {
+            my_size += ::protobuf::rt::bytes_size(1, &self.key);
+        }
+        if !self.value.is_empty() {
+            my_size += ::protobuf::rt::bytes_size(2, &self.value);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if !self.key.is_empty() {
+            os.write_bytes(1, &self.key)?;
+        }
+        if !self.value.is_empty() {
+            os.write_bytes(2, &self.value)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> StorageEntry {
+        StorageEntry::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const ::protobuf::reflect::MessageDescriptor,
+        };
+        unsafe {
+            descriptor.get(|| {
+                let mut fields = ::std::vec::Vec::new();
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeBytes>(
+                    "key",
+                    |m: &StorageEntry| { &m.key },
+                    |m: &mut StorageEntry| { &mut m.key },
+                ));
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeBytes>(
+                    "value",
+                    |m: &StorageEntry| { &m.value },
+                    |m: &mut StorageEntry| { &mut m.value },
+                ));
+                ::protobuf::reflect::MessageDescriptor::new::<StorageEntry>(
+                    "StorageEntry",
+                    fields,
+                    file_descriptor_proto()
+                )
+            })
+        }
+    }
+
+    fn default_instance() -> &'static StorageEntry {
+        static mut instance: ::protobuf::lazy::Lazy<StorageEntry> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const StorageEntry,
+        };
+        unsafe {
+            instance.get(StorageEntry::new)
+        }
+    }
+}
+
+impl ::protobuf::Clear for StorageEntry {
+    fn clear(&mut self) {
+        self.key.clear();
+        self.value.clear();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for StorageEntry {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for StorageEntry {
+    fn as_ref(&self) -> ::protobuf::reflect::ProtobufValueRef {
+        ::protobuf::reflect::ProtobufValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct StructLog {
+    // message fields
+    pub pc: u64,
+    pub op: ::std::string::String,
+    pub gas: u64,
+    pub gasCost: u64,
+    pub depth: u64,
+    pub stack: ::protobuf::RepeatedField<::std::vec::Vec<u8>>,
+    pub memory: ::std::vec::Vec<u8>,
+    pub storage: ::protobuf::RepeatedField<StorageEntry>,
+    pub error: ::std::string::String,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a StructLog {
+    fn default() -> &'a StructLog {
+        <StructLog as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl StructLog {
+    pub fn new() -> StructLog {
+        ::std::default::Default::default()
+    }
+
+    // uint64 pc = 1;
+
+
+    pub fn get_pc(&self) -> u64 {
+        self.pc
+    }
+    pub fn clear_pc(&mut self) {
+        self.pc = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_pc(&mut self, v: u64) {
+        self.pc = v;
+    }
+
+    // string op = 2;
+
+
+    pub fn get_op(&self) -> &str {
+        &self.op
+    }
+    pub fn clear_op(&mut self) {
+        self.op.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_op(&mut self, v: ::std::string::String) {
+        self.op = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_op(&mut self) -> &mut ::std::string::String {
+        &mut self.op
+    }
+
+    // Take field
+    pub fn take_op(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.op, ::std::string::String::new())
+    }
+
+    // uint64 gas = 3;
+
+
+    pub fn get_gas(&self) -> u64 {
+        self.gas
+    }
+    pub fn clear_gas(&mut self) {
+        self.gas = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_gas(&mut self, v: u64) {
+        self.gas = v;
+    }
+
+    // uint64 gasCost = 4;
+
+
+    pub fn get_gasCost(&self) -> u64 {
+        self.gasCost
+    }
+    pub fn clear_gasCost(&mut self) {
+        self.gasCost = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_gasCost(&mut self, v: u64) {
+        self.gasCost = v;
+    }
+
+    // uint64 depth = 5;
+
+
+    pub fn get_depth(&self) -> u64 {
+        self.depth
+    }
+    pub fn clear_depth(&mut self) {
+        self.depth = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_depth(&mut self, v: u64) {
+        self.depth = v;
+    }
+
+    // repeated bytes stack = 6;
+
+
+    pub fn get_stack(&self) -> &[::std::vec::Vec<u8>] {
+        &self.stack
+    }
+    pub fn clear_stack(&mut self) {
+        self.stack.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_stack(&mut self, v: ::protobuf::RepeatedField<::std::vec::Vec<u8>>) {
+        self.stack = v;
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_stack(&mut self) -> &mut ::protobuf::RepeatedField<::std::vec::Vec<u8>> {
+        &mut self.stack
+    }
+
+    // Take field
+    pub fn take_stack(&mut self) -> ::protobuf::RepeatedField<::std::vec::Vec<u8>> {
+        ::std::mem::replace(&mut self.stack, ::protobuf::RepeatedField::new())
+    }
+
+    // bytes memory = 7;
+
+
+    pub fn get_memory(&self) -> &[u8] {
+        &self.memory
+    }
+    pub fn clear_memory(&mut self) {
+        self.memory.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_memory(&mut self, v: ::std::vec::Vec<u8>) {
+        self.memory = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_memory(&mut self) -> &mut ::std::vec::Vec<u8> {
+        &mut self.memory
+    }
+
+    // Take field
+    pub fn take_memory(&mut self) -> ::std::vec::Vec<u8> {
+        ::std::mem::replace(&mut self.memory, ::std::vec::Vec::new())
+    }
+
+    // repeated .ffi.ffi.StorageEntry storage = 8;
+
+
+    pub fn get_storage(&self) -> &[StorageEntry] {
+        &self.storage
+    }
+    pub fn clear_storage(&mut self) {
+        self.storage.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_storage(&mut self, v: ::protobuf::RepeatedField<StorageEntry>) {
+        self.storage = v;
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_storage(&mut self) -> &mut ::protobuf::RepeatedField<StorageEntry> {
+        &mut self.storage
+    }
+
+    // Take field
+    pub fn take_storage(&mut self) -> ::protobuf::RepeatedField<StorageEntry> {
+        ::std::mem::replace(&mut self.storage, ::protobuf::RepeatedField::new())
+    }
+
+    // string error = 9;
+
+
+    pub fn get_error(&self) -> &str {
+        &self.error
+    }
+    pub fn clear_error(&mut self) {
+        self.error.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_error(&mut self, v: ::std::string::String) {
+        self.error = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_error(&mut self) -> &mut ::std::string::String {
+        &mut self.error
+    }
+
+    // Take field
+    pub fn take_error(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.error, ::std::string::String::new())
+    }
+}
+
+impl ::protobuf::Message for StructLog {
+    fn is_initialized(&self) -> bool {
+        for v in &self.storage {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_uint64()?;
+                    self.pc = tmp;
+                },
+                2 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.op)?;
+                },
+                3 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_uint64()?;
+                    self.gas = tmp;
+                },
+                4 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_uint64()?;
+                    self.gasCost = tmp;
+                },
+                5 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_uint64()?;
+                    self.depth = tmp;
+                },
+                6 => {
+                    ::protobuf::rt::read_repeated_bytes_into(wire_type, is, &mut self.stack)?;
+                },
+                7 => {
+                    ::protobuf::rt::read_singular_proto3_bytes_into(wire_type, is, &mut self.memory)?;
+                },
+                8 => {
+                    ::protobuf::rt::read_repeated_message_into(wire_type, is, &mut self.storage)?;
+                },
+                9 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.error)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if self.pc != 0 {
+            my_size += ::protobuf::rt::value_size(1, self.pc, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if !self.op.is_empty() {
+            my_size += ::protobuf::rt::string_size(2, &self.op);
+        }
+        if self.gas != 0 {
+            my_size += ::protobuf::rt::value_size(3, self.gas, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if self.gasCost != 0 {
+            my_size += ::protobuf::rt::value_size(4, self.gasCost, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if self.depth != 0 {
+            my_size += ::protobuf::rt::value_size(5, self.depth, ::protobuf::wire_format::WireTypeVarint);
+        }
+        for value in &self.stack {
+            my_size += ::protobuf::rt::bytes_size(6, &value);
+        };
+        if !self.memory.is_empty() {
+            my_size += ::protobuf::rt::bytes_size(7, &self.memory);
+        }
+        for value in &self.storage {
+            let len = value.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        };
+        if !self.error.is_empty() {
+            my_size += ::protobuf::rt::string_size(9, &self.error);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if self.pc != 0 {
+            os.write_uint64(1, self.pc)?;
+        }
+        if !self.op.is_empty() {
+            os.write_string(2, &self.op)?;
+        }
+        if self.gas != 0 {
+            os.write_uint64(3, self.gas)?;
+        }
+        if self.gasCost != 0 {
+            os.write_uint64(4, self.gasCost)?;
+        }
+        if self.depth != 0 {
+            os.write_uint64(5, self.depth)?;
+        }
+        for v in &self.stack {
+            os.write_bytes(6, &v)?;
+        };
+        if !self.memory.is_empty() {
+            os.write_bytes(7, &self.memory)?;
+        }
+        for v in &self.storage {
+            os.write_tag(8, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        };
+        if !self.error.is_empty() {
+            os.write_string(9, &self.error)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> StructLog {
+        StructLog::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const ::protobuf::reflect::MessageDescriptor,
+        };
+        unsafe {
+            descriptor.get(|| {
+                let mut fields = ::std::vec::Vec::new();
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeUint64>(
+                    "pc",
+                    |m: &StructLog| { &m.pc },
+                    |m: &mut StructLog| { &mut m.pc },
+                ));
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                    "op",
+                    |m: &StructLog| { &m.op },
+                    |m: &mut StructLog| { &mut m.op },
+                ));
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeUint64>(
+                    "gas",
+                    |m: &StructLog| { &m.gas },
+                    |m: &mut StructLog| { &mut m.gas },
+                ));
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeUint64>(
+                    "gasCost",
+                    |m: &StructLog| { &m.gasCost },
+                    |m: &mut StructLog| { &mut m.gasCost },
+                ));
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeUint64>(
+                    "depth",
+                    |m: &StructLog| { &m.depth },
+                    |m: &mut StructLog| { &mut m.depth },
+                ));
+                fields.push(::protobuf::reflect::accessor::make_repeated_field_accessor::<_, ::protobuf::types::ProtobufTypeBytes>(
+                    "stack",
+                    |m: &StructLog| { &m.stack },
+                    |m: &mut StructLog| { &mut m.stack },
+                ));
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeBytes>(
+                    "memory",
+                    |m: &StructLog| { &m.memory },
+                    |m: &mut StructLog| { &mut m.memory },
+                ));
+                fields.push(::protobuf::reflect::accessor::make_repeated_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<StorageEntry>>(
+                    "storage",
+                    |m: &StructLog| { &m.storage },
+                    |m: &mut StructLog| { &mut m.storage },
+                ));
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                    "error",
+                    |m: &StructLog| { &m.error },
+                    |m: &mut StructLog| { &mut m.error },
+                ));
+                ::protobuf::reflect::MessageDescriptor::new::<StructLog>(
+                    "StructLog",
+                    fields,
+                    file_descriptor_proto()
+                )
+            })
+        }
+    }
+
+    fn default_instance() -> &'static StructLog {
+        static mut instance: ::protobuf::lazy::Lazy<StructLog> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const StructLog,
+        };
+        unsafe {
+            instance.get(StructLog::new)
+        }
+    }
+}
+
+impl ::protobuf::Clear for StructLog {
+    fn clear(&mut self) {
+        self.pc = 0;
+        self.op.clear();
+        self.gas = 0;
+        self.gasCost = 0;
+        self.depth = 0;
+        self.stack.clear();
+        self.memory.clear();
+        self.storage.clear();
+        self.error.clear();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for StructLog {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for StructLog {
+    fn as_ref(&self) -> ::protobuf::reflect::ProtobufValueRef {
+        ::protobuf::reflect::ProtobufValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct StructLogs {
+    // message fields
+    pub logs: ::protobuf::RepeatedField<StructLog>,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a StructLogs {
+    fn default() -> &'a StructLogs {
+        <StructLogs as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl StructLogs {
+    pub fn new() -> StructLogs {
+        ::std::default::Default::default()
+    }
+
+    // repeated .ffi.ffi.StructLog logs = 1;
+
+
+    pub fn get_logs(&self) -> &[StructLog] {
+        &self.logs
+    }
+    pub fn clear_logs(&mut self) {
+        self.logs.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_logs(&mut self, v: ::protobuf::RepeatedField<StructLog>) {
+        self.logs = v;
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_logs(&mut self) -> &mut ::protobuf::RepeatedField<StructLog> {
+        &mut self.logs
+    }
+
+    // Take field
+    pub fn take_logs(&mut self) -> ::protobuf::RepeatedField<StructLog> {
+        ::std::mem::replace(&mut self.logs, ::protobuf::RepeatedField::new())
+    }
+}
+
+impl ::protobuf::Message for StructLogs {
+    fn is_initialized(&self) -> bool {
+        for v in &self.logs {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_repeated_message_into(wire_type, is, &mut self.logs)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        for value in &self.logs {
+            let len = value.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        };
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        for v in &self.logs {
+            os.write_tag(1, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        };
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> StructLogs {
+        StructLogs::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const ::protobuf::reflect::MessageDescriptor,
+        };
+        unsafe {
+            descriptor.get(|| {
+                let mut fields = ::std::vec::Vec::new();
+                fields.push(::protobuf::reflect::accessor::make_repeated_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<StructLog>>(
+                    "logs",
+                    |m: &StructLogs| { &m.logs },
+                    |m: &mut StructLogs| { &mut m.logs },
+                ));
+                ::protobuf::reflect::MessageDescriptor::new::<StructLogs>(
+                    "StructLogs",
+                    fields,
+                    file_descriptor_proto()
+                )
+            })
+        }
+    }
+
+    fn default_instance() -> &'static StructLogs {
+        static mut instance: ::protobuf::lazy::Lazy<StructLogs> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const StructLogs,
+        };
+        unsafe {
+            instance.get(StructLogs::new)
+        }
+    }
+}
+
+impl ::protobuf::Clear for StructLogs {
+    fn clear(&mut self) {
+        self.logs.clear();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for StructLogs {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for StructLogs {
+    fn as_ref(&self) -> ::protobuf::reflect::ProtobufValueRef {
+        ::protobuf::reflect::ProtobufValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct StructLogResponse {
+    // message fields
+    pub result: ::protobuf::SingularPtrField<HandleTransactionResponse>,
+    pub structLogs: ::std::vec::Vec<u8>,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a StructLogResponse {
+    fn default() -> &'a StructLogResponse {
+        <StructLogResponse as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl StructLogResponse {
+    pub fn new() -> StructLogResponse {
+        ::std::default::Default::default()
+    }
+
+    // .ffi.ffi.HandleTransactionResponse result = 1;
+
+
+    pub fn get_result(&self) -> &HandleTransactionResponse {
+        self.result.as_ref().unwrap_or_else(|| HandleTransactionResponse::default_instance())
+    }
+    pub fn clear_result(&mut self) {
+        self.result.clear();
+    }
+
+    pub fn has_result(&self) -> bool {
+        self.result.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_result(&mut self, v: HandleTransactionResponse) {
+        self.result = ::protobuf::SingularPtrField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_result(&mut self) -> &mut HandleTransactionResponse {
+        if self.result.is_none() {
+            self.result.set_default();
+        }
+        self.result.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_result(&mut self) -> HandleTransactionResponse {
+        self.result.take().unwrap_or_else(|| HandleTransactionResponse::new())
+    }
+
+    // bytes structLogs = 2;
+
+
+    pub fn get_structLogs(&self) -> &[u8] {
+        &self.structLogs
+    }
+    pub fn clear_structLogs(&mut self) {
+        self.structLogs.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_structLogs(&mut self, v: ::std::vec::Vec<u8>) {
+        self.structLogs = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_structLogs(&mut self) -> &mut ::std::vec::Vec<u8> {
+        &mut self.structLogs
+    }
+
+    // Take field
+    pub fn take_structLogs(&mut self) -> ::std::vec::Vec<u8> {
+        ::std::mem::replace(&mut self.structLogs, ::std::vec::Vec::new())
+    }
+}
+
+impl ::protobuf::Message for StructLogResponse {
+    fn is_initialized(&self) -> bool {
+        for v in &self.result {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_singular_message_into(wire_type, is, &mut self.result)?;
+                },
+                2 => {
+                    ::protobuf::rt::read_singular_proto3_bytes_into(wire_type, is, &mut self.structLogs)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if let Some(ref v) = self.result.as_ref() {
+            let len = v.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        }
+        if !self.structLogs.is_empty() {
+            my_size += ::protobuf::rt::bytes_size(2, &self.structLogs);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if let Some(ref v) = self.result.as_ref() {
+            os.write_tag(1, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        }
+        if !self.structLogs.is_empty() {
+            os.write_bytes(2, &self.structLogs)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> StructLogResponse {
+        StructLogResponse::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const ::protobuf::reflect::MessageDescriptor,
+        };
+        unsafe {
+            descriptor.get(|| {
+                let mut fields = ::std::vec::Vec::new();
+                fields.push(::protobuf::reflect::accessor::make_singular_ptr_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<HandleTransactionResponse>>(
+                    "result",
+                    |m: &StructLogResponse| { &m.result },
+                    |m: &mut StructLogResponse| { &mut m.result },
+                ));
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeBytes>(
+                    "structLogs",
+                    |m: &StructLogResponse| { &m.structLogs },
+                    |m: &mut StructLogResponse| { &mut m.structLogs },
+                ));
+                ::protobuf::reflect::MessageDescriptor::new::<StructLogResponse>(
+                    "StructLogResponse",
+                    fields,
+                    file_descriptor_proto()
+                )
+            })
+        }
+    }
+
+    fn default_instance() -> &'static StructLogResponse {
+        static mut instance: ::protobuf::lazy::Lazy<StructLogResponse> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const StructLogResponse,
+        };
+        unsafe {
+            instance.get(StructLogResponse::new)
+        }
+    }
+}
+
+impl ::protobuf::Clear for StructLogResponse {
+    fn clear(&mut self) {
+        self.result.clear();
+        self.structLogs.clear();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for StructLogResponse {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for StructLogResponse {
+    fn as_ref(&self) -> ::protobuf::reflect::ProtobufValueRef {
+        ::protobuf::reflect::ProtobufValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct SGXVMEstimateGasRequest {
+    // message fields
+    pub params: ::protobuf::SingularPtrField<SGXVMCallParams>,
+    pub context: ::protobuf::SingularPtrField<TransactionContext>,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a SGXVMEstimateGasRequest {
+    fn default() -> &'a SGXVMEstimateGasRequest {
+        <SGXVMEstimateGasRequest as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl SGXVMEstimateGasRequest {
+    pub fn new() -> SGXVMEstimateGasRequest {
+        ::std::default::Default::default()
+    }
+
+    // .ffi.ffi.SGXVMCallParams params = 1;
+
+
+    pub fn get_params(&self) -> &SGXVMCallParams {
+        self.params.as_ref().unwrap_or_else(|| SGXVMCallParams::default_instance())
+    }
+    pub fn clear_params(&mut self) {
+        self.params.clear();
+    }
+
+    pub fn has_params(&self) -> bool {
+        self.params.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_params(&mut self, v: SGXVMCallParams) {
+        self.params = ::protobuf::SingularPtrField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_params(&mut self) -> &mut SGXVMCallParams {
+        if self.params.is_none() {
+            self.params.set_default();
+        }
+        self.params.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_params(&mut self) -> SGXVMCallParams {
+        self.params.take().unwrap_or_else(|| SGXVMCallParams::new())
+    }
+
+    // .ffi.ffi.TransactionContext context = 2;
+
+
+    pub fn get_context(&self) -> &TransactionContext {
+        self.context.as_ref().unwrap_or_else(|| TransactionContext::default_instance())
+    }
+    pub fn clear_context(&mut self) {
+        self.context.clear();
+    }
+
+    pub fn has_context(&self) -> bool {
+        self.context.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_context(&mut self, v: TransactionContext) {
+        self.context = ::protobuf::SingularPtrField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_context(&mut self) -> &mut TransactionContext {
+        if self.context.is_none() {
+            self.context.set_default();
+        }
+        self.context.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_context(&mut self) -> TransactionContext {
+        self.context.take().unwrap_or_else(|| TransactionContext::new())
+    }
+}
+
+impl ::protobuf::Message for SGXVMEstimateGasRequest {
+    fn is_initialized(&self) -> bool {
+        for v in &self.params {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        for v in &self.context {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_singular_message_into(wire_type, is, &mut self.params)?;
+                },
+                2 => {
+                    ::protobuf::rt::read_singular_message_into(wire_type, is, &mut self.context)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if let Some(ref v) = self.params.as_ref() {
+            let len = v.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        }
+        if let Some(ref v) = self.context.as_ref() {
+            let len = v.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if let Some(ref v) = self.params.as_ref() {
+            os.write_tag(1, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        }
+        if let Some(ref v) = self.context.as_ref() {
+            os.write_tag(2, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> SGXVMEstimateGasRequest {
+        SGXVMEstimateGasRequest::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const ::protobuf::reflect::MessageDescriptor,
+        };
+        unsafe {
+            descriptor.get(|| {
+                let mut fields = ::std::vec::Vec::new();
+                fields.push(::protobuf::reflect::accessor::make_singular_ptr_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<SGXVMCallParams>>(
+                    "params",
+                    |m: &SGXVMEstimateGasRequest| { &m.params },
+                    |m: &mut SGXVMEstimateGasRequest| { &mut m.params },
+                ));
+                fields.push(::protobuf::reflect::accessor::make_singular_ptr_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<TransactionContext>>(
+                    "context",
+                    |m: &SGXVMEstimateGasRequest| { &m.context },
+                    |m: &mut SGXVMEstimateGasRequest| { &mut m.context },
+                ));
+                ::protobuf::reflect::MessageDescriptor::new::<SGXVMEstimateGasRequest>(
+                    "SGXVMEstimateGasRequest",
+                    fields,
+                    file_descriptor_proto()
+                )
+            })
+        }
+    }
+
+    fn default_instance() -> &'static SGXVMEstimateGasRequest {
+        static mut instance: ::protobuf::lazy::Lazy<SGXVMEstimateGasRequest> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const SGXVMEstimateGasRequest,
+        };
+        unsafe {
+            instance.get(SGXVMEstimateGasRequest::new)
+        }
+    }
+}
+
+impl ::protobuf::Clear for SGXVMEstimateGasRequest {
+    fn clear(&mut self) {
+        self.params.clear();
+        self.context.clear();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for SGXVMEstimateGasRequest {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for SGXVMEstimateGasRequest {
+    fn as_ref(&self) -> ::protobuf::reflect::ProtobufValueRef {
+        ::protobuf::reflect::ProtobufValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct EstimateGasResponse {
+    // message fields
+    pub gas: u64,
+    pub vm_error: ::std::string::String,
+    pub ret: ::std::vec::Vec<u8>,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a EstimateGasResponse {
+    fn default() -> &'a EstimateGasResponse {
+        <EstimateGasResponse as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl EstimateGasResponse {
+    pub fn new() -> EstimateGasResponse {
+        ::std::default::Default::default()
+    }
+
+    // uint64 gas = 1;
+
+
+    pub fn get_gas(&self) -> u64 {
+        self.gas
+    }
+    pub fn clear_gas(&mut self) {
+        self.gas = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_gas(&mut self, v: u64) {
+        self.gas = v;
+    }
+
+    // string vm_error = 2;
+
+
+    pub fn get_vm_error(&self) -> &str {
+        &self.vm_error
+    }
+    pub fn clear_vm_error(&mut self) {
+        self.vm_error.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_vm_error(&mut self, v: ::std::string::String) {
+        self.vm_error = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_vm_error(&mut self) -> &mut ::std::string::String {
+        &mut self.vm_error
+    }
+
+    // Take field
+    pub fn take_vm_error(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.vm_error, ::std::string::String::new())
+    }
+
+    // bytes ret = 3;
+
+
+    pub fn get_ret(&self) -> &[u8] {
+        &self.ret
+    }
+    pub fn clear_ret(&mut self) {
+        self.ret.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_ret(&mut self, v: ::std::vec::Vec<u8>) {
+        self.ret = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_ret(&mut self) -> &mut ::std::vec::Vec<u8> {
+        &mut self.ret
+    }
+
+    // Take field
+    pub fn take_ret(&mut self) -> ::std::vec::Vec<u8> {
+        ::std::mem::replace(&mut self.ret, ::std::vec::Vec::new())
+    }
+}
+
+impl ::protobuf::Message for EstimateGasResponse {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_uint64()?;
+                    self.gas = tmp;
+                },
+                2 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.vm_error)?;
+                },
+                3 => {
+                    ::protobuf::rt::read_singular_proto3_bytes_into(wire_type, is, &mut self.ret)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if self.gas != 0 {
+            my_size += ::protobuf::rt::value_size(1, self.gas, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if !self.vm_error.is_empty() {
+            my_size += ::protobuf::rt::string_size(2, &self.vm_error);
+        }
+        if !self.ret.is_empty() {
+            my_size += ::protobuf::rt::bytes_size(3, &self.ret);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if self.gas != 0 {
+            os.write_uint64(1, self.gas)?;
+        }
+        if !self.vm_error.is_empty() {
+            os.write_string(2, &self.vm_error)?;
+        }
+        if !self.ret.is_empty() {
+            os.write_bytes(3, &self.ret)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> EstimateGasResponse {
+        EstimateGasResponse::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const ::protobuf::reflect::MessageDescriptor,
+        };
+        unsafe {
+            descriptor.get(|| {
+                let mut fields = ::std::vec::Vec::new();
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeUint64>(
+                    "gas",
+                    |m: &EstimateGasResponse| { &m.gas },
+                    |m: &mut EstimateGasResponse| { &mut m.gas },
+                ));
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                    "vm_error",
+                    |m: &EstimateGasResponse| { &m.vm_error },
+                    |m: &mut EstimateGasResponse| { &mut m.vm_error },
+                ));
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeBytes>(
+                    "ret",
+                    |m: &EstimateGasResponse| { &m.ret },
+                    |m: &mut EstimateGasResponse| { &mut m.ret },
+                ));
+                ::protobuf::reflect::MessageDescriptor::new::<EstimateGasResponse>(
+                    "EstimateGasResponse",
+                    fields,
+                    file_descriptor_proto()
+                )
+            })
+        }
+    }
+
+    fn default_instance() -> &'static EstimateGasResponse {
+        static mut instance: ::protobuf::lazy::Lazy<EstimateGasResponse> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const EstimateGasResponse,
+        };
+        unsafe {
+            instance.get(EstimateGasResponse::new)
+        }
+    }
+}
+
+impl ::protobuf::Clear for EstimateGasResponse {
+    fn clear(&mut self) {
+        self.gas = 0;
+        self.vm_error.clear();
+        self.ret.clear();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for EstimateGasResponse {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for EstimateGasResponse {
+    fn as_ref(&self) -> ::protobuf::reflect::ProtobufValueRef {
+        ::protobuf::reflect::ProtobufValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct SGXVMStateDiffRequest {
+    // message fields
+    pub params: ::protobuf::SingularPtrField<SGXVMCallParams>,
+    pub context: ::protobuf::SingularPtrField<TransactionContext>,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a SGXVMStateDiffRequest {
+    fn default() -> &'a SGXVMStateDiffRequest {
+        <SGXVMStateDiffRequest as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl SGXVMStateDiffRequest {
+    pub fn new() -> SGXVMStateDiffRequest {
+        ::std::default::Default::default()
+    }
+
+    // .ffi.ffi.SGXVMCallParams params = 1;
+
+
+    pub fn get_params(&self) -> &SGXVMCallParams {
+        self.params.as_ref().unwrap_or_else(|| SGXVMCallParams::default_instance())
+    }
+    pub fn clear_params(&mut self) {
+        self.params.clear();
+    }
+
+    pub fn has_params(&self) -> bool {
+        self.params.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_params(&mut self, v: SGXVMCallParams) {
+        self.params = ::protobuf::SingularPtrField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_params(&mut self) -> &mut SGXVMCallParams {
+        if self.params.is_none() {
+            self.params.set_default();
+        }
+        self.params.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_params(&mut self) -> SGXVMCallParams {
+        self.params.take().unwrap_or_else(|| SGXVMCallParams::new())
+    }
+
+    // .ffi.ffi.TransactionContext context = 2;
+
+
+    pub fn get_context(&self) -> &TransactionContext {
+        self.context.as_ref().unwrap_or_else(|| TransactionContext::default_instance())
+    }
+    pub fn clear_context(&mut self) {
+        self.context.clear();
+    }
+
+    pub fn has_context(&self) -> bool {
+        self.context.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_context(&mut self, v: TransactionContext) {
+        self.context = ::protobuf::SingularPtrField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_context(&mut self) -> &mut TransactionContext {
+        if self.context.is_none() {
+            self.context.set_default();
+        }
+        self.context.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_context(&mut self) -> TransactionContext {
+        self.context.take().unwrap_or_else(|| TransactionContext::new())
+    }
+}
+
+impl ::protobuf::Message for SGXVMStateDiffRequest {
+    fn is_initialized(&self) -> bool {
+        for v in &self.params {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        for v in &self.context {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_singular_message_into(wire_type, is, &mut self.params)?;
+                },
+                2 => {
+                    ::protobuf::rt::read_singular_message_into(wire_type, is, &mut self.context)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if let Some(ref v) = self.params.as_ref() {
+            let len = v.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        }
+        if let Some(ref v) = self.context.as_ref() {
+            let len = v.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if let Some(ref v) = self.params.as_ref() {
+            os.write_tag(1, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        }
+        if let Some(ref v) = self.context.as_ref() {
+            os.write_tag(2, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> SGXVMStateDiffRequest {
+        SGXVMStateDiffRequest::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const ::protobuf::reflect::MessageDescriptor,
+        };
+        unsafe {
+            descriptor.get(|| {
+                let mut fields = ::std::vec::Vec::new();
+                fields.push(::protobuf::reflect::accessor::make_singular_ptr_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<SGXVMCallParams>>(
+                    "params",
+                    |m: &SGXVMStateDiffRequest| { &m.params },
+                    |m: &mut SGXVMStateDiffRequest| { &mut m.params },
+                ));
+                fields.push(::protobuf::reflect::accessor::make_singular_ptr_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<TransactionContext>>(
+                    "context",
+                    |m: &SGXVMStateDiffRequest| { &m.context },
+                    |m: &mut SGXVMStateDiffRequest| { &mut m.context },
+                ));
+                ::protobuf::reflect::MessageDescriptor::new::<SGXVMStateDiffRequest>(
+                    "SGXVMStateDiffRequest",
+                    fields,
+                    file_descriptor_proto()
+                )
+            })
+        }
+    }
+
+    fn default_instance() -> &'static SGXVMStateDiffRequest {
+        static mut instance: ::protobuf::lazy::Lazy<SGXVMStateDiffRequest> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const SGXVMStateDiffRequest,
+        };
+        unsafe {
+            instance.get(SGXVMStateDiffRequest::new)
+        }
+    }
+}
+
+impl ::protobuf::Clear for SGXVMStateDiffRequest {
+    fn clear(&mut self) {
+        self.params.clear();
+        self.context.clear();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for SGXVMStateDiffRequest {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for SGXVMStateDiffRequest {
+    fn as_ref(&self) -> ::protobuf::reflect::ProtobufValueRef {
+        ::protobuf::reflect::ProtobufValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct StorageDiff {
+    // message fields
+    pub key: ::std::vec::Vec<u8>,
+    pub originalValue: ::std::vec::Vec<u8>,
+    pub value: ::std::vec::Vec<u8>,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a StorageDiff {
+    fn default() -> &'a StorageDiff {
+        <StorageDiff as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl StorageDiff {
+    pub fn new() -> StorageDiff {
+        ::std::default::Default::default()
+    }
+
+    // bytes key = 1;
+
+
+    pub fn get_key(&self) -> &[u8] {
+        &self.key
+    }
+    pub fn clear_key(&mut self) {
+        self.key.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_key(&mut self, v: ::std::vec::Vec<u8>) {
+        self.key = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_key(&mut self) -> &mut ::std::vec::Vec<u8> {
+        &mut self.key
+    }
+
+    // Take field
+    pub fn take_key(&mut self) -> ::std::vec::Vec<u8> {
+        ::std::mem::replace(&mut self.key, ::std::vec::Vec::new())
+    }
+
+    // bytes originalValue = 2;
+
+
+    pub fn get_originalValue(&self) -> &[u8] {
+        &self.originalValue
+    }
+    pub fn clear_originalValue(&mut self) {
+        self.originalValue.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_originalValue(&mut self, v: ::std::vec::Vec<u8>) {
+        self.originalValue = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_originalValue(&mut self) -> &mut ::std::vec::Vec<u8> {
+        &mut self.originalValue
+    }
+
+    // Take field
+    pub fn take_originalValue(&mut self) -> ::std::vec::Vec<u8> {
+        ::std::mem::replace(&mut self.originalValue, ::std::vec::Vec::new())
+    }
+
+    // bytes value = 3;
+
+
+    pub fn get_value(&self) -> &[u8] {
+        &self.value
+    }
+    pub fn clear_value(&mut self) {
+        self.value.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_value(&mut self, v: ::std::vec::Vec<u8>) {
+        self.value = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_value(&mut self) -> &mut ::std::vec::Vec<u8> {
+        &mut self.value
+    }
+
+    // Take field
+    pub fn take_value(&mut self) -> ::std::vec::Vec<u8> {
+        ::std::mem::replace(&mut self.value, ::std::vec::Vec::new())
+    }
+}
+
+impl ::protobuf::Message for StorageDiff {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_singular_proto3_bytes_into(wire_type, is, &mut self.key)?;
+                },
+                2 => {
+                    ::protobuf::rt::read_singular_proto3_bytes_into(wire_type, is, &mut self.originalValue)?;
+                },
+                3 => {
+                    ::protobuf::rt::read_singular_proto3_bytes_into(wire_type, is, &mut self.value)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if !self.key.is_empty() {
+            my_size += ::protobuf::rt::bytes_size(1, &self.key);
+        }
+        if !self.originalValue.is_empty() {
+            my_size += ::protobuf::rt::bytes_size(2, &self.originalValue);
+        }
+        if !self.value.is_empty() {
+            my_size += ::protobuf::rt::bytes_size(3, &self.value);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if !self.key.is_empty() {
+            os.write_bytes(1, &self.key)?;
+        }
+        if !self.originalValue.is_empty() {
+            os.write_bytes(2, &self.originalValue)?;
+        }
+        if !self.value.is_empty() {
+            os.write_bytes(3, &self.value)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> StorageDiff {
+        StorageDiff::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const ::protobuf::reflect::MessageDescriptor,
+        };
+        unsafe {
+            descriptor.get(|| {
+                let mut fields = ::std::vec::Vec::new();
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeBytes>(
+                    "key",
+                    |m: &StorageDiff| { &m.key },
+                    |m: &mut StorageDiff| { &mut m.key },
+                ));
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeBytes>(
+                    "originalValue",
+                    |m: &StorageDiff| { &m.originalValue },
+                    |m: &mut StorageDiff| { &mut m.originalValue },
+                ));
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeBytes>(
+                    "value",
+                    |m: &StorageDiff| { &m.value },
+                    |m: &mut StorageDiff| { &mut m.value },
+                ));
+                ::protobuf::reflect::MessageDescriptor::new::<StorageDiff>(
+                    "StorageDiff",
+                    fields,
+                    file_descriptor_proto()
+                )
+            })
+        }
+    }
+
+    fn default_instance() -> &'static StorageDiff {
+        static mut instance: ::protobuf::lazy::Lazy<StorageDiff> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const StorageDiff,
+        };
+        unsafe {
+            instance.get(StorageDiff::new)
+        }
+    }
+}
+
+impl ::protobuf::Clear for StorageDiff {
+    fn clear(&mut self) {
+        self.key.clear();
+        self.originalValue.clear();
+        self.value.clear();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for StorageDiff {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for StorageDiff {
+    fn as_ref(&self) -> ::protobuf::reflect::ProtobufValueRef {
+        ::protobuf::reflect::ProtobufValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct AccountDiff {
+    // message fields
+    pub address: ::std::vec::Vec<u8>,
+    pub balanceBefore: ::std::vec::Vec<u8>,
+    pub balanceAfter: ::std::vec::Vec<u8>,
+    pub nonceBefore: u64,
+    pub nonceAfter: u64,
+    pub code: ::std::vec::Vec<u8>,
+    pub deleted: bool,
+    pub storage: ::protobuf::RepeatedField<StorageDiff>,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a AccountDiff {
+    fn default() -> &'a AccountDiff {
+        <AccountDiff as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl AccountDiff {
+    pub fn new() -> AccountDiff {
+        ::std::default::Default::default()
+    }
+
+    // bytes address = 1;
+
+
+    pub fn get_address(&self) -> &[u8] {
+        &self.address
+    }
+    pub fn clear_address(&mut self) {
+        self.address.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_address(&mut self, v: ::std::vec::Vec<u8>) {
+        self.address = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_address(&mut self) -> &mut ::std::vec::Vec<u8> {
+        &mut self.address
+    }
+
+    // Take field
+    pub fn take_address(&mut self) -> ::std::vec::Vec<u8> {
+        ::std::mem::replace(&mut self.address, ::std::vec::Vec::new())
+    }
+
+    // bytes balanceBefore = 2;
+
+
+    pub fn get_balanceBefore(&self) -> &[u8] {
+        &self.balanceBefore
+    }
+    pub fn clear_balanceBefore(&mut self) {
+        self.balanceBefore.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_balanceBefore(&mut self, v: ::std::vec::Vec<u8>) {
+        self.balanceBefore = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_balanceBefore(&mut self) -> &mut ::std::vec::Vec<u8> {
+        &mut self.balanceBefore
+    }
+
+    // Take field
+    pub fn take_balanceBefore(&mut self) -> ::std::vec::Vec<u8> {
+        ::std::mem::replace(&mut self.balanceBefore, ::std::vec::Vec::new())
+    }
+
+    // bytes balanceAfter = 3;
+
+
+    pub fn get_balanceAfter(&self) -> &[u8] {
+        &self.balanceAfter
+    }
+    pub fn clear_balanceAfter(&mut self) {
+        self.balanceAfter.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_balanceAfter(&mut self, v: ::std::vec::Vec<u8>) {
+        self.balanceAfter = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_balanceAfter(&mut self) -> &mut ::std::vec::Vec<u8> {
+        &mut self.balanceAfter
+    }
+
+    // Take field
+    pub fn take_balanceAfter(&mut self) -> ::std::vec::Vec<u8> {
+        ::std::mem::replace(&mut self.balanceAfter, ::std::vec::Vec::new())
+    }
+
+    // uint64 nonceBefore = 4;
+
+
+    pub fn get_nonceBefore(&self) -> u64 {
+        self.nonceBefore
+    }
+    pub fn clear_nonceBefore(&mut self) {
+        self.nonceBefore = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_nonceBefore(&mut self, v: u64) {
+        self.nonceBefore = v;
+    }
+
+    // uint64 nonceAfter = 5;
+
+
+    pub fn get_nonceAfter(&self) -> u64 {
+        self.nonceAfter
+    }
+    pub fn clear_nonceAfter(&mut self) {
+        self.nonceAfter = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_nonceAfter(&mut self, v: u64) {
+        self.nonceAfter = v;
+    }
+
+    // bytes code = 6;
+
+
+    pub fn get_code(&self) -> &[u8] {
+        &self.code
+    }
+    pub fn clear_code(&mut self) {
+        self.code.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_code(&mut self, v: ::std::vec::Vec<u8>) {
+        self.code = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_code(&mut self) -> &mut ::std::vec::Vec<u8> {
+        &mut self.code
+    }
+
+    // Take field
+    pub fn take_code(&mut self) -> ::std::vec::Vec<u8> {
+        ::std::mem::replace(&mut self.code, ::std::vec::Vec::new())
+    }
+
+    // bool deleted = 7;
+
+
+    pub fn get_deleted(&self) -> bool {
+        self.deleted
+    }
+    pub fn clear_deleted(&mut self) {
+        self.deleted = false;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_deleted(&mut self, v: bool) {
+        self.deleted = v;
+    }
+
+    // repeated .ffi.ffi.StorageDiff storage = 8;
+
+
+    pub fn get_storage(&self) -> &[StorageDiff] {
+        &self.storage
+    }
+    pub fn clear_storage(&mut self) {
+        self.storage.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_storage(&mut self, v: ::protobuf::RepeatedField<StorageDiff>) {
+        self.storage = v;
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_storage(&mut self) -> &mut ::protobuf::RepeatedField<StorageDiff> {
+        &mut self.storage
+    }
+
+    // Take field
+    pub fn take_storage(&mut self) -> ::protobuf::RepeatedField<StorageDiff> {
+        ::std::mem::replace(&mut self.storage, ::protobuf::RepeatedField::new())
+    }
+}
+
+impl ::protobuf::Message for AccountDiff {
+    fn is_initialized(&self) -> bool {
+        for v in &self.storage {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_singular_proto3_bytes_into(wire_type, is, &mut self.address)?;
+                },
+                2 => {
+                    ::protobuf::rt::read_singular_proto3_bytes_into(wire_type, is, &mut self.balanceBefore)?;
+                },
+                3 => {
+                    ::protobuf::rt::read_singular_proto3_bytes_into(wire_type, is, &mut self.balanceAfter)?;
+                },
+                4 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_uint64()?;
+                    self.nonceBefore = tmp;
+                },
+                5 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_uint64()?;
+                    self.nonceAfter = tmp;
+                },
+                6 => {
+                    ::protobuf::rt::read_singular_proto3_bytes_into(wire_type, is, &mut self.code)?;
+                },
+                7 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_bool()?;
+                    self.deleted = tmp;
+                },
+                8 => {
+                    ::protobuf::rt::read_repeated_message_into(wire_type, is, &mut self.storage)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if !self.address.is_empty() {
+            my_size += ::protobuf::rt::bytes_size(1, &self.address);
+        }
+        if !self.balanceBefore.is_empty() {
+            my_size += ::protobuf::rt::bytes_size(2, &self.balanceBefore);
+        }
+        if !self.balanceAfter.is_empty() {
+            my_size += ::protobuf::rt::bytes_size(3, &self.balanceAfter);
+        }
+        if self.nonceBefore != 0 {
+            my_size += ::protobuf::rt::value_size(4, self.nonceBefore, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if self.nonceAfter != 0 {
+            my_size += ::protobuf::rt::value_size(5, self.nonceAfter, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if !self.code.is_empty() {
+            my_size += ::protobuf::rt::bytes_size(6, &self.code);
+        }
+        if self.deleted != false {
+            my_size += 2;
+        }
+        for value in &self.storage {
+            let len = value.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        };
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if !self.address.is_empty() {
+            os.write_bytes(1, &self.address)?;
+        }
+        if !self.balanceBefore.is_empty() {
+            os.write_bytes(2, &self.balanceBefore)?;
+        }
+        if !self.balanceAfter.is_empty() {
+            os.write_bytes(3, &self.balanceAfter)?;
+        }
+        if self.nonceBefore != 0 {
+            os.write_uint64(4, self.nonceBefore)?;
+        }
+        if self.nonceAfter != 0 {
+            os.write_uint64(5, self.nonceAfter)?;
+        }
+        if !self.code.is_empty() {
+            os.write_bytes(6, &self.code)?;
+        }
+        if self.deleted != false {
+            os.write_bool(7, self.deleted)?;
+        }
+        for v in &self.storage {
+            os.write_tag(8, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        };
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> AccountDiff {
+        AccountDiff::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const ::protobuf::reflect::MessageDescriptor,
+        };
+        unsafe {
+            descriptor.get(|| {
+                let mut fields = ::std::vec::Vec::new();
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeBytes>(
+                    "address",
+                    |m: &AccountDiff| { &m.address },
+                    |m: &mut AccountDiff| { &mut m.address },
+                ));
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeBytes>(
+                    "balanceBefore",
+                    |m: &AccountDiff| { &m.balanceBefore },
+                    |m: &mut AccountDiff| { &mut m.balanceBefore },
+                ));
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeBytes>(
+                    "balanceAfter",
+                    |m: &AccountDiff| { &m.balanceAfter },
+                    |m: &mut AccountDiff| { &mut m.balanceAfter },
+                ));
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeUint64>(
+                    "nonceBefore",
+                    |m: &AccountDiff| { &m.nonceBefore },
+                    |m: &mut AccountDiff| { &mut m.nonceBefore },
+                ));
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeUint64>(
+                    "nonceAfter",
+                    |m: &AccountDiff| { &m.nonceAfter },
+                    |m: &mut AccountDiff| { &mut m.nonceAfter },
+                ));
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeBytes>(
+                    "code",
+                    |m: &AccountDiff| { &m.code },
+                    |m: &mut AccountDiff| { &mut m.code },
+                ));
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeBool>(
+                    "deleted",
+                    |m: &AccountDiff| { &m.deleted },
+                    |m: &mut AccountDiff| { &mut m.deleted },
+                ));
+                fields.push(::protobuf::reflect::accessor::make_repeated_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<StorageDiff>>(
+                    "storage",
+                    |m: &AccountDiff| { &m.storage },
+                    |m: &mut AccountDiff| { &mut m.storage },
+                ));
+                ::protobuf::reflect::MessageDescriptor::new::<AccountDiff>(
+                    "AccountDiff",
+                    fields,
+                    file_descriptor_proto()
+                )
+            })
+        }
+    }
+
+    fn default_instance() -> &'static AccountDiff {
+        static mut instance: ::protobuf::lazy::Lazy<AccountDiff> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const AccountDiff,
+        };
+        unsafe {
+            instance.get(AccountDiff::new)
+        }
+    }
+}
+
+impl ::protobuf::Clear for AccountDiff {
+    fn clear(&mut self) {
+        self.address.clear();
+        self.balanceBefore.clear();
+        self.balanceAfter.clear();
+        self.nonceBefore = 0;
+        self.nonceAfter = 0;
+        self.code.clear();
+        self.deleted = false;
+        self.storage.clear();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for AccountDiff {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for AccountDiff {
+    fn as_ref(&self) -> ::protobuf::reflect::ProtobufValueRef {
+        ::protobuf::reflect::ProtobufValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct StateDiffResponse {
+    // message fields
+    pub result: ::protobuf::SingularPtrField<HandleTransactionResponse>,
+    pub accounts: ::protobuf::RepeatedField<AccountDiff>,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a StateDiffResponse {
+    fn default() -> &'a StateDiffResponse {
+        <StateDiffResponse as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl StateDiffResponse {
+    pub fn new() -> StateDiffResponse {
+        ::std::default::Default::default()
+    }
+
+    // .ffi.ffi.HandleTransactionResponse result = 1;
+
+
+    pub fn get_result(&self) -> &HandleTransactionResponse {
+        self.result.as_ref().unwrap_or_else(|| HandleTransactionResponse::default_instance())
+    }
+    pub fn clear_result(&mut self) {
+        self.result.clear();
+    }
+
+    pub fn has_result(&self) -> bool {
+        self.result.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_result(&mut self, v: HandleTransactionResponse) {
+        self.result = ::protobuf::SingularPtrField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_result(&mut self) -> &mut HandleTransactionResponse {
+        if self.result.is_none() {
+            self.result.set_default();
+        }
+        self.result.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_result(&mut self) -> HandleTransactionResponse {
+        self.result.take().unwrap_or_else(|| HandleTransactionResponse::new())
+    }
+
+    // repeated .ffi.ffi.AccountDiff accounts = 2;
+
+
+    pub fn get_accounts(&self) -> &[AccountDiff] {
+        &self.accounts
+    }
+    pub fn clear_accounts(&mut self) {
+        self.accounts.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_accounts(&mut self, v: ::protobuf::RepeatedField<AccountDiff>) {
+        self.accounts = v;
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_accounts(&mut self) -> &mut ::protobuf::RepeatedField<AccountDiff> {
+        &mut self.accounts
+    }
+
+    // Take field
+    pub fn take_accounts(&mut self) -> ::protobuf::RepeatedField<AccountDiff> {
+        ::std::mem::replace(&mut self.accounts, ::protobuf::RepeatedField::new())
+    }
+}
+
+impl ::protobuf::Message for StateDiffResponse {
+    fn is_initialized(&self) -> bool {
+        for v in &self.result {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        for v in &self.accounts {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_singular_message_into(wire_type, is, &mut self.result)?;
+                },
+                2 => {
+                    ::protobuf::rt::read_repeated_message_into(wire_type, is, &mut self.accounts)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if let Some(ref v) = self.result.as_ref() {
+            let len = v.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        }
+        for value in &self.accounts {
+            let len = value.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        };
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if let Some(ref v) = self.result.as_ref() {
+            os.write_tag(1, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        }
+        for v in &self.accounts {
+            os.write_tag(2, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        };
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> StateDiffResponse {
+        StateDiffResponse::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const ::protobuf::reflect::MessageDescriptor,
+        };
+        unsafe {
+            descriptor.get(|| {
+                let mut fields = ::std::vec::Vec::new();
+                fields.push(::protobuf::reflect::accessor::make_singular_ptr_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<HandleTransactionResponse>>(
+                    "result",
+                    |m: &StateDiffResponse| { &m.result },
+                    |m: &mut StateDiffResponse| { &mut m.result },
+                ));
+                fields.push(::protobuf::reflect::accessor::make_repeated_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<AccountDiff>>(
+                    "accounts",
+                    |m: &StateDiffResponse| { &m.accounts },
+                    |m: &mut StateDiffResponse| { &mut m.accounts },
+                ));
+                ::protobuf::reflect::MessageDescriptor::new::<StateDiffResponse>(
+                    "StateDiffResponse",
+                    fields,
+                    file_descriptor_proto()
+                )
+            })
+        }
+    }
+
+    fn default_instance() -> &'static StateDiffResponse {
+        static mut instance: ::protobuf::lazy::Lazy<StateDiffResponse> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const StateDiffResponse,
+        };
+        unsafe {
+            instance.get(StateDiffResponse::new)
+        }
+    }
+}
+
+impl ::protobuf::Clear for StateDiffResponse {
+    fn clear(&mut self) {
+        self.result.clear();
+        self.accounts.clear();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for StateDiffResponse {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for StateDiffResponse {
+    fn as_ref(&self) -> ::protobuf::reflect::ProtobufValueRef {
+        ::protobuf::reflect::ProtobufValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct SGXVMCreateAccessListRequest {
+    // message fields
+    pub params: ::protobuf::SingularPtrField<SGXVMCallParams>,
+    pub context: ::protobuf::SingularPtrField<TransactionContext>,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a SGXVMCreateAccessListRequest {
+    fn default() -> &'a SGXVMCreateAccessListRequest {
+        <SGXVMCreateAccessListRequest as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl SGXVMCreateAccessListRequest {
+    pub fn new() -> SGXVMCreateAccessListRequest {
+        ::std::default::Default::default()
+    }
+
+    // .ffi.ffi.SGXVMCallParams params = 1;
+
+
+    pub fn get_params(&self) -> &SGXVMCallParams {
+        self.params.as_ref().unwrap_or_else(|| SGXVMCallParams::default_instance())
+    }
+    pub fn clear_params(&mut self) {
+        self.params.clear();
+    }
+
+    pub fn has_params(&self) -> bool {
+        self.params.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_params(&mut self, v: SGXVMCallParams) {
+        self.params = ::protobuf::SingularPtrField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_params(&mut self) -> &mut SGXVMCallParams {
+        if self.params.is_none() {
+            self.params.set_default();
+        }
+        self.params.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_params(&mut self) -> SGXVMCallParams {
+        self.params.take().unwrap_or_else(|| SGXVMCallParams::new())
+    }
+
+    // .ffi.ffi.TransactionContext context = 2;
+
+
+    pub fn get_context(&self) -> &TransactionContext {
+        self.context.as_ref().unwrap_or_else(|| TransactionContext::default_instance())
+    }
+    pub fn clear_context(&mut self) {
+        self.context.clear();
+    }
+
+    pub fn has_context(&self) -> bool {
+        self.context.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_context(&mut self, v: TransactionContext) {
+        self.context = ::protobuf::SingularPtrField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_context(&mut self) -> &mut TransactionContext {
+        if self.context.is_none() {
+            self.context.set_default();
+        }
+        self.context.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_context(&mut self) -> TransactionContext {
+        self.context.take().unwrap_or_else(|| TransactionContext::new())
+    }
+}
+
+impl ::protobuf::Message for SGXVMCreateAccessListRequest {
+    fn is_initialized(&self) -> bool {
+        for v in &self.params {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        for v in &self.context {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_singular_message_into(wire_type, is, &mut self.params)?;
+                },
+                2 => {
+                    ::protobuf::rt::read_singular_message_into(wire_type, is, &mut self.context)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if let Some(ref v) = self.params.as_ref() {
+            let len = v.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        }
+        if let Some(ref v) = self.context.as_ref() {
+            let len = v.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if let Some(ref v) = self.params.as_ref() {
+            os.write_tag(1, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        }
+        if let Some(ref v) = self.context.as_ref() {
+            os.write_tag(2, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> SGXVMCreateAccessListRequest {
+        SGXVMCreateAccessListRequest::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const ::protobuf::reflect::MessageDescriptor,
+        };
+        unsafe {
+            descriptor.get(|| {
+                let mut fields = ::std::vec::Vec::new();
+                fields.push(::protobuf::reflect::accessor::make_singular_ptr_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<SGXVMCallParams>>(
+                    "params",
+                    |m: &SGXVMCreateAccessListRequest| { &m.params },
+                    |m: &mut SGXVMCreateAccessListRequest| { &mut m.params },
+                ));
+                fields.push(::protobuf::reflect::accessor::make_singular_ptr_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<TransactionContext>>(
+                    "context",
+                    |m: &SGXVMCreateAccessListRequest| { &m.context },
+                    |m: &mut SGXVMCreateAccessListRequest| { &mut m.context },
+                ));
+                ::protobuf::reflect::MessageDescriptor::new::<SGXVMCreateAccessListRequest>(
+                    "SGXVMCreateAccessListRequest",
+                    fields,
+                    file_descriptor_proto()
+                )
+            })
+        }
+    }
+
+    fn default_instance() -> &'static SGXVMCreateAccessListRequest {
+        static mut instance: ::protobuf::lazy::Lazy<SGXVMCreateAccessListRequest> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const SGXVMCreateAccessListRequest,
+        };
+        unsafe {
+            instance.get(SGXVMCreateAccessListRequest::new)
+        }
+    }
+}
+
+impl ::protobuf::Clear for SGXVMCreateAccessListRequest {
+    fn clear(&mut self) {
+        self.params.clear();
+        self.context.clear();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for SGXVMCreateAccessListRequest {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for SGXVMCreateAccessListRequest {
+    fn as_ref(&self) -> ::protobuf::reflect::ProtobufValueRef {
+        ::protobuf::reflect::ProtobufValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct CreateAccessListResponse {
+    // message fields
+    pub result: ::protobuf::SingularPtrField<HandleTransactionResponse>,
+    pub accessList: ::protobuf::RepeatedField<AccessListItem>,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a CreateAccessListResponse {
+    fn default() -> &'a CreateAccessListResponse {
+        <CreateAccessListResponse as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl CreateAccessListResponse {
+    pub fn new() -> CreateAccessListResponse {
+        ::std::default::Default::default()
+    }
+
+    // .ffi.ffi.HandleTransactionResponse result = 1;
+
+
+    pub fn get_result(&self) -> &HandleTransactionResponse {
+        self.result.as_ref().unwrap_or_else(|| HandleTransactionResponse::default_instance())
+    }
+    pub fn clear_result(&mut self) {
+        self.result.clear();
+    }
+
+    pub fn has_result(&self) -> bool {
+        self.result.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_result(&mut self, v: HandleTransactionResponse) {
+        self.result = ::protobuf::SingularPtrField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_result(&mut self) -> &mut HandleTransactionResponse {
+        if self.result.is_none() {
+            self.result.set_default();
+        }
+        self.result.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_result(&mut self) -> HandleTransactionResponse {
+        self.result.take().unwrap_or_else(|| HandleTransactionResponse::new())
+    }
+
+    // repeated .ffi.ffi.AccessListItem accessList = 2;
+
+
+    pub fn get_accessList(&self) -> &[AccessListItem] {
+        &self.accessList
+    }
+    pub fn clear_accessList(&mut self) {
+        self.accessList.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_accessList(&mut self, v: ::protobuf::RepeatedField<AccessListItem>) {
+        self.accessList = v;
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_accessList(&mut self) -> &mut ::protobuf::RepeatedField<AccessListItem> {
+        &mut self.accessList
+    }
+
+    // Take field
+    pub fn take_accessList(&mut self) -> ::protobuf::RepeatedField<AccessListItem> {
+        ::std::mem::replace(&mut self.accessList, ::protobuf::RepeatedField::new())
+    }
+}
+
+impl ::protobuf::Message for CreateAccessListResponse {
+    fn is_initialized(&self) -> bool {
+        for v in &self.result {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        for v in &self.accessList {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_singular_message_into(wire_type, is, &mut self.result)?;
+                },
+                2 => {
+                    ::protobuf::rt::read_repeated_message_into(wire_type, is, &mut self.accessList)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if let Some(ref v) = self.result.as_ref() {
+            let len = v.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        }
+        for value in &self.accessList {
+            let len = value.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        };
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if let Some(ref v) = self.result.as_ref() {
+            os.write_tag(1, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        }
+        for v in &self.accessList {
+            os.write_tag(2, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        };
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> CreateAccessListResponse {
+        CreateAccessListResponse::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const ::protobuf::reflect::MessageDescriptor,
+        };
+        unsafe {
+            descriptor.get(|| {
+                let mut fields = ::std::vec::Vec::new();
+                fields.push(::protobuf::reflect::accessor::make_singular_ptr_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<HandleTransactionResponse>>(
+                    "result",
+                    |m: &CreateAccessListResponse| { &m.result },
+                    |m: &mut CreateAccessListResponse| { &mut m.result },
+                ));
+                fields.push(::protobuf::reflect::accessor::make_repeated_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<AccessListItem>>(
+                    "accessList",
+                    |m: &CreateAccessListResponse| { &m.accessList },
+                    |m: &mut CreateAccessListResponse| { &mut m.accessList },
+                ));
+                ::protobuf::reflect::MessageDescriptor::new::<CreateAccessListResponse>(
+                    "CreateAccessListResponse",
+                    fields,
+                    file_descriptor_proto()
+                )
+            })
+        }
+    }
+
+    fn default_instance() -> &'static CreateAccessListResponse {
+        static mut instance: ::protobuf::lazy::Lazy<CreateAccessListResponse> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const CreateAccessListResponse,
+        };
+        unsafe {
+            instance.get(CreateAccessListResponse::new)
+        }
+    }
+}
+
+impl ::protobuf::Clear for CreateAccessListResponse {
+    fn clear(&mut self) {
+        self.result.clear();
+        self.accessList.clear();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for CreateAccessListResponse {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for CreateAccessListResponse {
+    fn as_ref(&self) -> ::protobuf::reflect::ProtobufValueRef {
+        ::protobuf::reflect::ProtobufValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct SGXVMBatchTransaction {
+    // message oneof groups
+    pub tx: ::std::option::Option<SGXVMBatchTransaction_oneof_tx>,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a SGXVMBatchTransaction {
+    fn default() -> &'a SGXVMBatchTransaction {
+        <SGXVMBatchTransaction as ::protobuf::Message>::default_instance()
+    }
+}
+
+#[derive(Clone,PartialEq,Debug)]
+pub enum SGXVMBatchTransaction_oneof_tx {
+    call(SGXVMCallParams),
+    create(SGXVMCreateParams),
+}
+
+impl SGXVMBatchTransaction {
+    pub fn new() -> SGXVMBatchTransaction {
+        ::std::default::Default::default()
+    }
+
+    // .ffi.ffi.SGXVMCallParams call = 1;
+
+
+    pub fn get_call(&self) -> &SGXVMCallParams {
+        match self.tx {
+            ::std::option::Option::Some(SGXVMBatchTransaction_oneof_tx::call(ref v)) => v,
+            _ => SGXVMCallParams::default_instance(),
+        }
+    }
+    pub fn clear_call(&mut self) {
+        self.tx = ::std::option::Option::None;
+    }
+
+    pub fn has_call(&self) -> bool {
+        match self.tx {
+            ::std::option::Option::Some(SGXVMBatchTransaction_oneof_tx::call(..)) => true,
+            _ => false,
+        }
+    }
+
+    // Param is passed by value, moved
+    pub fn set_call(&mut self, v: SGXVMCallParams) {
+        self.tx = ::std::option::Option::Some(SGXVMBatchTransaction_oneof_tx::call(v))
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_call(&mut self) -> &mut SGXVMCallParams {
+        if let ::std::option::Option::Some(SGXVMBatchTransaction_oneof_tx::call(_)) = self.tx {
+        } else {
+            self.tx = ::std::option::Option::Some(SGXVMBatchTransaction_oneof_tx::call(SGXVMCallParams::new()));
+        }
+        match self.tx {
+            ::std::option::Option::Some(SGXVMBatchTransaction_oneof_tx::call(ref mut v)) => v,
+            _ => panic!(),
+        }
+    }
+
+    // Take field
+    pub fn take_call(&mut self) -> SGXVMCallParams {
+        if self.has_call() {
+            match self.tx.take() {
+                ::std::option::Option::Some(SGXVMBatchTransaction_oneof_tx::call(v)) => v,
+                _ => panic!(),
+            }
+        } else {
+            SGXVMCallParams::new()
+        }
+    }
+
+    // .ffi.ffi.SGXVMCreateParams create = 2;
+
+
+    pub fn get_create(&self) -> &SGXVMCreateParams {
+        match self.tx {
+            ::std::option::Option::Some(SGXVMBatchTransaction_oneof_tx::create(ref v)) => v,
+            _ => SGXVMCreateParams::default_instance(),
+        }
+    }
+    pub fn clear_create(&mut self) {
+        self.tx = ::std::option::Option::None;
+    }
+
+    pub fn has_create(&self) -> bool {
+        match self.tx {
+            ::std::option::Option::Some(SGXVMBatchTransaction_oneof_tx::create(..)) => true,
+            _ => false,
+        }
+    }
+
+    // Param is passed by value, moved
+    pub fn set_create(&mut self, v: SGXVMCreateParams) {
+        self.tx = ::std::option::Option::Some(SGXVMBatchTransaction_oneof_tx::create(v))
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_create(&mut self) -> &mut SGXVMCreateParams {
+        if let ::std::option::Option::Some(SGXVMBatchTransaction_oneof_tx::create(_)) = self.tx {
+        } else {
+            self.tx = ::std::option::Option::Some(SGXVMBatchTransaction_oneof_tx::create(SGXVMCreateParams::new()));
+        }
+        match self.tx {
+            ::std::option::Option::Some(SGXVMBatchTransaction_oneof_tx::create(ref mut v)) => v,
+            _ => panic!(),
+        }
+    }
+
+    // Take field
+    pub fn take_create(&mut self) -> SGXVMCreateParams {
+        if self.has_create() {
+            match self.tx.take() {
+                ::std::option::Option::Some(SGXVMBatchTransaction_oneof_tx::create(v)) => v,
+                _ => panic!(),
+            }
+        } else {
+            SGXVMCreateParams::new()
+        }
+    }
+}
+
+impl ::protobuf::Message for SGXVMBatchTransaction {
+    fn is_initialized(&self) -> bool {
+        if let Some(SGXVMBatchTransaction_oneof_tx::call(ref v)) = self.tx {
+            if !v.is_initialized() {
+                return false;
+            }
+        }
+        if let Some(SGXVMBatchTransaction_oneof_tx::create(ref v)) = self.tx {
+            if !v.is_initialized() {
+                return false;
+            }
+        }
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeLengthDelimited {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    self.tx = ::std::option::Option::Some(SGXVMBatchTransaction_oneof_tx::call(is.read_message()?));
+                },
+                2 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeLengthDelimited {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    self.tx = ::std::option::Option::Some(SGXVMBatchTransaction_oneof_tx::create(is.read_message()?));
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if let ::std::option::Option::Some(ref v) = self.tx {
+            match v {
+                &SGXVMBatchTransaction_oneof_tx::call(ref v) => {
+                    let len = v.compute_size();
+                    my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+                },
+                &SGXVMBatchTransaction_oneof_tx::create(ref v) => {
+                    let len = v.compute_size();
+                    my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+                },
+            };
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if let ::std::option::Option::Some(ref v) = self.tx {
+            match v {
+                &SGXVMBatchTransaction_oneof_tx::call(ref v) => {
+                    os.write_tag(1, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+                    os.write_raw_varint32(v.get_cached_size())?;
+                    v.write_to_with_cached_sizes(os)?;
+                },
+                &SGXVMBatchTransaction_oneof_tx::create(ref v) => {
+                    os.write_tag(2, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+                    os.write_raw_varint32(v.get_cached_size())?;
+                    v.write_to_with_cached_sizes(os)?;
+                },
+            };
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> SGXVMBatchTransaction {
+        SGXVMBatchTransaction::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const ::protobuf::reflect::MessageDescriptor,
+        };
+        unsafe {
+            descriptor.get(|| {
+                let mut fields = ::std::vec::Vec::new();
+                fields.push(::protobuf::reflect::accessor::make_singular_message_accessor::<_, SGXVMCallParams>(
+                    "call",
+                    SGXVMBatchTransaction::has_call,
+                    SGXVMBatchTransaction::get_call,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_singular_message_accessor::<_, SGXVMCreateParams>(
+                    "create",
+                    SGXVMBatchTransaction::has_create,
+                    SGXVMBatchTransaction::get_create,
+                ));
+                ::protobuf::reflect::MessageDescriptor::new::<SGXVMBatchTransaction>(
+                    "SGXVMBatchTransaction",
+                    fields,
+                    file_descriptor_proto()
+                )
+            })
+        }
+    }
+
+    fn default_instance() -> &'static SGXVMBatchTransaction {
+        static mut instance: ::protobuf::lazy::Lazy<SGXVMBatchTransaction> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const SGXVMBatchTransaction,
+        };
+        unsafe {
+            instance.get(SGXVMBatchTransaction::new)
+        }
+    }
+}
+
+impl ::protobuf::Clear for SGXVMBatchTransaction {
+    fn clear(&mut self) {
+        self.tx = ::std::option::Option::None;
+        self.tx = ::std::option::Option::None;
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for SGXVMBatchTransaction {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for SGXVMBatchTransaction {
+    fn as_ref(&self) -> ::protobuf::reflect::ProtobufValueRef {
+        ::protobuf::reflect::ProtobufValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct SGXVMBatchRequest {
+    // message fields
+    pub transactions: ::protobuf::RepeatedField<SGXVMBatchTransaction>,
+    pub context: ::protobuf::SingularPtrField<TransactionContext>,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a SGXVMBatchRequest {
+    fn default() -> &'a SGXVMBatchRequest {
+        <SGXVMBatchRequest as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl SGXVMBatchRequest {
+    pub fn new() -> SGXVMBatchRequest {
+        ::std::default::Default::default()
+    }
+
+    // repeated .ffi.ffi.SGXVMBatchTransaction transactions = 1;
+
+
+    pub fn get_transactions(&self) -> &[SGXVMBatchTransaction] {
+        &self.transactions
+    }
+    pub fn clear_transactions(&mut self) {
+        self.transactions.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_transactions(&mut self, v: ::protobuf::RepeatedField<SGXVMBatchTransaction>) {
+        self.transactions = v;
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_transactions(&mut self) -> &mut ::protobuf::RepeatedField<SGXVMBatchTransaction> {
+        &mut self.transactions
+    }
+
+    // Take field
+    pub fn take_transactions(&mut self) -> ::protobuf::RepeatedField<SGXVMBatchTransaction> {
+        ::std::mem::replace(&mut self.transactions, ::protobuf::RepeatedField::new())
+    }
+
+    // .ffi.ffi.TransactionContext context = 2;
+
+
+    pub fn get_context(&self) -> &TransactionContext {
+        self.context.as_ref().unwrap_or_else(|| TransactionContext::default_instance())
+    }
+    pub fn clear_context(&mut self) {
+        self.context.clear();
+    }
+
+    pub fn has_context(&self) -> bool {
+        self.context.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_context(&mut self, v: TransactionContext) {
+        self.context = ::protobuf::SingularPtrField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_context(&mut self) -> &mut TransactionContext {
+        if self.context.is_none() {
+            self.context.set_default();
+        }
+        self.context.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_context(&mut self) -> TransactionContext {
+        self.context.take().unwrap_or_else(|| TransactionContext::new())
+    }
+}
+
+impl ::protobuf::Message for SGXVMBatchRequest {
+    fn is_initialized(&self) -> bool {
+        for v in &self.transactions {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        for v in &self.context {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_repeated_message_into(wire_type, is, &mut self.transactions)?;
+                },
+                2 => {
+                    ::protobuf::rt::read_singular_message_into(wire_type, is, &mut self.context)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        for value in &self.transactions {
+            let len = value.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        };
+        if let Some(ref v) = self.context.as_ref() {
+            let len = v.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        for v in &self.transactions {
+            os.write_tag(1, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        };
+        if let Some(ref v) = self.context.as_ref() {
+            os.write_tag(2, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> SGXVMBatchRequest {
+        SGXVMBatchRequest::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const ::protobuf::reflect::MessageDescriptor,
+        };
+        unsafe {
+            descriptor.get(|| {
+                let mut fields = ::std::vec::Vec::new();
+                fields.push(::protobuf::reflect::accessor::make_repeated_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<SGXVMBatchTransaction>>(
+                    "transactions",
+                    |m: &SGXVMBatchRequest| { &m.transactions },
+                    |m: &mut SGXVMBatchRequest| { &mut m.transactions },
+                ));
+                fields.push(::protobuf::reflect::accessor::make_singular_ptr_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<TransactionContext>>(
+                    "context",
+                    |m: &SGXVMBatchRequest| { &m.context },
+                    |m: &mut SGXVMBatchRequest| { &mut m.context },
+                ));
+                ::protobuf::reflect::MessageDescriptor::new::<SGXVMBatchRequest>(
+                    "SGXVMBatchRequest",
+                    fields,
+                    file_descriptor_proto()
+                )
+            })
+        }
+    }
+
+    fn default_instance() -> &'static SGXVMBatchRequest {
+        static mut instance: ::protobuf::lazy::Lazy<SGXVMBatchRequest> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const SGXVMBatchRequest,
+        };
+        unsafe {
+            instance.get(SGXVMBatchRequest::new)
+        }
+    }
+}
+
+impl ::protobuf::Clear for SGXVMBatchRequest {
+    fn clear(&mut self) {
+        self.transactions.clear();
+        self.context.clear();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for SGXVMBatchRequest {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for SGXVMBatchRequest {
+    fn as_ref(&self) -> ::protobuf::reflect::ProtobufValueRef {
+        ::protobuf::reflect::ProtobufValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct BatchTransactionResult {
+    // message fields
+    pub result: ::protobuf::SingularPtrField<HandleTransactionResponse>,
+    pub cumulativeGasUsed: u64,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a BatchTransactionResult {
+    fn default() -> &'a BatchTransactionResult {
+        <BatchTransactionResult as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl BatchTransactionResult {
+    pub fn new() -> BatchTransactionResult {
+        ::std::default::Default::default()
+    }
+
+    // .ffi.ffi.HandleTransactionResponse result = 1;
+
+
+    pub fn get_result(&self) -> &HandleTransactionResponse {
+        self.result.as_ref().unwrap_or_else(|| HandleTransactionResponse::default_instance())
+    }
+    pub fn clear_result(&mut self) {
+        self.result.clear();
+    }
+
+    pub fn has_result(&self) -> bool {
+        self.result.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_result(&mut self, v: HandleTransactionResponse) {
+        self.result = ::protobuf::SingularPtrField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_result(&mut self) -> &mut HandleTransactionResponse {
+        if self.result.is_none() {
+            self.result.set_default();
+        }
+        self.result.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_result(&mut self) -> HandleTransactionResponse {
+        self.result.take().unwrap_or_else(|| HandleTransactionResponse::new())
+    }
+
+    // uint64 cumulativeGasUsed = 2;
+
+
+    pub fn get_cumulativeGasUsed(&self) -> u64 {
+        self.cumulativeGasUsed
+    }
+    pub fn clear_cumulativeGasUsed(&mut self) {
+        self.cumulativeGasUsed = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_cumulativeGasUsed(&mut self, v: u64) {
+        self.cumulativeGasUsed = v;
+    }
+}
+
+impl ::protobuf::Message for BatchTransactionResult {
+    fn is_initialized(&self) -> bool {
+        for v in &self.result {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_singular_message_into(wire_type, is, &mut self.result)?;
+                },
+                2 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_uint64()?;
+                    self.cumulativeGasUsed = tmp;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if let Some(ref v) = self.result.as_ref() {
+            let len = v.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        }
+        if self.cumulativeGasUsed != 0 {
+            my_size += ::protobuf::rt::value_size(2, self.cumulativeGasUsed, ::protobuf::wire_format::WireTypeVarint);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if let Some(ref v) = self.result.as_ref() {
+            os.write_tag(1, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        }
+        if self.cumulativeGasUsed != 0 {
+            os.write_uint64(2, self.cumulativeGasUsed)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> BatchTransactionResult {
+        BatchTransactionResult::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const ::protobuf::reflect::MessageDescriptor,
+        };
+        unsafe {
+            descriptor.get(|| {
+                let mut fields = ::std::vec::Vec::new();
+                fields.push(::protobuf::reflect::accessor::make_singular_ptr_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<HandleTransactionResponse>>(
+                    "result",
+                    |m: &BatchTransactionResult| { &m.result },
+                    |m: &mut BatchTransactionResult| { &mut m.result },
+                ));
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeUint64>(
+                    "cumulativeGasUsed",
+                    |m: &BatchTransactionResult| { &m.cumulativeGasUsed },
+                    |m: &mut BatchTransactionResult| { &mut m.cumulativeGasUsed },
+                ));
+                ::protobuf::reflect::MessageDescriptor::new::<BatchTransactionResult>(
+                    "BatchTransactionResult",
+                    fields,
+                    file_descriptor_proto()
+                )
+            })
+        }
+    }
+
+    fn default_instance() -> &'static BatchTransactionResult {
+        static mut instance: ::protobuf::lazy::Lazy<BatchTransactionResult> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const BatchTransactionResult,
+        };
+        unsafe {
+            instance.get(BatchTransactionResult::new)
+        }
+    }
+}
+
+impl ::protobuf::Clear for BatchTransactionResult {
+    fn clear(&mut self) {
+        self.result.clear();
+        self.cumulativeGasUsed = 0;
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for BatchTransactionResult {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for BatchTransactionResult {
+    fn as_ref(&self) -> ::protobuf::reflect::ProtobufValueRef {
+        ::protobuf::reflect::ProtobufValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct BatchResponse {
+    // message fields
+    pub results: ::protobuf::RepeatedField<BatchTransactionResult>,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a BatchResponse {
+    fn default() -> &'a BatchResponse {
+        <BatchResponse as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl BatchResponse {
+    pub fn new() -> BatchResponse {
+        ::std::default::Default::default()
+    }
+
+    // repeated .ffi.ffi.BatchTransactionResult results = 1;
+
+
+    pub fn get_results(&self) -> &[BatchTransactionResult] {
+        &self.results
+    }
+    pub fn clear_results(&mut self) {
+        self.results.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_results(&mut self, v: ::protobuf::RepeatedField<BatchTransactionResult>) {
+        self.results = v;
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_results(&mut self) -> &mut ::protobuf::RepeatedField<BatchTransactionResult> {
+        &mut self.results
+    }
+
+    // Take field
+    pub fn take_results(&mut self) -> ::protobuf::RepeatedField<BatchTransactionResult> {
+        ::std::mem::replace(&mut self.results, ::protobuf::RepeatedField::new())
+    }
+}
+
+impl ::protobuf::Message for BatchResponse {
+    fn is_initialized(&self) -> bool {
+        for v in &self.results {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_repeated_message_into(wire_type, is, &mut self.results)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        for value in &self.results {
+            let len = value.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        };
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        for v in &self.results {
+            os.write_tag(1, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        };
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> BatchResponse {
+        BatchResponse::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const ::protobuf::reflect::MessageDescriptor,
+        };
+        unsafe {
+            descriptor.get(|| {
+                let mut fields = ::std::vec::Vec::new();
+                fields.push(::protobuf::reflect::accessor::make_repeated_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<BatchTransactionResult>>(
+                    "results",
+                    |m: &BatchResponse| { &m.results },
+                    |m: &mut BatchResponse| { &mut m.results },
+                ));
+                ::protobuf::reflect::MessageDescriptor::new::<BatchResponse>(
+                    "BatchResponse",
+                    fields,
+                    file_descriptor_proto()
+                )
+            })
+        }
+    }
+
+    fn default_instance() -> &'static BatchResponse {
+        static mut instance: ::protobuf::lazy::Lazy<BatchResponse> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const BatchResponse,
+        };
+        unsafe {
+            instance.get(BatchResponse::new)
+        }
+    }
+}
+
+impl ::protobuf::Clear for BatchResponse {
+    fn clear(&mut self) {
+        self.results.clear();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for BatchResponse {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for BatchResponse {
+    fn as_ref(&self) -> ::protobuf::reflect::ProtobufValueRef {
+        ::protobuf::reflect::ProtobufValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct SGXVMSignedTransactionRequest {
+    // message fields
+    pub rawTransaction: ::std::vec::Vec<u8>,
+    pub context: ::protobuf::SingularPtrField<TransactionContext>,
+    pub commit: bool,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a SGXVMSignedTransactionRequest {
+    fn default() -> &'a SGXVMSignedTransactionRequest {
+        <SGXVMSignedTransactionRequest as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl SGXVMSignedTransactionRequest {
+    pub fn new() -> SGXVMSignedTransactionRequest {
+        ::std::default::Default::default()
+    }
+
+    // bytes rawTransaction = 1;
+
+
+    pub fn get_rawTransaction(&self) -> &[u8] {
+        &self.rawTransaction
+    }
+    pub fn clear_rawTransaction(&mut self) {
+        self.rawTransaction.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_rawTransaction(&mut self, v: ::std::vec::Vec<u8>) {
+        self.rawTransaction = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_rawTransaction(&mut self) -> &mut ::std::vec::Vec<u8> {
+        &mut self.rawTransaction
+    }
+
+    // Take field
+    pub fn take_rawTransaction(&mut self) -> ::std::vec::Vec<u8> {
+        ::std::mem::replace(&mut self.rawTransaction, ::std::vec::Vec::new())
+    }
+
+    // .ffi.ffi.TransactionContext context = 2;
+
+
+    pub fn get_context(&self) -> &TransactionContext {
+        self.context.as_ref().unwrap_or_else(|| TransactionContext::default_instance())
+    }
+    pub fn clear_context(&mut self) {
+        self.context.clear();
+    }
+
+    pub fn has_context(&self) -> bool {
+        self.context.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_context(&mut self, v: TransactionContext) {
+        self.context = ::protobuf::SingularPtrField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_context(&mut self) -> &mut TransactionContext {
+        if self.context.is_none() {
+            self.context.set_default();
+        }
+        self.context.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_context(&mut self) -> TransactionContext {
+        self.context.take().unwrap_or_else(|| TransactionContext::new())
+    }
+
+    // bool commit = 3;
+
+
+    pub fn get_commit(&self) -> bool {
+        self.commit
+    }
+    pub fn clear_commit(&mut self) {
+        self.commit = false;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_commit(&mut self, v: bool) {
+        self.commit = v;
+    }
+}
+
+impl ::protobuf::Message for SGXVMSignedTransactionRequest {
+    fn is_initialized(&self) -> bool {
+        for v in &self.context {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_singular_proto3_bytes_into(wire_type, is, &mut self.rawTransaction)?;
                 },
-                &CosmosRequest_oneof_req::storageCell(ref v) => {
-                    os.write_tag(5, ::protobuf::wire_format::WireTypeLengthDelimited)?;
-                    os.write_raw_varint32(v.get_cached_size())?;
-                    v.write_to_with_cached_sizes(os)?;
+                2 => {
+                    ::protobuf::rt::read_singular_message_into(wire_type, is, &mut self.context)?;
                 },
-                &CosmosRequest_oneof_req::insertAccountCode(ref v) => {
-                    os.write_tag(6, ::protobuf::wire_format::WireTypeLengthDelimited)?;
-                    os.write_raw_varint32(v.get_cached_size())?;
-                    v.write_to_with_cached_sizes(os)?;
+                3 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_bool()?;
+                    self.commit = tmp;
                 },
-                &CosmosRequest_oneof_req::insertStorageCell(ref v) => {
-                    os.write_tag(7, ::protobuf::wire_format::WireTypeLengthDelimited)?;
-                    os.write_raw_varint32(v.get_cached_size())?;
-                    v.write_to_with_cached_sizes(os)?;
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
                 },
-                &CosmosRequest_oneof_req::remove(ref v) => {
-                    os.write_tag(8, ::protobuf::wire_format::WireTypeLengthDelimited)?;
-                    os.write_raw_varint32(v.get_cached_size())?;
-                    v.write_to_with_cached_sizes(os)?;
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if !self.rawTransaction.is_empty() {
+            my_size += ::protobuf::rt::bytes_size(1, &self.rawTransaction);
+        }
+        if let Some(ref v) = self.context.as_ref() {
+            let len = v.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        }
+        if self.commit != false {
+            my_size += 2;
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if !self.rawTransaction.is_empty() {
+            os.write_bytes(1, &self.rawTransaction)?;
+        }
+        if let Some(ref v) = self.context.as_ref() {
+            os.write_tag(2, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        }
+        if self.commit != false {
+            os.write_bool(3, self.commit)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> SGXVMSignedTransactionRequest {
+        SGXVMSignedTransactionRequest::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const ::protobuf::reflect::MessageDescriptor,
+        };
+        unsafe {
+            descriptor.get(|| {
+                let mut fields = ::std::vec::Vec::new();
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeBytes>(
+                    "rawTransaction",
+                    |m: &SGXVMSignedTransactionRequest| { &m.rawTransaction },
+                    |m: &mut SGXVMSignedTransactionRequest| { &mut m.rawTransaction },
+                ));
+                fields.push(::protobuf::reflect::accessor::make_singular_ptr_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<TransactionContext>>(
+                    "context",
+                    |m: &SGXVMSignedTransactionRequest| { &m.context },
+                    |m: &mut SGXVMSignedTransactionRequest| { &mut m.context },
+                ));
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeBool>(
+                    "commit",
+                    |m: &SGXVMSignedTransactionRequest| { &m.commit },
+                    |m: &mut SGXVMSignedTransactionRequest| { &mut m.commit },
+                ));
+                ::protobuf::reflect::MessageDescriptor::new::<SGXVMSignedTransactionRequest>(
+                    "SGXVMSignedTransactionRequest",
+                    fields,
+                    file_descriptor_proto()
+                )
+            })
+        }
+    }
+
+    fn default_instance() -> &'static SGXVMSignedTransactionRequest {
+        static mut instance: ::protobuf::lazy::Lazy<SGXVMSignedTransactionRequest> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const SGXVMSignedTransactionRequest,
+        };
+        unsafe {
+            instance.get(SGXVMSignedTransactionRequest::new)
+        }
+    }
+}
+
+impl ::protobuf::Clear for SGXVMSignedTransactionRequest {
+    fn clear(&mut self) {
+        self.rawTransaction.clear();
+        self.context.clear();
+        self.commit = false;
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for SGXVMSignedTransactionRequest {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for SGXVMSignedTransactionRequest {
+    fn as_ref(&self) -> ::protobuf::reflect::ProtobufValueRef {
+        ::protobuf::reflect::ProtobufValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct SignedTransactionResponse {
+    // message fields
+    pub result: ::protobuf::SingularPtrField<HandleTransactionResponse>,
+    pub from: ::std::vec::Vec<u8>,
+    pub hash: ::std::vec::Vec<u8>,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a SignedTransactionResponse {
+    fn default() -> &'a SignedTransactionResponse {
+        <SignedTransactionResponse as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl SignedTransactionResponse {
+    pub fn new() -> SignedTransactionResponse {
+        ::std::default::Default::default()
+    }
+
+    // .ffi.ffi.HandleTransactionResponse result = 1;
+
+
+    pub fn get_result(&self) -> &HandleTransactionResponse {
+        self.result.as_ref().unwrap_or_else(|| HandleTransactionResponse::default_instance())
+    }
+    pub fn clear_result(&mut self) {
+        self.result.clear();
+    }
+
+    pub fn has_result(&self) -> bool {
+        self.result.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_result(&mut self, v: HandleTransactionResponse) {
+        self.result = ::protobuf::SingularPtrField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_result(&mut self) -> &mut HandleTransactionResponse {
+        if self.result.is_none() {
+            self.result.set_default();
+        }
+        self.result.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_result(&mut self) -> HandleTransactionResponse {
+        self.result.take().unwrap_or_else(|| HandleTransactionResponse::new())
+    }
+
+    // bytes from = 2;
+
+
+    pub fn get_from(&self) -> &[u8] {
+        &self.from
+    }
+    pub fn clear_from(&mut self) {
+        self.from.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_from(&mut self, v: ::std::vec::Vec<u8>) {
+        self.from = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_from(&mut self) -> &mut ::std::vec::Vec<u8> {
+        &mut self.from
+    }
+
+    // Take field
+    pub fn take_from(&mut self) -> ::std::vec::Vec<u8> {
+        ::std::mem::replace(&mut self.from, ::std::vec::Vec::new())
+    }
+
+    // bytes hash = 3;
+
+
+    pub fn get_hash(&self) -> &[u8] {
+        &self.hash
+    }
+    pub fn clear_hash(&mut self) {
+        self.hash.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_hash(&mut self, v: ::std::vec::Vec<u8>) {
+        self.hash = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_hash(&mut self) -> &mut ::std::vec::Vec<u8> {
+        &mut self.hash
+    }
+
+    // Take field
+    pub fn take_hash(&mut self) -> ::std::vec::Vec<u8> {
+        ::std::mem::replace(&mut self.hash, ::std::vec::Vec::new())
+    }
+}
+
+impl ::protobuf::Message for SignedTransactionResponse {
+    fn is_initialized(&self) -> bool {
+        for v in &self.result {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_singular_message_into(wire_type, is, &mut self.result)?;
                 },
-                &CosmosRequest_oneof_req::removeStorageCell(ref v) => {
-                    os.write_tag(9, ::protobuf::wire_format::WireTypeLengthDelimited)?;
-                    os.write_raw_varint32(v.get_cached_size())?;
-                    v.write_to_with_cached_sizes(os)?;
+                2 => {
+                    ::protobuf::rt::read_singular_proto3_bytes_into(wire_type, is, &mut self.from)?;
                 },
-                &CosmosRequest_oneof_req::removeStorage(ref v) => {
-                    os.write_tag(10, ::protobuf::wire_format::WireTypeLengthDelimited)?;
-                    os.write_raw_varint32(v.get_cached_size())?;
-                    v.write_to_with_cached_sizes(os)?;
+                3 => {
+                    ::protobuf::rt::read_singular_proto3_bytes_into(wire_type, is, &mut self.hash)?;
                 },
-                &CosmosRequest_oneof_req::blockHash(ref v) => {
-                    os.write_tag(11, ::protobuf::wire_format::WireTypeLengthDelimited)?;
-                    os.write_raw_varint32(v.get_cached_size())?;
-                    v.write_to_with_cached_sizes(os)?;
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
                 },
             };
         }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if let Some(ref v) = self.result.as_ref() {
+            let len = v.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        }
+        if !self.from.is_empty() {
+            my_size += ::protobuf::rt::bytes_size(2, &self.from);
+        }
+        if !self.hash.is_empty() {
+            my_size += ::protobuf::rt::bytes_size(3, &self.hash);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if let Some(ref v) = self.result.as_ref() {
+            os.write_tag(1, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        }
+        if !self.from.is_empty() {
+            os.write_bytes(2, &self.from)?;
+        }
+        if !self.hash.is_empty() {
+            os.write_bytes(3, &self.hash)?;
+        }
         os.write_unknown_fields(self.get_unknown_fields())?;
         ::std::result::Result::Ok(())
     }
@@ -6611,8 +16712,8 @@ impl ::protobuf::Message for CosmosRequest {
         Self::descriptor_static()
     }
 
-    fn new() -> CosmosRequest {
-        CosmosRequest::new()
+    fn new() -> SignedTransactionResponse {
+        SignedTransactionResponse::new()
     }
 
     fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
@@ -6623,63 +16724,23 @@ impl ::protobuf::Message for CosmosRequest {
         unsafe {
             descriptor.get(|| {
                 let mut fields = ::std::vec::Vec::new();
-                fields.push(::protobuf::reflect::accessor::make_singular_message_accessor::<_, QueryGetAccount>(
-                    "getAccount",
-                    CosmosRequest::has_getAccount,
-                    CosmosRequest::get_getAccount,
-                ));
-                fields.push(::protobuf::reflect::accessor::make_singular_message_accessor::<_, QueryInsertAccount>(
-                    "insertAccount",
-                    CosmosRequest::has_insertAccount,
-                    CosmosRequest::get_insertAccount,
-                ));
-                fields.push(::protobuf::reflect::accessor::make_singular_message_accessor::<_, QueryContainsKey>(
-                    "containsKey",
-                    CosmosRequest::has_containsKey,
-                    CosmosRequest::get_containsKey,
-                ));
-                fields.push(::protobuf::reflect::accessor::make_singular_message_accessor::<_, QueryGetAccountCode>(
-                    "accountCode",
-                    CosmosRequest::has_accountCode,
-                    CosmosRequest::get_accountCode,
-                ));
-                fields.push(::protobuf::reflect::accessor::make_singular_message_accessor::<_, QueryGetAccountStorageCell>(
-                    "storageCell",
-                    CosmosRequest::has_storageCell,
-                    CosmosRequest::get_storageCell,
-                ));
-                fields.push(::protobuf::reflect::accessor::make_singular_message_accessor::<_, QueryInsertAccountCode>(
-                    "insertAccountCode",
-                    CosmosRequest::has_insertAccountCode,
-                    CosmosRequest::get_insertAccountCode,
-                ));
-                fields.push(::protobuf::reflect::accessor::make_singular_message_accessor::<_, QueryInsertStorageCell>(
-                    "insertStorageCell",
-                    CosmosRequest::has_insertStorageCell,
-                    CosmosRequest::get_insertStorageCell,
-                ));
-                fields.push(::protobuf::reflect::accessor::make_singular_message_accessor::<_, QueryRemove>(
-                    "remove",
-                    CosmosRequest::has_remove,
-                    CosmosRequest::get_remove,
+                fields.push(::protobuf::reflect::accessor::make_singular_ptr_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<HandleTransactionResponse>>(
+                    "result",
+                    |m: &SignedTransactionResponse| { &m.result },
+                    |m: &mut SignedTransactionResponse| { &mut m.result },
                 ));
-                fields.push(::protobuf::reflect::accessor::make_singular_message_accessor::<_, QueryRemoveStorageCell>(
-                    "removeStorageCell",
-                    CosmosRequest::has_removeStorageCell,
-                    CosmosRequest::get_removeStorageCell,
-                ));
-                fields.push(::protobuf::reflect::accessor::make_singular_message_accessor::<_, QueryRemoveStorage>(
-                    "removeStorage",
-                    CosmosRequest::has_removeStorage,
-                    CosmosRequest::get_removeStorage,
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeBytes>(
+                    "from",
+                    |m: &SignedTransactionResponse| { &m.from },
+                    |m: &mut SignedTransactionResponse| { &mut m.from },
                 ));
-                fields.push(::protobuf::reflect::accessor::make_singular_message_accessor::<_, QueryBlockHash>(
-                    "blockHash",
-                    CosmosRequest::has_blockHash,
-                    CosmosRequest::get_blockHash,
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeBytes>(
+                    "hash",
+                    |m: &SignedTransactionResponse| { &m.hash },
+                    |m: &mut SignedTransactionResponse| { &mut m.hash },
                 ));
-                ::protobuf::reflect::MessageDescriptor::new::<CosmosRequest>(
-                    "CosmosRequest",
+                ::protobuf::reflect::MessageDescriptor::new::<SignedTransactionResponse>(
+                    "SignedTransactionResponse",
                     fields,
                     file_descriptor_proto()
                 )
@@ -6687,255 +16748,213 @@ impl ::protobuf::Message for CosmosRequest {
         }
     }
 
-    fn default_instance() -> &'static CosmosRequest {
-        static mut instance: ::protobuf::lazy::Lazy<CosmosRequest> = ::protobuf::lazy::Lazy {
+    fn default_instance() -> &'static SignedTransactionResponse {
+        static mut instance: ::protobuf::lazy::Lazy<SignedTransactionResponse> = ::protobuf::lazy::Lazy {
             lock: ::protobuf::lazy::ONCE_INIT,
-            ptr: 0 as *const CosmosRequest,
+            ptr: 0 as *const SignedTransactionResponse,
         };
         unsafe {
-            instance.get(CosmosRequest::new)
+            instance.get(SignedTransactionResponse::new)
         }
     }
 }
 
-impl ::protobuf::Clear for CosmosRequest {
+impl ::protobuf::Clear for SignedTransactionResponse {
     fn clear(&mut self) {
-        self.req = ::std::option::Option::None;
-        self.req = ::std::option::Option::None;
-        self.req = ::std::option::Option::None;
-        self.req = ::std::option::Option::None;
-        self.req = ::std::option::Option::None;
-        self.req = ::std::option::Option::None;
-        self.req = ::std::option::Option::None;
-        self.req = ::std::option::Option::None;
-        self.req = ::std::option::Option::None;
-        self.req = ::std::option::Option::None;
-        self.req = ::std::option::Option::None;
+        self.result.clear();
+        self.from.clear();
+        self.hash.clear();
         self.unknown_fields.clear();
     }
 }
 
-impl ::std::fmt::Debug for CosmosRequest {
+impl ::std::fmt::Debug for SignedTransactionResponse {
     fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
         ::protobuf::text_format::fmt(self, f)
     }
 }
 
-impl ::protobuf::reflect::ProtobufValue for CosmosRequest {
+impl ::protobuf::reflect::ProtobufValue for SignedTransactionResponse {
     fn as_ref(&self) -> ::protobuf::reflect::ProtobufValueRef {
         ::protobuf::reflect::ProtobufValueRef::Message(self)
     }
 }
 
 #[derive(PartialEq,Clone,Default)]
-pub struct SGXVMCallParams {
-    // message fields
-    pub from: ::std::vec::Vec<u8>,
-    pub to: ::std::vec::Vec<u8>,
-    pub data: ::std::vec::Vec<u8>,
-    pub gasLimit: u64,
-    pub value: ::std::vec::Vec<u8>,
-    pub accessList: ::protobuf::RepeatedField<AccessListItem>,
-    pub commit: bool,
-    pub nonce: u64,
+pub struct NodePublicKeyRequest {
     // special fields
     pub unknown_fields: ::protobuf::UnknownFields,
     pub cached_size: ::protobuf::CachedSize,
 }
 
-impl<'a> ::std::default::Default for &'a SGXVMCallParams {
-    fn default() -> &'a SGXVMCallParams {
-        <SGXVMCallParams as ::protobuf::Message>::default_instance()
+impl<'a> ::std::default::Default for &'a NodePublicKeyRequest {
+    fn default() -> &'a NodePublicKeyRequest {
+        <NodePublicKeyRequest as ::protobuf::Message>::default_instance()
     }
 }
 
-impl SGXVMCallParams {
-    pub fn new() -> SGXVMCallParams {
+impl NodePublicKeyRequest {
+    pub fn new() -> NodePublicKeyRequest {
         ::std::default::Default::default()
     }
+}
 
-    // bytes from = 1;
-
-
-    pub fn get_from(&self) -> &[u8] {
-        &self.from
-    }
-    pub fn clear_from(&mut self) {
-        self.from.clear();
-    }
-
-    // Param is passed by value, moved
-    pub fn set_from(&mut self, v: ::std::vec::Vec<u8>) {
-        self.from = v;
-    }
-
-    // Mutable pointer to the field.
-    // If field is not initialized, it is initialized with default value first.
-    pub fn mut_from(&mut self) -> &mut ::std::vec::Vec<u8> {
-        &mut self.from
-    }
-
-    // Take field
-    pub fn take_from(&mut self) -> ::std::vec::Vec<u8> {
-        ::std::mem::replace(&mut self.from, ::std::vec::Vec::new())
-    }
-
-    // bytes to = 2;
-
-
-    pub fn get_to(&self) -> &[u8] {
-        &self.to
-    }
-    pub fn clear_to(&mut self) {
-        self.to.clear();
-    }
-
-    // Param is passed by value, moved
-    pub fn set_to(&mut self, v: ::std::vec::Vec<u8>) {
-        self.to = v;
-    }
-
-    // Mutable pointer to the field.
-    // If field is not initialized, it is initialized with default value first.
-    pub fn mut_to(&mut self) -> &mut ::std::vec::Vec<u8> {
-        &mut self.to
-    }
-
-    // Take field
-    pub fn take_to(&mut self) -> ::std::vec::Vec<u8> {
-        ::std::mem::replace(&mut self.to, ::std::vec::Vec::new())
-    }
-
-    // bytes data = 3;
-
-
-    pub fn get_data(&self) -> &[u8] {
-        &self.data
-    }
-    pub fn clear_data(&mut self) {
-        self.data.clear();
-    }
-
-    // Param is passed by value, moved
-    pub fn set_data(&mut self, v: ::std::vec::Vec<u8>) {
-        self.data = v;
+impl ::protobuf::Message for NodePublicKeyRequest {
+    fn is_initialized(&self) -> bool {
+        true
     }
 
-    // Mutable pointer to the field.
-    // If field is not initialized, it is initialized with default value first.
-    pub fn mut_data(&mut self) -> &mut ::std::vec::Vec<u8> {
-        &mut self.data
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
     }
 
-    // Take field
-    pub fn take_data(&mut self) -> ::std::vec::Vec<u8> {
-        ::std::mem::replace(&mut self.data, ::std::vec::Vec::new())
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
     }
 
-    // uint64 gasLimit = 4;
-
-
-    pub fn get_gasLimit(&self) -> u64 {
-        self.gasLimit
-    }
-    pub fn clear_gasLimit(&mut self) {
-        self.gasLimit = 0;
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
     }
 
-    // Param is passed by value, moved
-    pub fn set_gasLimit(&mut self, v: u64) {
-        self.gasLimit = v;
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
     }
 
-    // bytes value = 5;
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
 
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
 
-    pub fn get_value(&self) -> &[u8] {
-        &self.value
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
     }
-    pub fn clear_value(&mut self) {
-        self.value.clear();
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
     }
-
-    // Param is passed by value, moved
-    pub fn set_value(&mut self, v: ::std::vec::Vec<u8>) {
-        self.value = v;
+    fn into_any(self: Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
     }
 
-    // Mutable pointer to the field.
-    // If field is not initialized, it is initialized with default value first.
-    pub fn mut_value(&mut self) -> &mut ::std::vec::Vec<u8> {
-        &mut self.value
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
     }
 
-    // Take field
-    pub fn take_value(&mut self) -> ::std::vec::Vec<u8> {
-        ::std::mem::replace(&mut self.value, ::std::vec::Vec::new())
+    fn new() -> NodePublicKeyRequest {
+        NodePublicKeyRequest::new()
     }
 
-    // repeated .ffi.ffi.AccessListItem accessList = 6;
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const ::protobuf::reflect::MessageDescriptor,
+        };
+        unsafe {
+            descriptor.get(|| {
+                let fields = ::std::vec::Vec::new();
+                ::protobuf::reflect::MessageDescriptor::new::<NodePublicKeyRequest>(
+                    "NodePublicKeyRequest",
+                    fields,
+                    file_descriptor_proto()
+                )
+            })
+        }
+    }
 
+    fn default_instance() -> &'static NodePublicKeyRequest {
+        static mut instance: ::protobuf::lazy::Lazy<NodePublicKeyRequest> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const NodePublicKeyRequest,
+        };
+        unsafe {
+            instance.get(NodePublicKeyRequest::new)
+        }
+    }
+}
 
-    pub fn get_accessList(&self) -> &[AccessListItem] {
-        &self.accessList
+impl ::protobuf::Clear for NodePublicKeyRequest {
+    fn clear(&mut self) {
+        self.unknown_fields.clear();
     }
-    pub fn clear_accessList(&mut self) {
-        self.accessList.clear();
+}
+
+impl ::std::fmt::Debug for NodePublicKeyRequest {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
     }
+}
 
-    // Param is passed by value, moved
-    pub fn set_accessList(&mut self, v: ::protobuf::RepeatedField<AccessListItem>) {
-        self.accessList = v;
+impl ::protobuf::reflect::ProtobufValue for NodePublicKeyRequest {
+    fn as_ref(&self) -> ::protobuf::reflect::ProtobufValueRef {
+        ::protobuf::reflect::ProtobufValueRef::Message(self)
     }
+}
 
-    // Mutable pointer to the field.
-    pub fn mut_accessList(&mut self) -> &mut ::protobuf::RepeatedField<AccessListItem> {
-        &mut self.accessList
+#[derive(PartialEq,Clone,Default)]
+pub struct NodePublicKeyResponse {
+    // message fields
+    pub publicKey: ::std::vec::Vec<u8>,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a NodePublicKeyResponse {
+    fn default() -> &'a NodePublicKeyResponse {
+        <NodePublicKeyResponse as ::protobuf::Message>::default_instance()
     }
+}
 
-    // Take field
-    pub fn take_accessList(&mut self) -> ::protobuf::RepeatedField<AccessListItem> {
-        ::std::mem::replace(&mut self.accessList, ::protobuf::RepeatedField::new())
+impl NodePublicKeyResponse {
+    pub fn new() -> NodePublicKeyResponse {
+        ::std::default::Default::default()
     }
 
-    // bool commit = 7;
+    // bytes publicKey = 1;
 
 
-    pub fn get_commit(&self) -> bool {
-        self.commit
+    pub fn get_publicKey(&self) -> &[u8] {
+        &self.publicKey
     }
-    pub fn clear_commit(&mut self) {
-        self.commit = false;
+    pub fn clear_publicKey(&mut self) {
+        self.publicKey.clear();
     }
 
     // Param is passed by value, moved
-    pub fn set_commit(&mut self, v: bool) {
-        self.commit = v;
+    pub fn set_publicKey(&mut self, v: ::std::vec::Vec<u8>) {
+        self.publicKey = v;
     }
 
-    // uint64 nonce = 8;
-
-
-    pub fn get_nonce(&self) -> u64 {
-        self.nonce
-    }
-    pub fn clear_nonce(&mut self) {
-        self.nonce = 0;
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_publicKey(&mut self) -> &mut ::std::vec::Vec<u8> {
+        &mut self.publicKey
     }
 
-    // Param is passed by value, moved
-    pub fn set_nonce(&mut self, v: u64) {
-        self.nonce = v;
+    // Take field
+    pub fn take_publicKey(&mut self) -> ::std::vec::Vec<u8> {
+        ::std::mem::replace(&mut self.publicKey, ::std::vec::Vec::new())
     }
 }
 
-impl ::protobuf::Message for SGXVMCallParams {
+impl ::protobuf::Message for NodePublicKeyResponse {
     fn is_initialized(&self) -> bool {
-        for v in &self.accessList {
-            if !v.is_initialized() {
-                return false;
-            }
-        };
         true
     }
 
@@ -6944,109 +16963,31 @@ impl ::protobuf::Message for SGXVMCallParams {
             let (field_number, wire_type) = is.read_tag_unpack()?;
             match field_number {
                 1 => {
-                    ::protobuf::rt::read_singular_proto3_bytes_into(wire_type, is, &mut self.from)?;
-                },
-                2 => {
-                    ::protobuf::rt::read_singular_proto3_bytes_into(wire_type, is, &mut self.to)?;
-                },
-                3 => {
-                    ::protobuf::rt::read_singular_proto3_bytes_into(wire_type, is, &mut self.data)?;
-                },
-                4 => {
-                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
-                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
-                    }
-                    let tmp = is.read_uint64()?;
-                    self.gasLimit = tmp;
-                },
-                5 => {
-                    ::protobuf::rt::read_singular_proto3_bytes_into(wire_type, is, &mut self.value)?;
-                },
-                6 => {
-                    ::protobuf::rt::read_repeated_message_into(wire_type, is, &mut self.accessList)?;
-                },
-                7 => {
-                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
-                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
-                    }
-                    let tmp = is.read_bool()?;
-                    self.commit = tmp;
-                },
-                8 => {
-                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
-                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
-                    }
-                    let tmp = is.read_uint64()?;
-                    self.nonce = tmp;
+                    ::protobuf::rt::read_singular_proto3_bytes_into(wire_type, is, &mut self.publicKey)?;
                 },
                 _ => {
-                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
-                },
-            };
-        }
-        ::std::result::Result::Ok(())
-    }
-
-    // Compute sizes of nested messages
-    #[allow(unused_variables)]
-    fn compute_size(&self) -> u32 {
-        let mut my_size = 0;
-        if !self.from.is_empty() {
-            my_size += ::protobuf::rt::bytes_size(1, &self.from);
-        }
-        if !self.to.is_empty() {
-            my_size += ::protobuf::rt::bytes_size(2, &self.to);
-        }
-        if !self.data.is_empty() {
-            my_size += ::protobuf::rt::bytes_size(3, &self.data);
-        }
-        if self.gasLimit != 0 {
-            my_size += ::protobuf::rt::value_size(4, self.gasLimit, ::protobuf::wire_format::WireTypeVarint);
-        }
-        if !self.value.is_empty() {
-            my_size += ::protobuf::rt::bytes_size(5, &self.value);
-        }
-        for value in &self.accessList {
-            let len = value.compute_size();
-            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
-        };
-        if self.commit != false {
-            my_size += 2;
-        }
-        if self.nonce != 0 {
-            my_size += ::protobuf::rt::value_size(8, self.nonce, ::protobuf::wire_format::WireTypeVarint);
-        }
-        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
-        self.cached_size.set(my_size);
-        my_size
-    }
-
-    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
-        if !self.from.is_empty() {
-            os.write_bytes(1, &self.from)?;
-        }
-        if !self.to.is_empty() {
-            os.write_bytes(2, &self.to)?;
-        }
-        if !self.data.is_empty() {
-            os.write_bytes(3, &self.data)?;
-        }
-        if self.gasLimit != 0 {
-            os.write_uint64(4, self.gasLimit)?;
-        }
-        if !self.value.is_empty() {
-            os.write_bytes(5, &self.value)?;
-        }
-        for v in &self.accessList {
-            os.write_tag(6, ::protobuf::wire_format::WireTypeLengthDelimited)?;
-            os.write_raw_varint32(v.get_cached_size())?;
-            v.write_to_with_cached_sizes(os)?;
-        };
-        if self.commit != false {
-            os.write_bool(7, self.commit)?;
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
         }
-        if self.nonce != 0 {
-            os.write_uint64(8, self.nonce)?;
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if !self.publicKey.is_empty() {
+            my_size += ::protobuf::rt::bytes_size(1, &self.publicKey);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if !self.publicKey.is_empty() {
+            os.write_bytes(1, &self.publicKey)?;
         }
         os.write_unknown_fields(self.get_unknown_fields())?;
         ::std::result::Result::Ok(())
@@ -7078,8 +17019,8 @@ impl ::protobuf::Message for SGXVMCallParams {
         Self::descriptor_static()
     }
 
-    fn new() -> SGXVMCallParams {
-        SGXVMCallParams::new()
+    fn new() -> NodePublicKeyResponse {
+        NodePublicKeyResponse::new()
     }
 
     fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
@@ -7091,47 +17032,12 @@ impl ::protobuf::Message for SGXVMCallParams {
             descriptor.get(|| {
                 let mut fields = ::std::vec::Vec::new();
                 fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeBytes>(
-                    "from",
-                    |m: &SGXVMCallParams| { &m.from },
-                    |m: &mut SGXVMCallParams| { &mut m.from },
-                ));
-                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeBytes>(
-                    "to",
-                    |m: &SGXVMCallParams| { &m.to },
-                    |m: &mut SGXVMCallParams| { &mut m.to },
-                ));
-                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeBytes>(
-                    "data",
-                    |m: &SGXVMCallParams| { &m.data },
-                    |m: &mut SGXVMCallParams| { &mut m.data },
-                ));
-                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeUint64>(
-                    "gasLimit",
-                    |m: &SGXVMCallParams| { &m.gasLimit },
-                    |m: &mut SGXVMCallParams| { &mut m.gasLimit },
-                ));
-                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeBytes>(
-                    "value",
-                    |m: &SGXVMCallParams| { &m.value },
-                    |m: &mut SGXVMCallParams| { &mut m.value },
-                ));
-                fields.push(::protobuf::reflect::accessor::make_repeated_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<AccessListItem>>(
-                    "accessList",
-                    |m: &SGXVMCallParams| { &m.accessList },
-                    |m: &mut SGXVMCallParams| { &mut m.accessList },
-                ));
-                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeBool>(
-                    "commit",
-                    |m: &SGXVMCallParams| { &m.commit },
-                    |m: &mut SGXVMCallParams| { &mut m.commit },
-                ));
-                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeUint64>(
-                    "nonce",
-                    |m: &SGXVMCallParams| { &m.nonce },
-                    |m: &mut SGXVMCallParams| { &mut m.nonce },
+                    "publicKey",
+                    |m: &NodePublicKeyResponse| { &m.publicKey },
+                    |m: &mut NodePublicKeyResponse| { &mut m.publicKey },
                 ));
-                ::protobuf::reflect::MessageDescriptor::new::<SGXVMCallParams>(
-                    "SGXVMCallParams",
+                ::protobuf::reflect::MessageDescriptor::new::<NodePublicKeyResponse>(
+                    "NodePublicKeyResponse",
                     fields,
                     file_descriptor_proto()
                 )
@@ -7139,225 +17045,133 @@ impl ::protobuf::Message for SGXVMCallParams {
         }
     }
 
-    fn default_instance() -> &'static SGXVMCallParams {
-        static mut instance: ::protobuf::lazy::Lazy<SGXVMCallParams> = ::protobuf::lazy::Lazy {
+    fn default_instance() -> &'static NodePublicKeyResponse {
+        static mut instance: ::protobuf::lazy::Lazy<NodePublicKeyResponse> = ::protobuf::lazy::Lazy {
             lock: ::protobuf::lazy::ONCE_INIT,
-            ptr: 0 as *const SGXVMCallParams,
+            ptr: 0 as *const NodePublicKeyResponse,
         };
         unsafe {
-            instance.get(SGXVMCallParams::new)
+            instance.get(NodePublicKeyResponse::new)
         }
     }
 }
 
-impl ::protobuf::Clear for SGXVMCallParams {
+impl ::protobuf::Clear for NodePublicKeyResponse {
     fn clear(&mut self) {
-        self.from.clear();
-        self.to.clear();
-        self.data.clear();
-        self.gasLimit = 0;
-        self.value.clear();
-        self.accessList.clear();
-        self.commit = false;
-        self.nonce = 0;
+        self.publicKey.clear();
         self.unknown_fields.clear();
     }
 }
 
-impl ::std::fmt::Debug for SGXVMCallParams {
+impl ::std::fmt::Debug for NodePublicKeyResponse {
     fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
         ::protobuf::text_format::fmt(self, f)
     }
 }
 
-impl ::protobuf::reflect::ProtobufValue for SGXVMCallParams {
+impl ::protobuf::reflect::ProtobufValue for NodePublicKeyResponse {
     fn as_ref(&self) -> ::protobuf::reflect::ProtobufValueRef {
         ::protobuf::reflect::ProtobufValueRef::Message(self)
     }
 }
 
 #[derive(PartialEq,Clone,Default)]
-pub struct SGXVMCreateParams {
+pub struct PrecompileOverride {
     // message fields
-    pub from: ::std::vec::Vec<u8>,
-    pub data: ::std::vec::Vec<u8>,
-    pub gasLimit: u64,
-    pub value: ::std::vec::Vec<u8>,
-    pub accessList: ::protobuf::RepeatedField<AccessListItem>,
-    pub commit: bool,
-    pub nonce: u64,
+    pub address: ::std::vec::Vec<u8>,
+    pub activationHeight: u64,
+    pub gasOverride: u64,
+    pub disabled: bool,
     // special fields
     pub unknown_fields: ::protobuf::UnknownFields,
     pub cached_size: ::protobuf::CachedSize,
 }
 
-impl<'a> ::std::default::Default for &'a SGXVMCreateParams {
-    fn default() -> &'a SGXVMCreateParams {
-        <SGXVMCreateParams as ::protobuf::Message>::default_instance()
+impl<'a> ::std::default::Default for &'a PrecompileOverride {
+    fn default() -> &'a PrecompileOverride {
+        <PrecompileOverride as ::protobuf::Message>::default_instance()
     }
 }
 
-impl SGXVMCreateParams {
-    pub fn new() -> SGXVMCreateParams {
+impl PrecompileOverride {
+    pub fn new() -> PrecompileOverride {
         ::std::default::Default::default()
     }
 
-    // bytes from = 1;
-
-
-    pub fn get_from(&self) -> &[u8] {
-        &self.from
-    }
-    pub fn clear_from(&mut self) {
-        self.from.clear();
-    }
-
-    // Param is passed by value, moved
-    pub fn set_from(&mut self, v: ::std::vec::Vec<u8>) {
-        self.from = v;
-    }
-
-    // Mutable pointer to the field.
-    // If field is not initialized, it is initialized with default value first.
-    pub fn mut_from(&mut self) -> &mut ::std::vec::Vec<u8> {
-        &mut self.from
-    }
-
-    // Take field
-    pub fn take_from(&mut self) -> ::std::vec::Vec<u8> {
-        ::std::mem::replace(&mut self.from, ::std::vec::Vec::new())
-    }
-
-    // bytes data = 2;
-
-
-    pub fn get_data(&self) -> &[u8] {
-        &self.data
-    }
-    pub fn clear_data(&mut self) {
-        self.data.clear();
-    }
-
-    // Param is passed by value, moved
-    pub fn set_data(&mut self, v: ::std::vec::Vec<u8>) {
-        self.data = v;
-    }
-
-    // Mutable pointer to the field.
-    // If field is not initialized, it is initialized with default value first.
-    pub fn mut_data(&mut self) -> &mut ::std::vec::Vec<u8> {
-        &mut self.data
-    }
-
-    // Take field
-    pub fn take_data(&mut self) -> ::std::vec::Vec<u8> {
-        ::std::mem::replace(&mut self.data, ::std::vec::Vec::new())
-    }
-
-    // uint64 gasLimit = 3;
-
-
-    pub fn get_gasLimit(&self) -> u64 {
-        self.gasLimit
-    }
-    pub fn clear_gasLimit(&mut self) {
-        self.gasLimit = 0;
-    }
-
-    // Param is passed by value, moved
-    pub fn set_gasLimit(&mut self, v: u64) {
-        self.gasLimit = v;
-    }
-
-    // bytes value = 4;
+    // bytes address = 1;
 
 
-    pub fn get_value(&self) -> &[u8] {
-        &self.value
+    pub fn get_address(&self) -> &[u8] {
+        &self.address
     }
-    pub fn clear_value(&mut self) {
-        self.value.clear();
+    pub fn clear_address(&mut self) {
+        self.address.clear();
     }
 
     // Param is passed by value, moved
-    pub fn set_value(&mut self, v: ::std::vec::Vec<u8>) {
-        self.value = v;
+    pub fn set_address(&mut self, v: ::std::vec::Vec<u8>) {
+        self.address = v;
     }
 
     // Mutable pointer to the field.
     // If field is not initialized, it is initialized with default value first.
-    pub fn mut_value(&mut self) -> &mut ::std::vec::Vec<u8> {
-        &mut self.value
+    pub fn mut_address(&mut self) -> &mut ::std::vec::Vec<u8> {
+        &mut self.address
     }
 
     // Take field
-    pub fn take_value(&mut self) -> ::std::vec::Vec<u8> {
-        ::std::mem::replace(&mut self.value, ::std::vec::Vec::new())
+    pub fn take_address(&mut self) -> ::std::vec::Vec<u8> {
+        ::std::mem::replace(&mut self.address, ::std::vec::Vec::new())
     }
 
-    // repeated .ffi.ffi.AccessListItem accessList = 5;
+    // uint64 activationHeight = 2;
 
 
-    pub fn get_accessList(&self) -> &[AccessListItem] {
-        &self.accessList
+    pub fn get_activationHeight(&self) -> u64 {
+        self.activationHeight
     }
-    pub fn clear_accessList(&mut self) {
-        self.accessList.clear();
+    pub fn clear_activationHeight(&mut self) {
+        self.activationHeight = 0;
     }
 
     // Param is passed by value, moved
-    pub fn set_accessList(&mut self, v: ::protobuf::RepeatedField<AccessListItem>) {
-        self.accessList = v;
-    }
-
-    // Mutable pointer to the field.
-    pub fn mut_accessList(&mut self) -> &mut ::protobuf::RepeatedField<AccessListItem> {
-        &mut self.accessList
-    }
-
-    // Take field
-    pub fn take_accessList(&mut self) -> ::protobuf::RepeatedField<AccessListItem> {
-        ::std::mem::replace(&mut self.accessList, ::protobuf::RepeatedField::new())
+    pub fn set_activationHeight(&mut self, v: u64) {
+        self.activationHeight = v;
     }
 
-    // bool commit = 6;
+    // uint64 gasOverride = 3;
 
 
-    pub fn get_commit(&self) -> bool {
-        self.commit
+    pub fn get_gasOverride(&self) -> u64 {
+        self.gasOverride
     }
-    pub fn clear_commit(&mut self) {
-        self.commit = false;
+    pub fn clear_gasOverride(&mut self) {
+        self.gasOverride = 0;
     }
 
     // Param is passed by value, moved
-    pub fn set_commit(&mut self, v: bool) {
-        self.commit = v;
+    pub fn set_gasOverride(&mut self, v: u64) {
+        self.gasOverride = v;
     }
 
-    // uint64 nonce = 7;
+    // bool disabled = 4;
 
 
-    pub fn get_nonce(&self) -> u64 {
-        self.nonce
+    pub fn get_disabled(&self) -> bool {
+        self.disabled
     }
-    pub fn clear_nonce(&mut self) {
-        self.nonce = 0;
+    pub fn clear_disabled(&mut self) {
+        self.disabled = false;
     }
 
     // Param is passed by value, moved
-    pub fn set_nonce(&mut self, v: u64) {
-        self.nonce = v;
+    pub fn set_disabled(&mut self, v: bool) {
+        self.disabled = v;
     }
 }
 
-impl ::protobuf::Message for SGXVMCreateParams {
+impl ::protobuf::Message for PrecompileOverride {
     fn is_initialized(&self) -> bool {
-        for v in &self.accessList {
-            if !v.is_initialized() {
-                return false;
-            }
-        };
         true
     }
 
@@ -7366,37 +17180,28 @@ impl ::protobuf::Message for SGXVMCreateParams {
             let (field_number, wire_type) = is.read_tag_unpack()?;
             match field_number {
                 1 => {
-                    ::protobuf::rt::read_singular_proto3_bytes_into(wire_type, is, &mut self.from)?;
+                    ::protobuf::rt::read_singular_proto3_bytes_into(wire_type, is, &mut self.address)?;
                 },
                 2 => {
-                    ::protobuf::rt::read_singular_proto3_bytes_into(wire_type, is, &mut self.data)?;
-                },
-                3 => {
                     if wire_type != ::protobuf::wire_format::WireTypeVarint {
                         return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
                     }
                     let tmp = is.read_uint64()?;
-                    self.gasLimit = tmp;
-                },
-                4 => {
-                    ::protobuf::rt::read_singular_proto3_bytes_into(wire_type, is, &mut self.value)?;
-                },
-                5 => {
-                    ::protobuf::rt::read_repeated_message_into(wire_type, is, &mut self.accessList)?;
-                },
-                6 => {
-                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
-                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
-                    }
-                    let tmp = is.read_bool()?;
-                    self.commit = tmp;
+                    self.activationHeight = tmp;
                 },
-                7 => {
+                3 => {
                     if wire_type != ::protobuf::wire_format::WireTypeVarint {
                         return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
                     }
                     let tmp = is.read_uint64()?;
-                    self.nonce = tmp;
+                    self.gasOverride = tmp;
+                },
+                4 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_bool()?;
+                    self.disabled = tmp;
                 },
                 _ => {
                     ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
@@ -7410,56 +17215,35 @@ impl ::protobuf::Message for SGXVMCreateParams {
     #[allow(unused_variables)]
     fn compute_size(&self) -> u32 {
         let mut my_size = 0;
-        if !self.from.is_empty() {
-            my_size += ::protobuf::rt::bytes_size(1, &self.from);
-        }
-        if !self.data.is_empty() {
-            my_size += ::protobuf::rt::bytes_size(2, &self.data);
+        if !self.address.is_empty() {
+            my_size += ::protobuf::rt::bytes_size(1, &self.address);
         }
-        if self.gasLimit != 0 {
-            my_size += ::protobuf::rt::value_size(3, self.gasLimit, ::protobuf::wire_format::WireTypeVarint);
+        if self.activationHeight != 0 {
+            my_size += ::protobuf::rt::value_size(2, self.activationHeight, ::protobuf::wire_format::WireTypeVarint);
         }
-        if !self.value.is_empty() {
-            my_size += ::protobuf::rt::bytes_size(4, &self.value);
+        if self.gasOverride != 0 {
+            my_size += ::protobuf::rt::value_size(3, self.gasOverride, ::protobuf::wire_format::WireTypeVarint);
         }
-        for value in &self.accessList {
-            let len = value.compute_size();
-            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
-        };
-        if self.commit != false {
+        if self.disabled != false {
             my_size += 2;
         }
-        if self.nonce != 0 {
-            my_size += ::protobuf::rt::value_size(7, self.nonce, ::protobuf::wire_format::WireTypeVarint);
-        }
         my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
         self.cached_size.set(my_size);
         my_size
     }
 
     fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
-        if !self.from.is_empty() {
-            os.write_bytes(1, &self.from)?;
-        }
-        if !self.data.is_empty() {
-            os.write_bytes(2, &self.data)?;
-        }
-        if self.gasLimit != 0 {
-            os.write_uint64(3, self.gasLimit)?;
+        if !self.address.is_empty() {
+            os.write_bytes(1, &self.address)?;
         }
-        if !self.value.is_empty() {
-            os.write_bytes(4, &self.value)?;
+        if self.activationHeight != 0 {
+            os.write_uint64(2, self.activationHeight)?;
         }
-        for v in &self.accessList {
-            os.write_tag(5, ::protobuf::wire_format::WireTypeLengthDelimited)?;
-            os.write_raw_varint32(v.get_cached_size())?;
-            v.write_to_with_cached_sizes(os)?;
-        };
-        if self.commit != false {
-            os.write_bool(6, self.commit)?;
+        if self.gasOverride != 0 {
+            os.write_uint64(3, self.gasOverride)?;
         }
-        if self.nonce != 0 {
-            os.write_uint64(7, self.nonce)?;
+        if self.disabled != false {
+            os.write_bool(4, self.disabled)?;
         }
         os.write_unknown_fields(self.get_unknown_fields())?;
         ::std::result::Result::Ok(())
@@ -7491,8 +17275,8 @@ impl ::protobuf::Message for SGXVMCreateParams {
         Self::descriptor_static()
     }
 
-    fn new() -> SGXVMCreateParams {
-        SGXVMCreateParams::new()
+    fn new() -> PrecompileOverride {
+        PrecompileOverride::new()
     }
 
     fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
@@ -7504,42 +17288,27 @@ impl ::protobuf::Message for SGXVMCreateParams {
             descriptor.get(|| {
                 let mut fields = ::std::vec::Vec::new();
                 fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeBytes>(
-                    "from",
-                    |m: &SGXVMCreateParams| { &m.from },
-                    |m: &mut SGXVMCreateParams| { &mut m.from },
-                ));
-                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeBytes>(
-                    "data",
-                    |m: &SGXVMCreateParams| { &m.data },
-                    |m: &mut SGXVMCreateParams| { &mut m.data },
+                    "address",
+                    |m: &PrecompileOverride| { &m.address },
+                    |m: &mut PrecompileOverride| { &mut m.address },
                 ));
                 fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeUint64>(
-                    "gasLimit",
-                    |m: &SGXVMCreateParams| { &m.gasLimit },
-                    |m: &mut SGXVMCreateParams| { &mut m.gasLimit },
-                ));
-                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeBytes>(
-                    "value",
-                    |m: &SGXVMCreateParams| { &m.value },
-                    |m: &mut SGXVMCreateParams| { &mut m.value },
+                    "activationHeight",
+                    |m: &PrecompileOverride| { &m.activationHeight },
+                    |m: &mut PrecompileOverride| { &mut m.activationHeight },
                 ));
-                fields.push(::protobuf::reflect::accessor::make_repeated_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<AccessListItem>>(
-                    "accessList",
-                    |m: &SGXVMCreateParams| { &m.accessList },
-                    |m: &mut SGXVMCreateParams| { &mut m.accessList },
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeUint64>(
+                    "gasOverride",
+                    |m: &PrecompileOverride| { &m.gasOverride },
+                    |m: &mut PrecompileOverride| { &mut m.gasOverride },
                 ));
                 fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeBool>(
-                    "commit",
-                    |m: &SGXVMCreateParams| { &m.commit },
-                    |m: &mut SGXVMCreateParams| { &mut m.commit },
-                ));
-                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeUint64>(
-                    "nonce",
-                    |m: &SGXVMCreateParams| { &m.nonce },
-                    |m: &mut SGXVMCreateParams| { &mut m.nonce },
+                    "disabled",
+                    |m: &PrecompileOverride| { &m.disabled },
+                    |m: &mut PrecompileOverride| { &mut m.disabled },
                 ));
-                ::protobuf::reflect::MessageDescriptor::new::<SGXVMCreateParams>(
-                    "SGXVMCreateParams",
+                ::protobuf::reflect::MessageDescriptor::new::<PrecompileOverride>(
+                    "PrecompileOverride",
                     fields,
                     file_descriptor_proto()
                 )
@@ -7547,138 +17316,88 @@ impl ::protobuf::Message for SGXVMCreateParams {
         }
     }
 
-    fn default_instance() -> &'static SGXVMCreateParams {
-        static mut instance: ::protobuf::lazy::Lazy<SGXVMCreateParams> = ::protobuf::lazy::Lazy {
+    fn default_instance() -> &'static PrecompileOverride {
+        static mut instance: ::protobuf::lazy::Lazy<PrecompileOverride> = ::protobuf::lazy::Lazy {
             lock: ::protobuf::lazy::ONCE_INIT,
-            ptr: 0 as *const SGXVMCreateParams,
+            ptr: 0 as *const PrecompileOverride,
         };
         unsafe {
-            instance.get(SGXVMCreateParams::new)
+            instance.get(PrecompileOverride::new)
         }
     }
 }
 
-impl ::protobuf::Clear for SGXVMCreateParams {
+impl ::protobuf::Clear for PrecompileOverride {
     fn clear(&mut self) {
-        self.from.clear();
-        self.data.clear();
-        self.gasLimit = 0;
-        self.value.clear();
-        self.accessList.clear();
-        self.commit = false;
-        self.nonce = 0;
+        self.address.clear();
+        self.activationHeight = 0;
+        self.gasOverride = 0;
+        self.disabled = false;
         self.unknown_fields.clear();
     }
 }
 
-impl ::std::fmt::Debug for SGXVMCreateParams {
+impl ::std::fmt::Debug for PrecompileOverride {
     fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
         ::protobuf::text_format::fmt(self, f)
     }
 }
 
-impl ::protobuf::reflect::ProtobufValue for SGXVMCreateParams {
+impl ::protobuf::reflect::ProtobufValue for PrecompileOverride {
     fn as_ref(&self) -> ::protobuf::reflect::ProtobufValueRef {
         ::protobuf::reflect::ProtobufValueRef::Message(self)
     }
 }
 
 #[derive(PartialEq,Clone,Default)]
-pub struct SGXVMCallRequest {
+pub struct ConfigurePrecompilesRequest {
     // message fields
-    pub params: ::protobuf::SingularPtrField<SGXVMCallParams>,
-    pub context: ::protobuf::SingularPtrField<TransactionContext>,
+    pub overrides: ::protobuf::RepeatedField<PrecompileOverride>,
     // special fields
     pub unknown_fields: ::protobuf::UnknownFields,
     pub cached_size: ::protobuf::CachedSize,
 }
 
-impl<'a> ::std::default::Default for &'a SGXVMCallRequest {
-    fn default() -> &'a SGXVMCallRequest {
-        <SGXVMCallRequest as ::protobuf::Message>::default_instance()
+impl<'a> ::std::default::Default for &'a ConfigurePrecompilesRequest {
+    fn default() -> &'a ConfigurePrecompilesRequest {
+        <ConfigurePrecompilesRequest as ::protobuf::Message>::default_instance()
     }
 }
 
-impl SGXVMCallRequest {
-    pub fn new() -> SGXVMCallRequest {
+impl ConfigurePrecompilesRequest {
+    pub fn new() -> ConfigurePrecompilesRequest {
         ::std::default::Default::default()
     }
 
-    // .ffi.ffi.SGXVMCallParams params = 1;
-
-
-    pub fn get_params(&self) -> &SGXVMCallParams {
-        self.params.as_ref().unwrap_or_else(|| SGXVMCallParams::default_instance())
-    }
-    pub fn clear_params(&mut self) {
-        self.params.clear();
-    }
-
-    pub fn has_params(&self) -> bool {
-        self.params.is_some()
-    }
-
-    // Param is passed by value, moved
-    pub fn set_params(&mut self, v: SGXVMCallParams) {
-        self.params = ::protobuf::SingularPtrField::some(v);
-    }
-
-    // Mutable pointer to the field.
-    // If field is not initialized, it is initialized with default value first.
-    pub fn mut_params(&mut self) -> &mut SGXVMCallParams {
-        if self.params.is_none() {
-            self.params.set_default();
-        }
-        self.params.as_mut().unwrap()
-    }
-
-    // Take field
-    pub fn take_params(&mut self) -> SGXVMCallParams {
-        self.params.take().unwrap_or_else(|| SGXVMCallParams::new())
-    }
-
-    // .ffi.ffi.TransactionContext context = 2;
+    // repeated .ffi.ffi.PrecompileOverride overrides = 1;
 
 
-    pub fn get_context(&self) -> &TransactionContext {
-        self.context.as_ref().unwrap_or_else(|| TransactionContext::default_instance())
+    pub fn get_overrides(&self) -> &[PrecompileOverride] {
+        &self.overrides
     }
-    pub fn clear_context(&mut self) {
-        self.context.clear();
-    }
-
-    pub fn has_context(&self) -> bool {
-        self.context.is_some()
+    pub fn clear_overrides(&mut self) {
+        self.overrides.clear();
     }
 
     // Param is passed by value, moved
-    pub fn set_context(&mut self, v: TransactionContext) {
-        self.context = ::protobuf::SingularPtrField::some(v);
+    pub fn set_overrides(&mut self, v: ::protobuf::RepeatedField<PrecompileOverride>) {
+        self.overrides = v;
     }
 
     // Mutable pointer to the field.
-    // If field is not initialized, it is initialized with default value first.
-    pub fn mut_context(&mut self) -> &mut TransactionContext {
-        if self.context.is_none() {
-            self.context.set_default();
-        }
-        self.context.as_mut().unwrap()
+    pub fn mut_overrides(&mut self) -> &mut ::protobuf::RepeatedField<PrecompileOverride> {
+        &mut self.overrides
     }
 
     // Take field
-    pub fn take_context(&mut self) -> TransactionContext {
-        self.context.take().unwrap_or_else(|| TransactionContext::new())
+    pub fn take_overrides(&mut self) -> ::protobuf::RepeatedField<PrecompileOverride> {
+        ::std::mem::replace(&mut self.overrides, ::protobuf::RepeatedField::new())
     }
 }
 
-impl ::protobuf::Message for SGXVMCallRequest {
+impl ::protobuf::Message for ConfigurePrecompilesRequest {
     fn is_initialized(&self) -> bool {
-        for v in &self.params {
-            if !v.is_initialized() {
-                return false;
-            }
-        };
-        for v in &self.context {
+        for v in &self.overrides {
             if !v.is_initialized() {
                 return false;
             }
@@ -7691,10 +17410,7 @@ impl ::protobuf::Message for SGXVMCallRequest {
             let (field_number, wire_type) = is.read_tag_unpack()?;
             match field_number {
                 1 => {
-                    ::protobuf::rt::read_singular_message_into(wire_type, is, &mut self.params)?;
-                },
-                2 => {
-                    ::protobuf::rt::read_singular_message_into(wire_type, is, &mut self.context)?;
+                    ::protobuf::rt::read_repeated_message_into(wire_type, is, &mut self.overrides)?;
                 },
                 _ => {
                     ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
@@ -7708,30 +17424,21 @@ impl ::protobuf::Message for SGXVMCallRequest {
     #[allow(unused_variables)]
     fn compute_size(&self) -> u32 {
         let mut my_size = 0;
-        if let Some(ref v) = self.params.as_ref() {
-            let len = v.compute_size();
-            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
-        }
-        if let Some(ref v) = self.context.as_ref() {
-            let len = v.compute_size();
+        for value in &self.overrides {
+            let len = value.compute_size();
             my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
-        }
+        };
         my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
         self.cached_size.set(my_size);
         my_size
     }
 
     fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
-        if let Some(ref v) = self.params.as_ref() {
+        for v in &self.overrides {
             os.write_tag(1, ::protobuf::wire_format::WireTypeLengthDelimited)?;
             os.write_raw_varint32(v.get_cached_size())?;
             v.write_to_with_cached_sizes(os)?;
-        }
-        if let Some(ref v) = self.context.as_ref() {
-            os.write_tag(2, ::protobuf::wire_format::WireTypeLengthDelimited)?;
-            os.write_raw_varint32(v.get_cached_size())?;
-            v.write_to_with_cached_sizes(os)?;
-        }
+        };
         os.write_unknown_fields(self.get_unknown_fields())?;
         ::std::result::Result::Ok(())
     }
@@ -7762,8 +17469,8 @@ impl ::protobuf::Message for SGXVMCallRequest {
         Self::descriptor_static()
     }
 
-    fn new() -> SGXVMCallRequest {
-        SGXVMCallRequest::new()
+    fn new() -> ConfigurePrecompilesRequest {
+        ConfigurePrecompilesRequest::new()
     }
 
     fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
@@ -7774,18 +17481,13 @@ impl ::protobuf::Message for SGXVMCallRequest {
         unsafe {
             descriptor.get(|| {
                 let mut fields = ::std::vec::Vec::new();
-                fields.push(::protobuf::reflect::accessor::make_singular_ptr_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<SGXVMCallParams>>(
-                    "params",
-                    |m: &SGXVMCallRequest| { &m.params },
-                    |m: &mut SGXVMCallRequest| { &mut m.params },
-                ));
-                fields.push(::protobuf::reflect::accessor::make_singular_ptr_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<TransactionContext>>(
-                    "context",
-                    |m: &SGXVMCallRequest| { &m.context },
-                    |m: &mut SGXVMCallRequest| { &mut m.context },
+                fields.push(::protobuf::reflect::accessor::make_repeated_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<PrecompileOverride>>(
+                    "overrides",
+                    |m: &ConfigurePrecompilesRequest| { &m.overrides },
+                    |m: &mut ConfigurePrecompilesRequest| { &mut m.overrides },
                 ));
-                ::protobuf::reflect::MessageDescriptor::new::<SGXVMCallRequest>(
-                    "SGXVMCallRequest",
+                ::protobuf::reflect::MessageDescriptor::new::<ConfigurePrecompilesRequest>(
+                    "ConfigurePrecompilesRequest",
                     fields,
                     file_descriptor_proto()
                 )
@@ -7793,137 +17495,85 @@ impl ::protobuf::Message for SGXVMCallRequest {
         }
     }
 
-    fn default_instance() -> &'static SGXVMCallRequest {
-        static mut instance: ::protobuf::lazy::Lazy<SGXVMCallRequest> = ::protobuf::lazy::Lazy {
+    fn default_instance() -> &'static ConfigurePrecompilesRequest {
+        static mut instance: ::protobuf::lazy::Lazy<ConfigurePrecompilesRequest> = ::protobuf::lazy::Lazy {
             lock: ::protobuf::lazy::ONCE_INIT,
-            ptr: 0 as *const SGXVMCallRequest,
+            ptr: 0 as *const ConfigurePrecompilesRequest,
         };
         unsafe {
-            instance.get(SGXVMCallRequest::new)
+            instance.get(ConfigurePrecompilesRequest::new)
         }
     }
 }
 
-impl ::protobuf::Clear for SGXVMCallRequest {
+impl ::protobuf::Clear for ConfigurePrecompilesRequest {
     fn clear(&mut self) {
-        self.params.clear();
-        self.context.clear();
+        self.overrides.clear();
         self.unknown_fields.clear();
     }
 }
 
-impl ::std::fmt::Debug for SGXVMCallRequest {
+impl ::std::fmt::Debug for ConfigurePrecompilesRequest {
     fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
         ::protobuf::text_format::fmt(self, f)
     }
 }
 
-impl ::protobuf::reflect::ProtobufValue for SGXVMCallRequest {
+impl ::protobuf::reflect::ProtobufValue for ConfigurePrecompilesRequest {
     fn as_ref(&self) -> ::protobuf::reflect::ProtobufValueRef {
         ::protobuf::reflect::ProtobufValueRef::Message(self)
     }
 }
 
 #[derive(PartialEq,Clone,Default)]
-pub struct SGXVMCreateRequest {
+pub struct ConfigurePrecompilesResponse {
     // message fields
-    pub params: ::protobuf::SingularPtrField<SGXVMCreateParams>,
-    pub context: ::protobuf::SingularPtrField<TransactionContext>,
+    pub error: ::std::string::String,
     // special fields
     pub unknown_fields: ::protobuf::UnknownFields,
     pub cached_size: ::protobuf::CachedSize,
 }
 
-impl<'a> ::std::default::Default for &'a SGXVMCreateRequest {
-    fn default() -> &'a SGXVMCreateRequest {
-        <SGXVMCreateRequest as ::protobuf::Message>::default_instance()
+impl<'a> ::std::default::Default for &'a ConfigurePrecompilesResponse {
+    fn default() -> &'a ConfigurePrecompilesResponse {
+        <ConfigurePrecompilesResponse as ::protobuf::Message>::default_instance()
     }
 }
 
-impl SGXVMCreateRequest {
-    pub fn new() -> SGXVMCreateRequest {
+impl ConfigurePrecompilesResponse {
+    pub fn new() -> ConfigurePrecompilesResponse {
         ::std::default::Default::default()
     }
 
-    // .ffi.ffi.SGXVMCreateParams params = 1;
-
-
-    pub fn get_params(&self) -> &SGXVMCreateParams {
-        self.params.as_ref().unwrap_or_else(|| SGXVMCreateParams::default_instance())
-    }
-    pub fn clear_params(&mut self) {
-        self.params.clear();
-    }
-
-    pub fn has_params(&self) -> bool {
-        self.params.is_some()
-    }
-
-    // Param is passed by value, moved
-    pub fn set_params(&mut self, v: SGXVMCreateParams) {
-        self.params = ::protobuf::SingularPtrField::some(v);
-    }
-
-    // Mutable pointer to the field.
-    // If field is not initialized, it is initialized with default value first.
-    pub fn mut_params(&mut self) -> &mut SGXVMCreateParams {
-        if self.params.is_none() {
-            self.params.set_default();
-        }
-        self.params.as_mut().unwrap()
-    }
-
-    // Take field
-    pub fn take_params(&mut self) -> SGXVMCreateParams {
-        self.params.take().unwrap_or_else(|| SGXVMCreateParams::new())
-    }
-
-    // .ffi.ffi.TransactionContext context = 2;
-
-
-    pub fn get_context(&self) -> &TransactionContext {
-        self.context.as_ref().unwrap_or_else(|| TransactionContext::default_instance())
-    }
-    pub fn clear_context(&mut self) {
-        self.context.clear();
-    }
+    // string error = 1;
 
-    pub fn has_context(&self) -> bool {
-        self.context.is_some()
+
+    pub fn get_error(&self) -> &str {
+        &self.error
+    }
+    pub fn clear_error(&mut self) {
+        self.error.clear();
     }
 
     // Param is passed by value, moved
-    pub fn set_context(&mut self, v: TransactionContext) {
-        self.context = ::protobuf::SingularPtrField::some(v);
+    pub fn set_error(&mut self, v: ::std::string::String) {
+        self.error = v;
     }
 
     // Mutable pointer to the field.
     // If field is not initialized, it is initialized with default value first.
-    pub fn mut_context(&mut self) -> &mut TransactionContext {
-        if self.context.is_none() {
-            self.context.set_default();
-        }
-        self.context.as_mut().unwrap()
+    pub fn mut_error(&mut self) -> &mut ::std::string::String {
+        &mut self.error
     }
 
     // Take field
-    pub fn take_context(&mut self) -> TransactionContext {
-        self.context.take().unwrap_or_else(|| TransactionContext::new())
+    pub fn take_error(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.error, ::std::string::String::new())
     }
 }
 
-impl ::protobuf::Message for SGXVMCreateRequest {
+impl ::protobuf::Message for ConfigurePrecompilesResponse {
     fn is_initialized(&self) -> bool {
-        for v in &self.params {
-            if !v.is_initialized() {
-                return false;
-            }
-        };
-        for v in &self.context {
-            if !v.is_initialized() {
-                return false;
-            }
-        };
         true
     }
 
@@ -7932,10 +17582,7 @@ impl ::protobuf::Message for SGXVMCreateRequest {
             let (field_number, wire_type) = is.read_tag_unpack()?;
             match field_number {
                 1 => {
-                    ::protobuf::rt::read_singular_message_into(wire_type, is, &mut self.params)?;
-                },
-                2 => {
-                    ::protobuf::rt::read_singular_message_into(wire_type, is, &mut self.context)?;
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.error)?;
                 },
                 _ => {
                     ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
@@ -7949,13 +17596,8 @@ impl ::protobuf::Message for SGXVMCreateRequest {
     #[allow(unused_variables)]
     fn compute_size(&self) -> u32 {
         let mut my_size = 0;
-        if let Some(ref v) = self.params.as_ref() {
-            let len = v.compute_size();
-            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
-        }
-        if let Some(ref v) = self.context.as_ref() {
-            let len = v.compute_size();
-            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        if !self.error.is_empty() {
+            my_size += ::protobuf::rt::string_size(1, &self.error);
         }
         my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
         self.cached_size.set(my_size);
@@ -7963,15 +17605,8 @@ impl ::protobuf::Message for SGXVMCreateRequest {
     }
 
     fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
-        if let Some(ref v) = self.params.as_ref() {
-            os.write_tag(1, ::protobuf::wire_format::WireTypeLengthDelimited)?;
-            os.write_raw_varint32(v.get_cached_size())?;
-            v.write_to_with_cached_sizes(os)?;
-        }
-        if let Some(ref v) = self.context.as_ref() {
-            os.write_tag(2, ::protobuf::wire_format::WireTypeLengthDelimited)?;
-            os.write_raw_varint32(v.get_cached_size())?;
-            v.write_to_with_cached_sizes(os)?;
+        if !self.error.is_empty() {
+            os.write_string(1, &self.error)?;
         }
         os.write_unknown_fields(self.get_unknown_fields())?;
         ::std::result::Result::Ok(())
@@ -8003,8 +17638,8 @@ impl ::protobuf::Message for SGXVMCreateRequest {
         Self::descriptor_static()
     }
 
-    fn new() -> SGXVMCreateRequest {
-        SGXVMCreateRequest::new()
+    fn new() -> ConfigurePrecompilesResponse {
+        ConfigurePrecompilesResponse::new()
     }
 
     fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
@@ -8015,18 +17650,13 @@ impl ::protobuf::Message for SGXVMCreateRequest {
         unsafe {
             descriptor.get(|| {
                 let mut fields = ::std::vec::Vec::new();
-                fields.push(::protobuf::reflect::accessor::make_singular_ptr_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<SGXVMCreateParams>>(
-                    "params",
-                    |m: &SGXVMCreateRequest| { &m.params },
-                    |m: &mut SGXVMCreateRequest| { &mut m.params },
-                ));
-                fields.push(::protobuf::reflect::accessor::make_singular_ptr_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<TransactionContext>>(
-                    "context",
-                    |m: &SGXVMCreateRequest| { &m.context },
-                    |m: &mut SGXVMCreateRequest| { &mut m.context },
+                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                    "error",
+                    |m: &ConfigurePrecompilesResponse| { &m.error },
+                    |m: &mut ConfigurePrecompilesResponse| { &mut m.error },
                 ));
-                ::protobuf::reflect::MessageDescriptor::new::<SGXVMCreateRequest>(
-                    "SGXVMCreateRequest",
+                ::protobuf::reflect::MessageDescriptor::new::<ConfigurePrecompilesResponse>(
+                    "ConfigurePrecompilesResponse",
                     fields,
                     file_descriptor_proto()
                 )
@@ -8034,503 +17664,657 @@ impl ::protobuf::Message for SGXVMCreateRequest {
         }
     }
 
-    fn default_instance() -> &'static SGXVMCreateRequest {
-        static mut instance: ::protobuf::lazy::Lazy<SGXVMCreateRequest> = ::protobuf::lazy::Lazy {
+    fn default_instance() -> &'static ConfigurePrecompilesResponse {
+        static mut instance: ::protobuf::lazy::Lazy<ConfigurePrecompilesResponse> = ::protobuf::lazy::Lazy {
             lock: ::protobuf::lazy::ONCE_INIT,
-            ptr: 0 as *const SGXVMCreateRequest,
+            ptr: 0 as *const ConfigurePrecompilesResponse,
         };
         unsafe {
-            instance.get(SGXVMCreateRequest::new)
+            instance.get(ConfigurePrecompilesResponse::new)
         }
     }
 }
 
-impl ::protobuf::Clear for SGXVMCreateRequest {
+impl ::protobuf::Clear for ConfigurePrecompilesResponse {
     fn clear(&mut self) {
-        self.params.clear();
-        self.context.clear();
+        self.error.clear();
         self.unknown_fields.clear();
     }
 }
 
-impl ::std::fmt::Debug for SGXVMCreateRequest {
+impl ::std::fmt::Debug for ConfigurePrecompilesResponse {
     fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
         ::protobuf::text_format::fmt(self, f)
     }
 }
 
-impl ::protobuf::reflect::ProtobufValue for SGXVMCreateRequest {
+impl ::protobuf::reflect::ProtobufValue for ConfigurePrecompilesResponse {
     fn as_ref(&self) -> ::protobuf::reflect::ProtobufValueRef {
         ::protobuf::reflect::ProtobufValueRef::Message(self)
     }
 }
 
 #[derive(PartialEq,Clone,Default)]
-pub struct NodePublicKeyRequest {
+pub struct FFIRequest {
+    // message oneof groups
+    pub req: ::std::option::Option<FFIRequest_oneof_req>,
     // special fields
     pub unknown_fields: ::protobuf::UnknownFields,
     pub cached_size: ::protobuf::CachedSize,
 }
 
-impl<'a> ::std::default::Default for &'a NodePublicKeyRequest {
-    fn default() -> &'a NodePublicKeyRequest {
-        <NodePublicKeyRequest as ::protobuf::Message>::default_instance()
+impl<'a> ::std::default::Default for &'a FFIRequest {
+    fn default() -> &'a FFIRequest {
+        <FFIRequest as ::protobuf::Message>::default_instance()
     }
 }
 
-impl NodePublicKeyRequest {
-    pub fn new() -> NodePublicKeyRequest {
+#[derive(Clone,PartialEq,Debug)]
+pub enum FFIRequest_oneof_req {
+    callRequest(SGXVMCallRequest),
+    createRequest(SGXVMCreateRequest),
+    publicKeyRequest(NodePublicKeyRequest),
+    traceCallRequest(SGXVMCallRequest),
+    traceCreateRequest(SGXVMCreateRequest),
+    structLogRequest(SGXVMStructLogRequest),
+    estimateGasRequest(SGXVMEstimateGasRequest),
+    stateDiffRequest(SGXVMStateDiffRequest),
+    createAccessListRequest(SGXVMCreateAccessListRequest),
+    batchRequest(SGXVMBatchRequest),
+    signedTransactionRequest(SGXVMSignedTransactionRequest),
+    configurePrecompilesRequest(ConfigurePrecompilesRequest),
+}
+
+impl FFIRequest {
+    pub fn new() -> FFIRequest {
         ::std::default::Default::default()
     }
-}
 
-impl ::protobuf::Message for NodePublicKeyRequest {
-    fn is_initialized(&self) -> bool {
-        true
+    // .ffi.ffi.SGXVMCallRequest callRequest = 1;
+
+
+    pub fn get_callRequest(&self) -> &SGXVMCallRequest {
+        match self.req {
+            ::std::option::Option::Some(FFIRequest_oneof_req::callRequest(ref v)) => v,
+            _ => SGXVMCallRequest::default_instance(),
+        }
+    }
+    pub fn clear_callRequest(&mut self) {
+        self.req = ::std::option::Option::None;
     }
 
-    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
-        while !is.eof()? {
-            let (field_number, wire_type) = is.read_tag_unpack()?;
-            match field_number {
-                _ => {
-                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
-                },
-            };
+    pub fn has_callRequest(&self) -> bool {
+        match self.req {
+            ::std::option::Option::Some(FFIRequest_oneof_req::callRequest(..)) => true,
+            _ => false,
         }
-        ::std::result::Result::Ok(())
     }
 
-    // Compute sizes of nested messages
-    #[allow(unused_variables)]
-    fn compute_size(&self) -> u32 {
-        let mut my_size = 0;
-        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
-        self.cached_size.set(my_size);
-        my_size
+    // Param is passed by value, moved
+    pub fn set_callRequest(&mut self, v: SGXVMCallRequest) {
+        self.req = ::std::option::Option::Some(FFIRequest_oneof_req::callRequest(v))
     }
 
-    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
-        os.write_unknown_fields(self.get_unknown_fields())?;
-        ::std::result::Result::Ok(())
+    // Mutable pointer to the field.
+    pub fn mut_callRequest(&mut self) -> &mut SGXVMCallRequest {
+        if let ::std::option::Option::Some(FFIRequest_oneof_req::callRequest(_)) = self.req {
+        } else {
+            self.req = ::std::option::Option::Some(FFIRequest_oneof_req::callRequest(SGXVMCallRequest::new()));
+        }
+        match self.req {
+            ::std::option::Option::Some(FFIRequest_oneof_req::callRequest(ref mut v)) => v,
+            _ => panic!(),
+        }
     }
 
-    fn get_cached_size(&self) -> u32 {
-        self.cached_size.get()
+    // Take field
+    pub fn take_callRequest(&mut self) -> SGXVMCallRequest {
+        if self.has_callRequest() {
+            match self.req.take() {
+                ::std::option::Option::Some(FFIRequest_oneof_req::callRequest(v)) => v,
+                _ => panic!(),
+            }
+        } else {
+            SGXVMCallRequest::new()
+        }
     }
 
-    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
-        &self.unknown_fields
+    // .ffi.ffi.SGXVMCreateRequest createRequest = 2;
+
+
+    pub fn get_createRequest(&self) -> &SGXVMCreateRequest {
+        match self.req {
+            ::std::option::Option::Some(FFIRequest_oneof_req::createRequest(ref v)) => v,
+            _ => SGXVMCreateRequest::default_instance(),
+        }
+    }
+    pub fn clear_createRequest(&mut self) {
+        self.req = ::std::option::Option::None;
     }
 
-    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
-        &mut self.unknown_fields
+    pub fn has_createRequest(&self) -> bool {
+        match self.req {
+            ::std::option::Option::Some(FFIRequest_oneof_req::createRequest(..)) => true,
+            _ => false,
+        }
     }
 
-    fn as_any(&self) -> &dyn (::std::any::Any) {
-        self as &dyn (::std::any::Any)
+    // Param is passed by value, moved
+    pub fn set_createRequest(&mut self, v: SGXVMCreateRequest) {
+        self.req = ::std::option::Option::Some(FFIRequest_oneof_req::createRequest(v))
     }
-    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
-        self as &mut dyn (::std::any::Any)
+
+    // Mutable pointer to the field.
+    pub fn mut_createRequest(&mut self) -> &mut SGXVMCreateRequest {
+        if let ::std::option::Option::Some(FFIRequest_oneof_req::createRequest(_)) = self.req {
+        } else {
+            self.req = ::std::option::Option::Some(FFIRequest_oneof_req::createRequest(SGXVMCreateRequest::new()));
+        }
+        match self.req {
+            ::std::option::Option::Some(FFIRequest_oneof_req::createRequest(ref mut v)) => v,
+            _ => panic!(),
+        }
+    }
+
+    // Take field
+    pub fn take_createRequest(&mut self) -> SGXVMCreateRequest {
+        if self.has_createRequest() {
+            match self.req.take() {
+                ::std::option::Option::Some(FFIRequest_oneof_req::createRequest(v)) => v,
+                _ => panic!(),
+            }
+        } else {
+            SGXVMCreateRequest::new()
+        }
+    }
+
+    // .ffi.ffi.NodePublicKeyRequest publicKeyRequest = 3;
+
+
+    pub fn get_publicKeyRequest(&self) -> &NodePublicKeyRequest {
+        match self.req {
+            ::std::option::Option::Some(FFIRequest_oneof_req::publicKeyRequest(ref v)) => v,
+            _ => NodePublicKeyRequest::default_instance(),
+        }
+    }
+    pub fn clear_publicKeyRequest(&mut self) {
+        self.req = ::std::option::Option::None;
+    }
+
+    pub fn has_publicKeyRequest(&self) -> bool {
+        match self.req {
+            ::std::option::Option::Some(FFIRequest_oneof_req::publicKeyRequest(..)) => true,
+            _ => false,
+        }
+    }
+
+    // Param is passed by value, moved
+    pub fn set_publicKeyRequest(&mut self, v: NodePublicKeyRequest) {
+        self.req = ::std::option::Option::Some(FFIRequest_oneof_req::publicKeyRequest(v))
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_publicKeyRequest(&mut self) -> &mut NodePublicKeyRequest {
+        if let ::std::option::Option::Some(FFIRequest_oneof_req::publicKeyRequest(_)) = self.req {
+        } else {
+            self.req = ::std::option::Option::Some(FFIRequest_oneof_req::publicKeyRequest(NodePublicKeyRequest::new()));
+        }
+        match self.req {
+            ::std::option::Option::Some(FFIRequest_oneof_req::publicKeyRequest(ref mut v)) => v,
+            _ => panic!(),
+        }
+    }
+
+    // Take field
+    pub fn take_publicKeyRequest(&mut self) -> NodePublicKeyRequest {
+        if self.has_publicKeyRequest() {
+            match self.req.take() {
+                ::std::option::Option::Some(FFIRequest_oneof_req::publicKeyRequest(v)) => v,
+                _ => panic!(),
+            }
+        } else {
+            NodePublicKeyRequest::new()
+        }
+    }
+
+    // .ffi.ffi.SGXVMCallRequest traceCallRequest = 4;
+
+
+    pub fn get_traceCallRequest(&self) -> &SGXVMCallRequest {
+        match self.req {
+            ::std::option::Option::Some(FFIRequest_oneof_req::traceCallRequest(ref v)) => v,
+            _ => SGXVMCallRequest::default_instance(),
+        }
     }
-    fn into_any(self: Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
-        self
+    pub fn clear_traceCallRequest(&mut self) {
+        self.req = ::std::option::Option::None;
     }
 
-    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
-        Self::descriptor_static()
+    pub fn has_traceCallRequest(&self) -> bool {
+        match self.req {
+            ::std::option::Option::Some(FFIRequest_oneof_req::traceCallRequest(..)) => true,
+            _ => false,
+        }
     }
 
-    fn new() -> NodePublicKeyRequest {
-        NodePublicKeyRequest::new()
+    // Param is passed by value, moved
+    pub fn set_traceCallRequest(&mut self, v: SGXVMCallRequest) {
+        self.req = ::std::option::Option::Some(FFIRequest_oneof_req::traceCallRequest(v))
     }
 
-    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
-        static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::lazy::Lazy {
-            lock: ::protobuf::lazy::ONCE_INIT,
-            ptr: 0 as *const ::protobuf::reflect::MessageDescriptor,
-        };
-        unsafe {
-            descriptor.get(|| {
-                let fields = ::std::vec::Vec::new();
-                ::protobuf::reflect::MessageDescriptor::new::<NodePublicKeyRequest>(
-                    "NodePublicKeyRequest",
-                    fields,
-                    file_descriptor_proto()
-                )
-            })
+    // Mutable pointer to the field.
+    pub fn mut_traceCallRequest(&mut self) -> &mut SGXVMCallRequest {
+        if let ::std::option::Option::Some(FFIRequest_oneof_req::traceCallRequest(_)) = self.req {
+        } else {
+            self.req = ::std::option::Option::Some(FFIRequest_oneof_req::traceCallRequest(SGXVMCallRequest::new()));
+        }
+        match self.req {
+            ::std::option::Option::Some(FFIRequest_oneof_req::traceCallRequest(ref mut v)) => v,
+            _ => panic!(),
         }
     }
 
-    fn default_instance() -> &'static NodePublicKeyRequest {
-        static mut instance: ::protobuf::lazy::Lazy<NodePublicKeyRequest> = ::protobuf::lazy::Lazy {
-            lock: ::protobuf::lazy::ONCE_INIT,
-            ptr: 0 as *const NodePublicKeyRequest,
-        };
-        unsafe {
-            instance.get(NodePublicKeyRequest::new)
+    // Take field
+    pub fn take_traceCallRequest(&mut self) -> SGXVMCallRequest {
+        if self.has_traceCallRequest() {
+            match self.req.take() {
+                ::std::option::Option::Some(FFIRequest_oneof_req::traceCallRequest(v)) => v,
+                _ => panic!(),
+            }
+        } else {
+            SGXVMCallRequest::new()
         }
     }
-}
 
-impl ::protobuf::Clear for NodePublicKeyRequest {
-    fn clear(&mut self) {
-        self.unknown_fields.clear();
-    }
-}
+    // .ffi.ffi.SGXVMCreateRequest traceCreateRequest = 5;
 
-impl ::std::fmt::Debug for NodePublicKeyRequest {
-    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
-        ::protobuf::text_format::fmt(self, f)
+
+    pub fn get_traceCreateRequest(&self) -> &SGXVMCreateRequest {
+        match self.req {
+            ::std::option::Option::Some(FFIRequest_oneof_req::traceCreateRequest(ref v)) => v,
+            _ => SGXVMCreateRequest::default_instance(),
+        }
+    }
+    pub fn clear_traceCreateRequest(&mut self) {
+        self.req = ::std::option::Option::None;
     }
-}
 
-impl ::protobuf::reflect::ProtobufValue for NodePublicKeyRequest {
-    fn as_ref(&self) -> ::protobuf::reflect::ProtobufValueRef {
-        ::protobuf::reflect::ProtobufValueRef::Message(self)
+    pub fn has_traceCreateRequest(&self) -> bool {
+        match self.req {
+            ::std::option::Option::Some(FFIRequest_oneof_req::traceCreateRequest(..)) => true,
+            _ => false,
+        }
     }
-}
 
-#[derive(PartialEq,Clone,Default)]
-pub struct NodePublicKeyResponse {
-    // message fields
-    pub publicKey: ::std::vec::Vec<u8>,
-    // special fields
-    pub unknown_fields: ::protobuf::UnknownFields,
-    pub cached_size: ::protobuf::CachedSize,
-}
+    // Param is passed by value, moved
+    pub fn set_traceCreateRequest(&mut self, v: SGXVMCreateRequest) {
+        self.req = ::std::option::Option::Some(FFIRequest_oneof_req::traceCreateRequest(v))
+    }
 
-impl<'a> ::std::default::Default for &'a NodePublicKeyResponse {
-    fn default() -> &'a NodePublicKeyResponse {
-        <NodePublicKeyResponse as ::protobuf::Message>::default_instance()
+    // Mutable pointer to the field.
+    pub fn mut_traceCreateRequest(&mut self) -> &mut SGXVMCreateRequest {
+        if let ::std::option::Option::Some(FFIRequest_oneof_req::traceCreateRequest(_)) = self.req {
+        } else {
+            self.req = ::std::option::Option::Some(FFIRequest_oneof_req::traceCreateRequest(SGXVMCreateRequest::new()));
+        }
+        match self.req {
+            ::std::option::Option::Some(FFIRequest_oneof_req::traceCreateRequest(ref mut v)) => v,
+            _ => panic!(),
+        }
     }
-}
 
-impl NodePublicKeyResponse {
-    pub fn new() -> NodePublicKeyResponse {
-        ::std::default::Default::default()
+    // Take field
+    pub fn take_traceCreateRequest(&mut self) -> SGXVMCreateRequest {
+        if self.has_traceCreateRequest() {
+            match self.req.take() {
+                ::std::option::Option::Some(FFIRequest_oneof_req::traceCreateRequest(v)) => v,
+                _ => panic!(),
+            }
+        } else {
+            SGXVMCreateRequest::new()
+        }
     }
 
-    // bytes publicKey = 1;
+    // .ffi.ffi.SGXVMStructLogRequest structLogRequest = 6;
 
 
-    pub fn get_publicKey(&self) -> &[u8] {
-        &self.publicKey
+    pub fn get_structLogRequest(&self) -> &SGXVMStructLogRequest {
+        match self.req {
+            ::std::option::Option::Some(FFIRequest_oneof_req::structLogRequest(ref v)) => v,
+            _ => SGXVMStructLogRequest::default_instance(),
+        }
     }
-    pub fn clear_publicKey(&mut self) {
-        self.publicKey.clear();
+    pub fn clear_structLogRequest(&mut self) {
+        self.req = ::std::option::Option::None;
+    }
+
+    pub fn has_structLogRequest(&self) -> bool {
+        match self.req {
+            ::std::option::Option::Some(FFIRequest_oneof_req::structLogRequest(..)) => true,
+            _ => false,
+        }
     }
 
     // Param is passed by value, moved
-    pub fn set_publicKey(&mut self, v: ::std::vec::Vec<u8>) {
-        self.publicKey = v;
+    pub fn set_structLogRequest(&mut self, v: SGXVMStructLogRequest) {
+        self.req = ::std::option::Option::Some(FFIRequest_oneof_req::structLogRequest(v))
     }
 
     // Mutable pointer to the field.
-    // If field is not initialized, it is initialized with default value first.
-    pub fn mut_publicKey(&mut self) -> &mut ::std::vec::Vec<u8> {
-        &mut self.publicKey
+    pub fn mut_structLogRequest(&mut self) -> &mut SGXVMStructLogRequest {
+        if let ::std::option::Option::Some(FFIRequest_oneof_req::structLogRequest(_)) = self.req {
+        } else {
+            self.req = ::std::option::Option::Some(FFIRequest_oneof_req::structLogRequest(SGXVMStructLogRequest::new()));
+        }
+        match self.req {
+            ::std::option::Option::Some(FFIRequest_oneof_req::structLogRequest(ref mut v)) => v,
+            _ => panic!(),
+        }
     }
 
     // Take field
-    pub fn take_publicKey(&mut self) -> ::std::vec::Vec<u8> {
-        ::std::mem::replace(&mut self.publicKey, ::std::vec::Vec::new())
+    pub fn take_structLogRequest(&mut self) -> SGXVMStructLogRequest {
+        if self.has_structLogRequest() {
+            match self.req.take() {
+                ::std::option::Option::Some(FFIRequest_oneof_req::structLogRequest(v)) => v,
+                _ => panic!(),
+            }
+        } else {
+            SGXVMStructLogRequest::new()
+        }
     }
-}
 
-impl ::protobuf::Message for NodePublicKeyResponse {
-    fn is_initialized(&self) -> bool {
-        true
-    }
+    // .ffi.ffi.SGXVMEstimateGasRequest estimateGasRequest = 7;
 
-    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
-        while !is.eof()? {
-            let (field_number, wire_type) = is.read_tag_unpack()?;
-            match field_number {
-                1 => {
-                    ::protobuf::rt::read_singular_proto3_bytes_into(wire_type, is, &mut self.publicKey)?;
-                },
-                _ => {
-                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
-                },
-            };
-        }
-        ::std::result::Result::Ok(())
-    }
 
-    // Compute sizes of nested messages
-    #[allow(unused_variables)]
-    fn compute_size(&self) -> u32 {
-        let mut my_size = 0;
-        if !self.publicKey.is_empty() {
-            my_size += ::protobuf::rt::bytes_size(1, &self.publicKey);
+    pub fn get_estimateGasRequest(&self) -> &SGXVMEstimateGasRequest {
+        match self.req {
+            ::std::option::Option::Some(FFIRequest_oneof_req::estimateGasRequest(ref v)) => v,
+            _ => SGXVMEstimateGasRequest::default_instance(),
         }
-        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
-        self.cached_size.set(my_size);
-        my_size
+    }
+    pub fn clear_estimateGasRequest(&mut self) {
+        self.req = ::std::option::Option::None;
     }
 
-    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
-        if !self.publicKey.is_empty() {
-            os.write_bytes(1, &self.publicKey)?;
+    pub fn has_estimateGasRequest(&self) -> bool {
+        match self.req {
+            ::std::option::Option::Some(FFIRequest_oneof_req::estimateGasRequest(..)) => true,
+            _ => false,
         }
-        os.write_unknown_fields(self.get_unknown_fields())?;
-        ::std::result::Result::Ok(())
     }
 
-    fn get_cached_size(&self) -> u32 {
-        self.cached_size.get()
+    // Param is passed by value, moved
+    pub fn set_estimateGasRequest(&mut self, v: SGXVMEstimateGasRequest) {
+        self.req = ::std::option::Option::Some(FFIRequest_oneof_req::estimateGasRequest(v))
     }
 
-    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
-        &self.unknown_fields
+    // Mutable pointer to the field.
+    pub fn mut_estimateGasRequest(&mut self) -> &mut SGXVMEstimateGasRequest {
+        if let ::std::option::Option::Some(FFIRequest_oneof_req::estimateGasRequest(_)) = self.req {
+        } else {
+            self.req = ::std::option::Option::Some(FFIRequest_oneof_req::estimateGasRequest(SGXVMEstimateGasRequest::new()));
+        }
+        match self.req {
+            ::std::option::Option::Some(FFIRequest_oneof_req::estimateGasRequest(ref mut v)) => v,
+            _ => panic!(),
+        }
     }
 
-    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
-        &mut self.unknown_fields
+    // Take field
+    pub fn take_estimateGasRequest(&mut self) -> SGXVMEstimateGasRequest {
+        if self.has_estimateGasRequest() {
+            match self.req.take() {
+                ::std::option::Option::Some(FFIRequest_oneof_req::estimateGasRequest(v)) => v,
+                _ => panic!(),
+            }
+        } else {
+            SGXVMEstimateGasRequest::new()
+        }
     }
 
-    fn as_any(&self) -> &dyn (::std::any::Any) {
-        self as &dyn (::std::any::Any)
-    }
-    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
-        self as &mut dyn (::std::any::Any)
+    // .ffi.ffi.SGXVMStateDiffRequest stateDiffRequest = 8;
+
+
+    pub fn get_stateDiffRequest(&self) -> &SGXVMStateDiffRequest {
+        match self.req {
+            ::std::option::Option::Some(FFIRequest_oneof_req::stateDiffRequest(ref v)) => v,
+            _ => SGXVMStateDiffRequest::default_instance(),
+        }
     }
-    fn into_any(self: Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
-        self
+    pub fn clear_stateDiffRequest(&mut self) {
+        self.req = ::std::option::Option::None;
     }
 
-    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
-        Self::descriptor_static()
+    pub fn has_stateDiffRequest(&self) -> bool {
+        match self.req {
+            ::std::option::Option::Some(FFIRequest_oneof_req::stateDiffRequest(..)) => true,
+            _ => false,
+        }
     }
 
-    fn new() -> NodePublicKeyResponse {
-        NodePublicKeyResponse::new()
+    // Param is passed by value, moved
+    pub fn set_stateDiffRequest(&mut self, v: SGXVMStateDiffRequest) {
+        self.req = ::std::option::Option::Some(FFIRequest_oneof_req::stateDiffRequest(v))
     }
 
-    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
-        static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::lazy::Lazy {
-            lock: ::protobuf::lazy::ONCE_INIT,
-            ptr: 0 as *const ::protobuf::reflect::MessageDescriptor,
-        };
-        unsafe {
-            descriptor.get(|| {
-                let mut fields = ::std::vec::Vec::new();
-                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeBytes>(
-                    "publicKey",
-                    |m: &NodePublicKeyResponse| { &m.publicKey },
-                    |m: &mut NodePublicKeyResponse| { &mut m.publicKey },
-                ));
-                ::protobuf::reflect::MessageDescriptor::new::<NodePublicKeyResponse>(
-                    "NodePublicKeyResponse",
-                    fields,
-                    file_descriptor_proto()
-                )
-            })
+    // Mutable pointer to the field.
+    pub fn mut_stateDiffRequest(&mut self) -> &mut SGXVMStateDiffRequest {
+        if let ::std::option::Option::Some(FFIRequest_oneof_req::stateDiffRequest(_)) = self.req {
+        } else {
+            self.req = ::std::option::Option::Some(FFIRequest_oneof_req::stateDiffRequest(SGXVMStateDiffRequest::new()));
+        }
+        match self.req {
+            ::std::option::Option::Some(FFIRequest_oneof_req::stateDiffRequest(ref mut v)) => v,
+            _ => panic!(),
         }
     }
 
-    fn default_instance() -> &'static NodePublicKeyResponse {
-        static mut instance: ::protobuf::lazy::Lazy<NodePublicKeyResponse> = ::protobuf::lazy::Lazy {
-            lock: ::protobuf::lazy::ONCE_INIT,
-            ptr: 0 as *const NodePublicKeyResponse,
-        };
-        unsafe {
-            instance.get(NodePublicKeyResponse::new)
+    // Take field
+    pub fn take_stateDiffRequest(&mut self) -> SGXVMStateDiffRequest {
+        if self.has_stateDiffRequest() {
+            match self.req.take() {
+                ::std::option::Option::Some(FFIRequest_oneof_req::stateDiffRequest(v)) => v,
+                _ => panic!(),
+            }
+        } else {
+            SGXVMStateDiffRequest::new()
         }
     }
-}
 
-impl ::protobuf::Clear for NodePublicKeyResponse {
-    fn clear(&mut self) {
-        self.publicKey.clear();
-        self.unknown_fields.clear();
-    }
-}
+    // .ffi.ffi.SGXVMCreateAccessListRequest createAccessListRequest = 9;
 
-impl ::std::fmt::Debug for NodePublicKeyResponse {
-    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
-        ::protobuf::text_format::fmt(self, f)
-    }
-}
 
-impl ::protobuf::reflect::ProtobufValue for NodePublicKeyResponse {
-    fn as_ref(&self) -> ::protobuf::reflect::ProtobufValueRef {
-        ::protobuf::reflect::ProtobufValueRef::Message(self)
+    pub fn get_createAccessListRequest(&self) -> &SGXVMCreateAccessListRequest {
+        match self.req {
+            ::std::option::Option::Some(FFIRequest_oneof_req::createAccessListRequest(ref v)) => v,
+            _ => SGXVMCreateAccessListRequest::default_instance(),
+        }
+    }
+    pub fn clear_createAccessListRequest(&mut self) {
+        self.req = ::std::option::Option::None;
     }
-}
 
-#[derive(PartialEq,Clone,Default)]
-pub struct FFIRequest {
-    // message oneof groups
-    pub req: ::std::option::Option<FFIRequest_oneof_req>,
-    // special fields
-    pub unknown_fields: ::protobuf::UnknownFields,
-    pub cached_size: ::protobuf::CachedSize,
-}
+    pub fn has_createAccessListRequest(&self) -> bool {
+        match self.req {
+            ::std::option::Option::Some(FFIRequest_oneof_req::createAccessListRequest(..)) => true,
+            _ => false,
+        }
+    }
 
-impl<'a> ::std::default::Default for &'a FFIRequest {
-    fn default() -> &'a FFIRequest {
-        <FFIRequest as ::protobuf::Message>::default_instance()
+    // Param is passed by value, moved
+    pub fn set_createAccessListRequest(&mut self, v: SGXVMCreateAccessListRequest) {
+        self.req = ::std::option::Option::Some(FFIRequest_oneof_req::createAccessListRequest(v))
     }
-}
 
-#[derive(Clone,PartialEq,Debug)]
-pub enum FFIRequest_oneof_req {
-    callRequest(SGXVMCallRequest),
-    createRequest(SGXVMCreateRequest),
-    publicKeyRequest(NodePublicKeyRequest),
-}
+    // Mutable pointer to the field.
+    pub fn mut_createAccessListRequest(&mut self) -> &mut SGXVMCreateAccessListRequest {
+        if let ::std::option::Option::Some(FFIRequest_oneof_req::createAccessListRequest(_)) = self.req {
+        } else {
+            self.req = ::std::option::Option::Some(FFIRequest_oneof_req::createAccessListRequest(SGXVMCreateAccessListRequest::new()));
+        }
+        match self.req {
+            ::std::option::Option::Some(FFIRequest_oneof_req::createAccessListRequest(ref mut v)) => v,
+            _ => panic!(),
+        }
+    }
 
-impl FFIRequest {
-    pub fn new() -> FFIRequest {
-        ::std::default::Default::default()
+    // Take field
+    pub fn take_createAccessListRequest(&mut self) -> SGXVMCreateAccessListRequest {
+        if self.has_createAccessListRequest() {
+            match self.req.take() {
+                ::std::option::Option::Some(FFIRequest_oneof_req::createAccessListRequest(v)) => v,
+                _ => panic!(),
+            }
+        } else {
+            SGXVMCreateAccessListRequest::new()
+        }
     }
 
-    // .ffi.ffi.SGXVMCallRequest callRequest = 1;
+    // .ffi.ffi.SGXVMBatchRequest batchRequest = 10;
 
 
-    pub fn get_callRequest(&self) -> &SGXVMCallRequest {
+    pub fn get_batchRequest(&self) -> &SGXVMBatchRequest {
         match self.req {
-            ::std::option::Option::Some(FFIRequest_oneof_req::callRequest(ref v)) => v,
-            _ => SGXVMCallRequest::default_instance(),
+            ::std::option::Option::Some(FFIRequest_oneof_req::batchRequest(ref v)) => v,
+            _ => SGXVMBatchRequest::default_instance(),
         }
     }
-    pub fn clear_callRequest(&mut self) {
+    pub fn clear_batchRequest(&mut self) {
         self.req = ::std::option::Option::None;
     }
 
-    pub fn has_callRequest(&self) -> bool {
+    pub fn has_batchRequest(&self) -> bool {
         match self.req {
-            ::std::option::Option::Some(FFIRequest_oneof_req::callRequest(..)) => true,
+            ::std::option::Option::Some(FFIRequest_oneof_req::batchRequest(..)) => true,
             _ => false,
         }
     }
 
     // Param is passed by value, moved
-    pub fn set_callRequest(&mut self, v: SGXVMCallRequest) {
-        self.req = ::std::option::Option::Some(FFIRequest_oneof_req::callRequest(v))
+    pub fn set_batchRequest(&mut self, v: SGXVMBatchRequest) {
+        self.req = ::std::option::Option::Some(FFIRequest_oneof_req::batchRequest(v))
     }
 
     // Mutable pointer to the field.
-    pub fn mut_callRequest(&mut self) -> &mut SGXVMCallRequest {
-        if let ::std::option::Option::Some(FFIRequest_oneof_req::callRequest(_)) = self.req {
+    pub fn mut_batchRequest(&mut self) -> &mut SGXVMBatchRequest {
+        if let ::std::option::Option::Some(FFIRequest_oneof_req::batchRequest(_)) = self.req {
         } else {
-            self.req = ::std::option::Option::Some(FFIRequest_oneof_req::callRequest(SGXVMCallRequest::new()));
+            self.req = ::std::option::Option::Some(FFIRequest_oneof_req::batchRequest(SGXVMBatchRequest::new()));
         }
         match self.req {
-            ::std::option::Option::Some(FFIRequest_oneof_req::callRequest(ref mut v)) => v,
+            ::std::option::Option::Some(FFIRequest_oneof_req::batchRequest(ref mut v)) => v,
             _ => panic!(),
         }
     }
 
     // Take field
-    pub fn take_callRequest(&mut self) -> SGXVMCallRequest {
-        if self.has_callRequest() {
+    pub fn take_batchRequest(&mut self) -> SGXVMBatchRequest {
+        if self.has_batchRequest() {
             match self.req.take() {
-                ::std::option::Option::Some(FFIRequest_oneof_req::callRequest(v)) => v,
+                ::std::option::Option::Some(FFIRequest_oneof_req::batchRequest(v)) => v,
                 _ => panic!(),
             }
         } else {
-            SGXVMCallRequest::new()
+            SGXVMBatchRequest::new()
         }
     }
 
-    // .ffi.ffi.SGXVMCreateRequest createRequest = 2;
+    // .ffi.ffi.SGXVMSignedTransactionRequest signedTransactionRequest = 11;
 
 
-    pub fn get_createRequest(&self) -> &SGXVMCreateRequest {
+    pub fn get_signedTransactionRequest(&self) -> &SGXVMSignedTransactionRequest {
         match self.req {
-            ::std::option::Option::Some(FFIRequest_oneof_req::createRequest(ref v)) => v,
-            _ => SGXVMCreateRequest::default_instance(),
+            ::std::option::Option::Some(FFIRequest_oneof_req::signedTransactionRequest(ref v)) => v,
+            _ => SGXVMSignedTransactionRequest::default_instance(),
         }
     }
-    pub fn clear_createRequest(&mut self) {
+    pub fn clear_signedTransactionRequest(&mut self) {
         self.req = ::std::option::Option::None;
     }
 
-    pub fn has_createRequest(&self) -> bool {
+    pub fn has_signedTransactionRequest(&self) -> bool {
         match self.req {
-            ::std::option::Option::Some(FFIRequest_oneof_req::createRequest(..)) => true,
+            ::std::option::Option::Some(FFIRequest_oneof_req::signedTransactionRequest(..)) => true,
             _ => false,
         }
     }
 
     // Param is passed by value, moved
-    pub fn set_createRequest(&mut self, v: SGXVMCreateRequest) {
-        self.req = ::std::option::Option::Some(FFIRequest_oneof_req::createRequest(v))
+    pub fn set_signedTransactionRequest(&mut self, v: SGXVMSignedTransactionRequest) {
+        self.req = ::std::option::Option::Some(FFIRequest_oneof_req::signedTransactionRequest(v))
     }
 
     // Mutable pointer to the field.
-    pub fn mut_createRequest(&mut self) -> &mut SGXVMCreateRequest {
-        if let ::std::option::Option::Some(FFIRequest_oneof_req::createRequest(_)) = self.req {
+    pub fn mut_signedTransactionRequest(&mut self) -> &mut SGXVMSignedTransactionRequest {
+        if let ::std::option::Option::Some(FFIRequest_oneof_req::signedTransactionRequest(_)) = self.req {
         } else {
-            self.req = ::std::option::Option::Some(FFIRequest_oneof_req::createRequest(SGXVMCreateRequest::new()));
+            self.req = ::std::option::Option::Some(FFIRequest_oneof_req::signedTransactionRequest(SGXVMSignedTransactionRequest::new()));
         }
         match self.req {
-            ::std::option::Option::Some(FFIRequest_oneof_req::createRequest(ref mut v)) => v,
+            ::std::option::Option::Some(FFIRequest_oneof_req::signedTransactionRequest(ref mut v)) => v,
             _ => panic!(),
         }
     }
 
     // Take field
-    pub fn take_createRequest(&mut self) -> SGXVMCreateRequest {
-        if self.has_createRequest() {
+    pub fn take_signedTransactionRequest(&mut self) -> SGXVMSignedTransactionRequest {
+        if self.has_signedTransactionRequest() {
             match self.req.take() {
-                ::std::option::Option::Some(FFIRequest_oneof_req::createRequest(v)) => v,
+                ::std::option::Option::Some(FFIRequest_oneof_req::signedTransactionRequest(v)) => v,
                 _ => panic!(),
             }
         } else {
-            SGXVMCreateRequest::new()
+            SGXVMSignedTransactionRequest::new()
         }
     }
 
-    // .ffi.ffi.NodePublicKeyRequest publicKeyRequest = 3;
+    // .ffi.ffi.ConfigurePrecompilesRequest configurePrecompilesRequest = 12;
 
 
-    pub fn get_publicKeyRequest(&self) -> &NodePublicKeyRequest {
+    pub fn get_configurePrecompilesRequest(&self) -> &ConfigurePrecompilesRequest {
         match self.req {
-            ::std::option::Option::Some(FFIRequest_oneof_req::publicKeyRequest(ref v)) => v,
-            _ => NodePublicKeyRequest::default_instance(),
+            ::std::option::Option::Some(FFIRequest_oneof_req::configurePrecompilesRequest(ref v)) => v,
+            _ => ConfigurePrecompilesRequest::default_instance(),
         }
     }
-    pub fn clear_publicKeyRequest(&mut self) {
+    pub fn clear_configurePrecompilesRequest(&mut self) {
         self.req = ::std::option::Option::None;
     }
 
-    pub fn has_publicKeyRequest(&self) -> bool {
+    pub fn has_configurePrecompilesRequest(&self) -> bool {
         match self.req {
-            ::std::option::Option::Some(FFIRequest_oneof_req::publicKeyRequest(..)) => true,
+            ::std::option::Option::Some(FFIRequest_oneof_req::configurePrecompilesRequest(..)) => true,
             _ => false,
         }
     }
 
     // Param is passed by value, moved
-    pub fn set_publicKeyRequest(&mut self, v: NodePublicKeyRequest) {
-        self.req = ::std::option::Option::Some(FFIRequest_oneof_req::publicKeyRequest(v))
+    pub fn set_configurePrecompilesRequest(&mut self, v: ConfigurePrecompilesRequest) {
+        self.req = ::std::option::Option::Some(FFIRequest_oneof_req::configurePrecompilesRequest(v))
     }
 
     // Mutable pointer to the field.
-    pub fn mut_publicKeyRequest(&mut self) -> &mut NodePublicKeyRequest {
-        if let ::std::option::Option::Some(FFIRequest_oneof_req::publicKeyRequest(_)) = self.req {
+    pub fn mut_configurePrecompilesRequest(&mut self) -> &mut ConfigurePrecompilesRequest {
+        if let ::std::option::Option::Some(FFIRequest_oneof_req::configurePrecompilesRequest(_)) = self.req {
         } else {
-            self.req = ::std::option::Option::Some(FFIRequest_oneof_req::publicKeyRequest(NodePublicKeyRequest::new()));
+            self.req = ::std::option::Option::Some(FFIRequest_oneof_req::configurePrecompilesRequest(ConfigurePrecompilesRequest::new()));
         }
         match self.req {
-            ::std::option::Option::Some(FFIRequest_oneof_req::publicKeyRequest(ref mut v)) => v,
+            ::std::option::Option::Some(FFIRequest_oneof_req::configurePrecompilesRequest(ref mut v)) => v,
             _ => panic!(),
         }
     }
 
     // Take field
-    pub fn take_publicKeyRequest(&mut self) -> NodePublicKeyRequest {
-        if self.has_publicKeyRequest() {
+    pub fn take_configurePrecompilesRequest(&mut self) -> ConfigurePrecompilesRequest {
+        if self.has_configurePrecompilesRequest() {
             match self.req.take() {
-                ::std::option::Option::Some(FFIRequest_oneof_req::publicKeyRequest(v)) => v,
+                ::std::option::Option::Some(FFIRequest_oneof_req::configurePrecompilesRequest(v)) => v,
                 _ => panic!(),
             }
         } else {
-            NodePublicKeyRequest::new()
+            ConfigurePrecompilesRequest::new()
         }
     }
 }
@@ -8552,6 +18336,51 @@ impl ::protobuf::Message for FFIRequest {
                 return false;
             }
         }
+        if let Some(FFIRequest_oneof_req::traceCallRequest(ref v)) = self.req {
+            if !v.is_initialized() {
+                return false;
+            }
+        }
+        if let Some(FFIRequest_oneof_req::traceCreateRequest(ref v)) = self.req {
+            if !v.is_initialized() {
+                return false;
+            }
+        }
+        if let Some(FFIRequest_oneof_req::structLogRequest(ref v)) = self.req {
+            if !v.is_initialized() {
+                return false;
+            }
+        }
+        if let Some(FFIRequest_oneof_req::estimateGasRequest(ref v)) = self.req {
+            if !v.is_initialized() {
+                return false;
+            }
+        }
+        if let Some(FFIRequest_oneof_req::stateDiffRequest(ref v)) = self.req {
+            if !v.is_initialized() {
+                return false;
+            }
+        }
+        if let Some(FFIRequest_oneof_req::createAccessListRequest(ref v)) = self.req {
+            if !v.is_initialized() {
+                return false;
+            }
+        }
+        if let Some(FFIRequest_oneof_req::batchRequest(ref v)) = self.req {
+            if !v.is_initialized() {
+                return false;
+            }
+        }
+        if let Some(FFIRequest_oneof_req::signedTransactionRequest(ref v)) = self.req {
+            if !v.is_initialized() {
+                return false;
+            }
+        }
+        if let Some(FFIRequest_oneof_req::configurePrecompilesRequest(ref v)) = self.req {
+            if !v.is_initialized() {
+                return false;
+            }
+        }
         true
     }
 
@@ -8577,6 +18406,60 @@ impl ::protobuf::Message for FFIRequest {
                     }
                     self.req = ::std::option::Option::Some(FFIRequest_oneof_req::publicKeyRequest(is.read_message()?));
                 },
+                4 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeLengthDelimited {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    self.req = ::std::option::Option::Some(FFIRequest_oneof_req::traceCallRequest(is.read_message()?));
+                },
+                5 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeLengthDelimited {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    self.req = ::std::option::Option::Some(FFIRequest_oneof_req::traceCreateRequest(is.read_message()?));
+                },
+                6 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeLengthDelimited {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    self.req = ::std::option::Option::Some(FFIRequest_oneof_req::structLogRequest(is.read_message()?));
+                },
+                7 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeLengthDelimited {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    self.req = ::std::option::Option::Some(FFIRequest_oneof_req::estimateGasRequest(is.read_message()?));
+                },
+                8 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeLengthDelimited {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    self.req = ::std::option::Option::Some(FFIRequest_oneof_req::stateDiffRequest(is.read_message()?));
+                },
+                9 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeLengthDelimited {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    self.req = ::std::option::Option::Some(FFIRequest_oneof_req::createAccessListRequest(is.read_message()?));
+                },
+                10 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeLengthDelimited {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    self.req = ::std::option::Option::Some(FFIRequest_oneof_req::batchRequest(is.read_message()?));
+                },
+                11 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeLengthDelimited {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    self.req = ::std::option::Option::Some(FFIRequest_oneof_req::signedTransactionRequest(is.read_message()?));
+                },
+                12 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeLengthDelimited {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    self.req = ::std::option::Option::Some(FFIRequest_oneof_req::configurePrecompilesRequest(is.read_message()?));
+                },
                 _ => {
                     ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
                 },
@@ -8603,6 +18486,42 @@ impl ::protobuf::Message for FFIRequest {
                     let len = v.compute_size();
                     my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
                 },
+                &FFIRequest_oneof_req::traceCallRequest(ref v) => {
+                    let len = v.compute_size();
+                    my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+                },
+                &FFIRequest_oneof_req::traceCreateRequest(ref v) => {
+                    let len = v.compute_size();
+                    my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+                },
+                &FFIRequest_oneof_req::structLogRequest(ref v) => {
+                    let len = v.compute_size();
+                    my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+                },
+                &FFIRequest_oneof_req::estimateGasRequest(ref v) => {
+                    let len = v.compute_size();
+                    my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+                },
+                &FFIRequest_oneof_req::stateDiffRequest(ref v) => {
+                    let len = v.compute_size();
+                    my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+                },
+                &FFIRequest_oneof_req::createAccessListRequest(ref v) => {
+                    let len = v.compute_size();
+                    my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+                },
+                &FFIRequest_oneof_req::batchRequest(ref v) => {
+                    let len = v.compute_size();
+                    my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+                },
+                &FFIRequest_oneof_req::signedTransactionRequest(ref v) => {
+                    let len = v.compute_size();
+                    my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+                },
+                &FFIRequest_oneof_req::configurePrecompilesRequest(ref v) => {
+                    let len = v.compute_size();
+                    my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+                },
             };
         }
         my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
@@ -8628,6 +18547,51 @@ impl ::protobuf::Message for FFIRequest {
                     os.write_raw_varint32(v.get_cached_size())?;
                     v.write_to_with_cached_sizes(os)?;
                 },
+                &FFIRequest_oneof_req::traceCallRequest(ref v) => {
+                    os.write_tag(4, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+                    os.write_raw_varint32(v.get_cached_size())?;
+                    v.write_to_with_cached_sizes(os)?;
+                },
+                &FFIRequest_oneof_req::traceCreateRequest(ref v) => {
+                    os.write_tag(5, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+                    os.write_raw_varint32(v.get_cached_size())?;
+                    v.write_to_with_cached_sizes(os)?;
+                },
+                &FFIRequest_oneof_req::structLogRequest(ref v) => {
+                    os.write_tag(6, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+                    os.write_raw_varint32(v.get_cached_size())?;
+                    v.write_to_with_cached_sizes(os)?;
+                },
+                &FFIRequest_oneof_req::estimateGasRequest(ref v) => {
+                    os.write_tag(7, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+                    os.write_raw_varint32(v.get_cached_size())?;
+                    v.write_to_with_cached_sizes(os)?;
+                },
+                &FFIRequest_oneof_req::stateDiffRequest(ref v) => {
+                    os.write_tag(8, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+                    os.write_raw_varint32(v.get_cached_size())?;
+                    v.write_to_with_cached_sizes(os)?;
+                },
+                &FFIRequest_oneof_req::createAccessListRequest(ref v) => {
+                    os.write_tag(9, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+                    os.write_raw_varint32(v.get_cached_size())?;
+                    v.write_to_with_cached_sizes(os)?;
+                },
+                &FFIRequest_oneof_req::batchRequest(ref v) => {
+                    os.write_tag(10, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+                    os.write_raw_varint32(v.get_cached_size())?;
+                    v.write_to_with_cached_sizes(os)?;
+                },
+                &FFIRequest_oneof_req::signedTransactionRequest(ref v) => {
+                    os.write_tag(11, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+                    os.write_raw_varint32(v.get_cached_size())?;
+                    v.write_to_with_cached_sizes(os)?;
+                },
+                &FFIRequest_oneof_req::configurePrecompilesRequest(ref v) => {
+                    os.write_tag(12, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+                    os.write_raw_varint32(v.get_cached_size())?;
+                    v.write_to_with_cached_sizes(os)?;
+                },
             };
         }
         os.write_unknown_fields(self.get_unknown_fields())?;
@@ -8687,6 +18651,51 @@ impl ::protobuf::Message for FFIRequest {
                     FFIRequest::has_publicKeyRequest,
                     FFIRequest::get_publicKeyRequest,
                 ));
+                fields.push(::protobuf::reflect::accessor::make_singular_message_accessor::<_, SGXVMCallRequest>(
+                    "traceCallRequest",
+                    FFIRequest::has_traceCallRequest,
+                    FFIRequest::get_traceCallRequest,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_singular_message_accessor::<_, SGXVMCreateRequest>(
+                    "traceCreateRequest",
+                    FFIRequest::has_traceCreateRequest,
+                    FFIRequest::get_traceCreateRequest,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_singular_message_accessor::<_, SGXVMStructLogRequest>(
+                    "structLogRequest",
+                    FFIRequest::has_structLogRequest,
+                    FFIRequest::get_structLogRequest,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_singular_message_accessor::<_, SGXVMEstimateGasRequest>(
+                    "estimateGasRequest",
+                    FFIRequest::has_estimateGasRequest,
+                    FFIRequest::get_estimateGasRequest,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_singular_message_accessor::<_, SGXVMStateDiffRequest>(
+                    "stateDiffRequest",
+                    FFIRequest::has_stateDiffRequest,
+                    FFIRequest::get_stateDiffRequest,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_singular_message_accessor::<_, SGXVMCreateAccessListRequest>(
+                    "createAccessListRequest",
+                    FFIRequest::has_createAccessListRequest,
+                    FFIRequest::get_createAccessListRequest,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_singular_message_accessor::<_, SGXVMBatchRequest>(
+                    "batchRequest",
+                    FFIRequest::has_batchRequest,
+                    FFIRequest::get_batchRequest,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_singular_message_accessor::<_, SGXVMSignedTransactionRequest>(
+                    "signedTransactionRequest",
+                    FFIRequest::has_signedTransactionRequest,
+                    FFIRequest::get_signedTransactionRequest,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_singular_message_accessor::<_, ConfigurePrecompilesRequest>(
+                    "configurePrecompilesRequest",
+                    FFIRequest::has_configurePrecompilesRequest,
+                    FFIRequest::get_configurePrecompilesRequest,
+                ));
                 ::protobuf::reflect::MessageDescriptor::new::<FFIRequest>(
                     "FFIRequest",
                     fields,
@@ -8709,6 +18718,15 @@ impl ::protobuf::Message for FFIRequest {
 
 impl ::protobuf::Clear for FFIRequest {
     fn clear(&mut self) {
+        self.req = ::std::option::Option::None;
+        self.req = ::std::option::Option::None;
+        self.req = ::std::option::Option::None;
+        self.req = ::std::option::Option::None;
+        self.req = ::std::option::Option::None;
+        self.req = ::std::option::Option::None;
+        self.req = ::std::option::Option::None;
+        self.req = ::std::option::Option::None;
+        self.req = ::std::option::Option::None;
         self.req = ::std::option::Option::None;
         self.req = ::std::option::Option::None;
         self.req = ::std::option::Option::None;
@@ -8736,41 +18754,58 @@ static file_descriptor_proto_data: &'static [u8] = b"\
     o\x18\x02\x20\x01(\x0cR\x02to\x12\x12\n\x04data\x18\x03\x20\x01(\x0cR\
     \x04data\x12\x1a\n\x08gasLimit\x18\x04\x20\x01(\x04R\x08gasLimit\x12\x14\
     \n\x05value\x18\x05\x20\x01(\x0cR\x05value\x127\n\naccessList\x18\x06\
-    \x20\x03(\x0b2\x17.ffi.ffi.AccessListItemR\naccessList\"\x90\x02\n\x12Tr\
+    \x20\x03(\x0b2\x17.ffi.ffi.AccessListItemR\naccessList\"\xf2\x02\n\x12Tr\
     ansactionContext\x12\x19\n\x08chain_id\x18\x01\x20\x01(\x04R\x07chainId\
     \x12\x1b\n\tgas_price\x18\x02\x20\x01(\x0cR\x08gasPrice\x12\x1c\n\ttimes\
     tamp\x18\x03\x20\x01(\x04R\ttimestamp\x12&\n\x0fblock_gas_limit\x18\x04\
     \x20\x01(\x04R\rblockGasLimit\x122\n\x16block_base_fee_per_gas\x18\x05\
     \x20\x01(\x0cR\x12blockBaseFeePerGas\x12%\n\x0eblock_coinbase\x18\x06\
     \x20\x01(\x0cR\rblockCoinbase\x12!\n\x0cblock_number\x18\x07\x20\x01(\
-    \x04R\x0bblockNumber\"\x89\x01\n\x18HandleTransactionRequest\x121\n\x07t\
-    x_data\x18\x01\x20\x01(\x0b2\x18.ffi.ffi.TransactionDataR\x06txData\x12:\
-    \n\ntx_context\x18\x02\x20\x01(\x0b2\x1b.ffi.ffi.TransactionContextR\ttx\
-    Context\"\x85\x01\n\x19HandleTransactionResponse\x12\x20\n\x04logs\x18\
-    \x02\x20\x03(\x0b2\x0c.ffi.ffi.LogR\x04logs\x12\x10\n\x03ret\x18\x03\x20\
-    \x01(\x0cR\x03ret\x12\x19\n\x08vm_error\x18\x04\x20\x01(\tR\x07vmError\
-    \x12\x19\n\x08gas_used\x18\x05\x20\x01(\x04R\x07gasUsed\"\x1d\n\x05Topic\
-    \x12\x14\n\x05inner\x18\x01\x20\x01(\x0cR\x05inner\"[\n\x03Log\x12\x18\n\
-    \x07address\x18\x01\x20\x01(\x0cR\x07address\x12&\n\x06topics\x18\x02\
-    \x20\x03(\x0b2\x0e.ffi.ffi.TopicR\x06topics\x12\x12\n\x04data\x18\x03\
-    \x20\x01(\x0cR\x04data\"+\n\x0fQueryGetAccount\x12\x18\n\x07address\x18\
-    \x01\x20\x01(\x0cR\x07address\"I\n\x17QueryGetAccountResponse\x12\x18\n\
-    \x07balance\x18\x01\x20\x01(\x0cR\x07balance\x12\x14\n\x05nonce\x18\x02\
-    \x20\x01(\x04R\x05nonce\"^\n\x12QueryInsertAccount\x12\x18\n\x07address\
-    \x18\x01\x20\x01(\x0cR\x07address\x12\x18\n\x07balance\x18\x02\x20\x01(\
-    \x0cR\x07balance\x12\x14\n\x05nonce\x18\x03\x20\x01(\x04R\x05nonce\"\x1c\
-    \n\x1aQueryInsertAccountResponse\"$\n\x10QueryContainsKey\x12\x10\n\x03k\
-    ey\x18\x01\x20\x01(\x0cR\x03key\"6\n\x18QueryContainsKeyResponse\x12\x1a\
-    \n\x08contains\x18\x01\x20\x01(\x08R\x08contains\"L\n\x1aQueryGetAccount\
-    StorageCell\x12\x18\n\x07address\x18\x01\x20\x01(\x0cR\x07address\x12\
-    \x14\n\x05index\x18\x02\x20\x01(\x0cR\x05index\":\n\"QueryGetAccountStor\
-    ageCellResponse\x12\x14\n\x05value\x18\x01\x20\x01(\x0cR\x05value\"/\n\
-    \x13QueryGetAccountCode\x12\x18\n\x07address\x18\x01\x20\x01(\x0cR\x07ad\
-    dress\"1\n\x1bQueryGetAccountCodeResponse\x12\x12\n\x04code\x18\x01\x20\
-    \x01(\x0cR\x04code\"F\n\x16QueryInsertAccountCode\x12\x18\n\x07address\
-    \x18\x01\x20\x01(\x0cR\x07address\x12\x12\n\x04code\x18\x02\x20\x01(\x0c\
-    R\x04code\"\x20\n\x1eQueryInsertAccountCodeResponse\"^\n\x16QueryInsertS\
-    torageCell\x12\x18\n\x07address\x18\x01\x20\x01(\x0cR\x07address\x12\x14\
+    \x04R\x0bblockNumber\x12\x19\n\x08evm_fork\x18\x08\x20\x01(\rR\x07evmFor\
+    k\x12\x1f\n\x0bprev_randao\x18\t\x20\x01(\x0cR\nprevRandao\x12$\n\x0eblo\
+    ck_gas_used\x18\n\x20\x01(\x04R\x0cblockGasUsed\"\x89\x01\n\x18HandleTra\
+    nsactionRequest\x121\n\x07tx_data\x18\x01\x20\x01(\x0b2\x18.ffi.ffi.Tran\
+    sactionDataR\x06txData\x12:\n\ntx_context\x18\x02\x20\x01(\x0b2\x1b.ffi.\
+    ffi.TransactionContextR\ttxContext\"\xb4\x03\n\x19HandleTransactionRespo\
+    nse\x12\x20\n\x04logs\x18\x02\x20\x03(\x0b2\x0c.ffi.ffi.LogR\x04logs\x12\
+    \x10\n\x03ret\x18\x03\x20\x01(\x0cR\x03ret\x12\x19\n\x08vm_error\x18\x04\
+    \x20\x01(\tR\x07vmError\x12\x19\n\x08gas_used\x18\x05\x20\x01(\x04R\x07g\
+    asUsed\x12-\n\x06revert\x18\x06\x20\x01(\x0b2\x15.ffi.ffi.RevertReasonR\
+    \x06revert\x12\x1e\n\nexitStatus\x18\x07\x20\x01(\tR\nexitStatus\x12\x1e\
+    \n\nexitReason\x18\x08\x20\x01(\tR\nexitReason\x12(\n\x0fcontractAddress\
+    \x18\t\x20\x01(\x0cR\x0fcontractAddress\x12\x20\n\x0bgasRefunded\x18\n\
+    \x20\x01(\x04R\x0bgasRefunded\x12\"\n\x0cintrinsicGas\x18\x0b\x20\x01(\
+    \x04R\x0cintrinsicGas\x12\"\n\x0cexecutionGas\x18\x0c\x20\x01(\x04R\x0ce\
+    xecutionGas\x12*\n\x07receipt\x18\r\x20\x01(\x0b2\x10.ffi.ffi.ReceiptR\
+    \x07receipt\"\x87\x01\n\x07Receipt\x12\x16\n\x06status\x18\x01\x20\x01(\
+    \rR\x06status\x12,\n\x11cumulativeGasUsed\x18\x02\x20\x01(\x04R\x11cumul\
+    ativeGasUsed\x12\x1c\n\tlogsBloom\x18\x03\x20\x01(\x0cR\tlogsBloom\x12\
+    \x18\n\x07encoded\x18\x04\x20\x01(\x0cR\x07encoded\"\x8a\x01\n\x0cRevert\
+    Reason\x12\x12\n\x04kind\x18\x01\x20\x01(\tR\x04kind\x12\x18\n\x07messag\
+    e\x18\x02\x20\x01(\tR\x07message\x12\x1c\n\tpanicCode\x18\x03\x20\x01(\
+    \x04R\tpanicCode\x12\x1a\n\x08selector\x18\x04\x20\x01(\x0cR\x08selector\
+    \x12\x12\n\x04args\x18\x05\x20\x01(\x0cR\x04args\"\x1d\n\x05Topic\x12\
+    \x14\n\x05inner\x18\x01\x20\x01(\x0cR\x05inner\"[\n\x03Log\x12\x18\n\x07\
+    address\x18\x01\x20\x01(\x0cR\x07address\x12&\n\x06topics\x18\x02\x20\
+    \x03(\x0b2\x0e.ffi.ffi.TopicR\x06topics\x12\x12\n\x04data\x18\x03\x20\
+    \x01(\x0cR\x04data\"+\n\x0fQueryGetAccount\x12\x18\n\x07address\x18\x01\
+    \x20\x01(\x0cR\x07address\"I\n\x17QueryGetAccountResponse\x12\x18\n\x07b\
+    alance\x18\x01\x20\x01(\x0cR\x07balance\x12\x14\n\x05nonce\x18\x02\x20\
+    \x01(\x04R\x05nonce\"^\n\x12QueryInsertAccount\x12\x18\n\x07address\x18\
+    \x01\x20\x01(\x0cR\x07address\x12\x18\n\x07balance\x18\x02\x20\x01(\x0cR\
+    \x07balance\x12\x14\n\x05nonce\x18\x03\x20\x01(\x04R\x05nonce\"\x1c\n\
+    \x1aQueryInsertAccountResponse\"$\n\x10QueryContainsKey\x12\x10\n\x03key\
+    \x18\x01\x20\x01(\x0cR\x03key\"6\n\x18QueryContainsKeyResponse\x12\x1a\n\
+    \x08contains\x18\x01\x20\x01(\x08R\x08contains\"L\n\x1aQueryGetAccountSt\
+    orageCell\x12\x18\n\x07address\x18\x01\x20\x01(\x0cR\x07address\x12\x14\
+    \n\x05index\x18\x02\x20\x01(\x0cR\x05index\":\n\"QueryGetAccountStorageC\
+    ellResponse\x12\x14\n\x05value\x18\x01\x20\x01(\x0cR\x05value\"/\n\x13Qu\
+    eryGetAccountCode\x12\x18\n\x07address\x18\x01\x20\x01(\x0cR\x07address\
+    \"1\n\x1bQueryGetAccountCodeResponse\x12\x12\n\x04code\x18\x01\x20\x01(\
+    \x0cR\x04code\"F\n\x16QueryInsertAccountCode\x12\x18\n\x07address\x18\
+    \x01\x20\x01(\x0cR\x07address\x12\x12\n\x04code\x18\x02\x20\x01(\x0cR\
+    \x04code\"\x20\n\x1eQueryInsertAccountCodeResponse\"^\n\x16QueryInsertSt\
+    orageCell\x12\x18\n\x07address\x18\x01\x20\x01(\x0cR\x07address\x12\x14\
     \n\x05index\x18\x02\x20\x01(\x0cR\x05index\x12\x14\n\x05value\x18\x03\
     \x20\x01(\x0cR\x05value\"\x20\n\x1eQueryInsertStorageCellResponse\"'\n\
     \x0bQueryRemove\x12\x18\n\x07address\x18\x01\x20\x01(\x0cR\x07address\"\
@@ -8780,58 +18815,172 @@ static file_descriptor_proto_data: &'static [u8] = b"\
     \x12QueryRemoveStorage\x12\x18\n\x07address\x18\x01\x20\x01(\x0cR\x07add\
     ress\"\x1c\n\x1aQueryRemoveStorageResponse\"(\n\x0eQueryBlockHash\x12\
     \x16\n\x06number\x18\x01\x20\x01(\x0cR\x06number\",\n\x16QueryBlockHashR\
-    esponse\x12\x12\n\x04hash\x18\x01\x20\x01(\x0cR\x04hash\"\x82\x06\n\rCos\
-    mosRequest\x12:\n\ngetAccount\x18\x01\x20\x01(\x0b2\x18.ffi.ffi.QueryGet\
-    AccountH\0R\ngetAccount\x12C\n\rinsertAccount\x18\x02\x20\x01(\x0b2\x1b.\
-    ffi.ffi.QueryInsertAccountH\0R\rinsertAccount\x12=\n\x0bcontainsKey\x18\
-    \x03\x20\x01(\x0b2\x19.ffi.ffi.QueryContainsKeyH\0R\x0bcontainsKey\x12@\
-    \n\x0baccountCode\x18\x04\x20\x01(\x0b2\x1c.ffi.ffi.QueryGetAccountCodeH\
-    \0R\x0baccountCode\x12G\n\x0bstorageCell\x18\x05\x20\x01(\x0b2#.ffi.ffi.\
-    QueryGetAccountStorageCellH\0R\x0bstorageCell\x12O\n\x11insertAccountCod\
-    e\x18\x06\x20\x01(\x0b2\x1f.ffi.ffi.QueryInsertAccountCodeH\0R\x11insert\
-    AccountCode\x12O\n\x11insertStorageCell\x18\x07\x20\x01(\x0b2\x1f.ffi.ff\
-    i.QueryInsertStorageCellH\0R\x11insertStorageCell\x12.\n\x06remove\x18\
-    \x08\x20\x01(\x0b2\x14.ffi.ffi.QueryRemoveH\0R\x06remove\x12O\n\x11remov\
-    eStorageCell\x18\t\x20\x01(\x0b2\x1f.ffi.ffi.QueryRemoveStorageCellH\0R\
-    \x11removeStorageCell\x12C\n\rremoveStorage\x18\n\x20\x01(\x0b2\x1b.ffi.\
-    ffi.QueryRemoveStorageH\0R\rremoveStorage\x127\n\tblockHash\x18\x0b\x20\
-    \x01(\x0b2\x17.ffi.ffi.QueryBlockHashH\0R\tblockHashB\x05\n\x03req\"\xe2\
-    \x01\n\x0fSGXVMCallParams\x12\x12\n\x04from\x18\x01\x20\x01(\x0cR\x04fro\
-    m\x12\x0e\n\x02to\x18\x02\x20\x01(\x0cR\x02to\x12\x12\n\x04data\x18\x03\
-    \x20\x01(\x0cR\x04data\x12\x1a\n\x08gasLimit\x18\x04\x20\x01(\x04R\x08ga\
-    sLimit\x12\x14\n\x05value\x18\x05\x20\x01(\x0cR\x05value\x127\n\naccessL\
-    ist\x18\x06\x20\x03(\x0b2\x17.ffi.ffi.AccessListItemR\naccessList\x12\
-    \x16\n\x06commit\x18\x07\x20\x01(\x08R\x06commit\x12\x14\n\x05nonce\x18\
-    \x08\x20\x01(\x04R\x05nonce\"\xd4\x01\n\x11SGXVMCreateParams\x12\x12\n\
-    \x04from\x18\x01\x20\x01(\x0cR\x04from\x12\x12\n\x04data\x18\x02\x20\x01\
-    (\x0cR\x04data\x12\x1a\n\x08gasLimit\x18\x03\x20\x01(\x04R\x08gasLimit\
-    \x12\x14\n\x05value\x18\x04\x20\x01(\x0cR\x05value\x127\n\naccessList\
-    \x18\x05\x20\x03(\x0b2\x17.ffi.ffi.AccessListItemR\naccessList\x12\x16\n\
-    \x06commit\x18\x06\x20\x01(\x08R\x06commit\x12\x14\n\x05nonce\x18\x07\
-    \x20\x01(\x04R\x05nonce\"{\n\x10SGXVMCallRequest\x120\n\x06params\x18\
-    \x01\x20\x01(\x0b2\x18.ffi.ffi.SGXVMCallParamsR\x06params\x125\n\x07cont\
-    ext\x18\x02\x20\x01(\x0b2\x1b.ffi.ffi.TransactionContextR\x07context\"\
-    \x7f\n\x12SGXVMCreateRequest\x122\n\x06params\x18\x01\x20\x01(\x0b2\x1a.\
-    ffi.ffi.SGXVMCreateParamsR\x06params\x125\n\x07context\x18\x02\x20\x01(\
-    \x0b2\x1b.ffi.ffi.TransactionContextR\x07context\"\x16\n\x14NodePublicKe\
-    yRequest\"5\n\x15NodePublicKeyResponse\x12\x1c\n\tpublicKey\x18\x01\x20\
-    \x01(\x0cR\tpublicKey\"\xe4\x01\n\nFFIRequest\x12=\n\x0bcallRequest\x18\
-    \x01\x20\x01(\x0b2\x19.ffi.ffi.SGXVMCallRequestH\0R\x0bcallRequest\x12C\
-    \n\rcreateRequest\x18\x02\x20\x01(\x0b2\x1b.ffi.ffi.SGXVMCreateRequestH\
-    \0R\rcreateRequest\x12K\n\x10publicKeyRequest\x18\x03\x20\x01(\x0b2\x1d.\
-    ffi.ffi.NodePublicKeyRequestH\0R\x10publicKeyRequestB\x05\n\x03reqB\x04Z\
-    \x02./J\xeb6\n\x07\x12\x05\0\0\xc9\x01\x01\n\x08\n\x01\x0c\x12\x03\0\0\
-    \x12\n\x08\n\x01\x02\x12\x03\x02\0\x10\n\x08\n\x01\x08\x12\x03\x04\0\x19\
-    \n\t\n\x02\x08\x0b\x12\x03\x04\0\x19\n\x1d\n\x02\x04\0\x12\x04\x08\0\x0b\
-    \x012\x11\x20General\x20request\n\n\n\n\x03\x04\0\x01\x12\x03\x08\x08\
-    \x16\n\x0b\n\x04\x04\0\x02\0\x12\x03\t\x02!\n\x0c\n\x05\x04\0\x02\0\x04\
-    \x12\x03\t\x02\n\n\x0c\n\x05\x04\0\x02\0\x05\x12\x03\t\x0b\x10\n\x0c\n\
-    \x05\x04\0\x02\0\x01\x12\x03\t\x11\x1c\n\x0c\n\x05\x04\0\x02\0\x03\x12\
-    \x03\t\x1f\x20\n\x0b\n\x04\x04\0\x02\x01\x12\x03\n\x02\x14\n\x0c\n\x05\
-    \x04\0\x02\x01\x05\x12\x03\n\x02\x07\n\x0c\n\x05\x04\0\x02\x01\x01\x12\
-    \x03\n\x08\x0f\n\x0c\n\x05\x04\0\x02\x01\x03\x12\x03\n\x12\x13\n\n\n\x02\
-    \x04\x01\x12\x04\r\0\x14\x01\n\n\n\x03\x04\x01\x01\x12\x03\r\x08\x17\n\
-    \x0b\n\x04\x04\x01\x02\0\x12\x03\x0e\x02\x11\n\x0c\n\x05\x04\x01\x02\0\
+    esponse\x12\x12\n\x04hash\x18\x01\x20\x01(\x0cR\x04hash\"\xc3\x01\n\x0eQ\
+    ueryBatchRead\x124\n\x08accounts\x18\x01\x20\x03(\x0b2\x18.ffi.ffi.Query\
+    GetAccountR\x08accounts\x122\n\x05codes\x18\x02\x20\x03(\x0b2\x1c.ffi.ff\
+    i.QueryGetAccountCodeR\x05codes\x12G\n\x0cstorageCells\x18\x03\x20\x03(\
+    \x0b2#.ffi.ffi.QueryGetAccountStorageCellR\x0cstorageCells\"\xe3\x01\n\
+    \x16QueryBatchReadResponse\x12<\n\x08accounts\x18\x01\x20\x03(\x0b2\x20.\
+    ffi.ffi.QueryGetAccountResponseR\x08accounts\x12:\n\x05codes\x18\x02\x20\
+    \x03(\x0b2$.ffi.ffi.QueryGetAccountCodeResponseR\x05codes\x12O\n\x0cstor\
+    ageCells\x18\x03\x20\x03(\x0b2+.ffi.ffi.QueryGetAccountStorageCellRespon\
+    seR\x0cstorageCells\"\xb3\x02\n\x0bQueryCommit\x12.\n\x07removed\x18\x01\
+    \x20\x03(\x0b2\x14.ffi.ffi.QueryRemoveR\x07removed\x12?\n\x0cresetStorag\
+    e\x18\x02\x20\x03(\x0b2\x1b.ffi.ffi.QueryRemoveStorageR\x0cresetStorage\
+    \x127\n\x08accounts\x18\x03\x20\x03(\x0b2\x1b.ffi.ffi.QueryInsertAccount\
+    R\x08accounts\x125\n\x05codes\x18\x04\x20\x03(\x0b2\x1f.ffi.ffi.QueryIns\
+    ertAccountCodeR\x05codes\x12C\n\x0cstorageCells\x18\x05\x20\x03(\x0b2\
+    \x1f.ffi.ffi.QueryInsertStorageCellR\x0cstorageCells\"\x15\n\x13QueryCom\
+    mitResponse\"B\n\x10QueryBankBalance\x12\x18\n\x07address\x18\x01\x20\
+    \x01(\x0cR\x07address\x12\x14\n\x05denom\x18\x02\x20\x01(\tR\x05denom\"4\
+    \n\x18QueryBankBalanceResponse\x12\x18\n\x07balance\x18\x01\x20\x01(\x0c\
+    R\x07balance\"\xaa\x07\n\rCosmosRequest\x12:\n\ngetAccount\x18\x01\x20\
+    \x01(\x0b2\x18.ffi.ffi.QueryGetAccountH\0R\ngetAccount\x12C\n\rinsertAcc\
+    ount\x18\x02\x20\x01(\x0b2\x1b.ffi.ffi.QueryInsertAccountH\0R\rinsertAcc\
+    ount\x12=\n\x0bcontainsKey\x18\x03\x20\x01(\x0b2\x19.ffi.ffi.QueryContai\
+    nsKeyH\0R\x0bcontainsKey\x12@\n\x0baccountCode\x18\x04\x20\x01(\x0b2\x1c\
+    .ffi.ffi.QueryGetAccountCodeH\0R\x0baccountCode\x12G\n\x0bstorageCell\
+    \x18\x05\x20\x01(\x0b2#.ffi.ffi.QueryGetAccountStorageCellH\0R\x0bstorag\
+    eCell\x12O\n\x11insertAccountCode\x18\x06\x20\x01(\x0b2\x1f.ffi.ffi.Quer\
+    yInsertAccountCodeH\0R\x11insertAccountCode\x12O\n\x11insertStorageCell\
+    \x18\x07\x20\x01(\x0b2\x1f.ffi.ffi.QueryInsertStorageCellH\0R\x11insertS\
+    torageCell\x12.\n\x06remove\x18\x08\x20\x01(\x0b2\x14.ffi.ffi.QueryRemov\
+    eH\0R\x06remove\x12O\n\x11removeStorageCell\x18\t\x20\x01(\x0b2\x1f.ffi.\
+    ffi.QueryRemoveStorageCellH\0R\x11removeStorageCell\x12C\n\rremoveStorag\
+    e\x18\n\x20\x01(\x0b2\x1b.ffi.ffi.QueryRemoveStorageH\0R\rremoveStorage\
+    \x127\n\tblockHash\x18\x0b\x20\x01(\x0b2\x17.ffi.ffi.QueryBlockHashH\0R\
+    \tblockHash\x127\n\tbatchRead\x18\x0c\x20\x01(\x0b2\x17.ffi.ffi.QueryBat\
+    chReadH\0R\tbatchRead\x12.\n\x06commit\x18\r\x20\x01(\x0b2\x14.ffi.ffi.Q\
+    ueryCommitH\0R\x06commit\x12=\n\x0bbankBalance\x18\x0e\x20\x01(\x0b2\x19\
+    .ffi.ffi.QueryBankBalanceH\0R\x0bbankBalanceB\x05\n\x03req\"\xfa\x01\n\
+    \x0fSGXVMCallParams\x12\x12\n\x04from\x18\x01\x20\x01(\x0cR\x04from\x12\
+    \x0e\n\x02to\x18\x02\x20\x01(\x0cR\x02to\x12\x12\n\x04data\x18\x03\x20\
+    \x01(\x0cR\x04data\x12\x1a\n\x08gasLimit\x18\x04\x20\x01(\x04R\x08gasLim\
+    it\x12\x14\n\x05value\x18\x05\x20\x01(\x0cR\x05value\x127\n\naccessList\
+    \x18\x06\x20\x03(\x0b2\x17.ffi.ffi.AccessListItemR\naccessList\x12\x16\n\
+    \x06commit\x18\x07\x20\x01(\x08R\x06commit\x12\x14\n\x05nonce\x18\x08\
+    \x20\x01(\x04R\x05nonce\x12\x16\n\x06txType\x18\t\x20\x01(\rR\x06txType\
+    \"\xec\x01\n\x11SGXVMCreateParams\x12\x12\n\x04from\x18\x01\x20\x01(\x0c\
+    R\x04from\x12\x12\n\x04data\x18\x02\x20\x01(\x0cR\x04data\x12\x1a\n\x08g\
+    asLimit\x18\x03\x20\x01(\x04R\x08gasLimit\x12\x14\n\x05value\x18\x04\x20\
+    \x01(\x0cR\x05value\x127\n\naccessList\x18\x05\x20\x03(\x0b2\x17.ffi.ffi\
+    .AccessListItemR\naccessList\x12\x16\n\x06commit\x18\x06\x20\x01(\x08R\
+    \x06commit\x12\x14\n\x05nonce\x18\x07\x20\x01(\x04R\x05nonce\x12\x16\n\
+    \x06txType\x18\x08\x20\x01(\rR\x06txType\"{\n\x10SGXVMCallRequest\x120\n\
+    \x06params\x18\x01\x20\x01(\x0b2\x18.ffi.ffi.SGXVMCallParamsR\x06params\
+    \x125\n\x07context\x18\x02\x20\x01(\x0b2\x1b.ffi.ffi.TransactionContextR\
+    \x07context\"\x7f\n\x12SGXVMCreateRequest\x122\n\x06params\x18\x01\x20\
+    \x01(\x0b2\x1a.ffi.ffi.SGXVMCreateParamsR\x06params\x125\n\x07context\
+    \x18\x02\x20\x01(\x0b2\x1b.ffi.ffi.TransactionContextR\x07context\"\xd9\
+    \x01\n\tCallFrame\x12\x12\n\x04type\x18\x01\x20\x01(\tR\x04type\x12\x12\
+    \n\x04from\x18\x02\x20\x01(\x0cR\x04from\x12\x0e\n\x02to\x18\x03\x20\x01\
+    (\x0cR\x02to\x12\x14\n\x05value\x18\x04\x20\x01(\x0cR\x05value\x12\x10\n\
+    \x03gas\x18\x05\x20\x01(\x04R\x03gas\x12\x14\n\x05input\x18\x06\x20\x01(\
+    \x0cR\x05input\x12\x16\n\x06output\x18\x07\x20\x01(\x0cR\x06output\x12\
+    \x14\n\x05error\x18\x08\x20\x01(\tR\x05error\x12(\n\x05calls\x18\t\x20\
+    \x03(\x0b2\x12.ffi.ffi.CallFrameR\x05calls\"\x88\x01\n\x18TraceTransacti\
+    onResponse\x12:\n\x06result\x18\x01\x20\x01(\x0b2\".ffi.ffi.HandleTransa\
+    ctionResponseR\x06result\x120\n\tcallTrace\x18\x02\x20\x01(\x0b2\x12.ffi\
+    .ffi.CallFrameR\tcallTrace\"^\n\x12StructLoggerConfig\x12\"\n\x0cenableM\
+    emory\x18\x01\x20\x01(\x08R\x0cenableMemory\x12$\n\renableStorage\x18\
+    \x02\x20\x01(\x08R\renableStorage\"\xb5\x01\n\x15SGXVMStructLogRequest\
+    \x120\n\x06params\x18\x01\x20\x01(\x0b2\x18.ffi.ffi.SGXVMCallParamsR\x06\
+    params\x125\n\x07context\x18\x02\x20\x01(\x0b2\x1b.ffi.ffi.TransactionCo\
+    ntextR\x07context\x123\n\x06config\x18\x03\x20\x01(\x0b2\x1b.ffi.ffi.Str\
+    uctLoggerConfigR\x06config\"6\n\x0cStorageEntry\x12\x10\n\x03key\x18\x01\
+    \x20\x01(\x0cR\x03key\x12\x14\n\x05value\x18\x02\x20\x01(\x0cR\x05value\
+    \"\xe2\x01\n\tStructLog\x12\x0e\n\x02pc\x18\x01\x20\x01(\x04R\x02pc\x12\
+    \x0e\n\x02op\x18\x02\x20\x01(\tR\x02op\x12\x10\n\x03gas\x18\x03\x20\x01(\
+    \x04R\x03gas\x12\x18\n\x07gasCost\x18\x04\x20\x01(\x04R\x07gasCost\x12\
+    \x14\n\x05depth\x18\x05\x20\x01(\x04R\x05depth\x12\x14\n\x05stack\x18\
+    \x06\x20\x03(\x0cR\x05stack\x12\x16\n\x06memory\x18\x07\x20\x01(\x0cR\
+    \x06memory\x12/\n\x07storage\x18\x08\x20\x03(\x0b2\x15.ffi.ffi.StorageEn\
+    tryR\x07storage\x12\x14\n\x05error\x18\t\x20\x01(\tR\x05error\"4\n\nStru\
+    ctLogs\x12&\n\x04logs\x18\x01\x20\x03(\x0b2\x12.ffi.ffi.StructLogR\x04lo\
+    gs\"o\n\x11StructLogResponse\x12:\n\x06result\x18\x01\x20\x01(\x0b2\".ff\
+    i.ffi.HandleTransactionResponseR\x06result\x12\x1e\n\nstructLogs\x18\x02\
+    \x20\x01(\x0cR\nstructLogs\"\x82\x01\n\x17SGXVMEstimateGasRequest\x120\n\
+    \x06params\x18\x01\x20\x01(\x0b2\x18.ffi.ffi.SGXVMCallParamsR\x06params\
+    \x125\n\x07context\x18\x02\x20\x01(\x0b2\x1b.ffi.ffi.TransactionContextR\
+    \x07context\"T\n\x13EstimateGasResponse\x12\x10\n\x03gas\x18\x01\x20\x01\
+    (\x04R\x03gas\x12\x19\n\x08vm_error\x18\x02\x20\x01(\tR\x07vmError\x12\
+    \x10\n\x03ret\x18\x03\x20\x01(\x0cR\x03ret\"\x80\x01\n\x15SGXVMStateDiff\
+    Request\x120\n\x06params\x18\x01\x20\x01(\x0b2\x18.ffi.ffi.SGXVMCallPara\
+    msR\x06params\x125\n\x07context\x18\x02\x20\x01(\x0b2\x1b.ffi.ffi.Transa\
+    ctionContextR\x07context\"[\n\x0bStorageDiff\x12\x10\n\x03key\x18\x01\
+    \x20\x01(\x0cR\x03key\x12$\n\roriginalValue\x18\x02\x20\x01(\x0cR\rorigi\
+    nalValue\x12\x14\n\x05value\x18\x03\x20\x01(\x0cR\x05value\"\x91\x02\n\
+    \x0bAccountDiff\x12\x18\n\x07address\x18\x01\x20\x01(\x0cR\x07address\
+    \x12$\n\rbalanceBefore\x18\x02\x20\x01(\x0cR\rbalanceBefore\x12\"\n\x0cb\
+    alanceAfter\x18\x03\x20\x01(\x0cR\x0cbalanceAfter\x12\x20\n\x0bnonceBefo\
+    re\x18\x04\x20\x01(\x04R\x0bnonceBefore\x12\x1e\n\nnonceAfter\x18\x05\
+    \x20\x01(\x04R\nnonceAfter\x12\x12\n\x04code\x18\x06\x20\x01(\x0cR\x04co\
+    de\x12\x18\n\x07deleted\x18\x07\x20\x01(\x08R\x07deleted\x12.\n\x07stora\
+    ge\x18\x08\x20\x03(\x0b2\x14.ffi.ffi.StorageDiffR\x07storage\"\x81\x01\n\
+    \x11StateDiffResponse\x12:\n\x06result\x18\x01\x20\x01(\x0b2\".ffi.ffi.H\
+    andleTransactionResponseR\x06result\x120\n\x08accounts\x18\x02\x20\x03(\
+    \x0b2\x14.ffi.ffi.AccountDiffR\x08accounts\"\x87\x01\n\x1cSGXVMCreateAcc\
+    essListRequest\x120\n\x06params\x18\x01\x20\x01(\x0b2\x18.ffi.ffi.SGXVMC\
+    allParamsR\x06params\x125\n\x07context\x18\x02\x20\x01(\x0b2\x1b.ffi.ffi\
+    .TransactionContextR\x07context\"\x8f\x01\n\x18CreateAccessListResponse\
+    \x12:\n\x06result\x18\x01\x20\x01(\x0b2\".ffi.ffi.HandleTransactionRespo\
+    nseR\x06result\x127\n\naccessList\x18\x02\x20\x03(\x0b2\x17.ffi.ffi.Acce\
+    ssListItemR\naccessList\"\x83\x01\n\x15SGXVMBatchTransaction\x12.\n\x04c\
+    all\x18\x01\x20\x01(\x0b2\x18.ffi.ffi.SGXVMCallParamsH\0R\x04call\x124\n\
+    \x06create\x18\x02\x20\x01(\x0b2\x1a.ffi.ffi.SGXVMCreateParamsH\0R\x06cr\
+    eateB\x04\n\x02tx\"\x8e\x01\n\x11SGXVMBatchRequest\x12B\n\x0ctransaction\
+    s\x18\x01\x20\x03(\x0b2\x1e.ffi.ffi.SGXVMBatchTransactionR\x0ctransactio\
+    ns\x125\n\x07context\x18\x02\x20\x01(\x0b2\x1b.ffi.ffi.TransactionContex\
+    tR\x07context\"\x82\x01\n\x16BatchTransactionResult\x12:\n\x06result\x18\
+    \x01\x20\x01(\x0b2\".ffi.ffi.HandleTransactionResponseR\x06result\x12,\n\
+    \x11cumulativeGasUsed\x18\x02\x20\x01(\x04R\x11cumulativeGasUsed\"J\n\rB\
+    atchResponse\x129\n\x07results\x18\x01\x20\x03(\x0b2\x1f.ffi.ffi.BatchTr\
+    ansactionResultR\x07results\"\x96\x01\n\x1dSGXVMSignedTransactionRequest\
+    \x12&\n\x0erawTransaction\x18\x01\x20\x01(\x0cR\x0erawTransaction\x125\n\
+    \x07context\x18\x02\x20\x01(\x0b2\x1b.ffi.ffi.TransactionContextR\x07con\
+    text\x12\x16\n\x06commit\x18\x03\x20\x01(\x08R\x06commit\"\x7f\n\x19Sign\
+    edTransactionResponse\x12:\n\x06result\x18\x01\x20\x01(\x0b2\".ffi.ffi.H\
+    andleTransactionResponseR\x06result\x12\x12\n\x04from\x18\x02\x20\x01(\
+    \x0cR\x04from\x12\x12\n\x04hash\x18\x03\x20\x01(\x0cR\x04hash\"\x16\n\
+    \x14NodePublicKeyRequest\"5\n\x15NodePublicKeyResponse\x12\x1c\n\tpublic\
+    Key\x18\x01\x20\x01(\x0cR\tpublicKey\"\x98\x01\n\x12PrecompileOverride\
+    \x12\x18\n\x07address\x18\x01\x20\x01(\x0cR\x07address\x12*\n\x10activat\
+    ionHeight\x18\x02\x20\x01(\x04R\x10activationHeight\x12\x20\n\x0bgasOver\
+    ride\x18\x03\x20\x01(\x04R\x0bgasOverride\x12\x1a\n\x08disabled\x18\x04\
+    \x20\x01(\x08R\x08disabled\"X\n\x1bConfigurePrecompilesRequest\x129\n\to\
+    verrides\x18\x01\x20\x03(\x0b2\x1b.ffi.ffi.PrecompileOverrideR\toverride\
+    s\"4\n\x1cConfigurePrecompilesResponse\x12\x14\n\x05error\x18\x01\x20\
+    \x01(\tR\x05error\"\xe1\x07\n\nFFIRequest\x12=\n\x0bcallRequest\x18\x01\
+    \x20\x01(\x0b2\x19.ffi.ffi.SGXVMCallRequestH\0R\x0bcallRequest\x12C\n\rc\
+    reateRequest\x18\x02\x20\x01(\x0b2\x1b.ffi.ffi.SGXVMCreateRequestH\0R\rc\
+    reateRequest\x12K\n\x10publicKeyRequest\x18\x03\x20\x01(\x0b2\x1d.ffi.ff\
+    i.NodePublicKeyRequestH\0R\x10publicKeyRequest\x12G\n\x10traceCallReques\
+    t\x18\x04\x20\x01(\x0b2\x19.ffi.ffi.SGXVMCallRequestH\0R\x10traceCallReq\
+    uest\x12M\n\x12traceCreateRequest\x18\x05\x20\x01(\x0b2\x1b.ffi.ffi.SGXV\
+    MCreateRequestH\0R\x12traceCreateRequest\x12L\n\x10structLogRequest\x18\
+    \x06\x20\x01(\x0b2\x1e.ffi.ffi.SGXVMStructLogRequestH\0R\x10structLogReq\
+    uest\x12R\n\x12estimateGasRequest\x18\x07\x20\x01(\x0b2\x20.ffi.ffi.SGXV\
+    MEstimateGasRequestH\0R\x12estimateGasRequest\x12L\n\x10stateDiffRequest\
+    \x18\x08\x20\x01(\x0b2\x1e.ffi.ffi.SGXVMStateDiffRequestH\0R\x10stateDif\
+    fRequest\x12a\n\x17createAccessListRequest\x18\t\x20\x01(\x0b2%.ffi.ffi.\
+    SGXVMCreateAccessListRequestH\0R\x17createAccessListRequest\x12@\n\x0cba\
+    tchRequest\x18\n\x20\x01(\x0b2\x1a.ffi.ffi.SGXVMBatchRequestH\0R\x0cbatc\
+    hRequest\x12d\n\x18signedTransactionRequest\x18\x0b\x20\x01(\x0b2&.ffi.f\
+    fi.SGXVMSignedTransactionRequestH\0R\x18signedTransactionRequest\x12h\n\
+    \x1bconfigurePrecompilesRequest\x18\x0c\x20\x01(\x0b2$.ffi.ffi.Configure\
+    PrecompilesRequestH\0R\x1bconfigurePrecompilesRequestB\x05\n\x03reqB\x04\
+    Z\x02./J\xaa\xa6\x01\n\x07\x12\x05\0\0\xfe\x03\x01\n\x08\n\x01\x0c\x12\
+    \x03\0\0\x12\n\x08\n\x01\x02\x12\x03\x02\0\x10\n\x08\n\x01\x08\x12\x03\
+    \x04\0\x19\n\t\n\x02\x08\x0b\x12\x03\x04\0\x19\n\x1d\n\x02\x04\0\x12\x04\
+    \x08\0\x0b\x012\x11\x20General\x20request\n\n\n\n\x03\x04\0\x01\x12\x03\
+    \x08\x08\x16\n\x0b\n\x04\x04\0\x02\0\x12\x03\t\x02!\n\x0c\n\x05\x04\0\
+    \x02\0\x04\x12\x03\t\x02\n\n\x0c\n\x05\x04\0\x02\0\x05\x12\x03\t\x0b\x10\
+    \n\x0c\n\x05\x04\0\x02\0\x01\x12\x03\t\x11\x1c\n\x0c\n\x05\x04\0\x02\0\
+    \x03\x12\x03\t\x1f\x20\n\x0b\n\x04\x04\0\x02\x01\x12\x03\n\x02\x14\n\x0c\
+    \n\x05\x04\0\x02\x01\x05\x12\x03\n\x02\x07\n\x0c\n\x05\x04\0\x02\x01\x01\
+    \x12\x03\n\x08\x0f\n\x0c\n\x05\x04\0\x02\x01\x03\x12\x03\n\x12\x13\n\n\n\
+    \x02\x04\x01\x12\x04\r\0\x14\x01\n\n\n\x03\x04\x01\x01\x12\x03\r\x08\x17\
+    \n\x0b\n\x04\x04\x01\x02\0\x12\x03\x0e\x02\x11\n\x0c\n\x05\x04\x01\x02\0\
     \x05\x12\x03\x0e\x02\x07\n\x0c\n\x05\x04\x01\x02\0\x01\x12\x03\x0e\x08\
     \x0c\n\x0c\n\x05\x04\x01\x02\0\x03\x12\x03\x0e\x0f\x10\n\x0b\n\x04\x04\
     \x01\x02\x01\x12\x03\x0f\x02\x0f\n\x0c\n\x05\x04\x01\x02\x01\x05\x12\x03\
@@ -8849,7 +18998,7 @@ static file_descriptor_proto_data: &'static [u8] = b"\
     \x04\x01\x02\x05\x04\x12\x03\x13\x02\n\n\x0c\n\x05\x04\x01\x02\x05\x06\
     \x12\x03\x13\x0b\x19\n\x0c\n\x05\x04\x01\x02\x05\x01\x12\x03\x13\x1a$\n\
     \x0c\n\x05\x04\x01\x02\x05\x03\x12\x03\x13'(\n\n\n\x02\x04\x02\x12\x04\
-    \x16\0\x1e\x01\n\n\n\x03\x04\x02\x01\x12\x03\x16\x08\x1a\n\x0b\n\x04\x04\
+    \x16\0'\x01\n\n\n\x03\x04\x02\x01\x12\x03\x16\x08\x1a\n\x0b\n\x04\x04\
     \x02\x02\0\x12\x03\x17\x02\x16\n\x0c\n\x05\x04\x02\x02\0\x05\x12\x03\x17\
     \x02\x08\n\x0c\n\x05\x04\x02\x02\0\x01\x12\x03\x17\t\x11\n\x0c\n\x05\x04\
     \x02\x02\0\x03\x12\x03\x17\x14\x15\n\x0b\n\x04\x04\x02\x02\x01\x12\x03\
@@ -8869,260 +19018,777 @@ static file_descriptor_proto_data: &'static [u8] = b"\
     \x04\x02\x02\x05\x03\x12\x03\x1c\x19\x1a\n\x0b\n\x04\x04\x02\x02\x06\x12\
     \x03\x1d\x02\x1a\n\x0c\n\x05\x04\x02\x02\x06\x05\x12\x03\x1d\x02\x08\n\
     \x0c\n\x05\x04\x02\x02\x06\x01\x12\x03\x1d\t\x15\n\x0c\n\x05\x04\x02\x02\
-    \x06\x03\x12\x03\x1d\x18\x19\n\n\n\x02\x04\x03\x12\x04\x20\0#\x01\n\n\n\
-    \x03\x04\x03\x01\x12\x03\x20\x08\x20\n\x0b\n\x04\x04\x03\x02\0\x12\x03!\
-    \x02\x1e\n\x0c\n\x05\x04\x03\x02\0\x06\x12\x03!\x02\x11\n\x0c\n\x05\x04\
-    \x03\x02\0\x01\x12\x03!\x12\x19\n\x0c\n\x05\x04\x03\x02\0\x03\x12\x03!\
-    \x1c\x1d\n\x0b\n\x04\x04\x03\x02\x01\x12\x03\"\x02$\n\x0c\n\x05\x04\x03\
-    \x02\x01\x06\x12\x03\"\x02\x14\n\x0c\n\x05\x04\x03\x02\x01\x01\x12\x03\"\
-    \x15\x1f\n\x0c\n\x05\x04\x03\x02\x01\x03\x12\x03\"\"#\n\n\n\x02\x04\x04\
-    \x12\x04%\00\x01\n\n\n\x03\x04\x04\x01\x12\x03%\x08!\nZ\n\x04\x04\x04\
-    \x02\0\x12\x03(\x02\x18\x1aM\x20logs\x20contains\x20the\x20transaction\
-    \x20hash\x20and\x20the\x20proto-compatible\x20ethereum\n\x20logs.\n\n\
-    \x0c\n\x05\x04\x04\x02\0\x04\x12\x03(\x02\n\n\x0c\n\x05\x04\x04\x02\0\
-    \x06\x12\x03(\x0b\x0e\n\x0c\n\x05\x04\x04\x02\0\x01\x12\x03(\x0f\x13\n\
-    \x0c\n\x05\x04\x04\x02\0\x03\x12\x03(\x16\x17\n\\\n\x04\x04\x04\x02\x01\
-    \x12\x03+\x02\x10\x1aO\x20returned\x20data\x20from\x20evm\x20function\
+    \x06\x03\x12\x03\x1d\x18\x19\nZ\n\x04\x04\x02\x02\x07\x12\x03\x1f\x02\
+    \x16\x1aM\x20EVM\x20hard\x20fork\x20to\x20use:\x200\x20-\x20London,\x201\
+    \x20-\x20Shanghai.\x20Cancun\x20is\x20not\x20supported\x20yet\n\n\x0c\n\
+    \x05\x04\x02\x02\x07\x05\x12\x03\x1f\x02\x08\n\x0c\n\x05\x04\x02\x02\x07\
+    \x01\x12\x03\x1f\t\x11\n\x0c\n\x05\x04\x02\x02\x07\x03\x12\x03\x1f\x14\
+    \x15\n\xe5\x01\n\x04\x04\x02\x02\x08\x12\x03#\x02\x18\x1a\xd7\x01\x2032-\
+    byte\x20block\x20randomness\x20returned\x20by\x20PREVRANDAO\x20opcode.\
+    \x20Mixed\x20with\x20secret\n\x20derived\x20from\x20master\x20key\x20ins\
+    ide\x20the\x20enclave,\x20so\x20transactions\x20are\x20rejected\n\x20if\
+    \x20master\x20key\x20is\x20not\x20initialized.\x20If\x20empty,\x20PREVRA\
+    NDAO\x20returns\x200\n\n\x0c\n\x05\x04\x02\x02\x08\x05\x12\x03#\x02\x07\
+    \n\x0c\n\x05\x04\x02\x02\x08\x01\x12\x03#\x08\x13\n\x0c\n\x05\x04\x02\
+    \x02\x08\x03\x12\x03#\x16\x17\n\x83\x01\n\x04\x04\x02\x02\t\x12\x03&\x02\
+    \x1d\x1av\x20gas\x20used\x20by\x20previous\x20transactions\x20of\x20the\
+    \x20block.\x20Cumulative\x20gas\x20used\x20of\n\x20receipts\x20is\x20cou\
+    nted\x20starting\x20from\x20this\x20value\n\n\x0c\n\x05\x04\x02\x02\t\
+    \x05\x12\x03&\x02\x08\n\x0c\n\x05\x04\x02\x02\t\x01\x12\x03&\t\x17\n\x0c\
+    \n\x05\x04\x02\x02\t\x03\x12\x03&\x1a\x1c\n\n\n\x02\x04\x03\x12\x04)\0,\
+    \x01\n\n\n\x03\x04\x03\x01\x12\x03)\x08\x20\n\x0b\n\x04\x04\x03\x02\0\
+    \x12\x03*\x02\x1e\n\x0c\n\x05\x04\x03\x02\0\x06\x12\x03*\x02\x11\n\x0c\n\
+    \x05\x04\x03\x02\0\x01\x12\x03*\x12\x19\n\x0c\n\x05\x04\x03\x02\0\x03\
+    \x12\x03*\x1c\x1d\n\x0b\n\x04\x04\x03\x02\x01\x12\x03+\x02$\n\x0c\n\x05\
+    \x04\x03\x02\x01\x06\x12\x03+\x02\x14\n\x0c\n\x05\x04\x03\x02\x01\x01\
+    \x12\x03+\x15\x1f\n\x0c\n\x05\x04\x03\x02\x01\x03\x12\x03+\"#\n\n\n\x02\
+    \x04\x04\x12\x04.\0L\x01\n\n\n\x03\x04\x04\x01\x12\x03.\x08!\nZ\n\x04\
+    \x04\x04\x02\0\x12\x031\x02\x18\x1aM\x20logs\x20contains\x20the\x20trans\
+    action\x20hash\x20and\x20the\x20proto-compatible\x20ethereum\n\x20logs.\
+    \n\n\x0c\n\x05\x04\x04\x02\0\x04\x12\x031\x02\n\n\x0c\n\x05\x04\x04\x02\
+    \0\x06\x12\x031\x0b\x0e\n\x0c\n\x05\x04\x04\x02\0\x01\x12\x031\x0f\x13\n\
+    \x0c\n\x05\x04\x04\x02\0\x03\x12\x031\x16\x17\n\\\n\x04\x04\x04\x02\x01\
+    \x12\x034\x02\x10\x1aO\x20returned\x20data\x20from\x20evm\x20function\
     \x20(result\x20or\x20data\x20supplied\x20with\x20revert\n\x20opcode)\n\n\
-    \x0c\n\x05\x04\x04\x02\x01\x05\x12\x03+\x02\x07\n\x0c\n\x05\x04\x04\x02\
-    \x01\x01\x12\x03+\x08\x0b\n\x0c\n\x05\x04\x04\x02\x01\x03\x12\x03+\x0e\
-    \x0f\n=\n\x04\x04\x04\x02\x02\x12\x03-\x02\x16\x1a0\x20vm\x20error\x20is\
+    \x0c\n\x05\x04\x04\x02\x01\x05\x12\x034\x02\x07\n\x0c\n\x05\x04\x04\x02\
+    \x01\x01\x12\x034\x08\x0b\n\x0c\n\x05\x04\x04\x02\x01\x03\x12\x034\x0e\
+    \x0f\n=\n\x04\x04\x04\x02\x02\x12\x036\x02\x16\x1a0\x20vm\x20error\x20is\
     \x20the\x20error\x20returned\x20by\x20vm\x20execution\n\n\x0c\n\x05\x04\
-    \x04\x02\x02\x05\x12\x03-\x02\x08\n\x0c\n\x05\x04\x04\x02\x02\x01\x12\
-    \x03-\t\x11\n\x0c\n\x05\x04\x04\x02\x02\x03\x12\x03-\x14\x15\n.\n\x04\
-    \x04\x04\x02\x03\x12\x03/\x02\x16\x1a!\x20gas\x20consumed\x20by\x20the\
-    \x20transaction\n\n\x0c\n\x05\x04\x04\x02\x03\x05\x12\x03/\x02\x08\n\x0c\
-    \n\x05\x04\x04\x02\x03\x01\x12\x03/\t\x11\n\x0c\n\x05\x04\x04\x02\x03\
-    \x03\x12\x03/\x14\x15\nc\n\x02\x04\x05\x12\x034\0\"\x1aX\x20Topic\x20rep\
-    resents\x2032-byte\x20words\x20that\x20is\x20used\x20to\x20describe\x20w\
-    hat\xe2\x80\x99s\x20going\x20on\x20in\x20an\n\x20event\n\n\n\n\x03\x04\
-    \x05\x01\x12\x034\x08\r\n\x0b\n\x04\x04\x05\x02\0\x12\x034\x10\x20\n\x0c\
-    \n\x05\x04\x05\x02\0\x05\x12\x034\x10\x15\n\x0c\n\x05\x04\x05\x02\0\x01\
-    \x12\x034\x16\x1b\n\x0c\n\x05\x04\x05\x02\0\x03\x12\x034\x1e\x1f\n\x81\
-    \x01\n\x02\x04\x06\x12\x049\0@\x01\x1au\x20Log\x20represents\x20an\x20pr\
-    otobuf\x20compatible\x20Ethereum\x20Log\x20that\x20defines\x20a\x20contr\
-    act\n\x20log\x20event.\n\x20Copied\x20from\x20`devnet/proto``\n\n\n\n\
-    \x03\x04\x06\x01\x12\x039\x08\x0b\n?\n\x04\x04\x06\x02\0\x12\x03;\x02\
-    \x14\x1a2\x20address\x20of\x20the\x20contract\x20that\x20generated\x20th\
-    e\x20event\n\n\x0c\n\x05\x04\x06\x02\0\x05\x12\x03;\x02\x07\n\x0c\n\x05\
-    \x04\x06\x02\0\x01\x12\x03;\x08\x0f\n\x0c\n\x05\x04\x06\x02\0\x03\x12\
-    \x03;\x12\x13\n7\n\x04\x04\x06\x02\x01\x12\x03=\x02\x1c\x1a*\x20list\x20\
-    of\x20topics\x20provided\x20by\x20the\x20contract.\n\n\x0c\n\x05\x04\x06\
-    \x02\x01\x04\x12\x03=\x02\n\n\x0c\n\x05\x04\x06\x02\x01\x06\x12\x03=\x0b\
-    \x10\n\x0c\n\x05\x04\x06\x02\x01\x01\x12\x03=\x11\x17\n\x0c\n\x05\x04\
-    \x06\x02\x01\x03\x12\x03=\x1a\x1b\n<\n\x04\x04\x06\x02\x02\x12\x03?\x02\
-    \x11\x1a/\x20supplied\x20by\x20the\x20contract,\x20usually\x20ABI-encode\
-    d\n\n\x0c\n\x05\x04\x06\x02\x02\x05\x12\x03?\x02\x07\n\x0c\n\x05\x04\x06\
-    \x02\x02\x01\x12\x03?\x08\x0c\n\x0c\n\x05\x04\x06\x02\x02\x03\x12\x03?\
-    \x0f\x10\nX\n\x02\x04\x07\x12\x04C\0F\x01\x1aL\x20Request\x20for\x20acco\
-    unt\x20code\x20(smart\x20contract\x20deployed\x20behind\x20provided\x20a\
-    ddress)\n\n\n\n\x03\x04\x07\x01\x12\x03C\x08\x17\n*\n\x04\x04\x07\x02\0\
-    \x12\x03E\x02\x14\x1a\x1d\x2020\x20bytes\x20of\x20account\x20address\n\n\
-    \x0c\n\x05\x04\x07\x02\0\x05\x12\x03E\x02\x07\n\x0c\n\x05\x04\x07\x02\0\
-    \x01\x12\x03E\x08\x0f\n\x0c\n\x05\x04\x07\x02\0\x03\x12\x03E\x12\x13\n'\
-    \n\x02\x04\x08\x12\x04I\0N\x01\x1a\x1b\x20Response\x20for\x20account\x20\
-    code\n\n\n\n\x03\x04\x08\x01\x12\x03I\x08\x1f\nb\n\x04\x04\x08\x02\0\x12\
-    \x03L\x02\x14\x1aU\x20Since\x20both\x20fields\x20are\x20256-bit\x20unsig\
-    ned\x20integer,\x20we\x20encode\x20them\x20as\n\x20big-endian\x20bytes\n\
-    \n\x0c\n\x05\x04\x08\x02\0\x05\x12\x03L\x02\x07\n\x0c\n\x05\x04\x08\x02\
-    \0\x01\x12\x03L\x08\x0f\n\x0c\n\x05\x04\x08\x02\0\x03\x12\x03L\x12\x13\n\
-    \x0b\n\x04\x04\x08\x02\x01\x12\x03M\x02\x13\n\x0c\n\x05\x04\x08\x02\x01\
-    \x05\x12\x03M\x02\x08\n\x0c\n\x05\x04\x08\x02\x01\x01\x12\x03M\t\x0e\n\
-    \x0c\n\x05\x04\x08\x02\x01\x03\x12\x03M\x11\x12\nF\n\x02\x04\t\x12\x04Q\
-    \0U\x01\x1a:\x20Request\x20to\x20insert\x20account\x20data\x20such\x20as\
-    \x20balance\x20and\x20nonce\n\n\n\n\x03\x04\t\x01\x12\x03Q\x08\x1a\n\x0b\
-    \n\x04\x04\t\x02\0\x12\x03R\x02\x14\n\x0c\n\x05\x04\t\x02\0\x05\x12\x03R\
-    \x02\x07\n\x0c\n\x05\x04\t\x02\0\x01\x12\x03R\x08\x0f\n\x0c\n\x05\x04\t\
-    \x02\0\x03\x12\x03R\x12\x13\n\x0b\n\x04\x04\t\x02\x01\x12\x03S\x02\x14\n\
-    \x0c\n\x05\x04\t\x02\x01\x05\x12\x03S\x02\x07\n\x0c\n\x05\x04\t\x02\x01\
-    \x01\x12\x03S\x08\x0f\n\x0c\n\x05\x04\t\x02\x01\x03\x12\x03S\x12\x13\n\
-    \x0b\n\x04\x04\t\x02\x02\x12\x03T\x02\x13\n\x0c\n\x05\x04\t\x02\x02\x05\
-    \x12\x03T\x02\x08\n\x0c\n\x05\x04\t\x02\x02\x01\x12\x03T\t\x0e\n\x0c\n\
-    \x05\x04\t\x02\x02\x03\x12\x03T\x11\x12\n+\n\x02\x04\n\x12\x03X\0%\x1a\
-    \x20\x20Response\x20for\x20account\x20insertion\n\n\n\n\x03\x04\n\x01\
-    \x12\x03X\x08\"\n\t\n\x02\x04\x0b\x12\x03Z\0+\n\n\n\x03\x04\x0b\x01\x12\
-    \x03Z\x08\x18\n\x0b\n\x04\x04\x0b\x02\0\x12\x03Z\x1b)\n\x0c\n\x05\x04\
-    \x0b\x02\0\x05\x12\x03Z\x1b\x20\n\x0c\n\x05\x04\x0b\x02\0\x01\x12\x03Z!$\
-    \n\x0c\n\x05\x04\x0b\x02\0\x03\x12\x03Z'(\n\t\n\x02\x04\x0c\x12\x03\\\07\
-    \n\n\n\x03\x04\x0c\x01\x12\x03\\\x08\x20\n\x0b\n\x04\x04\x0c\x02\0\x12\
-    \x03\\#5\n\x0c\n\x05\x04\x0c\x02\0\x05\x12\x03\\#'\n\x0c\n\x05\x04\x0c\
-    \x02\0\x01\x12\x03\\(0\n\x0c\n\x05\x04\x0c\x02\0\x03\x12\x03\\34\n\n\n\
-    \x02\x04\r\x12\x04^\0a\x01\n\n\n\x03\x04\r\x01\x12\x03^\x08\"\n\x0b\n\
-    \x04\x04\r\x02\0\x12\x03_\x02\x14\n\x0c\n\x05\x04\r\x02\0\x05\x12\x03_\
-    \x02\x07\n\x0c\n\x05\x04\r\x02\0\x01\x12\x03_\x08\x0f\n\x0c\n\x05\x04\r\
-    \x02\0\x03\x12\x03_\x12\x13\n\x0b\n\x04\x04\r\x02\x01\x12\x03`\x02\x12\n\
-    \x0c\n\x05\x04\r\x02\x01\x05\x12\x03`\x02\x07\n\x0c\n\x05\x04\r\x02\x01\
-    \x01\x12\x03`\x08\r\n\x0c\n\x05\x04\r\x02\x01\x03\x12\x03`\x10\x11\n\t\n\
-    \x02\x04\x0e\x12\x03c\0?\n\n\n\x03\x04\x0e\x01\x12\x03c\x08*\n\x0b\n\x04\
-    \x04\x0e\x02\0\x12\x03c-=\n\x0c\n\x05\x04\x0e\x02\0\x05\x12\x03c-2\n\x0c\
-    \n\x05\x04\x0e\x02\0\x01\x12\x03c38\n\x0c\n\x05\x04\x0e\x02\0\x03\x12\
-    \x03c;<\n\t\n\x02\x04\x0f\x12\x03e\02\n\n\n\x03\x04\x0f\x01\x12\x03e\x08\
-    \x1b\n\x0b\n\x04\x04\x0f\x02\0\x12\x03e\x1e0\n\x0c\n\x05\x04\x0f\x02\0\
-    \x05\x12\x03e\x1e#\n\x0c\n\x05\x04\x0f\x02\0\x01\x12\x03e$+\n\x0c\n\x05\
-    \x04\x0f\x02\0\x03\x12\x03e./\n\t\n\x02\x04\x10\x12\x03g\07\n\n\n\x03\
-    \x04\x10\x01\x12\x03g\x08#\n\x0b\n\x04\x04\x10\x02\0\x12\x03g&5\n\x0c\n\
-    \x05\x04\x10\x02\0\x05\x12\x03g&+\n\x0c\n\x05\x04\x10\x02\0\x01\x12\x03g\
-    ,0\n\x0c\n\x05\x04\x10\x02\0\x03\x12\x03g34\n\n\n\x02\x04\x11\x12\x04i\0\
-    l\x01\n\n\n\x03\x04\x11\x01\x12\x03i\x08\x1e\n\x0b\n\x04\x04\x11\x02\0\
-    \x12\x03j\x02\x14\n\x0c\n\x05\x04\x11\x02\0\x05\x12\x03j\x02\x07\n\x0c\n\
-    \x05\x04\x11\x02\0\x01\x12\x03j\x08\x0f\n\x0c\n\x05\x04\x11\x02\0\x03\
-    \x12\x03j\x12\x13\n\x0b\n\x04\x04\x11\x02\x01\x12\x03k\x02\x11\n\x0c\n\
-    \x05\x04\x11\x02\x01\x05\x12\x03k\x02\x07\n\x0c\n\x05\x04\x11\x02\x01\
-    \x01\x12\x03k\x08\x0c\n\x0c\n\x05\x04\x11\x02\x01\x03\x12\x03k\x0f\x10\n\
-    \t\n\x02\x04\x12\x12\x03n\0)\n\n\n\x03\x04\x12\x01\x12\x03n\x08&\n\n\n\
-    \x02\x04\x13\x12\x04p\0t\x01\n\n\n\x03\x04\x13\x01\x12\x03p\x08\x1e\n\
-    \x0b\n\x04\x04\x13\x02\0\x12\x03q\x02\x14\n\x0c\n\x05\x04\x13\x02\0\x05\
-    \x12\x03q\x02\x07\n\x0c\n\x05\x04\x13\x02\0\x01\x12\x03q\x08\x0f\n\x0c\n\
-    \x05\x04\x13\x02\0\x03\x12\x03q\x12\x13\n\x0b\n\x04\x04\x13\x02\x01\x12\
-    \x03r\x02\x12\n\x0c\n\x05\x04\x13\x02\x01\x05\x12\x03r\x02\x07\n\x0c\n\
-    \x05\x04\x13\x02\x01\x01\x12\x03r\x08\r\n\x0c\n\x05\x04\x13\x02\x01\x03\
-    \x12\x03r\x10\x11\n\x0b\n\x04\x04\x13\x02\x02\x12\x03s\x02\x12\n\x0c\n\
-    \x05\x04\x13\x02\x02\x05\x12\x03s\x02\x07\n\x0c\n\x05\x04\x13\x02\x02\
-    \x01\x12\x03s\x08\r\n\x0c\n\x05\x04\x13\x02\x02\x03\x12\x03s\x10\x11\n\t\
-    \n\x02\x04\x14\x12\x03v\0)\n\n\n\x03\x04\x14\x01\x12\x03v\x08&\n\t\n\x02\
-    \x04\x15\x12\x03x\0*\n\n\n\x03\x04\x15\x01\x12\x03x\x08\x13\n\x0b\n\x04\
-    \x04\x15\x02\0\x12\x03x\x16(\n\x0c\n\x05\x04\x15\x02\0\x05\x12\x03x\x16\
-    \x1b\n\x0c\n\x05\x04\x15\x02\0\x01\x12\x03x\x1c#\n\x0c\n\x05\x04\x15\x02\
-    \0\x03\x12\x03x&'\n\t\n\x02\x04\x16\x12\x03z\0\x1e\n\n\n\x03\x04\x16\x01\
-    \x12\x03z\x08\x1b\n\n\n\x02\x04\x17\x12\x04|\0\x7f\x01\n\n\n\x03\x04\x17\
-    \x01\x12\x03|\x08\x1e\n\x0b\n\x04\x04\x17\x02\0\x12\x03}\x02\x14\n\x0c\n\
-    \x05\x04\x17\x02\0\x05\x12\x03}\x02\x07\n\x0c\n\x05\x04\x17\x02\0\x01\
-    \x12\x03}\x08\x0f\n\x0c\n\x05\x04\x17\x02\0\x03\x12\x03}\x12\x13\n\x0b\n\
-    \x04\x04\x17\x02\x01\x12\x03~\x02\x12\n\x0c\n\x05\x04\x17\x02\x01\x05\
-    \x12\x03~\x02\x07\n\x0c\n\x05\x04\x17\x02\x01\x01\x12\x03~\x08\r\n\x0c\n\
-    \x05\x04\x17\x02\x01\x03\x12\x03~\x10\x11\n\n\n\x02\x04\x18\x12\x04\x81\
-    \x01\0)\n\x0b\n\x03\x04\x18\x01\x12\x04\x81\x01\x08&\n\n\n\x02\x04\x19\
-    \x12\x04\x83\x01\01\n\x0b\n\x03\x04\x19\x01\x12\x04\x83\x01\x08\x1a\n\
-    \x0c\n\x04\x04\x19\x02\0\x12\x04\x83\x01\x1d/\n\r\n\x05\x04\x19\x02\0\
-    \x05\x12\x04\x83\x01\x1d\"\n\r\n\x05\x04\x19\x02\0\x01\x12\x04\x83\x01#*\
-    \n\r\n\x05\x04\x19\x02\0\x03\x12\x04\x83\x01-.\n\n\n\x02\x04\x1a\x12\x04\
-    \x85\x01\0%\n\x0b\n\x03\x04\x1a\x01\x12\x04\x85\x01\x08\"\n\n\n\x02\x04\
-    \x1b\x12\x04\x87\x01\0,\n\x0b\n\x03\x04\x1b\x01\x12\x04\x87\x01\x08\x16\
-    \n\x0c\n\x04\x04\x1b\x02\0\x12\x04\x87\x01\x19*\n\r\n\x05\x04\x1b\x02\0\
-    \x05\x12\x04\x87\x01\x19\x1e\n\r\n\x05\x04\x1b\x02\0\x01\x12\x04\x87\x01\
-    \x1f%\n\r\n\x05\x04\x1b\x02\0\x03\x12\x04\x87\x01()\n\n\n\x02\x04\x1c\
-    \x12\x04\x88\x01\02\n\x0b\n\x03\x04\x1c\x01\x12\x04\x88\x01\x08\x1e\n\
-    \x0c\n\x04\x04\x1c\x02\0\x12\x04\x88\x01!0\n\r\n\x05\x04\x1c\x02\0\x05\
-    \x12\x04\x88\x01!&\n\r\n\x05\x04\x1c\x02\0\x01\x12\x04\x88\x01'+\n\r\n\
-    \x05\x04\x1c\x02\0\x03\x12\x04\x88\x01./\n\x0c\n\x02\x04\x1d\x12\x06\x8a\
-    \x01\0\x98\x01\x01\n\x0b\n\x03\x04\x1d\x01\x12\x04\x8a\x01\x08\x15\n\x0e\
-    \n\x04\x04\x1d\x08\0\x12\x06\x8b\x01\x02\x97\x01\x03\n\r\n\x05\x04\x1d\
-    \x08\0\x01\x12\x04\x8b\x01\x08\x0b\n\x0c\n\x04\x04\x1d\x02\0\x12\x04\x8c\
-    \x01\x04#\n\r\n\x05\x04\x1d\x02\0\x06\x12\x04\x8c\x01\x04\x13\n\r\n\x05\
-    \x04\x1d\x02\0\x01\x12\x04\x8c\x01\x14\x1e\n\r\n\x05\x04\x1d\x02\0\x03\
-    \x12\x04\x8c\x01!\"\n\x0c\n\x04\x04\x1d\x02\x01\x12\x04\x8d\x01\x04)\n\r\
-    \n\x05\x04\x1d\x02\x01\x06\x12\x04\x8d\x01\x04\x16\n\r\n\x05\x04\x1d\x02\
-    \x01\x01\x12\x04\x8d\x01\x17$\n\r\n\x05\x04\x1d\x02\x01\x03\x12\x04\x8d\
-    \x01'(\n\x0c\n\x04\x04\x1d\x02\x02\x12\x04\x8e\x01\x04%\n\r\n\x05\x04\
-    \x1d\x02\x02\x06\x12\x04\x8e\x01\x04\x14\n\r\n\x05\x04\x1d\x02\x02\x01\
-    \x12\x04\x8e\x01\x15\x20\n\r\n\x05\x04\x1d\x02\x02\x03\x12\x04\x8e\x01#$\
-    \n\x0c\n\x04\x04\x1d\x02\x03\x12\x04\x8f\x01\x04(\n\r\n\x05\x04\x1d\x02\
-    \x03\x06\x12\x04\x8f\x01\x04\x17\n\r\n\x05\x04\x1d\x02\x03\x01\x12\x04\
-    \x8f\x01\x18#\n\r\n\x05\x04\x1d\x02\x03\x03\x12\x04\x8f\x01&'\n\x0c\n\
-    \x04\x04\x1d\x02\x04\x12\x04\x90\x01\x04/\n\r\n\x05\x04\x1d\x02\x04\x06\
-    \x12\x04\x90\x01\x04\x1e\n\r\n\x05\x04\x1d\x02\x04\x01\x12\x04\x90\x01\
-    \x1f*\n\r\n\x05\x04\x1d\x02\x04\x03\x12\x04\x90\x01-.\n\x0c\n\x04\x04\
-    \x1d\x02\x05\x12\x04\x91\x01\x041\n\r\n\x05\x04\x1d\x02\x05\x06\x12\x04\
-    \x91\x01\x04\x1a\n\r\n\x05\x04\x1d\x02\x05\x01\x12\x04\x91\x01\x1b,\n\r\
-    \n\x05\x04\x1d\x02\x05\x03\x12\x04\x91\x01/0\n\x0c\n\x04\x04\x1d\x02\x06\
-    \x12\x04\x92\x01\x041\n\r\n\x05\x04\x1d\x02\x06\x06\x12\x04\x92\x01\x04\
-    \x1a\n\r\n\x05\x04\x1d\x02\x06\x01\x12\x04\x92\x01\x1b,\n\r\n\x05\x04\
-    \x1d\x02\x06\x03\x12\x04\x92\x01/0\n\x0c\n\x04\x04\x1d\x02\x07\x12\x04\
-    \x93\x01\x04\x1b\n\r\n\x05\x04\x1d\x02\x07\x06\x12\x04\x93\x01\x04\x0f\n\
-    \r\n\x05\x04\x1d\x02\x07\x01\x12\x04\x93\x01\x10\x16\n\r\n\x05\x04\x1d\
-    \x02\x07\x03\x12\x04\x93\x01\x19\x1a\n\x0c\n\x04\x04\x1d\x02\x08\x12\x04\
-    \x94\x01\x041\n\r\n\x05\x04\x1d\x02\x08\x06\x12\x04\x94\x01\x04\x1a\n\r\
-    \n\x05\x04\x1d\x02\x08\x01\x12\x04\x94\x01\x1b,\n\r\n\x05\x04\x1d\x02\
-    \x08\x03\x12\x04\x94\x01/0\n\x0c\n\x04\x04\x1d\x02\t\x12\x04\x95\x01\x04\
-    *\n\r\n\x05\x04\x1d\x02\t\x06\x12\x04\x95\x01\x04\x16\n\r\n\x05\x04\x1d\
-    \x02\t\x01\x12\x04\x95\x01\x17$\n\r\n\x05\x04\x1d\x02\t\x03\x12\x04\x95\
-    \x01')\n\x0c\n\x04\x04\x1d\x02\n\x12\x04\x96\x01\x04\"\n\r\n\x05\x04\x1d\
-    \x02\n\x06\x12\x04\x96\x01\x04\x12\n\r\n\x05\x04\x1d\x02\n\x01\x12\x04\
-    \x96\x01\x13\x1c\n\r\n\x05\x04\x1d\x02\n\x03\x12\x04\x96\x01\x1f!\nF\n\
-    \x02\x04\x1e\x12\x06\x9b\x01\0\xa4\x01\x01\x1a8\x20Message\x20with\x20da\
-    ta\x20required\x20to\x20execute\x20`call`\x20operation\n\n\x0b\n\x03\x04\
-    \x1e\x01\x12\x04\x9b\x01\x08\x17\n\x0c\n\x04\x04\x1e\x02\0\x12\x04\x9c\
-    \x01\x02\x11\n\r\n\x05\x04\x1e\x02\0\x05\x12\x04\x9c\x01\x02\x07\n\r\n\
-    \x05\x04\x1e\x02\0\x01\x12\x04\x9c\x01\x08\x0c\n\r\n\x05\x04\x1e\x02\0\
-    \x03\x12\x04\x9c\x01\x0f\x10\n\x0c\n\x04\x04\x1e\x02\x01\x12\x04\x9d\x01\
-    \x02\x0f\n\r\n\x05\x04\x1e\x02\x01\x05\x12\x04\x9d\x01\x02\x07\n\r\n\x05\
-    \x04\x1e\x02\x01\x01\x12\x04\x9d\x01\x08\n\n\r\n\x05\x04\x1e\x02\x01\x03\
-    \x12\x04\x9d\x01\r\x0e\n\x0c\n\x04\x04\x1e\x02\x02\x12\x04\x9e\x01\x02\
-    \x11\n\r\n\x05\x04\x1e\x02\x02\x05\x12\x04\x9e\x01\x02\x07\n\r\n\x05\x04\
-    \x1e\x02\x02\x01\x12\x04\x9e\x01\x08\x0c\n\r\n\x05\x04\x1e\x02\x02\x03\
-    \x12\x04\x9e\x01\x0f\x10\n\x0c\n\x04\x04\x1e\x02\x03\x12\x04\x9f\x01\x02\
-    \x16\n\r\n\x05\x04\x1e\x02\x03\x05\x12\x04\x9f\x01\x02\x08\n\r\n\x05\x04\
-    \x1e\x02\x03\x01\x12\x04\x9f\x01\t\x11\n\r\n\x05\x04\x1e\x02\x03\x03\x12\
-    \x04\x9f\x01\x14\x15\n\x0c\n\x04\x04\x1e\x02\x04\x12\x04\xa0\x01\x02\x12\
-    \n\r\n\x05\x04\x1e\x02\x04\x05\x12\x04\xa0\x01\x02\x07\n\r\n\x05\x04\x1e\
-    \x02\x04\x01\x12\x04\xa0\x01\x08\r\n\r\n\x05\x04\x1e\x02\x04\x03\x12\x04\
-    \xa0\x01\x10\x11\n\x0c\n\x04\x04\x1e\x02\x05\x12\x04\xa1\x01\x02)\n\r\n\
-    \x05\x04\x1e\x02\x05\x04\x12\x04\xa1\x01\x02\n\n\r\n\x05\x04\x1e\x02\x05\
-    \x06\x12\x04\xa1\x01\x0b\x19\n\r\n\x05\x04\x1e\x02\x05\x01\x12\x04\xa1\
-    \x01\x1a$\n\r\n\x05\x04\x1e\x02\x05\x03\x12\x04\xa1\x01'(\n\x0c\n\x04\
-    \x04\x1e\x02\x06\x12\x04\xa2\x01\x02\x12\n\r\n\x05\x04\x1e\x02\x06\x05\
-    \x12\x04\xa2\x01\x02\x06\n\r\n\x05\x04\x1e\x02\x06\x01\x12\x04\xa2\x01\
-    \x07\r\n\r\n\x05\x04\x1e\x02\x06\x03\x12\x04\xa2\x01\x10\x11\n\x0c\n\x04\
-    \x04\x1e\x02\x07\x12\x04\xa3\x01\x02\x13\n\r\n\x05\x04\x1e\x02\x07\x05\
-    \x12\x04\xa3\x01\x02\x08\n\r\n\x05\x04\x1e\x02\x07\x01\x12\x04\xa3\x01\t\
-    \x0e\n\r\n\x05\x04\x1e\x02\x07\x03\x12\x04\xa3\x01\x11\x12\nH\n\x02\x04\
-    \x1f\x12\x06\xa7\x01\0\xaf\x01\x01\x1a:\x20Message\x20with\x20data\x20re\
-    quired\x20to\x20execute\x20`create`\x20operation\n\n\x0b\n\x03\x04\x1f\
-    \x01\x12\x04\xa7\x01\x08\x19\n\x0c\n\x04\x04\x1f\x02\0\x12\x04\xa8\x01\
-    \x02\x11\n\r\n\x05\x04\x1f\x02\0\x05\x12\x04\xa8\x01\x02\x07\n\r\n\x05\
-    \x04\x1f\x02\0\x01\x12\x04\xa8\x01\x08\x0c\n\r\n\x05\x04\x1f\x02\0\x03\
-    \x12\x04\xa8\x01\x0f\x10\n\x0c\n\x04\x04\x1f\x02\x01\x12\x04\xa9\x01\x02\
-    \x11\n\r\n\x05\x04\x1f\x02\x01\x05\x12\x04\xa9\x01\x02\x07\n\r\n\x05\x04\
-    \x1f\x02\x01\x01\x12\x04\xa9\x01\x08\x0c\n\r\n\x05\x04\x1f\x02\x01\x03\
-    \x12\x04\xa9\x01\x0f\x10\n\x0c\n\x04\x04\x1f\x02\x02\x12\x04\xaa\x01\x02\
-    \x16\n\r\n\x05\x04\x1f\x02\x02\x05\x12\x04\xaa\x01\x02\x08\n\r\n\x05\x04\
-    \x1f\x02\x02\x01\x12\x04\xaa\x01\t\x11\n\r\n\x05\x04\x1f\x02\x02\x03\x12\
-    \x04\xaa\x01\x14\x15\n\x0c\n\x04\x04\x1f\x02\x03\x12\x04\xab\x01\x02\x12\
-    \n\r\n\x05\x04\x1f\x02\x03\x05\x12\x04\xab\x01\x02\x07\n\r\n\x05\x04\x1f\
-    \x02\x03\x01\x12\x04\xab\x01\x08\r\n\r\n\x05\x04\x1f\x02\x03\x03\x12\x04\
-    \xab\x01\x10\x11\n\x0c\n\x04\x04\x1f\x02\x04\x12\x04\xac\x01\x02)\n\r\n\
-    \x05\x04\x1f\x02\x04\x04\x12\x04\xac\x01\x02\n\n\r\n\x05\x04\x1f\x02\x04\
-    \x06\x12\x04\xac\x01\x0b\x19\n\r\n\x05\x04\x1f\x02\x04\x01\x12\x04\xac\
-    \x01\x1a$\n\r\n\x05\x04\x1f\x02\x04\x03\x12\x04\xac\x01'(\n\x0c\n\x04\
-    \x04\x1f\x02\x05\x12\x04\xad\x01\x02\x12\n\r\n\x05\x04\x1f\x02\x05\x05\
-    \x12\x04\xad\x01\x02\x06\n\r\n\x05\x04\x1f\x02\x05\x01\x12\x04\xad\x01\
-    \x07\r\n\r\n\x05\x04\x1f\x02\x05\x03\x12\x04\xad\x01\x10\x11\n\x0c\n\x04\
-    \x04\x1f\x02\x06\x12\x04\xae\x01\x02\x13\n\r\n\x05\x04\x1f\x02\x06\x05\
-    \x12\x04\xae\x01\x02\x08\n\r\n\x05\x04\x1f\x02\x06\x01\x12\x04\xae\x01\t\
-    \x0e\n\r\n\x05\x04\x1f\x02\x06\x03\x12\x04\xae\x01\x11\x12\n3\n\x02\x04\
-    \x20\x12\x06\xb2\x01\0\xb5\x01\x01\x1a%\x20Request\x20to\x20execute\x20`\
-    call`\x20operation\n\n\x0b\n\x03\x04\x20\x01\x12\x04\xb2\x01\x08\x18\n\
-    \x0c\n\x04\x04\x20\x02\0\x12\x04\xb3\x01\x02\x1d\n\r\n\x05\x04\x20\x02\0\
-    \x06\x12\x04\xb3\x01\x02\x11\n\r\n\x05\x04\x20\x02\0\x01\x12\x04\xb3\x01\
-    \x12\x18\n\r\n\x05\x04\x20\x02\0\x03\x12\x04\xb3\x01\x1b\x1c\n\x0c\n\x04\
-    \x04\x20\x02\x01\x12\x04\xb4\x01\x02!\n\r\n\x05\x04\x20\x02\x01\x06\x12\
-    \x04\xb4\x01\x02\x14\n\r\n\x05\x04\x20\x02\x01\x01\x12\x04\xb4\x01\x15\
-    \x1c\n\r\n\x05\x04\x20\x02\x01\x03\x12\x04\xb4\x01\x1f\x20\n5\n\x02\x04!\
-    \x12\x06\xb8\x01\0\xbb\x01\x01\x1a'\x20Request\x20to\x20execute\x20`crea\
-    te`\x20operation\n\n\x0b\n\x03\x04!\x01\x12\x04\xb8\x01\x08\x1a\n\x0c\n\
-    \x04\x04!\x02\0\x12\x04\xb9\x01\x02\x1f\n\r\n\x05\x04!\x02\0\x06\x12\x04\
-    \xb9\x01\x02\x13\n\r\n\x05\x04!\x02\0\x01\x12\x04\xb9\x01\x14\x1a\n\r\n\
-    \x05\x04!\x02\0\x03\x12\x04\xb9\x01\x1d\x1e\n\x0c\n\x04\x04!\x02\x01\x12\
-    \x04\xba\x01\x02!\n\r\n\x05\x04!\x02\x01\x06\x12\x04\xba\x01\x02\x14\n\r\
-    \n\x05\x04!\x02\x01\x01\x12\x04\xba\x01\x15\x1c\n\r\n\x05\x04!\x02\x01\
-    \x03\x12\x04\xba\x01\x1f\x20\n/\n\x02\x04\"\x12\x04\xbe\x01\0\x1f\x1a#\
-    \x20Request\x20to\x20obtain\x20node\x20public\x20key\n\n\x0b\n\x03\x04\"\
-    \x01\x12\x04\xbe\x01\x08\x1c\n+\n\x02\x04#\x12\x04\xc1\x01\06\x1a\x1f\
-    \x20Response\x20with\x20node\x20public\x20key\n\n\x0b\n\x03\x04#\x01\x12\
-    \x04\xc1\x01\x08\x1d\n\x0c\n\x04\x04#\x02\0\x12\x04\xc1\x01\x204\n\r\n\
-    \x05\x04#\x02\0\x05\x12\x04\xc1\x01\x20%\n\r\n\x05\x04#\x02\0\x01\x12\
-    \x04\xc1\x01&/\n\r\n\x05\x04#\x02\0\x03\x12\x04\xc1\x0123\n\x0c\n\x02\
-    \x04$\x12\x06\xc3\x01\0\xc9\x01\x01\n\x0b\n\x03\x04$\x01\x12\x04\xc3\x01\
-    \x08\x12\n\x0e\n\x04\x04$\x08\0\x12\x06\xc4\x01\x02\xc8\x01\x03\n\r\n\
-    \x05\x04$\x08\0\x01\x12\x04\xc4\x01\x08\x0b\n\x0c\n\x04\x04$\x02\0\x12\
-    \x04\xc5\x01\x04%\n\r\n\x05\x04$\x02\0\x06\x12\x04\xc5\x01\x04\x14\n\r\n\
-    \x05\x04$\x02\0\x01\x12\x04\xc5\x01\x15\x20\n\r\n\x05\x04$\x02\0\x03\x12\
-    \x04\xc5\x01#$\n\x0c\n\x04\x04$\x02\x01\x12\x04\xc6\x01\x04)\n\r\n\x05\
-    \x04$\x02\x01\x06\x12\x04\xc6\x01\x04\x16\n\r\n\x05\x04$\x02\x01\x01\x12\
-    \x04\xc6\x01\x17$\n\r\n\x05\x04$\x02\x01\x03\x12\x04\xc6\x01'(\n\x0c\n\
-    \x04\x04$\x02\x02\x12\x04\xc7\x01\x04.\n\r\n\x05\x04$\x02\x02\x06\x12\
-    \x04\xc7\x01\x04\x18\n\r\n\x05\x04$\x02\x02\x01\x12\x04\xc7\x01\x19)\n\r\
-    \n\x05\x04$\x02\x02\x03\x12\x04\xc7\x01,-b\x06proto3\
+    \x04\x02\x02\x05\x12\x036\x02\x08\n\x0c\n\x05\x04\x04\x02\x02\x01\x12\
+    \x036\t\x11\n\x0c\n\x05\x04\x04\x02\x02\x03\x12\x036\x14\x15\n.\n\x04\
+    \x04\x04\x02\x03\x12\x038\x02\x16\x1a!\x20gas\x20consumed\x20by\x20the\
+    \x20transaction\n\n\x0c\n\x05\x04\x04\x02\x03\x05\x12\x038\x02\x08\n\x0c\
+    \n\x05\x04\x04\x02\x03\x01\x12\x038\t\x11\n\x0c\n\x05\x04\x04\x02\x03\
+    \x03\x12\x038\x14\x15\n\x82\x01\n\x04\x04\x04\x02\x04\x12\x03;\x02\x1a\
+    \x1au\x20decoded\x20reason\x20of\x20reverted\x20transaction.\x20Not\x20s\
+    et\x20for\x20encrypted\n\x20transactions,\x20since\x20their\x20returned\
+    \x20data\x20is\x20encrypted\n\n\x0c\n\x05\x04\x04\x02\x04\x06\x12\x03;\
+    \x02\x0e\n\x0c\n\x05\x04\x04\x02\x04\x01\x12\x03;\x0f\x15\n\x0c\n\x05\
+    \x04\x04\x02\x04\x03\x12\x03;\x18\x19\nz\n\x04\x04\x04\x02\x05\x12\x03>\
+    \x02\x18\x1am\x20exit\x20status\x20returned\x20by\x20vm:\x20\"succeed\",\
+    \x20\"revert\",\x20\"error\"\x20or\x20\"fatal\".\n\x20Empty\x20if\x20tra\
+    nsaction\x20was\x20not\x20executed\n\n\x0c\n\x05\x04\x04\x02\x05\x05\x12\
+    \x03>\x02\x08\n\x0c\n\x05\x04\x04\x02\x05\x01\x12\x03>\t\x13\n\x0c\n\x05\
+    \x04\x04\x02\x05\x03\x12\x03>\x16\x17\nK\n\x04\x04\x04\x02\x06\x12\x03@\
+    \x02\x18\x1a>\x20variant\x20of\x20exit\x20reason,\x20for\x20example\x20\
+    \"Returned\"\x20or\x20\"OutOfGas\"\n\n\x0c\n\x05\x04\x04\x02\x06\x05\x12\
+    \x03@\x02\x08\n\x0c\n\x05\x04\x04\x02\x06\x01\x12\x03@\t\x13\n\x0c\n\x05\
+    \x04\x04\x02\x06\x03\x12\x03@\x16\x17\nJ\n\x04\x04\x04\x02\x07\x12\x03B\
+    \x02\x1c\x1a=\x20address\x20of\x20contract\x20created\x20by\x20successfu\
+    l\x20contract\x20creation\n\n\x0c\n\x05\x04\x04\x02\x07\x05\x12\x03B\x02\
+    \x07\n\x0c\n\x05\x04\x04\x02\x07\x01\x12\x03B\x08\x17\n\x0c\n\x05\x04\
+    \x04\x02\x07\x03\x12\x03B\x1a\x1b\nD\n\x04\x04\x04\x02\x08\x12\x03D\x02\
+    \x1a\x1a7\x20gas\x20refund,\x20which\x20is\x20already\x20subtracted\x20f\
+    rom\x20gas_used\n\n\x0c\n\x05\x04\x04\x02\x08\x05\x12\x03D\x02\x08\n\x0c\
+    \n\x05\x04\x04\x02\x08\x01\x12\x03D\t\x14\n\x0c\n\x05\x04\x04\x02\x08\
+    \x03\x12\x03D\x17\x19\nP\n\x04\x04\x04\x02\t\x12\x03F\x02\x1b\x1aC\x20ga\
+    s\x20charged\x20before\x20execution\x20for\x20transaction\x20data\x20and\
+    \x20access\x20list\n\n\x0c\n\x05\x04\x04\x02\t\x05\x12\x03F\x02\x08\n\
+    \x0c\n\x05\x04\x04\x02\t\x01\x12\x03F\t\x15\n\x0c\n\x05\x04\x04\x02\t\
+    \x03\x12\x03F\x18\x1a\n6\n\x04\x04\x04\x02\n\x12\x03H\x02\x1b\x1a)\x20ga\
+    s\x20consumed\x20by\x20execution\x20before\x20refund\n\n\x0c\n\x05\x04\
+    \x04\x02\n\x05\x12\x03H\x02\x08\n\x0c\n\x05\x04\x04\x02\n\x01\x12\x03H\t\
+    \x15\n\x0c\n\x05\x04\x04\x02\n\x03\x12\x03H\x18\x1a\nl\n\x04\x04\x04\x02\
+    \x0b\x12\x03K\x02\x17\x1a_\x20receipt\x20of\x20executed\x20transaction.\
+    \x20Set\x20for\x20call,\x20create,\x20batch\x20and\x20signed\n\x20transa\
+    ction\x20requests\n\n\x0c\n\x05\x04\x04\x02\x0b\x06\x12\x03K\x02\t\n\x0c\
+    \n\x05\x04\x04\x02\x0b\x01\x12\x03K\n\x11\n\x0c\n\x05\x04\x04\x02\x0b\
+    \x03\x12\x03K\x14\x16\n-\n\x02\x04\x05\x12\x04O\0X\x01\x1a!\x20Receipt\
+    \x20of\x20executed\x20transaction\n\n\n\n\x03\x04\x05\x01\x12\x03O\x08\
+    \x0f\n6\n\x04\x04\x05\x02\0\x12\x03Q\x02\x14\x1a)\x201\x20if\x20transact\
+    ion\x20succeeded,\x200\x20otherwise\n\n\x0c\n\x05\x04\x05\x02\0\x05\x12\
+    \x03Q\x02\x08\n\x0c\n\x05\x04\x05\x02\0\x01\x12\x03Q\t\x0f\n\x0c\n\x05\
+    \x04\x05\x02\0\x03\x12\x03Q\x12\x13\nJ\n\x04\x04\x05\x02\x01\x12\x03S\
+    \x02\x1f\x1a=\x20gas\x20used\x20by\x20this\x20and\x20all\x20previous\x20\
+    transactions\x20of\x20the\x20block\n\n\x0c\n\x05\x04\x05\x02\x01\x05\x12\
+    \x03S\x02\x08\n\x0c\n\x05\x04\x05\x02\x01\x01\x12\x03S\t\x1a\n\x0c\n\x05\
+    \x04\x05\x02\x01\x03\x12\x03S\x1d\x1e\n@\n\x04\x04\x05\x02\x02\x12\x03U\
+    \x02\x16\x1a3\x202048-bit\x20bloom\x20filter\x20of\x20log\x20addresses\
+    \x20and\x20topics\n\n\x0c\n\x05\x04\x05\x02\x02\x05\x12\x03U\x02\x07\n\
+    \x0c\n\x05\x04\x05\x02\x02\x01\x12\x03U\x08\x11\n\x0c\n\x05\x04\x05\x02\
+    \x02\x03\x12\x03U\x14\x15\nY\n\x04\x04\x05\x02\x03\x12\x03W\x02\x14\x1aL\
+    \x20RLP-encoded\x20receipt,\x20prefixed\x20with\x20transaction\x20type\
+    \x20for\x20typed\x20transactions\n\n\x0c\n\x05\x04\x05\x02\x03\x05\x12\
+    \x03W\x02\x07\n\x0c\n\x05\x04\x05\x02\x03\x01\x12\x03W\x08\x0f\n\x0c\n\
+    \x05\x04\x05\x02\x03\x03\x12\x03W\x12\x13\nQ\n\x02\x04\x06\x12\x04[\0e\
+    \x01\x1aE\x20RevertReason\x20contains\x20decoded\x20data\x20returned\x20\
+    by\x20reverted\x20transaction\n\n\n\n\x03\x04\x06\x01\x12\x03[\x08\x14\n\
+    e\n\x04\x04\x06\x02\0\x12\x03^\x02\x12\x1aX\x20\"error\"\x20for\x20`Erro\
+    r(string)`,\x20\"panic\"\x20for\x20`Panic(uint256)`,\x20\"custom\"\x20fo\
+    r\n\x20custom\x20errors\n\n\x0c\n\x05\x04\x06\x02\0\x05\x12\x03^\x02\x08\
+    \n\x0c\n\x05\x04\x06\x02\0\x01\x12\x03^\t\r\n\x0c\n\x05\x04\x06\x02\0\
+    \x03\x12\x03^\x10\x11\nB\n\x04\x04\x06\x02\x01\x12\x03`\x02\x15\x1a5\x20\
+    message\x20of\x20`Error(string)`\x20or\x20meaning\x20of\x20panic\x20code\
+    \n\n\x0c\n\x05\x04\x06\x02\x01\x05\x12\x03`\x02\x08\n\x0c\n\x05\x04\x06\
+    \x02\x01\x01\x12\x03`\t\x10\n\x0c\n\x05\x04\x06\x02\x01\x03\x12\x03`\x13\
+    \x14\n\x0b\n\x04\x04\x06\x02\x02\x12\x03a\x02\x17\n\x0c\n\x05\x04\x06\
+    \x02\x02\x05\x12\x03a\x02\x08\n\x0c\n\x05\x04\x06\x02\x02\x01\x12\x03a\t\
+    \x12\n\x0c\n\x05\x04\x06\x02\x02\x03\x12\x03a\x15\x16\nH\n\x04\x04\x06\
+    \x02\x03\x12\x03c\x02\x15\x1a;\x204-byte\x20selector\x20and\x20ABI-encod\
+    ed\x20arguments\x20of\x20custom\x20error\n\n\x0c\n\x05\x04\x06\x02\x03\
+    \x05\x12\x03c\x02\x07\n\x0c\n\x05\x04\x06\x02\x03\x01\x12\x03c\x08\x10\n\
+    \x0c\n\x05\x04\x06\x02\x03\x03\x12\x03c\x13\x14\n\x0b\n\x04\x04\x06\x02\
+    \x04\x12\x03d\x02\x11\n\x0c\n\x05\x04\x06\x02\x04\x05\x12\x03d\x02\x07\n\
+    \x0c\n\x05\x04\x06\x02\x04\x01\x12\x03d\x08\x0c\n\x0c\n\x05\x04\x06\x02\
+    \x04\x03\x12\x03d\x0f\x10\nc\n\x02\x04\x07\x12\x03i\0\"\x1aX\x20Topic\
+    \x20represents\x2032-byte\x20words\x20that\x20is\x20used\x20to\x20descri\
+    be\x20what\xe2\x80\x99s\x20going\x20on\x20in\x20an\n\x20event\n\n\n\n\
+    \x03\x04\x07\x01\x12\x03i\x08\r\n\x0b\n\x04\x04\x07\x02\0\x12\x03i\x10\
+    \x20\n\x0c\n\x05\x04\x07\x02\0\x05\x12\x03i\x10\x15\n\x0c\n\x05\x04\x07\
+    \x02\0\x01\x12\x03i\x16\x1b\n\x0c\n\x05\x04\x07\x02\0\x03\x12\x03i\x1e\
+    \x1f\n\x81\x01\n\x02\x04\x08\x12\x04n\0u\x01\x1au\x20Log\x20represents\
+    \x20an\x20protobuf\x20compatible\x20Ethereum\x20Log\x20that\x20defines\
+    \x20a\x20contract\n\x20log\x20event.\n\x20Copied\x20from\x20`devnet/prot\
+    o``\n\n\n\n\x03\x04\x08\x01\x12\x03n\x08\x0b\n?\n\x04\x04\x08\x02\0\x12\
+    \x03p\x02\x14\x1a2\x20address\x20of\x20the\x20contract\x20that\x20genera\
+    ted\x20the\x20event\n\n\x0c\n\x05\x04\x08\x02\0\x05\x12\x03p\x02\x07\n\
+    \x0c\n\x05\x04\x08\x02\0\x01\x12\x03p\x08\x0f\n\x0c\n\x05\x04\x08\x02\0\
+    \x03\x12\x03p\x12\x13\n7\n\x04\x04\x08\x02\x01\x12\x03r\x02\x1c\x1a*\x20\
+    list\x20of\x20topics\x20provided\x20by\x20the\x20contract.\n\n\x0c\n\x05\
+    \x04\x08\x02\x01\x04\x12\x03r\x02\n\n\x0c\n\x05\x04\x08\x02\x01\x06\x12\
+    \x03r\x0b\x10\n\x0c\n\x05\x04\x08\x02\x01\x01\x12\x03r\x11\x17\n\x0c\n\
+    \x05\x04\x08\x02\x01\x03\x12\x03r\x1a\x1b\n<\n\x04\x04\x08\x02\x02\x12\
+    \x03t\x02\x11\x1a/\x20supplied\x20by\x20the\x20contract,\x20usually\x20A\
+    BI-encoded\n\n\x0c\n\x05\x04\x08\x02\x02\x05\x12\x03t\x02\x07\n\x0c\n\
+    \x05\x04\x08\x02\x02\x01\x12\x03t\x08\x0c\n\x0c\n\x05\x04\x08\x02\x02\
+    \x03\x12\x03t\x0f\x10\nX\n\x02\x04\t\x12\x04x\0{\x01\x1aL\x20Request\x20\
+    for\x20account\x20code\x20(smart\x20contract\x20deployed\x20behind\x20pr\
+    ovided\x20address)\n\n\n\n\x03\x04\t\x01\x12\x03x\x08\x17\n*\n\x04\x04\t\
+    \x02\0\x12\x03z\x02\x14\x1a\x1d\x2020\x20bytes\x20of\x20account\x20addre\
+    ss\n\n\x0c\n\x05\x04\t\x02\0\x05\x12\x03z\x02\x07\n\x0c\n\x05\x04\t\x02\
+    \0\x01\x12\x03z\x08\x0f\n\x0c\n\x05\x04\t\x02\0\x03\x12\x03z\x12\x13\n(\
+    \n\x02\x04\n\x12\x05~\0\x83\x01\x01\x1a\x1b\x20Response\x20for\x20accoun\
+    t\x20code\n\n\n\n\x03\x04\n\x01\x12\x03~\x08\x1f\nc\n\x04\x04\n\x02\0\
+    \x12\x04\x81\x01\x02\x14\x1aU\x20Since\x20both\x20fields\x20are\x20256-b\
+    it\x20unsigned\x20integer,\x20we\x20encode\x20them\x20as\n\x20big-endian\
+    \x20bytes\n\n\r\n\x05\x04\n\x02\0\x05\x12\x04\x81\x01\x02\x07\n\r\n\x05\
+    \x04\n\x02\0\x01\x12\x04\x81\x01\x08\x0f\n\r\n\x05\x04\n\x02\0\x03\x12\
+    \x04\x81\x01\x12\x13\n\x0c\n\x04\x04\n\x02\x01\x12\x04\x82\x01\x02\x13\n\
+    \r\n\x05\x04\n\x02\x01\x05\x12\x04\x82\x01\x02\x08\n\r\n\x05\x04\n\x02\
+    \x01\x01\x12\x04\x82\x01\t\x0e\n\r\n\x05\x04\n\x02\x01\x03\x12\x04\x82\
+    \x01\x11\x12\nH\n\x02\x04\x0b\x12\x06\x86\x01\0\x8a\x01\x01\x1a:\x20Requ\
+    est\x20to\x20insert\x20account\x20data\x20such\x20as\x20balance\x20and\
+    \x20nonce\n\n\x0b\n\x03\x04\x0b\x01\x12\x04\x86\x01\x08\x1a\n\x0c\n\x04\
+    \x04\x0b\x02\0\x12\x04\x87\x01\x02\x14\n\r\n\x05\x04\x0b\x02\0\x05\x12\
+    \x04\x87\x01\x02\x07\n\r\n\x05\x04\x0b\x02\0\x01\x12\x04\x87\x01\x08\x0f\
+    \n\r\n\x05\x04\x0b\x02\0\x03\x12\x04\x87\x01\x12\x13\n\x0c\n\x04\x04\x0b\
+    \x02\x01\x12\x04\x88\x01\x02\x14\n\r\n\x05\x04\x0b\x02\x01\x05\x12\x04\
+    \x88\x01\x02\x07\n\r\n\x05\x04\x0b\x02\x01\x01\x12\x04\x88\x01\x08\x0f\n\
+    \r\n\x05\x04\x0b\x02\x01\x03\x12\x04\x88\x01\x12\x13\n\x0c\n\x04\x04\x0b\
+    \x02\x02\x12\x04\x89\x01\x02\x13\n\r\n\x05\x04\x0b\x02\x02\x05\x12\x04\
+    \x89\x01\x02\x08\n\r\n\x05\x04\x0b\x02\x02\x01\x12\x04\x89\x01\t\x0e\n\r\
+    \n\x05\x04\x0b\x02\x02\x03\x12\x04\x89\x01\x11\x12\n,\n\x02\x04\x0c\x12\
+    \x04\x8d\x01\0%\x1a\x20\x20Response\x20for\x20account\x20insertion\n\n\
+    \x0b\n\x03\x04\x0c\x01\x12\x04\x8d\x01\x08\"\n\n\n\x02\x04\r\x12\x04\x8f\
+    \x01\0+\n\x0b\n\x03\x04\r\x01\x12\x04\x8f\x01\x08\x18\n\x0c\n\x04\x04\r\
+    \x02\0\x12\x04\x8f\x01\x1b)\n\r\n\x05\x04\r\x02\0\x05\x12\x04\x8f\x01\
+    \x1b\x20\n\r\n\x05\x04\r\x02\0\x01\x12\x04\x8f\x01!$\n\r\n\x05\x04\r\x02\
+    \0\x03\x12\x04\x8f\x01'(\n\n\n\x02\x04\x0e\x12\x04\x91\x01\07\n\x0b\n\
+    \x03\x04\x0e\x01\x12\x04\x91\x01\x08\x20\n\x0c\n\x04\x04\x0e\x02\0\x12\
+    \x04\x91\x01#5\n\r\n\x05\x04\x0e\x02\0\x05\x12\x04\x91\x01#'\n\r\n\x05\
+    \x04\x0e\x02\0\x01\x12\x04\x91\x01(0\n\r\n\x05\x04\x0e\x02\0\x03\x12\x04\
+    \x91\x0134\n\x0c\n\x02\x04\x0f\x12\x06\x93\x01\0\x96\x01\x01\n\x0b\n\x03\
+    \x04\x0f\x01\x12\x04\x93\x01\x08\"\n\x0c\n\x04\x04\x0f\x02\0\x12\x04\x94\
+    \x01\x02\x14\n\r\n\x05\x04\x0f\x02\0\x05\x12\x04\x94\x01\x02\x07\n\r\n\
+    \x05\x04\x0f\x02\0\x01\x12\x04\x94\x01\x08\x0f\n\r\n\x05\x04\x0f\x02\0\
+    \x03\x12\x04\x94\x01\x12\x13\n\x0c\n\x04\x04\x0f\x02\x01\x12\x04\x95\x01\
+    \x02\x12\n\r\n\x05\x04\x0f\x02\x01\x05\x12\x04\x95\x01\x02\x07\n\r\n\x05\
+    \x04\x0f\x02\x01\x01\x12\x04\x95\x01\x08\r\n\r\n\x05\x04\x0f\x02\x01\x03\
+    \x12\x04\x95\x01\x10\x11\n\n\n\x02\x04\x10\x12\x04\x98\x01\0?\n\x0b\n\
+    \x03\x04\x10\x01\x12\x04\x98\x01\x08*\n\x0c\n\x04\x04\x10\x02\0\x12\x04\
+    \x98\x01-=\n\r\n\x05\x04\x10\x02\0\x05\x12\x04\x98\x01-2\n\r\n\x05\x04\
+    \x10\x02\0\x01\x12\x04\x98\x0138\n\r\n\x05\x04\x10\x02\0\x03\x12\x04\x98\
+    \x01;<\n\n\n\x02\x04\x11\x12\x04\x9a\x01\02\n\x0b\n\x03\x04\x11\x01\x12\
+    \x04\x9a\x01\x08\x1b\n\x0c\n\x04\x04\x11\x02\0\x12\x04\x9a\x01\x1e0\n\r\
+    \n\x05\x04\x11\x02\0\x05\x12\x04\x9a\x01\x1e#\n\r\n\x05\x04\x11\x02\0\
+    \x01\x12\x04\x9a\x01$+\n\r\n\x05\x04\x11\x02\0\x03\x12\x04\x9a\x01./\n\n\
+    \n\x02\x04\x12\x12\x04\x9c\x01\07\n\x0b\n\x03\x04\x12\x01\x12\x04\x9c\
+    \x01\x08#\n\x0c\n\x04\x04\x12\x02\0\x12\x04\x9c\x01&5\n\r\n\x05\x04\x12\
+    \x02\0\x05\x12\x04\x9c\x01&+\n\r\n\x05\x04\x12\x02\0\x01\x12\x04\x9c\x01\
+    ,0\n\r\n\x05\x04\x12\x02\0\x03\x12\x04\x9c\x0134\n\x0c\n\x02\x04\x13\x12\
+    \x06\x9e\x01\0\xa1\x01\x01\n\x0b\n\x03\x04\x13\x01\x12\x04\x9e\x01\x08\
+    \x1e\n\x0c\n\x04\x04\x13\x02\0\x12\x04\x9f\x01\x02\x14\n\r\n\x05\x04\x13\
+    \x02\0\x05\x12\x04\x9f\x01\x02\x07\n\r\n\x05\x04\x13\x02\0\x01\x12\x04\
+    \x9f\x01\x08\x0f\n\r\n\x05\x04\x13\x02\0\x03\x12\x04\x9f\x01\x12\x13\n\
+    \x0c\n\x04\x04\x13\x02\x01\x12\x04\xa0\x01\x02\x11\n\r\n\x05\x04\x13\x02\
+    \x01\x05\x12\x04\xa0\x01\x02\x07\n\r\n\x05\x04\x13\x02\x01\x01\x12\x04\
+    \xa0\x01\x08\x0c\n\r\n\x05\x04\x13\x02\x01\x03\x12\x04\xa0\x01\x0f\x10\n\
+    \n\n\x02\x04\x14\x12\x04\xa3\x01\0)\n\x0b\n\x03\x04\x14\x01\x12\x04\xa3\
+    \x01\x08&\n\x0c\n\x02\x04\x15\x12\x06\xa5\x01\0\xa9\x01\x01\n\x0b\n\x03\
+    \x04\x15\x01\x12\x04\xa5\x01\x08\x1e\n\x0c\n\x04\x04\x15\x02\0\x12\x04\
+    \xa6\x01\x02\x14\n\r\n\x05\x04\x15\x02\0\x05\x12\x04\xa6\x01\x02\x07\n\r\
+    \n\x05\x04\x15\x02\0\x01\x12\x04\xa6\x01\x08\x0f\n\r\n\x05\x04\x15\x02\0\
+    \x03\x12\x04\xa6\x01\x12\x13\n\x0c\n\x04\x04\x15\x02\x01\x12\x04\xa7\x01\
+    \x02\x12\n\r\n\x05\x04\x15\x02\x01\x05\x12\x04\xa7\x01\x02\x07\n\r\n\x05\
+    \x04\x15\x02\x01\x01\x12\x04\xa7\x01\x08\r\n\r\n\x05\x04\x15\x02\x01\x03\
+    \x12\x04\xa7\x01\x10\x11\n\x0c\n\x04\x04\x15\x02\x02\x12\x04\xa8\x01\x02\
+    \x12\n\r\n\x05\x04\x15\x02\x02\x05\x12\x04\xa8\x01\x02\x07\n\r\n\x05\x04\
+    \x15\x02\x02\x01\x12\x04\xa8\x01\x08\r\n\r\n\x05\x04\x15\x02\x02\x03\x12\
+    \x04\xa8\x01\x10\x11\n\n\n\x02\x04\x16\x12\x04\xab\x01\0)\n\x0b\n\x03\
+    \x04\x16\x01\x12\x04\xab\x01\x08&\n\n\n\x02\x04\x17\x12\x04\xad\x01\0*\n\
+    \x0b\n\x03\x04\x17\x01\x12\x04\xad\x01\x08\x13\n\x0c\n\x04\x04\x17\x02\0\
+    \x12\x04\xad\x01\x16(\n\r\n\x05\x04\x17\x02\0\x05\x12\x04\xad\x01\x16\
+    \x1b\n\r\n\x05\x04\x17\x02\0\x01\x12\x04\xad\x01\x1c#\n\r\n\x05\x04\x17\
+    \x02\0\x03\x12\x04\xad\x01&'\n\n\n\x02\x04\x18\x12\x04\xaf\x01\0\x1e\n\
+    \x0b\n\x03\x04\x18\x01\x12\x04\xaf\x01\x08\x1b\n\x0c\n\x02\x04\x19\x12\
+    \x06\xb1\x01\0\xb4\x01\x01\n\x0b\n\x03\x04\x19\x01\x12\x04\xb1\x01\x08\
+    \x1e\n\x0c\n\x04\x04\x19\x02\0\x12\x04\xb2\x01\x02\x14\n\r\n\x05\x04\x19\
+    \x02\0\x05\x12\x04\xb2\x01\x02\x07\n\r\n\x05\x04\x19\x02\0\x01\x12\x04\
+    \xb2\x01\x08\x0f\n\r\n\x05\x04\x19\x02\0\x03\x12\x04\xb2\x01\x12\x13\n\
+    \x0c\n\x04\x04\x19\x02\x01\x12\x04\xb3\x01\x02\x12\n\r\n\x05\x04\x19\x02\
+    \x01\x05\x12\x04\xb3\x01\x02\x07\n\r\n\x05\x04\x19\x02\x01\x01\x12\x04\
+    \xb3\x01\x08\r\n\r\n\x05\x04\x19\x02\x01\x03\x12\x04\xb3\x01\x10\x11\n\n\
+    \n\x02\x04\x1a\x12\x04\xb6\x01\0)\n\x0b\n\x03\x04\x1a\x01\x12\x04\xb6\
+    \x01\x08&\n\n\n\x02\x04\x1b\x12\x04\xb8\x01\01\n\x0b\n\x03\x04\x1b\x01\
+    \x12\x04\xb8\x01\x08\x1a\n\x0c\n\x04\x04\x1b\x02\0\x12\x04\xb8\x01\x1d/\
+    \n\r\n\x05\x04\x1b\x02\0\x05\x12\x04\xb8\x01\x1d\"\n\r\n\x05\x04\x1b\x02\
+    \0\x01\x12\x04\xb8\x01#*\n\r\n\x05\x04\x1b\x02\0\x03\x12\x04\xb8\x01-.\n\
+    \n\n\x02\x04\x1c\x12\x04\xba\x01\0%\n\x0b\n\x03\x04\x1c\x01\x12\x04\xba\
+    \x01\x08\"\n\n\n\x02\x04\x1d\x12\x04\xbc\x01\0,\n\x0b\n\x03\x04\x1d\x01\
+    \x12\x04\xbc\x01\x08\x16\n\x0c\n\x04\x04\x1d\x02\0\x12\x04\xbc\x01\x19*\
+    \n\r\n\x05\x04\x1d\x02\0\x05\x12\x04\xbc\x01\x19\x1e\n\r\n\x05\x04\x1d\
+    \x02\0\x01\x12\x04\xbc\x01\x1f%\n\r\n\x05\x04\x1d\x02\0\x03\x12\x04\xbc\
+    \x01()\n\n\n\x02\x04\x1e\x12\x04\xbd\x01\02\n\x0b\n\x03\x04\x1e\x01\x12\
+    \x04\xbd\x01\x08\x1e\n\x0c\n\x04\x04\x1e\x02\0\x12\x04\xbd\x01!0\n\r\n\
+    \x05\x04\x1e\x02\0\x05\x12\x04\xbd\x01!&\n\r\n\x05\x04\x1e\x02\0\x01\x12\
+    \x04\xbd\x01'+\n\r\n\x05\x04\x1e\x02\0\x03\x12\x04\xbd\x01./\nk\n\x02\
+    \x04\x1f\x12\x06\xc1\x01\0\xc5\x01\x01\x1a]\x20Request\x20to\x20obtain\
+    \x20several\x20accounts,\x20contract\x20codes\x20and\x20storage\x20cells\
+    \x20within\n\x20a\x20single\x20query\n\n\x0b\n\x03\x04\x1f\x01\x12\x04\
+    \xc1\x01\x08\x16\n\x0c\n\x04\x04\x1f\x02\0\x12\x04\xc2\x01\x02(\n\r\n\
+    \x05\x04\x1f\x02\0\x04\x12\x04\xc2\x01\x02\n\n\r\n\x05\x04\x1f\x02\0\x06\
+    \x12\x04\xc2\x01\x0b\x1a\n\r\n\x05\x04\x1f\x02\0\x01\x12\x04\xc2\x01\x1b\
+    #\n\r\n\x05\x04\x1f\x02\0\x03\x12\x04\xc2\x01&'\n\x0c\n\x04\x04\x1f\x02\
+    \x01\x12\x04\xc3\x01\x02)\n\r\n\x05\x04\x1f\x02\x01\x04\x12\x04\xc3\x01\
+    \x02\n\n\r\n\x05\x04\x1f\x02\x01\x06\x12\x04\xc3\x01\x0b\x1e\n\r\n\x05\
+    \x04\x1f\x02\x01\x01\x12\x04\xc3\x01\x1f$\n\r\n\x05\x04\x1f\x02\x01\x03\
+    \x12\x04\xc3\x01'(\n\x0c\n\x04\x04\x1f\x02\x02\x12\x04\xc4\x01\x027\n\r\
+    \n\x05\x04\x1f\x02\x02\x04\x12\x04\xc4\x01\x02\n\n\r\n\x05\x04\x1f\x02\
+    \x02\x06\x12\x04\xc4\x01\x0b%\n\r\n\x05\x04\x1f\x02\x02\x01\x12\x04\xc4\
+    \x01&2\n\r\n\x05\x04\x1f\x02\x02\x03\x12\x04\xc4\x0156\nc\n\x02\x04\x20\
+    \x12\x06\xc9\x01\0\xcd\x01\x01\x1aU\x20Response\x20for\x20batch\x20read.\
+    \x20Items\x20are\x20in\x20the\x20same\x20order\x20as\x20corresponding\n\
+    \x20sub-requests\n\n\x0b\n\x03\x04\x20\x01\x12\x04\xc9\x01\x08\x1e\n\x0c\
+    \n\x04\x04\x20\x02\0\x12\x04\xca\x01\x020\n\r\n\x05\x04\x20\x02\0\x04\
+    \x12\x04\xca\x01\x02\n\n\r\n\x05\x04\x20\x02\0\x06\x12\x04\xca\x01\x0b\"\
+    \n\r\n\x05\x04\x20\x02\0\x01\x12\x04\xca\x01#+\n\r\n\x05\x04\x20\x02\0\
+    \x03\x12\x04\xca\x01./\n\x0c\n\x04\x04\x20\x02\x01\x12\x04\xcb\x01\x021\
+    \n\r\n\x05\x04\x20\x02\x01\x04\x12\x04\xcb\x01\x02\n\n\r\n\x05\x04\x20\
+    \x02\x01\x06\x12\x04\xcb\x01\x0b&\n\r\n\x05\x04\x20\x02\x01\x01\x12\x04\
+    \xcb\x01',\n\r\n\x05\x04\x20\x02\x01\x03\x12\x04\xcb\x01/0\n\x0c\n\x04\
+    \x04\x20\x02\x02\x12\x04\xcc\x01\x02?\n\r\n\x05\x04\x20\x02\x02\x04\x12\
+    \x04\xcc\x01\x02\n\n\r\n\x05\x04\x20\x02\x02\x06\x12\x04\xcc\x01\x0b-\n\
+    \r\n\x05\x04\x20\x02\x02\x01\x12\x04\xcc\x01.:\n\r\n\x05\x04\x20\x02\x02\
+    \x03\x12\x04\xcc\x01=>\n\xcb\x01\n\x02\x04!\x12\x06\xd2\x01\0\xd9\x01\
+    \x01\x1a\xbc\x01\x20Request\x20to\x20apply\x20all\x20state\x20changes\
+    \x20of\x20transaction\x20at\x20once.\x20Changes\x20should\x20be\n\x20app\
+    lied\x20atomically\x20in\x20the\x20following\x20order:\x20removed\x20acc\
+    ounts,\x20reset\x20storages,\n\x20accounts,\x20codes,\x20storage\x20cell\
+    s\n\n\x0b\n\x03\x04!\x01\x12\x04\xd2\x01\x08\x13\n\x0c\n\x04\x04!\x02\0\
+    \x12\x04\xd3\x01\x02#\n\r\n\x05\x04!\x02\0\x04\x12\x04\xd3\x01\x02\n\n\r\
+    \n\x05\x04!\x02\0\x06\x12\x04\xd3\x01\x0b\x16\n\r\n\x05\x04!\x02\0\x01\
+    \x12\x04\xd3\x01\x17\x1e\n\r\n\x05\x04!\x02\0\x03\x12\x04\xd3\x01!\"\n\
+    \x0c\n\x04\x04!\x02\x01\x12\x04\xd4\x01\x02/\n\r\n\x05\x04!\x02\x01\x04\
+    \x12\x04\xd4\x01\x02\n\n\r\n\x05\x04!\x02\x01\x06\x12\x04\xd4\x01\x0b\
+    \x1d\n\r\n\x05\x04!\x02\x01\x01\x12\x04\xd4\x01\x1e*\n\r\n\x05\x04!\x02\
+    \x01\x03\x12\x04\xd4\x01-.\n\x0c\n\x04\x04!\x02\x02\x12\x04\xd5\x01\x02+\
+    \n\r\n\x05\x04!\x02\x02\x04\x12\x04\xd5\x01\x02\n\n\r\n\x05\x04!\x02\x02\
+    \x06\x12\x04\xd5\x01\x0b\x1d\n\r\n\x05\x04!\x02\x02\x01\x12\x04\xd5\x01\
+    \x1e&\n\r\n\x05\x04!\x02\x02\x03\x12\x04\xd5\x01)*\n\x0c\n\x04\x04!\x02\
+    \x03\x12\x04\xd6\x01\x02,\n\r\n\x05\x04!\x02\x03\x04\x12\x04\xd6\x01\x02\
+    \n\n\r\n\x05\x04!\x02\x03\x06\x12\x04\xd6\x01\x0b!\n\r\n\x05\x04!\x02\
+    \x03\x01\x12\x04\xd6\x01\"'\n\r\n\x05\x04!\x02\x03\x03\x12\x04\xd6\x01*+\
+    \n@\n\x04\x04!\x02\x04\x12\x04\xd8\x01\x023\x1a2\x20storage\x20cells\x20\
+    with\x20empty\x20value\x20should\x20be\x20removed\n\n\r\n\x05\x04!\x02\
+    \x04\x04\x12\x04\xd8\x01\x02\n\n\r\n\x05\x04!\x02\x04\x06\x12\x04\xd8\
+    \x01\x0b!\n\r\n\x05\x04!\x02\x04\x01\x12\x04\xd8\x01\".\n\r\n\x05\x04!\
+    \x02\x04\x03\x12\x04\xd8\x0112\n\n\n\x02\x04\"\x12\x04\xdb\x01\0\x1e\n\
+    \x0b\n\x03\x04\"\x01\x12\x04\xdb\x01\x08\x1b\nf\n\x02\x04#\x12\x06\xdf\
+    \x01\0\xe2\x01\x01\x1aX\x20Request\x20to\x20obtain\x20balance\x20of\x20C\
+    osmos\x20account\x20in\x20provided\x20denomination\x20from\n\x20bank\x20\
+    module\n\n\x0b\n\x03\x04#\x01\x12\x04\xdf\x01\x08\x18\n\x0c\n\x04\x04#\
+    \x02\0\x12\x04\xe0\x01\x02\x14\n\r\n\x05\x04#\x02\0\x05\x12\x04\xe0\x01\
+    \x02\x07\n\r\n\x05\x04#\x02\0\x01\x12\x04\xe0\x01\x08\x0f\n\r\n\x05\x04#\
+    \x02\0\x03\x12\x04\xe0\x01\x12\x13\n\x0c\n\x04\x04#\x02\x01\x12\x04\xe1\
+    \x01\x02\x13\n\r\n\x05\x04#\x02\x01\x05\x12\x04\xe1\x01\x02\x08\n\r\n\
+    \x05\x04#\x02\x01\x01\x12\x04\xe1\x01\t\x0e\n\r\n\x05\x04#\x02\x01\x03\
+    \x12\x04\xe1\x01\x11\x12\n\n\n\x02\x04$\x12\x04\xe3\x01\07\n\x0b\n\x03\
+    \x04$\x01\x12\x04\xe3\x01\x08\x20\n\x0c\n\x04\x04$\x02\0\x12\x04\xe3\x01\
+    #5\n\r\n\x05\x04$\x02\0\x05\x12\x04\xe3\x01#(\n\r\n\x05\x04$\x02\0\x01\
+    \x12\x04\xe3\x01)0\n\r\n\x05\x04$\x02\0\x03\x12\x04\xe3\x0134\n\x0c\n\
+    \x02\x04%\x12\x06\xe5\x01\0\xf6\x01\x01\n\x0b\n\x03\x04%\x01\x12\x04\xe5\
+    \x01\x08\x15\n\x0e\n\x04\x04%\x08\0\x12\x06\xe6\x01\x02\xf5\x01\x03\n\r\
+    \n\x05\x04%\x08\0\x01\x12\x04\xe6\x01\x08\x0b\n\x0c\n\x04\x04%\x02\0\x12\
+    \x04\xe7\x01\x04#\n\r\n\x05\x04%\x02\0\x06\x12\x04\xe7\x01\x04\x13\n\r\n\
+    \x05\x04%\x02\0\x01\x12\x04\xe7\x01\x14\x1e\n\r\n\x05\x04%\x02\0\x03\x12\
+    \x04\xe7\x01!\"\n\x0c\n\x04\x04%\x02\x01\x12\x04\xe8\x01\x04)\n\r\n\x05\
+    \x04%\x02\x01\x06\x12\x04\xe8\x01\x04\x16\n\r\n\x05\x04%\x02\x01\x01\x12\
+    \x04\xe8\x01\x17$\n\r\n\x05\x04%\x02\x01\x03\x12\x04\xe8\x01'(\n\x0c\n\
+    \x04\x04%\x02\x02\x12\x04\xe9\x01\x04%\n\r\n\x05\x04%\x02\x02\x06\x12\
+    \x04\xe9\x01\x04\x14\n\r\n\x05\x04%\x02\x02\x01\x12\x04\xe9\x01\x15\x20\
+    \n\r\n\x05\x04%\x02\x02\x03\x12\x04\xe9\x01#$\n\x0c\n\x04\x04%\x02\x03\
+    \x12\x04\xea\x01\x04(\n\r\n\x05\x04%\x02\x03\x06\x12\x04\xea\x01\x04\x17\
+    \n\r\n\x05\x04%\x02\x03\x01\x12\x04\xea\x01\x18#\n\r\n\x05\x04%\x02\x03\
+    \x03\x12\x04\xea\x01&'\n\x0c\n\x04\x04%\x02\x04\x12\x04\xeb\x01\x04/\n\r\
+    \n\x05\x04%\x02\x04\x06\x12\x04\xeb\x01\x04\x1e\n\r\n\x05\x04%\x02\x04\
+    \x01\x12\x04\xeb\x01\x1f*\n\r\n\x05\x04%\x02\x04\x03\x12\x04\xeb\x01-.\n\
+    \x0c\n\x04\x04%\x02\x05\x12\x04\xec\x01\x041\n\r\n\x05\x04%\x02\x05\x06\
+    \x12\x04\xec\x01\x04\x1a\n\r\n\x05\x04%\x02\x05\x01\x12\x04\xec\x01\x1b,\
+    \n\r\n\x05\x04%\x02\x05\x03\x12\x04\xec\x01/0\n\x0c\n\x04\x04%\x02\x06\
+    \x12\x04\xed\x01\x041\n\r\n\x05\x04%\x02\x06\x06\x12\x04\xed\x01\x04\x1a\
+    \n\r\n\x05\x04%\x02\x06\x01\x12\x04\xed\x01\x1b,\n\r\n\x05\x04%\x02\x06\
+    \x03\x12\x04\xed\x01/0\n\x0c\n\x04\x04%\x02\x07\x12\x04\xee\x01\x04\x1b\
+    \n\r\n\x05\x04%\x02\x07\x06\x12\x04\xee\x01\x04\x0f\n\r\n\x05\x04%\x02\
+    \x07\x01\x12\x04\xee\x01\x10\x16\n\r\n\x05\x04%\x02\x07\x03\x12\x04\xee\
+    \x01\x19\x1a\n\x0c\n\x04\x04%\x02\x08\x12\x04\xef\x01\x041\n\r\n\x05\x04\
+    %\x02\x08\x06\x12\x04\xef\x01\x04\x1a\n\r\n\x05\x04%\x02\x08\x01\x12\x04\
+    \xef\x01\x1b,\n\r\n\x05\x04%\x02\x08\x03\x12\x04\xef\x01/0\n\x0c\n\x04\
+    \x04%\x02\t\x12\x04\xf0\x01\x04*\n\r\n\x05\x04%\x02\t\x06\x12\x04\xf0\
+    \x01\x04\x16\n\r\n\x05\x04%\x02\t\x01\x12\x04\xf0\x01\x17$\n\r\n\x05\x04\
+    %\x02\t\x03\x12\x04\xf0\x01')\n\x0c\n\x04\x04%\x02\n\x12\x04\xf1\x01\x04\
+    \"\n\r\n\x05\x04%\x02\n\x06\x12\x04\xf1\x01\x04\x12\n\r\n\x05\x04%\x02\n\
+    \x01\x12\x04\xf1\x01\x13\x1c\n\r\n\x05\x04%\x02\n\x03\x12\x04\xf1\x01\
+    \x1f!\n\x0c\n\x04\x04%\x02\x0b\x12\x04\xf2\x01\x04\"\n\r\n\x05\x04%\x02\
+    \x0b\x06\x12\x04\xf2\x01\x04\x12\n\r\n\x05\x04%\x02\x0b\x01\x12\x04\xf2\
+    \x01\x13\x1c\n\r\n\x05\x04%\x02\x0b\x03\x12\x04\xf2\x01\x1f!\n\x0c\n\x04\
+    \x04%\x02\x0c\x12\x04\xf3\x01\x04\x1c\n\r\n\x05\x04%\x02\x0c\x06\x12\x04\
+    \xf3\x01\x04\x0f\n\r\n\x05\x04%\x02\x0c\x01\x12\x04\xf3\x01\x10\x16\n\r\
+    \n\x05\x04%\x02\x0c\x03\x12\x04\xf3\x01\x19\x1b\n\x0c\n\x04\x04%\x02\r\
+    \x12\x04\xf4\x01\x04&\n\r\n\x05\x04%\x02\r\x06\x12\x04\xf4\x01\x04\x14\n\
+    \r\n\x05\x04%\x02\r\x01\x12\x04\xf4\x01\x15\x20\n\r\n\x05\x04%\x02\r\x03\
+    \x12\x04\xf4\x01#%\nF\n\x02\x04&\x12\x06\xf9\x01\0\x88\x02\x01\x1a8\x20M\
+    essage\x20with\x20data\x20required\x20to\x20execute\x20`call`\x20operati\
+    on\n\n\x0b\n\x03\x04&\x01\x12\x04\xf9\x01\x08\x17\n\x0c\n\x04\x04&\x02\0\
+    \x12\x04\xfa\x01\x02\x11\n\r\n\x05\x04&\x02\0\x05\x12\x04\xfa\x01\x02\
+    \x07\n\r\n\x05\x04&\x02\0\x01\x12\x04\xfa\x01\x08\x0c\n\r\n\x05\x04&\x02\
+    \0\x03\x12\x04\xfa\x01\x0f\x10\n\x0c\n\x04\x04&\x02\x01\x12\x04\xfb\x01\
+    \x02\x0f\n\r\n\x05\x04&\x02\x01\x05\x12\x04\xfb\x01\x02\x07\n\r\n\x05\
+    \x04&\x02\x01\x01\x12\x04\xfb\x01\x08\n\n\r\n\x05\x04&\x02\x01\x03\x12\
+    \x04\xfb\x01\r\x0e\n\x0c\n\x04\x04&\x02\x02\x12\x04\xfc\x01\x02\x11\n\r\
+    \n\x05\x04&\x02\x02\x05\x12\x04\xfc\x01\x02\x07\n\r\n\x05\x04&\x02\x02\
+    \x01\x12\x04\xfc\x01\x08\x0c\n\r\n\x05\x04&\x02\x02\x03\x12\x04\xfc\x01\
+    \x0f\x10\n\x0c\n\x04\x04&\x02\x03\x12\x04\xfd\x01\x02\x16\n\r\n\x05\x04&\
+    \x02\x03\x05\x12\x04\xfd\x01\x02\x08\n\r\n\x05\x04&\x02\x03\x01\x12\x04\
+    \xfd\x01\t\x11\n\r\n\x05\x04&\x02\x03\x03\x12\x04\xfd\x01\x14\x15\n\x0c\
+    \n\x04\x04&\x02\x04\x12\x04\xfe\x01\x02\x12\n\r\n\x05\x04&\x02\x04\x05\
+    \x12\x04\xfe\x01\x02\x07\n\r\n\x05\x04&\x02\x04\x01\x12\x04\xfe\x01\x08\
+    \r\n\r\n\x05\x04&\x02\x04\x03\x12\x04\xfe\x01\x10\x11\n\x0c\n\x04\x04&\
+    \x02\x05\x12\x04\xff\x01\x02)\n\r\n\x05\x04&\x02\x05\x04\x12\x04\xff\x01\
+    \x02\n\n\r\n\x05\x04&\x02\x05\x06\x12\x04\xff\x01\x0b\x19\n\r\n\x05\x04&\
+    \x02\x05\x01\x12\x04\xff\x01\x1a$\n\r\n\x05\x04&\x02\x05\x03\x12\x04\xff\
+    \x01'(\n\x0c\n\x04\x04&\x02\x06\x12\x04\x80\x02\x02\x12\n\r\n\x05\x04&\
+    \x02\x06\x05\x12\x04\x80\x02\x02\x06\n\r\n\x05\x04&\x02\x06\x01\x12\x04\
+    \x80\x02\x07\r\n\r\n\x05\x04&\x02\x06\x03\x12\x04\x80\x02\x10\x11\n\xe3\
+    \x01\n\x04\x04&\x02\x07\x12\x04\x84\x02\x02\x13\x1a\xd4\x01\x20nonce\x20\
+    of\x20transaction.\x20If\x20`commit`\x20is\x20set,\x20it\x20should\x20be\
+    \x20equal\x20to\x20nonce\x20of\n\x20sender\x20account.\x20Nonce\x20is\
+    \x20incremented\x20inside\x20the\x20enclave\x20for\x20both\x20successful\
+    \n\x20and\x20failed\x20transactions,\x20so\x20Go\x20side\x20should\x20no\
+    t\x20increment\x20it\n\n\r\n\x05\x04&\x02\x07\x05\x12\x04\x84\x02\x02\
+    \x08\n\r\n\x05\x04&\x02\x07\x01\x12\x04\x84\x02\t\x0e\n\r\n\x05\x04&\x02\
+    \x07\x03\x12\x04\x84\x02\x11\x12\n\x91\x01\n\x04\x04&\x02\x08\x12\x04\
+    \x87\x02\x02\x14\x1a\x82\x01\x20type\x20of\x20transaction\x20used\x20for\
+    \x20receipt\x20encoding:\x200\x20-\x20legacy,\x201\x20-\x20EIP-2930,\n\
+    \x202\x20-\x20EIP-1559.\x20Transactions\x20of\x20other\x20types\x20are\
+    \x20rejected\n\n\r\n\x05\x04&\x02\x08\x05\x12\x04\x87\x02\x02\x08\n\r\n\
+    \x05\x04&\x02\x08\x01\x12\x04\x87\x02\t\x0f\n\r\n\x05\x04&\x02\x08\x03\
+    \x12\x04\x87\x02\x12\x13\nH\n\x02\x04'\x12\x06\x8b\x02\0\x99\x02\x01\x1a\
+    :\x20Message\x20with\x20data\x20required\x20to\x20execute\x20`create`\
+    \x20operation\n\n\x0b\n\x03\x04'\x01\x12\x04\x8b\x02\x08\x19\n\x0c\n\x04\
+    \x04'\x02\0\x12\x04\x8c\x02\x02\x11\n\r\n\x05\x04'\x02\0\x05\x12\x04\x8c\
+    \x02\x02\x07\n\r\n\x05\x04'\x02\0\x01\x12\x04\x8c\x02\x08\x0c\n\r\n\x05\
+    \x04'\x02\0\x03\x12\x04\x8c\x02\x0f\x10\n\x0c\n\x04\x04'\x02\x01\x12\x04\
+    \x8d\x02\x02\x11\n\r\n\x05\x04'\x02\x01\x05\x12\x04\x8d\x02\x02\x07\n\r\
+    \n\x05\x04'\x02\x01\x01\x12\x04\x8d\x02\x08\x0c\n\r\n\x05\x04'\x02\x01\
+    \x03\x12\x04\x8d\x02\x0f\x10\n\x0c\n\x04\x04'\x02\x02\x12\x04\x8e\x02\
+    \x02\x16\n\r\n\x05\x04'\x02\x02\x05\x12\x04\x8e\x02\x02\x08\n\r\n\x05\
+    \x04'\x02\x02\x01\x12\x04\x8e\x02\t\x11\n\r\n\x05\x04'\x02\x02\x03\x12\
+    \x04\x8e\x02\x14\x15\n\x0c\n\x04\x04'\x02\x03\x12\x04\x8f\x02\x02\x12\n\
+    \r\n\x05\x04'\x02\x03\x05\x12\x04\x8f\x02\x02\x07\n\r\n\x05\x04'\x02\x03\
+    \x01\x12\x04\x8f\x02\x08\r\n\r\n\x05\x04'\x02\x03\x03\x12\x04\x8f\x02\
+    \x10\x11\n\x0c\n\x04\x04'\x02\x04\x12\x04\x90\x02\x02)\n\r\n\x05\x04'\
+    \x02\x04\x04\x12\x04\x90\x02\x02\n\n\r\n\x05\x04'\x02\x04\x06\x12\x04\
+    \x90\x02\x0b\x19\n\r\n\x05\x04'\x02\x04\x01\x12\x04\x90\x02\x1a$\n\r\n\
+    \x05\x04'\x02\x04\x03\x12\x04\x90\x02'(\n\x0c\n\x04\x04'\x02\x05\x12\x04\
+    \x91\x02\x02\x12\n\r\n\x05\x04'\x02\x05\x05\x12\x04\x91\x02\x02\x06\n\r\
+    \n\x05\x04'\x02\x05\x01\x12\x04\x91\x02\x07\r\n\r\n\x05\x04'\x02\x05\x03\
+    \x12\x04\x91\x02\x10\x11\n\xe3\x01\n\x04\x04'\x02\x06\x12\x04\x95\x02\
+    \x02\x13\x1a\xd4\x01\x20nonce\x20of\x20transaction.\x20If\x20`commit`\
+    \x20is\x20set,\x20it\x20should\x20be\x20equal\x20to\x20nonce\x20of\n\x20\
+    sender\x20account.\x20Nonce\x20is\x20incremented\x20inside\x20the\x20enc\
+    lave\x20for\x20both\x20successful\n\x20and\x20failed\x20transactions,\
+    \x20so\x20Go\x20side\x20should\x20not\x20increment\x20it\n\n\r\n\x05\x04\
+    '\x02\x06\x05\x12\x04\x95\x02\x02\x08\n\r\n\x05\x04'\x02\x06\x01\x12\x04\
+    \x95\x02\t\x0e\n\r\n\x05\x04'\x02\x06\x03\x12\x04\x95\x02\x11\x12\n\x91\
+    \x01\n\x04\x04'\x02\x07\x12\x04\x98\x02\x02\x14\x1a\x82\x01\x20type\x20o\
+    f\x20transaction\x20used\x20for\x20receipt\x20encoding:\x200\x20-\x20leg\
+    acy,\x201\x20-\x20EIP-2930,\n\x202\x20-\x20EIP-1559.\x20Transactions\x20\
+    of\x20other\x20types\x20are\x20rejected\n\n\r\n\x05\x04'\x02\x07\x05\x12\
+    \x04\x98\x02\x02\x08\n\r\n\x05\x04'\x02\x07\x01\x12\x04\x98\x02\t\x0f\n\
+    \r\n\x05\x04'\x02\x07\x03\x12\x04\x98\x02\x12\x13\n\xd0\x01\n\x02\x04(\
+    \x12\x06\x9e\x02\0\xa1\x02\x01\x1a\xc1\x01\x20Request\x20to\x20execute\
+    \x20`call`\x20operation.\x20Sender\x20is\x20provided\x20by\x20the\x20hos\
+    t\x20and\x20is\x20not\n\x20verified,\x20while\x20encrypted\x20data\x20is\
+    \x20decrypted\x20anyway.\x20Only\x20signed\x20transactions\n\x20bind\x20\
+    encrypted\x20data\x20to\x20its\x20sender\n\n\x0b\n\x03\x04(\x01\x12\x04\
+    \x9e\x02\x08\x18\n\x0c\n\x04\x04(\x02\0\x12\x04\x9f\x02\x02\x1d\n\r\n\
+    \x05\x04(\x02\0\x06\x12\x04\x9f\x02\x02\x11\n\r\n\x05\x04(\x02\0\x01\x12\
+    \x04\x9f\x02\x12\x18\n\r\n\x05\x04(\x02\0\x03\x12\x04\x9f\x02\x1b\x1c\n\
+    \x0c\n\x04\x04(\x02\x01\x12\x04\xa0\x02\x02!\n\r\n\x05\x04(\x02\x01\x06\
+    \x12\x04\xa0\x02\x02\x14\n\r\n\x05\x04(\x02\x01\x01\x12\x04\xa0\x02\x15\
+    \x1c\n\r\n\x05\x04(\x02\x01\x03\x12\x04\xa0\x02\x1f\x20\n5\n\x02\x04)\
+    \x12\x06\xa4\x02\0\xa7\x02\x01\x1a'\x20Request\x20to\x20execute\x20`crea\
+    te`\x20operation\n\n\x0b\n\x03\x04)\x01\x12\x04\xa4\x02\x08\x1a\n\x0c\n\
+    \x04\x04)\x02\0\x12\x04\xa5\x02\x02\x1f\n\r\n\x05\x04)\x02\0\x06\x12\x04\
+    \xa5\x02\x02\x13\n\r\n\x05\x04)\x02\0\x01\x12\x04\xa5\x02\x14\x1a\n\r\n\
+    \x05\x04)\x02\0\x03\x12\x04\xa5\x02\x1d\x1e\n\x0c\n\x04\x04)\x02\x01\x12\
+    \x04\xa6\x02\x02!\n\r\n\x05\x04)\x02\x01\x06\x12\x04\xa6\x02\x02\x14\n\r\
+    \n\x05\x04)\x02\x01\x01\x12\x04\xa6\x02\x15\x1c\n\r\n\x05\x04)\x02\x01\
+    \x03\x12\x04\xa6\x02\x1f\x20\nD\n\x02\x04*\x12\x06\xaa\x02\0\xba\x02\x01\
+    \x1a6\x20Frame\x20of\x20internal\x20call\x20tree\x20collected\x20by\x20c\
+    all\x20tracer\n\n\x0b\n\x03\x04*\x01\x12\x04\xaa\x02\x08\x11\nY\n\x04\
+    \x04*\x02\0\x12\x04\xac\x02\x02\x12\x1aK\x20CALL,\x20STATICCALL,\x20DELE\
+    GATECALL,\x20CALLCODE,\x20CREATE,\x20CREATE2\x20or\x20SELFDESTRUCT\n\n\r\
+    \n\x05\x04*\x02\0\x05\x12\x04\xac\x02\x02\x08\n\r\n\x05\x04*\x02\0\x01\
+    \x12\x04\xac\x02\t\r\n\r\n\x05\x04*\x02\0\x03\x12\x04\xac\x02\x10\x11\n\
+    \x0c\n\x04\x04*\x02\x01\x12\x04\xad\x02\x02\x11\n\r\n\x05\x04*\x02\x01\
+    \x05\x12\x04\xad\x02\x02\x07\n\r\n\x05\x04*\x02\x01\x01\x12\x04\xad\x02\
+    \x08\x0c\n\r\n\x05\x04*\x02\x01\x03\x12\x04\xad\x02\x0f\x10\n\x0c\n\x04\
+    \x04*\x02\x02\x12\x04\xae\x02\x02\x0f\n\r\n\x05\x04*\x02\x02\x05\x12\x04\
+    \xae\x02\x02\x07\n\r\n\x05\x04*\x02\x02\x01\x12\x04\xae\x02\x08\n\n\r\n\
+    \x05\x04*\x02\x02\x03\x12\x04\xae\x02\r\x0e\n\x0c\n\x04\x04*\x02\x03\x12\
+    \x04\xaf\x02\x02\x12\n\r\n\x05\x04*\x02\x03\x05\x12\x04\xaf\x02\x02\x07\
+    \n\r\n\x05\x04*\x02\x03\x01\x12\x04\xaf\x02\x08\r\n\r\n\x05\x04*\x02\x03\
+    \x03\x12\x04\xaf\x02\x10\x11\n)\n\x04\x04*\x02\x04\x12\x04\xb1\x02\x02\
+    \x11\x1a\x1b\x20gas\x20provided\x20to\x20the\x20frame\n\n\r\n\x05\x04*\
+    \x02\x04\x05\x12\x04\xb1\x02\x02\x08\n\r\n\x05\x04*\x02\x04\x01\x12\x04\
+    \xb1\x02\t\x0c\n\r\n\x05\x04*\x02\x04\x03\x12\x04\xb1\x02\x0f\x10\n\x9a\
+    \x01\n\x04\x04*\x02\x05\x12\x04\xb4\x02\x02\x12\x1a\x8b\x01\x20call\x20i\
+    nput.\x20Encrypted\x20to\x20caller\x20key\x20for\x20encrypted\x20transac\
+    tions.\x20Empty\x20for\n\x20nested\x20calls,\x20since\x20it\x20can\x20co\
+    ntain\x20confidential\x20contract\x20state\n\n\r\n\x05\x04*\x02\x05\x05\
+    \x12\x04\xb4\x02\x02\x07\n\r\n\x05\x04*\x02\x05\x01\x12\x04\xb4\x02\x08\
+    \r\n\r\n\x05\x04*\x02\x05\x03\x12\x04\xb4\x02\x10\x11\n\x9b\x01\n\x04\
+    \x04*\x02\x06\x12\x04\xb7\x02\x02\x13\x1a\x8c\x01\x20call\x20output.\x20\
+    Encrypted\x20to\x20caller\x20key\x20for\x20encrypted\x20transactions.\
+    \x20Empty\x20for\n\x20nested\x20calls,\x20since\x20it\x20can\x20contain\
+    \x20confidential\x20contract\x20state\n\n\r\n\x05\x04*\x02\x06\x05\x12\
+    \x04\xb7\x02\x02\x07\n\r\n\x05\x04*\x02\x06\x01\x12\x04\xb7\x02\x08\x0e\
+    \n\r\n\x05\x04*\x02\x06\x03\x12\x04\xb7\x02\x11\x12\n\x0c\n\x04\x04*\x02\
+    \x07\x12\x04\xb8\x02\x02\x13\n\r\n\x05\x04*\x02\x07\x05\x12\x04\xb8\x02\
+    \x02\x08\n\r\n\x05\x04*\x02\x07\x01\x12\x04\xb8\x02\t\x0e\n\r\n\x05\x04*\
+    \x02\x07\x03\x12\x04\xb8\x02\x11\x12\n\x0c\n\x04\x04*\x02\x08\x12\x04\
+    \xb9\x02\x02\x1f\n\r\n\x05\x04*\x02\x08\x04\x12\x04\xb9\x02\x02\n\n\r\n\
+    \x05\x04*\x02\x08\x06\x12\x04\xb9\x02\x0b\x14\n\r\n\x05\x04*\x02\x08\x01\
+    \x12\x04\xb9\x02\x15\x1a\n\r\n\x05\x04*\x02\x08\x03\x12\x04\xb9\x02\x1d\
+    \x1e\n2\n\x02\x04+\x12\x06\xbd\x02\0\xc0\x02\x01\x1a$\x20Response\x20for\
+    \x20traced\x20call\x20or\x20create\n\n\x0b\n\x03\x04+\x01\x12\x04\xbd\
+    \x02\x08\x20\n\x0c\n\x04\x04+\x02\0\x12\x04\xbe\x02\x02'\n\r\n\x05\x04+\
+    \x02\0\x06\x12\x04\xbe\x02\x02\x1b\n\r\n\x05\x04+\x02\0\x01\x12\x04\xbe\
+    \x02\x1c\"\n\r\n\x05\x04+\x02\0\x03\x12\x04\xbe\x02%&\n\x0c\n\x04\x04+\
+    \x02\x01\x12\x04\xbf\x02\x02\x1a\n\r\n\x05\x04+\x02\x01\x06\x12\x04\xbf\
+    \x02\x02\x0b\n\r\n\x05\x04+\x02\x01\x01\x12\x04\xbf\x02\x0c\x15\n\r\n\
+    \x05\x04+\x02\x01\x03\x12\x04\xbf\x02\x18\x19\n\xab\x01\n\x02\x04,\x12\
+    \x06\xc4\x02\0\xc7\x02\x01\x1a\x9c\x01\x20Options\x20of\x20opcode-level\
+    \x20struct\x20logger.\x20Memory\x20and\x20storage\x20contain\x20confiden\
+    tial\n\x20contract\x20state,\x20so\x20requests\x20with\x20any\x20of\x20t\
+    hese\x20options\x20enabled\x20are\x20rejected\n\n\x0b\n\x03\x04,\x01\x12\
+    \x04\xc4\x02\x08\x1a\n\x0c\n\x04\x04,\x02\0\x12\x04\xc5\x02\x02\x18\n\r\
+    \n\x05\x04,\x02\0\x05\x12\x04\xc5\x02\x02\x06\n\r\n\x05\x04,\x02\0\x01\
+    \x12\x04\xc5\x02\x07\x13\n\r\n\x05\x04,\x02\0\x03\x12\x04\xc5\x02\x16\
+    \x17\n\x0c\n\x04\x04,\x02\x01\x12\x04\xc6\x02\x02\x19\n\r\n\x05\x04,\x02\
+    \x01\x05\x12\x04\xc6\x02\x02\x06\n\r\n\x05\x04,\x02\x01\x01\x12\x04\xc6\
+    \x02\x07\x14\n\r\n\x05\x04,\x02\x01\x03\x12\x04\xc6\x02\x17\x18\n\x8f\
+    \x02\n\x02\x04-\x12\x06\xcd\x02\0\xd1\x02\x01\x1a\x80\x02\x20Request\x20\
+    to\x20execute\x20call\x20with\x20attached\x20struct\x20logger.\x20Struct\
+    \x20logs\x20are\n\x20returned\x20only\x20for\x20non-commit\x20calls\x20w\
+    ith\x20encrypted\x20data.\x20Each\x20struct\x20log\n\x20contains\x20only\
+    \x20opcode,\x20gas\x20and\x20depth\x20of\x20the\x20step,\x20stack\x20is\
+    \x20never\x20included.\n\x20Amount\x20of\x20collected\x20steps\x20is\x20\
+    limited\n\n\x0b\n\x03\x04-\x01\x12\x04\xcd\x02\x08\x1d\n\x0c\n\x04\x04-\
+    \x02\0\x12\x04\xce\x02\x02\x1d\n\r\n\x05\x04-\x02\0\x06\x12\x04\xce\x02\
+    \x02\x11\n\r\n\x05\x04-\x02\0\x01\x12\x04\xce\x02\x12\x18\n\r\n\x05\x04-\
+    \x02\0\x03\x12\x04\xce\x02\x1b\x1c\n\x0c\n\x04\x04-\x02\x01\x12\x04\xcf\
+    \x02\x02!\n\r\n\x05\x04-\x02\x01\x06\x12\x04\xcf\x02\x02\x14\n\r\n\x05\
+    \x04-\x02\x01\x01\x12\x04\xcf\x02\x15\x1c\n\r\n\x05\x04-\x02\x01\x03\x12\
+    \x04\xcf\x02\x1f\x20\n\x0c\n\x04\x04-\x02\x02\x12\x04\xd0\x02\x02\x20\n\
+    \r\n\x05\x04-\x02\x02\x06\x12\x04\xd0\x02\x02\x14\n\r\n\x05\x04-\x02\x02\
+    \x01\x12\x04\xd0\x02\x15\x1b\n\r\n\x05\x04-\x02\x02\x03\x12\x04\xd0\x02\
+    \x1e\x1f\n\x0c\n\x02\x04.\x12\x06\xd3\x02\0\xd6\x02\x01\n\x0b\n\x03\x04.\
+    \x01\x12\x04\xd3\x02\x08\x14\n\x0c\n\x04\x04.\x02\0\x12\x04\xd4\x02\x02\
+    \x10\n\r\n\x05\x04.\x02\0\x05\x12\x04\xd4\x02\x02\x07\n\r\n\x05\x04.\x02\
+    \0\x01\x12\x04\xd4\x02\x08\x0b\n\r\n\x05\x04.\x02\0\x03\x12\x04\xd4\x02\
+    \x0e\x0f\n\x0c\n\x04\x04.\x02\x01\x12\x04\xd5\x02\x02\x12\n\r\n\x05\x04.\
+    \x02\x01\x05\x12\x04\xd5\x02\x02\x07\n\r\n\x05\x04.\x02\x01\x01\x12\x04\
+    \xd5\x02\x08\r\n\r\n\x05\x04.\x02\x01\x03\x12\x04\xd5\x02\x10\x11\n,\n\
+    \x02\x04/\x12\x06\xd9\x02\0\xe3\x02\x01\x1a\x1e\x20Single\x20step\x20of\
+    \x20evm\x20execution\n\n\x0b\n\x03\x04/\x01\x12\x04\xd9\x02\x08\x11\n\
+    \x0c\n\x04\x04/\x02\0\x12\x04\xda\x02\x02\x10\n\r\n\x05\x04/\x02\0\x05\
+    \x12\x04\xda\x02\x02\x08\n\r\n\x05\x04/\x02\0\x01\x12\x04\xda\x02\t\x0b\
+    \n\r\n\x05\x04/\x02\0\x03\x12\x04\xda\x02\x0e\x0f\n\x0c\n\x04\x04/\x02\
+    \x01\x12\x04\xdb\x02\x02\x10\n\r\n\x05\x04/\x02\x01\x05\x12\x04\xdb\x02\
+    \x02\x08\n\r\n\x05\x04/\x02\x01\x01\x12\x04\xdb\x02\t\x0b\n\r\n\x05\x04/\
+    \x02\x01\x03\x12\x04\xdb\x02\x0e\x0f\n\x0c\n\x04\x04/\x02\x02\x12\x04\
+    \xdc\x02\x02\x11\n\r\n\x05\x04/\x02\x02\x05\x12\x04\xdc\x02\x02\x08\n\r\
+    \n\x05\x04/\x02\x02\x01\x12\x04\xdc\x02\t\x0c\n\r\n\x05\x04/\x02\x02\x03\
+    \x12\x04\xdc\x02\x0f\x10\n\x0c\n\x04\x04/\x02\x03\x12\x04\xdd\x02\x02\
+    \x15\n\r\n\x05\x04/\x02\x03\x05\x12\x04\xdd\x02\x02\x08\n\r\n\x05\x04/\
+    \x02\x03\x01\x12\x04\xdd\x02\t\x10\n\r\n\x05\x04/\x02\x03\x03\x12\x04\
+    \xdd\x02\x13\x14\n\x0c\n\x04\x04/\x02\x04\x12\x04\xde\x02\x02\x13\n\r\n\
+    \x05\x04/\x02\x04\x05\x12\x04\xde\x02\x02\x08\n\r\n\x05\x04/\x02\x04\x01\
+    \x12\x04\xde\x02\t\x0e\n\r\n\x05\x04/\x02\x04\x03\x12\x04\xde\x02\x11\
+    \x12\n\x0c\n\x04\x04/\x02\x05\x12\x04\xdf\x02\x02\x1b\n\r\n\x05\x04/\x02\
+    \x05\x04\x12\x04\xdf\x02\x02\n\n\r\n\x05\x04/\x02\x05\x05\x12\x04\xdf\
+    \x02\x0b\x10\n\r\n\x05\x04/\x02\x05\x01\x12\x04\xdf\x02\x11\x16\n\r\n\
+    \x05\x04/\x02\x05\x03\x12\x04\xdf\x02\x19\x1a\n\x0c\n\x04\x04/\x02\x06\
+    \x12\x04\xe0\x02\x02\x13\n\r\n\x05\x04/\x02\x06\x05\x12\x04\xe0\x02\x02\
+    \x07\n\r\n\x05\x04/\x02\x06\x01\x12\x04\xe0\x02\x08\x0e\n\r\n\x05\x04/\
+    \x02\x06\x03\x12\x04\xe0\x02\x11\x12\n\x0c\n\x04\x04/\x02\x07\x12\x04\
+    \xe1\x02\x02$\n\r\n\x05\x04/\x02\x07\x04\x12\x04\xe1\x02\x02\n\n\r\n\x05\
+    \x04/\x02\x07\x06\x12\x04\xe1\x02\x0b\x17\n\r\n\x05\x04/\x02\x07\x01\x12\
+    \x04\xe1\x02\x18\x1f\n\r\n\x05\x04/\x02\x07\x03\x12\x04\xe1\x02\"#\n\x0c\
+    \n\x04\x04/\x02\x08\x12\x04\xe2\x02\x02\x13\n\r\n\x05\x04/\x02\x08\x05\
+    \x12\x04\xe2\x02\x02\x08\n\r\n\x05\x04/\x02\x08\x01\x12\x04\xe2\x02\t\
+    \x0e\n\r\n\x05\x04/\x02\x08\x03\x12\x04\xe2\x02\x11\x12\n\n\n\x02\x040\
+    \x12\x04\xe5\x02\03\n\x0b\n\x03\x040\x01\x12\x04\xe5\x02\x08\x12\n\x0c\n\
+    \x04\x040\x02\0\x12\x04\xe5\x02\x151\n\r\n\x05\x040\x02\0\x04\x12\x04\
+    \xe5\x02\x15\x1d\n\r\n\x05\x040\x02\0\x06\x12\x04\xe5\x02\x1e'\n\r\n\x05\
+    \x040\x02\0\x01\x12\x04\xe5\x02(,\n\r\n\x05\x040\x02\0\x03\x12\x04\xe5\
+    \x02/0\n=\n\x02\x041\x12\x06\xe8\x02\0\xec\x02\x01\x1a/\x20Response\x20f\
+    or\x20call\x20with\x20attached\x20struct\x20logger\n\n\x0b\n\x03\x041\
+    \x01\x12\x04\xe8\x02\x08\x19\n\x0c\n\x04\x041\x02\0\x12\x04\xe9\x02\x02'\
+    \n\r\n\x05\x041\x02\0\x06\x12\x04\xe9\x02\x02\x1b\n\r\n\x05\x041\x02\0\
+    \x01\x12\x04\xe9\x02\x1c\"\n\r\n\x05\x041\x02\0\x03\x12\x04\xe9\x02%&\nE\
+    \n\x04\x041\x02\x01\x12\x04\xeb\x02\x02\x17\x1a7\x20protobuf-encoded\x20\
+    `StructLogs`\x20encrypted\x20to\x20caller\x20key\n\n\r\n\x05\x041\x02\
+    \x01\x05\x12\x04\xeb\x02\x02\x07\n\r\n\x05\x041\x02\x01\x01\x12\x04\xeb\
+    \x02\x08\x12\n\r\n\x05\x041\x02\x01\x03\x12\x04\xeb\x02\x15\x16\n\x94\
+    \x01\n\x02\x042\x12\x06\xf0\x02\0\xf3\x02\x01\x1a\x85\x01\x20Request\x20\
+    to\x20estimate\x20minimal\x20gas\x20limit\x20of\x20the\x20transaction.\
+    \x20If\x20`to`\x20field\n\x20of\x20params\x20is\x20empty,\x20gas\x20is\
+    \x20estimated\x20for\x20contract\x20creation\n\n\x0b\n\x03\x042\x01\x12\
+    \x04\xf0\x02\x08\x1f\n\x0c\n\x04\x042\x02\0\x12\x04\xf1\x02\x02\x1d\n\r\
+    \n\x05\x042\x02\0\x06\x12\x04\xf1\x02\x02\x11\n\r\n\x05\x042\x02\0\x01\
+    \x12\x04\xf1\x02\x12\x18\n\r\n\x05\x042\x02\0\x03\x12\x04\xf1\x02\x1b\
+    \x1c\n\x0c\n\x04\x042\x02\x01\x12\x04\xf2\x02\x02!\n\r\n\x05\x042\x02\
+    \x01\x06\x12\x04\xf2\x02\x02\x14\n\r\n\x05\x042\x02\x01\x01\x12\x04\xf2\
+    \x02\x15\x1c\n\r\n\x05\x042\x02\x01\x03\x12\x04\xf2\x02\x1f\x20\n\x0c\n\
+    \x02\x043\x12\x06\xf5\x02\0\xfd\x02\x01\n\x0b\n\x03\x043\x01\x12\x04\xf5\
+    \x02\x08\x1b\n}\n\x04\x043\x02\0\x12\x04\xf8\x02\x02\x11\x1ao\x20minimal\
+    \x20gas\x20limit\x20with\x20which\x20transaction\x20succeeds.\x20Zero\
+    \x20if\x20transaction\n\x20fails\x20even\x20with\x20the\x20highest\x20ga\
+    s\x20limit\n\n\r\n\x05\x043\x02\0\x05\x12\x04\xf8\x02\x02\x08\n\r\n\x05\
+    \x043\x02\0\x01\x12\x04\xf8\x02\t\x0c\n\r\n\x05\x043\x02\0\x03\x12\x04\
+    \xf8\x02\x0f\x10\nM\n\x04\x043\x02\x01\x12\x04\xfa\x02\x02\x16\x1a?\x20e\
+    rror\x20returned\x20by\x20execution\x20with\x20the\x20highest\x20failed\
+    \x20gas\x20limit\n\n\r\n\x05\x043\x02\x01\x05\x12\x04\xfa\x02\x02\x08\n\
+    \r\n\x05\x043\x02\x01\x01\x12\x04\xfa\x02\t\x11\n\r\n\x05\x043\x02\x01\
+    \x03\x12\x04\xfa\x02\x14\x15\nL\n\x04\x043\x02\x02\x12\x04\xfc\x02\x02\
+    \x10\x1a>\x20data\x20returned\x20by\x20execution\x20with\x20the\x20highe\
+    st\x20failed\x20gas\x20limit\n\n\r\n\x05\x043\x02\x02\x05\x12\x04\xfc\
+    \x02\x02\x07\n\r\n\x05\x043\x02\x02\x01\x12\x04\xfc\x02\x08\x0b\n\r\n\
+    \x05\x043\x02\x02\x03\x12\x04\xfc\x02\x0e\x0f\n\x0c\n\x02\x044\x12\x06\
+    \xff\x02\0\x83\x03\x01\n\x0b\n\x03\x044\x01\x12\x04\xff\x02\x08\x1d\n@\n\
+    \x04\x044\x02\0\x12\x04\x81\x03\x02\x1d\x1a2\x20if\x20`to`\x20is\x20empt\
+    y,\x20contract\x20creation\x20is\x20simulated\n\n\r\n\x05\x044\x02\0\x06\
+    \x12\x04\x81\x03\x02\x11\n\r\n\x05\x044\x02\0\x01\x12\x04\x81\x03\x12\
+    \x18\n\r\n\x05\x044\x02\0\x03\x12\x04\x81\x03\x1b\x1c\n\x0c\n\x04\x044\
+    \x02\x01\x12\x04\x82\x03\x02!\n\r\n\x05\x044\x02\x01\x06\x12\x04\x82\x03\
+    \x02\x14\n\r\n\x05\x044\x02\x01\x01\x12\x04\x82\x03\x15\x1c\n\r\n\x05\
+    \x044\x02\x01\x03\x12\x04\x82\x03\x1f\x20\n\x0c\n\x02\x045\x12\x06\x85\
+    \x03\0\x8a\x03\x01\n\x0b\n\x03\x045\x01\x12\x04\x85\x03\x08\x13\n\x0c\n\
+    \x04\x045\x02\0\x12\x04\x86\x03\x02\x10\n\r\n\x05\x045\x02\0\x05\x12\x04\
+    \x86\x03\x02\x07\n\r\n\x05\x045\x02\0\x01\x12\x04\x86\x03\x08\x0b\n\r\n\
+    \x05\x045\x02\0\x03\x12\x04\x86\x03\x0e\x0f\nY\n\x04\x045\x02\x01\x12\
+    \x04\x88\x03\x02\x1a\x1aK\x20storage\x20values\x20are\x20always\x20omitt\
+    ed,\x20since\x20contract\x20storage\x20is\x20confidential\n\n\r\n\x05\
+    \x045\x02\x01\x05\x12\x04\x88\x03\x02\x07\n\r\n\x05\x045\x02\x01\x01\x12\
+    \x04\x88\x03\x08\x15\n\r\n\x05\x045\x02\x01\x03\x12\x04\x88\x03\x18\x19\
+    \n\x0c\n\x04\x045\x02\x02\x12\x04\x89\x03\x02\x12\n\r\n\x05\x045\x02\x02\
+    \x05\x12\x04\x89\x03\x02\x07\n\r\n\x05\x045\x02\x02\x01\x12\x04\x89\x03\
+    \x08\r\n\r\n\x05\x045\x02\x02\x03\x12\x04\x89\x03\x10\x11\n\x0c\n\x02\
+    \x046\x12\x06\x8c\x03\0\x96\x03\x01\n\x0b\n\x03\x046\x01\x12\x04\x8c\x03\
+    \x08\x13\n\x0c\n\x04\x046\x02\0\x12\x04\x8d\x03\x02\x14\n\r\n\x05\x046\
+    \x02\0\x05\x12\x04\x8d\x03\x02\x07\n\r\n\x05\x046\x02\0\x01\x12\x04\x8d\
+    \x03\x08\x0f\n\r\n\x05\x046\x02\0\x03\x12\x04\x8d\x03\x12\x13\n\x0c\n\
+    \x04\x046\x02\x01\x12\x04\x8e\x03\x02\x1a\n\r\n\x05\x046\x02\x01\x05\x12\
+    \x04\x8e\x03\x02\x07\n\r\n\x05\x046\x02\x01\x01\x12\x04\x8e\x03\x08\x15\
+    \n\r\n\x05\x046\x02\x01\x03\x12\x04\x8e\x03\x18\x19\n\x0c\n\x04\x046\x02\
+    \x02\x12\x04\x8f\x03\x02\x19\n\r\n\x05\x046\x02\x02\x05\x12\x04\x8f\x03\
+    \x02\x07\n\r\n\x05\x046\x02\x02\x01\x12\x04\x8f\x03\x08\x14\n\r\n\x05\
+    \x046\x02\x02\x03\x12\x04\x8f\x03\x17\x18\n\x0c\n\x04\x046\x02\x03\x12\
+    \x04\x90\x03\x02\x19\n\r\n\x05\x046\x02\x03\x05\x12\x04\x90\x03\x02\x08\
+    \n\r\n\x05\x046\x02\x03\x01\x12\x04\x90\x03\t\x14\n\r\n\x05\x046\x02\x03\
+    \x03\x12\x04\x90\x03\x17\x18\n\x0c\n\x04\x046\x02\x04\x12\x04\x91\x03\
+    \x02\x18\n\r\n\x05\x046\x02\x04\x05\x12\x04\x91\x03\x02\x08\n\r\n\x05\
+    \x046\x02\x04\x01\x12\x04\x91\x03\t\x13\n\r\n\x05\x046\x02\x04\x03\x12\
+    \x04\x91\x03\x16\x17\nD\n\x04\x046\x02\x05\x12\x04\x93\x03\x02\x11\x1a6\
+    \x20new\x20contract\x20bytecode,\x20empty\x20if\x20code\x20was\x20not\
+    \x20changed\n\n\r\n\x05\x046\x02\x05\x05\x12\x04\x93\x03\x02\x07\n\r\n\
+    \x05\x046\x02\x05\x01\x12\x04\x93\x03\x08\x0c\n\r\n\x05\x046\x02\x05\x03\
+    \x12\x04\x93\x03\x0f\x10\n\x0c\n\x04\x046\x02\x06\x12\x04\x94\x03\x02\
+    \x13\n\r\n\x05\x046\x02\x06\x05\x12\x04\x94\x03\x02\x06\n\r\n\x05\x046\
+    \x02\x06\x01\x12\x04\x94\x03\x07\x0e\n\r\n\x05\x046\x02\x06\x03\x12\x04\
+    \x94\x03\x11\x12\n\x0c\n\x04\x046\x02\x07\x12\x04\x95\x03\x02#\n\r\n\x05\
+    \x046\x02\x07\x04\x12\x04\x95\x03\x02\n\n\r\n\x05\x046\x02\x07\x06\x12\
+    \x04\x95\x03\x0b\x16\n\r\n\x05\x046\x02\x07\x01\x12\x04\x95\x03\x17\x1e\
+    \n\r\n\x05\x046\x02\x07\x03\x12\x04\x95\x03!\"\n\x0c\n\x02\x047\x12\x06\
+    \x98\x03\0\x9b\x03\x01\n\x0b\n\x03\x047\x01\x12\x04\x98\x03\x08\x19\n\
+    \x0c\n\x04\x047\x02\0\x12\x04\x99\x03\x02'\n\r\n\x05\x047\x02\0\x06\x12\
+    \x04\x99\x03\x02\x1b\n\r\n\x05\x047\x02\0\x01\x12\x04\x99\x03\x1c\"\n\r\
+    \n\x05\x047\x02\0\x03\x12\x04\x99\x03%&\n\x0c\n\x04\x047\x02\x01\x12\x04\
+    \x9a\x03\x02$\n\r\n\x05\x047\x02\x01\x04\x12\x04\x9a\x03\x02\n\n\r\n\x05\
+    \x047\x02\x01\x06\x12\x04\x9a\x03\x0b\x16\n\r\n\x05\x047\x02\x01\x01\x12\
+    \x04\x9a\x03\x17\x1f\n\r\n\x05\x047\x02\x01\x03\x12\x04\x9a\x03\"#\n\x0c\
+    \n\x02\x048\x12\x06\x9d\x03\0\xa1\x03\x01\n\x0b\n\x03\x048\x01\x12\x04\
+    \x9d\x03\x08$\nP\n\x04\x048\x02\0\x12\x04\x9f\x03\x02\x1d\x1aB\x20if\x20\
+    `to`\x20is\x20empty,\x20access\x20list\x20is\x20generated\x20for\x20cont\
+    ract\x20creation\n\n\r\n\x05\x048\x02\0\x06\x12\x04\x9f\x03\x02\x11\n\r\
+    \n\x05\x048\x02\0\x01\x12\x04\x9f\x03\x12\x18\n\r\n\x05\x048\x02\0\x03\
+    \x12\x04\x9f\x03\x1b\x1c\n\x0c\n\x04\x048\x02\x01\x12\x04\xa0\x03\x02!\n\
+    \r\n\x05\x048\x02\x01\x06\x12\x04\xa0\x03\x02\x14\n\r\n\x05\x048\x02\x01\
+    \x01\x12\x04\xa0\x03\x15\x1c\n\r\n\x05\x048\x02\x01\x03\x12\x04\xa0\x03\
+    \x1f\x20\n\x0c\n\x02\x049\x12\x06\xa3\x03\0\xa7\x03\x01\n\x0b\n\x03\x049\
+    \x01\x12\x04\xa3\x03\x08\x20\nF\n\x04\x049\x02\0\x12\x04\xa5\x03\x02'\
+    \x1a8\x20result\x20of\x20execution\x20with\x20generated\x20access\x20lis\
+    t\x20applied\n\n\r\n\x05\x049\x02\0\x06\x12\x04\xa5\x03\x02\x1b\n\r\n\
+    \x05\x049\x02\0\x01\x12\x04\xa5\x03\x1c\"\n\r\n\x05\x049\x02\0\x03\x12\
+    \x04\xa5\x03%&\n\x0c\n\x04\x049\x02\x01\x12\x04\xa6\x03\x02)\n\r\n\x05\
+    \x049\x02\x01\x04\x12\x04\xa6\x03\x02\n\n\r\n\x05\x049\x02\x01\x06\x12\
+    \x04\xa6\x03\x0b\x19\n\r\n\x05\x049\x02\x01\x01\x12\x04\xa6\x03\x1a$\n\r\
+    \n\x05\x049\x02\x01\x03\x12\x04\xa6\x03'(\n7\n\x02\x04:\x12\x06\xaa\x03\
+    \0\xaf\x03\x01\x1a)\x20Transaction\x20executed\x20as\x20part\x20of\x20a\
+    \x20batch\n\n\x0b\n\x03\x04:\x01\x12\x04\xaa\x03\x08\x1d\n\x0e\n\x04\x04\
+    :\x08\0\x12\x06\xab\x03\x02\xae\x03\x03\n\r\n\x05\x04:\x08\0\x01\x12\x04\
+    \xab\x03\x08\n\n\x0c\n\x04\x04:\x02\0\x12\x04\xac\x03\x04\x1d\n\r\n\x05\
+    \x04:\x02\0\x06\x12\x04\xac\x03\x04\x13\n\r\n\x05\x04:\x02\0\x01\x12\x04\
+    \xac\x03\x14\x18\n\r\n\x05\x04:\x02\0\x03\x12\x04\xac\x03\x1b\x1c\n\x0c\
+    \n\x04\x04:\x02\x01\x12\x04\xad\x03\x04!\n\r\n\x05\x04:\x02\x01\x06\x12\
+    \x04\xad\x03\x04\x15\n\r\n\x05\x04:\x02\x01\x01\x12\x04\xad\x03\x16\x1c\
+    \n\r\n\x05\x04:\x02\x01\x03\x12\x04\xad\x03\x1f\x20\n\xad\x01\n\x02\x04;\
+    \x12\x06\xb4\x03\0\xb7\x03\x01\x1a\x9e\x01\x20Request\x20to\x20execute\
+    \x20ordered\x20list\x20of\x20transactions\x20against\x20the\x20same\x20b\
+    lock\n\x20context.\x20State\x20changes\x20are\x20written\x20to\x20the\
+    \x20keeper\x20once\x20all\x20transactions\x20are\n\x20executed\n\n\x0b\n\
+    \x03\x04;\x01\x12\x04\xb4\x03\x08\x19\n\x0c\n\x04\x04;\x02\0\x12\x04\xb5\
+    \x03\x022\n\r\n\x05\x04;\x02\0\x04\x12\x04\xb5\x03\x02\n\n\r\n\x05\x04;\
+    \x02\0\x06\x12\x04\xb5\x03\x0b\x20\n\r\n\x05\x04;\x02\0\x01\x12\x04\xb5\
+    \x03!-\n\r\n\x05\x04;\x02\0\x03\x12\x04\xb5\x0301\n\x0c\n\x04\x04;\x02\
+    \x01\x12\x04\xb6\x03\x02!\n\r\n\x05\x04;\x02\x01\x06\x12\x04\xb6\x03\x02\
+    \x14\n\r\n\x05\x04;\x02\x01\x01\x12\x04\xb6\x03\x15\x1c\n\r\n\x05\x04;\
+    \x02\x01\x03\x12\x04\xb6\x03\x1f\x20\n\x0c\n\x02\x04<\x12\x06\xb9\x03\0\
+    \xbd\x03\x01\n\x0b\n\x03\x04<\x01\x12\x04\xb9\x03\x08\x1e\n\x0c\n\x04\
+    \x04<\x02\0\x12\x04\xba\x03\x02'\n\r\n\x05\x04<\x02\0\x06\x12\x04\xba\
+    \x03\x02\x1b\n\r\n\x05\x04<\x02\0\x01\x12\x04\xba\x03\x1c\"\n\r\n\x05\
+    \x04<\x02\0\x03\x12\x04\xba\x03%&\nK\n\x04\x04<\x02\x01\x12\x04\xbc\x03\
+    \x02\x1f\x1a=\x20gas\x20used\x20by\x20this\x20and\x20all\x20previous\x20\
+    transactions\x20of\x20the\x20block\n\n\r\n\x05\x04<\x02\x01\x05\x12\x04\
+    \xbc\x03\x02\x08\n\r\n\x05\x04<\x02\x01\x01\x12\x04\xbc\x03\t\x1a\n\r\n\
+    \x05\x04<\x02\x01\x03\x12\x04\xbc\x03\x1d\x1e\n\n\n\x02\x04=\x12\x04\xbf\
+    \x03\0F\n\x0b\n\x03\x04=\x01\x12\x04\xbf\x03\x08\x15\n\x0c\n\x04\x04=\
+    \x02\0\x12\x04\xbf\x03\x18D\n\r\n\x05\x04=\x02\0\x04\x12\x04\xbf\x03\x18\
+    \x20\n\r\n\x05\x04=\x02\0\x06\x12\x04\xbf\x03!7\n\r\n\x05\x04=\x02\0\x01\
+    \x12\x04\xbf\x038?\n\r\n\x05\x04=\x02\0\x03\x12\x04\xbf\x03BC\n\x90\x02\
+    \n\x02\x04>\x12\x06\xc5\x03\0\xca\x03\x01\x1a\x81\x02\x20Request\x20to\
+    \x20execute\x20signed\x20Ethereum\x20transaction.\x20Sender\x20is\x20rec\
+    overed\x20from\n\x20transaction\x20signature\x20inside\x20the\x20enclave\
+    ,\x20transaction\x20data\x20is\x20decrypted\x20after\n\x20signature\x20v\
+    erification.\x20Chain\x20id\x20of\x20context\x20should\x20match\x20chain\
+    \x20id\x20pinned\x20in\n\x20the\x20enclave\x20at\x20build\x20time\n\n\
+    \x0b\n\x03\x04>\x01\x12\x04\xc5\x03\x08%\nK\n\x04\x04>\x02\0\x12\x04\xc7\
+    \x03\x02\x1b\x1a=\x20RLP-encoded\x20signed\x20legacy,\x20EIP-2930\x20or\
+    \x20EIP-1559\x20transaction\n\n\r\n\x05\x04>\x02\0\x05\x12\x04\xc7\x03\
+    \x02\x07\n\r\n\x05\x04>\x02\0\x01\x12\x04\xc7\x03\x08\x16\n\r\n\x05\x04>\
+    \x02\0\x03\x12\x04\xc7\x03\x19\x1a\n\x0c\n\x04\x04>\x02\x01\x12\x04\xc8\
+    \x03\x02!\n\r\n\x05\x04>\x02\x01\x06\x12\x04\xc8\x03\x02\x14\n\r\n\x05\
+    \x04>\x02\x01\x01\x12\x04\xc8\x03\x15\x1c\n\r\n\x05\x04>\x02\x01\x03\x12\
+    \x04\xc8\x03\x1f\x20\n\x0c\n\x04\x04>\x02\x02\x12\x04\xc9\x03\x02\x12\n\
+    \r\n\x05\x04>\x02\x02\x05\x12\x04\xc9\x03\x02\x06\n\r\n\x05\x04>\x02\x02\
+    \x01\x12\x04\xc9\x03\x07\r\n\r\n\x05\x04>\x02\x02\x03\x12\x04\xc9\x03\
+    \x10\x11\n\x0c\n\x02\x04?\x12\x06\xcc\x03\0\xd2\x03\x01\n\x0b\n\x03\x04?\
+    \x01\x12\x04\xcc\x03\x08!\n\x0c\n\x04\x04?\x02\0\x12\x04\xcd\x03\x02'\n\
+    \r\n\x05\x04?\x02\0\x06\x12\x04\xcd\x03\x02\x1b\n\r\n\x05\x04?\x02\0\x01\
+    \x12\x04\xcd\x03\x1c\"\n\r\n\x05\x04?\x02\0\x03\x12\x04\xcd\x03%&\n;\n\
+    \x04\x04?\x02\x01\x12\x04\xcf\x03\x02\x11\x1a-\x20sender\x20recovered\
+    \x20from\x20transaction\x20signature\n\n\r\n\x05\x04?\x02\x01\x05\x12\
+    \x04\xcf\x03\x02\x07\n\r\n\x05\x04?\x02\x01\x01\x12\x04\xcf\x03\x08\x0c\
+    \n\r\n\x05\x04?\x02\x01\x03\x12\x04\xcf\x03\x0f\x10\n*\n\x04\x04?\x02\
+    \x02\x12\x04\xd1\x03\x02\x11\x1a\x1c\x20hash\x20of\x20signed\x20transact\
+    ion\n\n\r\n\x05\x04?\x02\x02\x05\x12\x04\xd1\x03\x02\x07\n\r\n\x05\x04?\
+    \x02\x02\x01\x12\x04\xd1\x03\x08\x0c\n\r\n\x05\x04?\x02\x02\x03\x12\x04\
+    \xd1\x03\x0f\x10\n/\n\x02\x04@\x12\x04\xd5\x03\0\x1f\x1a#\x20Request\x20\
+    to\x20obtain\x20node\x20public\x20key\n\n\x0b\n\x03\x04@\x01\x12\x04\xd5\
+    \x03\x08\x1c\n+\n\x02\x04A\x12\x04\xd8\x03\06\x1a\x1f\x20Response\x20wit\
+    h\x20node\x20public\x20key\n\n\x0b\n\x03\x04A\x01\x12\x04\xd8\x03\x08\
+    \x1d\n\x0c\n\x04\x04A\x02\0\x12\x04\xd8\x03\x204\n\r\n\x05\x04A\x02\0\
+    \x05\x12\x04\xd8\x03\x20%\n\r\n\x05\x04A\x02\0\x01\x12\x04\xd8\x03&/\n\r\
+    \n\x05\x04A\x02\0\x03\x12\x04\xd8\x0323\nQ\n\x02\x04B\x12\x06\xdb\x03\0\
+    \xe3\x03\x01\x1aC\x20Overrides\x20parameters\x20of\x20precompile\x20regi\
+    stered\x20at\x20provided\x20address\n\n\x0b\n\x03\x04B\x01\x12\x04\xdb\
+    \x03\x08\x1a\n\x0c\n\x04\x04B\x02\0\x12\x04\xdc\x03\x02\x14\n\r\n\x05\
+    \x04B\x02\0\x05\x12\x04\xdc\x03\x02\x07\n\r\n\x05\x04B\x02\0\x01\x12\x04\
+    \xdc\x03\x08\x0f\n\r\n\x05\x04B\x02\0\x03\x12\x04\xdc\x03\x12\x13\nH\n\
+    \x04\x04B\x02\x01\x12\x04\xde\x03\x02\x1e\x1a:\x20block\x20number\x20sta\
+    rting\x20from\x20which\x20precompile\x20is\x20available\n\n\r\n\x05\x04B\
+    \x02\x01\x05\x12\x04\xde\x03\x02\x08\n\r\n\x05\x04B\x02\x01\x01\x12\x04\
+    \xde\x03\t\x19\n\r\n\x05\x04B\x02\x01\x03\x12\x04\xde\x03\x1c\x1d\nU\n\
+    \x04\x04B\x02\x02\x12\x04\xe0\x03\x02\x19\x1aG\x20fixed\x20gas\x20cost\
+    \x20of\x20precompile.\x20If\x200,\x20own\x20cost\x20of\x20precompile\x20\
+    is\x20charged\n\n\r\n\x05\x04B\x02\x02\x05\x12\x04\xe0\x03\x02\x08\n\r\n\
+    \x05\x04B\x02\x02\x01\x12\x04\xe0\x03\t\x14\n\r\n\x05\x04B\x02\x02\x03\
+    \x12\x04\xe0\x03\x17\x18\nH\n\x04\x04B\x02\x03\x12\x04\xe2\x03\x02\x14\
+    \x1a:\x20removes\x20precompile\x20from\x20the\x20set\x20of\x20available\
+    \x20precompiles\n\n\r\n\x05\x04B\x02\x03\x05\x12\x04\xe2\x03\x02\x06\n\r\
+    \n\x05\x04B\x02\x03\x01\x12\x04\xe2\x03\x07\x0f\n\r\n\x05\x04B\x02\x03\
+    \x03\x12\x04\xe2\x03\x12\x13\n\xd6\x01\n\x02\x04C\x12\x04\xe8\x03\0R\x1a\
+    \xc9\x01\x20Request\x20to\x20configure\x20precompiles.\x20Should\x20be\
+    \x20sent\x20once\x20on\x20node\x20start,\x20before\n\x20any\x20transacti\
+    on\x20is\x20executed.\x20Configuration\x20affects\x20results\x20of\x20tr\
+    ansactions,\n\x20therefore\x20it\x20should\x20be\x20the\x20same\x20on\
+    \x20every\x20node\n\n\x0b\n\x03\x04C\x01\x12\x04\xe8\x03\x08#\n\x0c\n\
+    \x04\x04C\x02\0\x12\x04\xe8\x03&P\n\r\n\x05\x04C\x02\0\x04\x12\x04\xe8\
+    \x03&.\n\r\n\x05\x04C\x02\0\x06\x12\x04\xe8\x03/A\n\r\n\x05\x04C\x02\0\
+    \x01\x12\x04\xe8\x03BK\n\r\n\x05\x04C\x02\0\x03\x12\x04\xe8\x03NO\n\x0c\
+    \n\x02\x04D\x12\x06\xea\x03\0\xed\x03\x01\n\x0b\n\x03\x04D\x01\x12\x04\
+    \xea\x03\x08$\n2\n\x04\x04D\x02\0\x12\x04\xec\x03\x02\x13\x1a$\x20empty\
+    \x20if\x20configuration\x20was\x20applied\n\n\r\n\x05\x04D\x02\0\x05\x12\
+    \x04\xec\x03\x02\x08\n\r\n\x05\x04D\x02\0\x01\x12\x04\xec\x03\t\x0e\n\r\
+    \n\x05\x04D\x02\0\x03\x12\x04\xec\x03\x11\x12\n\x0c\n\x02\x04E\x12\x06\
+    \xef\x03\0\xfe\x03\x01\n\x0b\n\x03\x04E\x01\x12\x04\xef\x03\x08\x12\n\
+    \x0e\n\x04\x04E\x08\0\x12\x06\xf0\x03\x02\xfd\x03\x03\n\r\n\x05\x04E\x08\
+    \0\x01\x12\x04\xf0\x03\x08\x0b\n\x0c\n\x04\x04E\x02\0\x12\x04\xf1\x03\
+    \x04%\n\r\n\x05\x04E\x02\0\x06\x12\x04\xf1\x03\x04\x14\n\r\n\x05\x04E\
+    \x02\0\x01\x12\x04\xf1\x03\x15\x20\n\r\n\x05\x04E\x02\0\x03\x12\x04\xf1\
+    \x03#$\n\x0c\n\x04\x04E\x02\x01\x12\x04\xf2\x03\x04)\n\r\n\x05\x04E\x02\
+    \x01\x06\x12\x04\xf2\x03\x04\x16\n\r\n\x05\x04E\x02\x01\x01\x12\x04\xf2\
+    \x03\x17$\n\r\n\x05\x04E\x02\x01\x03\x12\x04\xf2\x03'(\n\x0c\n\x04\x04E\
+    \x02\x02\x12\x04\xf3\x03\x04.\n\r\n\x05\x04E\x02\x02\x06\x12\x04\xf3\x03\
+    \x04\x18\n\r\n\x05\x04E\x02\x02\x01\x12\x04\xf3\x03\x19)\n\r\n\x05\x04E\
+    \x02\x02\x03\x12\x04\xf3\x03,-\n\x0c\n\x04\x04E\x02\x03\x12\x04\xf4\x03\
+    \x04*\n\r\n\x05\x04E\x02\x03\x06\x12\x04\xf4\x03\x04\x14\n\r\n\x05\x04E\
+    \x02\x03\x01\x12\x04\xf4\x03\x15%\n\r\n\x05\x04E\x02\x03\x03\x12\x04\xf4\
+    \x03()\n\x0c\n\x04\x04E\x02\x04\x12\x04\xf5\x03\x04.\n\r\n\x05\x04E\x02\
+    \x04\x06\x12\x04\xf5\x03\x04\x16\n\r\n\x05\x04E\x02\x04\x01\x12\x04\xf5\
+    \x03\x17)\n\r\n\x05\x04E\x02\x04\x03\x12\x04\xf5\x03,-\n\x0c\n\x04\x04E\
+    \x02\x05\x12\x04\xf6\x03\x04/\n\r\n\x05\x04E\x02\x05\x06\x12\x04\xf6\x03\
+    \x04\x19\n\r\n\x05\x04E\x02\x05\x01\x12\x04\xf6\x03\x1a*\n\r\n\x05\x04E\
+    \x02\x05\x03\x12\x04\xf6\x03-.\n\x0c\n\x04\x04E\x02\x06\x12\x04\xf7\x03\
+    \x043\n\r\n\x05\x04E\x02\x06\x06\x12\x04\xf7\x03\x04\x1b\n\r\n\x05\x04E\
+    \x02\x06\x01\x12\x04\xf7\x03\x1c.\n\r\n\x05\x04E\x02\x06\x03\x12\x04\xf7\
+    \x0312\n\x0c\n\x04\x04E\x02\x07\x12\x04\xf8\x03\x04/\n\r\n\x05\x04E\x02\
+    \x07\x06\x12\x04\xf8\x03\x04\x19\n\r\n\x05\x04E\x02\x07\x01\x12\x04\xf8\
+    \x03\x1a*\n\r\n\x05\x04E\x02\x07\x03\x12\x04\xf8\x03-.\n\x0c\n\x04\x04E\
+    \x02\x08\x12\x04\xf9\x03\x04=\n\r\n\x05\x04E\x02\x08\x06\x12\x04\xf9\x03\
+    \x04\x20\n\r\n\x05\x04E\x02\x08\x01\x12\x04\xf9\x03!8\n\r\n\x05\x04E\x02\
+    \x08\x03\x12\x04\xf9\x03;<\n\x0c\n\x04\x04E\x02\t\x12\x04\xfa\x03\x04(\n\
+    \r\n\x05\x04E\x02\t\x06\x12\x04\xfa\x03\x04\x15\n\r\n\x05\x04E\x02\t\x01\
+    \x12\x04\xfa\x03\x16\"\n\r\n\x05\x04E\x02\t\x03\x12\x04\xfa\x03%'\n\x0c\
+    \n\x04\x04E\x02\n\x12\x04\xfb\x03\x04@\n\r\n\x05\x04E\x02\n\x06\x12\x04\
+    \xfb\x03\x04!\n\r\n\x05\x04E\x02\n\x01\x12\x04\xfb\x03\":\n\r\n\x05\x04E\
+    \x02\n\x03\x12\x04\xfb\x03=?\n\x0c\n\x04\x04E\x02\x0b\x12\x04\xfc\x03\
+    \x04A\n\r\n\x05\x04E\x02\x0b\x06\x12\x04\xfc\x03\x04\x1f\n\r\n\x05\x04E\
+    \x02\x0b\x01\x12\x04\xfc\x03\x20;\n\r\n\x05\x04E\x02\x0b\x03\x12\x04\xfc\
+    \x03>@b\x06proto3\
 ";
 
 static mut file_descriptor_proto_lazy: ::protobuf::lazy::Lazy<::protobuf::descriptor::FileDescriptorProto> = ::protobuf::lazy::Lazy {
@@ -1,7 +1,7 @@
 use sgxvm::evm::backend::Basic;
 use sgxvm::primitive_types::{H160, H256, U256};
-use sgxvm::storage::Storage;
-use std::vec::Vec;
+use sgxvm::storage::{BatchReadRequest, BatchReadResponse, Storage, WriteSet};
+use std::{string::{String, ToString}, vec::Vec};
 
 use crate::protobuf_generated::ffi;
 use crate::querier::GoQuerier;
@@ -46,20 +46,7 @@ impl Storage for FFIStorage {
                 }
             };
 
-            // Decrypt result
-            if decoded_result.value.is_empty() {
-                return None;
-            }
-
-            let decrypted_result = match encryption::decrypt_storage_cell(key.as_bytes().to_vec(), decoded_result.value) {
-                Ok(decrypted_result) => decrypted_result,
-                Err(err) => {
-                    println!("Cannot decrypt result. Reason: {:?}", err);
-                    return None;
-                }
-            };
-
-            Some(H256::from_slice(&decrypted_result))
+            decrypt_storage_value(key, decoded_result.value)
         } else {
             println!("Get account storage cell failed. Empty response");
             None
@@ -191,6 +178,99 @@ impl Storage for FFIStorage {
             println!("Remove storage cell failed. Empty response");
         }
     }
+
+    fn remove_storage(&mut self, key: &H160) {
+        let encoded_request = coder::encode_remove_storage(key);
+        if let Some(result) = ocall::make_request(self.querier, encoded_request) {
+            match protobuf::parse_from_bytes::<ffi::QueryRemoveStorageResponse>(result.as_slice()) {
+                Err(err) => {
+                    println!("Cannot decode protobuf. Got error: {:?}", err);
+                },
+                _ => {}
+            }
+        } else {
+            println!("Remove storage failed. Empty response");
+        }
+    }
+
+    fn commit(&mut self, write_set: WriteSet) -> Result<(), String> {
+        // Storage cells are encrypted before sending. If any of them cannot be encrypted,
+        // nothing is written to avoid partially applied state
+        let mut storage_cells = Vec::with_capacity(write_set.storage_cells.len());
+        for (key, index, value) in write_set.storage_cells {
+            let encrypted_value = match value {
+                Some(value) => encryption::encrypt_storage_cell(key.as_bytes().to_vec(), value.as_bytes().to_vec())
+                    .map_err(|err| format!("Cannot encrypt storage cell. Reason: {:?}", err))?,
+                None => Vec::default(),
+            };
+            storage_cells.push((key, index, encrypted_value));
+        }
+
+        let encoded_request = coder::encode_commit(
+            &write_set.removed,
+            &write_set.reset_storage,
+            &write_set.accounts,
+            write_set.codes,
+            storage_cells,
+        );
+        let result = ocall::make_request(self.querier, encoded_request)
+            .ok_or_else(|| "Commit failed. Empty response".to_string())?;
+        protobuf::parse_from_bytes::<ffi::QueryCommitResponse>(result.as_slice())
+            .map_err(|err| format!("Cannot decode commit response. Reason: {:?}", err))?;
+
+        Ok(())
+    }
+
+    fn get_batch(&self, request: &BatchReadRequest) -> BatchReadResponse {
+        let empty_response = || BatchReadResponse {
+            accounts: vec![Basic::default(); request.accounts.len()],
+            codes: vec![None; request.codes.len()],
+            storage_cells: vec![None; request.storage_cells.len()],
+        };
+
+        let encoded_request = coder::encode_batch_read(request);
+        let result = match ocall::make_request(self.querier, encoded_request) {
+            Some(result) => result,
+            None => {
+                println!("Batch read failed. Empty response");
+                return empty_response();
+            }
+        };
+
+        // Decode protobuf
+        let decoded_result = match protobuf::parse_from_bytes::<ffi::QueryBatchReadResponse>(result.as_slice()) {
+            Ok(res) => res,
+            Err(err) => {
+                println!("Cannot decode protobuf response: {:?}", err);
+                return empty_response();
+            }
+        };
+
+        // Missing items of response are treated as empty values, the same way as failed single reads
+        let mut accounts = decoded_result.accounts.into_iter();
+        let mut codes = decoded_result.codes.into_iter();
+        let mut storage_cells = decoded_result.storageCells.into_iter();
+
+        BatchReadResponse {
+            accounts: request
+                .accounts
+                .iter()
+                .map(|_| match accounts.next() {
+                    Some(account) => Basic {
+                        balance: U256::from_big_endian(account.balance.as_slice()),
+                        nonce: U256::from(account.nonce),
+                    },
+                    None => Basic::default(),
+                })
+                .collect(),
+            codes: request.codes.iter().map(|_| codes.next().map(|code| code.code)).collect(),
+            storage_cells: request
+                .storage_cells
+                .iter()
+                .map(|(key, _)| storage_cells.next().and_then(|cell| decrypt_storage_value(key, cell.value)))
+                .collect(),
+        }
+    }
 }
 
 impl FFIStorage {
@@ -198,3 +278,19 @@ impl FFIStorage {
         Self {querier}
     }
 }
+
+/// Decrypts storage cell value obtained from keeper. Empty value means that cell is not initialized
+fn decrypt_storage_value(key: &H160, value: Vec<u8>) -> Option<H256> {
+    if value.is_empty() {
+        return None;
+    }
+
+    match encryption::decrypt_storage_cell(key.as_bytes().to_vec(), value) {
+        Ok(decrypted_result) => Some(H256::from_slice(&decrypted_result)),
+        Err(err) => {
+            println!("Cannot decrypt result. Reason: {:?}", err);
+            None
+        }
+    }
+}
+
@@ -98,6 +98,87 @@ func (c MockedConnector) Query(request []byte) ([]byte, error) {
 			return nil, err
 		}
 		return proto.Marshal(&ffi.QueryRemoveStorageCellResponse{})
+	case *ffi.CosmosRequest_RemoveStorage:
+		ethAddress := common.BytesToAddress(request.RemoveStorage.Address)
+		println("[Go:Query] Remove storage: ", ethAddress.String())
+		if err := c.DB.RemoveStorage(ethAddress); err != nil {
+			return nil, err
+		}
+		return proto.Marshal(&ffi.QueryRemoveStorageResponse{})
+	case *ffi.CosmosRequest_BatchRead:
+		data := request.BatchRead
+		println("[Go:Query] Batch read. Accounts: ", len(data.Accounts), "codes: ", len(data.Codes), "storage cells: ", len(data.StorageCells))
+		response := &ffi.QueryBatchReadResponse{}
+		for _, accountRequest := range data.Accounts {
+			acct, err := c.DB.GetAccountOrEmpty(common.BytesToAddress(accountRequest.Address))
+			if err != nil {
+				return nil, err
+			}
+			response.Accounts = append(response.Accounts, &ffi.QueryGetAccountResponse{
+				Balance: acct.Balance,
+				Nonce:   acct.Nonce,
+			})
+		}
+		for _, codeRequest := range data.Codes {
+			acct, err := c.DB.GetAccountOrEmpty(common.BytesToAddress(codeRequest.Address))
+			if err != nil {
+				return nil, err
+			}
+			response.Codes = append(response.Codes, &ffi.QueryGetAccountCodeResponse{Code: acct.Code})
+		}
+		for _, cellRequest := range data.StorageCells {
+			value, err := c.DB.GetStorageCell(common.BytesToAddress(cellRequest.Address), cellRequest.Index)
+			if err != nil {
+				return nil, err
+			}
+			response.StorageCells = append(response.StorageCells, &ffi.QueryGetAccountStorageCellResponse{Value: value})
+		}
+		return proto.Marshal(response)
+	case *ffi.CosmosRequest_Commit:
+		data := request.Commit
+		println("[Go:Query] Commit. Accounts: ", len(data.Accounts), "codes: ", len(data.Codes), "storage cells: ", len(data.StorageCells))
+		for _, removeRequest := range data.Removed {
+			if err := c.DB.Delete(common.BytesToAddress(removeRequest.Address)); err != nil {
+				return nil, err
+			}
+		}
+		for _, resetRequest := range data.ResetStorage {
+			if err := c.DB.RemoveStorage(common.BytesToAddress(resetRequest.Address)); err != nil {
+				return nil, err
+			}
+		}
+		for _, accountRequest := range data.Accounts {
+			if err := c.DB.InsertAccount(common.BytesToAddress(accountRequest.Address), accountRequest.Balance, accountRequest.Nonce); err != nil {
+				return nil, err
+			}
+		}
+		for _, codeRequest := range data.Codes {
+			if err := c.DB.InsertContractCode(common.BytesToAddress(codeRequest.Address), codeRequest.Code); err != nil {
+				return nil, err
+			}
+		}
+		for _, cellRequest := range data.StorageCells {
+			ethAddress := common.BytesToAddress(cellRequest.Address)
+			// Empty value marks removed storage cell
+			if len(cellRequest.Value) == 0 {
+				if err := c.DB.RemoveStorageCell(ethAddress, cellRequest.Index); err != nil {
+					return nil, err
+				}
+				continue
+			}
+			if err := c.DB.InsertStorageCell(ethAddress, cellRequest.Index, cellRequest.Value); err != nil {
+				return nil, err
+			}
+		}
+		return proto.Marshal(&ffi.QueryCommitResponse{})
+	case *ffi.CosmosRequest_BankBalance:
+		ethAddress := common.BytesToAddress(request.BankBalance.Address)
+		// Mocked bank module keeps balances in a single denomination, which is stored within EVM account
+		acct, err := c.DB.GetAccountOrEmpty(ethAddress)
+		if err != nil {
+			return nil, err
+		}
+		return proto.Marshal(&ffi.QueryBankBalanceResponse{Balance: acct.Balance})
 	}
 
 	return nil, errors.New("wrong query")
@@ -238,3 +238,30 @@ func (m MockedDB) RemoveStorageCell(address ethcommon.Address, key []byte) error
 	txn.Commit()
 	return nil
 }
+
+// RemoveStorage removes all storage cells of account
+func (m MockedDB) RemoveStorage(address ethcommon.Address) error {
+	txn := m.db.Txn(true)
+
+	acct, err := m.GetAccount(address)
+	if err != nil {
+		return err
+	}
+
+	if acct == nil {
+		return nil
+	}
+
+	updatedAcct := Account{
+		Address: acct.Address,
+		Balance: acct.Balance,
+		Nonce:   acct.Nonce,
+		Code:    acct.Code,
+		State:   make(map[string][]byte),
+	}
+	if err := txn.Insert("account", &updatedAcct); err != nil {
+		return err
+	}
+	txn.Commit()
+	return nil
+}